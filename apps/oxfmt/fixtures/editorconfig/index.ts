@@ -0,0 +1,3 @@
+function foo() {
+    return 1;
+}
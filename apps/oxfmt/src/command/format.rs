@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
+    ignore::{IgnoreOptions, ignore_options},
+    misc_options, validate_paths,
+};
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub struct FormatCommand {
+    #[bpaf(external)]
+    pub basic_options: BasicOptions,
+
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    #[bpaf(external)]
+    pub misc_options: MiscOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
+    pub paths: Vec<PathBuf>,
+}
+
+impl FormatCommand {
+    pub fn handle_threads(&self) {
+        Self::set_rayon_threads(self.misc_options.threads);
+    }
+
+    fn set_rayon_threads(threads: Option<usize>) {
+        if let Some(threads) = threads {
+            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
+        }
+    }
+}
+
+/// Basic Configuration
+#[derive(Debug, Clone, Bpaf)]
+pub struct BasicOptions {
+    /// Prettier configuration file
+    ///  * only JSON is supported, comments are allowed
+    ///  * options from `.editorconfig` in the current working directory are applied first
+    ///
+    /// If not provided, oxfmt will look for the `"prettier"` key of `package.json`, `.prettierrc` or `.prettierrc.json`
+    /// in the directory of each file and its ancestors, and skip the ones which are not JSON with a warning.
+    #[bpaf(long, short, argument("./.prettierrc"))]
+    pub config: Option<PathBuf>,
+}
+
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// What to do with the formatted code.
+    /// Without `--write` or `--check`, it is printed to stdout.
+    #[bpaf(external(output_mode), optional)]
+    pub mode: Option<OutputMode>,
+
+    /// Read the code from stdin and print the formatted code to stdout.
+    /// PATH is used to find the options and the language, the file does not need to exist.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filepath: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Bpaf)]
+pub enum OutputMode {
+    /// Format files in place
+    Write,
+    /// Check if files are formatted, list the files which are not and exit with a non-zero code
+    Check,
+}
+
+#[cfg(test)]
+mod output_options {
+    use std::path::PathBuf;
+
+    use super::{OutputMode, OutputOptions, format_command};
+
+    fn get_output_options(arg: &str) -> OutputOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        format_command().run_inner(args.as_slice()).unwrap().output_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_output_options(".");
+        assert!(options.mode.is_none());
+        assert!(options.stdin_filepath.is_none());
+    }
+
+    #[test]
+    fn write() {
+        let options = get_output_options("--write .");
+        assert_eq!(options.mode, Some(OutputMode::Write));
+    }
+
+    #[test]
+    fn check() {
+        let options = get_output_options("--check .");
+        assert_eq!(options.mode, Some(OutputMode::Check));
+    }
+
+    #[test]
+    fn write_and_check() {
+        let args = ["--write", "--check", "."];
+        assert!(format_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn stdin_filepath() {
+        let options = get_output_options("--stdin-filepath src/foo.ts");
+        assert_eq!(options.stdin_filepath, Some(PathBuf::from("src/foo.ts")));
+    }
+}
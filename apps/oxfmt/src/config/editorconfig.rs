@@ -0,0 +1,201 @@
+use std::{fs, path::Path};
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+
+use super::{EndOfLine, OptionsPatch, RelativeGlobSet};
+
+/// The properties of an `.editorconfig` file which Prettier also reads: `indent_style`,
+/// `indent_size`, `tab_width`, `end_of_line` and `max_line_length`.
+///
+/// <https://spec.editorconfig.org>
+#[derive(Debug)]
+pub struct EditorConfig {
+    sections: Vec<Section>,
+}
+
+#[derive(Debug)]
+struct Section {
+    globs: RelativeGlobSet,
+    options: OptionsPatch,
+}
+
+impl EditorConfig {
+    /// # Errors
+    ///
+    /// When the file can not be read or a section has an invalid glob.
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let to_diagnostic = |err: String| {
+            OxcDiagnostic::error(format!(
+                "Failed to parse config {} with error {err}",
+                path.display()
+            ))
+        };
+        let source_text = fs::read_to_string(path).map_err(|err| to_diagnostic(err.to_string()))?;
+        let base = path.parent().unwrap_or(path);
+        Self::parse(&source_text, base).map_err(to_diagnostic)
+    }
+
+    /// Parses an `.editorconfig` file, whose section globs are relative to `base`.
+    ///
+    /// Unknown properties and invalid values are ignored, as the specification requires.
+    fn parse(source_text: &str, base: &Path) -> Result<Self, String> {
+        let mut sections = vec![];
+        let mut current: Option<(&str, Properties)> = None;
+
+        for line in source_text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                if let Some((glob, properties)) = current.take() {
+                    sections.push(Section::new(base, glob, &properties)?);
+                }
+                current = Some((glob, Properties::default()));
+                continue;
+            }
+            // Properties before the first section, i.e. `root`, do not affect formatting.
+            let (Some((_, properties)), Some((key, value))) = (&mut current, line.split_once('='))
+            else {
+                continue;
+            };
+            let key = key.trim().cow_to_ascii_lowercase();
+            let value = value.trim().cow_to_ascii_lowercase();
+            properties.set(&key, &value);
+        }
+        if let Some((glob, properties)) = current {
+            sections.push(Section::new(base, glob, &properties)?);
+        }
+
+        Ok(Self { sections })
+    }
+
+    /// Sets the options of the sections which match `path` in `patch`, later sections take
+    /// precedence.
+    pub(super) fn apply_to(&self, path: &Path, patch: &mut OptionsPatch) {
+        for section in &self.sections {
+            if section.globs.is_match(path) {
+                patch.merge(&section.options);
+            }
+        }
+    }
+}
+
+impl Section {
+    fn new(base: &Path, glob: &str, properties: &Properties) -> Result<Self, String> {
+        let globs = RelativeGlobSet::new(base, [glob])
+            .map_err(|err| format!("invalid section [{glob}]: {err}"))?;
+        Ok(Self { globs, options: properties.to_patch() })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Properties {
+    indent_style: Option<IndentStyle>,
+    indent_size: Option<IndentSize>,
+    tab_width: Option<IndentWidth>,
+    end_of_line: Option<LineEnding>,
+    max_line_length: Option<LineWidth>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum IndentSize {
+    /// `indent_size = tab`, the width of a tab is used.
+    Tab,
+    Width(IndentWidth),
+}
+
+impl Properties {
+    fn set(&mut self, key: &str, value: &str) {
+        match key {
+            "indent_style" => self.indent_style = value.parse().ok(),
+            "indent_size" => {
+                self.indent_size = if value == "tab" {
+                    Some(IndentSize::Tab)
+                } else {
+                    value.parse().ok().map(IndentSize::Width)
+                };
+            }
+            "tab_width" => self.tab_width = value.parse().ok(),
+            "end_of_line" => self.end_of_line = value.parse().ok(),
+            "max_line_length" => self.max_line_length = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    fn to_patch(&self) -> OptionsPatch {
+        let indent_width = match self.indent_size {
+            Some(IndentSize::Width(width)) => Some(width),
+            Some(IndentSize::Tab) | None => self.tab_width,
+        };
+        OptionsPatch {
+            indent_style: self.indent_style,
+            indent_width,
+            end_of_line: self.end_of_line.map(EndOfLine::Fixed),
+            line_width: self.max_line_length,
+            ..OptionsPatch::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+
+    use super::{super::EndOfLine, EditorConfig, OptionsPatch};
+
+    fn resolve(config: &EditorConfig, path: &str) -> OptionsPatch {
+        let mut patch = OptionsPatch::default();
+        config.apply_to(Path::new(path), &mut patch);
+        patch
+    }
+
+    #[test]
+    fn test_sections() {
+        let config = EditorConfig::parse(
+            "
+root = true
+
+[*]
+indent_style = space
+indent_size = 2
+end_of_line = LF
+max_line_length = off
+
+# tabs for TypeScript
+[*.{ts,tsx}]
+indent_style = tab
+indent_size = tab
+tab_width = 8
+
+[/vendor/**]
+max_line_length = 120
+unknown_property = 1
+",
+            Path::new("/project"),
+        )
+        .unwrap();
+
+        let patch = resolve(&config, "/project/src/foo.js");
+        assert_eq!(patch.indent_style, Some(IndentStyle::Space));
+        assert_eq!(patch.indent_width, Some(IndentWidth::try_from(2).unwrap()));
+        assert_eq!(patch.end_of_line, Some(EndOfLine::Fixed(LineEnding::Lf)));
+        assert_eq!(patch.line_width, None);
+
+        let patch = resolve(&config, "/project/src/foo.tsx");
+        assert_eq!(patch.indent_style, Some(IndentStyle::Tab));
+        assert_eq!(patch.indent_width, Some(IndentWidth::try_from(8).unwrap()));
+
+        let patch = resolve(&config, "/project/vendor/lib/foo.js");
+        assert_eq!(patch.line_width, Some(LineWidth::try_from(120).unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        assert!(EditorConfig::parse("[a[]\nindent_size = 2", Path::new("/project")).is_err());
+    }
+}
@@ -0,0 +1,320 @@
+mod editorconfig;
+mod prettierrc;
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_formatter::{
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, Expand, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+};
+use rustc_hash::FxHashMap;
+
+use self::{
+    editorconfig::EditorConfig,
+    prettierrc::{Prettierrc, PrettierrcError},
+};
+
+/// The options of every file, read from `.editorconfig` and the Prettier configuration files.
+///
+/// Options are applied from the lowest to the highest precedence: the defaults of
+/// [`FormatOptions`], the matching `.editorconfig` sections, the Prettier options and finally
+/// the matching Prettier `overrides`.
+#[derive(Debug, Default)]
+pub struct FormatConfig {
+    editorconfig: Option<EditorConfig>,
+    /// The configuration file given with `--config`, which applies to every file.
+    config: Option<Arc<Prettierrc>>,
+    /// The nearest Prettier configuration file of each directory, `None` when there is none.
+    prettierrcs: FxHashMap<PathBuf, Option<Arc<Prettierrc>>>,
+}
+
+impl FormatConfig {
+    /// The Prettier configuration files which are looked up in every directory, in the order
+    /// Prettier looks them up.
+    pub const PRETTIERRC: [&str; 3] = ["package.json", ".prettierrc", ".prettierrc.json"];
+    pub const EDITORCONFIG: &str = ".editorconfig";
+
+    /// Reads `.editorconfig` from `cwd` and the Prettier configuration file `config` when it is
+    /// given.
+    ///
+    /// # Errors
+    ///
+    /// When a configuration file can not be read or contains an invalid option.
+    pub fn load(cwd: &Path, config: Option<&Path>) -> Result<Self, OxcDiagnostic> {
+        let editorconfig_path = cwd.join(Self::EDITORCONFIG);
+        let editorconfig = if editorconfig_path.is_file() {
+            Some(EditorConfig::from_file(&editorconfig_path)?)
+        } else {
+            None
+        };
+
+        let config = config
+            .map(|config| Prettierrc::from_file(&cwd.join(config)))
+            .transpose()
+            .map_err(PrettierrcError::into_diagnostic)?
+            .flatten()
+            .map(Arc::new);
+
+        Ok(Self { editorconfig, config, prettierrcs: FxHashMap::default() })
+    }
+
+    /// Finds the Prettier configuration file of each of `paths` when none is given with
+    /// `--config`. Like in Prettier, this is the nearest one in the directory of the file or
+    /// its ancestors.
+    ///
+    /// Configuration files which can not be read or are not JSON, e.g. a YAML `.prettierrc`,
+    /// are skipped and returned as warnings. The files they apply to are formatted without a
+    /// Prettier configuration.
+    ///
+    /// # Errors
+    ///
+    /// When a configuration file contains an invalid option.
+    pub fn find_prettierrcs<'a>(
+        &mut self,
+        paths: impl IntoIterator<Item = &'a Path>,
+    ) -> Result<Vec<OxcDiagnostic>, OxcDiagnostic> {
+        let mut warnings = vec![];
+        if self.config.is_none() {
+            for dir in paths.into_iter().filter_map(Path::parent) {
+                self.find_prettierrc(dir, &mut warnings)?;
+            }
+        }
+        Ok(warnings)
+    }
+
+    fn find_prettierrc(
+        &mut self,
+        dir: &Path,
+        warnings: &mut Vec<OxcDiagnostic>,
+    ) -> Result<Option<Arc<Prettierrc>>, OxcDiagnostic> {
+        if let Some(prettierrc) = self.prettierrcs.get(dir) {
+            return Ok(prettierrc.clone());
+        }
+
+        let mut found = None;
+        for path in Self::PRETTIERRC.iter().map(|name| dir.join(name)) {
+            if !path.is_file() {
+                continue;
+            }
+            match Prettierrc::from_file(&path) {
+                // A `package.json` without a `"prettier"` key.
+                Ok(None) => continue,
+                Ok(Some(prettierrc)) => found = Some(Some(Arc::new(prettierrc))),
+                Err(PrettierrcError::Unsupported(err)) => {
+                    warnings.push(
+                        err.with_severity(Severity::Warning)
+                            .with_help("This file is skipped, only JSON is supported."),
+                    );
+                    found = Some(None);
+                }
+                Err(PrettierrcError::Invalid(err)) => return Err(err),
+            }
+            break;
+        }
+        let prettierrc = match (found, dir.parent()) {
+            (Some(prettierrc), _) => prettierrc,
+            (None, Some(parent)) => self.find_prettierrc(parent, warnings)?,
+            (None, None) => None,
+        };
+
+        self.prettierrcs.insert(dir.to_path_buf(), prettierrc.clone());
+        Ok(prettierrc)
+    }
+
+    /// The options to format the file at `path` with. `source_text` is needed for
+    /// `"endOfLine": "auto"`, which keeps the line ending the file already uses.
+    ///
+    /// The Prettier configuration file of `path` must have been looked up with
+    /// [`Self::find_prettierrcs`] before.
+    pub fn resolve(&self, path: &Path, source_text: &str) -> FormatOptions {
+        let mut patch = OptionsPatch::default();
+        if let Some(editorconfig) = &self.editorconfig {
+            editorconfig.apply_to(path, &mut patch);
+        }
+        let prettierrc = self.config.as_ref().or_else(|| {
+            path.parent().and_then(|dir| self.prettierrcs.get(dir)).and_then(Option::as_ref)
+        });
+        if let Some(prettierrc) = prettierrc {
+            prettierrc.apply_to(path, &mut patch);
+        }
+
+        let mut options = FormatOptions::new();
+        patch.apply_to(&mut options, source_text);
+        options
+    }
+}
+
+/// Options which are set by a configuration file, `None` when they are not set.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct OptionsPatch {
+    indent_style: Option<IndentStyle>,
+    indent_width: Option<IndentWidth>,
+    end_of_line: Option<EndOfLine>,
+    line_width: Option<LineWidth>,
+    quote_style: Option<QuoteStyle>,
+    jsx_quote_style: Option<QuoteStyle>,
+    quote_properties: Option<QuoteProperties>,
+    trailing_commas: Option<TrailingCommas>,
+    semicolons: Option<Semicolons>,
+    arrow_parentheses: Option<ArrowParentheses>,
+    bracket_spacing: Option<BracketSpacing>,
+    bracket_same_line: Option<BracketSameLine>,
+    attribute_position: Option<AttributePosition>,
+    expand: Option<Expand>,
+    experimental_operator_position: Option<OperatorPosition>,
+    embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
+}
+
+impl OptionsPatch {
+    /// Overwrites the options of `self` with the ones set in `other`.
+    fn merge(&mut self, other: &Self) {
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field;
+                    }
+                )*
+            };
+        }
+        merge!(
+            indent_style,
+            indent_width,
+            end_of_line,
+            line_width,
+            quote_style,
+            jsx_quote_style,
+            quote_properties,
+            trailing_commas,
+            semicolons,
+            arrow_parentheses,
+            bracket_spacing,
+            bracket_same_line,
+            attribute_position,
+            expand,
+            experimental_operator_position,
+            embedded_language_formatting
+        );
+    }
+
+    fn apply_to(&self, options: &mut FormatOptions, source_text: &str) {
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(
+                    if let Some(value) = self.$field {
+                        options.$field = value;
+                    }
+                )*
+            };
+        }
+        apply!(
+            indent_style,
+            indent_width,
+            line_width,
+            quote_style,
+            jsx_quote_style,
+            quote_properties,
+            trailing_commas,
+            semicolons,
+            arrow_parentheses,
+            bracket_spacing,
+            bracket_same_line,
+            attribute_position,
+            expand,
+            experimental_operator_position,
+            embedded_language_formatting
+        );
+        match self.end_of_line {
+            Some(EndOfLine::Fixed(line_ending)) => options.line_ending = line_ending,
+            Some(EndOfLine::Auto) => {
+                if let Some(line_ending) = detect_line_ending(source_text) {
+                    options.line_ending = line_ending;
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EndOfLine {
+    /// Use the line ending of the first line of the file.
+    Auto,
+    Fixed(LineEnding),
+}
+
+fn detect_line_ending(source_text: &str) -> Option<LineEnding> {
+    let index = source_text.find(['\r', '\n'])?;
+    let line_ending = match &source_text.as_bytes()[index..] {
+        [b'\r', b'\n', ..] => LineEnding::Crlf,
+        [b'\r', ..] => LineEnding::Cr,
+        _ => LineEnding::Lf,
+    };
+    Some(line_ending)
+}
+
+/// A set of globs in a configuration file, which match paths relative to the directory of
+/// that file. Like in Prettier and EditorConfig, a glob without a `/` matches the file name in
+/// any directory.
+#[derive(Debug)]
+struct RelativeGlobSet {
+    base: PathBuf,
+    globs: GlobSet,
+}
+
+impl RelativeGlobSet {
+    fn new<'s>(
+        base: &Path,
+        patterns: impl IntoIterator<Item = &'s str>,
+    ) -> Result<Self, globset::Error> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = if pattern.contains('/') {
+                pattern.trim_start_matches("./").trim_start_matches('/').to_string()
+            } else {
+                format!("**/{pattern}")
+            };
+            builder.add(GlobBuilder::new(&pattern).literal_separator(true).build()?);
+        }
+        Ok(Self { base: base.to_path_buf(), globs: builder.build()? })
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        self.globs.is_match(path.strip_prefix(&self.base).unwrap_or(path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_formatter::LineEnding;
+
+    use super::{RelativeGlobSet, detect_line_ending};
+
+    #[test]
+    fn test_detect_line_ending() {
+        assert_eq!(detect_line_ending("a\nb\r\n"), Some(LineEnding::Lf));
+        assert_eq!(detect_line_ending("a\r\nb\n"), Some(LineEnding::Crlf));
+        assert_eq!(detect_line_ending("a\rb"), Some(LineEnding::Cr));
+        assert_eq!(detect_line_ending("a"), None);
+    }
+
+    #[test]
+    fn test_relative_glob_set() {
+        let base = Path::new("/project");
+        let globs = RelativeGlobSet::new(base, ["*.ts", "/src/*.js", "./test/**/*.jsx"]).unwrap();
+        assert!(globs.is_match(Path::new("/project/foo.ts")));
+        assert!(globs.is_match(Path::new("/project/a/b/foo.ts")));
+        assert!(globs.is_match(Path::new("/project/src/foo.js")));
+        assert!(!globs.is_match(Path::new("/project/src/a/foo.js")));
+        assert!(!globs.is_match(Path::new("/project/foo.js")));
+        assert!(globs.is_match(Path::new("/project/test/a/foo.jsx")));
+    }
+}
@@ -0,0 +1,380 @@
+use std::{ffi::OsStr, fmt::Display, fs, path::Path, str::FromStr};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_formatter::{
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, Expand, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+};
+use serde::Deserialize;
+
+use super::{EndOfLine, OptionsPatch, RelativeGlobSet};
+
+/// A Prettier configuration file, or the `"prettier"` key of a `package.json`. Only the JSON
+/// format is supported, and options which [`oxc_formatter`] has no equivalent for are ignored.
+///
+/// <https://prettier.io/docs/configuration>
+#[derive(Debug)]
+pub struct Prettierrc {
+    options: OptionsPatch,
+    overrides: Vec<Override>,
+}
+
+#[derive(Debug)]
+struct Override {
+    files: RelativeGlobSet,
+    exclude_files: RelativeGlobSet,
+    options: OptionsPatch,
+}
+
+/// Why a Prettier configuration file could not be loaded.
+#[derive(Debug)]
+pub enum PrettierrcError {
+    /// The file can not be read or is in a format which is not supported, e.g. YAML.
+    Unsupported(OxcDiagnostic),
+    /// The file contains an invalid option.
+    Invalid(OxcDiagnostic),
+}
+
+impl PrettierrcError {
+    pub fn into_diagnostic(self) -> OxcDiagnostic {
+        match self {
+            Self::Unsupported(diagnostic) | Self::Invalid(diagnostic) => diagnostic,
+        }
+    }
+}
+
+impl Prettierrc {
+    /// Reads the configuration file at `path`. A `package.json` without a `"prettier"` key
+    /// returns `None`.
+    ///
+    /// # Errors
+    ///
+    /// When the file can not be read, is not valid JSON or contains an invalid option.
+    pub fn from_file(path: &Path) -> Result<Option<Self>, PrettierrcError> {
+        let to_diagnostic = |err: String| {
+            OxcDiagnostic::error(format!(
+                "Failed to parse config {} with error {err}",
+                path.display()
+            ))
+        };
+        let unsupported = |err: String| PrettierrcError::Unsupported(to_diagnostic(err));
+
+        let source_text = fs::read_to_string(path).map_err(|err| unsupported(err.to_string()))?;
+        let config = if path.file_name() == Some(OsStr::new("package.json")) {
+            let package_json = serde_json::from_str::<serde_json::Value>(&source_text)
+                .map_err(|err| unsupported(err.to_string()))?;
+            match package_json.get("prettier") {
+                None => return Ok(None),
+                Some(serde_json::Value::String(name)) => {
+                    return Err(unsupported(format!(
+                        "shared configuration {name:?} is not supported"
+                    )));
+                }
+                Some(config) => config.clone(),
+            }
+        } else {
+            Self::parse_json(source_text).map_err(unsupported)?
+        };
+        let base = path.parent().unwrap_or(path);
+        Self::from_json(config, base)
+            .map(Some)
+            .map_err(|err| PrettierrcError::Invalid(to_diagnostic(err)))
+    }
+
+    /// Parses JSON with comments.
+    fn parse_json(mut source_text: String) -> Result<serde_json::Value, String> {
+        json_strip_comments::strip(&mut source_text).map_err(|err| err.to_string())?;
+        serde_json::from_str(&source_text).map_err(|err| err.to_string())
+    }
+
+    /// Reads the options of a configuration, whose `overrides` globs are relative to `base`.
+    fn from_json(config: serde_json::Value, base: &Path) -> Result<Self, String> {
+        let config = PrettierrcJson::deserialize(config).map_err(|err| err.to_string())?;
+
+        let options = config.options.into_patch()?;
+        let overrides = config
+            .overrides
+            .into_iter()
+            .map(|config| {
+                let globs = |globs: &Globs| {
+                    RelativeGlobSet::new(base, globs.iter()).map_err(|err| err.to_string())
+                };
+                Ok(Override {
+                    files: globs(&config.files)?,
+                    exclude_files: globs(&config.exclude_files)?,
+                    options: config.options.into_patch()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self { options, overrides })
+    }
+
+    /// Sets the options which apply to `path` in `patch`.
+    pub(super) fn apply_to(&self, path: &Path, patch: &mut OptionsPatch) {
+        patch.merge(&self.options);
+        for config in &self.overrides {
+            if config.files.is_match(path) && !config.exclude_files.is_match(path) {
+                patch.merge(&config.options);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PrettierrcJson {
+    #[serde(flatten)]
+    options: OptionsJson,
+    #[serde(default)]
+    overrides: Vec<OverrideJson>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OverrideJson {
+    files: Globs,
+    #[serde(default)]
+    exclude_files: Globs,
+    #[serde(default)]
+    options: OptionsJson,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Globs {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Default for Globs {
+    fn default() -> Self {
+        Self::Many(vec![])
+    }
+}
+
+impl Globs {
+    fn iter(&self) -> impl Iterator<Item = &str> {
+        match self {
+            Self::One(glob) => std::slice::from_ref(glob).iter(),
+            Self::Many(globs) => globs.iter(),
+        }
+        .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OptionsJson {
+    print_width: Option<u16>,
+    tab_width: Option<u8>,
+    use_tabs: Option<bool>,
+    semi: Option<bool>,
+    single_quote: Option<bool>,
+    jsx_single_quote: Option<bool>,
+    quote_props: Option<String>,
+    trailing_comma: Option<String>,
+    bracket_spacing: Option<bool>,
+    bracket_same_line: Option<bool>,
+    object_wrap: Option<String>,
+    arrow_parens: Option<String>,
+    end_of_line: Option<String>,
+    single_attribute_per_line: Option<bool>,
+    experimental_operator_position: Option<String>,
+    embedded_language_formatting: Option<String>,
+}
+
+impl OptionsJson {
+    fn into_patch(self) -> Result<OptionsPatch, String> {
+        let end_of_line = match self.end_of_line.as_deref() {
+            Some("auto") => Some(EndOfLine::Auto),
+            value => parse_option::<LineEnding>("endOfLine", value)?.map(EndOfLine::Fixed),
+        };
+        let expand = match self.object_wrap.as_deref() {
+            None => None,
+            Some("preserve") => Some(Expand::Auto),
+            Some("collapse") => Some(Expand::Never),
+            Some(value) => {
+                return Err(format!(
+                    "Invalid value {value:?} for `objectWrap`, expected \"preserve\" or \"collapse\""
+                ));
+            }
+        };
+
+        Ok(OptionsPatch {
+            indent_style: self
+                .use_tabs
+                .map(|tabs| if tabs { IndentStyle::Tab } else { IndentStyle::Space }),
+            indent_width: self
+                .tab_width
+                .map(|width| {
+                    IndentWidth::try_from(width).map_err(|_| {
+                        format!(
+                            "`tabWidth` must be between {} and {}",
+                            IndentWidth::MIN,
+                            IndentWidth::MAX
+                        )
+                    })
+                })
+                .transpose()?,
+            end_of_line,
+            line_width: self
+                .print_width
+                .map(|width| {
+                    LineWidth::try_from(width).map_err(|_| {
+                        format!(
+                            "`printWidth` must be between {} and {}",
+                            LineWidth::MIN,
+                            LineWidth::MAX
+                        )
+                    })
+                })
+                .transpose()?,
+            quote_style: self.single_quote.map(quote_style),
+            jsx_quote_style: self.jsx_single_quote.map(quote_style),
+            quote_properties: parse_option::<QuoteProperties>(
+                "quoteProps",
+                self.quote_props.as_deref(),
+            )?,
+            trailing_commas: parse_option::<TrailingCommas>(
+                "trailingComma",
+                self.trailing_comma.as_deref(),
+            )?,
+            semicolons: self
+                .semi
+                .map(|semi| if semi { Semicolons::Always } else { Semicolons::AsNeeded }),
+            arrow_parentheses: parse_option::<ArrowParentheses>(
+                "arrowParens",
+                self.arrow_parens.as_deref(),
+            )?,
+            bracket_spacing: self.bracket_spacing.map(BracketSpacing::from),
+            bracket_same_line: self.bracket_same_line.map(BracketSameLine::from),
+            attribute_position: self.single_attribute_per_line.map(|single| {
+                if single { AttributePosition::Multiline } else { AttributePosition::Auto }
+            }),
+            expand,
+            experimental_operator_position: parse_option::<OperatorPosition>(
+                "experimentalOperatorPosition",
+                self.experimental_operator_position.as_deref(),
+            )?,
+            embedded_language_formatting: parse_option::<EmbeddedLanguageFormatting>(
+                "embeddedLanguageFormatting",
+                self.embedded_language_formatting.as_deref(),
+            )?,
+        })
+    }
+}
+
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote { QuoteStyle::Single } else { QuoteStyle::Double }
+}
+
+fn parse_option<T>(name: &str, value: Option<&str>) -> Result<Option<T>, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .map(|value| {
+            T::from_str(value).map_err(|err| format!("Invalid value {value:?} for `{name}`: {err}"))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_formatter::{
+        ArrowParentheses, EmbeddedLanguageFormatting, Expand, IndentStyle, IndentWidth, LineEnding,
+        LineWidth, QuoteStyle, Semicolons, TrailingCommas,
+    };
+
+    use super::{super::EndOfLine, OptionsPatch, Prettierrc};
+
+    fn parse(source_text: &str) -> Result<Prettierrc, String> {
+        let config = Prettierrc::parse_json(source_text.to_string())?;
+        Prettierrc::from_json(config, Path::new("/project"))
+    }
+
+    fn resolve(config: &Prettierrc, path: &str) -> OptionsPatch {
+        let mut patch = OptionsPatch::default();
+        config.apply_to(Path::new(path), &mut patch);
+        patch
+    }
+
+    #[test]
+    fn test_options() {
+        let config = parse(
+            r#"{
+                // comments are allowed
+                "$schema": "http://json.schemastore.org/prettierrc",
+                "printWidth": 100,
+                "tabWidth": 4,
+                "useTabs": true,
+                "semi": false,
+                "singleQuote": true,
+                "trailingComma": "es5",
+                "arrowParens": "avoid",
+                "endOfLine": "crlf",
+                "objectWrap": "collapse",
+                "embeddedLanguageFormatting": "off",
+                "plugins": ["prettier-plugin-foo"]
+            }"#,
+        )
+        .unwrap();
+        let patch = resolve(&config, "/project/foo.js");
+        assert_eq!(patch.line_width, Some(LineWidth::try_from(100).unwrap()));
+        assert_eq!(patch.indent_width, Some(IndentWidth::try_from(4).unwrap()));
+        assert_eq!(patch.indent_style, Some(IndentStyle::Tab));
+        assert_eq!(patch.semicolons, Some(Semicolons::AsNeeded));
+        assert_eq!(patch.quote_style, Some(QuoteStyle::Single));
+        assert_eq!(patch.jsx_quote_style, None);
+        assert_eq!(patch.trailing_commas, Some(TrailingCommas::Es5));
+        assert_eq!(patch.arrow_parentheses, Some(ArrowParentheses::AsNeeded));
+        assert_eq!(patch.end_of_line, Some(EndOfLine::Fixed(LineEnding::Crlf)));
+        assert_eq!(patch.expand, Some(Expand::Never));
+        assert_eq!(patch.embedded_language_formatting, Some(EmbeddedLanguageFormatting::Off));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let config = parse(
+            r#"{
+                "semi": false,
+                "overrides": [
+                    { "files": "*.ts", "excludeFiles": "legacy/**", "options": { "semi": true } },
+                    { "files": ["src/*.js"], "options": { "singleQuote": true } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let patch = resolve(&config, "/project/lib/foo.ts");
+        assert_eq!(patch.semicolons, Some(Semicolons::Always));
+
+        let patch = resolve(&config, "/project/legacy/foo.ts");
+        assert_eq!(patch.semicolons, Some(Semicolons::AsNeeded));
+
+        let patch = resolve(&config, "/project/src/foo.js");
+        assert_eq!(patch.semicolons, Some(Semicolons::AsNeeded));
+        assert_eq!(patch.quote_style, Some(QuoteStyle::Single));
+
+        let patch = resolve(&config, "/project/src/nested/foo.js");
+        assert_eq!(patch.quote_style, None);
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert_eq!(
+            parse(r#"{ "printWidth": 1000 }"#).unwrap_err(),
+            "`printWidth` must be between 1 and 320"
+        );
+        assert_eq!(
+            parse(r#"{ "trailingComma": "always" }"#).unwrap_err(),
+            "Invalid value \"always\" for `trailingComma`: Value not supported for TrailingCommas"
+        );
+        assert!(parse(r#"{ "semi": "false" }"#).is_err());
+        assert!(parse("semi: false").is_err());
+    }
+}
@@ -0,0 +1,602 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    io::{ErrorKind, Read, Write},
+    path::{Path, PathBuf, absolute},
+    sync::Arc,
+    time::Instant,
+};
+
+use cow_utils::CowUtils;
+use ignore::{
+    gitignore::Gitignore,
+    overrides::{Override, OverrideBuilder},
+};
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    DiagnosticService, Error, GraphicalReportHandler, NamedSource, OxcDiagnostic,
+};
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_partial_loader::{JavaScriptSource, PartialLoader};
+use oxc_span::SourceType;
+use rayon::prelude::*;
+
+use crate::{
+    cli::{CliRunResult, FormatCommand, IgnoreOptions, OutputMode},
+    config::FormatConfig,
+    walk::Walk,
+};
+
+#[derive(Debug)]
+pub struct FormatRunner {
+    options: FormatCommand,
+    cwd: PathBuf,
+}
+
+/// The outcome of formatting a single file.
+enum FileResult {
+    Formatted {
+        /// Whether the formatted code differs from the source text.
+        changed: bool,
+        /// The formatted code, only kept when it is printed to stdout.
+        code: Option<String>,
+    },
+    Failed(Vec<Error>),
+}
+
+impl FormatRunner {
+    pub(crate) fn new(options: FormatCommand) -> Self {
+        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+    }
+
+    #[must_use]
+    pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
+        self.cwd = cwd;
+        self
+    }
+
+    pub(crate) fn run(self, stdin: &mut dyn Read, stdout: &mut dyn Write) -> CliRunResult {
+        let FormatCommand { basic_options, output_options, ignore_options, paths, .. } =
+            self.options;
+
+        let handler = if cfg!(test) {
+            GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
+        } else {
+            GraphicalReportHandler::new()
+        };
+
+        let mut config = match FormatConfig::load(&self.cwd, basic_options.config.as_deref()) {
+            Ok(config) => config,
+            Err(err) => {
+                print_config_error(stdout, &handler, err);
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+
+        let override_builder = if ignore_options.no_ignore {
+            None
+        } else {
+            Some(Self::get_override_builder(&self.cwd, &ignore_options))
+        };
+
+        if let Some(path) = output_options.stdin_filepath {
+            let mut source_text = String::new();
+            if let Err(err) = stdin.read_to_string(&mut source_text) {
+                print_and_flush_stdout(stdout, &format!("Failed to read stdin: {err}\n"));
+                return CliRunResult::FormatFailed;
+            }
+
+            let path = self.cwd.join(path);
+            // Ignored files are printed unchanged, so that editors can always use the output.
+            let is_ignored = override_builder.as_ref().is_some_and(|builder| {
+                let (ignore, _err) = Gitignore::new(self.cwd.join(&ignore_options.ignore_path));
+                builder.matched(&path, false).is_ignore()
+                    || ignore.matched_path_or_any_parents(&path, false).is_ignore()
+            });
+            if is_ignored {
+                if output_options.mode != Some(OutputMode::Check) {
+                    print_and_flush_stdout(stdout, &source_text);
+                }
+                return CliRunResult::FormatSucceeded;
+            }
+
+            // Warnings are not printed, because the output is the formatted code.
+            if let Err(err) = config.find_prettierrcs([path.as_path()]) {
+                print_config_error(stdout, &handler, err);
+                return CliRunResult::InvalidOptionConfig;
+            }
+
+            return Self::format_stdin(
+                stdout,
+                &path,
+                &self.cwd,
+                &source_text,
+                &config,
+                output_options.mode,
+                &handler,
+            );
+        }
+
+        let mut paths = paths;
+        let provided_path_count = paths.len();
+        let now = Instant::now();
+
+        // The ignore crate whitelists explicit paths, but priority
+        // should be given to the ignore file, so that a list of changed
+        // files can be passed explicitly.
+        if let Some(builder) = &override_builder {
+            if !paths.is_empty() {
+                let (ignore, _err) = Gitignore::new(self.cwd.join(&ignore_options.ignore_path));
+
+                paths.retain_mut(|p| {
+                    // Try to prepend cwd to all paths
+                    let Ok(mut path) = absolute(self.cwd.join(&p)) else {
+                        return false;
+                    };
+
+                    std::mem::swap(p, &mut path);
+
+                    if path.is_dir() {
+                        true
+                    } else {
+                        !(builder.matched(p, false).is_ignore()
+                            || ignore.matched(path, false).is_ignore())
+                    }
+                });
+            }
+        }
+
+        if paths.is_empty() {
+            // If explicit paths were provided, but all have been
+            // filtered, return early.
+            if provided_path_count > 0 {
+                if output_options.mode.is_some() {
+                    print_and_flush_stdout(stdout, &Self::finished_message(now, 0));
+                }
+                return CliRunResult::FormatNoFilesFound;
+            }
+
+            paths.push(self.cwd.clone());
+        }
+
+        let mut paths = Walk::new(&paths, &ignore_options, override_builder).paths();
+        paths.sort_unstable();
+        let number_of_files = paths.len();
+        if number_of_files == 0 {
+            print_and_flush_stdout(stdout, "No files found to format.\n");
+            return CliRunResult::FormatNoFilesFound;
+        }
+
+        match config.find_prettierrcs(paths.iter().map(Path::new)) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!("{}\n", render_report(&handler, &warning.into())),
+                    );
+                }
+            }
+            Err(err) => {
+                print_config_error(stdout, &handler, err);
+                return CliRunResult::InvalidOptionConfig;
+            }
+        }
+
+        let mode = output_options.mode;
+        let results = paths
+            .par_iter()
+            .map(|path| Self::format_file(Path::new(path), &self.cwd, &config, mode))
+            .collect::<Vec<_>>();
+
+        let mut number_of_changed_files = 0;
+        let mut has_errors = false;
+        for (path, result) in paths.iter().zip(results) {
+            match result {
+                FileResult::Formatted { changed, code } => {
+                    if let Some(code) = code {
+                        print_and_flush_stdout(stdout, &code);
+                    } else if changed {
+                        let path = display_path(Path::new(path), &self.cwd);
+                        print_and_flush_stdout(stdout, &format!("{path}\n"));
+                    }
+                    number_of_changed_files += usize::from(changed);
+                }
+                FileResult::Failed(errors) => {
+                    has_errors = true;
+                    for error in &errors {
+                        print_and_flush_stdout(stdout, &render_report(&handler, error));
+                    }
+                }
+            }
+        }
+
+        if mode == Some(OutputMode::Check) && number_of_changed_files > 0 {
+            let s = if number_of_changed_files == 1 { "" } else { "s" };
+            print_and_flush_stdout(
+                stdout,
+                &format!(
+                    "\nFormat issues found in {number_of_changed_files} file{s}. Run `oxfmt --write` to fix them.\n"
+                ),
+            );
+        }
+        if mode.is_some() {
+            print_and_flush_stdout(stdout, &Self::finished_message(now, number_of_files));
+        }
+
+        if has_errors {
+            CliRunResult::FormatFailed
+        } else if mode == Some(OutputMode::Check) && number_of_changed_files > 0 {
+            CliRunResult::FormatCheckFailed
+        } else {
+            CliRunResult::FormatSucceeded
+        }
+    }
+
+    /// `--ignore-pattern` globs and `node_modules`, which Prettier never formats.
+    fn get_override_builder(cwd: &Path, ignore_options: &IgnoreOptions) -> Override {
+        let mut builder = OverrideBuilder::new(cwd);
+        // Meaning of ignore pattern is reversed
+        // <https://docs.rs/ignore/latest/ignore/overrides/struct.OverrideBuilder.html#method.add>
+        builder.add("!**/node_modules").unwrap();
+        for pattern in &ignore_options.ignore_pattern {
+            builder.add(&format!("!{pattern}")).unwrap();
+        }
+        builder.build().unwrap()
+    }
+
+    fn format_stdin(
+        stdout: &mut dyn Write,
+        path: &Path,
+        cwd: &Path,
+        source_text: &str,
+        config: &FormatConfig,
+        mode: Option<OutputMode>,
+        handler: &GraphicalReportHandler,
+    ) -> CliRunResult {
+        match Self::format_source(path, cwd, source_text, config) {
+            Ok(code) if mode == Some(OutputMode::Check) => {
+                if code == source_text {
+                    CliRunResult::FormatSucceeded
+                } else {
+                    print_and_flush_stdout(stdout, &format!("{}\n", display_path(path, cwd)));
+                    CliRunResult::FormatCheckFailed
+                }
+            }
+            Ok(code) => {
+                print_and_flush_stdout(stdout, &code);
+                CliRunResult::FormatSucceeded
+            }
+            Err(errors) => {
+                for error in &errors {
+                    print_and_flush_stdout(stdout, &render_report(handler, error));
+                }
+                CliRunResult::FormatFailed
+            }
+        }
+    }
+
+    fn format_file(
+        path: &Path,
+        cwd: &Path,
+        config: &FormatConfig,
+        mode: Option<OutputMode>,
+    ) -> FileResult {
+        let source_text = match fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(err) => {
+                let message = format!("Failed to read {}: {err}", display_path(path, cwd));
+                return FileResult::Failed(vec![OxcDiagnostic::error(message).into()]);
+            }
+        };
+
+        match Self::format_source(path, cwd, &source_text, config) {
+            Ok(code) => {
+                let changed = code != source_text;
+                if changed && mode == Some(OutputMode::Write) {
+                    if let Err(err) = fs::write(path, &code) {
+                        let message = format!("Failed to write {}: {err}", display_path(path, cwd));
+                        return FileResult::Failed(vec![OxcDiagnostic::error(message).into()]);
+                    }
+                }
+                FileResult::Formatted { changed, code: mode.is_none().then_some(code) }
+            }
+            Err(errors) => FileResult::Failed(errors),
+        }
+    }
+
+    fn format_source(
+        path: &Path,
+        cwd: &Path,
+        source_text: &str,
+        config: &FormatConfig,
+    ) -> Result<String, Vec<Error>> {
+        let extension = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        if let Some(sections) = PartialLoader::parse(extension, source_text) {
+            let options = config.resolve(path, source_text);
+            return Self::format_partial_source(path, cwd, source_text, &sections, &options);
+        }
+
+        let source_type = SourceType::from_path(path)
+            .map_err(|err| vec![OxcDiagnostic::error(err.to_string()).into()])?;
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .with_options(ParseOptions { preserve_parens: false, ..ParseOptions::default() })
+            .parse();
+
+        if !ret.errors.is_empty() {
+            let source =
+                Arc::new(NamedSource::new(display_path(path, cwd), source_text.to_string()));
+            return Err(ret
+                .errors
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+                .collect());
+        }
+
+        let options = config.resolve(path, source_text);
+        Ok(Formatter::new(&allocator, options).build(&ret.program))
+    }
+
+    /// Formats the script sections of a Vue, Svelte or Astro file, i.e. the `<script>` blocks
+    /// and the Astro frontmatter, leaving the markup untouched. Each section keeps the
+    /// indentation of its first line.
+    fn format_partial_source(
+        path: &Path,
+        cwd: &Path,
+        source_text: &str,
+        sections: &[JavaScriptSource<'_>],
+        options: &FormatOptions,
+    ) -> Result<String, Vec<Error>> {
+        let mut code = String::with_capacity(source_text.len());
+        let mut errors = vec![];
+        let mut last_end = 0;
+        for section in sections {
+            let start = section.start as usize;
+            code.push_str(&source_text[last_end..start]);
+            last_end = start + section.source_text.len();
+
+            if section.source_text.trim().is_empty() {
+                code.push_str(section.source_text);
+                continue;
+            }
+
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, section.source_text, section.source_type)
+                .with_options(ParseOptions { preserve_parens: false, ..ParseOptions::default() })
+                .parse();
+            if !ret.errors.is_empty() {
+                errors.extend(DiagnosticService::wrap_diagnostics(
+                    cwd,
+                    path,
+                    source_text,
+                    section.start,
+                    ret.errors,
+                ));
+                continue;
+            }
+
+            let indentation =
+                section_indentation(section.source_text, options.indent_width.value());
+            let formatted = Formatter::new(&allocator, options.clone())
+                .build_embedded(&ret.program, indentation);
+            // The closing tag stays on its own line, indented like before.
+            let closing_indentation = section
+                .source_text
+                .rsplit_once('\n')
+                .map(|(_, last_line)| last_line)
+                .filter(|last_line| last_line.trim().is_empty())
+                .unwrap_or_default();
+            let line_ending = options.line_ending.as_str();
+            code.push_str(line_ending);
+            code.push_str(&formatted);
+            code.push_str(line_ending);
+            code.push_str(closing_indentation);
+        }
+        code.push_str(&source_text[last_end..]);
+
+        if errors.is_empty() { Ok(code) } else { Err(errors) }
+    }
+
+    fn finished_message(start_time: Instant, number_of_files: usize) -> String {
+        let time = start_time.elapsed();
+        let time = if time.as_secs() == 0 {
+            format!("{}ms", time.as_millis())
+        } else {
+            format!("{:.1}s", time.as_secs_f64())
+        };
+        let s = if number_of_files == 1 { "" } else { "s" };
+        format!(
+            "Finished in {time} on {number_of_files} file{s} using {} threads.\n",
+            rayon::current_num_threads()
+        )
+    }
+}
+
+/// The width of the indentation of the first non-blank line of a script section, counting tabs
+/// as `tab_width` columns.
+fn section_indentation(section_text: &str, tab_width: u8) -> u16 {
+    let tab_width = u16::from(tab_width);
+    let first_line = section_text.lines().find(|line| !line.trim().is_empty()).unwrap_or_default();
+    first_line.chars().take_while(|c| c.is_whitespace()).fold(0, |width, c| {
+        if c == '\t' {
+            width + tab_width - width.checked_rem(tab_width).unwrap_or(0)
+        } else {
+            width + 1
+        }
+    })
+}
+
+/// `path` relative to `cwd`, with `/` as separator.
+fn display_path(path: &Path, cwd: &Path) -> String {
+    let path = path.strip_prefix(cwd).unwrap_or(path);
+    path.to_string_lossy().cow_replace('\\', "/").into_owned()
+}
+
+fn print_and_flush_stdout(stdout: &mut dyn Write, message: &str) {
+    stdout.write_all(message.as_bytes()).or_else(check_for_writer_error).unwrap();
+    stdout.flush().unwrap();
+}
+
+fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
+    // Do not panic when the process is killed (e.g. piping into `less`).
+    if matches!(error.kind(), ErrorKind::Interrupted | ErrorKind::BrokenPipe) {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+fn print_config_error(
+    stdout: &mut dyn Write,
+    handler: &GraphicalReportHandler,
+    err: OxcDiagnostic,
+) {
+    print_and_flush_stdout(
+        stdout,
+        &format!("Failed to parse configuration file.\n{}\n", render_report(handler, &err.into())),
+    );
+}
+
+fn render_report(handler: &GraphicalReportHandler, error: &Error) -> String {
+    let mut err = String::new();
+    handler.render_report(&mut err, error.as_ref()).unwrap();
+    err
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::FormatRunner;
+    use crate::{
+        cli::{CliRunResult, format_command},
+        tester::Tester,
+    };
+
+    #[test]
+    fn check() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn check_file() {
+        let args = &["--check", "formatted.js", "src/unformatted.ts"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn print_to_stdout() {
+        let args = &["src"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn ignore_pattern() {
+        let args = &["--check", "--ignore-pattern", "**/*.jsx"];
+        Tester::new().with_cwd("fixtures/check".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn prettierrc() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/prettierrc".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn nested_config() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/nested_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn editorconfig() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/editorconfig".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn prettierignore() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/prettierignore".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn prettierignore_explicit_path() {
+        let args = &["--check", "ignored.js"];
+        Tester::new().with_cwd("fixtures/prettierignore".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn no_ignore() {
+        let args = &["--check", "--no-ignore"];
+        Tester::new().with_cwd("fixtures/prettierignore".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn framework_files() {
+        let args = &[];
+        Tester::new().with_cwd("fixtures/frameworks".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn syntax_error() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/syntax_error".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn invalid_config() {
+        let args = &["--check"];
+        Tester::new().with_cwd("fixtures/invalid_config".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn stdin_filepath() {
+        let args = &["--stdin-filepath", "src/foo.js"];
+        Tester::new()
+            .with_cwd("fixtures/prettierrc".into())
+            .with_stdin("const a = \"b\";\nconst c=1\n")
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn stdin_filepath_check() {
+        let args = &["--check", "--stdin-filepath", "foo.ts"];
+        Tester::new()
+            .with_cwd("fixtures/prettierrc".into())
+            .with_stdin("const a = \"b\"\n")
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn stdin_filepath_ignored() {
+        let args = &["--stdin-filepath", "ignored.js"];
+        Tester::new()
+            .with_cwd("fixtures/prettierignore".into())
+            .with_stdin("const a=1\n")
+            .test_and_snapshot(args);
+    }
+
+    #[test]
+    fn write() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = PathBuf::from(dir.path());
+        fs::write(cwd.join("unformatted.js"), "const a=1\n").unwrap();
+        fs::write(cwd.join("formatted.js"), "const b = 2;\n").unwrap();
+
+        let options = format_command().run_inner(&["--write"]).unwrap();
+        let mut output = Vec::new();
+        let result = FormatRunner::new(options)
+            .with_cwd(cwd.clone())
+            .run(&mut std::io::empty(), &mut output);
+
+        assert!(matches!(result, CliRunResult::FormatSucceeded));
+        assert!(String::from_utf8(output).unwrap().starts_with("unformatted.js\nFinished in "));
+        assert_eq!(fs::read_to_string(cwd.join("unformatted.js")).unwrap(), "const a = 1;\n");
+        assert_eq!(fs::read_to_string(cwd.join("formatted.js")).unwrap(), "const b = 2;\n");
+    }
+}
@@ -0,0 +1,54 @@
+mod command;
+mod config;
+mod format;
+mod result;
+mod tester;
+mod walk;
+
+pub mod cli {
+    pub use crate::{command::*, format::FormatRunner, result::CliRunResult};
+}
+
+#[cfg(all(feature = "allocator", not(miri), not(target_family = "wasm")))]
+#[global_allocator]
+static GLOBAL: mimalloc_safe::MiMalloc = mimalloc_safe::MiMalloc;
+
+use cli::{CliRunResult, FormatRunner};
+use std::{ffi::OsStr, io::BufWriter};
+
+pub fn format() -> CliRunResult {
+    init_miette();
+
+    let mut args = std::env::args_os().peekable();
+
+    let args = match args.peek() {
+        Some(s) if s == OsStr::new("node") => args.skip(2),
+        _ => args.skip(1),
+    };
+    let args = args.collect::<Vec<_>>();
+
+    let cmd = crate::cli::format_command();
+    let command = match cmd.run_inner(&*args) {
+        Ok(cmd) => cmd,
+        Err(e) => {
+            e.print_message(100);
+            return if e.exit_code() == 0 {
+                CliRunResult::FormatSucceeded
+            } else {
+                CliRunResult::InvalidOptionConfig
+            };
+        }
+    };
+
+    command.handle_threads();
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    FormatRunner::new(command).run(&mut std::io::stdin(), &mut stdout)
+}
+
+// Initialize the data which relies on `is_atty` system calls so they don't block subsequent threads.
+fn init_miette() {
+    miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build()))).unwrap();
+}
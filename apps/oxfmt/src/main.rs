@@ -0,0 +1,5 @@
+use oxfmt::{cli::CliRunResult, format};
+
+fn main() -> CliRunResult {
+    format()
+}
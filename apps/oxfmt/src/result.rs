@@ -0,0 +1,22 @@
+use std::process::{ExitCode, Termination};
+
+#[derive(Debug)]
+pub enum CliRunResult {
+    None,
+    InvalidOptionConfig,
+    FormatSucceeded,
+    FormatNoFilesFound,
+    FormatCheckFailed,
+    FormatFailed,
+}
+
+impl Termination for CliRunResult {
+    fn report(self) -> ExitCode {
+        match self {
+            Self::None | Self::FormatSucceeded | Self::FormatNoFilesFound => ExitCode::SUCCESS,
+            Self::InvalidOptionConfig | Self::FormatCheckFailed | Self::FormatFailed => {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+function broken( {
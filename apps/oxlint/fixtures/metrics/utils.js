@@ -0,0 +1,27 @@
+export function clamp(value, min = 0, max = 1) {
+  if (value < min) {
+    return min;
+  } else if (value > max) {
+    return max;
+  }
+  return value;
+}
+
+export const sumOfPrimes = (max) => {
+  let total = 0;
+  outer: for (let i = 1; i <= max; ++i) {
+    for (let j = 2; j < i; ++j) {
+      if (i % j === 0) {
+        continue outer;
+      }
+    }
+    total += i;
+  }
+  return total;
+};
+
+export class Cache {
+  get(key, ...fallbacks) {
+    return this.map?.get(key) ?? fallbacks.find((value) => value !== undefined);
+  }
+}
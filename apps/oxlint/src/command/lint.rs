@@ -39,6 +39,12 @@ pub struct LintCommand {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the cyclomatic and cognitive complexity, lines of code and number of
+    /// parameters of every function as JSON, instead of linting.
+    /// Files with syntax errors are skipped.
+    #[bpaf(switch, hide_usage)]
+    pub metrics: bool,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -275,6 +281,18 @@ pub struct EnablePlugins {
     /// Enable the vue plugin and detect vue usage problems
     #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
     pub vue_plugin: OverrideToggle,
+
+    /// Enable the testing-library plugin and detect Testing Library usage problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub testing_library_plugin: OverrideToggle,
+
+    /// Enable the playwright plugin and detect Playwright usage problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub playwright_plugin: OverrideToggle,
+
+    /// Enable the security plugin and detect security problems
+    #[bpaf(flag(OverrideToggle::Enable, OverrideToggle::NotSet), hide_usage)]
+    pub security_plugin: OverrideToggle,
 }
 
 /// Enables or disables a boolean option, or leaves it unset.
@@ -353,6 +371,11 @@ impl EnablePlugins {
         self.node_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::NODE, yes));
         self.regex_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::REGEX, yes));
         self.vue_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::VUE, yes));
+        self.testing_library_plugin
+            .inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::TESTING_LIBRARY, yes));
+        self.playwright_plugin
+            .inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::PLAYWRIGHT, yes));
+        self.security_plugin.inspect(|yes| plugins.builtin.set(BuiltinLintPlugins::SECURITY, yes));
 
         // Without this, jest plugins adapted to vitest will not be enabled.
         if self.vitest_plugin.is_enabled() && self.jest_plugin.is_not_set() {
@@ -566,6 +589,14 @@ mod lint_options {
         assert!(options.list_rules);
     }
 
+    #[test]
+    fn metrics() {
+        let options = get_lint_options("--metrics");
+        assert!(options.metrics);
+        let options = get_lint_options(".");
+        assert!(!options.metrics);
+    }
+
     #[test]
     fn disable_nested_config() {
         let options = get_lint_options("--disable-nested-config");
@@ -15,7 +15,10 @@ use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandle
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, ExternalLinter, ExternalPluginStore,
     InvalidFilterKind, LintFilter, LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
+    metrics::{FunctionMetrics, collect_metrics},
+    read_to_string,
 };
+use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
@@ -63,6 +66,7 @@ impl LintRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            metrics,
             ..
         } = self.options;
 
@@ -180,6 +184,10 @@ impl LintRunner {
         let paths = walker.paths();
         let number_of_files = paths.len();
 
+        if metrics {
+            return Self::print_metrics(stdout, &self.cwd, &paths);
+        }
+
         let mut external_plugin_store = ExternalPluginStore::default();
 
         let search_for_nested_configs = !disable_nested_config &&
@@ -519,6 +527,36 @@ impl LintRunner {
         }
     }
 
+    /// Prints the metrics of the functions in `paths` as a JSON array of files,
+    /// sorted by path. Files that can't be read or parsed are skipped.
+    fn print_metrics(stdout: &mut dyn Write, cwd: &Path, paths: &[Arc<OsStr>]) -> CliRunResult {
+        #[derive(Debug, serde::Serialize)]
+        struct FileMetrics {
+            path: String,
+            functions: Vec<FunctionMetrics>,
+        }
+
+        let mut files = paths
+            .par_iter()
+            .filter_map(|path| {
+                let path = Path::new(path);
+                let source_text = read_to_string(path).ok()?;
+                let functions = collect_metrics(path, &source_text)?.ok()?;
+                let path = path.strip_prefix(cwd).unwrap_or(path);
+                Some(FileMetrics {
+                    path: path.to_string_lossy().cow_replace('\\', "/").into_owned(),
+                    functions,
+                })
+            })
+            .collect::<Vec<_>>();
+        files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        let mut output = serde_json::to_string_pretty(&files).unwrap();
+        output.push('\n');
+        print_and_flush_stdout(stdout, &output);
+        CliRunResult::PrintMetricsResult
+    }
+
     fn adjust_ignore_patterns(
         base: &PathBuf,
         path: &PathBuf,
@@ -885,6 +923,12 @@ mod test {
         fs::write(file, content_original).unwrap();
     }
 
+    #[test]
+    fn test_metrics() {
+        let args = &["--metrics", "fixtures/metrics"];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
@@ -14,6 +14,7 @@ pub enum CliRunResult {
     LintNoWarningsAllowed,
     LintNoFilesFound,
     PrintConfigResult,
+    PrintMetricsResult,
     ConfigFileInitFailed,
     ConfigFileInitSucceeded,
 }
@@ -23,6 +24,7 @@ impl Termination for CliRunResult {
         match self {
             Self::None
             | Self::PrintConfigResult
+            | Self::PrintMetricsResult
             | Self::ConfigFileInitSucceeded
             | Self::LintSucceeded
             // ToDo: when oxc_linter (config) validates the configuration, we can use exit_code = 1 to fail
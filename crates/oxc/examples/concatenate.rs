@@ -0,0 +1,72 @@
+#![expect(clippy::print_stdout)]
+
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+
+use oxc::{
+    ConcatenateModule, ModuleConcatenator,
+    allocator::Allocator,
+    codegen::{Codegen, CodegenOptions},
+    parser::Parser,
+    semantic::SemanticBuilder,
+    span::SourceType,
+};
+use rustc_hash::FxHashMap;
+
+// Instruction:
+// 1. create a `test.js` importing other modules with relative paths, e.g. `import { a } from './a.js'`
+// 2. run `cargo run -p oxc --example concatenate --features="full" -- test.js --sourcemap`
+
+fn main() -> io::Result<()> {
+    let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
+    let sourcemap = env::args().any(|arg| arg == "--sourcemap");
+    let allocator = Allocator::default();
+
+    // Load the module graph, resolving relative specifiers only.
+    let mut paths = vec![PathBuf::from(&name)];
+    let mut modules = vec![];
+    while modules.len() < paths.len() {
+        let path = paths[modules.len()].clone();
+        let source_text = allocator.alloc_str(&std::fs::read_to_string(&path)?);
+        let source_type = SourceType::from_path(&path).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+        let mut resolved_requests = FxHashMap::default();
+        for request in ret.module_record.requested_modules.keys() {
+            if !request.starts_with('.') {
+                continue;
+            }
+            let resolved = path.parent().unwrap_or(Path::new("")).join(request.as_str());
+            let index = paths.iter().position(|p| *p == resolved).unwrap_or_else(|| {
+                paths.push(resolved);
+                paths.len() - 1
+            });
+            resolved_requests.insert(request.to_string(), index);
+        }
+        modules.push(ConcatenateModule {
+            source_path: path.to_string_lossy().to_string(),
+            program: ret.program,
+            scoping,
+            module_record: ret.module_record,
+            resolved_requests,
+        });
+    }
+
+    let ret = ModuleConcatenator::new(&allocator).build(modules, 0);
+    for error in &ret.errors {
+        println!("{error}");
+    }
+    let source_map_path = sourcemap.then(|| PathBuf::from(&name));
+    let printed = Codegen::new()
+        .with_options(CodegenOptions { source_map_path, ..CodegenOptions::default() })
+        .build(&ret.program);
+    println!("{}", printed.code);
+    if let Some(map) = printed.map {
+        let map = ret.remap_source_map(&map);
+        println!("{}", map.to_json_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,1037 @@
+//! Scope hoisting (module concatenation).
+//!
+//! Concatenates a graph of ES modules into a single ES module:
+//! imports between the modules are resolved to the declarations they refer to,
+//! colliding top-level bindings are renamed, internal `import` / `export` statements are removed
+//! and only the exports of the entry module are kept.
+//!
+//! Spans of the concatenated program point into the concatenation of all source texts, so that
+//! codegen can print comments and produce a source map,
+//! which [ConcatenateReturn::remap_source_map] splits back into one source per module.
+
+use std::{cell::Cell, sync::Arc};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, TakeIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_mangler::is_reserved_name;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolId};
+use oxc_sourcemap::{SourceMap, Token};
+use oxc_span::{SPAN, SourceType, Span};
+use oxc_syntax::{
+    identifier::{is_identifier_name, is_line_terminator},
+    keyword::is_reserved_keyword_or_global_object,
+    module_record::{
+        ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ModuleRecord,
+    },
+};
+
+/// A module to be concatenated.
+pub struct ConcatenateModule<'a> {
+    /// Path of the module, used as the source name in source maps.
+    pub source_path: String,
+    pub program: Program<'a>,
+    pub scoping: Scoping,
+    pub module_record: ModuleRecord<'a>,
+    /// Import specifier to the index of the resolved module in the module list.
+    ///
+    /// Unresolved specifiers are treated as external modules and are kept as imports.
+    pub resolved_requests: FxHashMap<String, usize>,
+}
+
+pub struct ConcatenateReturn<'a> {
+    /// The concatenated program.
+    ///
+    /// Semantic information is not updated, rebuild it if it is needed.
+    pub program: Program<'a>,
+    /// Concatenated modules in execution order.
+    pub sources: Vec<ConcatenatedSource<'a>>,
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+pub struct ConcatenatedSource<'a> {
+    pub source_path: String,
+    pub source_text: &'a str,
+    /// Line of the concatenated source text where this module starts.
+    pub line_offset: u32,
+}
+
+impl ConcatenateReturn<'_> {
+    /// Split a source map generated from [ConcatenateReturn::program] into one source per module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `u32::MAX` sources.
+    pub fn remap_source_map(&self, map: &SourceMap) -> SourceMap {
+        let tokens = map
+            .get_tokens()
+            .map(|token| {
+                let source_id = token.get_source_id().map(|_| {
+                    self.sources
+                        .partition_point(|source| source.line_offset <= token.get_src_line())
+                        - 1
+                });
+                let src_line = source_id.map_or(token.get_src_line(), |id| {
+                    token.get_src_line() - self.sources[id].line_offset
+                });
+                Token::new(
+                    token.get_dst_line(),
+                    token.get_dst_col(),
+                    src_line,
+                    token.get_src_col(),
+                    source_id.map(|id| u32::try_from(id).unwrap()),
+                    token.get_name_id(),
+                )
+            })
+            .collect();
+        SourceMap::new(
+            map.get_file().map(Arc::from),
+            map.get_names().map(Arc::from).collect(),
+            None,
+            self.sources.iter().map(|source| Arc::from(source.source_path.as_str())).collect(),
+            self.sources.iter().map(|source| Some(Arc::from(source.source_text))).collect(),
+            tokens,
+            None,
+        )
+    }
+}
+
+pub struct ModuleConcatenator<'a> {
+    allocator: &'a Allocator,
+}
+
+impl<'a> ModuleConcatenator<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { allocator }
+    }
+
+    /// Concatenate all modules reachable from `entry` into a single program.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the concatenated source text is larger than 4 GiB.
+    pub fn build(
+        self,
+        mut modules: Vec<ConcatenateModule<'a>>,
+        entry: usize,
+    ) -> ConcatenateReturn<'a> {
+        let ast = AstBuilder::new(self.allocator);
+        let infos = modules.iter().map(ModuleInfo::new).collect::<Vec<_>>();
+        let mut linker = Linker {
+            modules: &modules,
+            infos: &infos,
+            externals: vec![],
+            external_ids: FxHashMap::default(),
+            errors: vec![],
+        };
+        let order = linker.execution_order(entry);
+
+        // Resolve every import binding to the binding it refers to.
+        let mut import_bindings = vec![FxHashMap::<SymbolId, Binding>::default(); modules.len()];
+        for &module in &order {
+            for (&symbol_id, import) in &infos[module].imports {
+                if let Some(binding) = linker.resolve_import(module, import) {
+                    import_bindings[module].insert(symbol_id, binding);
+                }
+            }
+        }
+        let entry_exports = linker
+            .export_names(entry, &mut FxHashSet::default())
+            .into_iter()
+            .filter_map(|name| {
+                let binding = linker.resolve_export(entry, &name, &mut FxHashSet::default())?;
+                Some((name, binding))
+            })
+            .collect::<Vec<_>>();
+        let mut namespaces = import_bindings
+            .iter()
+            .flat_map(FxHashMap::values)
+            .chain(entry_exports.iter().map(|(_, binding)| binding))
+            .filter_map(|binding| match binding {
+                Binding::Namespace(module) => Some(*module),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        namespaces.sort_unstable();
+        namespaces.dedup();
+        let namespace_exports = namespaces
+            .iter()
+            .map(|&module| {
+                let exports = linker
+                    .export_names(module, &mut FxHashSet::default())
+                    .into_iter()
+                    .filter(|name| {
+                        name != "default" || infos[module].exports.contains_key("default")
+                    })
+                    .filter_map(|name| {
+                        let binding =
+                            linker.resolve_export(module, &name, &mut FxHashSet::default())?;
+                        Some((name, binding))
+                    })
+                    .collect::<Vec<_>>();
+                (module, exports)
+            })
+            .collect::<FxHashMap<_, _>>();
+        let Linker { externals, errors, .. } = linker;
+
+        // Assign a unique name to every top-level binding of the output.
+        let mut names = Names::default();
+        if !namespaces.is_empty() {
+            // Referenced by the namespace objects.
+            names.taken.insert("Object".to_string());
+        }
+        for &module in &order {
+            let scoping = &modules[module].scoping;
+            let mut collector = ReferenceSiteCollector {
+                module,
+                scoping,
+                import_bindings: &import_bindings[module],
+                scope_stack: vec![],
+                sites: &mut names.sites,
+            };
+            collector.visit_program(&modules[module].program);
+        }
+        for (index, external) in externals.iter().enumerate() {
+            names.assign(Binding::External(index), &external.name_hint, &modules, &order);
+        }
+        for &module in &order {
+            let scoping = &modules[module].scoping;
+            for symbol_id in scoping.iter_bindings_in(scoping.root_scope_id()) {
+                if !infos[module].imports.contains_key(&symbol_id) {
+                    let name = scoping.symbol_name(symbol_id).to_string();
+                    names.assign(Binding::Symbol(module, symbol_id), &name, &modules, &order);
+                }
+            }
+            if infos[module].has_default_expression {
+                names.assign(
+                    Binding::Default(module),
+                    &format!("{}_default", module_name(&modules[module].source_path)),
+                    &modules,
+                    &order,
+                );
+            }
+            if namespace_exports.contains_key(&module) {
+                names.assign(
+                    Binding::Namespace(module),
+                    &module_name(&modules[module].source_path),
+                    &modules,
+                    &order,
+                );
+            }
+        }
+
+        // Concatenate the modules.
+        let mut source_text = String::new();
+        let mut sources = vec![];
+        let mut line_offset = 0;
+        let mut offsets = vec![0; modules.len()];
+        for &module in &order {
+            let text = modules[module].program.source_text;
+            offsets[module] = u32::try_from(source_text.len()).unwrap();
+            sources.push(ConcatenatedSource {
+                source_path: modules[module].source_path.clone(),
+                source_text: text,
+                line_offset,
+            });
+            source_text.push_str(text);
+            source_text.push('\n');
+            line_offset += count_lines(text);
+        }
+        let source_text = self.allocator.alloc_str(&source_text);
+
+        let mut body = ast.vec();
+        let mut comments = ast.vec();
+        let mut external_requests = vec![];
+        for &module in &order {
+            for request in &infos[module].requests {
+                if !modules[module].resolved_requests.contains_key(request.as_str())
+                    && !external_requests.contains(request)
+                {
+                    external_requests.push(*request);
+                }
+            }
+        }
+        body.extend(self.external_imports(&external_requests, &externals, &names, &infos[entry]));
+        for &module in &order {
+            let data = &mut modules[module];
+            if let Some(exports) = namespace_exports.get(&module) {
+                body.push(namespace_object(
+                    names.get(Binding::Namespace(module)),
+                    exports,
+                    &names,
+                    ast,
+                ));
+            }
+            let default_name =
+                names.assigned.get(&Binding::Default(module)).map(|name| ast.atom(name));
+            let mut statements = ast.vec();
+            for stmt in data.program.body.take_in(ast) {
+                if let Some(stmt) = strip_module_syntax(stmt, default_name, ast) {
+                    statements.push(stmt);
+                }
+            }
+            let mut renamer = Renamer {
+                ast,
+                module,
+                scoping: &data.scoping,
+                import_bindings: &import_bindings[module],
+                names: &names,
+                offset: offsets[module],
+            };
+            for stmt in &mut statements {
+                renamer.visit_statement(stmt);
+            }
+            body.extend(statements);
+            comments.extend(data.program.comments.iter().map(|comment| {
+                let mut comment = *comment;
+                comment.span = shift_span(comment.span, offsets[module]);
+                comment.attached_to += offsets[module];
+                comment
+            }));
+        }
+        if !entry_exports.is_empty() {
+            // `export { local as name }`
+            let specifiers = ast.vec_from_iter(entry_exports.iter().map(|(name, binding)| {
+                ast.export_specifier(
+                    SPAN,
+                    ast.module_export_name_identifier_reference(
+                        SPAN,
+                        ast.atom(names.get(*binding)),
+                    ),
+                    module_export_name(name, ast),
+                    ImportOrExportKind::Value,
+                )
+            }));
+            body.push(Statement::from(ast.module_declaration_export_named_declaration(
+                SPAN,
+                None,
+                specifiers,
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            )));
+        }
+
+        let entry_program = &mut modules[entry].program;
+        let hashbang = entry_program.hashbang.take().map(|mut hashbang| {
+            hashbang.span = shift_span(hashbang.span, offsets[entry]);
+            hashbang
+        });
+        let program = ast.program(
+            Span::new(0, u32::try_from(source_text.len()).unwrap()),
+            SourceType::mjs(),
+            source_text,
+            comments,
+            hashbang,
+            ast.vec(),
+            body,
+        );
+        ConcatenateReturn { program, sources, errors }
+    }
+
+    /// `import { a } from 'external'` statements of the output, and `export * from 'external'`
+    /// statements of the entry module.
+    fn external_imports(
+        &self,
+        external_requests: &[Atom<'a>],
+        externals: &[External<'a>],
+        names: &Names,
+        entry: &ModuleInfo<'a>,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let ast = AstBuilder::new(self.allocator);
+        let import = |specifiers, request: Atom<'a>| {
+            Statement::from(ast.module_declaration_import_declaration(
+                SPAN,
+                specifiers,
+                ast.string_literal(SPAN, request, None),
+                None,
+                NONE,
+                ImportOrExportKind::Value,
+            ))
+        };
+        let mut stmts = ast.vec();
+        for &request in external_requests {
+            let imports = externals
+                .iter()
+                .enumerate()
+                .filter(|(_, external)| external.request == request)
+                .collect::<Vec<_>>();
+            let mut specifiers = ast.vec();
+            for (index, external) in &imports {
+                let local =
+                    ast.binding_identifier(SPAN, ast.atom(names.get(Binding::External(*index))));
+                if let Some(name) = &external.import_name {
+                    // `import { name as local } from 'external'`
+                    specifiers.push(ast.import_declaration_specifier_import_specifier(
+                        SPAN,
+                        module_export_name(name, ast),
+                        local,
+                        ImportOrExportKind::Value,
+                    ));
+                } else {
+                    // `import * as local from 'external'`
+                    let specifier =
+                        ast.import_declaration_specifier_import_namespace_specifier(SPAN, local);
+                    stmts.push(import(Some(ast.vec1(specifier)), request));
+                }
+            }
+            if !specifiers.is_empty() {
+                stmts.push(import(Some(specifiers), request));
+            } else if imports.is_empty() {
+                // `import 'external'`
+                stmts.push(import(None, request));
+            }
+        }
+        for &request in &entry.external_star_exports {
+            stmts.push(Statement::from(ast.module_declaration_export_all_declaration(
+                SPAN,
+                None,
+                ast.string_literal(SPAN, request, None),
+                NONE,
+                ImportOrExportKind::Value,
+            )));
+        }
+        stmts
+    }
+}
+
+/// A top-level binding of the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Binding {
+    /// A top-level declaration of a module.
+    Symbol(usize, SymbolId),
+    /// `export default expr` of a module.
+    Default(usize),
+    /// The namespace object of a module, for `import * as ns`.
+    Namespace(usize),
+    /// An import of an external module.
+    External(usize),
+}
+
+#[derive(Debug)]
+enum ExportTarget<'a> {
+    Symbol(SymbolId),
+    DefaultExpression,
+    ReExport { request: Atom<'a>, import_name: Option<Atom<'a>> },
+}
+
+#[derive(Debug, Clone)]
+struct Import<'a> {
+    request: Atom<'a>,
+    /// `None` for `import * as ns`.
+    imported: Option<Atom<'a>>,
+    local_name: Atom<'a>,
+}
+
+#[derive(Debug)]
+struct External<'a> {
+    request: Atom<'a>,
+    import_name: Option<Atom<'a>>,
+    name_hint: String,
+}
+
+struct ModuleInfo<'a> {
+    imports: FxHashMap<SymbolId, Import<'a>>,
+    exports: FxHashMap<Atom<'a>, ExportTarget<'a>>,
+    star_exports: Vec<Atom<'a>>,
+    external_star_exports: Vec<Atom<'a>>,
+    /// Requests of import and re-export statements in source order.
+    requests: Vec<Atom<'a>>,
+    has_default_expression: bool,
+}
+
+impl<'a> ModuleInfo<'a> {
+    fn new(module: &ConcatenateModule<'a>) -> Self {
+        let record = &module.module_record;
+        let scoping = &module.scoping;
+        let mut imports = FxHashMap::default();
+        for entry in record.import_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(symbol_id) = scoping.get_root_binding(&entry.local_name.name) else {
+                continue;
+            };
+            let import_name = match &entry.import_name {
+                ImportImportName::Name(name) => Some(name.name),
+                ImportImportName::Default(_) => Some(Atom::from("default")),
+                ImportImportName::NamespaceObject => None,
+            };
+            imports.insert(
+                symbol_id,
+                Import {
+                    request: entry.module_request.name,
+                    imported: import_name,
+                    local_name: entry.local_name.name,
+                },
+            );
+        }
+
+        let mut exports = FxHashMap::default();
+        let mut has_default_expression = false;
+        for entry in record.local_export_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(export_name) = export_name(&entry.export_name) else { continue };
+            let target = match &entry.local_name {
+                ExportLocalName::Name(name) => match scoping.get_root_binding(&name.name) {
+                    Some(symbol_id) => ExportTarget::Symbol(symbol_id),
+                    None => continue,
+                },
+                ExportLocalName::Default(_) | ExportLocalName::Null => {
+                    has_default_expression = true;
+                    ExportTarget::DefaultExpression
+                }
+            };
+            exports.insert(export_name, target);
+        }
+        for entry in record.indirect_export_entries.iter().filter(|entry| !entry.is_type) {
+            let Some(export_name) = export_name(&entry.export_name) else { continue };
+            let Some(request) = &entry.module_request else { continue };
+            let import_name = match &entry.import_name {
+                ExportImportName::Name(name) => Some(name.name),
+                _ => None,
+            };
+            exports
+                .insert(export_name, ExportTarget::ReExport { request: request.name, import_name });
+        }
+        let (star_exports, external_star_exports) = record
+            .star_export_entries
+            .iter()
+            .filter_map(|entry| entry.module_request.as_ref().map(|request| request.name))
+            .partition(|request| module.resolved_requests.contains_key(request.as_str()));
+
+        let mut requests = record
+            .requested_modules
+            .iter()
+            .filter(|(_, requested)| requested.iter().any(|r| !r.is_type))
+            .map(|(request, requested)| {
+                (requested.iter().map(|r| r.statement_span.start).min().unwrap_or(0), *request)
+            })
+            .collect::<Vec<_>>();
+        requests.sort_unstable_by_key(|(start, _)| *start);
+        let requests = requests.into_iter().map(|(_, request)| request).collect();
+
+        Self {
+            imports,
+            exports,
+            star_exports,
+            external_star_exports,
+            requests,
+            has_default_expression,
+        }
+    }
+}
+
+fn export_name<'a>(export_name: &ExportExportName<'a>) -> Option<Atom<'a>> {
+    match export_name {
+        ExportExportName::Name(name) => Some(name.name),
+        ExportExportName::Default(_) => Some(Atom::from("default")),
+        ExportExportName::Null => None,
+    }
+}
+
+struct Linker<'m, 'a> {
+    modules: &'m [ConcatenateModule<'a>],
+    infos: &'m [ModuleInfo<'a>],
+    externals: Vec<External<'a>>,
+    external_ids: FxHashMap<(Atom<'a>, Option<Atom<'a>>), usize>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Linker<'_, 'a> {
+    fn resolve(&self, module: usize, request: &str) -> Option<usize> {
+        self.modules[module].resolved_requests.get(request).copied()
+    }
+
+    /// Modules reachable from `entry`, dependencies first.
+    fn execution_order(&self, entry: usize) -> Vec<usize> {
+        fn visit(linker: &Linker, module: usize, visited: &mut [bool], order: &mut Vec<usize>) {
+            if std::mem::replace(&mut visited[module], true) {
+                return;
+            }
+            for request in &linker.infos[module].requests {
+                if let Some(target) = linker.resolve(module, request) {
+                    visit(linker, target, visited, order);
+                }
+            }
+            order.push(module);
+        }
+        let mut visited = vec![false; self.modules.len()];
+        let mut order = vec![];
+        visit(self, entry, &mut visited, &mut order);
+        order
+    }
+
+    fn external(
+        &mut self,
+        request: Atom<'a>,
+        import_name: Option<Atom<'a>>,
+        name_hint: &str,
+    ) -> Binding {
+        let id = *self.external_ids.entry((request, import_name)).or_insert_with(|| {
+            self.externals.push(External {
+                request,
+                import_name,
+                name_hint: name_hint.to_string(),
+            });
+            self.externals.len() - 1
+        });
+        Binding::External(id)
+    }
+
+    fn resolve_import(&mut self, module: usize, import: &Import<'a>) -> Option<Binding> {
+        let Some(target) = self.resolve(module, &import.request) else {
+            return Some(self.external(import.request, import.imported, &import.local_name));
+        };
+        let Some(import_name) = import.imported else {
+            return Some(Binding::Namespace(target));
+        };
+        let binding = self.resolve_export(target, &import_name, &mut FxHashSet::default());
+        if binding.is_none() {
+            self.errors.push(OxcDiagnostic::error(format!(
+                "\"{import_name}\" is not exported by \"{}\", imported by \"{}\"",
+                self.modules[target].source_path, self.modules[module].source_path
+            )));
+        }
+        binding
+    }
+
+    /// Resolve an export of `module` to the binding declaring it, following re-exports.
+    fn resolve_export(
+        &mut self,
+        module: usize,
+        name: &str,
+        visited: &mut FxHashSet<(usize, String)>,
+    ) -> Option<Binding> {
+        if !visited.insert((module, name.to_string())) {
+            return None;
+        }
+        let info = &self.infos[module];
+        match info.exports.get(name) {
+            Some(ExportTarget::Symbol(symbol_id)) => match info.imports.get(symbol_id) {
+                Some(import) => self.resolve_import(module, &import.clone()),
+                None => Some(Binding::Symbol(module, *symbol_id)),
+            },
+            Some(ExportTarget::DefaultExpression) => Some(Binding::Default(module)),
+            Some(ExportTarget::ReExport { request, import_name }) => {
+                let (request, import_name) = (*request, *import_name);
+                match (self.resolve(module, &request), import_name) {
+                    (Some(target), Some(import_name)) => {
+                        self.resolve_export(target, &import_name, visited)
+                    }
+                    (Some(target), None) => Some(Binding::Namespace(target)),
+                    (None, _) => Some(self.external(request, import_name, name)),
+                }
+            }
+            None if name == "default" => None,
+            None => {
+                let star_exports = info.star_exports.clone();
+                star_exports.iter().find_map(|request| {
+                    let target = self.resolve(module, request)?;
+                    self.resolve_export(target, name, visited)
+                })
+            }
+        }
+    }
+
+    /// All export names of `module`, sorted.
+    fn export_names(&self, module: usize, visited: &mut FxHashSet<usize>) -> Vec<String> {
+        if !visited.insert(module) {
+            return vec![];
+        }
+        let info = &self.infos[module];
+        let mut names = info.exports.keys().map(ToString::to_string).collect::<Vec<_>>();
+        for request in &info.star_exports {
+            if let Some(target) = self.resolve(module, request) {
+                names.extend(
+                    self.export_names(target, visited).into_iter().filter(|name| name != "default"),
+                );
+            }
+        }
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+#[derive(Default)]
+struct Names {
+    /// Names of bindings assigned so far, and of globals referenced by generated code.
+    taken: FxHashSet<String>,
+    assigned: FxHashMap<Binding, String>,
+    /// Non-root scopes containing references to each binding.
+    sites: FxHashMap<Binding, Vec<(usize, ScopeId)>>,
+}
+
+impl Names {
+    fn get(&self, binding: Binding) -> &str {
+        &self.assigned[&binding]
+    }
+
+    /// Assign the first of `name`, `name$1`, `name$2`, ... which is not taken, does not shadow
+    /// a global referenced by any of the concatenated modules (`order`), and is not shadowed at
+    /// any reference to `binding`.
+    fn assign(
+        &mut self,
+        binding: Binding,
+        name: &str,
+        modules: &[ConcatenateModule],
+        order: &[usize],
+    ) {
+        let is_reserved = |candidate: &str| {
+            is_reserved_keyword_or_global_object(candidate)
+                || order.iter().any(|&module| is_reserved_name(candidate, &modules[module].scoping))
+        };
+        let sites = self.sites.get(&binding).map_or(&[][..], Vec::as_slice);
+        let is_shadowed = |candidate: &str| {
+            sites.iter().any(|(module, scope_id)| {
+                let scoping = &modules[*module].scoping;
+                scoping
+                    .scope_ancestors(*scope_id)
+                    .take_while(|scope_id| *scope_id != scoping.root_scope_id())
+                    .any(|scope_id| scoping.get_binding(scope_id, candidate).is_some())
+            })
+        };
+        let mut candidate = name.to_string();
+        let mut count = 0;
+        while self.taken.contains(&candidate) || is_reserved(&candidate) || is_shadowed(&candidate)
+        {
+            count += 1;
+            candidate = format!("{name}${count}");
+        }
+        self.taken.insert(candidate.clone());
+        self.assigned.insert(binding, candidate);
+    }
+}
+
+/// An identifier-safe name for a module derived from its path.
+fn module_name(source_path: &str) -> String {
+    let file_name = source_path.rsplit(['/', '\\']).next().unwrap_or(source_path);
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    let mut name = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '$' { c } else { '_' })
+        .collect::<String>();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$') {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// `name` as an export name, which is a string literal if it is not a valid identifier.
+fn module_export_name<'a>(name: &str, ast: AstBuilder<'a>) -> ModuleExportName<'a> {
+    let name = ast.atom(name);
+    if is_identifier_name(&name) {
+        ast.module_export_name_identifier_name(SPAN, name)
+    } else {
+        ast.module_export_name_string_literal(SPAN, name, None)
+    }
+}
+
+/// `var ns = Object.freeze({ __proto__: null, get a() { return a; } });`
+fn namespace_object<'a>(
+    name: &str,
+    exports: &[(String, Binding)],
+    names: &Names,
+    ast: AstBuilder<'a>,
+) -> Statement<'a> {
+    let mut properties = ast.vec_with_capacity(exports.len() + 1);
+    properties.push(ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        ast.property_key_static_identifier(SPAN, "__proto__"),
+        ast.expression_null_literal(SPAN),
+        false,
+        false,
+        false,
+    ));
+    for (export_name, binding) in exports {
+        let export_name = ast.atom(export_name);
+        let key = if is_identifier_name(&export_name) {
+            ast.property_key_static_identifier(SPAN, export_name)
+        } else {
+            PropertyKey::StringLiteral(ast.alloc_string_literal(SPAN, export_name, None))
+        };
+        let value = ast.expression_identifier(SPAN, ast.atom(names.get(*binding)));
+        let getter = ast.expression_function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ast.vec(), NONE),
+            NONE,
+            Some(ast.function_body(
+                SPAN,
+                ast.vec(),
+                ast.vec1(ast.statement_return(SPAN, Some(value))),
+            )),
+        );
+        properties.push(ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Get,
+            key,
+            getter,
+            false,
+            false,
+            false,
+        ));
+    }
+    let freeze = Expression::from(ast.member_expression_static(
+        SPAN,
+        ast.expression_identifier(SPAN, "Object"),
+        ast.identifier_name(SPAN, "freeze"),
+        false,
+    ));
+    let init = ast.expression_call(
+        SPAN,
+        freeze,
+        NONE,
+        ast.vec1(Argument::from(ast.expression_object(SPAN, properties))),
+        false,
+    );
+    let declarator = ast.variable_declarator(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ast.binding_pattern(
+            ast.binding_pattern_kind_binding_identifier(SPAN, ast.atom(name)),
+            NONE,
+            false,
+        ),
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ast.alloc_variable_declaration(
+        SPAN,
+        VariableDeclarationKind::Var,
+        ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Number of lines of `text` followed by a line break, as counted by source maps.
+fn count_lines(text: &str) -> u32 {
+    let mut lines = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' && chars.peek() == Some(&'\n') {
+            continue;
+        }
+        if is_line_terminator(c) {
+            lines += 1;
+        }
+    }
+    lines
+}
+
+fn shift_span(span: Span, offset: u32) -> Span {
+    Span::new(span.start + offset, span.end + offset)
+}
+
+/// Remove `import` and `export` syntax from a top-level statement.
+fn strip_module_syntax<'a>(
+    stmt: Statement<'a>,
+    default_name: Option<Atom<'a>>,
+    ast: AstBuilder<'a>,
+) -> Option<Statement<'a>> {
+    match stmt {
+        Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => None,
+        Statement::ExportNamedDeclaration(decl) => decl.unbox().declaration.map(Statement::from),
+        Statement::ExportDefaultDeclaration(decl) => {
+            let ExportDefaultDeclaration { span, declaration, .. } = decl.unbox();
+            match declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                    if func.id.is_none() {
+                        func.id = default_name.map(|name| ast.binding_identifier(SPAN, name));
+                    }
+                    Some(Statement::FunctionDeclaration(func))
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                    if class.id.is_none() {
+                        class.id = default_name.map(|name| ast.binding_identifier(SPAN, name));
+                    }
+                    Some(Statement::ClassDeclaration(class))
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => None,
+                kind => {
+                    let declarator = ast.variable_declarator(
+                        span,
+                        VariableDeclarationKind::Var,
+                        ast.binding_pattern(
+                            ast.binding_pattern_kind_binding_identifier(SPAN, default_name?),
+                            NONE,
+                            false,
+                        ),
+                        Some(kind.into_expression()),
+                        false,
+                    );
+                    Some(Statement::VariableDeclaration(ast.alloc_variable_declaration(
+                        span,
+                        VariableDeclarationKind::Var,
+                        ast.vec1(declarator),
+                        false,
+                    )))
+                }
+            }
+        }
+        stmt => Some(stmt),
+    }
+}
+
+/// Collect the scopes from which top-level bindings are referenced.
+struct ReferenceSiteCollector<'s> {
+    module: usize,
+    scoping: &'s Scoping,
+    import_bindings: &'s FxHashMap<SymbolId, Binding>,
+    scope_stack: Vec<ScopeId>,
+    sites: &'s mut FxHashMap<Binding, Vec<(usize, ScopeId)>>,
+}
+
+impl<'a> Visit<'a> for ReferenceSiteCollector<'_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if let Some(scope_id) = scope_id.get() {
+            self.scope_stack.push(scope_id);
+        }
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if self.scoping.symbol_scope_id(symbol_id) != self.scoping.root_scope_id() {
+            return;
+        }
+        let binding = self
+            .import_bindings
+            .get(&symbol_id)
+            .copied()
+            .unwrap_or(Binding::Symbol(self.module, symbol_id));
+        if let Some(&scope_id) = self.scope_stack.last() {
+            if scope_id != self.scoping.root_scope_id() {
+                self.sites.entry(binding).or_default().push((self.module, scope_id));
+            }
+        }
+    }
+
+    fn visit_import_declaration(&mut self, _decl: &ImportDeclaration<'a>) {}
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if decl.declaration.is_some() {
+            walk::walk_export_named_declaration(self, decl);
+        }
+    }
+}
+
+/// Rename top-level bindings of a module to their final names, and shift spans to point into
+/// the concatenated source text.
+struct Renamer<'s, 'a> {
+    ast: AstBuilder<'a>,
+    module: usize,
+    scoping: &'s Scoping,
+    import_bindings: &'s FxHashMap<SymbolId, Binding>,
+    names: &'s Names,
+    offset: u32,
+}
+
+impl<'a> Renamer<'_, 'a> {
+    fn reference_name(&self, ident: &IdentifierReference<'a>) -> Option<Atom<'a>> {
+        let symbol_id = self.scoping.get_reference(ident.reference_id.get()?).symbol_id()?;
+        if self.scoping.symbol_scope_id(symbol_id) != self.scoping.root_scope_id() {
+            return None;
+        }
+        let binding = self
+            .import_bindings
+            .get(&symbol_id)
+            .copied()
+            .unwrap_or(Binding::Symbol(self.module, symbol_id));
+        self.names.assigned.get(&binding).map(|name| self.ast.atom(name))
+    }
+}
+
+impl<'a> VisitMut<'a> for Renamer<'_, 'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = shift_span(*span, self.offset);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(symbol_id) = ident.symbol_id.get() {
+            if self.scoping.symbol_scope_id(symbol_id) == self.scoping.root_scope_id() {
+                if let Some(name) =
+                    self.names.assigned.get(&Binding::Symbol(self.module, symbol_id))
+                {
+                    ident.name = self.ast.atom(name);
+                }
+            }
+        }
+        walk_mut::walk_binding_identifier(self, ident);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if let Some(name) = self.reference_name(ident) {
+            ident.name = name;
+        }
+        walk_mut::walk_identifier_reference(self, ident);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        if prop.shorthand {
+            if let (PropertyKey::StaticIdentifier(key), Expression::Identifier(value)) =
+                (&prop.key, &prop.value)
+            {
+                prop.shorthand = key.name == value.name;
+            }
+        }
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        walk_mut::walk_binding_property(self, prop);
+        if prop.shorthand {
+            let value = match &prop.value.kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident.name),
+                BindingPatternKind::AssignmentPattern(pattern) => {
+                    pattern.left.get_binding_identifier().map(|ident| ident.name)
+                }
+                _ => None,
+            };
+            if let (PropertyKey::StaticIdentifier(key), Some(value)) = (&prop.key, value) {
+                prop.shorthand = key.name == value;
+            }
+        }
+    }
+
+    fn visit_assignment_target_property(&mut self, prop: &mut AssignmentTargetProperty<'a>) {
+        // `({ a } = obj)` -> `({ a: a$1 } = obj)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident_prop) = prop {
+            if self
+                .reference_name(&ident_prop.binding)
+                .is_some_and(|name| name != ident_prop.binding.name)
+            {
+                let ident_prop = ident_prop.take_in(self.ast);
+                let AssignmentTargetPropertyIdentifier { span, binding, init } = ident_prop;
+                let key = self.ast.property_key_static_identifier(binding.span, binding.name);
+                let target = AssignmentTarget::from(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding)),
+                );
+                let binding = match init {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *prop = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding, false,
+                );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, prop);
+    }
+}
@@ -4,6 +4,14 @@ mod compiler;
 #[cfg(feature = "full")]
 pub use compiler::{Compiler, CompilerInterface};
 
+#[cfg(feature = "concatenate")]
+mod concatenator;
+
+#[cfg(feature = "concatenate")]
+pub use concatenator::{
+    ConcatenateModule, ConcatenateReturn, ConcatenatedSource, ModuleConcatenator,
+};
+
 pub mod allocator {
     //! Memory arena allocator used by all other submodules.
     //!
@@ -0,0 +1,130 @@
+use oxc::{
+    ConcatenateModule, ModuleConcatenator, allocator::Allocator, codegen::Codegen, parser::Parser,
+    semantic::SemanticBuilder, span::SourceType,
+};
+use rustc_hash::FxHashMap;
+
+/// Concatenate `files` starting from the first one, resolving `./name` to the file `name`.
+fn concatenate(files: &[(&str, &str)]) -> String {
+    let allocator = Allocator::default();
+    let modules = files
+        .iter()
+        .map(|(path, source_text)| {
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+            assert!(ret.errors.is_empty(), "{path}: {:?}", ret.errors);
+            let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+            let resolved_requests = ret
+                .module_record
+                .requested_modules
+                .keys()
+                .filter_map(|request| {
+                    let path = request.strip_prefix("./")?;
+                    let index = files.iter().position(|(p, _)| *p == path)?;
+                    Some((request.to_string(), index))
+                })
+                .collect::<FxHashMap<_, _>>();
+            ConcatenateModule {
+                source_path: (*path).to_string(),
+                program: ret.program,
+                scoping,
+                module_record: ret.module_record,
+                resolved_requests,
+            }
+        })
+        .collect();
+    let ret = ModuleConcatenator::new(&allocator).build(modules, 0);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new().build(&ret.program).code
+}
+
+#[test]
+fn name_collisions() {
+    let code = concatenate(&[
+        ("main.js", "import { a } from './a.js';\nconst b = 2;\nconsole.log(a, b);"),
+        ("a.js", "const b = 1;\nexport const a = b;"),
+    ]);
+    assert_eq!(code, "const b = 1;\nconst a = b;\nconst b$1 = 2;\nconsole.log(a, b$1);\n");
+
+    // A renamed binding must not be shadowed at its references.
+    let code = concatenate(&[
+        (
+            "main.js",
+            "import { a } from './a.js';\nconst b = 2;\nfunction f(b$1) { return a + b + b$1; }",
+        ),
+        ("a.js", "const b = 1;\nexport const a = () => b;"),
+    ]);
+    assert_eq!(
+        code,
+        "const b = 1;\nconst a = () => b;\nconst b$2 = 2;\nfunction f(b$1) {\n\treturn a + b$2 + b$1;\n}\n"
+    );
+}
+
+#[test]
+fn reserved_names() {
+    // Globals referenced by any module, `exports` and `Object` (used by namespace objects)
+    // are not shadowed by top-level bindings.
+    let code = concatenate(&[
+        (
+            "main.js",
+            "import * as ns from './a.js';\nimport './b.js';\nconst Object = 1;\nconst exports = 2;\nconsole.log(ns, Object, exports);",
+        ),
+        ("a.js", "export const Symbol = 1;\nexport const x = globalThis.Symbol.iterator;"),
+        ("b.js", "console.log(Symbol.iterator);"),
+    ]);
+    assert_eq!(
+        code,
+        "var a = Object.freeze({\n\t__proto__: null,\n\tget Symbol() {\n\t\treturn Symbol$1;\n\t},\n\tget x() {\n\t\treturn x;\n\t}\n});\nconst Symbol$1 = 1;\nconst x = globalThis.Symbol.iterator;\nconsole.log(Symbol.iterator);\nconst Object$1 = 1;\nconst exports$1 = 2;\nconsole.log(a, Object$1, exports$1);\n"
+    );
+}
+
+#[test]
+fn namespace_imports() {
+    let code = concatenate(&[
+        ("main.js", "import * as ns from './a.js';\nconsole.log(ns);"),
+        ("a.js", "export const a = 1;\nexport { a as 'x-y' };\nexport default 2;"),
+    ]);
+    assert_eq!(
+        code,
+        "var a$1 = Object.freeze({\n\t__proto__: null,\n\tget a() {\n\t\treturn a;\n\t},\n\tget default() {\n\t\treturn a_default;\n\t},\n\tget \"x-y\"() {\n\t\treturn a;\n\t}\n});\nconst a = 1;\nvar a_default = 2;\nconsole.log(a$1);\n"
+    );
+}
+
+#[test]
+fn re_exports() {
+    let code = concatenate(&[
+        ("main.js", "export * from './a.js';\nexport { b as c, b as 'd\\u2028' } from './b.js';"),
+        ("a.js", "export const a = 1;\nexport default 2;"),
+        ("b.js", "export function b() {}"),
+    ]);
+    assert_eq!(
+        code,
+        "const a = 1;\nvar a_default = 2;\nfunction b() {}\nexport { a, b as c, b as \"d\\u2028\" };\n"
+    );
+}
+
+#[test]
+fn external_imports() {
+    let code = concatenate(&[
+        (
+            "main.js",
+            "import d, { a as b, 'x-y' as c } from 'ext';\nimport * as ns from \"it's\";\nimport 'side';\nimport { e } from './a.js';\nexport * from 'star';\nconst a = 1;\nconsole.log(a, b, c, d, e, ns);",
+        ),
+        ("a.js", "export { f as e } from 'ext';"),
+    ]);
+    assert_eq!(
+        code,
+        "import { default as d, \"x-y\" as c, f as e, a as b } from \"ext\";\nimport * as ns from \"it's\";\nimport \"side\";\nimport \"star\";\nexport * from \"star\";\nconst a = 1;\nconsole.log(a, b, c, d, e, ns);\n"
+    );
+}
+
+#[test]
+fn cycles() {
+    let code = concatenate(&[
+        ("main.js", "import { a } from './a.js';\nexport function b() { return a(); }"),
+        ("a.js", "import { b } from './main.js';\nexport function a() { return b; }"),
+    ]);
+    assert_eq!(
+        code,
+        "function a() {\n\treturn b;\n}\nfunction b() {\n\treturn a();\n}\nexport { b };\n"
+    );
+}
@@ -128,10 +128,11 @@ pub struct Comment {
     /// The span of the comment text, with leading and trailing delimiters.
     pub span: Span,
 
-    /// Start of token this leading comment is attached to.
-    /// `/* Leading */ token`
-    ///                ^ This start
-    /// NOTE: Trailing comment attachment is not computed yet.
+    /// Start of token this leading comment is attached to,
+    /// or end of token this trailing comment is attached to.
+    /// `/* Leading */ token /* Trailing */`
+    ///                ^    ^ This end for trailing comments
+    ///                | This start for leading comments
     #[estree(skip)]
     pub attached_to: u32,
 
@@ -12,6 +12,7 @@ use petgraph::{
     Direction,
     visit::{Control, DfsEvent, EdgeRef},
 };
+use rustc_hash::FxHashSet;
 
 pub mod graph {
     pub use petgraph::*;
@@ -228,6 +229,138 @@ impl ControlFlowGraph {
         }
     }
 
+    /// Returns `true` if some path starting at `from` reaches the end of its function without
+    /// passing through an instruction for which `is_exit` returns `true`.
+    ///
+    /// Explicit error edges (into `catch` clauses) are followed, nested functions and implicit
+    /// error paths are not. Infinite loops, as detected by [`Self::is_infinite_loop_start`] with
+    /// `try_eval_const_condition`, never fall through.
+    pub fn can_fall_through<F, G>(
+        &self,
+        from: BlockNodeId,
+        is_exit: F,
+        try_eval_const_condition: G,
+    ) -> bool
+    where
+        F: Fn(&Instruction) -> bool,
+        G: Fn(&Instruction) -> EvalConstConditionResult,
+    {
+        let mut visited = FxHashSet::default();
+        let mut loop_ends = FxHashSet::default();
+        let mut stack = vec![from];
+        while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            if self.basic_block(node).instructions().iter().any(&is_exit) {
+                // The block may still throw before it gets to the exit.
+                stack.extend(
+                    self.graph
+                        .edges_directed(node, Direction::Outgoing)
+                        .filter(|e| matches!(e.weight(), EdgeType::Error(ErrorEdgeKind::Explicit)))
+                        .map(|e| e.target()),
+                );
+                continue;
+            }
+            if let Some((_, end)) = self.is_infinite_loop_start(node, &try_eval_const_condition) {
+                loop_ends.insert(end);
+            }
+            let mut successors = self.successors(node, true).peekable();
+            if successors.peek().is_none() {
+                return true;
+            }
+            // The `Normal` edges of an infinite loop's end are its exits, they are never taken.
+            let is_loop_end = loop_ends.contains(&node);
+            stack.extend(
+                successors
+                    .filter(|(_, kind)| !is_loop_end || !matches!(kind, EdgeType::Normal))
+                    .map(|(target, _)| target)
+                    .filter(|target| !visited.contains(target)),
+            );
+        }
+        false
+    }
+
+    /// Walks the instructions of every path starting at `from`, in order, without entering
+    /// nested functions or error handlers.
+    ///
+    /// `visit` decides for each instruction whether to keep going (`Control::Continue`), to end
+    /// the current path (`Control::Prune`) or to stop the whole walk (`Control::Break`), whose
+    /// value is returned. Each basic block is visited at most once.
+    pub fn walk_instructions<B, F>(&self, from: BlockNodeId, mut visit: F) -> Option<B>
+    where
+        F: FnMut(&Instruction) -> Control<B>,
+    {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![from];
+        'blocks: while let Some(node) = stack.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            for instruction in self.basic_block(node).instructions() {
+                match visit(instruction) {
+                    Control::Continue => {}
+                    Control::Prune => continue 'blocks,
+                    Control::Break(value) => return Some(value),
+                }
+            }
+            stack.extend(
+                self.successors(node, false)
+                    .map(|(target, _)| target)
+                    .filter(|target| !visited.contains(target)),
+            );
+        }
+        None
+    }
+
+    /// Returns `true` if control can come back to `node` after leaving it without passing through
+    /// `entry`, following only the edges that model normal control flow.
+    ///
+    /// Passing the block a loop statement starts in as `entry` tells whether the loop's body can
+    /// run again without the whole loop being re-entered, e.g. by an enclosing loop. Unlike
+    /// [`Self::is_cyclic`], paths through `return`, `throw` or `break` statements, nested
+    /// functions and implicit error edges are ignored.
+    pub fn is_reentrant(&self, node: BlockNodeId, entry: BlockNodeId) -> bool {
+        let mut visited = FxHashSet::from_iter([entry]);
+        let mut stack = self.successors(node, true).map(|(target, _)| target).collect::<Vec<_>>();
+        while let Some(current) = stack.pop() {
+            if current == node {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            stack.extend(self.successors(current, true).map(|(target, _)| target));
+        }
+        false
+    }
+
+    /// The blocks control can flow to from `node` without an exception being thrown, along with
+    /// the kind of the edge leading there. When a block has nowhere else to go, its `finally`
+    /// edges are taken instead, as they are the only way out of a `try` or `catch` block.
+    fn successors(
+        &self,
+        node: BlockNodeId,
+        include_explicit_errors: bool,
+    ) -> impl Iterator<Item = (BlockNodeId, &EdgeType)> + '_ {
+        let edges = || self.graph.edges_directed(node, Direction::Outgoing);
+        let is_normal = move |kind: &EdgeType| match kind {
+            EdgeType::Jump | EdgeType::Normal | EdgeType::Backedge | EdgeType::Join => true,
+            EdgeType::Error(ErrorEdgeKind::Explicit) => include_explicit_errors,
+            _ => false,
+        };
+        let finalize = !edges().any(|e| is_normal(e.weight()));
+        edges()
+            .filter(move |e| {
+                if finalize {
+                    matches!(e.weight(), EdgeType::Finalize)
+                } else {
+                    is_normal(e.weight())
+                }
+            })
+            .map(|e| (e.target(), e.weight()))
+    }
+
     pub fn is_cyclic(&self, node: BlockNodeId) -> bool {
         set_depth_first_search(&self.graph, Some(node), |event| match event {
             DfsEvent::BackEdge(_, id) if id == node => Err(()),
@@ -4,6 +4,7 @@
 use std::ops::Not;
 
 use oxc_ast::ast::{BinaryExpression, Expression, LogicalExpression};
+use oxc_span::GetSpan;
 use oxc_syntax::{
     operator::{BinaryOperator, LogicalOperator},
     precedence::{GetPrecedence, Precedence},
@@ -219,9 +220,11 @@ impl<'a> BinaryExpressionVisitor<'a> {
     }
 
     pub fn visit_right_and_finish(&self, p: &mut Codegen) {
-        p.print_soft_space();
-        self.operator.r#gen(p);
-        p.print_soft_space();
+        p.print_operator_with_comments(
+            self.e.left().span().end,
+            self.e.right().span().start,
+            |p| self.operator.r#gen(p),
+        );
         self.e.right().gen_expr(p, self.right_precedence, self.ctx);
         if self.wrap {
             p.print_ascii_byte(b')');
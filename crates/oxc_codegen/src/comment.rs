@@ -1,12 +1,13 @@
-use rustc_hash::{FxHashMap, FxHashSet};
-use std::borrow::Cow;
+use rustc_hash::FxHashSet;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use oxc_ast::{Comment, CommentKind, ast::Program};
+use oxc_span::Span;
 use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{Codegen, LegalComment, options::CommentOptions};
 
-pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
+pub type CommentsMap = BTreeMap</* attached_to */ u32, Vec<Comment>>;
 
 impl Codegen<'_> {
     pub(crate) fn build_comments(&mut self, comments: &[Comment]) {
@@ -19,22 +20,25 @@ impl Codegen<'_> {
                 continue;
             }
             let mut add = false;
-            if comment.is_leading() {
-                if comment.is_legal() && self.options.print_legal_comment() {
-                    add = true;
-                }
-                if comment.is_jsdoc() && self.options.print_jsdoc_comment() {
-                    add = true;
-                }
-                if comment.is_annotation() && self.options.print_annotation_comment() {
-                    add = true;
-                }
-                if comment.is_normal() && self.options.print_normal_comment() {
-                    add = true;
-                }
+            if comment.is_legal() && self.options.print_legal_comment() {
+                add = true;
+            }
+            if comment.is_jsdoc() && self.options.print_jsdoc_comment() {
+                add = true;
+            }
+            if comment.is_annotation() && self.options.print_annotation_comment() {
+                add = true;
+            }
+            if comment.is_normal() && self.options.print_normal_comment() {
+                add = true;
             }
             if add {
-                self.comments.entry(comment.attached_to).or_default().push(*comment);
+                let comments = if comment.is_leading() {
+                    &mut self.comments
+                } else {
+                    &mut self.trailing_comments
+                };
+                comments.entry(comment.attached_to).or_default().push(*comment);
             }
         }
     }
@@ -43,6 +47,198 @@ impl Codegen<'_> {
         self.comments.contains_key(&start)
     }
 
+    /// Whether there are any comments attached to tokens within `span`.
+    pub(crate) fn has_comments_in(&self, span: Span) -> bool {
+        // Spans of transformed nodes are not guaranteed to be ordered.
+        if span.start >= span.end {
+            return false;
+        }
+        // Leading comments at `span.start` are comments of the node itself.
+        self.comments.range(span.start + 1..span.end).next().is_some()
+            || self.trailing_comments.range(span.start..span.end).next().is_some()
+    }
+
+    /// Print trailing comments of the token ending at `end`.
+    ///
+    /// Trailing comments are always followed by a line break in the source.
+    /// In minify mode, a line break is printed after a line comment.
+    /// Otherwise, the caller is responsible for printing the line break.
+    pub(crate) fn print_trailing_comments(&mut self, end: u32) -> bool {
+        if self.trailing_comments.is_empty() {
+            return false;
+        }
+        let Some(comments) = self.trailing_comments.remove(&end) else { return false };
+        self.print_trailing_comment_list(&comments);
+        true
+    }
+
+    fn print_trailing_comment_list(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            self.print_hard_space();
+            self.print_comment(comment);
+        }
+        if self.options.minify && comments.last().is_some_and(|comment| comment.is_line()) {
+            self.print_hard_newline();
+        }
+    }
+
+    /// Print trailing comments of a statement on the same line as the statement.
+    pub(crate) fn print_statement_comments(&mut self, span: Span) {
+        if self.trailing_comments.is_empty() || !self.trailing_comments.contains_key(&span.end) {
+            return;
+        }
+        // Move the line break printed after the statement to after the comments.
+        let has_newline = self.last_byte() == Some(b'\n') && self.code.pop_ascii_byte().is_some();
+        self.print_trailing_comments(span.end);
+        if has_newline {
+            self.print_hard_newline();
+        }
+    }
+
+    /// Print leading comments of a node inside an expression, on the same line where possible.
+    pub(crate) fn print_inner_comments(&mut self, start: u32) {
+        let Some(comments) = self.get_comments(start) else { return };
+        for comment in &comments {
+            self.print_comment(comment);
+            if comment.is_line() {
+                self.print_hard_newline();
+                self.print_indent();
+            } else {
+                self.print_soft_space();
+            }
+        }
+    }
+
+    /// Print block comments that sit on the same line as their surroundings, e.g. `{ /* empty */ }`.
+    pub(crate) fn print_inline_comments(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            self.print_soft_space();
+            self.print_comment(comment);
+        }
+        self.print_soft_space();
+    }
+
+    /// Print comments before the closing `}` of a block, each followed by a line break.
+    pub(crate) fn print_dangling_comments(&mut self, comments: &[Comment]) {
+        self.print_comments(comments);
+        self.print_next_indent_as_space = false;
+        if self.last_byte().is_some_and(|b| b != b'\n') {
+            self.print_soft_newline();
+        }
+    }
+
+    /// Print comments after the last item of a list: comments around a trailing comma,
+    /// and comments before the closing bracket of `span`.
+    pub(crate) fn print_list_end_comments(&mut self, last_item_end: u32, span: Span) {
+        if span.end == 0 {
+            return;
+        }
+        let has_newline = self.print_comments_between(last_item_end, span.end - 1);
+        if let Some(comments) = self.get_comments(span.end - 1) {
+            if !has_newline {
+                self.print_soft_newline();
+            }
+            self.print_dangling_comments(&comments);
+        }
+    }
+
+    /// Print comments attached to tokens in `start..end`,
+    /// e.g. comments around the operator between two operands, or around a `,` in a list.
+    ///
+    /// Returns `true` if a line comment was printed last, which is followed by a line break.
+    /// The caller is responsible for indenting the next line.
+    pub(crate) fn print_comments_between(&mut self, start: u32, end: u32) -> bool {
+        if start >= end || (self.comments.is_empty() && self.trailing_comments.is_empty()) {
+            return false;
+        }
+        let leading = self.comments.range(start..end).map(|(key, _)| *key).collect::<Vec<_>>();
+        let trailing =
+            self.trailing_comments.range(start..end).map(|(key, _)| *key).collect::<Vec<_>>();
+        if leading.is_empty() && trailing.is_empty() {
+            return false;
+        }
+        let mut comments = leading
+            .into_iter()
+            .filter_map(|key| self.comments.remove(&key))
+            .chain(trailing.into_iter().filter_map(|key| self.trailing_comments.remove(&key)))
+            .flatten()
+            .collect::<Vec<_>>();
+        comments.sort_unstable_by_key(|comment| comment.span.start);
+        let mut has_newline = false;
+        for comment in &comments {
+            if has_newline {
+                self.print_indent();
+            } else if self.last_byte().is_none_or(|b| !matches!(b, b'(' | b'[')) {
+                self.print_soft_space();
+            }
+            self.print_comment(comment);
+            has_newline = comment.is_line();
+            if has_newline {
+                self.print_hard_newline();
+            }
+        }
+        has_newline
+    }
+
+    /// Print a binary operator (e.g. `+`, `?`, `:`) located between `start` and `end`,
+    /// keeping comments on the same side of the operator as in the source.
+    pub(crate) fn print_operator_with_comments<F: FnOnce(&mut Self)>(
+        &mut self,
+        start: u32,
+        end: u32,
+        op: F,
+    ) {
+        if self.comments.is_empty() && self.trailing_comments.is_empty() {
+            self.print_soft_space();
+            op(self);
+            self.print_soft_space();
+            return;
+        }
+        let operator_start = self.find_operator_start(start, end);
+        if self.print_comments_between(start, operator_start + 1) {
+            self.print_indent();
+        } else {
+            self.print_soft_space();
+        }
+        op(self);
+        if self.print_comments_between(start, end) {
+            self.print_indent();
+        } else {
+            self.print_soft_space();
+        }
+    }
+
+    /// Skip whitespace, comments and closing parentheses after `start` to find the operator token.
+    fn find_operator_start(&self, start: u32, end: u32) -> u32 {
+        let Some(source_text) = self.source_text else {
+            return start;
+        };
+        let end = (end as usize).min(source_text.len());
+        let mut pos = start as usize;
+        while pos < end {
+            let rest = &source_text[pos..end];
+            if let Some(comment) = rest.strip_prefix("/*") {
+                pos += comment.find("*/").map_or(rest.len(), |i| i + 4);
+            } else if rest.starts_with("//") {
+                pos += rest.find(is_line_terminator).unwrap_or(rest.len());
+            } else {
+                let Some(c) = rest.chars().next() else { break };
+                if c != ')' && !c.is_whitespace() {
+                    break;
+                }
+                pos += c.len_utf8();
+            }
+        }
+        #[expect(clippy::cast_possible_truncation)]
+        let pos = pos as u32;
+        pos
+    }
+
+    /// Whether a line break would be printed by [`Codegen::print_inner_comments`] at `start`.
+    pub(crate) fn has_line_comment(&self, start: u32) -> bool {
+        self.comments.get(&start).is_some_and(|comments| comments.iter().any(|c| c.is_line()))
+    }
+
     pub(crate) fn print_leading_comments(&mut self, start: u32) {
         if let Some(comments) = self.comments.remove(&start) {
             self.print_comments(&comments);
@@ -128,7 +324,7 @@ impl Codegen<'_> {
         let comment_source = comment.span.source_text(source_text);
         match comment.kind {
             CommentKind::Line => {
-                self.print_str_escaping_script_close_tag(comment_source);
+                self.print_raw_text(comment_source);
             }
             CommentKind::Block => {
                 // Print block comments with our own indentation.
@@ -136,7 +332,7 @@ impl Codegen<'_> {
                     if !line.starts_with("/*") {
                         self.print_indent();
                     }
-                    self.print_str_escaping_script_close_tag(line.trim_start());
+                    self.print_raw_text(line.trim_start());
                     if !line.ends_with("*/") {
                         self.print_hard_newline();
                     }
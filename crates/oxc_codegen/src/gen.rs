@@ -12,6 +12,7 @@ use oxc_syntax::{
 use crate::{
     Codegen, Context, Operator, Quote,
     binary_expr_visitor::{BinaryExpressionVisitor, Binaryish, BinaryishOperator},
+    str::NonAsciiEscape,
 };
 
 const PURE_COMMENT: &str = "/* @__PURE__ */ ";
@@ -66,6 +67,7 @@ impl Gen for Hashbang<'_> {
 
 impl Gen for Directive<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.print_comments_at(self.span.start);
         p.add_source_mapping(self.span);
         p.print_indent();
         // A Use Strict Directive may not contain an EscapeSequence or LineContinuation.
@@ -92,10 +94,11 @@ impl Gen for Directive<'_> {
             }
         }
         quote.print(p);
-        p.print_str(directive);
+        p.print_str_ascii_only(directive, NonAsciiEscape::Utf16);
         quote.print(p);
         p.print_ascii_byte(b';');
         p.print_soft_newline();
+        p.print_statement_comments(self.span);
     }
 }
 
@@ -182,6 +185,7 @@ impl Gen for Statement<'_> {
                 p.print_semicolon_after_statement();
             }
         }
+        p.print_statement_comments(self.span());
     }
 }
 
@@ -543,7 +547,9 @@ impl Gen for ReturnStatement<'_> {
         p.print_str("return");
         if let Some(arg) = &self.argument {
             p.print_soft_space();
-            p.print_expression(arg);
+            // A line comment before the argument would insert a semicolon after `return`
+            let wrap = p.has_line_comment(arg.span().start);
+            p.wrap(wrap, |p| p.print_expression(arg));
         }
         p.print_semicolon_after_statement();
     }
@@ -609,7 +615,8 @@ impl Gen for ThrowStatement<'_> {
         p.print_space_before_identifier();
         p.print_str("throw");
         p.print_soft_space();
-        p.print_expression(&self.argument);
+        let wrap = p.has_line_comment(self.argument.span().start);
+        p.wrap(wrap, |p| p.print_expression(&self.argument));
         p.print_semicolon_after_statement();
     }
 }
@@ -658,6 +665,14 @@ impl Gen for VariableDeclaration<'_> {
             p.print_soft_space();
         }
         p.print_list(&self.declarations, ctx);
+        // Comments before `;`, e.g. `let a, b /* c */;`
+        if let Some(last) = self.declarations.last() {
+            if last.span.end < self.span.end
+                && p.print_comments_between(last.span.end, self.span.end)
+            {
+                p.print_indent();
+            }
+        }
     }
 }
 
@@ -676,9 +691,9 @@ impl Gen for VariableDeclarator<'_> {
             type_annotation.print(p, ctx);
         }
         if let Some(init) = &self.init {
-            p.print_soft_space();
-            p.print_equal();
-            p.print_soft_space();
+            let id_end =
+                self.id.type_annotation.as_ref().map_or(self.id.span().end, |t| t.span.end);
+            p.print_operator_with_comments(id_end, init.span().start, Codegen::print_equal);
             init.print_expr(p, Precedence::Comma, ctx);
         }
     }
@@ -789,6 +804,15 @@ impl Gen for FormalParameters<'_> {
             }
             rest.print(p, ctx);
         }
+        // Comments before `)`, e.g. `function foo(/* no params */) {}`
+        let last_end = self
+            .rest
+            .as_ref()
+            .map(|rest| rest.span.end)
+            .or_else(|| self.items.last().map(|item| item.span.end));
+        if p.print_comments_between(last_end.unwrap_or(self.span.start), self.span.end) {
+            p.print_indent();
+        }
     }
 }
 
@@ -863,7 +887,14 @@ impl Gen for ImportDeclaration<'_> {
                     ImportDeclarationSpecifier::ImportSpecifier(spec) => {
                         if in_block {
                             p.print_comma();
-                            p.print_soft_space();
+                            if p.print_comments_between(
+                                specifiers[index - 1].span().end,
+                                spec.span.start,
+                            ) {
+                                p.print_indent();
+                            } else {
+                                p.print_soft_space();
+                            }
                         } else {
                             if index != 0 {
                                 p.print_comma();
@@ -874,6 +905,7 @@ impl Gen for ImportDeclaration<'_> {
                             p.print_soft_space();
                         }
 
+                        p.print_inner_comments(spec.span.start);
                         if spec.import_kind.is_type() {
                             p.print_str("type ");
                         }
@@ -889,7 +921,12 @@ impl Gen for ImportDeclaration<'_> {
                 }
             }
             if in_block {
-                p.print_soft_space();
+                let last_end = specifiers.last().map_or(self.span.start, |last| last.span().end);
+                if p.print_comments_between(last_end, self.source.span.start) {
+                    p.print_indent();
+                } else {
+                    p.print_soft_space();
+                }
                 p.print_ascii_byte(b'}');
                 p.print_soft_space();
             }
@@ -980,10 +1017,15 @@ impl Gen for ExportNamedDeclaration<'_> {
             }
             p.print_soft_space();
             p.print_ascii_byte(b'{');
-            if !self.specifiers.is_empty() {
+            if let Some(last) = self.specifiers.last() {
                 p.print_soft_space();
                 p.print_list(&self.specifiers, ctx);
-                p.print_soft_space();
+                let end = self.source.as_ref().map_or(self.span.end, |source| source.span.start);
+                if p.print_comments_between(last.span.end, end) {
+                    p.print_indent();
+                } else {
+                    p.print_soft_space();
+                }
             }
             p.print_ascii_byte(b'}');
             if let Some(source) = &self.source {
@@ -1123,6 +1165,10 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
 
 impl GenExpr for Expression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        // Comments before object literals are handled in `ObjectExpression`.
+        if !matches!(self, Self::ObjectExpression(_)) {
+            p.print_inner_comments(self.span().start);
+        }
         match self {
             Self::BooleanLiteral(lit) => lit.print(p, ctx),
             Self::NullLiteral(lit) => lit.print(p, ctx),
@@ -1192,7 +1238,7 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_str_ascii_only(name, NonAsciiEscape::CodePoint);
     }
 }
 
@@ -1200,7 +1246,7 @@ impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping(self.span);
-        p.print_str(self.name.as_str());
+        p.print_str_ascii_only(self.name.as_str(), NonAsciiEscape::CodePoint);
     }
 }
 
@@ -1209,7 +1255,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_str_ascii_only(name, NonAsciiEscape::CodePoint);
     }
 }
 
@@ -1217,7 +1263,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_str_ascii_only(self.name.as_str(), NonAsciiEscape::CodePoint);
     }
 }
 
@@ -1310,7 +1356,7 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(self.regex.pattern.text.as_str());
+        p.print_str_ascii_only(self.regex.pattern.text.as_str(), NonAsciiEscape::Utf16);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1443,18 +1489,31 @@ impl Gen for SpreadElement<'_> {
 
 impl Gen for ArrayExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        let is_multi_line = self.elements.len() > 2;
+        let has_comments = p.has_comments_in(self.span);
+        let is_multi_line = self.elements.len() > 2 || has_comments;
         p.add_source_mapping(self.span);
         p.print_ascii_byte(b'[');
         if is_multi_line {
             p.indent();
         }
+        let mut prev_end = self.span.start;
         for (i, item) in self.elements.iter().enumerate() {
+            let span = item.span();
             if i != 0 {
                 p.print_comma();
             }
+            let mut has_newline = false;
+            if has_comments {
+                has_newline = p.print_comments_between(prev_end, span.start);
+                prev_end = span.end;
+            }
             if is_multi_line {
-                p.print_soft_newline();
+                if !has_newline {
+                    p.print_soft_newline();
+                }
+                if has_comments {
+                    p.print_leading_comments(span.start);
+                }
                 p.print_indent();
             } else if i != 0 {
                 p.print_soft_space();
@@ -1464,8 +1523,13 @@ impl Gen for ArrayExpression<'_> {
                 p.print_comma();
             }
         }
+        if has_comments {
+            p.print_list_end_comments(prev_end, self.span);
+        }
         if is_multi_line {
-            p.print_soft_newline();
+            if p.last_byte() != Some(b'\n') {
+                p.print_soft_newline();
+            }
             p.dedent();
             p.print_indent();
         }
@@ -1478,7 +1542,8 @@ impl GenExpr for ObjectExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
         let n = p.code_len();
         let len = self.properties.len();
-        let is_multi_line = len > 1;
+        let has_inner_comments = p.has_comments_in(self.span);
+        let is_multi_line = len > 1 || has_inner_comments;
         let has_comment = p.has_comment(self.span.start);
         let wrap = has_comment || p.start_of_stmt == n || p.start_of_arrow_expr == n;
         p.wrap(wrap, |p| {
@@ -1493,20 +1558,37 @@ impl GenExpr for ObjectExpression<'_> {
             if is_multi_line {
                 p.indent();
             }
+            let mut prev_end = self.span.start;
             for (i, item) in self.properties.iter().enumerate() {
+                let span = item.span();
                 if i != 0 {
                     p.print_comma();
                 }
+                let mut has_newline = false;
+                if has_inner_comments {
+                    has_newline = p.print_comments_between(prev_end, span.start);
+                    prev_end = span.end;
+                }
                 if is_multi_line {
-                    p.print_soft_newline();
+                    if !has_newline {
+                        p.print_soft_newline();
+                    }
+                    if has_inner_comments {
+                        p.print_leading_comments(span.start);
+                    }
                     p.print_indent();
                 } else {
                     p.print_soft_space();
                 }
                 item.print(p, ctx);
             }
+            if has_inner_comments {
+                p.print_list_end_comments(prev_end, self.span);
+            }
             if is_multi_line {
-                p.print_soft_newline();
+                if p.last_byte() != Some(b'\n') {
+                    p.print_soft_newline();
+                }
                 p.dedent();
                 p.print_indent();
             } else if len > 0 {
@@ -1692,7 +1774,8 @@ impl GenExpr for YieldExpression<'_> {
             }
             if let Some(argument) = self.argument.as_ref() {
                 p.print_soft_space();
-                argument.print_expr(p, Precedence::Yield, Context::empty());
+                let wrap = p.has_line_comment(argument.span().start);
+                p.wrap(wrap, |p| argument.print_expr(p, Precedence::Yield, Context::empty()));
             }
         });
     }
@@ -1809,13 +1892,17 @@ impl GenExpr for ConditionalExpression<'_> {
         }
         p.wrap(wrap, |p| {
             self.test.print_expr(p, Precedence::Conditional, ctx & Context::FORBID_IN);
-            p.print_soft_space();
-            p.print_ascii_byte(b'?');
-            p.print_soft_space();
+            p.print_operator_with_comments(
+                self.test.span().end,
+                self.consequent.span().start,
+                |p| p.print_ascii_byte(b'?'),
+            );
             self.consequent.print_expr(p, Precedence::Yield, Context::empty());
-            p.print_soft_space();
-            p.print_colon();
-            p.print_soft_space();
+            p.print_operator_with_comments(
+                self.consequent.span().end,
+                self.alternate.span().start,
+                Codegen::print_colon,
+            );
             self.alternate.print_expr(p, Precedence::Yield, ctx & Context::FORBID_IN);
         });
     }
@@ -1829,9 +1916,9 @@ impl GenExpr for AssignmentExpression<'_> {
             && matches!(self.left, AssignmentTarget::ObjectAssignmentTarget(_));
         p.wrap(wrap || precedence >= self.precedence(), |p| {
             self.left.print(p, ctx);
-            p.print_soft_space();
-            p.print_str(self.operator.as_str());
-            p.print_soft_space();
+            p.print_operator_with_comments(self.left.span().end, self.right.span().start, |p| {
+                p.print_str(self.operator.as_str());
+            });
             self.right.print_expr(p, Precedence::Comma, ctx);
         });
     }
@@ -2073,8 +2160,12 @@ impl GenExpr for ImportExpression<'_> {
                 options.gen_expr(p, Precedence::Comma, Context::empty());
             }
             if has_comment {
+                let last_end = self.options.as_ref().unwrap_or(&self.source).span().end;
+                let has_newline = p.print_comments_between(last_end, self.span.end - 1);
                 // Handle `/* comment */);`
-                if !has_comment_before_right_paren || !p.print_expr_comments(self.span.end - 1) {
+                if (!has_comment_before_right_paren || !p.print_expr_comments(self.span.end - 1))
+                    && !has_newline
+                {
                     p.print_soft_newline();
                 }
                 p.dedent();
@@ -2086,30 +2177,40 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span);
-        p.print_ascii_byte(b'`');
-
-        debug_assert_eq!(self.quasis.len(), self.expressions.len() + 1);
-
-        let (first_quasi, remaining_quasis) = self.quasis.split_first().unwrap();
+        print_template_literal(self, p, /* is_tagged */ false);
+    }
+}
 
-        p.add_source_mapping(first_quasi.span);
-        p.print_str_escaping_script_close_tag(first_quasi.value.raw.as_str());
-        p.add_source_mapping_end(first_quasi.span);
+/// The raw text of a tagged template is observable by the tag (`String.raw`),
+/// so it is printed as-is, without escaping non-ASCII characters.
+fn print_template_literal(lit: &TemplateLiteral<'_>, p: &mut Codegen, is_tagged: bool) {
+    p.add_source_mapping(lit.span);
+    p.print_ascii_byte(b'`');
 
-        for (expr, quasi) in self.expressions.iter().zip(remaining_quasis) {
-            p.print_str("${");
-            p.print_expression(expr);
-            p.print_ascii_byte(b'}');
+    debug_assert_eq!(lit.quasis.len(), lit.expressions.len() + 1);
 
-            p.add_source_mapping(quasi.span);
+    let print_quasi = |p: &mut Codegen, quasi: &TemplateElement<'_>| {
+        p.add_source_mapping(quasi.span);
+        if is_tagged {
             p.print_str_escaping_script_close_tag(quasi.value.raw.as_str());
-            p.add_source_mapping_end(quasi.span);
+        } else {
+            p.print_raw_text(quasi.value.raw.as_str());
         }
+        p.add_source_mapping_end(quasi.span);
+    };
 
-        p.print_ascii_byte(b'`');
-        p.add_source_mapping_end(self.span);
+    let (first_quasi, remaining_quasis) = lit.quasis.split_first().unwrap();
+    print_quasi(p, first_quasi);
+
+    for (expr, quasi) in lit.expressions.iter().zip(remaining_quasis) {
+        p.print_str("${");
+        p.print_expression(expr);
+        p.print_ascii_byte(b'}');
+        print_quasi(p, quasi);
     }
+
+    p.print_ascii_byte(b'`');
+    p.add_source_mapping_end(lit.span);
 }
 
 impl Gen for TaggedTemplateExpression<'_> {
@@ -2119,7 +2220,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        print_template_literal(&self.quasi, p, /* is_tagged */ true);
     }
 }
 
@@ -2309,6 +2410,7 @@ impl Gen for ClassBody<'_> {
                 p.print_leading_comments(item.span().start);
                 p.print_indent();
                 item.print(p, ctx);
+                p.print_statement_comments(item.span());
             }
         });
     }
@@ -2436,7 +2538,7 @@ impl Gen for JSXAttributeValue<'_> {
             Self::StringLiteral(lit) => {
                 let quote = if lit.value.contains('"') { b'\'' } else { b'"' };
                 p.print_ascii_byte(quote);
-                p.print_str(&lit.value);
+                p.print_str_ascii_only(&lit.value, NonAsciiEscape::HtmlEntity);
                 p.print_ascii_byte(quote);
             }
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
@@ -2518,7 +2620,7 @@ impl Gen for JSXClosingFragment {
 impl Gen for JSXText<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span);
-        p.print_str(self.value.as_str());
+        p.print_str_ascii_only(self.value.as_str(), NonAsciiEscape::HtmlEntity);
     }
 }
 
@@ -2759,7 +2861,7 @@ impl Gen for PrivateIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_str_ascii_only(self.name.as_str(), NonAsciiEscape::CodePoint);
     }
 }
 
@@ -3217,7 +3319,7 @@ impl Gen for TSTemplateLiteralType<'_> {
                     p.print_str("}");
                 }
             }
-            p.print_str(item.value.raw.as_str());
+            p.print_str_ascii_only(item.value.raw.as_str(), NonAsciiEscape::Utf16);
         }
         p.print_str("`");
     }
@@ -40,7 +40,7 @@ use crate::{
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, CommentOptions, LegalComment},
+    options::{CodegenOptions, CommentOptions, IndentChar, LegalComment},
 };
 
 /// Output from [`Codegen::build`]
@@ -90,6 +90,8 @@ pub struct Codegen<'a> {
     // states
     prev_op_end: usize,
     prev_reg_exp_end: usize,
+    /// End of the last identifier printed with a trailing `\u{...}` escape
+    prev_escaped_identifier_end: usize,
     need_space_before_dot: usize,
     print_next_indent_as_space: bool,
     binary_expr_stack: Stack<BinaryExpressionVisitor<'a>>,
@@ -109,11 +111,17 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
+    /// Start of the last line known to be in the buffer, for [`CodegenOptions::max_line_length`]
+    line_start: usize,
+
     /// Fast path for [CodegenOptions::single_quote]
     quote: Quote,
 
     // Builders
+    /// Leading comments, keyed by start of the token they are attached to
     comments: CommentsMap,
+    /// Trailing comments, keyed by end of the token they are attached to
+    trailing_comments: CommentsMap,
 
     sourcemap_builder: Option<SourcemapBuilder<'a>>,
 }
@@ -155,14 +163,17 @@ impl<'a> Codegen<'a> {
             binary_expr_stack: Stack::with_capacity(12),
             prev_op_end: 0,
             prev_reg_exp_end: 0,
+            prev_escaped_identifier_end: 0,
             prev_op: None,
             start_of_stmt: 0,
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
             is_jsx: false,
             indent: 0,
+            line_start: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
+            trailing_comments: CommentsMap::default(),
             sourcemap_builder: None,
         }
     }
@@ -396,18 +407,43 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_semicolon(&mut self) {
         self.print_ascii_byte(b';');
+        self.print_newline_if_line_too_long();
     }
 
     #[inline]
     fn print_comma(&mut self) {
         self.print_ascii_byte(b',');
+        self.print_newline_if_line_too_long();
+    }
+
+    /// Break the line if it is longer than [`CodegenOptions::max_line_length`].
+    ///
+    /// Only called after `,` and `;`, where a line break never changes semantics.
+    #[inline]
+    fn print_newline_if_line_too_long(&mut self) {
+        let Some(max_line_length) = self.options.max_line_length else { return };
+        if self.code.len() - self.line_start <= max_line_length {
+            return;
+        }
+        // Line breaks may have been printed since `line_start` was last updated
+        let line = &self.code.as_bytes()[self.line_start..];
+        if let Some(pos) = line.iter().rposition(|&b| b == b'\n') {
+            self.line_start += pos + 1;
+            if self.code.len() - self.line_start <= max_line_length {
+                return;
+            }
+        }
+        self.print_hard_newline();
+        self.line_start = self.code.len();
     }
 
     #[inline]
     fn print_space_before_identifier(&mut self) {
         let Some(byte) = self.last_byte() else { return };
 
-        if self.prev_reg_exp_end != self.code.len() {
+        if self.prev_reg_exp_end != self.code.len()
+            && self.prev_escaped_identifier_end != self.code.len()
+        {
             let is_identifier = if byte.is_ascii() {
                 // Fast path for ASCII (very common case)
                 is_identifier_part_ascii(byte as char)
@@ -467,7 +503,11 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        let n = self.indent as usize * self.options.indent_width;
+        match self.options.indent_char {
+            IndentChar::Tab => self.code.print_indent(n),
+            IndentChar::Space => self.code.print_ascii_bytes(std::iter::repeat_n(b' ', n)),
+        }
     }
 
     #[inline]
@@ -503,13 +543,30 @@ impl<'a> Codegen<'a> {
     }
 
     fn print_curly_braces<F: FnOnce(&mut Self)>(&mut self, span: Span, single_line: bool, op: F) {
+        // Comments before `}`
+        let dangling_comments = if span.end > 0 { self.get_comments(span.end - 1) } else { None };
+        let single_line = single_line
+            && dangling_comments.as_ref().is_none_or(|comments| {
+                comments
+                    .iter()
+                    .all(|c| c.is_block() && !c.preceded_by_newline() && !c.followed_by_newline())
+            })
+            && !self.trailing_comments.contains_key(&(span.start + 1));
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
         if !single_line {
+            self.print_trailing_comments(span.start + 1);
             self.print_soft_newline();
             self.indent();
         }
         op(self);
+        if let Some(comments) = dangling_comments {
+            if single_line {
+                self.print_inline_comments(&comments);
+            } else {
+                self.print_dangling_comments(&comments);
+            }
+        }
         if !single_line {
             self.dedent();
             self.print_indent();
@@ -521,11 +578,17 @@ impl<'a> Codegen<'a> {
     fn print_block_start(&mut self, span: Span) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
+        self.print_trailing_comments(span.start + 1);
         self.print_soft_newline();
         self.indent();
     }
 
     fn print_block_end(&mut self, span: Span) {
+        if span.end > 0 {
+            if let Some(comments) = self.get_comments(span.end - 1) {
+                self.print_dangling_comments(&comments);
+            }
+        }
         self.dedent();
         self.print_indent();
         self.add_source_mapping_end(span);
@@ -602,14 +665,23 @@ impl<'a> Codegen<'a> {
     }
 
     #[inline]
-    fn print_list<T: Gen>(&mut self, items: &[T], ctx: Context) {
+    fn print_list<T: Gen + GetSpan>(&mut self, items: &[T], ctx: Context) {
         let Some((first, rest)) = items.split_first() else {
             return;
         };
+        self.print_inner_comments(first.span().start);
         first.print(self, ctx);
+        let mut prev_end = first.span().end;
         for item in rest {
+            let span = item.span();
             self.print_comma();
-            self.print_soft_space();
+            if self.print_comments_between(prev_end, span.start) {
+                self.print_indent();
+            } else {
+                self.print_soft_space();
+            }
+            prev_end = span.end;
+            self.print_inner_comments(span.start);
             item.print(self, ctx);
         }
     }
@@ -633,14 +705,24 @@ impl<'a> Codegen<'a> {
         let has_comment_before_right_paren = span.end > 0 && self.has_comment(span.end - 1);
 
         let has_comment = has_comment_before_right_paren
-            || arguments.iter().any(|item| self.has_comment(item.span().start));
+            || arguments.iter().any(|item| self.has_comment(item.span().start))
+            || arguments.windows(2).any(|pair| {
+                self.has_comments_in(Span::new(pair[0].span().end, pair[1].span().start))
+            })
+            || arguments
+                .last()
+                .is_some_and(|last| self.has_comments_in(Span::new(last.span().end, span.end)));
 
         if has_comment {
             self.indent();
             self.print_list_with_comments(arguments, ctx);
+            let has_newline = arguments.last().is_some_and(|last| {
+                span.end > 0 && self.print_comments_between(last.span().end, span.end - 1)
+            });
             // Handle `/* comment */);`
-            if !has_comment_before_right_paren
-                || (span.end > 0 && !self.print_expr_comments(span.end - 1))
+            if (!has_comment_before_right_paren
+                || (span.end > 0 && !self.print_expr_comments(span.end - 1)))
+                && !has_newline
             {
                 self.print_soft_newline();
             }
@@ -663,12 +745,17 @@ impl<'a> Codegen<'a> {
             self.print_indent();
         }
         first.print(self, ctx);
+        let mut prev_end = first.span().end;
         for item in rest {
             self.print_comma();
+            let has_newline = self.print_comments_between(prev_end, item.span().start);
+            prev_end = item.span().end;
             if self.print_expr_comments(item.span().start) {
                 self.print_indent();
             } else {
-                self.print_soft_newline();
+                if !has_newline {
+                    self.print_soft_newline();
+                }
                 self.print_indent();
             }
             item.print(self, ctx);
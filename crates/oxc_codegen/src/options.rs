@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 /// Codegen Options.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct CodegenOptions {
     /// Use single quotes instead of double quotes.
     ///
@@ -26,6 +26,51 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Escape all non-ASCII characters.
+    ///
+    /// Non-ASCII characters in strings, template literals, regular expressions and comments
+    /// are printed as `\uXXXX`, identifiers as `\uXXXX` or `\u{XXXXX}`,
+    /// and JSX text as HTML entities.
+    ///
+    /// <https://esbuild.github.io/api/#charset>
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
+
+    /// Character used for indentation.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [`CodegenOptions::indent_char`] characters printed per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Insert a line break after a `,` or `;` once a line exceeds this many bytes.
+    ///
+    /// Useful for tools which cannot handle very long lines in minified output.
+    ///
+    /// <https://esbuild.github.io/api/#line-limit>
+    ///
+    /// Default is `None` - lines are not broken.
+    pub max_line_length: Option<usize>,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            single_quote: false,
+            minify: false,
+            comments: CommentOptions::default(),
+            source_map_path: None,
+            ascii_only: false,
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            max_line_length: None,
+        }
+    }
 }
 
 impl CodegenOptions {
@@ -36,6 +81,10 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            ascii_only: false,
+            indent_char: IndentChar::default(),
+            indent_width: 1,
+            max_line_length: None,
         }
     }
 
@@ -60,6 +109,16 @@ impl CodegenOptions {
     }
 }
 
+/// Indentation character.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab,
+    /// Indent with spaces.
+    Space,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// Comment Options
 pub struct CommentOptions {
@@ -1,4 +1,4 @@
-use std::slice;
+use std::{borrow::Cow, fmt::Write, slice};
 
 use oxc_ast::ast::StringLiteral;
 use oxc_data_structures::{
@@ -25,7 +25,86 @@ impl Quote {
     }
 }
 
+/// How non-ASCII characters are escaped when [`CodegenOptions::ascii_only`] is enabled.
+///
+/// [`CodegenOptions::ascii_only`]: crate::CodegenOptions::ascii_only
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NonAsciiEscape {
+    /// `\uXXXX`, with astral characters as a surrogate pair.
+    /// Valid in strings, template literals, regular expressions and comments.
+    Utf16,
+    /// `\uXXXX` or `\u{XXXXX}`. Valid in identifiers.
+    CodePoint,
+    /// `&#xXXXX;`. Valid in JSX text and attribute strings.
+    HtmlEntity,
+}
+
+/// Escape all non-ASCII characters in `s`.
+pub fn escape_non_ascii(s: &str, escape: NonAsciiEscape) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut escaped = String::with_capacity(s.len() + 16);
+    for ch in s.chars() {
+        if ch.is_ascii() {
+            escaped.push(ch);
+            continue;
+        }
+        let code_point = u32::from(ch);
+        match escape {
+            NonAsciiEscape::Utf16 => {
+                for unit in ch.encode_utf16(&mut [0; 2]) {
+                    write!(escaped, "\\u{unit:04X}").unwrap();
+                }
+            }
+            NonAsciiEscape::CodePoint if code_point <= 0xFFFF => {
+                write!(escaped, "\\u{code_point:04X}").unwrap();
+            }
+            NonAsciiEscape::CodePoint => write!(escaped, "\\u{{{code_point:X}}}").unwrap(),
+            NonAsciiEscape::HtmlEntity => write!(escaped, "&#x{code_point:X};").unwrap(),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
 impl Codegen<'_> {
+    /// Print `s`, escaping non-ASCII characters if [`CodegenOptions::ascii_only`] is enabled.
+    ///
+    /// [`CodegenOptions::ascii_only`]: crate::CodegenOptions::ascii_only
+    #[inline]
+    pub(crate) fn print_str_ascii_only(&mut self, s: &str, escape: NonAsciiEscape) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| {
+                self.print_str(&escape_non_ascii(s, escape));
+                // `\u{10000}` followed by an identifier would continue the identifier
+                if escape == NonAsciiEscape::CodePoint && self.last_byte() == Some(b'}') {
+                    self.prev_escaped_identifier_end = self.code.len();
+                }
+            });
+        } else {
+            self.print_str(s);
+        }
+    }
+
+    /// Print source text of a template element or comment, escaping `</script`,
+    /// and non-ASCII characters if [`CodegenOptions::ascii_only`] is enabled.
+    ///
+    /// [`CodegenOptions::ascii_only`]: crate::CodegenOptions::ascii_only
+    #[inline]
+    pub(crate) fn print_raw_text(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            cold_branch(|| {
+                self.print_str_escaping_script_close_tag(&escape_non_ascii(
+                    s,
+                    NonAsciiEscape::Utf16,
+                ));
+            });
+        } else {
+            self.print_str_escaping_script_close_tag(s);
+        }
+    }
+
     /// Print a [`StringLiteral`].
     pub(crate) fn print_string_literal(&mut self, s: &StringLiteral<'_>, allow_backtick: bool) {
         self.add_source_mapping(s.span);
@@ -196,7 +275,12 @@ impl PrintStringState<'_> {
         // Therefore the slice between these two must be a valid UTF-8 string.
         unsafe {
             let slice = slice::from_raw_parts(self.chunk_start, len);
-            codegen.code.print_bytes_unchecked(slice);
+            if codegen.options.ascii_only && !slice.is_ascii() {
+                let s = std::str::from_utf8_unchecked(slice);
+                cold_branch(|| codegen.print_str(&escape_non_ascii(s, NonAsciiEscape::Utf16)));
+            } else {
+                codegen.code.print_bytes_unchecked(slice);
+            }
         }
     }
 
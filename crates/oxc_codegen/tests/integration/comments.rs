@@ -11,6 +11,39 @@ fn unit() {
     );
 }
 
+#[test]
+fn trailing() {
+    test("foo(); // trailing", "foo(); // trailing\n");
+    test(
+        "if (a) { // after brace\n b();\n} // after if",
+        "if (a) { // after brace\n\tb();\n} // after if\n",
+    );
+    test(
+        "const x = {\n a: 1, // first\n b: 2, // second\n};",
+        "const x = {\n\ta: 1, // first\n\tb: 2 // second\n};\n",
+    );
+    test("const y = [\n 1, // one\n 2,\n];", "const y = [\n\t1, // one\n\t2\n];\n");
+    test("call(a, // after a\n b);", "call(\n\ta, // after a\n\tb\n);\n");
+}
+
+#[test]
+fn inner() {
+    test("let z = /* init */ 1 + /* right */ 2;", "let z = /* init */ 1 + /* right */ 2;\n");
+    test("x = cond /* c1 */ ? a /* c2 */ : b;", "x = cond /* c1 */ ? a /* c2 */ : b;\n");
+    test("function f(/* no params */) {}", "function f(/* no params */) {}\n");
+    test("class A { /* empty */ }", "class A { /* empty */ }\n");
+    test("let a, b /* c */;", "let a, b /* c */;\n");
+    test("export { a, /* b */ c };", "export { a, /* b */ c };\n");
+    test("import { a /* b */ } from 'x';", "import { a /* b */ } from \"x\";\n");
+}
+
+#[test]
+fn dangling() {
+    test("{\n // only comment in block\n}", "{\n\t// only comment in block\n}\n");
+    test("class A {\n // only in class\n}", "class A {\n\t// only in class\n}\n");
+    test("const o = {\n // only in object\n};", "const o = {\n\t// only in object\n};\n");
+}
+
 pub mod jsdoc {
     use crate::snapshot;
 
@@ -2,7 +2,9 @@
 //! * <https://github.com/evanw/esbuild/blob/v0.24.0/internal/js_printer/js_printer_test.go>
 //! * <https://github.com/evanw/esbuild/blob/v0.24.0/internal/js_parser/js_parser_test.go>
 
-use crate::tester::{test, test_minify};
+use oxc_codegen::CodegenOptions;
+
+use crate::tester::{default_options, test, test_minify, test_options};
 
 // NOTE: These values are aligned with terser, not esbuild.
 #[test]
@@ -204,8 +206,8 @@ fn test_new() {
     );
     test(
         "new Worker(/* webpackFoo: 1 */ 'path' /* webpackBar:2 */ ,);",
-        "new Worker(\n\t/* webpackFoo: 1 */\n\t\"path\"\n);\n",
-    ); // Not currently handled
+        "new Worker(\n\t/* webpackFoo: 1 */\n\t\"path\" /* webpackBar:2 */\n);\n",
+    );
     test(
         "new Worker(/* webpackFoo: 1 */ 'path', /* webpackBar:2 */ );",
         "new Worker(\n\t/* webpackFoo: 1 */\n\t\"path\"\n\t/* webpackBar:2 */\n);\n",
@@ -272,8 +274,8 @@ fn test_call() {
     );
     test(
         "require(/* webpackFoo: 1 */ 'path' /* webpackBar:2 */ ,);",
-        "require(\n\t/* webpackFoo: 1 */\n\t\"path\"\n);\n",
-    ); // Not currently handled
+        "require(\n\t/* webpackFoo: 1 */\n\t\"path\" /* webpackBar:2 */\n);\n",
+    );
     test(
         "require(/* webpackFoo: 1 */ 'path', /* webpackBar:2 */ );",
         "require(\n\t/* webpackFoo: 1 */\n\t\"path\"\n\t/* webpackBar:2 */\n);\n",
@@ -703,8 +705,8 @@ fn test_import() {
     );
     test(
         "import(/* webpackFoo: 1 */ 'path' /* webpackBar:2 */ ,);",
-        "import(\n\t/* webpackFoo: 1 */\n\t\"path\"\n);\n",
-    ); // Not currently handled
+        "import(\n\t/* webpackFoo: 1 */\n\t\"path\" /* webpackBar:2 */\n);\n",
+    );
     test(
         "import(/* webpackFoo: 1 */ 'path', /* webpackBar:2 */ );",
         "import(\n\t/* webpackFoo: 1 */\n\t\"path\"\n\t/* webpackBar:2 */\n);\n",
@@ -872,52 +874,65 @@ fn minify() {
 
 #[test]
 fn test_ascii_only() {
+    fn test_ascii(source_text: &str, expected: &str) {
+        test_options(
+            source_text,
+            expected,
+            CodegenOptions { ascii_only: true, ..default_options() },
+        );
+    }
+    fn test_minify_ascii(source_text: &str, expected: &str) {
+        let options = CodegenOptions { ascii_only: true, ..CodegenOptions::minify() };
+        test_options(source_text, expected, options);
+    }
+
     test("let π = 'π'", "let π = \"π\";\n");
     test("let π_ = 'π'", "let π_ = \"π\";\n");
     test("let _π = 'π'", "let _π = \"π\";\n");
-    // testASCII(t, "let π = 'π'", "let \\u03C0 = \"\\u03C0\";\n");
-    // testASCII(t, "let π_ = 'π'", "let \\u03C0_ = \"\\u03C0\";\n");
-    // testASCII(t, "let _π = 'π'", "let _\\u03C0 = \"\\u03C0\";\n");
+    test_ascii("let π = 'π'", "let \\u03C0 = \"\\u03C0\";\n");
+    test_ascii("let π_ = 'π'", "let \\u03C0_ = \"\\u03C0\";\n");
+    test_ascii("let _π = 'π'", "let _\\u03C0 = \"\\u03C0\";\n");
 
     test("let 貓 = '🐈'", "let 貓 = \"🐈\";\n");
     test("let 貓abc = '🐈'", "let 貓abc = \"🐈\";\n");
     test("let abc貓 = '🐈'", "let abc貓 = \"🐈\";\n");
-    // testASCII(t, "let 貓 = '🐈'", "let \\u8C93 = \"\\u{1F408}\";\n");
-    // testASCII(t, "let 貓abc = '🐈'", "let \\u8C93abc = \"\\u{1F408}\";\n");
-    // testASCII(t, "let abc貓 = '🐈'", "let abc\\u8C93 = \"\\u{1F408}\";\n");
+    test_ascii("let 貓 = '🐈'", "let \\u8C93 = \"\\uD83D\\uDC08\";\n");
+    test_ascii("let 貓abc = '🐈'", "let \\u8C93abc = \"\\uD83D\\uDC08\";\n");
+    test_ascii("let abc貓 = '🐈'", "let abc\\u8C93 = \"\\uD83D\\uDC08\";\n");
 
     // Test a character outside the BMP
     test("var 𐀀", "var 𐀀;\n");
     test("var \\u{10000}", "var 𐀀;\n");
-    // testASCII(t, "var 𐀀", "var \\u{10000};\n");
-    // testASCII(t, "var \\u{10000}", "var \\u{10000};\n");
-    // testTargetASCII(t, 2015, "'𐀀'", "\"\\u{10000}\";\n");
-    // testTargetASCII(t, 5, "'𐀀'", "\"\\uD800\\uDC00\";\n");
-    // testTargetASCII(t, 2015, "x.𐀀", "x[\"\\u{10000}\"];\n");
-    // testTargetASCII(t, 5, "x.𐀀", "x[\"\\uD800\\uDC00\"];\n");
+    test_ascii("var 𐀀", "var \\u{10000};\n");
+    test_ascii("var \\u{10000}", "var \\u{10000};\n");
+    test_ascii("'𐀀'", "\"\\uD800\\uDC00\";\n");
+    test_ascii("x.𐀀", "x.\\u{10000};\n");
 
     // Escapes should use consistent case
-    // testASCII(
-    // t,
-    // "var \\u{100a} = {\\u100A: '\\u100A'}",
-    // "var \\u100A = { \\u100A: \"\\u100A\" };\n",
-    // );
-    // testASCII(
-    // t,
-    // "var \\u{1000a} = {\\u{1000A}: '\\u{1000A}'}",
-    // "var \\u{1000A} = { \"\\u{1000A}\": \"\\u{1000A}\" };\n",
-    // );
+    test_ascii("var \\u{100a} = {\\u100A: '\\u100A'}", "var \\u100A = { \\u100A: \"\\u100A\" };\n");
+    test_ascii(
+        "var \\u{1000a} = {\\u{1000A}: '\\u{1000A}'}",
+        "var \\u{1000A} = { \\u{1000A}: \"\\uD800\\uDC0A\" };\n",
+    );
 
     // These characters should always be escaped
-    // test( "let x = '\u2028'", "let x = \"\\u2028\";\n");
-    // test( "let x = '\u2029'", "let x = \"\\u2029\";\n");
-    // test( "let x = '\uFEFF'", "let x = \"\\uFEFF\";\n");
+    test("let x = '\u{2028}'", "let x = \"\\u2028\";\n");
+    test("let x = '\u{2029}'", "let x = \"\\u2029\";\n");
+    test_ascii("let x = '\u{FEFF}'", "let x = \"\\uFEFF\";\n");
 
     // There should still be a space before "extends"
-    // testASCII(t, "class 𐀀 extends π {}", "class \\u{10000} extends \\u03C0 {\n}\n");
-    // testASCII(t, "(class 𐀀 extends π {})", "(class \\u{10000} extends \\u03C0 {\n});\n");
-    // test_minifyASCII(t, "class 𐀀 extends π {}", "class \\u{10000} extends \\u03C0{}");
-    // test_minifyASCII(t, "(class 𐀀 extends π {})", "(class \\u{10000} extends \\u03C0{});");
+    test_ascii("class 𐀀 extends π {}", "class \\u{10000} extends \\u03C0 {}\n");
+    test_ascii("(class 𐀀 extends π {})", "(class \\u{10000} extends \\u03C0 {});\n");
+    test_minify_ascii("class 𐀀 extends π {}", "class \\u{10000} extends \\u03C0{}");
+    test_minify_ascii("(class 𐀀 extends π {})", "(class \\u{10000} extends \\u03C0{});");
+
+    // Template literals, regular expressions, comments and JSX
+    test_ascii("`π${π}π`", "`\\u03C0${\\u03C0}\\u03C0`;\n");
+    // The raw text of tagged templates is observable, so it is not escaped.
+    test_ascii("String.raw`π${π}π`", "String.raw`π${\\u03C0}π`;\n");
+    test_ascii("/π/u", "/\\u03C0/u;\n");
+    test_ascii("// π\nx", "// \\u03C0\nx;\n");
+    test_ascii("<a b='π'>π</a>", "<a b=\"&#x3C0;\">&#x3C0;</a>;\n");
 }
 
 #[test]
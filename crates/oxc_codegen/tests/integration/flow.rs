@@ -0,0 +1,53 @@
+use oxc_span::SourceType;
+
+use crate::tester::{default_options, test_options_with_source_type};
+
+#[track_caller]
+fn test_flow(source_text: &str, expected: &str) {
+    let source_type = SourceType::jsx().with_flow(true);
+    test_options_with_source_type(source_text, expected, source_type, default_options());
+}
+
+#[test]
+fn opaque_type() {
+    test_flow("opaque type ID: string = string;", "type ID = string;\n");
+    test_flow("export opaque type Token = string;", "export type Token = string;\n");
+    test_flow("declare opaque type Secret: string;", "declare type Secret = string;\n");
+    test_flow("declare opaque type Hidden;", "declare type Hidden = unknown;\n");
+}
+
+#[test]
+fn declare_module() {
+    test_flow(
+        "declare module 'm' { declare export var x: number; }",
+        "declare module \"m\" {\n\texport declare var x: number;\n}\n",
+    );
+    test_flow(
+        "declare export function f(x: number): string;",
+        "export declare function f(x: number): string;\n",
+    );
+}
+
+#[test]
+fn variance() {
+    test_flow(
+        "class C<+T, -U: Object = {}> { +p: T; q: U; }",
+        "class C<\n\tout T,\n\tin U extends Object = {}\n> {\n\treadonly p: T;\n\tq: U;\n}\n",
+    );
+    test_flow(
+        "type Props = {| +name: string, age: number |};",
+        "type Props = {\n\treadonly name: string;\n\tage: number;\n};\n",
+    );
+}
+
+#[test]
+fn predicate() {
+    test_flow(
+        "function f(x: mixed): boolean %checks { return !!x; }",
+        "function f(x: mixed): boolean {\n\treturn !!x;\n}\n",
+    );
+    test_flow(
+        "declare function g(x: mixed): boolean %checks(typeof x === 'string');",
+        "declare function g(x: mixed): boolean;\n",
+    );
+}
@@ -1,4 +1,4 @@
-use oxc_codegen::CodegenOptions;
+use oxc_codegen::{CodegenOptions, IndentChar};
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -605,3 +605,27 @@ fn v8_intrinsics() {
         parse_opts,
     );
 }
+
+#[test]
+fn indent() {
+    let spaces = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options("if (a) { if (b) { c } }", "if (a) {\n  if (b) {\n    c;\n  }\n}\n", spaces);
+    let tabs = CodegenOptions { indent_width: 2, ..CodegenOptions::default() };
+    test_options("if (a) { b }", "if (a) {\n\t\tb;\n}\n", tabs);
+}
+
+#[test]
+fn max_line_length() {
+    let options = CodegenOptions { max_line_length: Some(10), ..CodegenOptions::minify() };
+    test_options(
+        "foo(aaaa, bbbb, cccc, dddd); bar(); baz()",
+        "foo(aaaa,bbbb,\ncccc,dddd);\nbar();baz();\n",
+        options.clone(),
+    );
+    // Strings and templates are never broken
+    test_options("x = ['aaaaaaaaaa,b', `c,d`]", "x=[`aaaaaaaaaa,b`,\n`c,d`];", options);
+}
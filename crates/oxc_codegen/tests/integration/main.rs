@@ -1,6 +1,7 @@
 #![expect(clippy::missing_panics_doc, clippy::literal_string_with_formatting_args)]
 pub mod comments;
 pub mod esbuild;
+pub mod flow;
 pub mod js;
 pub mod sourcemap;
 pub mod ts;
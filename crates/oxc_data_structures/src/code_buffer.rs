@@ -187,6 +187,26 @@ impl CodeBuffer {
         self.peek_nth_char_back(0)
     }
 
+    /// Remove the last byte from the buffer, if it is an ASCII byte.
+    ///
+    /// Returns `None` if the buffer is empty, or if the last byte is not ASCII
+    /// (removing it would leave an invalid UTF-8 string in the buffer).
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::code_buffer::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("foo\n");
+    /// assert_eq!(code.pop_ascii_byte(), Some(b'\n'));
+    ///
+    /// let source = code.into_string();
+    /// assert_eq!(source, "foo");
+    /// ```
+    #[inline]
+    pub fn pop_ascii_byte(&mut self) -> Option<u8> {
+        if self.buf.last()?.is_ascii() { self.buf.pop() } else { None }
+    }
+
     /// Push a single ASCII byte into the buffer.
     ///
     /// # Panics
@@ -5,11 +5,21 @@ use std::{fs, path::Path};
 use oxc_allocator::Allocator;
 use oxc_formatter::{FormatOptions, Formatter};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
 use pico_args::Arguments;
 
 fn main() -> Result<(), String> {
     let mut args = Arguments::from_env();
+    // Format only the statements in a byte range, e.g. `--range 10..20`
+    let range = args
+        .opt_value_from_fn("--range", |range: &str| {
+            let (start, end) = range.split_once("..").ok_or("expected `start..end`")?;
+            Ok::<_, String>(Span::new(
+                start.parse().map_err(|_| "invalid start")?,
+                end.parse().map_err(|_| "invalid end")?,
+            ))
+        })
+        .map_err(|err| err.to_string())?;
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -31,7 +41,16 @@ fn main() -> Result<(), String> {
     }
 
     let options = FormatOptions::default();
-    let code = Formatter::new(&allocator, options).build(&ret.program);
+    let formatter = Formatter::new(&allocator, options);
+    let code = if let Some(range) = range {
+        let Some(edit) = formatter.build_range(&ret.program, range) else {
+            return Err("No statement in the range".to_string());
+        };
+        let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+        format!("{}{}{}", &source_text[..start], edit.text, &source_text[end..])
+    } else {
+        formatter.build(&ret.program)
+    };
 
     println!("{code}");
 
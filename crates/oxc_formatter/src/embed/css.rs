@@ -0,0 +1,610 @@
+//! A formatter for CSS embedded in JavaScript, e.g. with styled-components, where declarations
+//! can be at the top level and rules can be nested.
+
+use cow_utils::CowUtils;
+
+use super::EmbeddedFormatter;
+use crate::options::{FormatOptions, IndentStyle, QuoteStyle};
+
+const PLACEHOLDER: &str = "@prettier-placeholder-";
+
+/// The built-in formatter of CSS, which follows the style of Prettier.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CssFormatter;
+
+impl EmbeddedFormatter for CssFormatter {
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String> {
+        let items = Parser { source_text, position: 0 }.parse_block(true)?;
+        let mut printer = Printer::new(options);
+        printer.print_items(&items, 0);
+        Some(printer.output)
+    }
+}
+
+/// A node with the whitespace before it.
+struct Item<'s> {
+    node: Node<'s>,
+    /// Whether there is an empty line between the previous node and this one.
+    empty_line_before: bool,
+    /// Whether this node starts on the line on which the previous node ends.
+    same_line: bool,
+}
+
+enum Node<'s> {
+    Declaration {
+        property: &'s str,
+        value: &'s str,
+    },
+    Rule {
+        selector: &'s str,
+        children: Vec<Item<'s>>,
+    },
+    AtRule {
+        name: &'s str,
+        params: &'s str,
+        children: Option<Vec<Item<'s>>>,
+    },
+    Comment(&'s str),
+    /// Any other statement, e.g. a placeholder of a mixin.
+    Other {
+        text: &'s str,
+        semicolon: bool,
+    },
+}
+
+enum Terminator {
+    Semicolon,
+    Block,
+    /// The end of the block, the end of the text or a line break after a placeholder.
+    None,
+}
+
+struct Parser<'s> {
+    source_text: &'s str,
+    position: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn rest(&self) -> &'s str {
+        &self.source_text[self.position..]
+    }
+
+    /// Parses the nodes of a block, until its closing `}` or, for the top level, the end of
+    /// the text. Returns `None` if the block can't be parsed.
+    fn parse_block(&mut self, top_level: bool) -> Option<Vec<Item<'s>>> {
+        let mut items = vec![];
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            let whitespace = &rest[..rest.len() - trimmed.len()];
+            self.position += whitespace.len();
+            let newlines = whitespace.matches('\n').count();
+            let empty_line_before = newlines > 1 && !items.is_empty();
+            let same_line = newlines == 0 && !items.is_empty();
+
+            let rest = self.rest();
+            if rest.is_empty() {
+                return top_level.then_some(items);
+            }
+            if rest.starts_with('}') {
+                if top_level {
+                    return None;
+                }
+                self.position += 1;
+                return Some(items);
+            }
+            if rest.starts_with(';') {
+                self.position += 1;
+                continue;
+            }
+
+            let node = if let Some(comment) = rest.strip_prefix("/*") {
+                let end = comment.find("*/")? + 4;
+                self.position += end;
+                Node::Comment(&rest[..end])
+            } else if rest.starts_with("//") {
+                let end = rest.find('\n').unwrap_or(rest.len());
+                self.position += end;
+                Node::Comment(rest[..end].trim_end())
+            } else {
+                self.parse_statement()?
+            };
+            items.push(Item { node, empty_line_before, same_line });
+        }
+    }
+
+    fn parse_statement(&mut self) -> Option<Node<'s>> {
+        let start = self.position;
+        let (end, terminator) = self.scan_statement()?;
+        let text = self.source_text[start..end].trim();
+
+        match terminator {
+            Terminator::Block => {
+                let children = self.parse_block(false)?;
+                if text.starts_with('@') && !text.starts_with(PLACEHOLDER) {
+                    let (name, params) = split_at_rule(text);
+                    Some(Node::AtRule { name, params, children: Some(children) })
+                } else {
+                    Some(Node::Rule { selector: text, children })
+                }
+            }
+            Terminator::Semicolon | Terminator::None => {
+                let semicolon = matches!(terminator, Terminator::Semicolon);
+                if text.starts_with('@') && !text.starts_with(PLACEHOLDER) {
+                    let (name, params) = split_at_rule(text);
+                    Some(Node::AtRule { name, params, children: None })
+                } else if let Some(colon) = find_top_level(text, ':') {
+                    let property = text[..colon].trim_end();
+                    let value = text[colon + 1..].trim_start();
+                    Some(Node::Declaration { property, value })
+                } else {
+                    Some(Node::Other { text, semicolon })
+                }
+            }
+        }
+    }
+
+    /// Finds the end of the statement at the current position, and moves after its terminator.
+    fn scan_statement(&mut self) -> Option<(usize, Terminator)> {
+        let source_text = self.source_text;
+        let start = self.position;
+        let bytes = source_text.as_bytes();
+        let mut depth = 0usize;
+        let mut index = start;
+        while index < bytes.len() {
+            match bytes[index] {
+                quote @ (b'"' | b'\'') => index = skip_string(source_text, index, quote)?,
+                b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                    index += source_text[index + 2..].find("*/")? + 4;
+                }
+                // A line comment in a statement can't be moved safely.
+                b'/' if bytes.get(index + 1) == Some(&b'/') && depth == 0 => return None,
+                b'(' | b'[' => {
+                    depth += 1;
+                    index += 1;
+                }
+                b'#' if bytes.get(index + 1) == Some(&b'{') => {
+                    depth += 1;
+                    index += 2;
+                }
+                b')' | b']' => {
+                    depth = depth.checked_sub(1)?;
+                    index += 1;
+                }
+                b'}' if depth > 0 => {
+                    depth -= 1;
+                    index += 1;
+                }
+                b';' if depth == 0 => {
+                    self.position = index + 1;
+                    return Some((index, Terminator::Semicolon));
+                }
+                b'{' if depth == 0 => {
+                    self.position = index + 1;
+                    return Some((index, Terminator::Block));
+                }
+                b'}' => {
+                    self.position = index;
+                    return Some((index, Terminator::None));
+                }
+                b'\n' if depth == 0 && is_placeholder(source_text[start..index].trim()) => {
+                    let next = source_text[index..].trim_start();
+                    if !next.starts_with('{') {
+                        self.position = index;
+                        return Some((index, Terminator::None));
+                    }
+                    index += 1;
+                }
+                _ => index += 1,
+            }
+        }
+        (depth == 0).then(|| {
+            self.position = bytes.len();
+            (bytes.len(), Terminator::None)
+        })
+    }
+}
+
+/// Returns the index after the string starting at `start`.
+fn skip_string(text: &str, start: usize, quote: u8) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'\n' => return None,
+            byte if byte == quote => return Some(index + 1),
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+fn is_placeholder(text: &str) -> bool {
+    text.strip_prefix(PLACEHOLDER)
+        .is_some_and(|index| !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+/// Splits `@media (x)` into its name and its params.
+fn split_at_rule(text: &str) -> (&str, &str) {
+    let end = text[1..]
+        .find(|c: char| c.is_whitespace() || matches!(c, '(' | '"' | '\''))
+        .map_or(text.len(), |index| index + 1);
+    (&text[..end], text[end..].trim())
+}
+
+/// Returns the index of the first `needle` which is outside of strings, parentheses and
+/// brackets.
+fn find_top_level(text: &str, needle: char) -> Option<usize> {
+    split_top_level(text, needle).next().and_then(|first| {
+        let end = first.len();
+        (end < text.len()).then_some(end)
+    })
+}
+
+/// Splits `text` at each `separator` which is outside of strings, parentheses and brackets.
+fn split_top_level(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    let mut index = 0;
+    let mut depth = 0usize;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        while index < bytes.len() {
+            let byte = bytes[index];
+            match byte {
+                b'"' | b'\'' => {
+                    index = skip_string(text, index, byte).unwrap_or(bytes.len());
+                    continue;
+                }
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                _ if depth == 0 && char::from(byte) == separator => {
+                    let part = &text[start..index];
+                    index += 1;
+                    start = index;
+                    return Some(part);
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        done = true;
+        Some(&text[start..])
+    })
+}
+
+struct Printer<'o> {
+    options: &'o FormatOptions,
+    indent: String,
+    output: String,
+}
+
+impl<'o> Printer<'o> {
+    fn new(options: &'o FormatOptions) -> Self {
+        let indent = match options.indent_style {
+            IndentStyle::Tab => "\t".to_string(),
+            IndentStyle::Space => " ".repeat(usize::from(options.indent_width.value())),
+        };
+        Self { options, indent, output: String::new() }
+    }
+
+    fn new_line(&mut self, level: usize) {
+        self.output.push('\n');
+        self.write_indent(level);
+    }
+
+    fn write_indent(&mut self, level: usize) {
+        for _ in 0..level {
+            self.output.push_str(&self.indent);
+        }
+    }
+
+    fn print_items(&mut self, items: &[Item<'_>], level: usize) {
+        for (index, item) in items.iter().enumerate() {
+            if index == 0 {
+                self.write_indent(level);
+            } else if item.same_line && matches!(item.node, Node::Comment(_)) {
+                self.output.push(' ');
+            } else {
+                if item.empty_line_before {
+                    self.output.push('\n');
+                }
+                self.new_line(level);
+            }
+            self.print_node(&item.node, level);
+        }
+    }
+
+    fn print_node(&mut self, node: &Node<'_>, level: usize) {
+        match node {
+            Node::Declaration { property, value } => self.print_declaration(property, value, level),
+            Node::Rule { selector, children } => {
+                let selectors = split_top_level(selector, ',')
+                    .map(format_selector)
+                    .filter(|selector| !selector.is_empty())
+                    .collect::<Vec<_>>();
+                for (index, selector) in selectors.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push(',');
+                        self.new_line(level);
+                    }
+                    self.output.push_str(selector);
+                }
+                self.output.push_str(" {");
+                self.print_block(children, level);
+            }
+            Node::AtRule { name, params, children } => {
+                self.output.push_str(&name.cow_to_ascii_lowercase());
+                if !params.is_empty() {
+                    self.output.push(' ');
+                    self.output.push_str(&space_after_colons(&self.format_value(params)));
+                }
+                if let Some(children) = children {
+                    self.output.push_str(" {");
+                    self.print_block(children, level);
+                } else {
+                    self.output.push(';');
+                }
+            }
+            Node::Comment(comment) => self.output.push_str(comment),
+            Node::Other { text, semicolon } => {
+                self.output.push_str(&collapse_whitespace(text));
+                if *semicolon {
+                    self.output.push(';');
+                }
+            }
+        }
+    }
+
+    fn print_block(&mut self, children: &[Item<'_>], level: usize) {
+        if !children.is_empty() {
+            self.output.push('\n');
+            self.print_items(children, level + 1);
+        }
+        self.new_line(level);
+        self.output.push('}');
+    }
+
+    fn print_declaration(&mut self, property: &str, value: &str, level: usize) {
+        // Custom properties, preprocessor variables and interpolations keep their case.
+        if property.starts_with("--") || property.contains(['$', '@', '#']) {
+            self.output.push_str(property);
+        } else {
+            self.output.push_str(&property.cow_to_ascii_lowercase());
+        }
+        self.output.push(':');
+
+        let is_custom_property = property.starts_with("--");
+        let is_grid = property.get(..4).is_some_and(|start| start.eq_ignore_ascii_case("grid"));
+        if is_grid && value.contains('\n') {
+            // The lines of grid templates are kept.
+            for line in value.lines().map(str::trim).filter(|line| !line.is_empty()) {
+                self.new_line(level + 1);
+                self.output.push_str(&self.format_value(line));
+            }
+        } else {
+            let values = split_top_level(value, ',').map(str::trim).collect::<Vec<_>>();
+            // Like Prettier, a list of values with several words is printed one value per line.
+            let multi_value = !is_custom_property
+                && values.len() > 1
+                && values.iter().any(|value| split_top_level(value, ' ').count() > 1);
+            if multi_value {
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push(',');
+                    }
+                    self.new_line(level + 1);
+                    self.output.push_str(&self.format_value(value));
+                }
+            } else if !value.is_empty() {
+                self.output.push(' ');
+                self.output.push_str(&self.format_value(value));
+            }
+        }
+        self.output.push(';');
+    }
+
+    /// Normalizes the whitespace, the commas, the numbers and the quotes of a value.
+    fn format_value(&self, value: &str) -> String {
+        let mut output = String::with_capacity(value.len());
+        let mut chars = value.char_indices().peekable();
+        let mut pending_space = false;
+        while let Some((index, c)) = chars.next() {
+            if c.is_whitespace() {
+                pending_space = true;
+                continue;
+            }
+            if pending_space
+                && !matches!(c, ')' | ']' | ',')
+                && !matches!(output.chars().last(), None | Some('(' | '['))
+            {
+                output.push(' ');
+            }
+            pending_space = false;
+            let previous = output.chars().last();
+
+            match c {
+                '"' | '\'' => {
+                    let end =
+                        skip_string(value, index, value.as_bytes()[index]).unwrap_or(value.len());
+                    output.push_str(&self.format_string(&value[index..end]));
+                    while chars.peek().is_some_and(|&(next, _)| next < end) {
+                        chars.next();
+                    }
+                }
+                '/' if value[index..].starts_with("/*") => {
+                    let end =
+                        value[index + 2..].find("*/").map_or(value.len(), |end| index + end + 4);
+                    output.push_str(&value[index..end]);
+                    while chars.peek().is_some_and(|&(next, _)| next < end) {
+                        chars.next();
+                    }
+                }
+                // The content of `url()` is kept as is.
+                '(' if output.len() >= 3
+                    && output
+                        .get(output.len() - 3..)
+                        .is_some_and(|end| end.eq_ignore_ascii_case("url")) =>
+                {
+                    let end = value[index..].find(')').map_or(value.len(), |end| index + end + 1);
+                    output.push('(');
+                    output.push_str(value[index + 1..end].trim_end_matches(')').trim());
+                    output.push(')');
+                    while chars.peek().is_some_and(|&(next, _)| next < end) {
+                        chars.next();
+                    }
+                }
+                ',' => {
+                    output.push(',');
+                    pending_space = true;
+                }
+                '0'..='9' | '.' if is_number_start(value, index, previous) => {
+                    let end = value[index..]
+                        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                        .map_or(value.len(), |end| index + end);
+                    output.push_str(&format_number(&value[index..end]));
+                    while chars.peek().is_some_and(|&(next, _)| next < end) {
+                        chars.next();
+                    }
+                }
+                _ => output.push(c),
+            }
+        }
+        if let Some(bang) = output.rfind('!') {
+            if output[bang + 1..].trim_start().eq_ignore_ascii_case("important") {
+                let before = output[..bang].trim_end();
+                output = if before.is_empty() {
+                    "!important".to_string()
+                } else {
+                    format!("{before} !important")
+                };
+            }
+        }
+        output
+    }
+
+    /// Uses the preferred quotes, unless the string contains more of them than of the others.
+    fn format_string(&self, string: &str) -> String {
+        let content = &string[1..string.len() - 1];
+        let preferred = match self.options.quote_style {
+            QuoteStyle::Double => '"',
+            QuoteStyle::Single => '\'',
+        };
+        if content.contains(preferred) {
+            string.to_string()
+        } else {
+            format!("{preferred}{content}{preferred}")
+        }
+    }
+}
+
+/// Whether a number starts at `index`, and not in an identifier, a hex color or a
+/// placeholder.
+fn is_number_start(value: &str, index: usize, previous: Option<char>) -> bool {
+    let is_word = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '#' | '@' | '$' | '.');
+    if value.as_bytes()[index] == b'.'
+        && !value[index + 1..].starts_with(|c: char| c.is_ascii_digit())
+    {
+        return false;
+    }
+    match previous {
+        Some('-') => {
+            let before_minus = value[..index].trim_end_matches('-').chars().last();
+            !before_minus.is_some_and(|c| is_word(c) || c == '-')
+        }
+        Some(c) => !is_word(c),
+        None => true,
+    }
+}
+
+/// Prints numbers like Prettier: `.5` is `0.5`, `1.50` is `1.5` and `1.0` is `1`.
+fn format_number(number: &str) -> String {
+    let mut number = number.to_string();
+    if number.starts_with('.') {
+        number.insert(0, '0');
+    }
+    if number.contains('.') {
+        number.truncate(number.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    number
+}
+
+/// Prints each selector of a list on its own line, with spaces around combinators.
+fn format_selector(selector: &str) -> String {
+    let mut output = String::with_capacity(selector.len());
+    let mut depth = 0usize;
+    let mut pending_space = false;
+    let bytes = selector.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        let c = char::from(bytes[index]);
+        if !c.is_ascii() {
+            let c = selector[index..].chars().next().unwrap_or_default();
+            output.push(c);
+            index += c.len_utf8();
+            continue;
+        }
+        match c {
+            '"' | '\'' => {
+                let end = skip_string(selector, index, bytes[index]).unwrap_or(bytes.len());
+                if pending_space && !output.is_empty() {
+                    output.push(' ');
+                }
+                pending_space = false;
+                output.push_str(&selector[index..end]);
+                index = end;
+                continue;
+            }
+            c if c.is_whitespace() => pending_space = true,
+            '>' | '+' | '~' if depth == 0 => {
+                if !output.is_empty() {
+                    output.push(' ');
+                }
+                output.push(c);
+                output.push(' ');
+                pending_space = false;
+            }
+            _ => {
+                if pending_space && !output.is_empty() && !output.ends_with([' ', '(', '[']) {
+                    output.push(' ');
+                }
+                pending_space = false;
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                output.push(c);
+            }
+        }
+        index += 1;
+    }
+    output.trim().to_string()
+}
+
+/// Adds a space after the colons of media features, e.g. `(max-width: 600px)`.
+fn space_after_colons(params: &str) -> String {
+    let mut output = String::with_capacity(params.len());
+    let mut depth = 0usize;
+    let mut chars = params.chars().peekable();
+    while let Some(c) = chars.next() {
+        output.push(c);
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ':' if depth > 0 && chars.peek().is_some_and(|next| !next.is_whitespace()) => {
+                output.push(' ');
+            }
+            _ => {}
+        }
+    }
+    output
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,577 @@
+//! A formatter for GraphQL documents, which follows the style of Prettier's GraphQL printer.
+//!
+//! The document is only tokenized, and the tokens are grouped by their brackets: definitions,
+//! selections and arguments are recognized from the tokens which start them.
+
+use super::EmbeddedFormatter;
+use crate::options::{FormatOptions, IndentStyle};
+
+/// The built-in formatter of GraphQL.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GraphqlFormatter;
+
+impl EmbeddedFormatter for GraphqlFormatter {
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String> {
+        let tokens = tokenize(source_text)?;
+        let mut tokens = tokens.into_iter();
+        let trees = build_trees(&mut tokens, None)?;
+        let mut printer = Printer::new(options);
+        printer.print_lines(&trees, Context::Document, 0)?;
+        Some(printer.output)
+    }
+}
+
+const DEFINITION_KEYWORDS: [&str; 12] = [
+    "query",
+    "mutation",
+    "subscription",
+    "fragment",
+    "schema",
+    "scalar",
+    "type",
+    "interface",
+    "union",
+    "enum",
+    "input",
+    "directive",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Punctuator,
+    Name,
+    Number,
+    String,
+    BlockString,
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'s> {
+    kind: TokenKind,
+    text: &'s str,
+    /// Whether the token is on a line after the previous token.
+    line_break_before: bool,
+    /// Whether there is an empty line between the previous token and this one.
+    empty_line_before: bool,
+}
+
+impl Token<'_> {
+    fn is(&self, punctuator: &str) -> bool {
+        self.kind == TokenKind::Punctuator && self.text == punctuator
+    }
+
+    fn is_string(&self) -> bool {
+        matches!(self.kind, TokenKind::String | TokenKind::BlockString)
+    }
+}
+
+/// Splits the source text into tokens, commas being insignificant like whitespace. Returns
+/// `None` for an invalid token.
+fn tokenize(source_text: &str) -> Option<Vec<Token<'_>>> {
+    let bytes = source_text.as_bytes();
+    let mut tokens = vec![];
+    let mut index = 0;
+    let mut newlines = 0;
+    while index < bytes.len() {
+        let start = index;
+        let kind = match bytes[index] {
+            b'\n' => {
+                newlines += 1;
+                index += 1;
+                continue;
+            }
+            b' ' | b'\t' | b'\r' | b',' => {
+                index += 1;
+                continue;
+            }
+            b'#' => {
+                index = source_text[index..].find('\n').map_or(bytes.len(), |end| index + end);
+                TokenKind::Comment
+            }
+            b'"' if source_text[index..].starts_with("\"\"\"") => {
+                let mut end = index + 3;
+                loop {
+                    let offset = source_text[end..].find("\"\"\"")?;
+                    end += offset;
+                    if bytes[end - 1] != b'\\' {
+                        break;
+                    }
+                    end += 3;
+                }
+                index = end + 3;
+                TokenKind::BlockString
+            }
+            b'"' => {
+                index += 1;
+                loop {
+                    match bytes.get(index)? {
+                        b'\\' => index += 2,
+                        b'"' => break,
+                        b'\n' => return None,
+                        _ => index += 1,
+                    }
+                }
+                index += 1;
+                TokenKind::String
+            }
+            b'.' if source_text[index..].starts_with("...") => {
+                index += 3;
+                TokenKind::Punctuator
+            }
+            b'!' | b'$' | b'&' | b'(' | b')' | b':' | b'=' | b'@' | b'[' | b']' | b'{' | b'|'
+            | b'}' => {
+                index += 1;
+                TokenKind::Punctuator
+            }
+            b'-' | b'0'..=b'9' => {
+                index += 1;
+                while index < bytes.len()
+                    && (bytes[index].is_ascii_alphanumeric()
+                        || matches!(bytes[index], b'.' | b'+' | b'-'))
+                {
+                    index += 1;
+                }
+                TokenKind::Number
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                while index < bytes.len()
+                    && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_')
+                {
+                    index += 1;
+                }
+                TokenKind::Name
+            }
+            _ => return None,
+        };
+        tokens.push(Token {
+            kind,
+            text: &source_text[start..index],
+            line_break_before: newlines > 0,
+            empty_line_before: newlines > 1,
+        });
+        newlines = 0;
+    }
+    Some(tokens)
+}
+
+/// A token, or tokens between brackets.
+enum Tree<'s> {
+    Token(Token<'s>),
+    Group { open: Token<'s>, children: Vec<Tree<'s>> },
+}
+
+impl<'s> Tree<'s> {
+    fn first_token(&self) -> &Token<'s> {
+        match self {
+            Self::Token(token) | Self::Group { open: token, .. } => token,
+        }
+    }
+
+    fn is(&self, punctuator: &str) -> bool {
+        matches!(self, Self::Token(token) if token.is(punctuator))
+    }
+
+    fn is_name(&self, name: &str) -> bool {
+        matches!(self, Self::Token(token) if token.kind == TokenKind::Name && token.text == name)
+    }
+
+    fn is_group(&self, open: &str) -> bool {
+        matches!(self, Self::Group { open: token, .. } if token.text == open)
+    }
+
+    fn is_comment(&self) -> bool {
+        matches!(self, Self::Token(token) if token.kind == TokenKind::Comment)
+    }
+}
+
+fn build_trees<'s>(
+    tokens: &mut impl Iterator<Item = Token<'s>>,
+    close: Option<&str>,
+) -> Option<Vec<Tree<'s>>> {
+    let mut trees = vec![];
+    while let Some(token) = tokens.next() {
+        if token.kind == TokenKind::Punctuator {
+            let expected_close = match token.text {
+                "{" => Some("}"),
+                "(" => Some(")"),
+                "[" => Some("]"),
+                "}" | ")" | "]" => {
+                    return (close == Some(token.text) && is_complete(&trees)).then_some(trees);
+                }
+                _ => None,
+            };
+            if let Some(expected_close) = expected_close {
+                let children = build_trees(tokens, Some(expected_close))?;
+                trees.push(Tree::Group { open: token, children });
+                continue;
+            }
+        }
+        trees.push(Tree::Token(token));
+    }
+    (close.is_none() && is_complete(&trees)).then_some(trees)
+}
+
+/// Returns `false` if the trees of a document or a group end with a punctuator which must be
+/// followed by something, e.g. `{ alias: }`.
+fn is_complete(trees: &[Tree<'_>]) -> bool {
+    let last = trees.iter().rev().find(|tree| !tree.is_comment());
+    !last.is_some_and(|tree| {
+        ["...", ":", "=", "@", "$", "|", "&"].iter().any(|punctuator| tree.is(punctuator))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Context {
+    /// The definitions of the document.
+    Document,
+    /// A selection set, or the body of a type, an enum or a schema.
+    Block,
+    /// Arguments, variable definitions and argument definitions.
+    Arguments,
+    List,
+    Object,
+}
+
+impl Context {
+    const fn is_block_like(self) -> bool {
+        matches!(self, Self::Document | Self::Block)
+    }
+}
+
+/// Splits the trees of a document or a group into its definitions, selections, fields,
+/// arguments or values, recognized from the tokens which start them.
+fn split_items<'t, 's>(trees: &'t [Tree<'s>], context: Context) -> Vec<&'t [Tree<'s>]> {
+    let mut items = vec![];
+    let mut start = 0;
+    for (index, tree) in trees.iter().enumerate() {
+        if index == start {
+            continue;
+        }
+        let item = &trees[start..index];
+        let is_comment = tree.is_comment() || item.last().is_some_and(Tree::is_comment);
+        if is_comment || starts_item(item, tree, context) {
+            items.push(item);
+            start = index;
+        }
+    }
+    if start < trees.len() {
+        items.push(&trees[start..]);
+    }
+    items
+}
+
+fn starts_item(item: &[Tree<'_>], tree: &Tree<'_>, context: Context) -> bool {
+    let Some(previous) = item.last() else { return false };
+
+    if context == Context::List {
+        return !tree.is("!") && !previous.is("$");
+    }
+
+    // Whether the previous tree ends a definition, a field, an argument or a value.
+    let is_complete = match previous {
+        Tree::Group { .. } => true,
+        Tree::Token(previous) => match previous.kind {
+            TokenKind::Name => {
+                // `... on Type`
+                let is_type_condition =
+                    previous.text == "on" && item.len() > 1 && item[item.len() - 2].is("...");
+                let continues_definition = context == Context::Document
+                    && matches!(previous.text, "extend" | "implements" | "on");
+                !is_type_condition && !continues_definition
+            }
+            TokenKind::Number | TokenKind::Comment => true,
+            // A string at the start is a description.
+            TokenKind::String | TokenKind::BlockString => item.len() > 1,
+            TokenKind::Punctuator => previous.text == "!",
+        },
+    };
+    if !is_complete {
+        return false;
+    }
+
+    match (context, tree) {
+        // `{ ... }` is a query only after another definition.
+        (Context::Document, Tree::Group { .. }) => previous.is_group("{"),
+        (Context::Document, Tree::Token(token)) => {
+            token.is_string()
+                || token.kind == TokenKind::Name
+                    && (DEFINITION_KEYWORDS.contains(&token.text) || token.text == "extend")
+        }
+        (_, Tree::Token(token)) => {
+            matches!(token.kind, TokenKind::Name | TokenKind::String | TokenKind::BlockString)
+                || token.is("...")
+                || token.is("$")
+        }
+        (_, Tree::Group { .. }) => false,
+    }
+}
+
+struct Printer<'o> {
+    options: &'o FormatOptions,
+    indent: String,
+    line_width: usize,
+    output: String,
+}
+
+impl<'o> Printer<'o> {
+    fn new(options: &'o FormatOptions) -> Self {
+        let indent = match options.indent_style {
+            IndentStyle::Tab => "\t".to_string(),
+            IndentStyle::Space => " ".repeat(usize::from(options.indent_width.value())),
+        };
+        let line_width = usize::from(options.line_width.value());
+        Self { options, indent, line_width, output: String::new() }
+    }
+
+    /// A printer for the flat version of a group, without line breaks.
+    fn flat(&self) -> Self {
+        Self {
+            options: self.options,
+            indent: String::new(),
+            line_width: usize::MAX,
+            output: String::new(),
+        }
+    }
+
+    fn is_flat(&self) -> bool {
+        self.line_width == usize::MAX
+    }
+
+    fn column(&self) -> usize {
+        let line = self.output.rfind('\n').map_or(&*self.output, |index| &self.output[index + 1..]);
+        let tab_width = usize::from(self.options.indent_width.value());
+        line.chars().map(|c| if c == '\t' { tab_width } else { 1 }).sum()
+    }
+
+    fn new_line(&mut self, level: usize) {
+        self.output.push('\n');
+        for _ in 0..level {
+            self.output.push_str(&self.indent);
+        }
+    }
+
+    /// Prints each item on its own line, keeping empty lines between them.
+    fn print_lines(&mut self, trees: &[Tree<'_>], context: Context, level: usize) -> Option<()> {
+        for (index, item) in split_items(trees, context).into_iter().enumerate() {
+            let first = item[0].first_token();
+            if index > 0 {
+                if first.kind == TokenKind::Comment && !first.line_break_before {
+                    self.output.push(' ');
+                    self.output.push_str(first.text.trim_end());
+                    continue;
+                }
+                if first.empty_line_before && context.is_block_like() {
+                    self.output.push('\n');
+                }
+                self.new_line(level);
+            } else if level > 0 || !self.output.is_empty() {
+                self.new_line(level);
+            }
+            self.print_item(item, context, level)?;
+        }
+        Some(())
+    }
+
+    fn print_item(&mut self, item: &[Tree<'_>], context: Context, level: usize) -> Option<()> {
+        for (index, tree) in item.iter().enumerate() {
+            if index > 0 {
+                let previous = &item[index - 1];
+                if index == 1 && previous.first_token().is_string() && !tree.is_group("{") {
+                    // A description is on its own line.
+                    if self.is_flat() {
+                        return None;
+                    }
+                    self.new_line(level);
+                } else if needs_space(previous, tree) {
+                    self.output.push(' ');
+                }
+            }
+            match tree {
+                Tree::Token(token) => self.print_token(token, level)?,
+                Tree::Group { open, children } => {
+                    let group_context =
+                        group_context(open, context, index.checked_sub(1).map(|i| &item[i]));
+                    let rest = self.rest_width(&item[index + 1..], tree, context);
+                    self.print_group(children, group_context, level, rest)?;
+                }
+            }
+        }
+        Some(())
+    }
+
+    /// The width of the trees after a group on its line, up to a selection set or a body.
+    fn rest_width(&self, trees: &[Tree<'_>], group: &Tree<'_>, context: Context) -> usize {
+        let mut width = 0;
+        let mut previous = group;
+        for tree in trees {
+            if needs_space(previous, tree) {
+                width += 1;
+            }
+            match tree {
+                Tree::Token(token) => width += token.text.len(),
+                Tree::Group { open, children } => {
+                    let group_context = group_context(open, context, Some(previous));
+                    if group_context == Context::Block {
+                        return width + 1;
+                    }
+                    let mut flat = self.flat();
+                    if flat.print_flat_items(children, group_context).is_none() {
+                        return width;
+                    }
+                    width += flat.output.len();
+                }
+            }
+            previous = tree;
+        }
+        width
+    }
+
+    fn print_token(&mut self, token: &Token<'_>, level: usize) -> Option<()> {
+        match token.kind {
+            TokenKind::BlockString => {
+                if self.is_flat() {
+                    return None;
+                }
+                let content = &token.text[3..token.text.len() - 3];
+                let lines = block_string_lines(content);
+                self.output.push_str("\"\"\"");
+                for line in lines {
+                    if line.is_empty() {
+                        self.output.push('\n');
+                    } else {
+                        self.new_line(level);
+                        self.output.push_str(line);
+                    }
+                }
+                self.new_line(level);
+                self.output.push_str("\"\"\"");
+            }
+            TokenKind::Comment => {
+                if self.is_flat() {
+                    return None;
+                }
+                self.output.push_str(token.text.trim_end());
+            }
+            _ => self.output.push_str(token.text),
+        }
+        Some(())
+    }
+
+    /// Prints a group on one line if it fits, else each of its items on its own line.
+    fn print_group(
+        &mut self,
+        children: &[Tree<'_>],
+        context: Context,
+        level: usize,
+        rest: usize,
+    ) -> Option<()> {
+        let (open, close) = match context {
+            Context::Arguments => ("(", ")"),
+            Context::List => ("[", "]"),
+            Context::Document | Context::Block | Context::Object => ("{", "}"),
+        };
+
+        if children.is_empty() {
+            self.output.push_str(open);
+            self.output.push_str(close);
+            return Some(());
+        }
+
+        if self.is_flat() {
+            return self.print_flat_items(children, context);
+        }
+
+        if context != Context::Block {
+            let mut flat = self.flat();
+            if flat.print_flat_items(children, context).is_some()
+                && self.column() + flat.output.len() + rest <= self.line_width
+            {
+                self.output.push_str(&flat.output);
+                return Some(());
+            }
+        }
+
+        self.output.push_str(open);
+        self.print_lines(children, context, level + 1)?;
+        self.new_line(level);
+        self.output.push_str(close);
+        Some(())
+    }
+
+    fn print_flat_items(&mut self, children: &[Tree<'_>], context: Context) -> Option<()> {
+        let (open, close) = match context {
+            Context::Arguments => ("(", ")"),
+            Context::List => ("[", "]"),
+            Context::Object => ("{ ", " }"),
+            Context::Document | Context::Block => return None,
+        };
+        self.output.push_str(open);
+        for (index, item) in split_items(children, context).into_iter().enumerate() {
+            if index > 0 {
+                self.output.push_str(", ");
+            }
+            self.print_item(item, context, 0)?;
+        }
+        self.output.push_str(close);
+        Some(())
+    }
+}
+
+/// The context of the items in a group, from its opening bracket, the context of the group
+/// and the tree before it.
+fn group_context(open: &Token<'_>, context: Context, previous: Option<&Tree<'_>>) -> Context {
+    match open.text {
+        "(" => Context::Arguments,
+        "[" => Context::List,
+        _ if matches!(context, Context::Arguments | Context::List | Context::Object)
+            || previous.is_some_and(|previous| previous.is(":") || previous.is("=")) =>
+        {
+            Context::Object
+        }
+        _ => Context::Block,
+    }
+}
+
+fn needs_space(previous: &Tree<'_>, tree: &Tree<'_>) -> bool {
+    if previous.is("$") || previous.is("@") {
+        return false;
+    }
+    if previous.is("...") {
+        // `...Fragment`, but `... on Type` and `... @include(if: $a)`
+        return tree.is_name("on")
+            || !matches!(tree, Tree::Token(token) if token.kind == TokenKind::Name);
+    }
+    !(tree.is("!") || tree.is(":") || tree.is_group("("))
+}
+
+/// Returns the lines of a block string without their common indentation, and without the
+/// empty lines at its start and its end, like the GraphQL specification.
+fn block_string_lines(content: &str) -> Vec<&str> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let common_indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut lines = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 {
+                line.trim()
+            } else {
+                line.get(common_indent..).unwrap_or("").trim_end()
+            }
+        })
+        .collect::<Vec<_>>();
+    while lines.first().is_some_and(|line| line.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
@@ -0,0 +1,474 @@
+//! Formatting of code in other languages embedded in template literals, like Prettier's
+//! `embed`: CSS in styled-components, GraphQL in `gql` tags and HTML in `html` tags.
+//!
+//! The code is formatted by an [`EmbeddedFormatter`], CSS and GraphQL ones are built in and
+//! others can be added with [`crate::Formatter::with_embedded_formatter`].
+
+mod css;
+mod graphql;
+
+use std::{fmt, rc::Rc};
+
+use oxc_ast::ast::*;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    formatter::{Buffer, Format, FormatResult, Formatter, prelude::*},
+    generated::ast_nodes::{AstNode, AstNodes},
+    options::{FormatOptions, IndentStyle},
+    write,
+};
+
+pub use self::{css::CssFormatter, graphql::GraphqlFormatter};
+
+/// A language which can be embedded in a template literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmbeddedLanguage {
+    Css,
+    GraphQL,
+    Html,
+}
+
+impl EmbeddedLanguage {
+    /// The text before and after the index of an expression in its placeholder, which replaces
+    /// the expression in the code to format.
+    const fn placeholder_affixes(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Css | Self::GraphQL => ("@prettier-placeholder-", ""),
+            Self::Html => ("PRETTIER_HTML_PLACEHOLDER_", "_IN_JS"),
+        }
+    }
+
+    fn placeholder(self, index: usize) -> String {
+        let (prefix, suffix) = self.placeholder_affixes();
+        format!("{prefix}{index}{suffix}")
+    }
+}
+
+/// Formats code embedded in template literals.
+///
+/// The expressions of the template are replaced by placeholders, like in Prettier:
+/// `@prettier-placeholder-<index>` in CSS and `PRETTIER_HTML_PLACEHOLDER_<index>_IN_JS` in
+/// HTML. They must be kept, in the same order, in the formatted code. GraphQL templates are
+/// formatted one template element at a time instead, as expressions can only be between
+/// definitions.
+pub trait EmbeddedFormatter {
+    /// Formats `source_text`, indenting the lines with the indent style and width of
+    /// `options`. Returns `None` when it can't be formatted, e.g. because of a syntax error,
+    /// in which case the template is printed as it is.
+    fn format(&self, source_text: &str, options: &FormatOptions) -> Option<String>;
+}
+
+/// The formatters of the embedded languages.
+#[derive(Clone)]
+pub struct EmbeddedFormatters {
+    formatters: FxHashMap<EmbeddedLanguage, Rc<dyn EmbeddedFormatter>>,
+}
+
+impl Default for EmbeddedFormatters {
+    fn default() -> Self {
+        let mut formatters = Self { formatters: FxHashMap::default() };
+        formatters.insert(EmbeddedLanguage::Css, Rc::new(CssFormatter));
+        formatters.insert(EmbeddedLanguage::GraphQL, Rc::new(GraphqlFormatter));
+        formatters
+    }
+}
+
+impl fmt::Debug for EmbeddedFormatters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.formatters.keys()).finish()
+    }
+}
+
+impl EmbeddedFormatters {
+    pub fn insert(&mut self, language: EmbeddedLanguage, formatter: Rc<dyn EmbeddedFormatter>) {
+        self.formatters.insert(language, formatter);
+    }
+
+    pub fn get(&self, language: EmbeddedLanguage) -> Option<&dyn EmbeddedFormatter> {
+        self.formatters.get(&language).map(AsRef::as_ref)
+    }
+}
+
+/// Returns the language of the code in `template`, recognized from the tag of the template,
+/// from where it is used, or from a `/* GraphQL */` or `/* HTML */` comment before it.
+pub fn embedded_language(
+    template: &AstNode<'_, TemplateLiteral<'_>>,
+    f: &Formatter<'_, '_>,
+) -> Option<EmbeddedLanguage> {
+    if f.options().embedded_language_formatting.is_off() {
+        return None;
+    }
+
+    let comment = f
+        .comments()
+        .printed_comments()
+        .last()
+        .filter(|comment| comment.is_block() && comment.attached_to == template.span.start);
+    if let Some(comment) = comment {
+        match comment.content_span().source_text(f.source_text()) {
+            " GraphQL " => return Some(EmbeddedLanguage::GraphQL),
+            " HTML " => return Some(EmbeddedLanguage::Html),
+            _ => {}
+        }
+    }
+
+    match template.parent {
+        AstNodes::TaggedTemplateExpression(tagged) => tag_language(&tagged.tag),
+        // graphql(schema, `query { ... }`)
+        AstNodes::Argument(argument) => match argument.parent {
+            AstNodes::CallExpression(call) => {
+                is_identifier(&call.callee, "graphql").then_some(EmbeddedLanguage::GraphQL)
+            }
+            _ => None,
+        },
+        AstNodes::JSXExpressionContainer(container) => match container.parent {
+            // <div css={`...`} />
+            AstNodes::JSXAttribute(attribute) => {
+                matches!(&attribute.name, JSXAttributeName::Identifier(name) if name.name == "css")
+                    .then_some(EmbeddedLanguage::Css)
+            }
+            // <style jsx>{`...`}</style>
+            AstNodes::JSXElement(element) => {
+                is_styled_jsx(&element.opening_element).then_some(EmbeddedLanguage::Css)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn tag_language(tag: &Expression<'_>) -> Option<EmbeddedLanguage> {
+    match tag.without_parentheses() {
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            "css" | "keyframes" | "injectGlobal" | "createGlobalStyle" => {
+                Some(EmbeddedLanguage::Css)
+            }
+            "gql" | "graphql" => Some(EmbeddedLanguage::GraphQL),
+            "html" => Some(EmbeddedLanguage::Html),
+            _ => None,
+        },
+        // styled.foo``, css.global``, css.resolve``
+        Expression::StaticMemberExpression(member) => {
+            if is_identifier(&member.object, "styled")
+                || (is_identifier(&member.object, "css")
+                    && matches!(member.property.name.as_str(), "global" | "resolve"))
+            {
+                Some(EmbeddedLanguage::Css)
+            } else if is_identifier(&member.object, "graphql")
+                && member.property.name == "experimental"
+            {
+                Some(EmbeddedLanguage::GraphQL)
+            } else {
+                None
+            }
+        }
+        Expression::CallExpression(call) => is_styled_call(call).then_some(EmbeddedLanguage::Css),
+        _ => None,
+    }
+}
+
+/// `styled(Component)`, `styled.foo.attrs(...)`, `styled(Component).attrs(...)` and
+/// `Component.extend.attrs(...)`.
+fn is_styled_call(call: &CallExpression<'_>) -> bool {
+    match &call.callee {
+        Expression::Identifier(ident) => ident.name == "styled",
+        Expression::StaticMemberExpression(member) => match &member.object {
+            Expression::StaticMemberExpression(object) => {
+                is_identifier(&object.object, "styled")
+                    || (matches!(&object.object, Expression::Identifier(ident) if ident.name.starts_with(|c: char| c.is_ascii_uppercase()))
+                        && object.property.name == "extend")
+            }
+            Expression::CallExpression(object) => is_identifier(&object.callee, "styled"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_styled_jsx(element: &JSXOpeningElement<'_>) -> bool {
+    matches!(&element.name, JSXElementName::Identifier(name) if name.name == "style")
+        && element.attributes.iter().any(|attribute| {
+            matches!(attribute, JSXAttributeItem::Attribute(attribute)
+                if matches!(&attribute.name, JSXAttributeName::Identifier(name) if name.name == "jsx"))
+        })
+}
+
+fn is_identifier(expression: &Expression<'_>, name: &str) -> bool {
+    matches!(expression, Expression::Identifier(ident) if ident.name == name)
+}
+
+/// A template literal whose content is formatted as an embedded language.
+pub struct FormatEmbeddedTemplate<'a, 'b> {
+    template: &'b AstNode<'a, TemplateLiteral<'a>>,
+    lines: Vec<EmbeddedLine<'a>>,
+    /// Whether the content is printed on its own lines, between the backticks.
+    block: bool,
+}
+
+/// A line of formatted code, in which the placeholders are replaced by the expressions.
+struct EmbeddedLine<'a> {
+    indent_level: u16,
+    segments: Vec<Segment<'a>>,
+    empty_line_before: bool,
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Expression(usize),
+}
+
+impl<'a, 'b> FormatEmbeddedTemplate<'a, 'b> {
+    /// Formats the content of `template` as `language`, returns `None` if there is no formatter
+    /// for the language or if the content can't be formatted.
+    pub fn new(
+        template: &'b AstNode<'a, TemplateLiteral<'a>>,
+        language: EmbeddedLanguage,
+        f: &Formatter<'_, 'a>,
+    ) -> Option<Self> {
+        let formatter = f.context().embedded_formatters().get(language)?;
+        let options = f.options();
+        let allocator = f.context().allocator();
+
+        if language == EmbeddedLanguage::GraphQL {
+            let lines = graphql_lines(template, formatter, options, allocator)?;
+            return Some(Self { template, lines, block: true });
+        }
+
+        let mut source_text = String::new();
+        for (index, quasi) in template.quasis.iter().enumerate() {
+            if index > 0 {
+                source_text.push_str(&language.placeholder(index - 1));
+            }
+            source_text.push_str(quasi.value.raw.as_str());
+        }
+        if source_text.trim().is_empty() {
+            return Some(Self { template, lines: vec![], block: false });
+        }
+
+        let formatted = formatter.format(&source_text, options)?;
+        let mut lines = vec![];
+        let mut next_expression = 0;
+        let mut empty_line_before = false;
+        for line in formatted.trim_matches(['\r', '\n']).lines() {
+            if line.trim().is_empty() {
+                empty_line_before = !lines.is_empty();
+                continue;
+            }
+            let (indent_level, line) = split_indentation(line, options);
+            let mut segments = vec![];
+            let mut rest = line;
+            let (prefix, suffix) = language.placeholder_affixes();
+            while let Some(start) = rest.find(prefix) {
+                let after_prefix = &rest[start + prefix.len()..];
+                let digits =
+                    after_prefix.find(|c: char| !c.is_ascii_digit()).unwrap_or(after_prefix.len());
+                let Ok(index) = after_prefix[..digits].parse::<usize>() else {
+                    return None;
+                };
+                if index != next_expression || !after_prefix[digits..].starts_with(suffix) {
+                    return None;
+                }
+                next_expression += 1;
+                if start > 0 {
+                    segments.push(Segment::Text(allocator.alloc_str(&rest[..start])));
+                }
+                segments.push(Segment::Expression(index));
+                rest = &after_prefix[digits + suffix.len()..];
+            }
+            if !rest.is_empty() {
+                segments.push(Segment::Text(allocator.alloc_str(rest)));
+            }
+            lines.push(EmbeddedLine { indent_level, segments, empty_line_before });
+            empty_line_before = false;
+        }
+        if next_expression != template.expressions.len() {
+            return None;
+        }
+
+        // Like Prettier, HTML stays inline when it isn't surrounded by whitespace.
+        let block = language != EmbeddedLanguage::Html
+            || lines.len() > 1
+            || source_text.starts_with(char::is_whitespace);
+        Some(Self { template, lines, block })
+    }
+}
+
+/// Formats each template element of a GraphQL template on its own, the expressions being on
+/// their own lines between them.
+fn graphql_lines<'a>(
+    template: &AstNode<'a, TemplateLiteral<'a>>,
+    formatter: &dyn EmbeddedFormatter,
+    options: &FormatOptions,
+    allocator: &'a oxc_allocator::Allocator,
+) -> Option<Vec<EmbeddedLine<'a>>> {
+    let quasis = &template.quasis;
+    if quasis.len() == 1 && quasis[0].value.raw.trim().is_empty() {
+        return Some(vec![]);
+    }
+
+    let mut lines = vec![];
+    let mut empty_line_before = false;
+    for (index, quasi) in quasis.iter().enumerate() {
+        let is_first = index == 0;
+        let is_last = index == quasis.len() - 1;
+        let text = quasi.value.raw.as_str();
+        let source_lines = text.split('\n').collect::<Vec<_>>();
+        let count = source_lines.len();
+        let starts_with_empty_line =
+            count > 2 && source_lines[0].trim().is_empty() && source_lines[1].trim().is_empty();
+        let ends_with_empty_line = count > 2
+            && source_lines[count - 1].trim().is_empty()
+            && source_lines[count - 2].trim().is_empty();
+
+        // An expression in a comment
+        if !is_last && source_lines[count - 1].contains('#') {
+            return None;
+        }
+
+        let comments_only = source_lines.iter().all(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with('#')
+        });
+        let formatted = if comments_only {
+            graphql_comments(&source_lines)
+        } else {
+            Some(formatter.format(text, options)?)
+        };
+
+        if let Some(formatted) = formatted {
+            empty_line_before |= !is_first && starts_with_empty_line;
+            for line in formatted.trim_matches(['\r', '\n']).lines() {
+                if line.trim().is_empty() {
+                    empty_line_before = true;
+                    continue;
+                }
+                let (indent_level, line) = split_indentation(line, options);
+                let segments = vec![Segment::Text(allocator.alloc_str(line))];
+                lines.push(EmbeddedLine { indent_level, segments, empty_line_before });
+                empty_line_before = false;
+            }
+            empty_line_before = !is_last && ends_with_empty_line;
+        } else if !is_first && !is_last && starts_with_empty_line {
+            empty_line_before = true;
+        }
+
+        if !is_last {
+            let segments = vec![Segment::Expression(index)];
+            lines.push(EmbeddedLine { indent_level: 0, segments, empty_line_before });
+            empty_line_before = false;
+        }
+    }
+    Some(lines)
+}
+
+/// The comments of a template element without any GraphQL code, keeping empty lines between
+/// them.
+fn graphql_comments(source_lines: &[&str]) -> Option<String> {
+    let mut comments = String::new();
+    let mut previous_empty = false;
+    for line in source_lines.iter().map(|line| line.trim()) {
+        if line.is_empty() {
+            previous_empty = true;
+            continue;
+        }
+        if !comments.is_empty() {
+            comments.push_str(if previous_empty { "\n\n" } else { "\n" });
+        }
+        comments.push_str(line);
+        previous_empty = false;
+    }
+    (!comments.is_empty()).then_some(comments)
+}
+
+/// Splits the indentation of a line of formatted code, which is indented with the options'
+/// indent style, into an indent level and the rest of the line.
+fn split_indentation<'s>(line: &'s str, options: &FormatOptions) -> (u16, &'s str) {
+    match options.indent_style {
+        IndentStyle::Tab => {
+            let content = line.trim_start_matches('\t');
+            let level = line.len() - content.len();
+            (u16::try_from(level).unwrap_or(u16::MAX), content)
+        }
+        IndentStyle::Space => {
+            let width = usize::from(options.indent_width.value());
+            if width == 0 {
+                return (0, line);
+            }
+            let spaces = line.len() - line.trim_start_matches(' ').len();
+            let level = spaces / width;
+            (u16::try_from(level).unwrap_or(u16::MAX), &line[level * width..])
+        }
+    }
+}
+
+impl<'a> Format<'a> for FormatEmbeddedTemplate<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let expressions = self.template.expressions().iter().collect::<Vec<_>>();
+        let format_lines = format_with(|f| {
+            for (index, line) in self.lines.iter().enumerate() {
+                let line_break = self.block || index > 0;
+                let format_line =
+                    FormatEmbeddedLine { line, expressions: &expressions, line_break };
+                write!(f, format_line)?;
+            }
+            Ok(())
+        });
+
+        write!(f, "`")?;
+        if self.block {
+            if !self.lines.is_empty() {
+                write!(f, [indent(&format_lines), hard_line_break()])?;
+            }
+        } else {
+            write!(f, format_lines)?;
+        }
+        write!(f, "`")
+    }
+}
+
+struct FormatEmbeddedLine<'a, 'b> {
+    line: &'b EmbeddedLine<'a>,
+    expressions: &'b [&'a AstNode<'a, Expression<'a>>],
+    /// Whether the line starts on a new line, it doesn't for inline content.
+    line_break: bool,
+}
+
+impl<'a> Format<'a> for FormatEmbeddedLine<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let content = format_with(|f| {
+            if self.line_break {
+                if self.line.empty_line_before {
+                    write!(f, empty_line())?;
+                } else {
+                    write!(f, hard_line_break())?;
+                }
+            }
+            for segment in &self.line.segments {
+                match segment {
+                    Segment::Text(text) => write!(f, dynamic_text(text))?,
+                    Segment::Expression(index) => {
+                        write!(f, ["${", self.expressions[*index], line_suffix_boundary(), "}"])?;
+                    }
+                }
+            }
+            Ok(())
+        });
+        write!(f, FormatIndented { level: self.line.indent_level, content: &content })
+    }
+}
+
+/// Indents `content` by `level` levels.
+struct FormatIndented<'b, T> {
+    level: u16,
+    content: &'b T,
+}
+
+impl<'a, T: Format<'a>> Format<'a> for FormatIndented<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if self.level == 0 {
+            return write!(f, self.content);
+        }
+        write!(f, indent(&FormatIndented { level: self.level - 1, content: self.content }))
+    }
+}
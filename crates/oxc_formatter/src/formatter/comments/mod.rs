@@ -245,6 +245,48 @@ impl<'a> Comments<'a> {
         self.printed_count += 1;
     }
 
+    /// Marks the comments that end before `pos` as printed, e.g. because they are part of a node
+    /// printed as it is in the source.
+    pub fn mark_as_printed_before(&mut self, pos: u32) {
+        while self.comments.get(self.printed_count).is_some_and(|comment| comment.span.end <= pos) {
+            self.printed_count += 1;
+        }
+    }
+
+    /// Returns `true` if the node starting at `start` has a leading suppression comment
+    /// (`// prettier-ignore` or `// oxfmt-ignore`), which means it's printed as it is.
+    pub fn is_suppressed(&self, start: u32) -> bool {
+        let end = self.comments.partition_point(|comment| comment.span.end <= start);
+        self.comments[..end]
+            .iter()
+            .rev()
+            .take_while(|comment| comment.is_leading() && comment.attached_to == start)
+            .any(|comment| is_suppression_comment(comment, self.source_text, SuppressionKind::Node))
+    }
+
+    /// Returns where the range of statements ignored by a `// prettier-ignore-start` comment before
+    /// `start` ends: at the start of the `// prettier-ignore-end` comment, or at [`u32::MAX`] if
+    /// there is none. Returns `None` if there is no `// prettier-ignore-start`.
+    pub fn ignored_range_end(&self, start: u32) -> Option<u32> {
+        let comments = self.comments.as_slice();
+        let end = comments.partition_point(|comment| comment.span.end <= start);
+        let has_range_start = comments[..end]
+            .iter()
+            .rev()
+            .take_while(|comment| comment.is_leading() && comment.attached_to == start)
+            .any(|comment| {
+                is_suppression_comment(comment, self.source_text, SuppressionKind::RangeStart)
+            });
+        if !has_range_start {
+            return None;
+        }
+
+        let range_end = comments[end..].iter().find(|comment| {
+            is_suppression_comment(comment, self.source_text, SuppressionKind::RangeEnd)
+        });
+        Some(range_end.map_or(u32::MAX, |comment| comment.span.start))
+    }
+
     pub fn get_trailing_comments(
         &self,
         enclosing_node: &SiblingNode<'a>,
@@ -340,6 +382,43 @@ impl<'a> Comments<'a> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `// prettier-ignore`, which suppresses the formatting of the following node.
+    Node,
+    /// `// prettier-ignore-start`, which suppresses the formatting of the following statements.
+    RangeStart,
+    /// `// prettier-ignore-end`, which ends a range started by [`SuppressionKind::RangeStart`].
+    RangeEnd,
+}
+
+/// Returns `true` if `comment` is a suppression comment of the given `kind`. Both the `prettier`
+/// and the `oxfmt` prefix are supported, and block comments may start with a `*`:
+///
+/// ```js
+/// // prettier-ignore
+/// /* oxfmt-ignore */
+/// /** prettier-ignore-start */
+/// ```
+pub fn is_suppression_comment(comment: &Comment, source_text: &str, kind: SuppressionKind) -> bool {
+    let content = comment.content_span().source_text(source_text);
+    let content =
+        if comment.is_block() { content.strip_prefix('*').unwrap_or(content) } else { content };
+    let Some(suffix) = content
+        .trim()
+        .strip_prefix("prettier-ignore")
+        .or_else(|| content.trim().strip_prefix("oxfmt-ignore"))
+    else {
+        return false;
+    };
+
+    match kind {
+        SuppressionKind::Node => suffix.is_empty(),
+        SuppressionKind::RangeStart => suffix == "-start",
+        SuppressionKind::RangeEnd => suffix == "-end",
+    }
+}
+
 #[inline]
 pub fn is_new_line(char: char) -> ControlFlow<bool> {
     if char == ' ' || char == '\t' {
@@ -8,7 +8,10 @@ use oxc_ast::{
 use oxc_span::{GetSpan, SourceType, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{formatter::FormatElement, generated::ast_nodes::AstNode, options::FormatOptions};
+use crate::{
+    embed::EmbeddedFormatters, formatter::FormatElement, generated::ast_nodes::AstNode,
+    options::FormatOptions,
+};
 
 use super::Comments;
 
@@ -26,6 +29,8 @@ pub struct FormatContext<'ast> {
     cached_elements: FxHashMap<Span, FormatElement<'ast>>,
 
     allocator: &'ast Allocator,
+
+    embedded_formatters: EmbeddedFormatters,
 }
 
 impl std::fmt::Debug for FormatContext<'_> {
@@ -45,9 +50,11 @@ impl<'ast> FormatContext<'ast> {
         program: &'ast Program<'ast>,
         allocator: &'ast Allocator,
         options: FormatOptions,
+        embedded_formatters: EmbeddedFormatters,
     ) -> Self {
         Self {
             options,
+            embedded_formatters,
             source_text: program.source_text,
             source_type: program.source_type,
             comments: Comments::new(program.source_text, &program.comments),
@@ -98,4 +105,9 @@ impl<'ast> FormatContext<'ast> {
     pub fn allocator(&self) -> &'ast Allocator {
         self.allocator
     }
+
+    /// Returns the formatters of the languages embedded in template literals.
+    pub fn embedded_formatters(&self) -> &EmbeddedFormatters {
+        &self.embedded_formatters
+    }
 }
@@ -1,7 +1,3 @@
-// pub use super::verbatim::{
-// format_bogus_node, format_or_verbatim, format_suppressed_node, format_verbatim_node,
-// format_verbatim_skipped,
-// };
 pub use super::{Buffer as _, BufferExtensions, Format, Format as _, FormatResult};
 pub use super::{
     builders::*,
@@ -12,4 +8,5 @@ pub use super::{
     format_extensions::{MemoizeFormat, Memoized},
     formatter::Formatter,
     trivia::{format_dangling_comments, format_leading_comments},
+    verbatim::format_suppressed_node,
 };
@@ -239,16 +239,8 @@ impl<'a> Printer<'a> {
                 self.state.line_suffixes.extend(args, queue.iter_content(TagKind::LineSuffix));
             }
 
-            FormatElement::Tag(StartVerbatim(kind)) => {
-                todo!()
-                // if let VerbatimKind::Verbatim { length } = kind {
-                // self.state.verbatim_markers.push(TextRange::at(
-                // TextSize::from(self.state.buffer.len() as u32),
-                // *length,
-                // ));
-                // }
-
-                // stack.push(TagKind::Verbatim, args);
+            FormatElement::Tag(StartVerbatim(_)) => {
+                stack.push(TagKind::Verbatim, args);
             }
 
             FormatElement::Tag(tag @ (StartLabelled(_) | StartEntry)) => {
@@ -2,9 +2,13 @@
 // To edit this generated file you have to edit `tasks/ast_tools/src/generators/formatter/format.rs`.
 
 use oxc_ast::ast::*;
+use oxc_span::GetSpan;
 
 use crate::{
-    formatter::{Buffer, Format, FormatResult, Formatter, trivia::FormatTrailingComments},
+    formatter::{
+        Buffer, Format, FormatResult, Formatter, prelude::format_suppressed_node,
+        trivia::FormatTrailingComments,
+    },
     generated::ast_nodes::{AstNode, SiblingNode},
     parentheses::NeedsParentheses,
     write::{FormatFunctionOptions, FormatJsArrowFunctionExpressionOptions, FormatWrite},
@@ -27,7 +31,11 @@ impl<'a> Format<'a> for AstNode<'a, Expression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IdentifierName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -36,7 +44,11 @@ impl<'a> Format<'a> for AstNode<'a, IdentifierName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IdentifierReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -45,7 +57,11 @@ impl<'a> Format<'a> for AstNode<'a, IdentifierReference<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BindingIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -54,7 +70,11 @@ impl<'a> Format<'a> for AstNode<'a, BindingIdentifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, LabelIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -67,7 +87,11 @@ impl<'a> Format<'a> for AstNode<'a, ThisExpression> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -83,7 +107,11 @@ impl<'a> Format<'a> for AstNode<'a, ArrayExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -101,7 +129,11 @@ impl<'a> Format<'a> for AstNode<'a, ArrayExpressionElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Elision> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -114,7 +146,11 @@ impl<'a> Format<'a> for AstNode<'a, ObjectExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -132,7 +168,11 @@ impl<'a> Format<'a> for AstNode<'a, ObjectPropertyKind<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -147,7 +187,11 @@ impl<'a> Format<'a> for AstNode<'a, PropertyKey<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TemplateLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -160,7 +204,11 @@ impl<'a> Format<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -172,7 +220,11 @@ impl<'a> Format<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TemplateElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -191,7 +243,11 @@ impl<'a> Format<'a> for AstNode<'a, ComputedMemberExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -207,7 +263,11 @@ impl<'a> Format<'a> for AstNode<'a, StaticMemberExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -223,7 +283,11 @@ impl<'a> Format<'a> for AstNode<'a, PrivateFieldExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -239,7 +303,11 @@ impl<'a> Format<'a> for AstNode<'a, CallExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -255,7 +323,11 @@ impl<'a> Format<'a> for AstNode<'a, NewExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -267,7 +339,11 @@ impl<'a> Format<'a> for AstNode<'a, NewExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, MetaProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -276,7 +352,11 @@ impl<'a> Format<'a> for AstNode<'a, MetaProperty<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SpreadElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -295,7 +375,11 @@ impl<'a> Format<'a> for AstNode<'a, UpdateExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -311,7 +395,11 @@ impl<'a> Format<'a> for AstNode<'a, UnaryExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -327,7 +415,11 @@ impl<'a> Format<'a> for AstNode<'a, BinaryExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -343,7 +435,11 @@ impl<'a> Format<'a> for AstNode<'a, PrivateInExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -359,7 +455,11 @@ impl<'a> Format<'a> for AstNode<'a, LogicalExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -375,7 +475,11 @@ impl<'a> Format<'a> for AstNode<'a, ConditionalExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -391,7 +495,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -421,7 +529,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ArrayAssignmentTarget<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -430,7 +542,11 @@ impl<'a> Format<'a> for AstNode<'a, ArrayAssignmentTarget<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectAssignmentTarget<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -439,7 +555,11 @@ impl<'a> Format<'a> for AstNode<'a, ObjectAssignmentTarget<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentTargetRest<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -454,7 +574,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetMaybeDefault<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentTargetWithDefault<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -469,7 +593,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetProperty<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentTargetPropertyIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -478,7 +606,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetPropertyIdentifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentTargetPropertyProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -491,7 +623,11 @@ impl<'a> Format<'a> for AstNode<'a, SequenceExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -503,7 +639,11 @@ impl<'a> Format<'a> for AstNode<'a, SequenceExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Super> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -516,7 +656,11 @@ impl<'a> Format<'a> for AstNode<'a, AwaitExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -532,7 +676,11 @@ impl<'a> Format<'a> for AstNode<'a, ChainExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -554,7 +702,11 @@ impl<'a> Format<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -572,7 +724,11 @@ impl<'a> Format<'a> for AstNode<'a, Statement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Directive<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -581,7 +737,11 @@ impl<'a> Format<'a> for AstNode<'a, Directive<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Hashbang<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -590,7 +750,11 @@ impl<'a> Format<'a> for AstNode<'a, Hashbang<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BlockStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -605,7 +769,11 @@ impl<'a> Format<'a> for AstNode<'a, Declaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, VariableDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -614,7 +782,11 @@ impl<'a> Format<'a> for AstNode<'a, VariableDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, VariableDeclarator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -623,7 +795,11 @@ impl<'a> Format<'a> for AstNode<'a, VariableDeclarator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, EmptyStatement> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -632,7 +808,11 @@ impl<'a> Format<'a> for AstNode<'a, EmptyStatement> {
 impl<'a> Format<'a> for AstNode<'a, ExpressionStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -641,7 +821,11 @@ impl<'a> Format<'a> for AstNode<'a, ExpressionStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IfStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -650,7 +834,11 @@ impl<'a> Format<'a> for AstNode<'a, IfStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, DoWhileStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -659,7 +847,11 @@ impl<'a> Format<'a> for AstNode<'a, DoWhileStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WhileStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -668,7 +860,11 @@ impl<'a> Format<'a> for AstNode<'a, WhileStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -683,7 +879,11 @@ impl<'a> Format<'a> for AstNode<'a, ForStatementInit<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForInStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -698,7 +898,11 @@ impl<'a> Format<'a> for AstNode<'a, ForStatementLeft<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForOfStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -707,7 +911,11 @@ impl<'a> Format<'a> for AstNode<'a, ForOfStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ContinueStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -716,7 +924,11 @@ impl<'a> Format<'a> for AstNode<'a, ContinueStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BreakStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -725,7 +937,11 @@ impl<'a> Format<'a> for AstNode<'a, BreakStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ReturnStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -734,7 +950,11 @@ impl<'a> Format<'a> for AstNode<'a, ReturnStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WithStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -743,7 +963,11 @@ impl<'a> Format<'a> for AstNode<'a, WithStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SwitchStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -752,7 +976,11 @@ impl<'a> Format<'a> for AstNode<'a, SwitchStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SwitchCase<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -761,7 +989,11 @@ impl<'a> Format<'a> for AstNode<'a, SwitchCase<'a>> {
 impl<'a> Format<'a> for AstNode<'a, LabeledStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -770,7 +1002,11 @@ impl<'a> Format<'a> for AstNode<'a, LabeledStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ThrowStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -779,7 +1015,11 @@ impl<'a> Format<'a> for AstNode<'a, ThrowStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TryStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -788,7 +1028,11 @@ impl<'a> Format<'a> for AstNode<'a, TryStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, CatchClause<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -797,7 +1041,11 @@ impl<'a> Format<'a> for AstNode<'a, CatchClause<'a>> {
 impl<'a> Format<'a> for AstNode<'a, CatchParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -806,7 +1054,11 @@ impl<'a> Format<'a> for AstNode<'a, CatchParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, DebuggerStatement> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -827,7 +1079,11 @@ impl<'a> Format<'a> for AstNode<'a, BindingPatternKind<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -836,7 +1092,11 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -845,7 +1105,11 @@ impl<'a> Format<'a> for AstNode<'a, ObjectPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BindingProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -854,7 +1118,11 @@ impl<'a> Format<'a> for AstNode<'a, BindingProperty<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ArrayPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -863,7 +1131,11 @@ impl<'a> Format<'a> for AstNode<'a, ArrayPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BindingRestElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -876,7 +1148,11 @@ impl<'a> Format<'a, FormatFunctionOptions> for AstNode<'a, Function<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -894,7 +1170,11 @@ impl<'a> Format<'a, FormatFunctionOptions> for AstNode<'a, Function<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write_with_options(options, f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write_with_options(options, f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -912,7 +1192,11 @@ impl<'a> Format<'a> for AstNode<'a, FormalParameters<'a>> {
 impl<'a> Format<'a> for AstNode<'a, FormalParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -921,7 +1205,11 @@ impl<'a> Format<'a> for AstNode<'a, FormalParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, FunctionBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -936,7 +1224,11 @@ impl<'a> Format<'a, FormatJsArrowFunctionExpressionOptions>
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -954,7 +1246,11 @@ impl<'a> Format<'a, FormatJsArrowFunctionExpressionOptions>
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write_with_options(options, f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write_with_options(options, f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -970,7 +1266,11 @@ impl<'a> Format<'a> for AstNode<'a, YieldExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -986,7 +1286,11 @@ impl<'a> Format<'a> for AstNode<'a, Class<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -998,7 +1302,11 @@ impl<'a> Format<'a> for AstNode<'a, Class<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ClassBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1013,7 +1321,11 @@ impl<'a> Format<'a> for AstNode<'a, ClassElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, MethodDefinition<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1022,7 +1334,11 @@ impl<'a> Format<'a> for AstNode<'a, MethodDefinition<'a>> {
 impl<'a> Format<'a> for AstNode<'a, PropertyDefinition<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1031,7 +1347,11 @@ impl<'a> Format<'a> for AstNode<'a, PropertyDefinition<'a>> {
 impl<'a> Format<'a> for AstNode<'a, PrivateIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1040,7 +1360,11 @@ impl<'a> Format<'a> for AstNode<'a, PrivateIdentifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, StaticBlock<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1055,7 +1379,11 @@ impl<'a> Format<'a> for AstNode<'a, ModuleDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AccessorProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1068,7 +1396,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1080,7 +1412,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1095,7 +1431,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportDeclarationSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1104,7 +1444,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportDefaultSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1113,7 +1457,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportDefaultSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportNamespaceSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1122,7 +1470,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportNamespaceSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WithClause<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1131,7 +1483,11 @@ impl<'a> Format<'a> for AstNode<'a, WithClause<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1146,7 +1502,11 @@ impl<'a> Format<'a> for AstNode<'a, ImportAttributeKey<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportNamedDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1155,7 +1515,11 @@ impl<'a> Format<'a> for AstNode<'a, ExportNamedDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportDefaultDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1164,7 +1528,11 @@ impl<'a> Format<'a> for AstNode<'a, ExportDefaultDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportAllDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1173,7 +1541,11 @@ impl<'a> Format<'a> for AstNode<'a, ExportAllDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1198,7 +1570,11 @@ impl<'a> Format<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1210,7 +1586,11 @@ impl<'a> Format<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BooleanLiteral> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1219,7 +1599,11 @@ impl<'a> Format<'a> for AstNode<'a, BooleanLiteral> {
 impl<'a> Format<'a> for AstNode<'a, NullLiteral> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1232,7 +1616,11 @@ impl<'a> Format<'a> for AstNode<'a, NumericLiteral<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1248,7 +1636,11 @@ impl<'a> Format<'a> for AstNode<'a, StringLiteral<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1260,7 +1652,11 @@ impl<'a> Format<'a> for AstNode<'a, StringLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BigIntLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1269,7 +1665,11 @@ impl<'a> Format<'a> for AstNode<'a, BigIntLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, RegExpLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1278,7 +1678,11 @@ impl<'a> Format<'a> for AstNode<'a, RegExpLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1287,7 +1691,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXOpeningElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1296,7 +1704,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXOpeningElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXClosingElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1305,7 +1717,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXClosingElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXFragment<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1314,7 +1730,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXFragment<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXOpeningFragment> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1323,7 +1743,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXOpeningFragment> {
 impl<'a> Format<'a> for AstNode<'a, JSXClosingFragment> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1338,7 +1762,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXElementName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXNamespacedName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1351,7 +1779,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXMemberExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1369,7 +1801,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXMemberExpressionObject<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXExpressionContainer<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1388,7 +1824,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXEmptyExpression> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1406,7 +1846,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttributeItem<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1415,7 +1859,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttribute<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXSpreadAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1436,7 +1884,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttributeValue<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1451,7 +1903,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXChild<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXSpreadChild<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1460,7 +1916,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXSpreadChild<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXText<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1469,7 +1929,11 @@ impl<'a> Format<'a> for AstNode<'a, JSXText<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSThisParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1478,7 +1942,11 @@ impl<'a> Format<'a> for AstNode<'a, TSThisParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1487,7 +1955,11 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1496,7 +1968,11 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumMember<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1511,7 +1987,11 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumMemberName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeAnnotation<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1520,7 +2000,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAnnotation<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSLiteralType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1541,7 +2025,11 @@ impl<'a> Format<'a> for AstNode<'a, TSType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSConditionalType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1550,7 +2038,11 @@ impl<'a> Format<'a> for AstNode<'a, TSConditionalType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSUnionType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1559,7 +2051,11 @@ impl<'a> Format<'a> for AstNode<'a, TSUnionType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIntersectionType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1568,7 +2064,11 @@ impl<'a> Format<'a> for AstNode<'a, TSIntersectionType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSParenthesizedType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1577,7 +2077,11 @@ impl<'a> Format<'a> for AstNode<'a, TSParenthesizedType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeOperator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1586,7 +2090,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeOperator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSArrayType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1595,7 +2103,11 @@ impl<'a> Format<'a> for AstNode<'a, TSArrayType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIndexedAccessType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1604,7 +2116,11 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexedAccessType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTupleType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1613,7 +2129,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTupleType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSNamedTupleMember<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1622,7 +2142,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNamedTupleMember<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSOptionalType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1631,7 +2155,11 @@ impl<'a> Format<'a> for AstNode<'a, TSOptionalType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSRestType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1646,7 +2174,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTupleElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSAnyKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1655,7 +2187,11 @@ impl<'a> Format<'a> for AstNode<'a, TSAnyKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSStringKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1664,7 +2200,11 @@ impl<'a> Format<'a> for AstNode<'a, TSStringKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSBooleanKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1673,7 +2213,11 @@ impl<'a> Format<'a> for AstNode<'a, TSBooleanKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNumberKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1682,7 +2226,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNumberKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNeverKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1691,7 +2239,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNeverKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSIntrinsicKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1700,7 +2252,11 @@ impl<'a> Format<'a> for AstNode<'a, TSIntrinsicKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSUnknownKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1709,7 +2265,11 @@ impl<'a> Format<'a> for AstNode<'a, TSUnknownKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNullKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1718,7 +2278,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNullKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSUndefinedKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1727,7 +2291,11 @@ impl<'a> Format<'a> for AstNode<'a, TSUndefinedKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSVoidKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1736,7 +2304,11 @@ impl<'a> Format<'a> for AstNode<'a, TSVoidKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSSymbolKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1745,7 +2317,11 @@ impl<'a> Format<'a> for AstNode<'a, TSSymbolKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSThisType> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1754,7 +2330,11 @@ impl<'a> Format<'a> for AstNode<'a, TSThisType> {
 impl<'a> Format<'a> for AstNode<'a, TSObjectKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1763,7 +2343,11 @@ impl<'a> Format<'a> for AstNode<'a, TSObjectKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSBigIntKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1772,7 +2356,11 @@ impl<'a> Format<'a> for AstNode<'a, TSBigIntKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1787,7 +2375,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSQualifiedName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1796,7 +2388,11 @@ impl<'a> Format<'a> for AstNode<'a, TSQualifiedName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameterInstantiation<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1805,7 +2401,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameterInstantiation<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1814,7 +2414,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameterDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1823,7 +2427,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameterDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeAliasDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1832,7 +2440,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAliasDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSClassImplements<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1841,7 +2453,11 @@ impl<'a> Format<'a> for AstNode<'a, TSClassImplements<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInterfaceDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1850,7 +2466,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInterfaceDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInterfaceBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1859,7 +2479,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInterfaceBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSPropertySignature<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1874,7 +2498,11 @@ impl<'a> Format<'a> for AstNode<'a, TSSignature<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIndexSignature<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1883,7 +2511,11 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexSignature<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSCallSignatureDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1892,7 +2524,11 @@ impl<'a> Format<'a> for AstNode<'a, TSCallSignatureDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSMethodSignature<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1901,7 +2537,11 @@ impl<'a> Format<'a> for AstNode<'a, TSMethodSignature<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSConstructSignatureDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1910,7 +2550,11 @@ impl<'a> Format<'a> for AstNode<'a, TSConstructSignatureDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIndexSignatureName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1919,7 +2563,11 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexSignatureName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInterfaceHeritage<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1928,7 +2576,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInterfaceHeritage<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypePredicate<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1943,7 +2595,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypePredicateName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSModuleDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1964,7 +2620,11 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleDeclarationBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSModuleBlock<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1973,7 +2633,11 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleBlock<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1982,7 +2646,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInferType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -1991,7 +2659,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInferType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeQuery<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2006,7 +2678,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeQueryExprName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSImportType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2015,7 +2691,11 @@ impl<'a> Format<'a> for AstNode<'a, TSImportType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSFunctionType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2024,7 +2704,11 @@ impl<'a> Format<'a> for AstNode<'a, TSFunctionType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSConstructorType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2033,7 +2717,11 @@ impl<'a> Format<'a> for AstNode<'a, TSConstructorType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSMappedType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2042,7 +2730,11 @@ impl<'a> Format<'a> for AstNode<'a, TSMappedType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTemplateLiteralType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2055,7 +2747,11 @@ impl<'a> Format<'a> for AstNode<'a, TSAsExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2071,7 +2767,11 @@ impl<'a> Format<'a> for AstNode<'a, TSSatisfiesExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2087,7 +2787,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAssertion<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2099,7 +2803,11 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAssertion<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSImportEqualsDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2114,7 +2822,11 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleReference<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSExternalModuleReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2127,7 +2839,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNonNullExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2139,7 +2855,11 @@ impl<'a> Format<'a> for AstNode<'a, TSNonNullExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Decorator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2148,7 +2868,11 @@ impl<'a> Format<'a> for AstNode<'a, Decorator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSExportAssignment<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2157,7 +2881,11 @@ impl<'a> Format<'a> for AstNode<'a, TSExportAssignment<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSNamespaceExportDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2170,7 +2898,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInstantiationExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2182,7 +2914,11 @@ impl<'a> Format<'a> for AstNode<'a, TSInstantiationExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocNullableType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2191,7 +2927,11 @@ impl<'a> Format<'a> for AstNode<'a, JSDocNullableType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocNonNullableType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -2200,7 +2940,11 @@ impl<'a> Format<'a> for AstNode<'a, JSDocNonNullableType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocUnknownType> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         self.format_leading_comments(f)?;
-        let result = self.write(f);
+        let result = if f.context().comments().is_suppressed(self.span().start) {
+            format_suppressed_node(self.span()).fmt(f)
+        } else {
+            self.write(f)
+        };
         self.format_trailing_comments(f)?;
         result
     }
@@ -14,9 +14,11 @@ mod generated {
     pub mod format;
     pub mod format_write;
 }
+mod embed;
 mod formatter;
 mod options;
 mod parentheses;
+mod range;
 mod utils;
 mod write;
 
@@ -25,29 +27,54 @@ use std::{
     fmt::{self, Display},
     marker::PhantomData,
     mem::{self, transmute},
+    rc::Rc,
     vec::IntoIter,
 };
 
 use oxc_allocator::{Address, Allocator, GetAddress};
 use oxc_ast::{AstKind, ast::*};
+use oxc_span::Span;
 use rustc_hash::{FxHashMap, FxHashSet};
 use write::FormatWrite;
 
-pub use crate::options::*;
 use crate::{
+    embed::EmbeddedFormatters,
     formatter::FormatContext,
     generated::ast_nodes::{AstNode, AstNodes},
 };
+pub use crate::{
+    embed::{EmbeddedFormatter, EmbeddedLanguage},
+    options::*,
+    range::RangeEdit,
+};
 
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     options: FormatOptions,
+    embedded_formatters: EmbeddedFormatters,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, source_text: "", options }
+        Self {
+            allocator,
+            source_text: "",
+            options,
+            embedded_formatters: EmbeddedFormatters::default(),
+        }
+    }
+
+    /// Formats the code of `language` embedded in template literals with `formatter`, instead
+    /// of the built-in formatter of the language if there is one.
+    #[must_use]
+    pub fn with_embedded_formatter(
+        mut self,
+        language: EmbeddedLanguage,
+        formatter: impl EmbeddedFormatter + 'static,
+    ) -> Self {
+        self.embedded_formatters.insert(language, Rc::new(formatter));
+        self
     }
 
     pub fn build(mut self, program: &Program<'a>) -> String {
@@ -56,7 +83,8 @@ impl<'a> Formatter<'a> {
 
         let source_text = program.source_text;
         self.source_text = source_text;
-        let context = FormatContext::new(program, self.allocator, self.options);
+        let context =
+            FormatContext::new(program, self.allocator, self.options, self.embedded_formatters);
         let formatted = formatter::format(
             program,
             context,
@@ -65,4 +93,70 @@ impl<'a> Formatter<'a> {
         .unwrap();
         formatted.print().unwrap().into_code()
     }
+
+    /// Formats the statements, class elements or object properties touched by `range`, like
+    /// Prettier's `rangeStart` and `rangeEnd` options. They are formatted on their own, indented
+    /// like the line they start on.
+    ///
+    /// Returns the replacement of these nodes in the source text, or `None` if the range doesn't
+    /// touch any of them.
+    pub fn build_range(self, program: &Program<'a>, range: Span) -> Option<RangeEdit> {
+        let span = range::units_range(program, range)?;
+        let (program, kind) = range::range_program(program, span, self.allocator);
+        let program = self.allocator.alloc(program);
+        let indent_width = self.options.indent_width.value();
+        let indentation = range::indentation_width(program.source_text, span.start, indent_width);
+        let code = self.format_indented(program, kind, indentation);
+        Some(RangeEdit { span, text: code.trim().to_string() })
+    }
+
+    /// Formats a program embedded in another document, e.g. the `<script>` block of a Vue
+    /// component, whose code starts `indentation` columns from the start of the line.
+    ///
+    /// Every line of the returned code is indented by `indentation`, and the line width accounts
+    /// for it. The code doesn't start or end with a line break.
+    pub fn build_embedded(self, program: &Program<'a>, indentation: u16) -> String {
+        let code = self.format_indented(program, range::UnitKind::Statement, indentation);
+        code.trim_start_matches(['\r', '\n']).trim_end().to_string()
+    }
+
+    /// Formats `program` indented by `indentation` columns, starting with a line break.
+    fn format_indented(
+        mut self,
+        program: &'a Program<'a>,
+        kind: range::UnitKind,
+        indentation: u16,
+    ) -> String {
+        let parent = self.allocator.alloc(AstNodes::Dummy());
+        let program_node = AstNode::new(program, parent, self.allocator);
+
+        self.source_text = program.source_text;
+        let indent_width = u16::from(self.options.indent_width.value());
+        let indent_level = indentation.checked_div(indent_width).unwrap_or(0);
+        let align = indentation - indent_level * indent_width;
+        let format_range = range::FormatRangeProgram {
+            program: &program_node,
+            kind,
+            indent_level,
+            align: u8::try_from(align).unwrap_or(u8::MAX),
+        };
+
+        let context =
+            FormatContext::new(program, self.allocator, self.options, self.embedded_formatters);
+        let formatted = formatter::format(
+            program,
+            context,
+            formatter::Arguments::new(&[formatter::Argument::new(&format_range)]),
+        )
+        .unwrap();
+        formatted.print().unwrap().into_code()
+    }
+
+    /// Formats the statement, class element or object property ending with the character before
+    /// `offset`, e.g. after typing a `;` or a `}` in an editor.
+    ///
+    /// Returns `None` if there is none there.
+    pub fn build_on_type(self, program: &Program<'a>, offset: u32) -> Option<RangeEdit> {
+        self.build_range(program, Span::new(offset.checked_sub(1)?, offset))
+    }
 }
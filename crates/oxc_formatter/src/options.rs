@@ -62,6 +62,10 @@ pub struct FormatOptions {
     /// - `"start"`: Places the operator at the beginning of the next line.
     /// - `"end"`: Places the operator at the end of the current line (default).
     pub experimental_operator_position: OperatorPosition,
+
+    /// Whether to format code embedded in template literals, e.g. CSS in styled-components or
+    /// GraphQL in `gql` tags. Defaults to "auto".
+    pub embedded_language_formatting: EmbeddedLanguageFormatting,
 }
 
 impl FormatOptions {
@@ -82,6 +86,7 @@ impl FormatOptions {
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
             experimental_operator_position: OperatorPosition::default(),
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
         }
     }
 
@@ -106,7 +111,8 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
         writeln!(f, "Expand lists: {}", self.expand)?;
-        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)
+        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)?;
+        writeln!(f, "Embedded language formatting: {}", self.embedded_language_formatting)
     }
 }
 
@@ -908,3 +914,44 @@ impl fmt::Display for OperatorPosition {
         f.write_str(s)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EmbeddedLanguageFormatting {
+    /// Format embedded code when the language is recognized.
+    #[default]
+    Auto,
+    /// Never format embedded code.
+    Off,
+}
+
+impl EmbeddedLanguageFormatting {
+    pub const fn is_auto(self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub const fn is_off(self) -> bool {
+        matches!(self, Self::Off)
+    }
+}
+
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "off" => Ok(Self::Off),
+            _ => Err("Value not supported for EmbeddedLanguageFormatting"),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedLanguageFormatting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            EmbeddedLanguageFormatting::Auto => "Auto",
+            EmbeddedLanguageFormatting::Off => "Off",
+        };
+        f.write_str(s)
+    }
+}
@@ -0,0 +1,317 @@
+//! Formatting of a range of the source text, with the semantics of Prettier's `rangeStart` and
+//! `rangeEnd` options: the range is expanded to the format units it touches, which are formatted
+//! on their own and indented like the line the range starts on.
+//!
+//! Format units are statements, class elements and object properties.
+
+use oxc_allocator::{Allocator, CloneIn, Vec};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{
+    Visit,
+    walk::{walk_class_element, walk_object_property_kind, walk_statement},
+};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    formatter::{Buffer, Format, FormatResult, Formatter, prelude::*},
+    generated::ast_nodes::{AstNode, AstNodes},
+    write,
+};
+
+/// A replacement of `span` in the source text with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeEdit {
+    pub span: Span,
+    pub text: String,
+}
+
+/// The kind of nodes a range program is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitKind {
+    /// The statements are the body of the program.
+    Statement,
+    /// The class elements are in the only statement of the program, `(class { ... })`.
+    ClassElement,
+    /// The object properties are in the only statement of the program, `({ ... })`.
+    ObjectProperty,
+}
+
+/// Returns the span of the format units to format for `range`, `None` if the range doesn't
+/// touch any of them.
+///
+/// Like in Prettier, the range is first trimmed, and the innermost units containing its
+/// start and its end are expanded to ancestors until they are siblings.
+pub fn units_range(program: &Program<'_>, range: Span) -> Option<Span> {
+    let source_len = u32::try_from(program.source_text.len()).unwrap_or(u32::MAX);
+    let range = Span::new(range.start.min(source_len), range.end.min(source_len));
+    if range.start > range.end {
+        return None;
+    }
+    let range_text = range.source_text(program.source_text);
+    let leading_whitespace = range_text.len() - range_text.trim_start().len();
+    #[expect(clippy::cast_possible_truncation)]
+    let (start, end) = if range_text.trim().is_empty() {
+        (range.start, range.start)
+    } else {
+        (range.start + leading_whitespace as u32, range.start + range_text.trim_end().len() as u32)
+    };
+
+    let start_ancestors = UnitsAtOffset::find(program, start, RangeBound::Start);
+    let end_ancestors = UnitsAtOffset::find(program, end, RangeBound::End);
+    let (&start_statement, &end_statement) = (start_ancestors.last()?, end_ancestors.last()?);
+
+    let mut start_node = start_statement;
+    let mut end_node = end_statement;
+    if start_node != end_node {
+        for &parent in end_ancestors.iter().rev().skip(1) {
+            if parent.start >= start_statement.start {
+                end_node = parent;
+            } else {
+                break;
+            }
+        }
+        for &parent in start_ancestors.iter().rev().skip(1) {
+            if parent.end <= end_statement.end {
+                start_node = parent;
+            } else {
+                break;
+            }
+            if start_node == end_node {
+                break;
+            }
+        }
+    }
+
+    Some(Span::new(start_node.start.min(end_node.start), start_node.end.max(end_node.end)))
+}
+
+/// Returns a program with the format units in `span` and the comments between them.
+pub fn range_program<'a>(
+    program: &Program<'a>,
+    span: Span,
+    allocator: &'a Allocator,
+) -> (Program<'a>, UnitKind) {
+    let mut collector = CollectUnits {
+        span,
+        allocator,
+        statements: Vec::new_in(allocator),
+        class_elements: Vec::new_in(allocator),
+        object_properties: Vec::new_in(allocator),
+    };
+    collector.visit_program(program);
+
+    let ast = AstBuilder::new(allocator);
+    // The units in the span are siblings, so they are all of the same kind.
+    let (kind, statements) = if !collector.class_elements.is_empty() {
+        let body = ast.class_body(span, collector.class_elements);
+        let class = ast.expression_class(
+            span,
+            ClassType::ClassExpression,
+            ast.vec(),
+            None,
+            None::<TSTypeParameterDeclaration>,
+            None,
+            None::<TSTypeParameterInstantiation>,
+            ast.vec(),
+            body,
+            false,
+            false,
+        );
+        (UnitKind::ClassElement, ast.vec1(ast.statement_expression(span, class)))
+    } else if !collector.object_properties.is_empty() {
+        let object = ast.expression_object(span, collector.object_properties);
+        (UnitKind::ObjectProperty, ast.vec1(ast.statement_expression(span, object)))
+    } else {
+        (UnitKind::Statement, collector.statements)
+    };
+
+    let comments = Vec::from_iter_in(
+        program.comments.iter().filter(|comment| span.contains_inclusive(comment.span)).copied(),
+        allocator,
+    );
+    let program = ast.program(
+        span,
+        program.source_type,
+        program.source_text,
+        comments,
+        None,
+        ast.vec(),
+        statements,
+    );
+    (program, kind)
+}
+
+/// Returns the width of the indentation of the line containing `offset`, counting tabs as
+/// `indent_width` columns.
+pub fn indentation_width(source_text: &str, offset: u32, indent_width: u8) -> u16 {
+    let before = &source_text[..offset as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let indent_width = u16::from(indent_width);
+    before[line_start..].chars().take_while(|c| c.is_whitespace()).fold(0, |width, c| {
+        if c == '\t' {
+            width + indent_width - width.checked_rem(indent_width).unwrap_or(0)
+        } else {
+            width + 1
+        }
+    })
+}
+
+/// Formats a program that is a range of the source text. A line break is printed first, so the
+/// first line is indented and measured like the following ones; it must be trimmed afterwards.
+pub struct FormatRangeProgram<'a, 'b> {
+    pub program: &'b AstNode<'a, Program<'a>>,
+    pub kind: UnitKind,
+    pub indent_level: u16,
+    pub align: u8,
+}
+
+impl<'a> Format<'a> for FormatRangeProgram<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if self.indent_level > 0 {
+            let inner = FormatRangeProgram { indent_level: self.indent_level - 1, ..*self };
+            return write!(f, indent(&inner));
+        }
+
+        let content = format_with(|f| {
+            write!(f, hard_line_break())?;
+            if self.kind == UnitKind::Statement {
+                return write!(f, self.program);
+            }
+            let Some(statement) = self.program.body().first() else { return Ok(()) };
+            let AstNodes::ExpressionStatement(statement) = statement.as_ast_nodes() else {
+                return Ok(());
+            };
+            match statement.expression().as_ast_nodes() {
+                AstNodes::Class(class) => write!(f, class.body().body()),
+                AstNodes::ObjectExpression(object) => {
+                    // Unlike in an object, the last property has no trailing comma, and the
+                    // properties stay on the same line if they were.
+                    let properties = object.properties();
+                    for (index, property) in properties.iter().enumerate() {
+                        if index > 0 {
+                            match get_lines_before(property.span(), f) {
+                                0 => write!(f, space())?,
+                                1 => write!(f, hard_line_break())?,
+                                _ => write!(f, empty_line())?,
+                            }
+                        }
+                        write!(f, property)?;
+                        if index + 1 < properties.len() {
+                            write!(f, ",")?;
+                        }
+                    }
+                    Ok(())
+                }
+                _ => Ok(()),
+            }
+        });
+        if self.align > 0 { write!(f, align(self.align, &content)) } else { write!(f, content) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RangeBound {
+    Start,
+    End,
+}
+
+/// Finds the spans of the format units containing an offset, from the outermost to the innermost.
+/// The start of a range is not in a unit ending at it, the end of a range is not in a unit
+/// starting at it.
+struct UnitsAtOffset {
+    offset: u32,
+    bound: RangeBound,
+    ancestors: std::vec::Vec<Span>,
+}
+
+impl UnitsAtOffset {
+    fn find(program: &Program<'_>, offset: u32, bound: RangeBound) -> std::vec::Vec<Span> {
+        let mut finder = Self { offset, bound, ancestors: vec![] };
+        finder.visit_program(program);
+        finder.ancestors
+    }
+
+    fn contains(&self, span: Span) -> bool {
+        match self.bound {
+            RangeBound::Start => span.start <= self.offset && self.offset < span.end,
+            RangeBound::End => span.start < self.offset && self.offset <= span.end,
+        }
+    }
+
+    /// Records `span` and returns whether the unit contains the offset.
+    fn enter(&mut self, span: Span) -> bool {
+        let contains = self.contains(span);
+        if contains {
+            self.ancestors.push(span);
+        }
+        contains
+    }
+}
+
+impl<'a> Visit<'a> for UnitsAtOffset {
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if self.enter(it.span()) {
+            walk_statement(self, it);
+        }
+    }
+
+    fn visit_class_element(&mut self, it: &ClassElement<'a>) {
+        if self.enter(it.span()) {
+            walk_class_element(self, it);
+        }
+    }
+
+    fn visit_object_property_kind(&mut self, it: &ObjectPropertyKind<'a>) {
+        if self.enter(it.span()) {
+            walk_object_property_kind(self, it);
+        }
+    }
+}
+
+/// Collects the outermost format units in a span.
+struct CollectUnits<'a> {
+    span: Span,
+    allocator: &'a Allocator,
+    statements: Vec<'a, Statement<'a>>,
+    class_elements: Vec<'a, ClassElement<'a>>,
+    object_properties: Vec<'a, ObjectPropertyKind<'a>>,
+}
+
+impl CollectUnits<'_> {
+    /// Whether a unit is in the span. Units overlapping it are walked to find the units in it.
+    fn is_in_span(&self, span: Span) -> Option<bool> {
+        if self.span.contains_inclusive(span) {
+            Some(true)
+        } else if span.start < self.span.end && self.span.start < span.end {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Visit<'a> for CollectUnits<'a> {
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        match self.is_in_span(it.span()) {
+            Some(true) => self.statements.push(it.clone_in(self.allocator)),
+            Some(false) => walk_statement(self, it),
+            None => {}
+        }
+    }
+
+    fn visit_class_element(&mut self, it: &ClassElement<'a>) {
+        match self.is_in_span(it.span()) {
+            Some(true) => self.class_elements.push(it.clone_in(self.allocator)),
+            Some(false) => walk_class_element(self, it),
+            None => {}
+        }
+    }
+
+    fn visit_object_property_kind(&mut self, it: &ObjectPropertyKind<'a>) {
+        match self.is_in_span(it.span()) {
+            Some(true) => self.object_properties.push(it.clone_in(self.allocator)),
+            Some(false) => walk_object_property_kind(self, it),
+            None => {}
+        }
+    }
+}
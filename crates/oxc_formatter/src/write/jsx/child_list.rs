@@ -0,0 +1,851 @@
+use std::cell::RefCell;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use crate::{
+    format_args,
+    formatter::{
+        Buffer, Format, FormatElement, FormatResult, Formatter, VecBuffer,
+        comments::{SuppressionKind, is_suppression_comment},
+        prelude::*,
+    },
+    generated::ast_nodes::AstNode,
+    write,
+};
+
+use super::{JsxRawSpace, JsxSpace, is_meaningful_jsx_text, is_whitespace_jsx_expression};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum JsxChildListLayout {
+    /// Prefers to format the children on a single line if possible.
+    #[default]
+    BestFitting,
+
+    /// Forces the children to be formatted over multiple lines
+    Multiline,
+}
+
+impl JsxChildListLayout {
+    const fn is_multiline(self) -> bool {
+        matches!(self, JsxChildListLayout::Multiline)
+    }
+}
+
+pub enum FormatChildrenResult<'a> {
+    ForceMultiline(FormatMultilineChildren<'a>),
+    BestFitting {
+        flat_children: FormatFlatChildren<'a>,
+        expanded_children: FormatMultilineChildren<'a>,
+    },
+}
+
+/// Formats the children of a JSX element or fragment following Prettier's algorithm: text is
+/// split into words which fill the lines, other children are separated by line breaks, and
+/// meaningful whitespace next to a line break is printed as `{" "}`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FormatJsxChildList {
+    layout: JsxChildListLayout,
+}
+
+impl FormatJsxChildList {
+    pub fn new(layout: JsxChildListLayout) -> Self {
+        Self { layout }
+    }
+
+    pub fn fmt_children<'a>(
+        self,
+        list: &AstNode<'a, Vec<'a, JSXChild<'a>>>,
+        f: &mut Formatter<'_, 'a>,
+    ) -> FormatResult<FormatChildrenResult<'a>> {
+        let children_meta = Self::children_meta(list, f);
+        let layout = self.layout(children_meta);
+
+        let multiline_layout = if children_meta.meaningful_text {
+            MultilineLayout::Fill
+        } else {
+            MultilineLayout::NoFill
+        };
+
+        let mut flat = FlatBuilder::new();
+        let mut multiline = MultilineBuilder::new(multiline_layout);
+
+        let mut force_multiline = layout.is_multiline();
+
+        let mut children = jsx_split_children(list, f);
+
+        // Trim trailing new lines
+        if let Some(JsxChild::EmptyLine | JsxChild::Newline) = children.last() {
+            children.pop();
+        }
+
+        let mut last: Option<&JsxChild> = None;
+        // Whether the next non-text child follows a `{/* prettier-ignore */}`.
+        let mut is_next_suppressed = false;
+        let mut children_iter = children.iter().peekable();
+
+        // Trim leading new lines
+        if let Some(JsxChild::Newline | JsxChild::EmptyLine) = children_iter.peek() {
+            children_iter.next();
+        }
+
+        while let Some(child) = children_iter.next() {
+            let mut child_breaks = false;
+
+            match child {
+                // A single word: Both `a` and `b` are a word in `a b` because they're separated by JSX Whitespace.
+                JsxChild::Word(word) => {
+                    let separator = match children_iter.peek() {
+                        // Separate words by a space or line break in extended mode
+                        Some(JsxChild::Word(_)) => Some(WordSeparator::BetweenWords),
+
+                        // Last word or last word before an element without any whitespace in between
+                        Some(JsxChild::NonText(next_child)) => Some(WordSeparator::EndOfText {
+                            is_soft_line_break: !is_self_closing_element(next_child)
+                                || word.is_single_character(),
+                        }),
+
+                        Some(JsxChild::Newline | JsxChild::Whitespace | JsxChild::EmptyLine)
+                        | None => None,
+                    };
+
+                    child_breaks = separator.is_some_and(WordSeparator::will_break);
+
+                    flat.write(&format_args!(word, separator), f);
+
+                    if let Some(separator) = separator {
+                        multiline.write_with_separator(word, &separator, f);
+                    } else {
+                        // it's safe to write without a separator because None means that next element is a separator or end of the iterator
+                        multiline.write_content(word, f);
+                    }
+                }
+
+                // * Whitespace after the opening tag and before a meaningful text: `<div> a`
+                // * Whitespace before the closing tag: `a </div>`
+                // * Whitespace before an opening tag: `a <div>`
+                JsxChild::Whitespace => {
+                    flat.write(&JsxSpace, f);
+
+                    // ```javascript
+                    // <div>a
+                    // {' '}</div>
+                    // ```
+                    let is_after_line_break = last.is_some_and(JsxChild::is_any_line);
+
+                    // `<div>aaa </div>` or `<div> </div>`
+                    let is_trailing_or_only_whitespace = children_iter.peek().is_none();
+
+                    if is_trailing_or_only_whitespace || is_after_line_break {
+                        multiline.write_separator(&JsxRawSpace, f);
+                    }
+                    // Leading whitespace. Only possible if used together with a expression child
+                    //
+                    // ```
+                    // <div>
+                    //
+                    //   {' '}
+                    //   <b />
+                    // </div>
+                    // ```
+                    else if last.is_none() {
+                        multiline.write_with_separator(&JsxRawSpace, &hard_line_break(), f);
+                    } else {
+                        multiline.write_separator(&JsxSpace, f);
+                    }
+                }
+
+                // A new line between some JSX text and an element
+                JsxChild::Newline => {
+                    let is_soft_break = {
+                        // Here we handle the case when we have a newline between a single character word and a jsx element
+                        // We need to use the previous and the next element
+                        // [JsxChild::Word, JsxChild::Newline, JsxChild::NonText]
+                        // ```
+                        // <div>
+                        //   <div>First</div>,
+                        //   <div>Second</div>
+                        // </div>
+                        // ```
+                        if let Some(JsxChild::Word(word)) = last {
+                            let is_next_element_self_closing = matches!(
+                                children_iter.peek(),
+                                Some(JsxChild::NonText(child)) if is_self_closing_element(child)
+                            );
+                            !is_next_element_self_closing && word.is_single_character()
+                        }
+                        // Here we handle the case when we have a single character word between a new line and a jsx element
+                        // Here we need to look ahead two elements
+                        // [JsxChild::Newline, JsxChild::Word, JsxChild::NonText]
+                        // ```
+                        // <div>
+                        //   <div>First</div>
+                        //   ,<div>Second</div>
+                        // </div>
+                        // ```
+                        else if let Some(JsxChild::Word(next_word)) = children_iter.peek() {
+                            let mut lookahead = children_iter.clone();
+                            lookahead.next();
+                            let is_next_next_element_self_closing = matches!(
+                                lookahead.peek(),
+                                Some(JsxChild::NonText(child)) if is_self_closing_element(child)
+                            );
+
+                            !is_next_next_element_self_closing && next_word.is_single_character()
+                        } else {
+                            false
+                        }
+                    };
+
+                    if is_soft_break {
+                        multiline.write_separator(&soft_line_break(), f);
+                    } else {
+                        child_breaks = true;
+                        multiline.write_separator(&hard_line_break(), f);
+                    }
+                }
+
+                // An empty line between some JSX text and an element
+                JsxChild::EmptyLine => {
+                    child_breaks = true;
+
+                    multiline.write_separator(&empty_line(), f);
+                }
+
+                // Any child that isn't text
+                JsxChild::NonText(child) => {
+                    let non_text = &FormatNonText { child, is_suppressed: is_next_suppressed };
+                    is_next_suppressed = is_suppression_container(child, f);
+                    let line_mode = match children_iter.peek() {
+                        Some(JsxChild::Word(word)) => {
+                            // Break if the current or next element is a self closing element
+                            // ```javascript
+                            // <pre className="h-screen overflow-y-scroll" />adefg
+                            // ```
+                            // Becomes
+                            // ```javascript
+                            // <pre className="h-screen overflow-y-scroll" />
+                            // adefg
+                            // ```
+                            if is_self_closing_element(child) && !word.is_single_character() {
+                                Some(LineMode::Hard)
+                            } else {
+                                Some(LineMode::Soft)
+                            }
+                        }
+
+                        // Add a hard line break if what comes after the element is not a text or is all whitespace
+                        Some(JsxChild::NonText(_)) => Some(LineMode::Hard),
+
+                        // Don't insert trailing line breaks
+                        Some(JsxChild::Newline | JsxChild::Whitespace | JsxChild::EmptyLine)
+                        | None => None,
+                    };
+
+                    child_breaks = line_mode.is_some_and(|mode| mode == LineMode::Hard);
+
+                    let format_separator = line_mode.map(|mode| match mode {
+                        LineMode::Hard => hard_line_break(),
+                        LineMode::Soft => soft_line_break(),
+                    });
+
+                    if force_multiline {
+                        if let Some(format_separator) = format_separator {
+                            multiline.write_with_separator(non_text, &format_separator, f);
+                        } else {
+                            // it's safe to write without a separator because None means that next element is a separator or end of the iterator
+                            multiline.write_content(non_text, f);
+                        }
+                    } else {
+                        let mut memoized = non_text.memoized();
+
+                        force_multiline = memoized.inspect(f)?.will_break();
+                        flat.write(&format_args!(memoized, format_separator), f);
+
+                        if let Some(format_separator) = format_separator {
+                            multiline.write_with_separator(&memoized, &format_separator, f);
+                        } else {
+                            // it's safe to write without a separator because None means that next element is a separator or end of the iterator
+                            multiline.write_content(&memoized, f);
+                        }
+                    }
+                }
+            }
+
+            if matches!(child, JsxChild::Word(_)) {
+                is_next_suppressed = false;
+            }
+
+            if child_breaks {
+                flat.disable();
+                force_multiline = true;
+            }
+
+            last = Some(child);
+        }
+
+        if force_multiline {
+            Ok(FormatChildrenResult::ForceMultiline(multiline.finish()?))
+        } else {
+            Ok(FormatChildrenResult::BestFitting {
+                flat_children: flat.finish()?,
+                expanded_children: multiline.finish()?,
+            })
+        }
+    }
+
+    fn layout(self, meta: ChildrenMeta) -> JsxChildListLayout {
+        match self.layout {
+            JsxChildListLayout::BestFitting => {
+                if meta.any_tag || meta.multiple_expressions {
+                    JsxChildListLayout::Multiline
+                } else {
+                    JsxChildListLayout::BestFitting
+                }
+            }
+            JsxChildListLayout::Multiline => JsxChildListLayout::Multiline,
+        }
+    }
+
+    /// Computes additional meta data about the children by iterating once over all children.
+    fn children_meta<'a>(
+        list: &AstNode<'a, Vec<'a, JSXChild<'a>>>,
+        f: &Formatter<'_, 'a>,
+    ) -> ChildrenMeta {
+        let mut meta = ChildrenMeta::default();
+        let mut has_expression = false;
+
+        for child in list {
+            match child.as_ref() {
+                JSXChild::Element(_) | JSXChild::Fragment(_) => meta.any_tag = true,
+                JSXChild::ExpressionContainer(container) => {
+                    if is_whitespace_jsx_expression(container, f) {
+                        meta.meaningful_text = true;
+                    } else {
+                        meta.multiple_expressions = has_expression;
+                        has_expression = true;
+                    }
+                }
+                JSXChild::Text(text) => {
+                    meta.meaningful_text = meta.meaningful_text
+                        || is_meaningful_jsx_text(text.span.source_text(f.source_text()));
+                }
+                JSXChild::Spread(_) => {}
+            }
+        }
+
+        meta
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct ChildrenMeta {
+    /// `true` if children contains a [JSXElement] or [JSXFragment]
+    any_tag: bool,
+
+    /// `true` if children contains more than one [JSXExpressionContainer]
+    multiple_expressions: bool,
+
+    /// `true` if any child is a [JSXText] with meaningful text, or a `{" "}` whitespace.
+    meaningful_text: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum LineMode {
+    Soft,
+    Hard,
+}
+
+/// Returns `true` if `child` is an element without a closing tag, e.g. `<br />`.
+fn is_self_closing_element(child: &AstNode<'_, JSXChild<'_>>) -> bool {
+    matches!(child.as_ref(), JSXChild::Element(element) if element.closing_element.is_none())
+}
+
+/// Returns `true` if `child` is `{/* prettier-ignore */}`, which suppresses the formatting of the
+/// next child that isn't text.
+fn is_suppression_container(child: &AstNode<'_, JSXChild<'_>>, f: &Formatter<'_, '_>) -> bool {
+    let JSXChild::ExpressionContainer(container) = child.as_ref() else { return false };
+    matches!(container.expression, JSXExpression::EmptyExpression(_))
+        && f.context()
+            .comments()
+            .filter_comments_in_span(container.span)
+            .any(|comment| is_suppression_comment(comment, f.source_text(), SuppressionKind::Node))
+}
+
+/// A child that isn't text, printed as it is in the source if it is suppressed.
+struct FormatNonText<'a, 'b> {
+    child: &'b AstNode<'a, JSXChild<'a>>,
+    is_suppressed: bool,
+}
+
+impl<'a> Format<'a> for FormatNonText<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if self.is_suppressed {
+            format_suppressed_node(self.child.span()).fmt(f)
+        } else {
+            self.child.fmt(f)
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum WordSeparator {
+    /// Separator between two words. Creates a soft line break or space.
+    ///
+    /// `a b`
+    BetweenWords,
+
+    /// A separator of a word at the end of a [JSXText] element. Either because it is the last
+    /// child in its parent OR it is right before the start of another child (element, expression, ...).
+    ///
+    /// ```javascript
+    /// <div>a</div>; // last element of parent
+    /// <div>a<other /></div> // last element before another element
+    /// <div>a{expression}</div> // last element before expression
+    /// ```
+    ///
+    /// Creates a soft line break EXCEPT if the next element is a self closing element
+    /// and the word is longer than a single character, which results in a hard line break:
+    ///
+    /// ```javascript
+    /// a = <div>ab<br/></div>;
+    ///
+    /// // becomes
+    ///
+    /// a = (
+    ///     <div>
+    ///         ab
+    ///         <br />
+    ///     </div>
+    /// );
+    /// ```
+    EndOfText { is_soft_line_break: bool },
+}
+
+impl WordSeparator {
+    /// Returns if formatting this separator will result in a child that expands
+    fn will_break(self) -> bool {
+        matches!(self, WordSeparator::EndOfText { is_soft_line_break: false })
+    }
+}
+
+impl<'a> Format<'a> for WordSeparator {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self {
+            WordSeparator::BetweenWords => soft_line_break_or_space().fmt(f),
+            WordSeparator::EndOfText { is_soft_line_break } => {
+                if *is_soft_line_break {
+                    soft_line_break().fmt(f)
+                }
+                // ```javascript
+                // <div>ab<br/></div>
+                // ```
+                // Becomes
+                //
+                // ```javascript
+                // <div>
+                //  ab
+                //  <br />
+                // </div>
+                // ```
+                else {
+                    hard_line_break().fmt(f)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+enum MultilineLayout {
+    Fill,
+    #[default]
+    NoFill,
+}
+
+/// Builder that helps to create the output for the multiline layout.
+///
+/// The multiline layout breaks all lines but tries to fill as many children on the same line as possible
+/// (at least in the fill layout)
+struct MultilineBuilder<'a> {
+    layout: MultilineLayout,
+    result: FormatResult<std::vec::Vec<FormatElement<'a>>>,
+}
+
+impl<'a> MultilineBuilder<'a> {
+    fn new(layout: MultilineLayout) -> Self {
+        Self { layout, result: Ok(std::vec::Vec::new()) }
+    }
+
+    /// Formats an element that does not require a separator
+    /// It is safe to omit the separator because at the call side we must guarantee that we have reached the end of the iterator
+    /// or the next element is a space/newline that should be written into the separator "slot".
+    fn write_content(&mut self, content: &dyn Format<'a>, f: &mut Formatter<'_, 'a>) {
+        self.write(content, None, f);
+    }
+
+    /// Formatting a separator does not require any element in the separator slot
+    fn write_separator(&mut self, separator: &dyn Format<'a>, f: &mut Formatter<'_, 'a>) {
+        self.write(separator, None, f);
+    }
+
+    fn write_with_separator(
+        &mut self,
+        content: &dyn Format<'a>,
+        separator: &dyn Format<'a>,
+        f: &mut Formatter<'_, 'a>,
+    ) {
+        self.write(content, Some(separator), f);
+    }
+
+    fn write(
+        &mut self,
+        content: &dyn Format<'a>,
+        separator: Option<&dyn Format<'a>>,
+        f: &mut Formatter<'_, 'a>,
+    ) {
+        let result = std::mem::replace(&mut self.result, Ok(std::vec::Vec::new()));
+
+        self.result = result.and_then(|elements| {
+            let mut buffer = VecBuffer::new_with_vec(f.state_mut(), elements);
+            match self.layout {
+                MultilineLayout::Fill => {
+                    // Make sure that the separator and content only ever write a single element
+                    buffer.write_element(FormatElement::Tag(Tag::StartEntry))?;
+                    write!(buffer, [content])?;
+                    buffer.write_element(FormatElement::Tag(Tag::EndEntry))?;
+
+                    if let Some(separator) = separator {
+                        buffer.write_element(FormatElement::Tag(Tag::StartEntry))?;
+                        write!(buffer, [separator])?;
+                        buffer.write_element(FormatElement::Tag(Tag::EndEntry))?;
+                    }
+                }
+                MultilineLayout::NoFill => {
+                    write!(buffer, [content, separator])?;
+                }
+            }
+            Ok(buffer.into_vec())
+        });
+    }
+
+    fn finish(self) -> FormatResult<FormatMultilineChildren<'a>> {
+        Ok(FormatMultilineChildren { layout: self.layout, elements: RefCell::new(self.result?) })
+    }
+}
+
+pub struct FormatMultilineChildren<'a> {
+    layout: MultilineLayout,
+    elements: RefCell<std::vec::Vec<FormatElement<'a>>>,
+}
+
+impl<'a> Format<'a> for FormatMultilineChildren<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let format_inner = format_once(|f| {
+            if let Some(elements) = f.intern_vec(self.elements.take()) {
+                match self.layout {
+                    MultilineLayout::Fill => {
+                        f.write_element(FormatElement::Tag(Tag::StartFill))?;
+                        f.write_element(elements)?;
+                        f.write_element(FormatElement::Tag(Tag::EndFill))?;
+                    }
+                    MultilineLayout::NoFill => {
+                        write!(
+                            f,
+                            [group(&format_once(|f| f.write_element(elements)))
+                                .should_expand(true)]
+                        )?;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        // This indent is wrapped with a group to ensure that the print mode is
+        // set to `Expanded` when the group prints and will guarantee that the
+        // content _does not_ fit when printed as part of a `Fill`. Example:
+        //   <div>
+        //     <span a b>
+        //       <Foo />
+        //     </span>{" "}
+        //     ({variable})
+        //   </div>
+        // Here, the `<span>` element gets formatted using the `MultilineBuilder`, which
+        // wraps everything in a `Fill`. The `Fill` checks if the `<span>` fits on the line,
+        // which it would in flat mode even though its children are forced to break.
+        write!(f, [group(&block_indent(&format_inner))])
+    }
+}
+
+struct FlatBuilder<'a> {
+    result: FormatResult<std::vec::Vec<FormatElement<'a>>>,
+    disabled: bool,
+}
+
+impl<'a> FlatBuilder<'a> {
+    fn new() -> Self {
+        Self { result: Ok(std::vec::Vec::new()), disabled: false }
+    }
+
+    fn write(&mut self, content: &dyn Format<'a>, f: &mut Formatter<'_, 'a>) {
+        if self.disabled {
+            return;
+        }
+
+        let result = std::mem::replace(&mut self.result, Ok(std::vec::Vec::new()));
+
+        self.result = result.and_then(|elements| {
+            let mut buffer = VecBuffer::new_with_vec(f.state_mut(), elements);
+
+            write!(buffer, [content])?;
+
+            Ok(buffer.into_vec())
+        });
+    }
+
+    fn disable(&mut self) {
+        self.disabled = true;
+    }
+
+    fn finish(self) -> FormatResult<FormatFlatChildren<'a>> {
+        assert!(
+            !self.disabled,
+            "The flat builder has been disabled and thus, does no longer store any elements. Make sure you don't call disable if you later intend to format the flat content."
+        );
+
+        Ok(FormatFlatChildren { elements: RefCell::new(self.result?) })
+    }
+}
+
+pub struct FormatFlatChildren<'a> {
+    elements: RefCell<std::vec::Vec<FormatElement<'a>>>,
+}
+
+impl<'a> Format<'a> for FormatFlatChildren<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if let Some(elements) = f.intern_vec(self.elements.take()) {
+            f.write_element(elements)?;
+        }
+        Ok(())
+    }
+}
+
+/// An element of a JSX child list.
+#[derive(Debug, Clone, Copy)]
+enum JsxChild<'a> {
+    /// A Single word in a JSX text. For example, the words for `a b\nc` are `[a, b, c]`
+    Word(JsxWord<'a>),
+
+    /// A ` ` or `{" "}` whitespace
+    ///
+    /// ```javascript
+    /// <div> </div>
+    /// <div>a </div>
+    /// <div> a</div>
+    /// <div>{' '}a</div>
+    /// <div>a{' '}</div>
+    /// <div>{' '}</div>
+    /// <div>a
+    /// {' '}b</div>
+    /// ```
+    ///
+    /// Whitespace between two words is not represented as whitespace
+    /// ```javascript
+    /// <div>a b</div>
+    /// ```
+    /// The space between `a` and `b` is not considered a whitespace.
+    Whitespace,
+
+    /// A new line at the start or end of a [JSXText] with meaningful content. (that isn't all whitespace
+    /// and contains a new line).
+    ///
+    /// ```javascript
+    /// <div>
+    ///     a
+    /// </div>
+    /// ```
+    Newline,
+
+    /// A [JSXText] that only consists of whitespace and has at least two line breaks;
+    ///
+    /// ```javascript
+    /// <div>
+    ///   <test />
+    ///
+    ///   <test />
+    /// </div>
+    /// ```
+    ///
+    /// The text between `<test />` and `<test />` is an empty line text.
+    EmptyLine,
+
+    /// Any other content that isn't a text. Should be formatted as is.
+    NonText(&'a AstNode<'a, JSXChild<'a>>),
+}
+
+impl JsxChild<'_> {
+    const fn is_any_line(&self) -> bool {
+        matches!(self, JsxChild::EmptyLine | JsxChild::Newline)
+    }
+}
+
+/// A word in a Jsx Text. A word is string sequence that isn't separated by any JSX whitespace.
+#[derive(Debug, Clone, Copy)]
+struct JsxWord<'a> {
+    text: &'a str,
+}
+
+impl JsxWord<'_> {
+    fn is_single_character(&self) -> bool {
+        self.text.chars().count() == 1
+    }
+}
+
+impl<'a> Format<'a> for JsxWord<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        dynamic_text(self.text).fmt(f)
+    }
+}
+
+/// Splits the children into words, whitespace, line breaks and non-text children.
+fn jsx_split_children<'a>(
+    list: &AstNode<'a, Vec<'a, JSXChild<'a>>>,
+    f: &Formatter<'_, 'a>,
+) -> std::vec::Vec<JsxChild<'a>> {
+    let mut builder = JsxSplitChildrenBuilder::default();
+
+    for child in list {
+        match child.as_ref() {
+            JSXChild::Text(text) => {
+                // Split the text into words
+                // Keep track if there's any leading/trailing empty line, new line or whitespace
+                let mut chunks =
+                    JsxSplitChunksIterator::new(text.span.source_text(f.source_text())).peekable();
+
+                // Text starting with a whitespace
+                if let Some(JsxTextChunk::Whitespace(whitespace)) = chunks.peek().copied() {
+                    chunks.next();
+
+                    if whitespace.contains('\n') {
+                        if chunks.peek().is_none() {
+                            // A text only consisting of whitespace that also contains a new line isn't considered meaningful text.
+                            // It can be entirely removed from the content without changing the semantics.
+                            // Keep up to one blank line between tags/expressions/text.
+                            // ```javascript
+                            // <div>
+                            //
+                            //   <MyElement />
+                            // </div>
+                            // ```
+                            if whitespace.matches('\n').count() > 1 {
+                                builder.entry(JsxChild::EmptyLine);
+                            }
+
+                            continue;
+                        }
+
+                        builder.entry(JsxChild::Newline);
+                    } else {
+                        builder.entry(JsxChild::Whitespace);
+                    }
+                }
+
+                while let Some(chunk) = chunks.next() {
+                    match chunk {
+                        JsxTextChunk::Whitespace(whitespace) => {
+                            // Only handle trailing whitespace. Words must always be joined by new lines
+                            if chunks.peek().is_none() {
+                                if whitespace.contains('\n') {
+                                    builder.entry(JsxChild::Newline);
+                                } else {
+                                    builder.entry(JsxChild::Whitespace);
+                                }
+                            }
+                        }
+                        JsxTextChunk::Word(word) => {
+                            builder.entry(JsxChild::Word(JsxWord { text: word }));
+                        }
+                    }
+                }
+            }
+            JSXChild::ExpressionContainer(container)
+                if is_whitespace_jsx_expression(container, f) =>
+            {
+                builder.entry(JsxChild::Whitespace);
+            }
+            _ => builder.entry(JsxChild::NonText(child)),
+        }
+    }
+
+    builder.buffer
+}
+
+/// The builder is used to:
+/// 1. Remove [JsxChild::EmptyLine], [JsxChild::Newline], [JsxChild::Whitespace] if a next element is [JsxChild::Whitespace]
+/// 2. Don't push a new element [JsxChild::EmptyLine], [JsxChild::Newline], [JsxChild::Whitespace] if previous one is [JsxChild::EmptyLine], [JsxChild::Newline], [JsxChild::Whitespace]
+///
+/// [Prettier applies]: <https://github.com/prettier/prettier/blob/b0d9387b95cdd4e9d50f5999d3be53b0b5d03a97/src/language-js/print/jsx.js#L144-L180>
+#[derive(Default)]
+struct JsxSplitChildrenBuilder<'a> {
+    buffer: std::vec::Vec<JsxChild<'a>>,
+}
+
+impl<'a> JsxSplitChildrenBuilder<'a> {
+    fn entry(&mut self, child: JsxChild<'a>) {
+        match self.buffer.last_mut() {
+            Some(last @ (JsxChild::EmptyLine | JsxChild::Newline | JsxChild::Whitespace)) => {
+                if matches!(child, JsxChild::Whitespace) {
+                    *last = child;
+                } else if matches!(child, JsxChild::NonText(_) | JsxChild::Word(_)) {
+                    self.buffer.push(child);
+                }
+            }
+            _ => self.buffer.push(child),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum JsxTextChunk<'a> {
+    Whitespace(&'a str),
+    Word(&'a str),
+}
+
+/// Splits a text into whitespace only and non-whitespace chunks.
+struct JsxSplitChunksIterator<'a> {
+    position: usize,
+    text: &'a str,
+}
+
+impl<'a> JsxSplitChunksIterator<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { position: 0, text }
+    }
+}
+
+impl<'a> Iterator for JsxSplitChunksIterator<'a> {
+    type Item = JsxTextChunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.position..];
+        let first = rest.chars().next()?;
+        let is_whitespace = is_jsx_whitespace(first);
+
+        let len = rest.find(|c| is_jsx_whitespace(c) != is_whitespace).unwrap_or(rest.len());
+        let chunk = &rest[..len];
+        self.position += len;
+
+        Some(if is_whitespace {
+            JsxTextChunk::Whitespace(chunk)
+        } else {
+            JsxTextChunk::Word(chunk)
+        })
+    }
+}
+
+/// JSX only treats these characters as whitespace, e.g. a non-breaking space is part of a word.
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\n' | '\t' | '\r')
+}
@@ -0,0 +1,236 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+
+use crate::{
+    best_fitting, format_args,
+    formatter::{Buffer, Format, FormatResult, Formatter, prelude::*},
+    generated::ast_nodes::{AstNode, AstNodes},
+    write,
+};
+
+use super::{
+    child_list::{FormatChildrenResult, FormatJsxChildList, JsxChildListLayout},
+    is_meaningful_jsx_text,
+};
+
+/// A JSX element or fragment, which only differ in how their tags are formatted.
+pub enum AnyJsxTagWithChildren<'a, 'b> {
+    Element(&'b AstNode<'a, JSXElement<'a>>),
+    Fragment(&'b AstNode<'a, JSXFragment<'a>>),
+}
+
+impl<'a> AnyJsxTagWithChildren<'a, '_> {
+    fn parent(&self) -> &'a AstNodes<'a> {
+        match self {
+            Self::Element(element) => element.parent,
+            Self::Fragment(fragment) => fragment.parent,
+        }
+    }
+
+    fn children(&self) -> &AstNode<'a, Vec<'a, JSXChild<'a>>> {
+        match self {
+            Self::Element(element) => element.children(),
+            Self::Fragment(fragment) => fragment.children(),
+        }
+    }
+
+    fn fmt_opening(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self {
+            Self::Element(element) => write!(f, element.opening_element()),
+            Self::Fragment(fragment) => write!(f, fragment.opening_fragment()),
+        }
+    }
+
+    fn fmt_closing(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self {
+            Self::Element(element) => write!(f, element.closing_element()),
+            Self::Fragment(fragment) => write!(f, fragment.closing_fragment()),
+        }
+    }
+
+    fn has_multiple_attributes(&self) -> bool {
+        match self {
+            Self::Element(element) => element.opening_element.attributes.len() > 1,
+            Self::Fragment(_) => false,
+        }
+    }
+
+    fn layout(&self, f: &Formatter<'_, 'a>) -> ElementLayout {
+        match self.children().as_ref().as_slice() {
+            [] => ElementLayout::NoChildren,
+            [JSXChild::Text(text)]
+                if !is_meaningful_jsx_text(text.span.source_text(f.source_text())) =>
+            {
+                ElementLayout::NoChildren
+            }
+            [JSXChild::ExpressionContainer(container)]
+                if matches!(
+                    container.expression,
+                    JSXExpression::TemplateLiteral(_) | JSXExpression::TaggedTemplateExpression(_)
+                ) =>
+            {
+                ElementLayout::Template
+            }
+            _ => ElementLayout::Default,
+        }
+    }
+
+    fn fmt_tag(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let format_opening = format_with(|f| self.fmt_opening(f));
+        let format_closing = format_with(|f| self.fmt_closing(f));
+
+        match self.layout(f) {
+            ElementLayout::NoChildren => write!(f, [format_opening, format_closing]),
+            ElementLayout::Template => {
+                write!(f, [format_opening, self.children().first(), format_closing])
+            }
+            ElementLayout::Default => {
+                let mut format_opening = format_opening.memoized();
+                let opening_breaks = format_opening.inspect(f)?.will_break();
+
+                let list_layout = if self.has_multiple_attributes() || opening_breaks {
+                    JsxChildListLayout::Multiline
+                } else {
+                    JsxChildListLayout::BestFitting
+                };
+
+                let children =
+                    FormatJsxChildList::new(list_layout).fmt_children(self.children(), f)?;
+
+                match children {
+                    FormatChildrenResult::ForceMultiline(multiline) => {
+                        write!(f, [format_opening, multiline, format_closing])
+                    }
+                    FormatChildrenResult::BestFitting { flat_children, expanded_children } => {
+                        let format_closing = format_closing.memoized();
+                        write!(
+                            f,
+                            [best_fitting![
+                                format_args!(format_opening, flat_children, format_closing),
+                                format_args!(format_opening, expanded_children, format_closing)
+                            ]]
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for AnyJsxTagWithChildren<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let format_tag = format_with(|f| self.fmt_tag(f));
+
+        match get_wrap_state(self.parent()) {
+            WrapState::NoWrap => write!(f, [format_tag]),
+            WrapState::WrapOnBreak => {
+                write!(
+                    f,
+                    [group(&format_args!(
+                        if_group_breaks(&"("),
+                        soft_block_indent(&format_tag),
+                        if_group_breaks(&")")
+                    ))
+                    .should_expand(should_expand(self.parent()))]
+                )
+            }
+        }
+    }
+}
+
+enum ElementLayout {
+    /// Empty elements, or elements with only whitespace containing a line break:
+    ///
+    /// ```jsx
+    /// <div></div>
+    /// <div>
+    /// </div>
+    /// ```
+    NoChildren,
+
+    /// Elements whose only child is a template literal, which is printed as it is:
+    ///
+    /// ```jsx
+    /// <style>{`
+    ///   div { color: red; }
+    /// `}</style>
+    /// ```
+    Template,
+
+    /// All other elements.
+    Default,
+}
+
+/// Indicates whether a multiline JSX element is wrapped in parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WrapState {
+    NoWrap,
+    WrapOnBreak,
+}
+
+/// Multiline elements are wrapped in parentheses, except in these positions:
+///
+/// ```jsx
+/// [<div />];
+/// <div>{<span />}</div>;
+/// render(<App />);
+/// cond ? <div /> : null;
+/// ```
+fn get_wrap_state(parent: &AstNodes<'_>) -> WrapState {
+    match parent {
+        AstNodes::ArrayExpression(_)
+        | AstNodes::JSXAttribute(_)
+        | AstNodes::JSXElement(_)
+        | AstNodes::JSXExpressionContainer(_)
+        | AstNodes::JSXFragment(_)
+        | AstNodes::CallExpression(_)
+        | AstNodes::ConditionalExpression(_) => WrapState::NoWrap,
+        AstNodes::Argument(argument) if matches!(argument.parent, AstNodes::CallExpression(_)) => {
+            WrapState::NoWrap
+        }
+        // The expression body of an arrow function is an expression statement as well.
+        AstNodes::ExpressionStatement(statement) if !is_arrow_function_body(statement) => {
+            WrapState::NoWrap
+        }
+        _ => WrapState::WrapOnBreak,
+    }
+}
+
+fn is_arrow_function_body(statement: &AstNode<'_, ExpressionStatement<'_>>) -> bool {
+    matches!(
+        statement.parent,
+        AstNodes::FunctionBody(body)
+            if matches!(body.parent, AstNodes::ArrowFunctionExpression(arrow) if arrow.expression)
+    )
+}
+
+/// This is a very special situation where we're returning a JSX element
+/// from an arrow function that's passed as an argument to a function,
+/// which is itself inside a JSX expression container.
+///
+/// If you're wondering why this is the only other case, it's because
+/// Prettier defines it to be that way.
+///
+/// ```jsx
+///  let bar = <div>
+///    {foo(() => <div> the quick brown fox jumps over the lazy dog </div>)}
+///  </div>;
+/// ```
+fn should_expand(parent: &AstNodes<'_>) -> bool {
+    let AstNodes::ExpressionStatement(statement) = parent else {
+        return false;
+    };
+    if !is_arrow_function_body(statement) {
+        return false;
+    }
+
+    // `statement.parent`: FunctionBody
+    // `statement.parent.parent()`: ArrowFunctionExpression
+    // `statement.parent.parent().parent()`: Argument
+    let AstNodes::Argument(argument) = statement.parent.parent().parent() else {
+        return false;
+    };
+
+    matches!(argument.parent, AstNodes::CallExpression(_) | AstNodes::NewExpression(_))
+        && matches!(argument.parent.parent(), AstNodes::JSXExpressionContainer(_))
+}
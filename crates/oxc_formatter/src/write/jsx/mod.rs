@@ -0,0 +1,417 @@
+mod child_list;
+mod element;
+
+use std::borrow::Cow;
+
+use cow_utils::CowUtils;
+use oxc_ast::{Comment, ast::*};
+use oxc_span::GetSpan;
+
+use crate::{
+    QuoteStyle, format_args,
+    formatter::{Buffer, Format, FormatResult, Formatter, prelude::*},
+    generated::ast_nodes::{AstNode, AstNodes},
+    options::AttributePosition,
+    write,
+};
+
+use self::element::AnyJsxTagWithChildren;
+
+use super::FormatWrite;
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXElement<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        AnyJsxTagWithChildren::Element(self).fmt(f)
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXFragment<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        AnyJsxTagWithChildren::Fragment(self).fmt(f)
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXOpeningElement<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let is_self_closing = matches!(
+            self.parent,
+            AstNodes::JSXElement(element) if element.closing_element.is_none()
+        );
+        let name = self.name();
+        let type_arguments = self.type_arguments();
+        let attributes = self.attributes();
+
+        let comments = f.context().comments();
+        let name_has_comments = comments.has_comments_between(
+            self.span.start,
+            attributes.first().map_or(self.span.end, |attribute| attribute.span().start),
+        );
+
+        // Don't break self-closing elements with no attributes and no comments
+        if is_self_closing && attributes.is_empty() && !name_has_comments {
+            return write!(f, ["<", name, type_arguments, space(), "/>"]);
+        }
+
+        // Don't break up opening elements with a single long text attribute
+        if let [JSXAttributeItem::Attribute(attribute)] = attributes.as_ref().as_slice() {
+            if !name_has_comments
+                && is_single_line_string_attribute(attribute)
+                && !comments.has_comments_between(attribute.span.start, self.span.end)
+            {
+                return write!(
+                    f,
+                    [group(&format_args!(
+                        "<",
+                        name,
+                        type_arguments,
+                        space(),
+                        attributes.first(),
+                        is_self_closing.then_some(format_args!(space(), "/")),
+                        ">"
+                    ))]
+                );
+            }
+        }
+
+        // We should print the opening element expanded if any prop value is a
+        // string literal with a newline
+        let should_break = attributes.iter().any(|attribute| {
+            matches!(attribute.as_ref(), JSXAttributeItem::Attribute(attribute) if is_multiline_string_attribute(attribute))
+        });
+
+        let last_attribute_has_trailing_comments = attributes.last().is_some_and(|attribute| {
+            comments.has_comments_between(attribute.span().end, self.span.end)
+        });
+        // Simple tags (no attributes and no comment in tag name) should be kept unbroken
+        // regardless of `bracketSameLine`.
+        let bracket_same_line = (attributes.is_empty() && !name_has_comments)
+            || (f.options().bracket_same_line.value()
+                && (!name_has_comments || !attributes.is_empty())
+                && !last_attribute_has_trailing_comments);
+
+        let single_attribute_per_line =
+            f.options().attribute_position == AttributePosition::Multiline && attributes.len() > 1;
+
+        let format_attributes = format_with(|f| {
+            for attribute in attributes {
+                if single_attribute_per_line {
+                    write!(f, [hard_line_break(), attribute])?;
+                } else {
+                    write!(f, [soft_line_break_or_space(), attribute])?;
+                }
+            }
+            Ok(())
+        });
+
+        let format_end = format_with(|f| {
+            if is_self_closing {
+                write!(f, [soft_line_break_or_space(), "/>"])
+            } else if bracket_same_line {
+                write!(f, ">")
+            } else {
+                write!(f, [soft_line_break(), ">"])
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                "<",
+                name,
+                type_arguments,
+                indent(&format_attributes),
+                format_end
+            ))
+            .should_expand(should_break)]
+        )
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXClosingElement<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, ["</", self.name(), ">"])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXOpeningFragment> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, ["<", FormatFragmentComments(self.span), ">"])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXClosingFragment> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, ["</", FormatFragmentComments(self.span), ">"])
+    }
+}
+
+/// Formats the comments inside of a fragment tag, e.g. `< /* comment */ >`.
+struct FormatFragmentComments(Span);
+
+impl<'a> Format<'a> for FormatFragmentComments {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if !f.context().comments().has_comments_in_span(self.0) {
+            return Ok(());
+        }
+
+        let has_line_comment = has_line_comment_in_span(self.0, f);
+        write!(
+            f,
+            [
+                indent(&format_args!(
+                    has_line_comment.then_some(hard_line_break()),
+                    format_dangling_comments(self.0)
+                )),
+                has_line_comment.then_some(hard_line_break())
+            ]
+        )
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXNamespacedName<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [self.namespace(), ":", self.name()])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXMemberExpression<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, [self.object(), ".", self.property()])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXExpressionContainer<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let expression = self.expression();
+        let is_child = matches!(self.parent, AstNodes::JSXElement(_) | AstNodes::JSXFragment(_));
+
+        let has_comment = |span: Span| {
+            f.context().comments().has_comments_between(self.span.start, span.start)
+                || f.context().comments().has_comments_between(span.end, self.span.end)
+        };
+        let should_inline = match expression.as_ref() {
+            JSXExpression::EmptyExpression(_) => true,
+            expression => {
+                let expression = expression.to_expression();
+                !has_comment(expression.span())
+                    && should_inline_jsx_expression(expression, is_child)
+            }
+        };
+
+        if should_inline {
+            write!(f, [group(&format_args!("{", expression, line_suffix_boundary(), "}"))])
+        } else {
+            write!(
+                f,
+                [group(&format_args!(
+                    "{",
+                    soft_block_indent(&expression),
+                    line_suffix_boundary(),
+                    "}"
+                ))]
+            )
+        }
+    }
+}
+
+/// Expressions which are kept next to the curly braces of their JSX expression container,
+/// because they already break nicely on their own.
+fn should_inline_jsx_expression(expression: &Expression<'_>, is_child: bool) -> bool {
+    match expression {
+        Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::CallExpression(_)
+        | Expression::ImportExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::TaggedTemplateExpression(_) => true,
+        Expression::ChainExpression(chain) => {
+            matches!(chain.expression, ChainElement::CallExpression(_))
+        }
+        Expression::AwaitExpression(await_expression) => {
+            matches!(await_expression.argument, Expression::JSXElement(_))
+                || should_inline_jsx_expression(&await_expression.argument, false)
+        }
+        Expression::ConditionalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::LogicalExpression(_) => is_child,
+        _ => false,
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXEmptyExpression> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        // `{/* comment */}` or `{// comment\n}`, the closing curly brace can't be on the line
+        // of a line comment.
+        let has_line_comment = has_line_comment_in_span(self.span, f);
+        write!(
+            f,
+            [format_dangling_comments(self.span), has_line_comment.then_some(hard_line_break())]
+        )
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXAttribute<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, self.name())?;
+        if let Some(value) = &self.value() {
+            write!(f, "=")?;
+            match value.as_ref() {
+                JSXAttributeValue::StringLiteral(string) => {
+                    let raw = string.span.source_text(f.source_text());
+                    let quote_style = f.options().jsx_quote_style;
+                    match normalize_jsx_string(raw, quote_style) {
+                        Cow::Borrowed(text) => write!(f, dynamic_text(text))?,
+                        Cow::Owned(text) => {
+                            let text = f.context().allocator().alloc_str(&text);
+                            write!(f, dynamic_text(text))?;
+                        }
+                    }
+                }
+                _ => write!(f, value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Normalizes the quotes of a JSX attribute string. JSX strings don't support escapes, so quotes
+/// in the string are written as HTML entities. The preferred quote is used unless the string
+/// contains more of it than of the other quote.
+fn normalize_jsx_string(raw: &str, preferred_quote: QuoteStyle) -> Cow<'_, str> {
+    let content = &raw[1..raw.len() - 1];
+    let content = content.cow_replace("&apos;", "'");
+    let content = if content.contains("&quot;") {
+        Cow::Owned(content.cow_replace("&quot;", "\"").into_owned())
+    } else {
+        content
+    };
+
+    let double_quotes = content.matches('"').count();
+    let single_quotes = content.matches('\'').count();
+    let quote = match preferred_quote {
+        QuoteStyle::Double if double_quotes > single_quotes => QuoteStyle::Single,
+        QuoteStyle::Single if single_quotes > double_quotes => QuoteStyle::Double,
+        quote => quote,
+    };
+    let (quote, escaped) = match quote {
+        QuoteStyle::Double => ('"', "&quot;"),
+        QuoteStyle::Single => ('\'', "&apos;"),
+    };
+
+    if matches!(content, Cow::Borrowed(_)) && raw.starts_with(quote) && !content.contains(quote) {
+        return Cow::Borrowed(raw);
+    }
+    Cow::Owned(format!("{quote}{}{quote}", content.cow_replace(quote, escaped)))
+}
+
+fn is_single_line_string_attribute(attribute: &JSXAttribute<'_>) -> bool {
+    matches!(&attribute.value, Some(JSXAttributeValue::StringLiteral(string)) if !string.value.contains('\n'))
+}
+
+fn is_multiline_string_attribute(attribute: &JSXAttribute<'_>) -> bool {
+    matches!(&attribute.value, Some(JSXAttributeValue::StringLiteral(string)) if string.value.contains('\n'))
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXSpreadAttribute<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, ["{...", self.argument(), "}"])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXIdentifier<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, dynamic_text(self.name().as_str()))
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXSpreadChild<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let expression = self.expression();
+        write!(f, ["{...", expression, "}"])
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, JSXText<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(f, dynamic_text(self.value().as_str()))
+    }
+}
+
+/// Creates either a space using an expression child and a string literal,
+/// or a regular space, depending on whether the group breaks or not.
+///
+/// ```jsx
+///  <div> Winter Light </div>;
+///
+///  <div>
+///    {" "}Winter Light
+///    Through A Glass Darkly
+///    The Silence
+///    Seventh Seal
+///    Wild Strawberries
+///  </div>
+/// ```
+struct JsxSpace;
+
+impl<'a> Format<'a> for JsxSpace {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        write!(
+            f,
+            [
+                if_group_breaks(&format_args!(JsxRawSpace, soft_line_break())),
+                if_group_fits_on_line(&space())
+            ]
+        )
+    }
+}
+
+/// A `{" "}`, quoted according to `jsxQuoteStyle`.
+struct JsxRawSpace;
+
+impl<'a> Format<'a> for JsxRawSpace {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let jsx_space = match f.options().jsx_quote_style {
+            QuoteStyle::Double => r#"{" "}"#,
+            QuoteStyle::Single => "{' '}",
+        };
+
+        write!(f, text(jsx_space))
+    }
+}
+
+/// Meaningful JSX text is defined to be text that has either non-whitespace
+/// characters, or does not contain a newline. Whitespace is defined as ASCII
+/// whitespace.
+fn is_meaningful_jsx_text(text: &str) -> bool {
+    let mut has_newline = false;
+    for c in text.chars() {
+        // If there is a non-whitespace character
+        if !matches!(c, ' ' | '\n' | '\t' | '\r') {
+            return true;
+        } else if c == '\n' {
+            has_newline = true;
+        }
+    }
+
+    !has_newline
+}
+
+/// Returns `true` for a `{" "}` child without comments, which is formatted like a space in the text.
+fn is_whitespace_jsx_expression(
+    container: &JSXExpressionContainer<'_>,
+    f: &Formatter<'_, '_>,
+) -> bool {
+    match &container.expression {
+        JSXExpression::StringLiteral(string) => {
+            matches!(string.span.source_text(f.source_text()), "\" \"" | "' '")
+                && !f.context().comments().has_comments_in_span(container.span)
+        }
+        _ => false,
+    }
+}
+
+fn has_line_comment_in_span(span: Span, f: &Formatter<'_, '_>) -> bool {
+    f.context().comments().filter_comments_in_span(span).any(|comment| comment.is_line())
+}
@@ -8,6 +8,7 @@ mod block_statement;
 mod call_arguments;
 mod class;
 mod function;
+mod jsx;
 mod object_like;
 mod object_pattern_like;
 mod parameter_list;
@@ -26,10 +27,11 @@ use cow_utils::CowUtils;
 
 use oxc_allocator::{Address, Box, FromIn, StringBuilder, Vec};
 use oxc_ast::{AstKind, ast::*};
-use oxc_span::{GetSpan, SPAN};
+use oxc_span::{GetSpan, SPAN, Span};
 use oxc_syntax::identifier::{ZWNBSP, is_identifier_name, is_line_terminator};
 
 use crate::{
+    embed::{FormatEmbeddedTemplate, embedded_language},
     format_args,
     formatter::{
         Buffer, Format, FormatResult, Formatter,
@@ -270,6 +272,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ObjectProperty<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TemplateLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if let Some(embedded) = embedded_language(self, f)
+            .and_then(|language| FormatEmbeddedTemplate::new(self, language, f))
+        {
+            return write!(f, embedded);
+        }
+
         write!(f, "`")?;
         let mut expressions = self.expressions().iter();
 
@@ -429,6 +437,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, LogicalExpression<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ConditionalExpression<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if is_jsx_conditional_chain(self) {
+            return format_jsx_conditional(self, f);
+        }
+
         write!(
             f,
             [
@@ -446,6 +458,99 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ConditionalExpression<'a>> {
     }
 }
 
+/// Returns `true` if any conditional expression in the chain `self` belongs to has a JSX element
+/// as its test, consequent or alternate. Such chains are formatted in "JSX mode":
+///
+/// ```jsx
+/// const message = isLoading ? (
+///   <Spinner size="large" />
+/// ) : (
+///   <Content items={items} onSelect={handleSelect} />
+/// );
+/// ```
+fn is_jsx_conditional_chain(conditional: &AstNode<'_, ConditionalExpression<'_>>) -> bool {
+    let mut root = conditional.as_ref();
+    let mut parent = conditional.parent;
+    while let AstNodes::ConditionalExpression(parent_conditional) = parent {
+        if parent_conditional.test.span() == root.span {
+            break;
+        }
+        root = parent_conditional.as_ref();
+        parent = parent_conditional.parent;
+    }
+    conditional_chain_contains_jsx(root)
+}
+
+fn conditional_chain_contains_jsx(conditional: &ConditionalExpression<'_>) -> bool {
+    [&conditional.test, &conditional.consequent, &conditional.alternate].into_iter().any(
+        |expression| match expression.without_parentheses() {
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+            Expression::ConditionalExpression(conditional) => {
+                conditional_chain_contains_jsx(conditional)
+            }
+            _ => false,
+        },
+    )
+}
+
+/// Formats the branches of a conditional in JSX mode, where a branch that breaks is wrapped in
+/// parentheses instead of being indented. `null` and `undefined` are never wrapped, nor are
+/// nested conditionals in the alternate, which continue the chain.
+fn format_jsx_conditional<'a>(
+    conditional: &AstNode<'a, ConditionalExpression<'a>>,
+    f: &mut Formatter<'_, 'a>,
+) -> FormatResult<()> {
+    let format_conditional = format_with(|f| {
+        write!(
+            f,
+            [
+                conditional.test(),
+                space(),
+                "?",
+                space(),
+                FormatJsxConditionalBranch {
+                    branch: conditional.consequent(),
+                    is_alternate: false
+                },
+                space(),
+                ":",
+                space(),
+                FormatJsxConditionalBranch { branch: conditional.alternate(), is_alternate: true }
+            ]
+        )
+    });
+
+    if matches!(conditional.parent, AstNodes::ConditionalExpression(_)) {
+        write!(f, format_conditional)
+    } else {
+        write!(f, group(&format_conditional))
+    }
+}
+
+struct FormatJsxConditionalBranch<'a, 'b> {
+    branch: &'b AstNode<'a, Expression<'a>>,
+    is_alternate: bool,
+}
+
+impl<'a> Format<'a> for FormatJsxConditionalBranch<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let should_wrap = match self.branch.as_ref().without_parentheses() {
+            Expression::NullLiteral(_) => false,
+            Expression::Identifier(identifier) => identifier.name != "undefined",
+            Expression::ConditionalExpression(_) => !self.is_alternate,
+            _ => true,
+        };
+        if should_wrap {
+            write!(
+                f,
+                [if_group_breaks(&"("), soft_block_indent(&self.branch), if_group_breaks(&")")]
+            )
+        } else {
+            write!(f, self.branch)
+        }
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, AssignmentExpression<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         AssignmentLike::AssignmentExpression(self).fmt(f)
@@ -564,16 +669,50 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let comments = f.context().comments().clone();
         let mut join = f.join_nodes_with_hardline();
-        for stmt in
-            self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
-        {
-            join.entry(stmt.span(), stmt);
+        let mut statements = self
+            .iter()
+            .filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
+            .peekable();
+        while let Some(stmt) = statements.next() {
+            let Some(range_end) = comments.ignored_range_end(stmt.span().start) else {
+                join.entry(stmt.span(), stmt);
+                continue;
+            };
+
+            // The statements between `// prettier-ignore-start` and `// prettier-ignore-end`,
+            // or the end of the list, are printed as they are.
+            let mut span = stmt.span();
+            while let Some(next) = statements.next_if(|next| next.span().end <= range_end) {
+                span = Span::new(span.start, next.span().end);
+            }
+            join.entry(span, &FormatIgnoredStatements(span));
         }
         join.finish()
     }
 }
 
+/// A range of statements suppressed by a `// prettier-ignore-start` comment.
+struct FormatIgnoredStatements(Span);
+
+impl<'a> Format<'a> for FormatIgnoredStatements {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        // Comments on the line of the last statement stay there.
+        let source_text = f.source_text();
+        let mut span = self.0;
+        for comment in f.context().comments().comments_after(span.end) {
+            let gap = Span::new(span.end, comment.span.start).source_text(source_text);
+            if gap.contains(['\n', '\r']) {
+                break;
+            }
+            span = Span::new(span.start, comment.span.end);
+        }
+
+        write!(f, [format_leading_comments(span), format_suppressed_node(span)])
+    }
+}
+
 impl<'a> FormatWrite<'a> for AstNode<'a, Hashbang<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, ["#!", dynamic_text(self.value().as_str())])?;
@@ -1246,6 +1385,13 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, ClassElement<'a>>> {
 
 impl<'a> Format<'a> for (&AstNode<'a, ClassElement<'a>>, Option<&AstNode<'a, ClassElement<'a>>>) {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let span = self.0.span();
+        // A suppressed property is printed with its semicolon, if it has one.
+        if f.context().comments().is_suppressed(span.start)
+            && span.source_text(f.source_text()).ends_with(';')
+        {
+            return write!(f, self.0);
+        }
         write!(f, [self.0, ClassPropertySemicolon::new(self.0, self.1)])
     }
 }
@@ -1755,127 +1901,6 @@ impl<'a> FormatWrite<'a> for AstNode<'a, RegExpLiteral<'a>> {
     }
 }
 
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXElement<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, ["<", self.opening_element().name()])?;
-        for attr in self.opening_element().attributes() {
-            match attr.as_ref() {
-                JSXAttributeItem::Attribute(_) => {
-                    write!(f, hard_space())?;
-                }
-                JSXAttributeItem::SpreadAttribute(_) => {
-                    write!(f, space())?;
-                }
-            }
-            write!(f, attr)?;
-        }
-        if self.closing_element().is_none() {
-            write!(f, [space(), "/"])?;
-        }
-        write!(f, ">")?;
-
-        for child in self.children() {
-            write!(f, child)?;
-        }
-        write!(f, self.closing_element())
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXOpeningElement<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        // Implemented in JSXElement above due to no access to
-        // no `self_closing`.
-        unreachable!()
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXClosingElement<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, ["</", self.name(), ">"])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXFragment<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, self.opening_fragment())?;
-        for child in self.children() {
-            write!(f, child)?;
-        }
-        write!(f, self.closing_fragment())
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXOpeningFragment> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, "<>")
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXClosingFragment> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, "</>")
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXNamespacedName<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, [self.namespace(), ":", self.name()])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXMemberExpression<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, [self.object(), ".", self.property()])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXExpressionContainer<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, ["{", self.expression(), "}"])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXEmptyExpression> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXAttribute<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, self.name())?;
-        if let Some(value) = &self.value() {
-            write!(f, ["=", value])?;
-        }
-        Ok(())
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXSpreadAttribute<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, ["{...", self.argument(), "}"])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXIdentifier<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, dynamic_text(self.name().as_str()))
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXSpreadChild<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        let expression = self.expression();
-        write!(f, ["{...", expression, "}"])
-    }
-}
-
-impl<'a> FormatWrite<'a> for AstNode<'a, JSXText<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
-        write!(f, dynamic_text(self.value().as_str()))
-    }
-}
-
 impl<'a> FormatWrite<'a> for AstNode<'a, TSThisParameter<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, "this")?;
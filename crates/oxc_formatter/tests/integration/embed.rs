@@ -0,0 +1,37 @@
+use crate::tester::{test, test_same};
+
+#[test]
+fn css() {
+    test(
+        "const Button = styled.button`\n  color:   ${props => props.color};\n  padding:${p}px .5em;\n  &:hover{background:red}\n  ${mixin}\n`;\n",
+        "const Button = styled.button`\n  color: ${(props) => props.color};\n  padding: ${p}px 0.5em;\n  &:hover {\n    background: red;\n  }\n  ${mixin}\n`;\n",
+    );
+    test(
+        "const c = css`display:flex;margin:0 ${gap}px;`;\n",
+        "const c = css`\n  display: flex;\n  margin: 0 ${gap}px;\n`;\n",
+    );
+    test(
+        "const Title = styled(Heading).attrs({ level: 1 })`font-size:2em`;\n",
+        "const Title = styled(Heading).attrs({ level: 1 })`\n  font-size: 2em;\n`;\n",
+    );
+    test_same("const empty = css``;\n");
+}
+
+#[test]
+fn graphql() {
+    test(
+        "const q = gql`\n  query   Foo($id: ID!){ user(id:$id){ name, ...UserFields } }\n  ${UserFields}\n`;\n",
+        "const q = gql`\n  query Foo($id: ID!) {\n    user(id: $id) {\n      name\n      ...UserFields\n    }\n  }\n  ${UserFields}\n`;\n",
+    );
+    test("const q = graphql`query { a }`;\n", "const q = graphql`\n  query {\n    a\n  }\n`;\n");
+    test("const q = /* GraphQL */ `{ a }`;\n", "const q = /* GraphQL */ `\n  {\n    a\n  }\n`;\n");
+}
+
+#[test]
+fn invalid() {
+    // Code which can't be formatted is printed as it is.
+    test_same("const c = css`color: red; {{{`;\n");
+    test_same("const c = styled.div`\n  color:red;\n  }\n`;\n");
+    test_same("const q = gql`query { a `;\n");
+    test_same("const q = gql`query { a: }`;\n");
+}
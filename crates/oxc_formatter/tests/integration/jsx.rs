@@ -0,0 +1,58 @@
+use crate::tester::{test, test_same};
+
+#[test]
+fn fill() {
+    // Words fill the lines.
+    test(
+        "const a = <div>Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore</div>;\n",
+        "const a = (\n  <div>\n    Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\n    tempor incididunt ut labore\n  </div>\n);\n",
+    );
+    test("const a = <div>a   b\n  c</div>;\n", "const a = <div>a b c</div>;\n");
+    // Elements are filled with the words next to them.
+    test(
+        "const a = <div>Some text that is long enough to break <b>bold</b> and then more text after the element here</div>;\n",
+        "const a = (\n  <div>\n    Some text that is long enough to break <b>bold</b> and then more text after\n    the element here\n  </div>\n);\n",
+    );
+    test(
+        "const a = <div><b>first</b> <i>second</i></div>;\n",
+        "const a = (\n  <div>\n    <b>first</b> <i>second</i>\n  </div>\n);\n",
+    );
+}
+
+#[test]
+fn forced_break() {
+    test_same("const a = <div>a b</div>;\n");
+    // Elements and several expressions are on their own lines.
+    test(
+        "const a = <div><a /><b /></div>;\n",
+        "const a = (\n  <div>\n    <a />\n    <b />\n  </div>\n);\n",
+    );
+    test(
+        "const a = <div>{a}{b}</div>;\n",
+        "const a = (\n  <div>\n    {a}\n    {b}\n  </div>\n);\n",
+    );
+    // A word is not kept on the line of a following self-closing element.
+    test(
+        "const a = <div>ab<br/></div>;\n",
+        "const a = (\n  <div>\n    ab\n    <br />\n  </div>\n);\n",
+    );
+}
+
+#[test]
+fn separator() {
+    // Meaningful whitespace before a line break is printed as `{" "}`.
+    test(
+        "const a = <div>Some text that is long enough to make this line wrap right before <b>the element</b> ok</div>;\n",
+        "const a = (\n  <div>\n    Some text that is long enough to make this line wrap right before{\" \"}\n    <b>the element</b> ok\n  </div>\n);\n",
+    );
+    test(
+        "const a = <div>{\" \"}a long line of text that goes past the print width limit of eighty columns ok</div>;\n",
+        "const a = (\n  <div>\n    {\" \"}\n    a long line of text that goes past the print width limit of eighty columns\n    ok\n  </div>\n);\n",
+    );
+    // `{" "}` is printed as a space when the children fit on a line.
+    test("const a = <>\n  a{\" \"}\n  {b}\n</>;\n", "const a = <>a {b}</>;\n");
+    test(
+        "const a = <div>\n  text {\" \"}\n  <b>x</b>\n</div>;\n",
+        "const a = (\n  <div>\n    text <b>x</b>\n  </div>\n);\n",
+    );
+}
@@ -0,0 +1,9 @@
+#![expect(clippy::missing_panics_doc)]
+pub mod embed;
+pub mod jsx;
+pub mod prettier_ignore;
+pub mod range;
+
+mod tester;
+
+pub use tester::*;
@@ -0,0 +1,42 @@
+use crate::tester::{test, test_same};
+
+#[test]
+fn statement() {
+    test(
+        "// prettier-ignore\nconst  matrix = [\n  1,0,0,\n  0,1,0,\n];\nconst  x  =  1;\n",
+        "// prettier-ignore\nconst  matrix = [\n  1,0,0,\n  0,1,0,\n];\nconst x = 1;\n",
+    );
+    test_same("function f() {\n  /* prettier-ignore */\n  call( a,b );\n}\n");
+    test(
+        "// prettier-ignore-start\nconst  a  =  1;\nconst  b  =  2;\n// prettier-ignore-end\nconst  c  =  3;\n",
+        "// prettier-ignore-start\nconst  a  =  1;\nconst  b  =  2;\n// prettier-ignore-end\nconst c = 3;\n",
+    );
+}
+
+#[test]
+fn class_member() {
+    test(
+        "class A {\n  // prettier-ignore\n  method( a,b ) { return a+b }\n  other( a,b ) { return a+b }\n}\n",
+        "class A {\n  // prettier-ignore\n  method( a,b ) { return a+b }\n  other(a, b) {\n    return a + b;\n  }\n}\n",
+    );
+    test(
+        "class A {\n  // prettier-ignore\n  x  =  1;\n  y  =  2;\n}\n",
+        "class A {\n  // prettier-ignore\n  x  =  1;\n  y = 2;\n}\n",
+    );
+}
+
+#[test]
+fn jsx_child() {
+    test(
+        "const el = (\n  <div>\n    {/* prettier-ignore */}\n    <span   a = \"1\"   />\n    <span   b = \"2\"   />\n  </div>\n);\n",
+        "const el = (\n  <div>\n    {/* prettier-ignore */}\n    <span   a = \"1\"   />\n    <span b=\"2\" />\n  </div>\n);\n",
+    );
+}
+
+#[test]
+fn object_property() {
+    test(
+        "const o = {\n  // prettier-ignore\n  key:   'value',\n  other:   'value',\n};\n",
+        "const o = {\n  // prettier-ignore\n  key:   'value',\n  other: \"value\",\n};\n",
+    );
+}
@@ -0,0 +1,54 @@
+use crate::tester::{test_on_type, test_range};
+
+#[test]
+fn inside_statement() {
+    test_range("let  a=«1»;\nlet  b=2;\n", "let a = 1;\nlet  b=2;\n");
+    test_range(
+        "function f() {\n    if (x) {\n        foo( «a,b» );\n    }\n}\n",
+        "function f() {\n    if (x) {\n        foo(a, b);\n    }\n}\n",
+    );
+}
+
+#[test]
+fn several_statements() {
+    test_range(
+        "let  a=1;\nlet  «b=2;\n\n\nlet  c»=3;\nlet  d=4;\n",
+        "let  a=1;\nlet b = 2;\n\nlet c = 3;\nlet  d=4;\n",
+    );
+    // The statements are expanded to siblings.
+    test_range("if (x) {\n  «foo( a );\n}\nbar( b»);\n", "if (x) {\n  foo(a);\n}\nbar(b);\n");
+}
+
+#[test]
+fn class_element() {
+    test_range("class A {\n  «x=1»;\n  y  =  2;\n}\n", "class A {\n  x = 1;\n  y  =  2;\n}\n");
+    test_range(
+        "class A {\n  «foo( ) { }\n  static  bar()»{}\n  baz(){}\n}\n",
+        "class A {\n  foo() {}\n  static bar() {}\n  baz(){}\n}\n",
+    );
+    // A statement in a method is the innermost unit.
+    test_range(
+        "class A {\n  foo() {\n    return  «a+b»;\n  }\n}\n",
+        "class A {\n  foo() {\n    return a + b;\n  }\n}\n",
+    );
+}
+
+#[test]
+fn object_property() {
+    test_range("const o = {\n  «a:1»,\n  b :  2,\n};\n", "const o = {\n  a: 1,\n  b :  2,\n};\n");
+    test_range(
+        "const o = {\n  «a:1,\n  ...rest,\n  b (){ }»,\n  c :  2,\n};\n",
+        "const o = {\n  a: 1,\n  ...rest,\n  b() {},\n  c :  2,\n};\n",
+    );
+    test_range("const o = { «a:1, b:2» };\n", "const o = { a: 1, b: 2 };\n");
+}
+
+#[test]
+fn on_type() {
+    test_on_type("let  a=1;\nlet  b=2;»\n", "let  a=1;\nlet b = 2;\n");
+    test_on_type(
+        "function f() {\n  if (x) {\n    foo( );\n  }»\n}\n",
+        "function f() {\n  if (x) {\n    foo();\n  }\n}\n",
+    );
+    test_on_type("class A {\n  foo( ) {\n  }»\n}\n", "class A {\n  foo() {}\n}\n");
+}
@@ -0,0 +1,68 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+/// Formats `source_text` and compares the result to `expected`.
+#[track_caller]
+pub fn test(source_text: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::tsx()).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let result = Formatter::new(&allocator, FormatOptions::default()).build(&ret.program);
+    assert_eq!(result, expected, "\nfor source: {source_text}");
+}
+
+/// Formats `source_text` and checks that it is unchanged.
+#[track_caller]
+pub fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+/// Removes the `«` and `»` markers from `source_text`, and returns their offsets in the result.
+fn remove_markers(source_text: &str) -> (String, Option<u32>, Option<u32>) {
+    let mut text = String::with_capacity(source_text.len());
+    let (mut start, mut end) = (None, None);
+    for c in source_text.chars() {
+        let offset = Some(u32::try_from(text.len()).unwrap());
+        match c {
+            '«' => start = offset,
+            '»' => end = offset,
+            c => text.push(c),
+        }
+    }
+    (text, start, end)
+}
+
+/// Formats the range between `«` and `»` in `source_text`, and compares the source text with
+/// the edit applied to `expected`.
+#[track_caller]
+pub fn test_range(source_text: &str, expected: &str) {
+    let (source_text, start, end) = remove_markers(source_text);
+    let range = Span::new(start.expect("missing `«`"), end.expect("missing `»`"));
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::tsx()).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let edit = Formatter::new(&allocator, FormatOptions::default())
+        .build_range(&ret.program, range)
+        .expect("no format unit in the range");
+    let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+    let result = format!("{}{}{}", &source_text[..start], edit.text, &source_text[end..]);
+    assert_eq!(result, expected, "\nfor source: {source_text}");
+}
+
+/// Formats on type with the cursor at `»` in `source_text`, and compares the source text with
+/// the edit applied to `expected`.
+#[track_caller]
+pub fn test_on_type(source_text: &str, expected: &str) {
+    let (source_text, _, offset) = remove_markers(source_text);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, SourceType::tsx()).parse();
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let edit = Formatter::new(&allocator, FormatOptions::default())
+        .build_on_type(&ret.program, offset.expect("missing `»`"))
+        .expect("no format unit before the cursor");
+    let (start, end) = (edit.span.start as usize, edit.span.end as usize);
+    let result = format!("{}{}{}", &source_text[..start], edit.text, &source_text[end..]);
+    assert_eq!(result, expected, "\nfor source: {source_text}");
+}
@@ -100,6 +100,7 @@ impl<'a> IsolatedDeclarations<'a> {
         let mut value = None;
 
         if property.accessibility.is_none_or(|a| !a.is_private()) {
+            let errors_len = self.errors_len();
             if property.type_annotation.is_some() {
                 type_annotation = property.type_annotation.clone_in(self.ast.allocator);
             } else if let Some(expr) = property.value.as_ref() {
@@ -127,6 +128,9 @@ impl<'a> IsolatedDeclarations<'a> {
             if type_annotation.is_none() && value.is_none() {
                 self.error(property_must_have_explicit_type(property.key.span()));
             }
+            if self.errors_len() > errors_len {
+                self.fix_class_property(property);
+            }
         }
 
         self.ast.class_element_property_definition(
@@ -514,9 +518,18 @@ impl<'a> IsolatedDeclarations<'a> {
                                     method.key.span(),
                                 ));
                             }
+                            self.fix_function_return_type(function);
                             rt
                         }
                         MethodDefinitionKind::Get => {
+                            // Only one of the accessors is required to be annotated
+                            let has_setter_annotation =
+                                accessor_annotations.iter().any(|(key, annotation)| {
+                                    method.key.content_eq(key) && annotation.setter.is_some()
+                                });
+                            if !has_setter_annotation {
+                                self.fix_function_return_type(function);
+                            }
                             let rt = accessor_annotations.iter().find_map(|(key, annotation)| {
                                 if method.key.content_eq(key) {
                                     // No explicit return type for getter, should infer it from the first parameter of setter, if not exists,
@@ -65,6 +65,7 @@ impl<'a> IsolatedDeclarations<'a> {
 
         let mut binding_type = None;
         let mut init = None;
+        let errors_len = self.errors_len();
         if decl.id.type_annotation.is_none() {
             if let Some(init_expr) = &decl.init {
                 // if kind is const and it doesn't need to infer type from expression
@@ -88,6 +89,9 @@ impl<'a> IsolatedDeclarations<'a> {
                     self.error(variable_must_have_explicit_type(decl.id.span()));
                 }
             }
+            if self.errors_len() > errors_len {
+                self.fix_variable_declarator(decl);
+            }
         }
         let id = binding_type.map_or_else(
             || decl.id.clone_in(self.ast.allocator),
@@ -0,0 +1,501 @@
+//! Fixes for missing type annotations
+//!
+//! With [`IsolatedDeclarations::build_with_fixes`](crate::IsolatedDeclarations::build_with_fixes),
+//! the transformer infers the types of declarations that `isolatedDeclarations` requires to be
+//! annotated, and generates text edits that insert these annotations into the source.
+//!
+//! Besides the inference used for the declaration emit, fixes can infer the types of:
+//! * array literals, e.g. `[1, "a"]` -> `(number | string)[]`
+//! * calls of top-level functions with a return type annotation, e.g. `foo()` -> `number`
+//! * instances of top-level classes, e.g. `new Foo()` -> `Foo`
+//! * references to top-level bindings with a type annotation, e.g. `{ foo }` -> `{ foo: number }`
+
+use std::{collections::hash_map::Entry, mem};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::CloneIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_codegen::{Codegen, CodegenOptions, Context, Gen, IndentChar};
+use oxc_span::{ContentEq, GetSpan, SPAN};
+use oxc_syntax::symbol::SymbolId;
+
+use crate::{IsolatedDeclarations, return_type::FunctionReturnType};
+
+/// A text edit that inserts a missing type annotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeAnnotationFix {
+    /// The span of the source text to replace, which is empty for insertions.
+    pub span: Span,
+    /// The text to replace [`TypeAnnotationFix::span`] with.
+    pub content: String,
+}
+
+/// Apply `fixes` (as returned in [`IsolatedDeclarationsReturn::fixes`]) to `source_text`.
+///
+/// Fixes must be sorted by their spans, a fix overlapping with a previous one is skipped.
+///
+/// [`IsolatedDeclarationsReturn::fixes`]: crate::IsolatedDeclarationsReturn::fixes
+pub fn apply_fixes(source_text: &str, fixes: &[TypeAnnotationFix]) -> String {
+    let mut output = String::with_capacity(source_text.len());
+    let mut last_end = 0;
+    for fix in fixes {
+        let start = fix.span.start as usize;
+        if start < last_end {
+            continue;
+        }
+        output.push_str(&source_text[last_end..start]);
+        output.push_str(&fix.content);
+        last_end = fix.span.end as usize;
+    }
+    output.push_str(&source_text[last_end..]);
+    output
+}
+
+/// A top-level binding whose type is known without inference.
+pub enum LocalBinding<'a> {
+    /// `function foo(): T {}` or `const foo = (): T => {}`
+    ///
+    /// The return type is `None` if the function is generic, overloaded or not annotated.
+    Function(Option<TSType<'a>>),
+    /// `class Foo {}`
+    Class { is_generic: bool },
+    /// `const foo: T = ...`
+    Variable(TSType<'a>),
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    pub(crate) fn collect_local_bindings(
+        &self,
+        program: &Program<'a>,
+    ) -> FxHashMap<SymbolId, LocalBinding<'a>> {
+        let mut bindings = FxHashMap::default();
+        for stmt in &program.body {
+            let decl = match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    let Some(decl) = &decl.declaration else { continue };
+                    decl
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            self.collect_function_binding(func, &mut bindings);
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            Self::collect_class_binding(class, &mut bindings);
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                _ => {
+                    let Some(decl) = stmt.as_declaration() else { continue };
+                    decl
+                }
+            };
+            match decl {
+                Declaration::FunctionDeclaration(func) => {
+                    self.collect_function_binding(func, &mut bindings);
+                }
+                Declaration::ClassDeclaration(class) => {
+                    Self::collect_class_binding(class, &mut bindings);
+                }
+                Declaration::VariableDeclaration(decl) => {
+                    for declarator in &decl.declarations {
+                        self.collect_variable_binding(declarator, &mut bindings);
+                    }
+                }
+                _ => {}
+            }
+        }
+        bindings
+    }
+
+    fn collect_function_binding(
+        &self,
+        func: &Function<'a>,
+        bindings: &mut FxHashMap<SymbolId, LocalBinding<'a>>,
+    ) {
+        let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) else { return };
+        match bindings.entry(symbol_id) {
+            // Overloads may have different return types
+            Entry::Occupied(mut entry) => {
+                entry.insert(LocalBinding::Function(None));
+            }
+            Entry::Vacant(entry) => {
+                let return_type = self.get_return_type_of_call(
+                    func.type_parameters.as_deref(),
+                    func.return_type.as_deref(),
+                );
+                entry.insert(LocalBinding::Function(return_type));
+            }
+        }
+    }
+
+    fn collect_class_binding(
+        class: &Class<'a>,
+        bindings: &mut FxHashMap<SymbolId, LocalBinding<'a>>,
+    ) {
+        if let Some(symbol_id) = class.id.as_ref().and_then(|id| id.symbol_id.get()) {
+            let is_generic = class.type_parameters.is_some();
+            bindings.insert(symbol_id, LocalBinding::Class { is_generic });
+        }
+    }
+
+    fn collect_variable_binding(
+        &self,
+        declarator: &VariableDeclarator<'a>,
+        bindings: &mut FxHashMap<SymbolId, LocalBinding<'a>>,
+    ) {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let Some(symbol_id) = ident.symbol_id.get() else { return };
+        let binding = if let Some(annotation) = &declarator.id.type_annotation {
+            LocalBinding::Variable(annotation.type_annotation.clone_in(self.ast.allocator))
+        } else {
+            match &declarator.init {
+                Some(Expression::FunctionExpression(func)) => {
+                    LocalBinding::Function(self.get_return_type_of_call(
+                        func.type_parameters.as_deref(),
+                        func.return_type.as_deref(),
+                    ))
+                }
+                Some(Expression::ArrowFunctionExpression(func)) => {
+                    LocalBinding::Function(self.get_return_type_of_call(
+                        func.type_parameters.as_deref(),
+                        func.return_type.as_deref(),
+                    ))
+                }
+                _ => return,
+            }
+        };
+        bindings.insert(symbol_id, binding);
+    }
+
+    /// Get the type of calling a non-generic function with the given return type annotation.
+    fn get_return_type_of_call(
+        &self,
+        type_parameters: Option<&TSTypeParameterDeclaration<'a>>,
+        return_type: Option<&TSTypeAnnotation<'a>>,
+    ) -> Option<TSType<'a>> {
+        if type_parameters.is_some() {
+            return None;
+        }
+        match &return_type?.type_annotation {
+            // `asserts x is T`
+            TSType::TSTypePredicate(predicate) if predicate.asserts => {
+                Some(self.ast.ts_type_void_keyword(SPAN))
+            }
+            // `x is T`
+            TSType::TSTypePredicate(_) => Some(self.ast.ts_type_boolean_keyword(SPAN)),
+            ts_type => Some(ts_type.clone_in(self.ast.allocator)),
+        }
+    }
+
+    /// Get the top-level binding that `ident` refers to.
+    ///
+    /// Returns `None` if `ident` is unresolved or refers to a binding in a nested scope,
+    /// e.g. a parameter shadowing a top-level binding of the same name.
+    fn get_local_binding(&self, ident: &IdentifierReference<'a>) -> Option<&LocalBinding<'a>> {
+        let symbol_id = self.local_references.get(&ident.reference_id.get()?)?;
+        self.local_bindings.get(symbol_id)
+    }
+
+    pub(crate) fn is_inferring_for_fix(&self) -> bool {
+        self.inferring_for_fix.get()
+    }
+
+    /// Fixes are only generated for nodes visited by the declaration emit,
+    /// not for nodes visited when inferring types for other fixes.
+    fn can_fix(&self) -> bool {
+        self.fix && !self.is_inferring_for_fix()
+    }
+
+    /// Run `infer` without reporting errors.
+    ///
+    /// The result is discarded if any error occurs, because the inferred type would
+    /// contain `unknown` in place of the types that cannot be inferred.
+    fn infer_type_for_fix(&self, infer: impl FnOnce() -> Option<TSType<'a>>) -> Option<TSType<'a>> {
+        let errors = self.take_errors();
+        self.inferring_for_fix.set(true);
+        let ts_type = infer();
+        self.inferring_for_fix.set(false);
+        let has_errors = !self.errors.borrow().is_empty();
+        *self.errors.borrow_mut() = errors;
+        ts_type.filter(|ts_type| !has_errors && !matches!(ts_type, TSType::TSUnknownKeyword(_)))
+    }
+
+    /// `foo` -> the annotated type of `foo`, or `typeof foo` for functions and classes.
+    pub(crate) fn infer_type_from_local_binding(
+        &self,
+        ident: &IdentifierReference<'a>,
+    ) -> Option<TSType<'a>> {
+        if !self.is_inferring_for_fix() {
+            return None;
+        }
+        match self.get_local_binding(ident)? {
+            LocalBinding::Variable(ts_type) => Some(ts_type.clone_in(self.ast.allocator)),
+            LocalBinding::Function(_) | LocalBinding::Class { .. } => {
+                let expr_name = TSTypeQueryExprName::IdentifierReference(
+                    self.ast.alloc_identifier_reference(SPAN, ident.name),
+                );
+                Some(self.ast.ts_type_type_query(SPAN, expr_name, NONE))
+            }
+        }
+    }
+
+    /// `foo()` -> the return type of `foo`
+    pub(crate) fn infer_type_from_call_expression(
+        &self,
+        call: &CallExpression<'a>,
+    ) -> Option<TSType<'a>> {
+        if !self.is_inferring_for_fix() || call.optional || call.type_arguments.is_some() {
+            return None;
+        }
+        let Expression::Identifier(callee) = &call.callee else { return None };
+        match self.get_local_binding(callee)? {
+            LocalBinding::Function(return_type) => return_type.clone_in(self.ast.allocator),
+            LocalBinding::Variable(TSType::TSFunctionType(func)) => self
+                .get_return_type_of_call(func.type_parameters.as_deref(), Some(&func.return_type)),
+            LocalBinding::Variable(_) | LocalBinding::Class { .. } => None,
+        }
+    }
+
+    /// `new Foo()` -> `Foo`
+    pub(crate) fn infer_type_from_new_expression(
+        &self,
+        expr: &NewExpression<'a>,
+    ) -> Option<TSType<'a>> {
+        if !self.is_inferring_for_fix() || expr.type_arguments.is_some() {
+            return None;
+        }
+        let Expression::Identifier(callee) = &expr.callee else { return None };
+        if !matches!(
+            self.get_local_binding(callee),
+            Some(LocalBinding::Class { is_generic: false })
+        ) {
+            return None;
+        }
+        let type_name = self.ast.ts_type_name_identifier_reference(SPAN, callee.name);
+        Some(self.ast.ts_type_type_reference(SPAN, type_name, NONE))
+    }
+
+    /// `[1, "a"]` -> `(number | string)[]`
+    pub(crate) fn infer_type_from_array_expression(
+        &self,
+        expr: &ArrayExpression<'a>,
+    ) -> Option<TSType<'a>> {
+        let mut element_types: Vec<TSType<'a>> = Vec::new();
+        for element in &expr.elements {
+            let element_type = match element {
+                ArrayExpressionElement::SpreadElement(_) => return None,
+                ArrayExpressionElement::Elision(_) => self.ast.ts_type_undefined_keyword(SPAN),
+                _ => self.infer_type_from_expression(element.to_expression())?,
+            };
+            if !element_types.iter().any(|ts_type| ts_type.content_eq(&element_type)) {
+                element_types.push(element_type);
+            }
+        }
+
+        let element_type = match element_types.len() {
+            // The type of `[]` depends on how it is used later.
+            0 => return None,
+            1 => element_types.pop().unwrap(),
+            _ => self.ast.ts_type_union_type(SPAN, self.ast.vec_from_iter(element_types)),
+        };
+        let element_type = if matches!(
+            element_type,
+            TSType::TSUnionType(_)
+                | TSType::TSIntersectionType(_)
+                | TSType::TSFunctionType(_)
+                | TSType::TSConstructorType(_)
+                | TSType::TSConditionalType(_)
+                | TSType::TSTypeOperatorType(_)
+        ) {
+            self.ast.ts_type_parenthesized_type(SPAN, element_type)
+        } else {
+            element_type
+        };
+        Some(self.ast.ts_type_array_type(SPAN, element_type))
+    }
+
+    /// `let foo = bar()` -> `let foo: T = bar()`
+    ///
+    /// Only called if inferring the type of the declarator reported errors.
+    pub(crate) fn fix_variable_declarator(&self, decl: &VariableDeclarator<'a>) {
+        if !self.can_fix() || decl.id.type_annotation.is_some() || decl.definite {
+            return;
+        }
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+        let Some(init) = &decl.init else { return };
+        // The return type of the function is fixed instead.
+        if init.is_function() {
+            return;
+        }
+        if let Some(ts_type) = self.infer_type_for_fix(|| self.infer_type_from_expression(init)) {
+            self.add_type_annotation_fix(Span::empty(ident.span.end), &ts_type);
+        }
+    }
+
+    /// `function foo(a = bar()) {}` -> `function foo(a: T = bar()) {}`
+    ///
+    /// Only called if inferring the type of the parameter reported errors.
+    pub(crate) fn fix_formal_parameter(&self, param: &FormalParameter<'a>) {
+        if !self.can_fix() {
+            return;
+        }
+        let BindingPatternKind::AssignmentPattern(pattern) = &param.pattern.kind else { return };
+        let BindingPatternKind::BindingIdentifier(ident) = &pattern.left.kind else { return };
+        if pattern.left.type_annotation.is_some() {
+            return;
+        }
+        if let Some(ts_type) =
+            self.infer_type_for_fix(|| self.infer_type_from_expression(&pattern.right))
+        {
+            self.add_type_annotation_fix(Span::empty(ident.span.end), &ts_type);
+        }
+    }
+
+    /// `class A { foo = bar() }` -> `class A { foo: T = bar() }`
+    ///
+    /// Only called if inferring the type of the property reported errors.
+    pub(crate) fn fix_class_property(&self, property: &PropertyDefinition<'a>) {
+        if !self.can_fix()
+            || property.type_annotation.is_some()
+            || property.computed
+            || property.optional
+            || property.definite
+        {
+            return;
+        }
+        let Some(value) = &property.value else { return };
+        if let Some(ts_type) = self.infer_type_for_fix(|| self.infer_type_from_expression(value)) {
+            self.add_type_annotation_fix(Span::empty(property.key.span().end), &ts_type);
+        }
+    }
+
+    /// `function foo() { return 1 }` -> `function foo(): number { return 1 }`
+    pub(crate) fn fix_function_return_type(&self, func: &Function<'a>) {
+        if !self.can_fix() || func.return_type.is_some() || func.generator {
+            return;
+        }
+        let Some(body) = &func.body else { return };
+        if let Some(ts_type) = self.infer_type_for_fix(|| {
+            FunctionReturnType::infer(self, body)
+                .map(|t| self.wrap_async_return_type(t, func.r#async))
+        }) {
+            self.add_return_type_fix(&func.params, &ts_type);
+        }
+    }
+
+    /// `() => 1` -> `(): number => 1`
+    pub(crate) fn fix_arrow_function_return_type(&self, func: &ArrowFunctionExpression<'a>) {
+        if !self.can_fix() || func.return_type.is_some() {
+            return;
+        }
+        let ts_type = self.infer_type_for_fix(|| {
+            let ts_type = if func.expression {
+                let Some(Statement::ExpressionStatement(stmt)) = func.body.statements.first()
+                else {
+                    return None;
+                };
+                self.infer_type_from_expression(&stmt.expression)
+            } else {
+                FunctionReturnType::infer(self, &func.body)
+            };
+            ts_type.map(|t| self.wrap_async_return_type(t, func.r#async))
+        });
+        if let Some(ts_type) = ts_type {
+            self.add_return_type_fix(&func.params, &ts_type);
+        }
+    }
+
+    /// `T` -> `Promise<T>` for async functions
+    fn wrap_async_return_type(&self, ts_type: TSType<'a>, is_async: bool) -> TSType<'a> {
+        let is_promise = matches!(
+            &ts_type,
+            TSType::TSTypeReference(reference)
+                if reference.type_name.get_identifier_reference().is_some_and(|ident| ident.name == "Promise")
+        );
+        if !is_async || is_promise {
+            return ts_type;
+        }
+        let type_name = self.ast.ts_type_name_identifier_reference(SPAN, "Promise");
+        let type_arguments =
+            self.ast.alloc_ts_type_parameter_instantiation(SPAN, self.ast.vec1(ts_type));
+        self.ast.ts_type_type_reference(SPAN, type_name, Some(type_arguments))
+    }
+
+    fn add_return_type_fix(&self, params: &FormalParameters<'a>, ts_type: &TSType<'a>) {
+        let annotation = self.print_ts_type(ts_type, params.span.end);
+        let fix = if self.source_text.as_bytes().get(params.span.start as usize) == Some(&b'(') {
+            TypeAnnotationFix { span: Span::empty(params.span.end), content: annotation }
+        } else {
+            // `a => a` -> `(a): T => a`
+            let param = params.span.source_text(self.source_text);
+            TypeAnnotationFix { span: params.span, content: format!("({param}){annotation}") }
+        };
+        self.fixes.borrow_mut().push(fix);
+    }
+
+    fn add_type_annotation_fix(&self, span: Span, ts_type: &TSType<'a>) {
+        let content = self.print_ts_type(ts_type, span.start);
+        self.fixes.borrow_mut().push(TypeAnnotationFix { span, content });
+    }
+
+    /// Print `ts_type` as a type annotation inserted at `position`, e.g. `: number`.
+    ///
+    /// Multi-line types, e.g. object literal types, are indented like the line they are inserted
+    /// into, with the indentation style of the source.
+    fn print_ts_type(&self, ts_type: &TSType<'a>, position: u32) -> String {
+        let (indent_char, indent_width) = detect_indentation(self.source_text);
+        let options = CodegenOptions { indent_char, indent_width, ..CodegenOptions::default() };
+        let mut codegen = Codegen::new().with_options(options).with_source_text(self.source_text);
+        codegen.print_str(": ");
+        ts_type.print(&mut codegen, Context::default());
+        let annotation = codegen.into_source_text();
+
+        let line_start = self.source_text[..position as usize].rfind('\n').map_or(0, |i| i + 1);
+        let line = &self.source_text[line_start..];
+        let line_indentation = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        if line_indentation.is_empty() {
+            return annotation;
+        }
+        let mut lines = annotation.split('\n');
+        let mut output = lines.next().unwrap_or_default().to_string();
+        for line in lines {
+            output.push('\n');
+            output.push_str(line_indentation);
+            output.push_str(line);
+        }
+        output
+    }
+
+    pub(crate) fn take_fixes(&self) -> Vec<TypeAnnotationFix> {
+        let mut fixes = mem::take(&mut *self.fixes.borrow_mut());
+        fixes.sort_unstable_by_key(|fix| (fix.span.start, fix.span.end));
+        // The same declaration can be transformed more than once.
+        fixes.dedup_by_key(|fix| fix.span);
+        fixes
+    }
+}
+
+/// The indentation of the first indented line of `source_text`, tabs if there is none.
+///
+/// Lines of block comments, e.g. ` * @param`, are skipped.
+fn detect_indentation(source_text: &str) -> (IndentChar, usize) {
+    source_text
+        .lines()
+        .find_map(|line| {
+            let content = line.trim_start_matches([' ', '\t']);
+            let indentation = &line[..line.len() - content.len()];
+            if indentation.is_empty() || content.is_empty() || content.starts_with('*') {
+                return None;
+            }
+            Some(if indentation.starts_with('\t') {
+                (IndentChar::Tab, 1)
+            } else {
+                (IndentChar::Space, indentation.len())
+            })
+        })
+        .unwrap_or((IndentChar::Tab, 1))
+}
@@ -21,6 +21,7 @@ impl<'a> IsolatedDeclarations<'a> {
         if return_type.is_none() {
             self.error(function_must_have_explicit_return_type(get_function_span(func)));
         }
+        self.fix_function_return_type(func);
         let params = self.transform_formal_parameters(&func.params, false);
         self.ast.alloc_function(
             func.span,
@@ -71,11 +72,15 @@ impl<'a> IsolatedDeclarations<'a> {
                 .as_ref()
                 .map(|type_annotation| type_annotation.type_annotation.clone_in(self.ast.allocator))
                 .or_else(|| {
+                    let errors_len = self.errors_len();
                     // report error for has no type annotation
                     let new_type = self.infer_type_from_formal_parameter(param);
                     if new_type.is_none() {
                         self.error(parameter_must_have_explicit_type(param.span));
                     }
+                    if self.errors_len() > errors_len {
+                        self.fix_formal_parameter(param);
+                    }
                     new_type
                 })
                 .map(|ts_type| {
@@ -27,7 +27,7 @@ impl<'a> IsolatedDeclarations<'a> {
             }
             Expression::Identifier(ident) => match ident.name.as_str() {
                 "undefined" => Some(self.ast.ts_type_undefined_keyword(SPAN)),
-                _ => None,
+                _ => self.infer_type_from_local_binding(ident),
             },
             Expression::FunctionExpression(func) => {
                 self.transform_function_to_ts_type(func).map(|x| x.clone_in(self.ast.allocator))
@@ -39,6 +39,9 @@ impl<'a> IsolatedDeclarations<'a> {
                 Some(self.transform_object_expression_to_ts_type(expr, false))
             }
             Expression::ArrayExpression(expr) => {
+                if self.is_inferring_for_fix() {
+                    return self.infer_type_from_array_expression(expr);
+                }
                 self.error(array_inferred(expr.span));
                 Some(self.ast.ts_type_unknown_keyword(expr.span))
             }
@@ -72,6 +75,8 @@ impl<'a> IsolatedDeclarations<'a> {
                     None
                 }
             }
+            Expression::CallExpression(call) => self.infer_type_from_call_expression(call),
+            Expression::NewExpression(expr) => self.infer_type_from_new_expression(expr),
             _ => None,
         }
     }
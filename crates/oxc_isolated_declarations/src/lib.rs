@@ -5,7 +5,10 @@
 //! * <https://www.typescriptlang.org/tsconfig#isolatedDeclarations>
 //! * <https://github.com/microsoft/TypeScript/blob/v5.6.3/src/compiler/transformers/declarations.ts>
 
-use std::{cell::RefCell, mem};
+use std::{
+    cell::{Cell, RefCell},
+    mem,
+};
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -13,14 +16,17 @@ use oxc_allocator::{Allocator, CloneIn, Vec as ArenaVec};
 use oxc_ast::{AstBuilder, NONE, ast::*};
 use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::Scoping;
 use oxc_span::{Atom, GetSpan, SPAN, SourceType};
+use oxc_syntax::{reference::ReferenceId, symbol::SymbolId};
 
-use crate::{diagnostics::function_with_assigning_properties, scope::ScopeTree};
+use crate::{diagnostics::function_with_assigning_properties, fix::LocalBinding, scope::ScopeTree};
 
 mod class;
 mod declaration;
 mod diagnostics;
 mod r#enum;
+mod fix;
 mod formal_parameter_binding_pattern;
 mod function;
 mod inferrer;
@@ -31,6 +37,8 @@ mod scope;
 mod signatures;
 mod types;
 
+pub use crate::fix::{TypeAnnotationFix, apply_fixes};
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct IsolatedDeclarationsOptions {
     /// Do not emit declarations for code that has an `@internal` annotation in its JSDoc comment.
@@ -48,6 +56,8 @@ pub struct IsolatedDeclarationsOptions {
 pub struct IsolatedDeclarationsReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<OxcDiagnostic>,
+    /// Fixes sorted by their spans, only generated by [`IsolatedDeclarations::build_with_fixes`].
+    pub fixes: Vec<TypeAnnotationFix>,
 }
 
 pub struct IsolatedDeclarations<'a> {
@@ -56,9 +66,20 @@ pub struct IsolatedDeclarations<'a> {
     // state
     scope: ScopeTree<'a>,
     errors: RefCell<Vec<OxcDiagnostic>>,
+    fixes: RefCell<Vec<TypeAnnotationFix>>,
+    /// Whether the current type inference is performed for generating a fix.
+    inferring_for_fix: Cell<bool>,
+    /// Top-level bindings that can be used to infer types for fixes.
+    local_bindings: FxHashMap<SymbolId, LocalBinding<'a>>,
+    /// References to [`IsolatedDeclarations::local_bindings`], resolved from the scoping of the
+    /// program.
+    local_references: FxHashMap<ReferenceId, SymbolId>,
+    source_text: &'a str,
 
     // options
     strip_internal: bool,
+    /// Whether fixes are generated, see [`IsolatedDeclarations::build_with_fixes`].
+    fix: bool,
 
     /// Start position of `@internal` jsdoc annotations.
     internal_annotations: FxHashSet<u32>,
@@ -70,9 +91,15 @@ impl<'a> IsolatedDeclarations<'a> {
         Self {
             ast: AstBuilder::new(allocator),
             strip_internal,
+            fix: false,
             internal_annotations: FxHashSet::default(),
             scope: ScopeTree::new(),
             errors: RefCell::new(vec![]),
+            fixes: RefCell::new(vec![]),
+            inferring_for_fix: Cell::new(false),
+            local_bindings: FxHashMap::default(),
+            local_references: FxHashMap::default(),
+            source_text: "",
         }
     }
 
@@ -85,6 +112,7 @@ impl<'a> IsolatedDeclarations<'a> {
         } else {
             FxHashSet::default()
         };
+        self.source_text = program.source_text;
         let source_type = SourceType::d_ts();
         let directives = self.ast.vec();
         let stmts = self.transform_program(program);
@@ -97,13 +125,46 @@ impl<'a> IsolatedDeclarations<'a> {
             directives,
             stmts,
         );
-        IsolatedDeclarationsReturn { program, errors: self.take_errors() }
+        IsolatedDeclarationsReturn { program, errors: self.take_errors(), fixes: self.take_fixes() }
+    }
+
+    /// Like [`IsolatedDeclarations::build`], and also generates fixes that insert the missing
+    /// type annotations required by `isolatedDeclarations` whenever the type can be determined
+    /// from the source, which are returned in [`IsolatedDeclarationsReturn::fixes`].
+    ///
+    /// Unlike the declaration emit, which infers function return types from `return` statements,
+    /// the TypeScript compiler requires them to be written out, so fixes are also generated for
+    /// functions and methods that don't have an explicit return type.
+    ///
+    /// `scoping` must be built from `program`, e.g. by [`oxc_semantic::SemanticBuilder`].
+    pub fn build_with_fixes(
+        mut self,
+        program: &Program<'a>,
+        scoping: &Scoping,
+    ) -> IsolatedDeclarationsReturn<'a> {
+        self.fix = true;
+        self.local_bindings = self.collect_local_bindings(program);
+        self.local_references = self
+            .local_bindings
+            .keys()
+            .flat_map(|&symbol_id| {
+                scoping
+                    .get_resolved_reference_ids(symbol_id)
+                    .iter()
+                    .map(move |&reference_id| (reference_id, symbol_id))
+            })
+            .collect();
+        self.build(program)
     }
 
     fn take_errors(&self) -> Vec<OxcDiagnostic> {
         mem::take(&mut self.errors.borrow_mut())
     }
 
+    fn errors_len(&self) -> usize {
+        self.errors.borrow().len()
+    }
+
     /// Add an Error
     fn error(&self, error: OxcDiagnostic) {
         self.errors.borrow_mut().push(error);
@@ -63,7 +63,20 @@ impl<'a> FunctionReturnType<'a> {
 
         visitor.visit_function_body(body);
 
-        let expr = visitor.return_expression??;
+        let expr = match visitor.return_expression {
+            Some(Some(expr)) => expr,
+            // Functions that don't return a value are only inferred as `void` when generating fixes,
+            // the declaration emit requires them to be annotated explicitly.
+            Some(None) => {
+                return transformer
+                    .is_inferring_for_fix()
+                    .then(|| transformer.ast.ts_type_void_keyword(SPAN));
+            }
+            None => {
+                return (transformer.is_inferring_for_fix() && visitor.return_statement_count == 0)
+                    .then(|| transformer.ast.ts_type_void_keyword(SPAN));
+            }
+        };
         let Some(mut expr_type) = transformer.infer_type_from_expression(&expr) else {
             // Avoid report error in parent function
             return if expr.is_function() {
@@ -26,6 +26,7 @@ impl<'a> IsolatedDeclarations<'a> {
         if return_type.is_none() {
             self.error(function_must_have_explicit_return_type(get_function_span(func)));
         }
+        self.fix_function_return_type(func);
 
         let params = self.transform_formal_parameters(&func.params, false);
 
@@ -52,6 +53,7 @@ impl<'a> IsolatedDeclarations<'a> {
                 func.body.span.start + 1,
             )));
         }
+        self.fix_arrow_function_return_type(func);
 
         let params = self.transform_formal_parameters(&func.params, false);
 
@@ -116,7 +118,8 @@ impl<'a> IsolatedDeclarations<'a> {
                         return None;
                     }
 
-                    if object.shorthand {
+                    // The type of shorthand properties can only be inferred when generating fixes
+                    if object.shorthand && !self.is_inferring_for_fix() {
                         self.error(shorthand_property(object.span));
                         return None;
                     }
@@ -133,6 +136,7 @@ impl<'a> IsolatedDeclarations<'a> {
                         if return_type.is_none() {
                             self.error(method_must_have_explicit_return_type(object.key.span()));
                         }
+                        self.fix_function_return_type(function);
                         let params = self.transform_formal_parameters(&function.params, false);
                         let key = self.transform_property_key(key);
                         let computed = key
@@ -0,0 +1,121 @@
+use oxc_allocator::Allocator;
+use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions, apply_fixes};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+fn fix_test(source: &str, expected: &str) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path("test.ts").unwrap();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    let scoping = SemanticBuilder::new().build(&ret.program).semantic.into_scoping();
+    let ret = IsolatedDeclarations::new(&allocator, IsolatedDeclarationsOptions::default())
+        .build_with_fixes(&ret.program, &scoping);
+    assert_eq!(apply_fixes(source, &ret.fixes), expected);
+}
+
+#[test]
+fn variables() {
+    fix_test("export const a = [1, 2];", "export const a: number[] = [1, 2];");
+    fix_test("export let a = [1, 'a', 2];", "export let a: (number | string)[] = [1, 'a', 2];");
+    fix_test(
+        "export const a = { b: [true] };",
+        "export const a: {\n\tb: boolean[];\n} = { b: [true] };",
+    );
+    fix_test(
+        "function foo(): Foo { return {} }\nexport const a = foo();",
+        "function foo(): Foo { return {} }\nexport const a: Foo = foo();",
+    );
+    fix_test(
+        "const foo = (): string => '';\nexport const a = foo();",
+        "const foo = (): string => '';\nexport const a: string = foo();",
+    );
+    fix_test(
+        "class Foo {}\nexport const a = new Foo();",
+        "class Foo {}\nexport const a: Foo = new Foo();",
+    );
+    fix_test(
+        "const b: number = 1;\nexport const a = { b };",
+        "const b: number = 1;\nexport const a: {\n\tb: number;\n} = { b };",
+    );
+    // Literals can be inferred by `isolatedDeclarations`
+    fix_test("export let a = 1;", "export let a = 1;");
+    // Cannot infer
+    fix_test("export const a = foo();", "export const a = foo();");
+    fix_test("export const a = [...b];", "export const a = [...b];");
+    fix_test(
+        "function foo<T>(): T {}\nexport const a = foo();",
+        "function foo<T>(): T {}\nexport const a = foo();",
+    );
+}
+
+#[test]
+fn functions() {
+    fix_test("export function foo() { return 1 }", "export function foo(): number { return 1 }");
+    fix_test("export function foo() {}", "export function foo(): void {}");
+    fix_test(
+        "export async function foo() { return '' }",
+        "export async function foo(): Promise<string> { return '' }",
+    );
+    fix_test(
+        "export function foo() { return [1] }",
+        "export function foo(): number[] { return [1] }",
+    );
+    fix_test("export const foo = () => 1;", "export const foo = (): number => 1;");
+    fix_test("export const foo = a => 1;", "export const foo = (a): number => 1;");
+    fix_test(
+        "export function foo(a = [1]): void {}",
+        "export function foo(a: number[] = [1]): void {}",
+    );
+    // Cannot infer
+    fix_test("export function foo() { return bar() }", "export function foo() { return bar() }");
+    fix_test("export function* foo() { yield 1 }", "export function* foo() { yield 1 }");
+    // Not exported
+    fix_test("function foo() { return 1 }\nexport {}", "function foo() { return 1 }\nexport {}");
+    // Shadowed top-level bindings
+    fix_test(
+        "const b: number = 1;\nexport function f(b) { return b }",
+        "const b: number = 1;\nexport function f(b) { return b }",
+    );
+    fix_test(
+        "function g(): number { return 1 }\nexport function f() { const g = () => ''; return g() }",
+        "function g(): number { return 1 }\nexport function f() { const g = () => ''; return g() }",
+    );
+    fix_test(
+        "class A {}\nexport const a = (A) => new A();",
+        "class A {}\nexport const a = (A) => new A();",
+    );
+}
+
+#[test]
+fn indentation() {
+    fix_test(
+        "if (x) {\n  function f() {}\n}\nexport class A {\n  foo = { a: [1] };\n}",
+        "if (x) {\n  function f() {}\n}\nexport class A {\n  foo: {\n    a: number[];\n  } = { a: [1] };\n}",
+    );
+    fix_test(
+        "export class A {\n\tfoo() {\n\t\treturn { a: [1] };\n\t}\n}",
+        "export class A {\n\tfoo(): {\n\t\ta: number[];\n\t} {\n\t\treturn { a: [1] };\n\t}\n}",
+    );
+}
+
+#[test]
+fn classes() {
+    fix_test(
+        "export class A { foo() { return 1 } }",
+        "export class A { foo(): number { return 1 } }",
+    );
+    fix_test("export class A { foo = [''] }", "export class A { foo: string[] = [''] }");
+    fix_test(
+        "export class A { get foo() { return 1 } }",
+        "export class A { get foo(): number { return 1 } }",
+    );
+    fix_test(
+        "export class A { get foo() { return 1 } set foo(v: number) {} }",
+        "export class A { get foo() { return 1 } set foo(v: number) {} }",
+    );
+    fix_test(
+        "export class A { private foo() { return 1 } }",
+        "export class A { private foo() { return 1 } }",
+    );
+}
@@ -1,4 +1,5 @@
 mod deno;
+mod fix;
 
 use std::{fmt::Write, fs, path::Path, sync::Arc};
 
@@ -293,6 +293,20 @@ pub fn get_declaration_from_reference_id<'a, 'b>(
     Some(semantic.nodes().get_node(symbol_table.symbol_declaration(symbol_id)))
 }
 
+/// Whether the symbol is declared by a function's parameter list, including
+/// destructured and rest parameters.
+pub fn is_function_parameter(symbol_id: SymbolId, semantic: &Semantic<'_>) -> bool {
+    let nodes = semantic.nodes();
+    let declaration = semantic.scoping().symbol_declaration(symbol_id);
+    match nodes.kind(declaration) {
+        AstKind::FormalParameter(_) => true,
+        AstKind::BindingRestElement(_) => {
+            matches!(nodes.parent_kind(declaration), AstKind::FormalParameters(_))
+        }
+        _ => false,
+    }
+}
+
 pub fn get_symbol_id_of_variable(
     ident: &IdentifierReference,
     semantic: &Semantic<'_>,
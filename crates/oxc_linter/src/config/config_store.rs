@@ -96,6 +96,23 @@ impl Config {
         self.base.rules.len()
     }
 
+    /// Whether a rule is enabled for any file, either in the base configuration or by one of the
+    /// overrides.
+    pub(crate) fn has_rule_enabled(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.base
+            .rules
+            .iter()
+            .any(|(rule, _)| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+            || self.overrides.iter().any(|override_config| {
+                override_config.rules.rules.iter().any(|rule| {
+                    rule.rule_name == rule_name
+                        && BuiltinLintPlugins::from(rule.plugin_name.as_str())
+                            == BuiltinLintPlugins::from(plugin_name)
+                        && rule.severity.is_warn_deny()
+                })
+            })
+    }
+
     pub fn apply_overrides(
         &self,
         path: &Path,
@@ -260,6 +277,16 @@ impl ConfigStore {
         &self.base.base.config.plugins
     }
 
+    /// Whether a rule is enabled for any file, in the root configuration or in any nested
+    /// configuration.
+    pub(crate) fn has_rule_enabled(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.base.has_rule_enabled(plugin_name, rule_name)
+            || self
+                .nested_configs
+                .values()
+                .any(|config| config.has_rule_enabled(plugin_name, rule_name))
+    }
+
     pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
         let resolved_config = if self.nested_configs.is_empty() {
             &self.base
@@ -16,9 +16,9 @@ impl LintPlugins {
 }
 
 bitflags! {
-    // NOTE: may be increased to a u32 if needed
+    // NOTE: may be increased to a u64 if needed
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-    pub struct BuiltinLintPlugins: u16 {
+    pub struct BuiltinLintPlugins: u32 {
         /// Not really a plugin. Included for completeness.
         const ESLINT = 0;
         /// `eslint-plugin-react`, plus `eslint-plugin-react-hooks`
@@ -51,6 +51,12 @@ bitflags! {
         const REGEX = 1 << 13;
         /// `eslint-plugin-vue`
         const VUE = 1 << 14;
+        /// `eslint-plugin-testing-library`
+        const TESTING_LIBRARY = 1 << 15;
+        /// `eslint-plugin-playwright`
+        const PLAYWRIGHT = 1 << 16;
+        /// `eslint-plugin-security`, plus custom rules for XSS sinks
+        const SECURITY = 1 << 17;
     }
 }
 
@@ -125,6 +131,9 @@ impl From<&str> for BuiltinLintPlugins {
             "node" => BuiltinLintPlugins::NODE,
             "regex" => BuiltinLintPlugins::REGEX,
             "vue" => BuiltinLintPlugins::VUE,
+            "testing-library" | "testing_library" => BuiltinLintPlugins::TESTING_LIBRARY,
+            "playwright" => BuiltinLintPlugins::PLAYWRIGHT,
+            "security" => BuiltinLintPlugins::SECURITY,
             // "eslint" is not really a plugin, so it's 'empty'. This has the added benefit of
             // making it the default value.
             _ => BuiltinLintPlugins::empty(),
@@ -150,6 +159,9 @@ impl From<BuiltinLintPlugins> for &'static str {
             BuiltinLintPlugins::NODE => "node",
             BuiltinLintPlugins::REGEX => "regex",
             BuiltinLintPlugins::VUE => "vue",
+            BuiltinLintPlugins::TESTING_LIBRARY => "testing-library",
+            BuiltinLintPlugins::PLAYWRIGHT => "playwright",
+            BuiltinLintPlugins::SECURITY => "security",
             _ => "",
         }
     }
@@ -235,6 +247,9 @@ impl JsonSchema for LintPlugins {
             Node,
             Regex,
             Vue,
+            TestingLibrary,
+            Playwright,
+            Security,
         }
 
         let enum_schema = r#gen.subschema_for::<LintPluginOptionsSchema>();
@@ -286,6 +301,11 @@ mod tests {
         assert_eq!(BuiltinLintPlugins::from("react"), BuiltinLintPlugins::REACT);
         assert_eq!(BuiltinLintPlugins::from("typescript-eslint"), BuiltinLintPlugins::TYPESCRIPT);
         assert_eq!(BuiltinLintPlugins::from("deepscan"), BuiltinLintPlugins::OXC);
+        assert_eq!(
+            BuiltinLintPlugins::from("testing-library"),
+            BuiltinLintPlugins::TESTING_LIBRARY
+        );
+        assert_eq!(BuiltinLintPlugins::from("playwright"), BuiltinLintPlugins::PLAYWRIGHT);
         assert_eq!(BuiltinLintPlugins::from("unknown"), BuiltinLintPlugins::empty());
     }
 
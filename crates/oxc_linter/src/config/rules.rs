@@ -239,6 +239,7 @@ fn parse_rule_key(name: &str) -> (String, String) {
         "import-x" => ("import", rule_name),
         "jsx-a11y" => ("jsx_a11y", rule_name),
         "react-perf" => ("react_perf", rule_name),
+        "testing-library" => ("testing_library", rule_name),
         // e.g. "@next/next/google-font-display"
         "@next" => ("nextjs", rule_name.trim_start_matches("next/")),
         // For backwards compatibility, react hook rules reside in the react plugin.
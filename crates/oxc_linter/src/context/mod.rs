@@ -470,4 +470,7 @@ const PLUGIN_PREFIXES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "unicorn" => "eslint-plugin-unicorn",
     "vitest" => "eslint-plugin-vitest",
     "node" => "eslint-plugin-node",
+    "testing_library" => "eslint-plugin-testing-library",
+    "playwright" => "eslint-plugin-playwright",
+    "security" => "eslint-plugin-security",
 };
@@ -190,6 +190,84 @@ pub fn is_valid_aria_property(name: &str) -> bool {
     AriaProperty::try_from(name).is_ok()
 }
 
+/// The type of the value of an ARIA property.
+/// Reference: <https://github.com/A11yance/aria-query/blob/v5.3.2/src/ariaPropsMap.js>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AriaPropertyType {
+    /// `undefined` is allowed as well if `allow_undefined` is set.
+    Boolean {
+        allow_undefined: bool,
+    },
+    /// The ID of an element.
+    Id,
+    /// A space separated list of element IDs.
+    IdList,
+    Integer,
+    Number,
+    String,
+    /// One of the given values.
+    Token(&'static [&'static str]),
+    /// A space separated list of the given values.
+    TokenList(&'static [&'static str]),
+    /// A boolean, or `"mixed"`.
+    Tristate,
+}
+
+impl AriaProperty {
+    pub fn value_type(self) -> AriaPropertyType {
+        use AriaPropertyType::{
+            Boolean, Id, IdList, Integer, Number, String, Token, TokenList, Tristate,
+        };
+        match self {
+            Self::ActiveDescendant | Self::Details | Self::ErrorMessage => Id,
+            Self::Controls | Self::DescribedBy | Self::FlowTo | Self::LabelledBy | Self::Owns => {
+                IdList
+            }
+            Self::Atomic
+            | Self::Busy
+            | Self::Disabled
+            | Self::Modal
+            | Self::Multiline
+            | Self::Multiselectable
+            | Self::Readonly
+            | Self::Required => Boolean { allow_undefined: false },
+            Self::Expanded | Self::Grabbed | Self::Hidden | Self::Selected => {
+                Boolean { allow_undefined: true }
+            }
+            Self::Checked | Self::Pressed => Tristate,
+            Self::ColCount
+            | Self::ColIndex
+            | Self::ColSpan
+            | Self::Level
+            | Self::PosInSet
+            | Self::RowCount
+            | Self::RowIndex
+            | Self::RowSpan
+            | Self::SetSize => Integer,
+            Self::ValueMax | Self::ValueMin | Self::ValueNow => Number,
+            Self::BrailleLabel
+            | Self::BrailleRoleDescription
+            | Self::Description
+            | Self::KeyShortcuts
+            | Self::Label
+            | Self::Placeholder
+            | Self::RoleDescription
+            | Self::ValueText => String,
+            Self::AutoComplete => Token(&["inline", "list", "both", "none"]),
+            Self::Current => Token(&["page", "step", "location", "date", "time", "true", "false"]),
+            Self::HasPopup => {
+                Token(&["false", "true", "menu", "listbox", "tree", "grid", "dialog"])
+            }
+            Self::Invalid => Token(&["grammar", "false", "spelling", "true"]),
+            Self::Live => Token(&["assertive", "off", "polite"]),
+            Self::Orientation => Token(&["vertical", "undefined", "horizontal"]),
+            Self::Sort => Token(&["ascending", "descending", "none", "other"]),
+            Self::DropEffect => TokenList(&["copy", "execute", "link", "move", "none", "popup"]),
+            Self::Relevant => TokenList(&["additions", "all", "removals", "text"]),
+        }
+    }
+}
+
 /// set of valid ARIA role definitions
 /// Reference: <https://www.w3.org/TR/wai-aria/#role_definitions>
 /// Reference: <https://github.com/A11yance/aria-query/blob/v5.3.2/src/rolesMap.js>
@@ -321,6 +399,100 @@ pub const VALID_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
     "treeitem",
 ];
 
+/// Roles of widgets, i.e. roles descending from the abstract `widget` role, and `toolbar`,
+/// which supports `aria-activedescendant` and thus is treated as a widget in practice.
+///
+/// `progressbar` descends from `widget` as well, but its value is read only, so it is
+/// considered non-interactive.
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isInteractiveRole.js>
+pub const INTERACTIVE_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
+    "button",
+    "checkbox",
+    "columnheader",
+    "combobox",
+    "doc-backlink",
+    "doc-biblioref",
+    "doc-glossref",
+    "doc-noteref",
+    "grid",
+    "gridcell",
+    "link",
+    "listbox",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "radiogroup",
+    "row",
+    "rowheader",
+    "scrollbar",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "tablist",
+    "textbox",
+    "toolbar",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Abstract roles, which must not be used in content.
+/// Reference: <https://www.w3.org/TR/wai-aria/#abstract_roles>
+pub const ABSTRACT_ARIA_ROLES: phf::Set<&'static str> = phf::phf_set![
+    "command",
+    "composite",
+    "input",
+    "landmark",
+    "range",
+    "roletype",
+    "section",
+    "sectionhead",
+    "select",
+    "structure",
+    "widget",
+    "window",
+];
+
+/// Whether `role` is a concrete role that is not a widget.
+/// Reference: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isNonInteractiveRole.js>
+pub fn is_non_interactive_aria_role(role: &str) -> bool {
+    VALID_ARIA_ROLES.contains(role) && !INTERACTIVE_ARIA_ROLES.contains(role)
+}
+
+/// JSX event handlers, by the type of event.
+/// Reference: <https://github.com/jsx-eslint/jsx-ast-utils/blob/v3.3.5/src/eventHandlers.js>
+pub const FOCUS_EVENT_HANDLERS: [&str; 2] = ["onFocus", "onBlur"];
+pub const IMAGE_EVENT_HANDLERS: [&str; 2] = ["onLoad", "onError"];
+pub const KEYBOARD_EVENT_HANDLERS: [&str; 3] = ["onKeyDown", "onKeyPress", "onKeyUp"];
+pub const MOUSE_EVENT_HANDLERS: [&str; 19] = [
+    "onClick",
+    "onContextMenu",
+    "onDblClick",
+    "onDoubleClick",
+    "onDrag",
+    "onDragEnd",
+    "onDragEnter",
+    "onDragExit",
+    "onDragLeave",
+    "onDragOver",
+    "onDragStart",
+    "onDrop",
+    "onMouseDown",
+    "onMouseEnter",
+    "onMouseLeave",
+    "onMouseMove",
+    "onMouseOut",
+    "onMouseOver",
+    "onMouseUp",
+];
+
 pub const HTML_TAG: phf::Set<&'static str> = phf::phf_set![
     "a",
     "abbr",
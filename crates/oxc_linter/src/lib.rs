@@ -29,6 +29,7 @@ mod service;
 mod utils;
 
 pub mod loader;
+pub mod metrics;
 pub mod rules;
 pub mod table;
 
@@ -122,6 +123,11 @@ impl Linter {
         self.config.number_of_rules()
     }
 
+    /// Whether a rule is enabled for any of the files to lint.
+    pub(crate) fn has_rule_enabled(&self, plugin_name: &str, rule_name: &str) -> bool {
+        self.config.has_rule_enabled(plugin_name, rule_name)
+    }
+
     pub fn run<'a>(
         &self,
         path: &Path,
@@ -318,7 +324,18 @@ impl RawTransferMetadata {
 
 #[cfg(test)]
 mod test {
-    use super::Oxlintrc;
+    use std::{path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::{ParseOptions, Parser};
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use rustc_hash::FxHashMap;
+
+    use super::{
+        ConfigStore, ConfigStoreBuilder, ExternalPluginStore, LintOptions, Linter, ModuleRecord,
+        Oxlintrc,
+    };
 
     #[test]
     fn test_schema_json() {
@@ -336,4 +353,54 @@ mod test {
             insta::assert_snapshot!(json);
         });
     }
+
+    /// All rules run on programs recovered from syntax errors, and report on the statements
+    /// around the errors.
+    #[test]
+    fn test_lint_recovered_program() {
+        let source_text = "import { a as } from 'a';
+const = 1;
+let b = ;
+foo(b c);
+class C { d = ; e() { debugger; } }
+enum { F }
+const g = { h: , i };
+export default ;
+debugger;
+";
+        let path = Path::new("recovered.tsx");
+        let allocator = Allocator::default();
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::tsx()).with_options(options).parse();
+        assert!(!ret.errors.is_empty());
+        assert!(!ret.panicked);
+
+        let semantic = SemanticBuilder::new()
+            .with_cfg(true)
+            .with_scope_tree_child_ids(true)
+            .with_build_jsdoc(true)
+            .build(allocator.alloc(ret.program))
+            .semantic;
+        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let config = ConfigStore::new(
+            ConfigStoreBuilder::all().build(),
+            FxHashMap::default(),
+            ExternalPluginStore::default(),
+        );
+        let messages = Linter::new(LintOptions::default(), config, None).run(
+            path,
+            Rc::new(semantic),
+            module_record,
+            &allocator,
+        );
+
+        let count = |message: &str| {
+            messages.iter().filter(|m| m.error.to_string().contains(message)).count()
+        };
+        assert_eq!(count("`debugger` statement is not allowed"), 2);
+        assert_eq!(count("'g' is declared but never used"), 1);
+        // Placeholders for the missing names declare nothing.
+        assert_eq!(count("''"), 0);
+    }
 }
@@ -1,11 +1,15 @@
 use std::{error::Error, fmt, path::Path};
 
-use oxc_span::SourceType;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
-mod partial_loader;
-mod source;
-pub use partial_loader::{LINT_PARTIAL_LOADER_EXTENSIONS, LINTABLE_EXTENSIONS, PartialLoader};
-pub use source::JavaScriptSource;
+pub use oxc_partial_loader::{
+    JavaScriptSource, PARTIAL_LOADER_EXTENSIONS as LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader,
+};
+
+/// All valid JavaScript/TypeScript extensions, plus additional framework files that
+/// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
+pub const LINTABLE_EXTENSIONS: &[&str] =
+    constcat::concat_slices!([&str]: VALID_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS);
 
 // TODO: use oxc_resolver::FileSystem. We can't do so until that crate exposes FileSystemOs
 // externally.
@@ -0,0 +1,185 @@
+//! Code health metrics of the functions in a file, for `oxlint --metrics`.
+
+use std::{ffi::OsStr, path::Path};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstKind, ast::PropertyKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType};
+use serde::Serialize;
+
+use crate::{
+    ast_util::is_function_node,
+    loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
+    utils::{
+        SwitchComplexity, cognitive_complexity, cyclomatic_complexity, get_complexity_function_name,
+    },
+};
+
+/// Metrics of a single function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionMetrics {
+    /// Kind and name of the function, e.g. ``async function `foo` `` or ``method `bar` ``.
+    pub name: String,
+    /// 1-based line of the start of the function.
+    pub line: usize,
+    /// 1-based column of the start of the function, in characters.
+    pub column: usize,
+    /// See `eslint/complexity`, with the classic variant.
+    pub cyclomatic_complexity: usize,
+    /// See `oxc/cognitive-complexity`.
+    pub cognitive_complexity: usize,
+    /// Number of lines the function spans.
+    pub lines_of_code: usize,
+    /// Number of parameters, including a rest parameter.
+    pub parameters: usize,
+}
+
+/// Collects the metrics of every function in `source_text`, in source order.
+///
+/// Returns `None` if the file type of `path` is not supported, and the syntax errors
+/// if the file can't be parsed.
+pub fn collect_metrics(
+    path: &Path,
+    source_text: &str,
+) -> Option<Result<Vec<FunctionMetrics>, Vec<OxcDiagnostic>>> {
+    let ext = path.extension().and_then(OsStr::to_str)?;
+    let source_type = match SourceType::from_path(path) {
+        // Treat JS files as JSX to maximize the chance of parsing them, like the linter does.
+        Ok(source_type) if source_type.is_javascript() => source_type.with_jsx(true),
+        Ok(source_type) => source_type,
+        Err(_) if LINT_PARTIAL_LOADER_EXTENSIONS.contains(&ext) => SourceType::default(),
+        Err(_) => return None,
+    };
+    let sections = PartialLoader::parse(ext, source_text)
+        .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+
+    let mut metrics = vec![];
+    for section in sections {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, section.source_text, section.source_type)
+            .with_options(ParseOptions {
+                allow_return_outside_function: true,
+                ..ParseOptions::default()
+            })
+            .parse();
+        if !ret.errors.is_empty() {
+            return Some(Err(ret.errors));
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new().build(program).semantic;
+
+        for node in semantic.nodes() {
+            if !is_function_node(node) {
+                continue;
+            }
+            let (params, rest) = match node.kind() {
+                AstKind::Function(func) => (func.params.items.len(), func.params.rest.is_some()),
+                AstKind::ArrowFunctionExpression(arrow) => {
+                    (arrow.params.items.len(), arrow.params.rest.is_some())
+                }
+                _ => continue,
+            };
+            let parent = semantic.nodes().parent_node(node.id());
+            let name = get_complexity_function_name(node, parent).into_owned();
+            let span = node.span();
+            let code = span.source_text(section.source_text);
+            // The function of a method starts at its parameters, so report the start of the method.
+            let start = match parent.kind() {
+                AstKind::MethodDefinition(method) => method.span.start,
+                AstKind::ObjectProperty(property)
+                    if property.method || property.kind != PropertyKind::Init =>
+                {
+                    property.span.start
+                }
+                _ => span.start,
+            };
+            let start = (section.start + start) as usize;
+            let (line, column) = line_and_column(source_text, start);
+
+            metrics.push(FunctionMetrics {
+                name,
+                line,
+                column,
+                cyclomatic_complexity: cyclomatic_complexity(
+                    node.kind(),
+                    SwitchComplexity::Classic,
+                ),
+                cognitive_complexity: cognitive_complexity(node.kind()),
+                lines_of_code: code.lines().count().max(1),
+                parameters: params + usize::from(rest),
+            });
+        }
+    }
+    Some(Ok(metrics))
+}
+
+fn line_and_column(source_text: &str, offset: usize) -> (usize, usize) {
+    let before = &source_text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.bytes().filter(|b| *b == b'\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{FunctionMetrics, collect_metrics};
+
+    #[test]
+    fn function_metrics() {
+        let source_text = "function foo(a, b = 1, ...c) {\n  if (a) {\n    return a ? b : c;\n  }\n}\nconst bar = () => x && y;\n";
+        let metrics = collect_metrics(Path::new("test.js"), source_text).unwrap().unwrap();
+        assert_eq!(
+            metrics,
+            vec![
+                FunctionMetrics {
+                    name: "function `foo`".to_string(),
+                    line: 1,
+                    column: 1,
+                    cyclomatic_complexity: 4,
+                    cognitive_complexity: 3,
+                    lines_of_code: 5,
+                    parameters: 3,
+                },
+                FunctionMetrics {
+                    name: "function `bar`".to_string(),
+                    line: 6,
+                    column: 13,
+                    cyclomatic_complexity: 2,
+                    cognitive_complexity: 1,
+                    lines_of_code: 1,
+                    parameters: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_loader_offsets() {
+        let source_text = "<template><div /></template>\n<script>\nfunction foo() {}\n</script>\n";
+        let metrics = collect_metrics(Path::new("test.vue"), source_text).unwrap().unwrap();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!((metrics[0].line, metrics[0].column), (3, 1));
+    }
+
+    #[test]
+    fn unsupported_and_invalid_files() {
+        assert!(collect_metrics(Path::new("test.txt"), "function foo() {}").is_none());
+        assert!(collect_metrics(Path::new("test.js"), "function foo( {}").unwrap().is_err());
+    }
+
+    #[test]
+    fn method_positions() {
+        let source_text =
+            "class A {\n  static foo() {}\n}\n({ get bar() {}, baz: function () {} });\n";
+        let metrics = collect_metrics(Path::new("test.js"), source_text).unwrap().unwrap();
+        let positions = metrics.iter().map(|m| (m.line, m.column)).collect::<Vec<_>>();
+        assert_eq!(positions, vec![(2, 3), (4, 4), (4, 23)]);
+    }
+}
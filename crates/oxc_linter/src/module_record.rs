@@ -6,7 +6,7 @@ use std::{
     sync::{Arc, OnceLock, RwLock},
 };
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_semantic::Semantic;
 use oxc_span::{CompactStr, Span};
@@ -48,6 +48,20 @@ pub struct ModuleRecord {
     /// [`ModuleRecord`] is created. You must link the module records yourself.
     pub loaded_modules: RwLock<FxHashMap<CompactStr, Arc<ModuleRecord>>>,
 
+    /// Resolved absolute paths of the specifiers in `requested_modules`.
+    ///
+    /// A specifier maps to `None` if it could not be resolved. Unlike `loaded_modules`, this map
+    /// also contains specifiers resolved to files that are not part of the module graph, such as
+    /// `.json` or `.css` files. It is only populated when the import plugin is enabled.
+    pub resolved_requested_modules: FxHashMap<CompactStr, Option<PathBuf>>,
+
+    /// Modules importing this module, keyed by their resolved absolute path, along with the names
+    /// they import from it.
+    ///
+    /// These reverse edges of the module graph are only populated when a rule needs the complete
+    /// module graph, such as `import/no-unused-modules`.
+    pub importers: RwLock<FxHashMap<PathBuf, ImportedNames>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of `ImportEntry` records derived from the code of this module
@@ -102,6 +116,8 @@ impl fmt::Debug for ModuleRecord {
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("resolved_requested_modules", &self.resolved_requested_modules)
+            .field("importers", &self.importers)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -135,6 +151,31 @@ impl<'a> From<&oxc_syntax::module_record::NameSpan<'a>> for NameSpan {
     }
 }
 
+/// The bindings a module imports, or re-exports, from one of its dependencies.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportedNames {
+    /// Names imported or re-exported by name, `default` for default imports.
+    pub names: FxHashSet<CompactStr>,
+    /// Imported as a namespace object (`import * as ns`, `export * as ns from`), so every export
+    /// may be used.
+    pub namespace: bool,
+    /// Re-exported with `export * from`, which forwards every export except `default`.
+    pub star: bool,
+}
+
+impl ImportedNames {
+    /// Whether the export `name` is imported.
+    pub fn contains(&self, name: &str) -> bool {
+        self.namespace || (self.star && name != "default") || self.names.contains(name)
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        self.names.extend(other.names);
+        self.namespace |= other.namespace;
+        self.star |= other.star;
+    }
+}
+
 /// [`ImportEntry`](https://tc39.es/ecma262/#importentry-record)
 ///
 /// ## Examples
@@ -508,6 +549,40 @@ impl ModuleRecord {
         }
     }
 
+    /// Collects what this module imports, or re-exports, from the module requested with
+    /// `specifier`.
+    pub fn imported_names(&self, specifier: &str) -> ImportedNames {
+        let mut imported = ImportedNames::default();
+        for entry in &self.import_entries {
+            if entry.module_request.name() != specifier {
+                continue;
+            }
+            match &entry.import_name {
+                ImportImportName::Name(name) => {
+                    imported.names.insert(name.name.clone());
+                }
+                ImportImportName::Default(_) => {
+                    imported.names.insert(CompactStr::new("default"));
+                }
+                ImportImportName::NamespaceObject => imported.namespace = true,
+            }
+        }
+        for entry in self.indirect_export_entries.iter().chain(&self.star_export_entries) {
+            if entry.module_request.as_ref().is_none_or(|request| request.name() != specifier) {
+                continue;
+            }
+            match &entry.import_name {
+                ExportImportName::Name(name) => {
+                    imported.names.insert(name.name.clone());
+                }
+                ExportImportName::All => imported.namespace = true,
+                ExportImportName::AllButDefault => imported.star = true,
+                ExportImportName::Null => {}
+            }
+        }
+        imported
+    }
+
     pub(crate) fn exported_bindings_from_star_export(
         &self,
     ) -> &FxHashMap<PathBuf, Vec<CompactStr>> {
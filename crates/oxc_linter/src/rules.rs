@@ -26,14 +26,20 @@ mod import {
     pub mod no_duplicates;
     pub mod no_dynamic_require;
     pub mod no_empty_named_blocks;
+    pub mod no_extraneous_dependencies;
+    pub mod no_internal_modules;
     pub mod no_mutable_exports;
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_named_default;
     pub mod no_namespace;
+    pub mod no_restricted_paths;
     pub mod no_self_import;
     pub mod no_unassigned_import;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
     pub mod prefer_default_export;
     pub mod unambiguous;
 }
@@ -42,6 +48,8 @@ mod eslint {
     pub mod array_callback_return;
     pub mod arrow_body_style;
     pub mod block_scoped_var;
+    pub mod complexity;
+    pub mod consistent_return;
     pub mod curly;
     pub mod default_case;
     pub mod default_case_last;
@@ -112,6 +120,7 @@ mod eslint {
     pub mod no_labels;
     pub mod no_lone_blocks;
     pub mod no_lonely_if;
+    pub mod no_loop_func;
     pub mod no_loss_of_precision;
     pub mod no_magic_numbers;
     pub mod no_multi_assign;
@@ -125,6 +134,7 @@ mod eslint {
     pub mod no_nonoctal_decimal_escape;
     pub mod no_obj_calls;
     pub mod no_object_constructor;
+    pub mod no_param_reassign;
     pub mod no_plusplus;
     pub mod no_proto;
     pub mod no_prototype_builtins;
@@ -132,11 +142,14 @@ mod eslint {
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
+    pub mod no_restricted_properties;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
@@ -146,8 +159,10 @@ mod eslint {
     pub mod no_undef;
     pub mod no_undefined;
     pub mod no_unexpected_multiline;
+    pub mod no_unmodified_loop_condition;
     pub mod no_unneeded_ternary;
     pub mod no_unreachable;
+    pub mod no_unreachable_loop;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
@@ -155,6 +170,7 @@ mod eslint {
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
     pub mod no_unused_vars;
+    pub mod no_use_before_define;
     pub mod no_useless_backreference;
     pub mod no_useless_call;
     pub mod no_useless_catch;
@@ -162,10 +178,12 @@ mod eslint {
     pub mod no_useless_constructor;
     pub mod no_useless_escape;
     pub mod no_useless_rename;
+    pub mod no_useless_return;
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
     pub mod operator_assignment;
+    pub mod prefer_const;
     pub mod prefer_exponentiation_operator;
     pub mod prefer_numeric_literals;
     pub mod prefer_object_has_own;
@@ -289,15 +307,18 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod display_name;
     pub mod exhaustive_deps;
     pub mod forbid_elements;
     pub mod forward_ref_uses_ref;
+    pub mod hook_use_state;
     pub mod iframe_missing_sandbox;
     pub mod jsx_boolean_value;
     pub mod jsx_curly_brace_presence;
     pub mod jsx_filename_extension;
     pub mod jsx_key;
     pub mod jsx_no_comment_textnodes;
+    pub mod jsx_no_constructed_context_values;
     pub mod jsx_no_duplicate_props;
     pub mod jsx_no_script_url;
     pub mod jsx_no_target_blank;
@@ -312,12 +333,15 @@ mod react {
     pub mod no_find_dom_node;
     pub mod no_is_mounted;
     pub mod no_namespace;
+    pub mod no_object_type_as_default_prop;
     pub mod no_render_return_value;
     pub mod no_set_state;
     pub mod no_string_refs;
     pub mod no_unescaped_entities;
     pub mod no_unknown_property;
+    pub mod no_unstable_nested_components;
     pub mod prefer_es6_class;
+    pub mod prop_types;
     pub mod react_in_jsx_scope;
     pub mod require_render_return;
     pub mod rules_of_hooks;
@@ -447,14 +471,17 @@ mod jsx_a11y {
     pub mod anchor_is_valid;
     pub mod aria_activedescendant_has_tabindex;
     pub mod aria_props;
+    pub mod aria_proptypes;
     pub mod aria_role;
     pub mod aria_unsupported_elements;
     pub mod autocomplete_valid;
     pub mod click_events_have_key_events;
+    pub mod control_has_associated_label;
     pub mod heading_has_content;
     pub mod html_has_lang;
     pub mod iframe_has_title;
     pub mod img_redundant_alt;
+    pub mod interactive_supports_focus;
     pub mod label_has_associated_control;
     pub mod lang;
     pub mod media_has_caption;
@@ -463,8 +490,12 @@ mod jsx_a11y {
     pub mod no_aria_hidden_on_focusable;
     pub mod no_autofocus;
     pub mod no_distracting_elements;
+    pub mod no_interactive_element_to_noninteractive_role;
+    pub mod no_noninteractive_element_interactions;
+    pub mod no_noninteractive_element_to_interactive_role;
     pub mod no_noninteractive_tabindex;
     pub mod no_redundant_roles;
+    pub mod no_static_element_interactions;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
     pub mod role_supports_aria_props;
@@ -481,6 +512,7 @@ mod oxc {
     pub mod bad_min_max_func;
     pub mod bad_object_literal_comparison;
     pub mod bad_replace_all_arg;
+    pub mod cognitive_complexity;
     pub mod const_comparisons;
     pub mod double_comparisons;
     pub mod erasing_op;
@@ -576,10 +608,40 @@ mod node {
     pub mod no_new_require;
 }
 
+mod testing_library {
+    pub mod await_async_queries;
+    pub mod await_async_utils;
+    pub mod no_await_sync_queries;
+    pub mod no_container;
+    pub mod no_debugging_utils;
+    pub mod no_node_access;
+    pub mod prefer_screen_queries;
+}
+
+mod security {
+    pub mod detect_child_process;
+    pub mod detect_eval_with_expression;
+    pub mod detect_html_injection;
+    pub mod detect_non_literal_regexp;
+    pub mod detect_object_injection;
+}
+
+mod playwright {
+    pub mod missing_playwright_await;
+    pub mod no_element_handle;
+    pub mod no_focused_test;
+    pub mod no_networkidle;
+    pub mod no_page_pause;
+    pub mod no_skipped_test;
+    pub mod no_wait_for_timeout;
+}
+
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
     eslint::arrow_body_style,
     eslint::block_scoped_var,
+    eslint::complexity,
+    eslint::consistent_return,
     eslint::curly,
     eslint::default_case,
     eslint::default_case_last,
@@ -614,11 +676,16 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_labels,
     eslint::no_lone_blocks,
     eslint::no_lonely_if,
+    eslint::no_loop_func,
     eslint::no_multi_assign,
     eslint::no_nested_ternary,
     eslint::no_object_constructor,
+    eslint::no_param_reassign,
     eslint::no_restricted_imports,
+    eslint::no_restricted_properties,
+    eslint::no_restricted_syntax,
     eslint::no_unneeded_ternary,
+    eslint::no_unmodified_loop_condition,
     eslint::no_useless_backreference,
     eslint::no_useless_call,
     eslint::no_compare_neg_zero,
@@ -678,6 +745,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_template_curly_in_string,
@@ -688,6 +756,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_undefined,
     eslint::no_unexpected_multiline,
     eslint::no_unreachable,
+    eslint::no_unreachable_loop,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
@@ -695,16 +764,19 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
     eslint::no_unused_vars,
+    eslint::no_use_before_define,
     eslint::no_useless_catch,
     eslint::no_useless_concat,
     eslint::no_useless_constructor,
     eslint::no_useless_escape,
     eslint::no_useless_rename,
+    eslint::no_useless_return,
     eslint::no_var,
     eslint::no_void,
     eslint::no_with,
     eslint::operator_assignment,
     eslint::prefer_promise_reject_errors,
+    eslint::prefer_const,
     eslint::prefer_exponentiation_operator,
     eslint::prefer_numeric_literals,
     eslint::prefer_object_has_own,
@@ -746,10 +818,16 @@ oxc_macros::declare_all_lint_rules! {
     import::no_default_export,
     import::no_duplicates,
     import::no_dynamic_require,
+    import::no_extraneous_dependencies,
+    import::no_internal_modules,
     import::no_named_as_default,
     import::no_named_as_default_member,
+    import::no_restricted_paths,
     import::no_self_import,
+    import::no_unresolved,
+    import::no_unused_modules,
     import::no_webpack_loader_syntax,
+    import::order,
     import::prefer_default_export,
     import::unambiguous,
     jest::consistent_test_it,
@@ -820,34 +898,41 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_returns_type,
     jsdoc::require_yields,
     jsx_a11y::alt_text,
+    jsx_a11y::anchor_ambiguous_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
     jsx_a11y::aria_activedescendant_has_tabindex,
     jsx_a11y::aria_props,
+    jsx_a11y::aria_proptypes,
     jsx_a11y::aria_role,
     jsx_a11y::aria_unsupported_elements,
     jsx_a11y::autocomplete_valid,
     jsx_a11y::click_events_have_key_events,
+    jsx_a11y::control_has_associated_label,
     jsx_a11y::heading_has_content,
     jsx_a11y::html_has_lang,
     jsx_a11y::iframe_has_title,
     jsx_a11y::img_redundant_alt,
+    jsx_a11y::interactive_supports_focus,
     jsx_a11y::label_has_associated_control,
     jsx_a11y::lang,
     jsx_a11y::media_has_caption,
     jsx_a11y::mouse_events_have_key_events,
-    jsx_a11y::no_noninteractive_tabindex,
     jsx_a11y::no_access_key,
     jsx_a11y::no_aria_hidden_on_focusable,
     jsx_a11y::no_autofocus,
     jsx_a11y::no_distracting_elements,
+    jsx_a11y::no_interactive_element_to_noninteractive_role,
+    jsx_a11y::no_noninteractive_element_interactions,
+    jsx_a11y::no_noninteractive_element_to_interactive_role,
+    jsx_a11y::no_noninteractive_tabindex,
     jsx_a11y::no_redundant_roles,
+    jsx_a11y::no_static_element_interactions,
     jsx_a11y::prefer_tag_over_role,
     jsx_a11y::role_has_required_aria_props,
     jsx_a11y::role_supports_aria_props,
     jsx_a11y::scope,
     jsx_a11y::tabindex_no_positive,
-    jsx_a11y::anchor_ambiguous_text,
     nextjs::google_font_display,
     nextjs::google_font_preconnect,
     nextjs::inline_script_id,
@@ -879,6 +964,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::bad_min_max_func,
     oxc::bad_object_literal_comparison,
     oxc::bad_replace_all_arg,
+    oxc::cognitive_complexity,
     oxc::const_comparisons,
     oxc::double_comparisons,
     oxc::erasing_op,
@@ -895,6 +981,13 @@ oxc_macros::declare_all_lint_rules! {
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
+    playwright::missing_playwright_await,
+    playwright::no_element_handle,
+    playwright::no_focused_test,
+    playwright::no_networkidle,
+    playwright::no_page_pause,
+    playwright::no_skipped_test,
+    playwright::no_wait_for_timeout,
     promise::avoid_new,
     promise::catch_or_return,
     promise::no_return_wrap,
@@ -911,15 +1004,18 @@ oxc_macros::declare_all_lint_rules! {
     promise::valid_params,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::display_name,
     react::exhaustive_deps,
     react::forbid_elements,
     react::forward_ref_uses_ref,
+    react::hook_use_state,
     react::iframe_missing_sandbox,
     react::jsx_filename_extension,
     react::jsx_boolean_value,
     react::jsx_curly_brace_presence,
     react::jsx_key,
     react::jsx_no_comment_textnodes,
+    react::jsx_no_constructed_context_values,
     react::jsx_no_duplicate_props,
     react::jsx_no_script_url,
     react::jsx_no_target_blank,
@@ -934,12 +1030,15 @@ oxc_macros::declare_all_lint_rules! {
     react::no_direct_mutation_state,
     react::no_find_dom_node,
     react::no_is_mounted,
+    react::no_object_type_as_default_prop,
     react::no_render_return_value,
     react::no_set_state,
     react::no_string_refs,
     react::no_unescaped_entities,
     react::no_unknown_property,
+    react::no_unstable_nested_components,
     react::prefer_es6_class,
+    react::prop_types,
     react::react_in_jsx_scope,
     react::require_render_return,
     react::rules_of_hooks,
@@ -950,6 +1049,18 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
+    security::detect_child_process,
+    security::detect_eval_with_expression,
+    security::detect_html_injection,
+    security::detect_non_literal_regexp,
+    security::detect_object_injection,
+    testing_library::await_async_queries,
+    testing_library::await_async_utils,
+    testing_library::no_await_sync_queries,
+    testing_library::no_container,
+    testing_library::no_debugging_utils,
+    testing_library::no_node_access,
+    testing_library::prefer_screen_queries,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -0,0 +1,268 @@
+use std::ops::Deref;
+
+use cow_utils::CowUtils;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    ast_util::is_function_node,
+    context::LintContext,
+    rule::Rule,
+    utils::{SwitchComplexity, cyclomatic_complexity, get_complexity_function_name},
+};
+
+fn complexity_diagnostic(name: &str, complexity: usize, max: usize, span: Span) -> OxcDiagnostic {
+    let mut chars = name.chars();
+    let name = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+    OxcDiagnostic::warn(format!(
+        "{name} has a complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Consider splitting it into smaller functions.")
+    .with_label(span)
+}
+
+const DEFAULT_MAX_COMPLEXITY: usize = 20;
+
+#[derive(Debug, Default, Clone)]
+pub struct Complexity(Box<ComplexityConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ComplexityConfig {
+    max: usize,
+    variant: SwitchComplexity,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self { max: DEFAULT_MAX_COMPLEXITY, variant: SwitchComplexity::default() }
+    }
+}
+
+impl Deref for Complexity {
+    type Target = ComplexityConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a maximum cyclomatic complexity of functions.
+    ///
+    /// The cyclomatic complexity is the number of linearly independent paths
+    /// through a function. It starts at one, and every `if`, conditional
+    /// expression, logical operator, loop, `catch`, `case`, default value and
+    /// optional chain adds one.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Functions with many branches are hard to understand and need many tests
+    /// to cover every path through them.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "max": 2 }`:
+    /// ```js
+    /// function a(x) {
+    ///   if (true) {
+    ///     return x; // 1st path
+    ///   } else if (false) {
+    ///     return x + 1; // 2nd path
+    ///   } else {
+    ///     return 4; // 3rd path
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "max": 2 }`:
+    /// ```js
+    /// function a(x) {
+    ///   if (true) {
+    ///     return x; // 1st path
+    ///   } else {
+    ///     return 4; // 2nd path
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### max
+    ///
+    /// `{ type: number, default: 20 }`
+    ///
+    /// The maximum complexity allowed. The option can also be given as a number,
+    /// e.g. `"eslint/complexity": ["error", 10]`.
+    ///
+    /// #### variant
+    ///
+    /// `{ type: "classic" | "modified", default: "classic" }`
+    ///
+    /// With `"modified"`, a `switch` statement adds one to the complexity, no
+    /// matter how many cases it has.
+    Complexity,
+    eslint,
+    pedantic
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let as_usize = |value: &Value| value.as_u64().and_then(|max| usize::try_from(max).ok());
+        let max = config
+            .and_then(as_usize)
+            .or_else(|| {
+                config
+                    .and_then(|config| config.get("max").or_else(|| config.get("maximum")))
+                    .and_then(as_usize)
+            })
+            .unwrap_or(DEFAULT_MAX_COMPLEXITY);
+        let variant = match config.and_then(|config| config.get("variant")).and_then(Value::as_str)
+        {
+            Some("modified") => SwitchComplexity::Modified,
+            _ => SwitchComplexity::Classic,
+        };
+        Self(Box::new(ComplexityConfig { max, variant }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_function_node(node) {
+            return;
+        }
+        let complexity = cyclomatic_complexity(node.kind(), self.variant);
+        if complexity > self.max {
+            let mut name =
+                get_complexity_function_name(node, ctx.nodes().parent_node(node.id())).into_owned();
+            // Like ESLint, e.g. "Arrow function `foo`".
+            if matches!(node.kind(), AstKind::ArrowFunctionExpression(_)) {
+                name = name.cow_replacen("function", "arrow function", 1).into_owned();
+            }
+            ctx.diagnostic(complexity_diagnostic(&name, complexity, self.max, node.span()));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([2]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([2]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(json!([3])),
+        ),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([2]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([2]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(json!([3])),
+        ),
+        (
+            "function a(obj) {if(obj){ for(var x in obj) {try {x.getThis();} catch (e) {x.getThat();}}} else {return false;}}",
+            Some(json!([4])),
+        ),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(json!([2]))),
+        ("function a(x) {return x === 4 ? 3 : (x === 3 ? 2 : 1);}", Some(json!([3]))),
+        ("function a(x) {return x || 4;}", Some(json!([2]))),
+        ("function a(x) {x && 4;}", Some(json!([2]))),
+        ("function a(x) {x ?? 4;}", Some(json!([2]))),
+        ("function a(x) {x ||= 4;}", Some(json!([2]))),
+        ("function a(x) {x = 4;}", Some(json!([1]))),
+        ("function a(x) {x += 4;}", Some(json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(json!([3])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: if(x == 'foo') {5;};}}",
+            Some(json!([4])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([2]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([2]))),
+        ("if (foo) { bar(); }", Some(json!([0]))),
+        ("var a = (x) => {do {'foo';} while (true)}", Some(json!([2]))),
+        ("function foo() { function bar() { if (a) {} } }", Some(json!([2]))),
+        ("function foo(a = 1) {}", Some(json!([2]))),
+        ("function foo({ a = 1 }) {}", Some(json!([2]))),
+        ("function foo() { ({ a = 1 } = b); }", Some(json!([2]))),
+        ("function foo() { a?.b; }", Some(json!([2]))),
+        ("function foo() { a?.(); }", Some(json!([2]))),
+        ("class C { x = a || b; y = c || d; }", Some(json!([1]))),
+        ("class C { static { a || b; } }", Some(json!([1]))),
+        ("function foo() { class C { x = a || b; } }", Some(json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(json!([{ "max": 2, "variant": "modified" }])),
+        ),
+        ("function a(x) {}", Some(json!([{ "max": 1 }]))),
+        ("function a(x) {}", Some(json!([{ "maximum": 1 }]))),
+        ("function a(x) { if (a || b && c) {} }", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(json!([0]))),
+        ("var func = function () {}", Some(json!([0]))),
+        ("var obj = { a(x) {} }", Some(json!([0]))),
+        ("class Test { a(x) {} }", Some(json!([0]))),
+        ("var a = (x) => {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([1]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(json!([2])),
+        ),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([1]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([1]))),
+        ("function a(obj) {for(var i of obj) {obj[i] = 3;}}", Some(json!([1]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(json!([2])),
+        ),
+        ("function a(x) {try {x.getThis();} catch (e) {x.getThat();}}", Some(json!([1]))),
+        ("function a(x) {return x === 4 ? 3 : 5;}", Some(json!([1]))),
+        ("function a(x) {return x || 4;}", Some(json!([1]))),
+        ("function a(x) {x ??= 4;}", Some(json!([1]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(json!([2])),
+        ),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(json!([{ "max": 1, "variant": "modified" }])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([1]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([1]))),
+        (
+            "function a(x) {(function() {while(true){'foo';}})(); (function() {while(true){'bar';}})();}",
+            Some(json!([1])),
+        ),
+        ("function foo(a = 1) {}", Some(json!([1]))),
+        ("function foo() { a?.b?.c; }", Some(json!([2]))),
+        ("class C { static async *#method() { if (a) {} } }", Some(json!([1]))),
+        ("class C { method = () => { if (a) {} } }", Some(json!([1]))),
+        ("const g = async () => { if (a) {} }", Some(json!([1]))),
+        ("foo(() => { if (a) {} })", Some(json!([1]))),
+        ("class C { get x() { return a || b; } }", Some(json!([1]))),
+        (
+            "function foo(a) { if (a) {} if (b) {} if (c) {} if (d) {} if (e) {} if (f) {} if (g) {} if (h) {} if (i) {} if (j) {} if (k) {} if (l) {} if (m) {} if (n) {} if (o) {} if (p) {} if (q) {} if (r) {} if (s) {} if (t) {} }",
+            None,
+        ),
+    ];
+
+    Tester::new(Complexity::NAME, Complexity::PLUGIN, pass, fail).test_and_snapshot();
+}
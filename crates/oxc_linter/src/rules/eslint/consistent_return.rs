@@ -0,0 +1,301 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, MethodDefinitionKind, ReturnStatement, UnaryOperator},
+};
+use oxc_cfg::{EvalConstConditionResult, Instruction, InstructionKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::NodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{AstNode, ast_util::get_function_name_with_kind, context::LintContext, rule::Rule};
+
+fn missing_return_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected to return a value at the end of {name}."))
+        .with_help("Return a value from all code paths, or from none of them.")
+        .with_label(span)
+}
+
+fn missing_return_value_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{name} expected a return value."))
+        .with_help("Return a value from all code paths, or from none of them.")
+        .with_label(span)
+}
+
+fn unexpected_return_value_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{name} expected no return value."))
+        .with_help("Return a value from all code paths, or from none of them.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn {
+    treat_undefined_as_unspecified: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function that returns a value on some code paths but not on others is
+    /// confusing to call: the caller can't tell whether `undefined` is a
+    /// meaningful result or the sign of a missed case. It is often a bug.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return;
+    ///     }
+    /// }
+    ///
+    /// function doSomethingElse(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return false;
+    ///     }
+    /// }
+    ///
+    /// function Foo() {
+    ///     if (!(this instanceof Foo)) {
+    ///         return new Foo();
+    ///     }
+    ///     this.a = 0;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### treatUndefinedAsUnspecified
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// When `true`, `return undefined` and `return void 0` are treated like a
+    /// `return` without a value.
+    ConsistentReturn,
+    eslint,
+    suspicious
+);
+
+impl Rule for ConsistentReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let treat_undefined_as_unspecified = value
+            .get(0)
+            .and_then(|config| config.get("treatUndefinedAsUnspecified"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        Self { treat_undefined_as_unspecified }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut returns_by_function: FxHashMap<NodeId, Vec<&ReturnStatement>> =
+            FxHashMap::default();
+        for node in ctx.nodes() {
+            let AstKind::ReturnStatement(ret) = node.kind() else { continue };
+            let Some(function) = ctx.nodes().ancestors(node.id()).find(|node| {
+                matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+            }) else {
+                continue;
+            };
+            returns_by_function.entry(function.id()).or_default().push(ret);
+        }
+
+        let mut functions = returns_by_function.into_iter().collect::<Vec<_>>();
+        functions.sort_unstable_by_key(|(id, _)| *id);
+
+        for (function_id, returns) in functions {
+            let function = ctx.nodes().get_node(function_id);
+            let parent = ctx.nodes().parent_node(function_id);
+            let name = get_function_name_with_kind(function, parent);
+
+            let has_return_value = self.has_return_value(returns[0]);
+            for ret in &returns[1..] {
+                if self.has_return_value(ret) == has_return_value {
+                    continue;
+                }
+                let name = upper_case_first(&name);
+                ctx.diagnostic(if has_return_value {
+                    missing_return_value_diagnostic(&name, ret.span)
+                } else {
+                    unexpected_return_value_diagnostic(&name, ret.span)
+                });
+            }
+
+            if has_return_value
+                && !is_constructor(function, parent)
+                && ctx.cfg().can_fall_through(
+                    function.cfg_id(),
+                    |instruction| {
+                        matches!(
+                            instruction.kind,
+                            InstructionKind::Return(_) | InstructionKind::Throw
+                        )
+                    },
+                    |instruction| eval_const_condition(instruction, ctx),
+                )
+            {
+                ctx.diagnostic(missing_return_diagnostic(
+                    &name,
+                    function_head_span(function, parent, ctx),
+                ));
+            }
+        }
+    }
+}
+
+impl ConsistentReturn {
+    fn has_return_value(&self, ret: &ReturnStatement) -> bool {
+        let Some(argument) = &ret.argument else { return false };
+        if !self.treat_undefined_as_unspecified {
+            return true;
+        }
+        match argument.without_parentheses() {
+            Expression::Identifier(ident) => ident.name != "undefined",
+            Expression::UnaryExpression(unary) => unary.operator != UnaryOperator::Void,
+            _ => true,
+        }
+    }
+}
+
+/// Class constructors and ES5-style constructors (functions named in `PascalCase`) may return
+/// early with a value while otherwise falling through to initialize `this`.
+fn is_constructor(function: &AstNode, parent: &AstNode) -> bool {
+    match function.kind() {
+        AstKind::Function(func) => {
+            matches!(parent.kind(), AstKind::MethodDefinition(method) if method.kind == MethodDefinitionKind::Constructor)
+                || func.id.as_ref().is_some_and(|id| id.name.starts_with(char::is_uppercase))
+        }
+        _ => false,
+    }
+}
+
+fn eval_const_condition(instruction: &Instruction, ctx: &LintContext) -> EvalConstConditionResult {
+    match instruction {
+        Instruction { kind: InstructionKind::Condition, node_id: Some(id) } => {
+            match ctx.nodes().kind(*id) {
+                AstKind::BooleanLiteral(lit) => EvalConstConditionResult::Eval(lit.value),
+                _ => EvalConstConditionResult::Fail,
+            }
+        }
+        _ => EvalConstConditionResult::NotFound,
+    }
+}
+
+/// The span from the start of a function (or of the method it is the value of) to its
+/// parameters, or the `=>` of an arrow function.
+#[expect(clippy::cast_possible_truncation)]
+fn function_head_span(function: &AstNode, parent: &AstNode, ctx: &LintContext) -> Span {
+    match function.kind() {
+        AstKind::Function(func) => {
+            let start = match parent.kind() {
+                AstKind::MethodDefinition(_)
+                | AstKind::PropertyDefinition(_)
+                | AstKind::ObjectProperty(_) => parent.span().start,
+                _ => func.span.start,
+            };
+            Span::new(start, func.params.span.start)
+        }
+        AstKind::ArrowFunctionExpression(arrow) => {
+            let between = Span::new(arrow.params.span.end, arrow.body.span.start);
+            ctx.source_range(between)
+                .find("=>")
+                .map_or(arrow.span, |offset| Span::sized(between.start + offset as u32, 2))
+        }
+        _ => function.span(),
+    }
+}
+
+fn upper_case_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function foo() { if (true) return; }", None),
+        ("function foo() { if (true) return; else return; }", None),
+        ("function foo() { if (true) return true; else return false; }", None),
+        ("f(function() { return; })", None),
+        ("f(function() { if (true) return; })", None),
+        ("f(function() { if (true) return; else return; })", None),
+        ("f(function() { if (true) return true; else return false; })", None),
+        ("function foo() { function bar() { return true; } return; }", None),
+        ("function foo() { function bar() { return; } return false; }", None),
+        ("function Foo() { if (!(this instanceof Foo)) return new Foo(); }", None),
+        ("function foo() { if (true) return; else undefined; }", None),
+        (
+            "function foo() { if (true) return undefined; else return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return void 0; else return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return; else return undefined; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        ("var x = () => { return {}; };", None),
+        ("class Foo { constructor() { if (true) return foo; } }", None),
+        ("var Foo = class { constructor() { if (true) return foo; } }", None),
+        ("function foo() { while (true) { if (a) return 1; } }", None),
+        ("function foo() { for (;;) { if (a) return 1; } }", None),
+        ("function foo() { if (a) { return 1; } else { throw new Error(); } }", None),
+        ("function foo() { try { return a(); } catch { return 0; } }", None),
+        ("function foo() { switch (a) { case 1: return 1; default: return 2; } }", None),
+        ("var x = () => a ? 1 : undefined;", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) return true; else return; }", None),
+        ("var foo = () => { if (true) return true; else return; }", None),
+        ("function foo() { if (true) return; else return false; }", None),
+        ("f(function() { if (true) return true; else return; })", None),
+        ("f(function() { if (true) return; else return false; })", None),
+        ("f(a => { if (true) return; else return false; })", None),
+        (
+            "function foo() { if (true) return undefined; else return true; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return true; return void 0; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        ("function foo() { if (a) return true; }", None),
+        ("var foo = () => { if (a) return true; }", None),
+        ("var foo = function() { if (a) return true; }", None),
+        ("var obj = { foo() { if (a) return true; } }", None),
+        ("class A { foo() { if (a) return true; } }", None),
+        ("class A { static #foo() { if (a) return true; } }", None),
+        ("function foo() { if (a) return true; else if (b) return false; }", None),
+        ("function foo() { try { return a(); } catch {} }", None),
+        ("function foo() { while (a) { if (b) return 1; } }", None),
+        ("function foo() { switch (a) { case 1: return 1; } }", None),
+        ("function foo() { if (a) { return 1; } function bar() { return; } }", None),
+    ];
+
+    Tester::new(ConsistentReturn::NAME, ConsistentReturn::PLUGIN, pass, fail).test_and_snapshot();
+}
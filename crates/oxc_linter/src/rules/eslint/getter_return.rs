@@ -5,13 +5,7 @@ use oxc_ast::{
         PropertyKind, match_member_expression,
     },
 };
-use oxc_cfg::{
-    EdgeType, ErrorEdgeKind, InstructionKind, ReturnInstructionKind,
-    graph::{
-        Direction,
-        visit::{Control, DfsEvent, set_depth_first_search},
-    },
-};
+use oxc_cfg::{EvalConstConditionResult, InstructionKind, ReturnInstructionKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -212,7 +206,6 @@ impl GetterReturn {
 
         let cfg = ctx.cfg();
 
-        let graph = cfg.graph();
         let definitely_returns_in_all_codepaths = 'returns: {
             // The expression is the equivalent of return.
             // Therefore, if a function is an expression, it always returns its value.
@@ -235,74 +228,19 @@ impl GetterReturn {
                     }
                 }
             }
-            let output = set_depth_first_search(graph, Some(node.cfg_id()), |event| {
-                match event {
-                    // We only need to check paths that are normal or jump.
-                    DfsEvent::TreeEdge(a, b) => {
-                        let edges = graph.edges_connecting(a, b).collect::<Vec<_>>();
-                        if edges.iter().any(|e| {
-                            matches!(
-                                e.weight(),
-                                EdgeType::Normal
-                                    | EdgeType::Jump
-                                    | EdgeType::Error(ErrorEdgeKind::Explicit)
-                            )
-                        }) {
-                            Control::Continue
-                        } else {
-                            Control::Prune
-                        }
+            !cfg.can_fall_through(
+                node.cfg_id(),
+                |instruction| match instruction.kind {
+                    // Throws are classified as returning.
+                    InstructionKind::Throw => true,
+                    InstructionKind::Return(kind) => {
+                        self.allow_implicit
+                            || matches!(kind, ReturnInstructionKind::NotImplicitUndefined)
                     }
-                    DfsEvent::Discover(basic_block_id, _) => {
-                        let return_instruction =
-                            cfg.basic_block(basic_block_id).instructions().iter().find(|it| {
-                                match it.kind {
-                                    // Throws are classified as returning.
-                                    InstructionKind::Return(_) | InstructionKind::Throw => true,
-
-                                    // Ignore irrelevant elements.
-                                    InstructionKind::ImplicitReturn
-                                    | InstructionKind::Break(_)
-                                    | InstructionKind::Continue(_)
-                                    | InstructionKind::Iteration(_)
-                                    | InstructionKind::Unreachable
-                                    | InstructionKind::Condition
-                                    | InstructionKind::Statement => false,
-                                }
-                            });
-
-                        let does_return = return_instruction.is_some_and(|ret| {
-                            !matches!( ret.kind,
-                            InstructionKind::Return(ReturnInstructionKind::ImplicitUndefined)
-                                if !self.allow_implicit
-                            )
-                        });
-
-                        // Return true as the second argument to signify we should
-                        // continue walking this branch, as we haven't seen anything
-                        // that will signify to us that this path of the program will
-                        // definitely return or throw.
-                        if graph.edges_directed(basic_block_id, Direction::Outgoing).any(|e| {
-                            matches!(
-                                e.weight(),
-                                EdgeType::Jump
-                                    | EdgeType::Normal
-                                    | EdgeType::Backedge
-                                    | EdgeType::Error(ErrorEdgeKind::Explicit)
-                            )
-                        }) {
-                            Control::Continue
-                        } else if does_return {
-                            Control::Prune
-                        } else {
-                            Control::Break(())
-                        }
-                    }
-                    _ => Control::Continue,
-                }
-            });
-
-            output.break_value().is_none()
+                    _ => false,
+                },
+                |_| EvalConstConditionResult::NotFound,
+            )
         };
 
         if !definitely_returns_in_all_codepaths {
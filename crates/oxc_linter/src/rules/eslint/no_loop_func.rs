@@ -0,0 +1,333 @@
+use oxc_ast::{
+    AstKind,
+    ast::{VariableDeclarationKind, VariableDeclarator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::Idx;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, ast_util::iter_outer_expressions, context::LintContext, rule::Rule};
+
+fn no_loop_func_diagnostic(names: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Function declared in a loop contains unsafe references to variable(s) {names}."
+    ))
+    .with_help("Move the function out of the loop, or only capture variables that are not reassigned across iterations.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoopFunc;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow function declarations that contain unsafe references inside
+    /// loop statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function created inside a loop captures the variables of the
+    /// enclosing scope, not their values at the time of creation. When such a
+    /// variable is reassigned by later iterations, every function sees its
+    /// final value, which is rarely what was intended.
+    ///
+    /// Variables declared with `const`, and `let` variables declared inside
+    /// the loop, get a fresh binding for every iteration and are safe.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// for (var i = 10; i; i--) {
+    ///     (function() { return i; });
+    /// }
+    ///
+    /// let foo = 0;
+    /// for (let i = 0; i < 10; ++i) {
+    ///     // Bad, `foo` is not in the loop-block's scope and is modified by later iterations.
+    ///     setTimeout(() => console.log(foo));
+    ///     foo += 1;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// for (let i = 10; i; i--) {
+    ///     var a = function() { return i; };
+    ///     a();
+    /// }
+    ///
+    /// var foo = 100;
+    /// for (let i = 10; i; i--) {
+    ///     // OK, all references are referring to never modified variables.
+    ///     var a = function() { return foo; };
+    ///     a();
+    /// }
+    /// ```
+    NoLoopFunc,
+    eslint,
+    suspicious
+);
+
+impl Rule for NoLoopFunc {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let span = match node.kind() {
+            AstKind::Function(func) => func.span,
+            AstKind::ArrowFunctionExpression(arrow) => arrow.span,
+            _ => return,
+        };
+        let Some(loop_node) = containing_loop(node, ctx) else { return };
+        if is_skipped_iife(node, ctx) {
+            return;
+        }
+
+        let mut unsafe_names: Vec<&str> = vec![];
+        let scoping = ctx.scoping();
+        let descendants = ctx
+            .nodes()
+            .iter()
+            .skip(node.id().index() + 1)
+            .take_while(|descendant| span.contains_inclusive(descendant.span()));
+        for descendant in descendants {
+            let AstKind::IdentifierReference(ident) = descendant.kind() else { continue };
+            let reference = scoping.get_reference(ident.reference_id());
+            let Some(symbol_id) = reference.symbol_id() else { continue };
+            if reference.is_type() || span.contains_inclusive(scoping.symbol_span(symbol_id)) {
+                continue;
+            }
+            let name = scoping.symbol_name(symbol_id);
+            if !unsafe_names.contains(&name) && !is_safe(loop_node, symbol_id, ctx) {
+                unsafe_names.push(name);
+            }
+        }
+
+        if !unsafe_names.is_empty() {
+            let names =
+                unsafe_names.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>().join(", ");
+            ctx.diagnostic(no_loop_func_diagnostic(&names, span));
+        }
+    }
+}
+
+/// Returns the innermost loop the given node is executed in, without crossing function
+/// boundaries other than skipped IIFEs. The `init` of a `for` statement and the `right` of a
+/// `for-in`/`for-of` statement are outside of the loop.
+fn containing_loop<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let mut child_span = node.span();
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => return Some(ancestor),
+            AstKind::ForStatement(stmt)
+                if !stmt
+                    .init
+                    .as_ref()
+                    .is_some_and(|init| init.span().contains_inclusive(child_span)) =>
+            {
+                return Some(ancestor);
+            }
+            AstKind::ForInStatement(stmt) if !stmt.right.span().contains_inclusive(child_span) => {
+                return Some(ancestor);
+            }
+            AstKind::ForOfStatement(stmt) if !stmt.right.span().contains_inclusive(child_span) => {
+                return Some(ancestor);
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                if !is_skipped_iife(ancestor, ctx) =>
+            {
+                return None;
+            }
+            _ => {}
+        }
+        child_span = ancestor.span();
+    }
+    None
+}
+
+/// Immediately invoked functions run before the next iteration can change anything, unless they
+/// are async, generators, or reference themselves (and so may be called again later).
+fn is_skipped_iife(node: &AstNode, ctx: &LintContext) -> bool {
+    let (span, is_referenced) = match node.kind() {
+        AstKind::Function(func) if func.is_expression() && !func.r#async && !func.generator => (
+            func.span,
+            func.id.as_ref().is_some_and(|id| {
+                !ctx.scoping().get_resolved_reference_ids(id.symbol_id()).is_empty()
+            }),
+        ),
+        AstKind::ArrowFunctionExpression(arrow) if !arrow.r#async => (arrow.span, false),
+        _ => return false,
+    };
+    !is_referenced
+        && matches!(
+            iter_outer_expressions(ctx.semantic(), node.id()).next(),
+            Some(AstKind::CallExpression(call)) if call.callee.span().contains_inclusive(span)
+        )
+}
+
+/// A variable is safe to capture if it is `const`, if it is a `let` declared inside the loop (so
+/// each iteration gets its own binding), or if it is never written to once the loop has started.
+fn is_safe<'a>(loop_node: &AstNode<'a>, symbol_id: SymbolId, ctx: &LintContext<'a>) -> bool {
+    let scoping = ctx.scoping();
+    let declarator = match ctx.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+        AstKind::VariableDeclarator(declarator) => Some(declarator),
+        _ => None,
+    };
+    let declaration =
+        declarator.map(|_| ctx.nodes().parent_node(scoping.symbol_declaration(symbol_id)));
+
+    if let Some(declarator) = declarator {
+        match declarator.kind {
+            VariableDeclarationKind::Var => {}
+            VariableDeclarationKind::Let => {
+                let declaration_span = declaration.map_or(declarator.span, GetSpan::span);
+                let loop_span = loop_node.span();
+                if declaration_span.start > loop_span.start && declaration_span.end < loop_span.end
+                {
+                    return true;
+                }
+            }
+            _ => return true,
+        }
+    }
+
+    let excluded = declaration
+        .filter(|_| {
+            declarator.is_some_and(|declarator| declarator.kind == VariableDeclarationKind::Let)
+        })
+        .map(GetSpan::span);
+    let border = top_loop(loop_node, excluded, ctx).span().start;
+    let symbol_variable_scope = variable_scope(scoping.symbol_scope_id(symbol_id), ctx);
+
+    let is_safe_write = |node_id: NodeId, start: u32| {
+        symbol_variable_scope == variable_scope(ctx.nodes().get_node(node_id).scope_id(), ctx)
+            && start < border
+    };
+
+    // Initializers of `var` declarations are writes, too.
+    if let (Some(declarator), Some(declaration)) = (declarator, declaration) {
+        if has_initializer(declarator, declaration, ctx)
+            && !is_safe_write(declaration.id(), declarator.span.start)
+        {
+            return false;
+        }
+    }
+
+    scoping.get_resolved_references(symbol_id).all(|reference| {
+        !reference.is_write()
+            || is_safe_write(reference.node_id(), ctx.semantic().reference_span(reference).start)
+    })
+}
+
+fn has_initializer(
+    declarator: &VariableDeclarator,
+    declaration: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    declarator.init.is_some()
+        || matches!(
+            ctx.nodes().parent_kind(declaration.id()),
+            AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)
+        )
+}
+
+/// Returns the outermost loop around `loop_node` that starts after `excluded`.
+fn top_loop<'a, 'b>(
+    loop_node: &'b AstNode<'a>,
+    excluded: Option<Span>,
+    ctx: &'b LintContext<'a>,
+) -> &'b AstNode<'a> {
+    let border = excluded.map_or(0, |span| span.end);
+    let mut top = loop_node;
+    let mut current = Some(loop_node);
+    while let Some(node) = current {
+        if node.span().start < border {
+            break;
+        }
+        top = node;
+        current = containing_loop(node, ctx);
+    }
+    top
+}
+
+/// The closest function (or top-level) scope of the given scope.
+fn variable_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scope_id)
+        .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+        .unwrap_or_else(|| scoping.root_scope_id())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "string = 'function a() {}';",
+        "for (var i=0; i<l; i++) { } var a = function() { i; };",
+        "for (var i=0, a=function() { i; }; i<l; i++) { }",
+        "for (var x in xs.filter(function(x) { return x != upper; })) { }",
+        "for (var x of xs.filter(function(x) { return x != upper; })) { }",
+        "for (var i=0; i<l; i++) { (function() {}) }",
+        "for (var i in {}) { (function() {}) }",
+        "for (var i of {}) { (function() {}) }",
+        "for (let i=0; i<l; i++) { (function() { i; }) }",
+        "for (let i in {}) { i = 7; (function() { i; }) }",
+        "for (const i of {}) { (function() { i; }) }",
+        "for (let i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) { } }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "let a = 0; for (var i in {}) { (function() { a; }); }",
+        "let a = 0; for (var i of {}) { (function() { a; }); }",
+        "let a = 0; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); }",
+        "let a = 0; for (let i in {}) { function foo() { (function() { a; }); } }",
+        "let a = 0; for (let i of {}) { (() => { (function() { a; }); }); }",
+        "var a = 0; for (let i=0; i<l; i++) { (function() { a; }); }",
+        "var a = 0; for (let i in {}) { (function() { a; }); }",
+        "var a = 0; for (let i of {}) { (function() { a; }); }",
+        "let result = {}; for (const score in scores) { const letters = scores[score]; letters.split('').forEach(letter => { result[letter] = score; }); } result.__default = 6;",
+        "while (true) { (function() { a; }); }",
+        "let a; for (const i of b) { (() => a)(); }",
+        "for (var i = 0; i < 10; i++) { (function() { return i; })(); }",
+        "for (var i = 0; i < 10; i++) { (() => i)(); }",
+        "for (var i = 0; i < 10; i++) { (function() { (function() { return i; })(); })(); }",
+        "const foo = () => {}; for (var i = 0; i < 10; i++) { foo(() => 1); }",
+        "for (let i = 0; i < 10; i++) { function foo() { console.log('A'); } }",
+    ];
+
+    let fail = vec![
+        "for (var i=0; i<l; i++) { (function() { i; }) }",
+        "for (var i=0; i<l; i++) { for (var j=0; j<m; j++) { (function() { i+j; }) } }",
+        "for (var i in {}) { (function() { i; }) }",
+        "for (var i of {}) { (function() { i; }) }",
+        "for (var i=0; i < l; i++) { (() => { i; }) }",
+        "for (var i=0; i < l; i++) { var a = function() { i; } }",
+        "for (var i=0; i < l; i++) { function a() { i; }; a(); }",
+        "var i; while(i) { (function() { i; }); i--; }",
+        "var i; do { (function() { i; }); i--; } while (i)",
+        "let a; for (let i=0; i<l; i++) { a = 1; (function() { a; });}",
+        "let a; for (let i in {}) { (function() { a; }); a = 1; }",
+        "let a; for (let i of {}) { (function() { a; }); } a = 1;",
+        "let a; for (let i=0; i<l; i++) { (function() { (function() { a; }); }); a = 1; }",
+        "let a; for (let i in {}) { a = 1; function foo() { (function() { a; }); } }",
+        "let a; for (let i of {}) { (() => { (function() { a; }); }); } a = 1;",
+        "for (var i = 0; i < 10; ++i) { for (let x in xs.filter(x => x != i)) { } }",
+        "for (let x of xs) { let a; for (let y of ys) { a = 1; (function() { a; }); } }",
+        "for (var x of xs) { for (let y of ys) { (function() { x; }); } }",
+        "for (var x of xs) { (function() { x; }); }",
+        "var a; for (let x of xs) { a = 1; (function() { a; }); }",
+        "var a; for (let x of xs) { (function() { a; }); a = 1; }",
+        "let a; function foo() { a = 10; } for (let x of xs) { (function() { a; }); } foo();",
+        "let a; function foo() { a = 10; for (let x of xs) { (function() { a; }); } } foo();",
+        "let a; for (var i=0; i<l; i++) { (function* (){i;})() }",
+        "let a; for (var i=0; i<l; i++) { (async function (){i;})() }",
+        "for (var i = 0; i < 10; i++) { var x = i; (function() { return x; }) }",
+        "for (var i = 0; i < 10; i++) { (function foo() { return foo(i); })(); }",
+    ];
+
+    Tester::new(NoLoopFunc::NAME, NoLoopFunc::PLUGIN, pass, fail).test_and_snapshot();
+}
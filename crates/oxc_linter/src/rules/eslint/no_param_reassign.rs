@@ -0,0 +1,335 @@
+use lazy_regex::Regex;
+use oxc_ast::{AstKind, ast::UnaryOperator};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{ast_util::is_function_parameter, context::LintContext, rule::Rule};
+
+fn assignment_to_parameter_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Assignment to function parameter '{name}'."))
+        .with_help("Use a local variable instead of reassigning the parameter.")
+        .with_label(span)
+}
+
+fn assignment_to_parameter_property_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Assignment to property of function parameter '{name}'."))
+        .with_help("Copy the parameter before modifying its properties.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoParamReassign(Box<NoParamReassignConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoParamReassignConfig {
+    props: bool,
+    ignore_property_modifications_for: Vec<String>,
+    ignore_property_modifications_for_regex: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoParamReassign {
+    type Target = NoParamReassignConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows reassigning function parameters, and optionally modifying their properties.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assignment to variables declared as function parameters can be misleading and lead
+    /// to confusing behavior, as modifying function parameters will also mutate the
+    /// `arguments` object when not in strict mode. Modifying properties of a parameter
+    /// mutates the object passed in by the caller.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function foo(bar) {
+    ///     bar = 13;
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     bar++;
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     for (bar in baz) {}
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function foo(bar) {
+    ///     var baz = bar;
+    /// }
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "props": true }`:
+    /// ```js
+    /// function foo(bar) {
+    ///     bar.prop = "value";
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     delete bar.aaa;
+    /// }
+    ///
+    /// function foo(bar) {
+    ///     [bar.a] = [];
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### props
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also report modifications of parameter properties.
+    ///
+    /// #### ignorePropertyModificationsFor
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Parameter names whose properties may be modified when `props` is enabled.
+    ///
+    /// #### ignorePropertyModificationsForRegex
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Patterns matching parameter names whose properties may be modified when `props` is enabled.
+    NoParamReassign,
+    eslint,
+    restriction
+);
+
+impl Rule for NoParamReassign {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let strings = |key: &str| {
+            config
+                .get(key)
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        };
+        Self(Box::new(NoParamReassignConfig {
+            props: config.get("props").and_then(Value::as_bool).unwrap_or(false),
+            ignore_property_modifications_for: strings("ignorePropertyModificationsFor")
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            ignore_property_modifications_for_regex: strings("ignorePropertyModificationsForRegex")
+                .into_iter()
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect(),
+        }))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
+        if !is_function_parameter(symbol_id, ctx.semantic()) {
+            return;
+        }
+        let scoping = ctx.scoping();
+        let name = scoping.symbol_name(symbol_id);
+        let check_props = self.props && !self.is_ignored_property_assignment(name);
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let span = ctx.semantic().reference_span(reference);
+            if reference.is_write() {
+                ctx.diagnostic(assignment_to_parameter_diagnostic(name, span));
+            } else if check_props && is_modifying_property(reference.node_id(), ctx) {
+                ctx.diagnostic(assignment_to_parameter_property_diagnostic(name, span));
+            }
+        }
+    }
+}
+
+impl NoParamReassign {
+    fn is_ignored_property_assignment(&self, name: &str) -> bool {
+        self.ignore_property_modifications_for.iter().any(|ignored| ignored == name)
+            || self.ignore_property_modifications_for_regex.iter().any(|re| re.is_match(name))
+    }
+}
+
+/// Whether the identifier is the object of a member expression that gets modified,
+/// e.g. `a.b = 1`, `a.b++`, `delete a.b` or `[a.b] = []`.
+fn is_modifying_property(node_id: NodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let mut child = nodes.get_node(node_id);
+    for parent in nodes.ancestors(node_id) {
+        let kind = parent.kind();
+        let is_loop_head = matches!(kind, AstKind::ForInStatement(_) | AstKind::ForOfStatement(_));
+        if !is_loop_head
+            && (kind.is_statement()
+                || kind.is_declaration()
+                || kind.is_function_like()
+                || matches!(kind, AstKind::Program(_) | AstKind::FunctionBody(_)))
+        {
+            return false;
+        }
+        let child_span = child.kind().span();
+        match kind {
+            AstKind::AssignmentExpression(assignment) => {
+                return assignment.left.span().contains_inclusive(child_span);
+            }
+            AstKind::UpdateExpression(_) => return true,
+            AstKind::UnaryExpression(unary) if unary.operator == UnaryOperator::Delete => {
+                return true;
+            }
+            AstKind::ForInStatement(stmt) => {
+                return stmt.left.span().contains_inclusive(child_span);
+            }
+            AstKind::ForOfStatement(stmt) => {
+                return stmt.left.span().contains_inclusive(child_span);
+            }
+            AstKind::CallExpression(call) if call.callee.span() != child_span => return false,
+            AstKind::ComputedMemberExpression(member) if member.expression.span() == child_span => {
+                return false;
+            }
+            AstKind::ObjectProperty(property) if property.key.span() == child_span => return false,
+            AstKind::AssignmentTargetPropertyProperty(property)
+                if property.name.span().contains_inclusive(child_span) =>
+            {
+                return false;
+            }
+            AstKind::ConditionalExpression(conditional)
+                if conditional.test.span() == child_span =>
+            {
+                return false;
+            }
+            _ => {}
+        }
+        child = parent;
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo(a) { var b = a; }", None),
+        ("function foo(a) { for (b in a); }", None),
+        ("function foo(a) { for (b of a); }", None),
+        ("function foo(a) { a.prop = 'value'; }", None),
+        ("function foo(a) { for (a.prop in obj); }", None),
+        ("function foo(a) { for (a.prop of arr); }", None),
+        ("function foo(a) { (function() { var a = 12; a++; })(); }", None),
+        ("function foo() { someGlobal = 13; }", None),
+        ("function foo() { someGlobal = 13; someGlobal = 13; }", None),
+        ("function foo(a) { a.b = 0; }", None),
+        ("function foo(a) { delete a.b; }", None),
+        ("function foo(a) { ++a.b; }", None),
+        ("function foo(a) { [a.b] = []; }", None),
+        ("function foo(a) { bar(a.b).c = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { data[a.b] = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { +a.b; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { (a ? [] : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { (a.b ? [] : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        (
+            "function foo(a) { a.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a) { ++a.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a) { delete a.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a, z) { a.b = 0; x.y = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a", "x"] }])),
+        ),
+        (
+            "function foo(a) { a.b.c = 0;}",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(aFoo) { aFoo.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        (
+            "function foo(aFoo) { ++aFoo.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        (
+            "function foo(aFoo) { [aFoo.b] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        ("function foo(a) { ({ [a]: variable } = value) }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ([...a.b] = obj); }", Some(json!([{ "props": false }]))),
+        ("function foo(a) { ({...a.b} = obj); }", Some(json!([{ "props": false }]))),
+        ("function foo(a) { for (obj[a.b] in obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for (obj[a.b] of arr); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for (bar in a.b); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for (bar of a.b); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a.b(); }", Some(json!([{ "props": true }]))),
+        ("function foo(a = 1) { return a; }", None),
+        ("function foo(a) { try {} catch (a) { a = 1; } }", None),
+    ];
+
+    let fail = vec![
+        ("function foo(bar) { bar = 13; }", None),
+        ("function foo(bar) { bar += 13; }", None),
+        ("function foo(bar) { (function() { bar = 13; })(); }", None),
+        ("function foo(bar) { ++bar; }", None),
+        ("function foo(bar) { bar++; }", None),
+        ("function foo(bar) { --bar; }", None),
+        ("function foo(bar) { bar--; }", None),
+        ("function foo({bar}) { bar = 13; }", None),
+        ("function foo([, {bar}]) { bar = 13; }", None),
+        ("function foo(bar) { ({bar} = {}); }", None),
+        ("function foo(bar) { ({x: [, bar = 0]} = {}); }", None),
+        ("function foo(bar) { for (bar in baz); }", None),
+        ("function foo(bar) { for (bar of baz); }", None),
+        ("function foo(...bar) { bar = []; }", None),
+        ("const foo = (bar) => { bar = 1; };", None),
+        ("class A { m(bar) { bar = 1; } }", None),
+        ("function foo(bar) { bar.a = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { bar.get(0).a = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { delete bar.a; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { ++bar.a; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { for (bar.a in {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { for (bar.a of []); }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { (bar ? bar : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { [bar.a] = []; }", Some(json!([{ "props": true }]))),
+        (
+            "function foo(bar) { [bar.a] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(bar) { [bar.a] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        ("function foo(bar) { ({foo: bar.a} = {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ({a} = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ([...a.b] = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ({...a.b} = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for ({bar: a.b} in {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for ([a.b] of []); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a &&= b; }", None),
+        ("function foo(a) { a ||= b; }", None),
+        ("function foo(a) { a ??= b; }", None),
+        ("function foo(a) { a.b &&= c; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a.b.c ||= d; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a[b] ??= c; }", Some(json!([{ "props": true }]))),
+    ];
+
+    Tester::new(NoParamReassign::NAME, NoParamReassign::PLUGIN, pass, fail).test_and_snapshot();
+}
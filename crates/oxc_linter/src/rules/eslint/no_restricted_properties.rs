@@ -0,0 +1,425 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentTarget, AssignmentTargetProperty, Expression, ObjectAssignmentTarget,
+        ObjectPattern,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::number::ToJsString;
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_restricted_properties_diagnostic(
+    object: Option<&str>,
+    property: &str,
+    message: Option<&str>,
+    span: Span,
+) -> OxcDiagnostic {
+    let name = match object {
+        Some(object) => format!("{object}.{property}"),
+        None => property.to_string(),
+    };
+    let message = message.map(|message| format!(" {message}")).unwrap_or_default();
+    OxcDiagnostic::warn(format!("'{name}' is restricted from being used.{message}"))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedProperties(Box<NoRestrictedPropertiesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPropertiesConfig {
+    restrictions: Vec<RestrictedProperty>,
+}
+
+impl std::ops::Deref for NoRestrictedProperties {
+    type Target = NoRestrictedPropertiesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedProperty {
+    object: Option<String>,
+    property: Option<String>,
+    message: Option<String>,
+    allow_objects: Vec<String>,
+    allow_properties: Vec<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows certain properties on certain objects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Certain properties on objects may be disallowed in a codebase. This is useful for
+    /// deprecating an API or restricting usage of a module's methods. For example, you may
+    /// want to disallow using `describe.only` when using Mocha, or telling people to use
+    /// `Object.assign` instead of `_.extend`.
+    ///
+    /// ### Options
+    ///
+    /// An array of objects, each with an `object` and/or `property` name and an optional `message`.
+    /// If only `object` is given, all of its properties are restricted, except those in
+    /// `allowProperties`. If only `property` is given, it is restricted on all objects, except
+    /// those in `allowObjects`.
+    ///
+    /// ```json
+    /// "no-restricted-properties": ["error", {
+    ///     "object": "disallowedObjectName",
+    ///     "property": "disallowedPropertyName",
+    ///     "message": "Please use allowedObjectName.allowedPropertyName."
+    /// }]
+    /// ```
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the
+    /// `[{ "object": "disallowedObjectName", "property": "disallowedPropertyName" }]` option:
+    /// ```js
+    /// const example = disallowedObjectName.disallowedPropertyName;
+    /// disallowedObjectName.disallowedPropertyName();
+    /// const { disallowedPropertyName } = disallowedObjectName;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same option:
+    /// ```js
+    /// const example = disallowedObjectName.somePropertyName;
+    /// allowedObjectName.disallowedPropertyName();
+    /// ```
+    NoRestrictedProperties,
+    eslint,
+    restriction,
+);
+
+impl TryFrom<Value> for NoRestrictedPropertiesConfig {
+    type Error = OxcDiagnostic;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let strings = |value: Option<&Value>| -> Vec<String> {
+            value
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect()
+        };
+        let mut restrictions = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|item| {
+                let object = item.get("object").and_then(Value::as_str);
+                let property = item.get("property").and_then(Value::as_str);
+                if object.is_none() && property.is_none() {
+                    return Err(OxcDiagnostic::warn(
+                        "Expecting `object` or `property` in eslint/no-restricted-properties configuration",
+                    ));
+                }
+                Ok(RestrictedProperty {
+                    object: object.map(String::from),
+                    property: property.map(String::from),
+                    message: item.get("message").and_then(Value::as_str).map(String::from),
+                    allow_objects: strings(item.get("allowObjects")),
+                    allow_properties: strings(item.get("allowProperties")),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Restrictions on a specific object take precedence over global property restrictions.
+        restrictions.sort_by_key(|restriction| restriction.object.is_none());
+        Ok(Self { restrictions })
+    }
+}
+
+impl Rule for NoRestrictedProperties {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(NoRestrictedPropertiesConfig::try_from(value).unwrap()))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::StaticMemberExpression(member) => {
+                let property = Cow::Borrowed(member.property.name.as_str());
+                self.check(
+                    member.object.get_identifier_reference().map(|ident| ident.name.as_str()),
+                    &property,
+                    member.span,
+                    ctx,
+                );
+            }
+            AstKind::ComputedMemberExpression(member) => {
+                let Some(property) = computed_property_name(&member.expression) else { return };
+                self.check(
+                    member.object.get_identifier_reference().map(|ident| ident.name.as_str()),
+                    &property,
+                    member.span,
+                    ctx,
+                );
+            }
+            AstKind::ObjectPattern(pattern) => {
+                // `const { a } = object` or `function f({ a } = object) {}`
+                let object = match ctx.nodes().parent_kind(node.id()) {
+                    AstKind::VariableDeclarator(declarator) => declarator.init.as_ref(),
+                    AstKind::AssignmentPattern(assignment) => Some(&assignment.right),
+                    _ => None,
+                };
+                let Some(Expression::Identifier(object)) = object else { return };
+                self.check_object_pattern(&object.name, pattern, ctx);
+            }
+            AstKind::AssignmentExpression(assignment) => {
+                // `({ a } = object)`
+                let (
+                    AssignmentTarget::ObjectAssignmentTarget(target),
+                    Expression::Identifier(object),
+                ) = (&assignment.left, &assignment.right)
+                else {
+                    return;
+                };
+                self.check_object_assignment_target(&object.name, target, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoRestrictedProperties {
+    fn check_object_pattern(&self, object: &str, pattern: &ObjectPattern, ctx: &LintContext) {
+        for property in &pattern.properties {
+            if let Some(name) = property.key.static_name() {
+                self.check(Some(object), &name, property.span, ctx);
+            }
+        }
+    }
+
+    fn check_object_assignment_target(
+        &self,
+        object: &str,
+        target: &ObjectAssignmentTarget,
+        ctx: &LintContext,
+    ) {
+        for property in &target.properties {
+            let name = match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                    Some(Cow::Borrowed(ident.binding.name.as_str()))
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    property.name.static_name()
+                }
+            };
+            if let Some(name) = name {
+                self.check(Some(object), &name, property.span(), ctx);
+            }
+        }
+    }
+
+    /// Report the first restriction on `object.property`, where `object` is the name of the
+    /// accessed identifier, if any.
+    fn check(&self, object: Option<&str>, property: &str, span: Span, ctx: &LintContext) {
+        let Some(restriction) = self.restrictions.iter().find(|restriction| {
+            restriction.object.as_ref().is_none_or(|restricted| Some(restricted.as_str()) == object)
+                && restriction.property.as_ref().is_none_or(|restricted| restricted == property)
+                && !restriction.allow_properties.iter().any(|allowed| allowed == property)
+                && !restriction.allow_objects.iter().any(|allowed| Some(allowed.as_str()) == object)
+        }) else {
+            return;
+        };
+        ctx.diagnostic(no_restricted_properties_diagnostic(
+            restriction.object.as_deref(),
+            restriction.property.as_deref().unwrap_or(property),
+            restriction.message.as_deref(),
+            span,
+        ));
+    }
+}
+
+/// Name of the property accessed by `object[expression]`, if it is a literal.
+fn computed_property_name<'a>(expression: &Expression<'a>) -> Option<Cow<'a, str>> {
+    match expression.get_inner_expression() {
+        Expression::StringLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+        Expression::NumericLiteral(lit) => Some(Cow::Owned(lit.value.to_js_string())),
+        Expression::BigIntLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+        Expression::NullLiteral(_) => Some(Cow::Borrowed("null")),
+        Expression::RegExpLiteral(lit) => lit.raw.map(|raw| Cow::Borrowed(raw.as_str())),
+        Expression::TemplateLiteral(lit) => {
+            lit.single_quasi().map(|quasi| Cow::Borrowed(quasi.as_str()))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test_invalid() {
+    use serde_json::json;
+
+    let invalid_options = vec![
+        json!([{}]),
+        json!([{ "message": "Restricted." }]),
+        json!([{ "object": "foo" }, { "allowObjects": ["bar"] }]),
+    ];
+    for options in invalid_options {
+        assert!(NoRestrictedPropertiesConfig::try_from(options).is_err());
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "someObject.someProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "someObject.someProperty()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "anotherObject.disallowedProperty()",
+            Some(
+                json!([{ "object": "someObject", "property": "disallowedProperty", "message": "Please use someObject.allowedProperty instead." }]),
+            ),
+        ),
+        (
+            "anotherObject['disallowedProperty']()",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        ("obj.toString", Some(json!([{ "object": "obj", "property": "__proto__" }]))),
+        ("toString.toString", Some(json!([{ "object": "obj", "property": "foo" }]))),
+        ("obj.toString", Some(json!([{ "object": "obj", "property": "foo" }]))),
+        ("foo.bar", Some(json!([{ "property": "baz" }]))),
+        ("foo.bar", Some(json!([{ "object": "baz" }]))),
+        ("foo()", Some(json!([{ "object": "foo" }]))),
+        ("foo;", Some(json!([{ "object": "foo" }]))),
+        ("foo[/(?<zero>0)/]", Some(json!([{ "property": "null" }]))),
+        ("let bar = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {baz: bar} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {unrelated} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {baz: {bar: qux}} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar} = foo.baz;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {baz: bar} = foo;", Some(json!([{ "property": "bar" }]))),
+        ("let baz; ({baz: bar} = foo)", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let bar;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let bar; ([bar = 5] = foo);", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        (
+            "function qux({baz: bar} = foo) {}",
+            Some(json!([{ "object": "foo", "property": "bar" }])),
+        ),
+        ("let [bar, baz] = foo;", Some(json!([{ "object": "foo", "property": "1" }]))),
+        (
+            "someObject.disallowedProperty",
+            Some(json!([{ "property": "disallowedProperty", "allowObjects": ["someObject"] }])),
+        ),
+        (
+            "someObject.disallowedProperty; anotherObject.disallowedProperty()",
+            Some(
+                json!([{ "property": "disallowedProperty", "allowObjects": ["someObject", "anotherObject"] }]),
+            ),
+        ),
+        (
+            "someObject.allowedProperty",
+            Some(json!([{ "object": "someObject", "allowProperties": ["allowedProperty"] }])),
+        ),
+        (
+            "const { allowedProperty } = someObject;",
+            Some(json!([{ "object": "someObject", "allowProperties": ["allowedProperty"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "someObject.disallowedProperty",
+            Some(json!([{ "object": "someObject", "property": "disallowedProperty" }])),
+        ),
+        (
+            "someObject.disallowedProperty",
+            Some(
+                json!([{ "object": "someObject", "property": "disallowedProperty", "message": "Please use someObject.allowedProperty instead." }]),
+            ),
+        ),
+        (
+            "someObject.disallowedProperty; anotherObject.anotherDisallowedProperty()",
+            Some(
+                json!([{ "object": "someObject", "property": "disallowedProperty" }, { "object": "anotherObject", "property": "anotherDisallowedProperty" }]),
+            ),
+        ),
+        (
+            "foo.__proto__",
+            Some(
+                json!([{ "property": "__proto__", "message": "Please use Object.getPrototypeOf instead." }]),
+            ),
+        ),
+        (
+            "foo['__proto__']",
+            Some(
+                json!([{ "property": "__proto__", "message": "Please use Object.getPrototypeOf instead." }]),
+            ),
+        ),
+        ("foo.bar.baz;", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar();", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar.baz();", Some(json!([{ "object": "foo" }]))),
+        ("foo.bar.baz;", Some(json!([{ "property": "bar" }]))),
+        ("foo.bar();", Some(json!([{ "property": "bar" }]))),
+        ("foo.bar.baz();", Some(json!([{ "property": "bar" }]))),
+        ("foo[/(?<zero>0)/]", Some(json!([{ "property": "/(?<zero>0)/" }]))),
+        (
+            "require.call({}, 'foo')",
+            Some(json!([{ "object": "require", "message": "Please call require() directly." }])),
+        ),
+        ("require['resolve']", Some(json!([{ "object": "require" }]))),
+        ("let {bar} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar: baz} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {'bar': baz} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar: {baz: qux}} = foo;", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let {bar} = foo;", Some(json!([{ "object": "foo" }]))),
+        ("let {bar: baz} = foo;", Some(json!([{ "object": "foo" }]))),
+        ("let {bar} = foo;", Some(json!([{ "property": "bar" }]))),
+        ("let bar; ({bar} = foo);", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("let bar; ({bar: baz = 1} = foo);", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        ("function qux({bar} = foo) {}", Some(json!([{ "object": "foo", "property": "bar" }]))),
+        (
+            "function qux({bar: baz} = foo) {}",
+            Some(json!([{ "object": "foo", "property": "bar" }])),
+        ),
+        ("var {['foo']: qux, bar} = baz", Some(json!([{ "object": "baz", "property": "foo" }]))),
+        ("obj['toString']", Some(json!([{ "object": "obj", "property": "toString" }]))),
+        ("obj.toString", Some(json!([{ "property": "toString" }]))),
+        ("obj.toString()", Some(json!([{ "property": "toString" }]))),
+        (
+            "someObject.disallowedProperty",
+            Some(json!([{ "property": "disallowedProperty", "allowObjects": ["anotherObject"] }])),
+        ),
+        (
+            "someObject.disallowedProperty",
+            Some(json!([{ "object": "someObject", "allowProperties": ["allowedProperty"] }])),
+        ),
+        (
+            "const { disallowedProperty } = someObject;",
+            Some(json!([{ "object": "someObject", "allowProperties": ["allowedProperty"] }])),
+        ),
+        ("foo.bar", Some(json!([{ "object": "foo", "property": "bar" }, { "property": "bar" }]))),
+    ];
+
+    Tester::new(NoRestrictedProperties::NAME, NoRestrictedProperties::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
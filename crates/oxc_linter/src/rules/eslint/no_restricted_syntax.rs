@@ -0,0 +1,220 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::Selector};
+
+fn no_restricted_syntax_diagnostic(message: String, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+impl std::ops::Deref for NoRestrictedSyntax {
+    type Target = NoRestrictedSyntaxConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    /// Selector as written in the configuration, used in the default message.
+    source: String,
+    selector: Selector,
+    message: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching any of the configured
+    /// [selectors](https://eslint.org/docs/latest/extend/selectors).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them.
+    /// This rule can be used to ban specific language features, or usages of a library,
+    /// which aren't covered by other rules.
+    ///
+    /// Selectors are matched against the [ESTree](https://github.com/estree/estree) AST,
+    /// using the same syntax as [esquery](https://github.com/estools/esquery).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with the
+    /// `["error", "WithStatement", { "selector": "CallExpression[callee.object.name='moment']", "message": "Use Temporal instead." }]`
+    /// option:
+    /// ```js
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// const now = moment.utc();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with the same option:
+    /// ```js
+    /// me.dontMess();
+    ///
+    /// const now = Temporal.Now.instant();
+    /// ```
+    NoRestrictedSyntax,
+    eslint,
+    restriction,
+);
+
+impl TryFrom<Value> for NoRestrictedSyntaxConfig {
+    type Error = OxcDiagnostic;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let restrictions = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|item| {
+                // "no-restricted-syntax": ["error", "WithStatement"]
+                // "no-restricted-syntax": ["error", { "selector": "WithStatement", "message": "..." }]
+                let (source, message) = match item {
+                    Value::String(selector) => (selector.as_str(), None),
+                    Value::Object(obj) => (
+                        obj.get("selector").and_then(Value::as_str)?,
+                        obj.get("message").and_then(Value::as_str).map(String::from),
+                    ),
+                    _ => return None,
+                };
+                Some(
+                    Selector::parse(source)
+                        .map(|selector| RestrictedSyntax {
+                            source: source.to_string(),
+                            selector,
+                            message,
+                        })
+                        .map_err(|error| {
+                            OxcDiagnostic::warn(format!(
+                                "Invalid selector '{source}' in eslint/no-restricted-syntax configuration: {error}"
+                            ))
+                        }),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { restrictions })
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        Self(Box::new(NoRestrictedSyntaxConfig::try_from(value).unwrap()))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restriction in &self.restrictions {
+            if restriction.selector.matches(node, ctx.nodes()) {
+                let message = restriction
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("Using '{}' is not allowed.", restriction.source));
+                ctx.diagnostic(no_restricted_syntax_diagnostic(message, node.span()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_invalid() {
+    use serde_json::json;
+
+    let invalid_options = vec![
+        json!(["CallExpression["]),
+        json!([{ "selector": ":nth-child(foo)" }]),
+        json!(["WithStatement", "Identifier[name="]),
+    ];
+    for options in invalid_options {
+        assert!(NoRestrictedSyntaxConfig::try_from(options).is_err());
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }]))),
+        ("({ foo: 1, bar: 2 })", Some(json!([{ "selector": "Property > Literal.key" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        ("moment.utc()", Some(json!(["CallExpression[callee.name='moment']"]))),
+        ("a?.b", Some(json!(["MemberExpression[optional!=true]"]))),
+        (
+            "foo(bar)",
+            Some(json!([":not(CallExpression, Identifier, ExpressionStatement, Program)"])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{
+                "selector": "FunctionDeclaration[params.length>2]",
+                "message": "custom error message."
+            }])),
+        ),
+        (
+            "moment(); moment.utc();",
+            Some(json!([{
+                "selector": "CallExpression[callee.object.name='moment']",
+                "message": "Use Temporal instead."
+            }])),
+        ),
+        ("a?.b", Some(json!(["MemberExpression[optional=true]"]))),
+        ("a?.b", Some(json!(["ChainExpression"]))),
+        ("const x = y ? 1 : 2;", Some(json!(["VariableDeclarator:has(ConditionalExpression)"]))),
+        ("if (a) { b(); c(); }", Some(json!(["BlockStatement > :nth-child(2)"]))),
+        (
+            "foo?.bar();",
+            Some(json!([":matches(CallExpression, ChainExpression) > *:not(Identifier)"])),
+        ),
+        ("const x = /^[a-z]+$/;", Some(json!(["Literal[regex.pattern=/a-z/]"]))),
+        ("function f() { g(); } const h = () => g();", Some(json!([":function CallExpression"]))),
+        ("eval('x')", Some(json!(["CallExpression[callee.name=/^ev/i][arguments.0.value='x']"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
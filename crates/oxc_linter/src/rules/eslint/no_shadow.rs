@@ -0,0 +1,426 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_shadow_diagnostic(name: &str, span: Span, shadowed_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already declared in the upper scope.")).with_labels([
+        span.label(format!("'{name}' is declared here")),
+        shadowed_span.label(format!("'{name}' is first declared here")),
+    ])
+}
+
+fn no_shadow_global_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is already a global variable.")).with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Hoist {
+    /// Report shadowing before the outer functions are defined.
+    #[default]
+    Functions,
+    /// Report shadowing before the outer variables and functions are defined.
+    All,
+    /// Never report shadowing before the outer variables are defined.
+    Never,
+    /// Report shadowing before the outer types are defined.
+    Types,
+    /// Report shadowing before the outer functions and types are defined.
+    FunctionsAndTypes,
+}
+
+#[derive(Debug, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoShadowConfig {
+    builtin_globals: bool,
+    hoist: Hoist,
+    allow: Vec<String>,
+    ignore_on_initialization: bool,
+    ignore_type_value_shadow: bool,
+}
+
+impl Default for NoShadow {
+    fn default() -> Self {
+        Self(Box::new(NoShadowConfig {
+            builtin_globals: false,
+            hoist: Hoist::default(),
+            allow: vec![],
+            ignore_on_initialization: false,
+            ignore_type_value_shadow: true,
+        }))
+    }
+}
+
+impl std::ops::Deref for NoShadow {
+    type Target = NoShadowConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows variable declarations from shadowing variables declared in the outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shadowing is the process by which a local variable shares the same name as a variable
+    /// in its containing scope. It makes it impossible to access the outer variable, and can
+    /// cause confusion about which variable a name refers to.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    ///
+    /// if (true) {
+    ///     let a = 5;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var a = 3;
+    /// function b() {
+    ///     var c = 10;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### builtinGlobals
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also report shadowing of global variables, e.g. `function f() { var Object = 0; }`.
+    ///
+    /// #### hoist
+    ///
+    /// `{ type: "functions" | "all" | "never" | "types" | "functions-and-types", default: "functions" }`
+    ///
+    /// Whether to report shadowing before the outer declaration is defined.
+    /// With `"functions"`, only shadowing of function declarations is reported,
+    /// with `"all"` everything is, and with `"never"` nothing is.
+    /// `"types"` and `"functions-and-types"` apply the same to type aliases and interfaces.
+    ///
+    /// #### allow
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Names that are allowed to shadow.
+    ///
+    /// #### ignoreOnInitialization
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignore shadowing in callbacks within the initializer of the shadowed variable,
+    /// e.g. `const x = foo(x => x)`, where the outer variable is not initialized yet.
+    ///
+    /// #### ignoreTypeValueShadow
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Ignore types shadowing values and values shadowing types,
+    /// e.g. `const foo = 1; function f<foo>() {}`.
+    NoShadow,
+    eslint,
+    suspicious
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let default = Self::default();
+        let bool_option =
+            |key: &str, default: bool| config.get(key).and_then(Value::as_bool).unwrap_or(default);
+        let hoist = match config.get("hoist").and_then(Value::as_str) {
+            Some("all") => Hoist::All,
+            Some("never") => Hoist::Never,
+            Some("types") => Hoist::Types,
+            Some("functions-and-types") => Hoist::FunctionsAndTypes,
+            _ => Hoist::Functions,
+        };
+        Self(Box::new(NoShadowConfig {
+            builtin_globals: bool_option("builtinGlobals", default.builtin_globals),
+            hoist,
+            allow: config
+                .get("allow")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect(),
+            ignore_on_initialization: bool_option(
+                "ignoreOnInitialization",
+                default.ignore_on_initialization,
+            ),
+            ignore_type_value_shadow: bool_option(
+                "ignoreTypeValueShadow",
+                default.ignore_type_value_shadow,
+            ),
+        }))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
+        let scoping = ctx.scoping();
+        let flags = scoping.symbol_flags(symbol_id);
+        if flags.is_enum_member() {
+            return;
+        }
+        let name = scoping.symbol_name(symbol_id);
+        if self.allow.iter().any(|allowed| allowed == name) {
+            return;
+        }
+        let span = scoping.symbol_span(symbol_id);
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+
+        let Some(parent_scope_id) = scoping.scope_parent_id(scope_id) else {
+            // Top-level declarations of scripts are globals themselves.
+            if self.builtin_globals
+                && ctx.source_type().is_module()
+                && (ctx.env_contains_var(name) || ctx.globals().is_enabled(name))
+            {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+
+        let Some(shadowed_id) = scoping.find_binding(parent_scope_id, name) else {
+            if self.builtin_globals
+                && (ctx.env_contains_var(name) || ctx.globals().is_enabled(name))
+            {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+
+        let shadowed_flags = scoping.symbol_flags(shadowed_id);
+        if self.ignore_type_value_shadow && is_type_value_shadow(flags, shadowed_flags) {
+            return;
+        }
+        if is_on_initializer(symbol_id, shadowed_id, ctx) {
+            return;
+        }
+        if self.ignore_on_initialization && is_in_initializer_callback(symbol_id, shadowed_id, ctx)
+        {
+            return;
+        }
+        if self.is_in_tdz(symbol_id, shadowed_id, ctx) {
+            return;
+        }
+        ctx.diagnostic(no_shadow_diagnostic(name, span, scoping.symbol_span(shadowed_id)));
+    }
+}
+
+impl NoShadow {
+    /// Whether the variable is declared before the shadowed one, and shadowing
+    /// that kind of declaration before it is defined is allowed by `hoist`.
+    fn is_in_tdz(&self, symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+        let scoping = ctx.scoping();
+        if scoping.symbol_span(symbol_id).end >= scoping.symbol_span(shadowed_id).start {
+            return false;
+        }
+        let shadowed_flags = scoping.symbol_flags(shadowed_id);
+        let is_function = shadowed_flags.is_function();
+        let is_type = shadowed_flags.intersects(SymbolFlags::TypeAlias | SymbolFlags::Interface);
+        match self.hoist {
+            Hoist::All => false,
+            Hoist::Never => true,
+            Hoist::Functions => !is_function,
+            Hoist::Types => !is_type,
+            Hoist::FunctionsAndTypes => !is_function && !is_type,
+        }
+    }
+}
+
+fn is_type_only(flags: SymbolFlags) -> bool {
+    flags.is_type() && !flags.is_value()
+}
+
+/// Imports may be either values or types.
+fn is_value_only(flags: SymbolFlags) -> bool {
+    flags.is_value() && !flags.is_type() && !flags.is_import()
+}
+
+fn is_type_value_shadow(flags: SymbolFlags, shadowed_flags: SymbolFlags) -> bool {
+    (is_type_only(flags) && is_value_only(shadowed_flags))
+        || (is_value_only(flags) && is_type_only(shadowed_flags))
+}
+
+/// The `VariableDeclarator` declaring the symbol, if any.
+fn get_declarator_id(symbol_id: SymbolId, ctx: &LintContext) -> Option<NodeId> {
+    let declaration = ctx.scoping().symbol_declaration(symbol_id);
+    matches!(ctx.nodes().kind(declaration), AstKind::VariableDeclarator(_)).then_some(declaration)
+}
+
+/// `var a = function a() {}` and `var A = class A {}`, where the name of a function or
+/// class expression is the same as the variable it initializes.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+    let scoping = ctx.scoping();
+    let is_expression_name = match ctx.nodes().kind(scoping.symbol_declaration(symbol_id)) {
+        AstKind::Function(func) => func.is_expression(),
+        AstKind::Class(class) => class.is_expression(),
+        _ => false,
+    };
+    is_expression_name
+        && get_declarator_id(shadowed_id, ctx).is_some_and(|declarator| {
+            ctx.nodes().kind(declarator).span().contains_inclusive(scoping.symbol_span(symbol_id))
+        })
+}
+
+/// `var x = foo(x => x)`: the variable is declared by a callback in the initializer of
+/// the shadowed variable, which is presumably not initialized yet when it is called.
+fn is_in_initializer_callback(
+    symbol_id: SymbolId,
+    shadowed_id: SymbolId,
+    ctx: &LintContext,
+) -> bool {
+    let nodes = ctx.nodes();
+    let function_id = ctx.scoping().get_node_id(ctx.scoping().symbol_scope_id(symbol_id));
+    let function_span = nodes.kind(function_id).span();
+    if !nodes.kind(function_id).is_function_like() {
+        return false;
+    }
+    // An immediately invoked function runs during the initialization.
+    let is_iife = nodes
+        .ancestors(function_id)
+        .find(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)))
+        .is_some_and(|node| {
+            matches!(node.kind(), AstKind::CallExpression(call) if call.callee.span().contains_inclusive(function_span))
+        });
+    if is_iife {
+        return false;
+    }
+    let shadowed_span = ctx.scoping().symbol_span(shadowed_id);
+    for node in nodes.ancestors(function_id) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                return declarator.id.span().contains_inclusive(shadowed_span)
+                    && declarator
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| init.span().contains_inclusive(function_span));
+            }
+            AstKind::AssignmentPattern(pattern)
+                if pattern.left.span().contains_inclusive(shadowed_span)
+                    && pattern.right.span().contains_inclusive(function_span) =>
+            {
+                return true;
+            }
+            kind if kind.is_statement() || kind.is_function_like() => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("{ const a = 0; } const a = 1;", None),
+        ("{ const a = 0; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { let a; } var a;", None),
+        ("function foo() { var a; } let a;", None),
+        ("function foo(a) { } let a;", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("class C { foo; foo() { let foo; } }", None),
+        ("function foo() { var a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "functions" }]))),
+        ("const a = [].find(a => a)", Some(json!([{ "ignoreOnInitialization": true }]))),
+        (
+            "const a = [].find(function(a) { return a; })",
+            Some(json!([{ "ignoreOnInitialization": true }])),
+        ),
+        (
+            "const [a = [].find(a => true)] = dummy",
+            Some(json!([{ "ignoreOnInitialization": true }])),
+        ),
+        ("const a = foo(a => { return a; })", Some(json!([{ "ignoreOnInitialization": true }]))),
+        ("type Foo = 1; function f() { const Foo = 2; }", None),
+        ("const foo = 1; function f<foo>(a: foo) {}", None),
+        ("const x = 1; { type x = string; }", None),
+        ("interface Foo {} function f() { const Foo = 1; }", None),
+        ("enum E { A } const A = 1;", None),
+        (
+            "function foo(a: string): void; function foo(a: number): void; function foo(a: any) {}",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "functions" }]))),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("{ let a; } var a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } function a() {}", None),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo(a) { } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("function foo() { var Array = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        ("var error; try {} catch (error) {}", None),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        ("class C { m() { class C {} } }", None),
+        ("let x = 1; function f() { let x = 2; return function g() { let x = 3; }; }", None),
+        ("const a = [].find(a => a)", None),
+        ("const a = (a => a)()", Some(json!([{ "ignoreOnInitialization": true }]))),
+        ("let x = foo((x, y) => {});\nlet y;", Some(json!([{ "hoist": "all" }]))),
+        ("type Foo = 1; function f() { type Foo = 2; }", None),
+        (
+            "type Foo = 1; function f() { const Foo = 2; }",
+            Some(json!([{ "ignoreTypeValueShadow": false }])),
+        ),
+        ("function f() { type T = 1; } type T = 2;", Some(json!([{ "hoist": "types" }]))),
+        (
+            "function f() { type T = 1; } type T = 2;",
+            Some(json!([{ "hoist": "functions-and-types" }])),
+        ),
+        ("import { a } from 'b'; function f() { const a = 1; }", None),
+    ];
+
+    Tester::new(NoShadow::NAME, NoShadow::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,323 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, CallExpression, Class, Expression, Function, MemberExpression,
+        NewExpression, TaggedTemplateExpression, YieldExpression,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, Reference};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_unmodified_loop_condition_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is not modified in this loop."))
+        .with_help("Update the variable inside the loop, or the loop may never end.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unmodified loop conditions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables in a loop condition often need to be modified in the loop. If
+    /// they are not, the loop is either never entered or never left, which is
+    /// probably a mistake.
+    ///
+    /// Conditions that contain function calls, member accesses or similar
+    /// expressions are not checked, as their value may change without any
+    /// variable being reassigned.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let node = something;
+    /// while (node) {
+    ///     doSomething(node);
+    /// }
+    /// node = other;
+    ///
+    /// for (let j = 0; j < items.length; ++i) {
+    ///     doSomething(items[j]);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// while (node) {
+    ///     doSomething(node);
+    ///     node = node.parent;
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++j) {
+    ///     doSomething(items[j]);
+    /// }
+    ///
+    /// // the call may have side effects
+    /// while (node !== root) {
+    ///     doSomething(node);
+    /// }
+    /// ```
+    NoUnmodifiedLoopCondition,
+    eslint,
+    suspicious
+);
+
+/// A reference to a variable inside of the test of a loop.
+struct LoopCondition<'a> {
+    name: &'a str,
+    span: Span,
+    loop_id: NodeId,
+    /// The outermost binary or conditional expression of the test the reference is part of. The
+    /// test only needs one of the variables it groups to be modified.
+    group: Option<NodeId>,
+    modified: bool,
+}
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn run_once(&self, ctx: &LintContext) {
+        let scoping = ctx.scoping();
+        let mut conditions = vec![];
+
+        for symbol_id in scoping.symbol_ids() {
+            let start = conditions.len();
+            let name = scoping.symbol_name(symbol_id);
+            for reference in scoping.get_resolved_references(symbol_id) {
+                if reference.is_type() {
+                    continue;
+                }
+                if let Some((loop_id, group)) = to_loop_condition(reference.node_id(), ctx) {
+                    conditions.push(LoopCondition {
+                        name,
+                        span: ctx.nodes().get_node(reference.node_id()).span(),
+                        loop_id,
+                        group,
+                        modified: false,
+                    });
+                }
+            }
+            if conditions.len() == start {
+                continue;
+            }
+
+            let modifiers = scoping
+                .get_resolved_references(symbol_id)
+                .filter(|reference| reference.is_write())
+                .map(Reference::node_id)
+                .collect::<Vec<_>>();
+            for condition in &mut conditions[start..] {
+                condition.modified = modifiers
+                    .iter()
+                    .any(|&modifier| is_modified_in_loop(condition.loop_id, modifier, ctx));
+            }
+        }
+
+        let mut modified_groups: FxHashMap<NodeId, bool> = FxHashMap::default();
+        for condition in &conditions {
+            match condition.group {
+                Some(group) => *modified_groups.entry(group).or_default() |= condition.modified,
+                None if !condition.modified => ctx.diagnostic(
+                    no_unmodified_loop_condition_diagnostic(condition.name, condition.span),
+                ),
+                None => {}
+            }
+        }
+
+        let mut groups = modified_groups.into_iter().collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|(group, _)| *group);
+        for (group, modified) in groups {
+            if modified {
+                continue;
+            }
+            for condition in conditions.iter().filter(|condition| condition.group == Some(group)) {
+                ctx.diagnostic(no_unmodified_loop_condition_diagnostic(
+                    condition.name,
+                    condition.span,
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the loop whose test contains the given reference, along with the group expression the
+/// reference belongs to, if any. Returns `None` for references in dynamic tests.
+fn to_loop_condition(reference: NodeId, ctx: &LintContext) -> Option<(NodeId, Option<NodeId>)> {
+    let mut child = ctx.nodes().get_node(reference);
+    let mut group = None;
+    for node in ctx.nodes().ancestors(reference) {
+        match node.kind() {
+            AstKind::WhileStatement(stmt) if stmt.test.span() == child.span() => {
+                return Some((node.id(), group));
+            }
+            AstKind::DoWhileStatement(stmt) if stmt.test.span() == child.span() => {
+                return Some((node.id(), group));
+            }
+            AstKind::ForStatement(stmt)
+                if stmt.test.as_ref().is_some_and(|test| test.span() == child.span()) =>
+            {
+                return Some((node.id(), group));
+            }
+            AstKind::CallExpression(_)
+            | AstKind::NewExpression(_)
+            | AstKind::YieldExpression(_)
+            | AstKind::StaticMemberExpression(_)
+            | AstKind::ComputedMemberExpression(_)
+            | AstKind::PrivateFieldExpression(_)
+            | AstKind::Function(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::Class(_) => return None,
+            kind if kind.is_statement() || kind.is_declaration() => return None,
+            AstKind::BinaryExpression(expr) => {
+                if has_dynamic_expressions(&expr.left) || has_dynamic_expressions(&expr.right) {
+                    return None;
+                }
+                group = Some(node.id());
+            }
+            AstKind::ConditionalExpression(expr) => {
+                if has_dynamic_expressions(&expr.test)
+                    || has_dynamic_expressions(&expr.consequent)
+                    || has_dynamic_expressions(&expr.alternate)
+                {
+                    return None;
+                }
+                group = Some(node.id());
+            }
+            _ => {}
+        }
+        child = node;
+    }
+    None
+}
+
+/// Whether the write at `modifier` happens inside the loop, either directly or in a function
+/// declaration that is referenced inside the loop.
+fn is_modified_in_loop(loop_id: NodeId, modifier: NodeId, ctx: &LintContext) -> bool {
+    let span = ctx.nodes().get_node(modifier).span();
+    if is_in_loop(loop_id, span, ctx) {
+        return true;
+    }
+    let Some(function) = ctx.nodes().ancestors(modifier).find_map(|node| match node.kind() {
+        AstKind::Function(func) if func.is_declaration() => Some(func),
+        _ => None,
+    }) else {
+        return false;
+    };
+    function.id.as_ref().is_some_and(|id| {
+        ctx.scoping().get_resolved_references(id.symbol_id()).any(|reference| {
+            is_in_loop(loop_id, ctx.nodes().get_node(reference.node_id()).span(), ctx)
+        })
+    })
+}
+
+fn is_in_loop(loop_id: NodeId, span: Span, ctx: &LintContext) -> bool {
+    match ctx.nodes().kind(loop_id) {
+        AstKind::ForStatement(stmt) => {
+            stmt.span.contains_inclusive(span)
+                && !stmt.init.as_ref().is_some_and(|init| init.span().contains_inclusive(span))
+        }
+        kind => kind.span().contains_inclusive(span),
+    }
+}
+
+fn has_dynamic_expressions(expr: &Expression) -> bool {
+    let mut finder = DynamicExpressionFinder { found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
+
+/// Looks for expressions whose value may change without a variable being reassigned.
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_new_expression(&mut self, _it: &NewExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_member_expression(&mut self, _it: &MemberExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "var foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 0; while (++foo < bar) { }",
+        "var foo = 0, obj = {}; while (foo === obj.bar) { }",
+        "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+        "var foo = 0, f = {}; while (foo === f()) { }",
+        "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { foo += 1; }",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; ++bar; }",
+        "var foo = 0, bar = 0; while (foo || bar) { ++foo; ++bar; }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "var foo = 0; do { } while (foo++);",
+        "for (var foo = 0; foo; ++foo) { }",
+        "for (var foo = 0; foo;) { ++foo }",
+        "var foo = 0, bar = 0; for (bar; foo;) { ++foo }",
+        "var foo; if (foo) { } while (foo) { foo = 1; }",
+        "var a = [1, 2, 3]; var len = a.length; for (var i = 0; i < len - 1; i++) {}",
+        "var foo = 0; while (foo) { (function() { ++foo; }); }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { } foo = 1;",
+        "for (let i = 0; i < 10; ++j) { }",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, NoUnmodifiedLoopCondition::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
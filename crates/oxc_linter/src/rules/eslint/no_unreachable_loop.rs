@@ -0,0 +1,210 @@
+use bitflags::bitflags;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_unreachable_loop_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Invalid loop. Its body allows only one iteration.")
+        .with_help(
+            "Use an `if` statement instead, or make sure the loop can reach its next iteration.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoop {
+    /// Kinds of loops that are not checked.
+    ignore: LoopKinds,
+}
+
+bitflags! {
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct LoopKinds: u8 {
+        /// `'WhileStatement'`
+        const While = 1 << 0;
+        /// `'DoWhileStatement'`
+        const DoWhile = 1 << 1;
+        /// `'ForStatement'`
+        const For = 1 << 2;
+        /// `'ForInStatement'`
+        const ForIn = 1 << 3;
+        /// `'ForOfStatement'`
+        const ForOf = 1 << 4;
+    }
+}
+
+impl TryFrom<&str> for LoopKinds {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "WhileStatement" => Ok(Self::While),
+            "DoWhileStatement" => Ok(Self::DoWhile),
+            "ForStatement" => Ok(Self::For),
+            "ForInStatement" => Ok(Self::ForIn),
+            "ForOfStatement" => Ok(Self::ForOf),
+            _ => Err(()),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow loops with a body that allows only one iteration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A loop that can never reach its second iteration is a possible error in
+    /// the code. Most likely a `break`, `return` or `throw` was meant to be
+    /// conditional, or a `continue` is missing.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// while (foo) {
+    ///     doSomething(foo);
+    ///     foo = foo.parent;
+    ///     break;
+    /// }
+    ///
+    /// for (const item of items) {
+    ///     if (item.ok) {
+    ///         return item;
+    ///     } else {
+    ///         throw new Error();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// while (foo) {
+    ///     doSomething(foo);
+    ///     foo = foo.parent;
+    /// }
+    ///
+    /// for (const item of items) {
+    ///     if (item.ok) {
+    ///         return item;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignore
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Loop types to skip, any of `"WhileStatement"`, `"DoWhileStatement"`,
+    /// `"ForStatement"`, `"ForInStatement"` and `"ForOfStatement"`.
+    NoUnreachableLoop,
+    eslint,
+    correctness
+);
+
+impl Rule for NoUnreachableLoop {
+    fn from_configuration(value: Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(Value::as_array)
+            .map(|kinds| {
+                kinds
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|kind| LoopKinds::try_from(kind).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { ignore }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // Look for the bodies of loops, the loop itself starts in a different basic block.
+        let parent = ctx.nodes().parent_node(node.id());
+        let (kind, body, span) = match parent.kind() {
+            AstKind::WhileStatement(stmt) => (LoopKinds::While, &stmt.body, stmt.span),
+            AstKind::DoWhileStatement(stmt) => (LoopKinds::DoWhile, &stmt.body, stmt.span),
+            AstKind::ForStatement(stmt) => (LoopKinds::For, &stmt.body, stmt.span),
+            AstKind::ForInStatement(stmt) => (LoopKinds::ForIn, &stmt.body, stmt.span),
+            AstKind::ForOfStatement(stmt) => (LoopKinds::ForOf, &stmt.body, stmt.span),
+            _ => return,
+        };
+        if body.span() != node.span() || self.ignore.contains(kind) {
+            return;
+        }
+
+        let cfg = ctx.cfg();
+        if cfg.basic_block(node.cfg_id()).is_unreachable() {
+            return;
+        }
+        if !cfg.is_reentrant(node.cfg_id(), parent.cfg_id()) {
+            ctx.diagnostic(no_unreachable_loop_diagnostic(span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("while (foo) {}", None),
+        ("while (foo) { bar(); }", None),
+        ("while (foo) { if (bar) break; }", None),
+        ("while (foo) { if (bar) return; }", None),
+        ("while (foo) { if (bar) throw err; }", None),
+        ("while (foo) { if (bar) { break; } baz(); }", None),
+        ("while (foo) { if (bar) continue; break; }", None),
+        ("while (foo) { if (bar) { continue; } else { break; } break; }", None),
+        ("while (foo) { try { return bar(); } catch (e) {} }", None),
+        ("while (foo) { try { bar(); } finally { baz(); } }", None),
+        ("while (foo) { switch (bar) { case 1: break; default: return; } }", None),
+        ("while (foo) { function f() { return; } }", None),
+        ("do { bar(); } while (foo)", None),
+        ("do { if (bar) break; } while (foo)", None),
+        ("do { if (bar) continue; break; } while (foo)", None),
+        ("for (;;) {}", None),
+        ("for (;;) { if (foo) break; }", None),
+        ("for (let i = 0; i < 10; i++) { if (i > 5) return; }", None),
+        ("for (const a in b) { if (a) break; }", None),
+        ("for (const a of b) { if (a) return a; }", None),
+        ("for (const a of b) { if (a) { continue; } return a; }", None),
+        ("outer: for (const a of b) { for (const c of d) { if (c) continue outer; } }", None),
+        ("while (foo) { break; }", Some(serde_json::json!([{ "ignore": ["WhileStatement"] }]))),
+        (
+            "for (const a of b) { return a; }",
+            Some(serde_json::json!([{ "ignore": ["ForOfStatement"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("while (foo) { break; }", None),
+        ("while (foo) { return; }", None),
+        ("while (foo) { throw err; }", None),
+        ("while (foo) { bar(); break; }", None),
+        ("while (foo) { if (bar) { break; } else { return; } }", None),
+        ("while (foo) { switch (bar) { case 1: return; default: throw err; } }", None),
+        ("while (foo) { try { return bar(); } finally { baz(); } }", None),
+        ("do { break; } while (foo)", None),
+        ("do { return; } while (foo)", None),
+        ("for (;;) { break; }", None),
+        ("for (let i = 0; i < 10; i++) { return i; }", None),
+        ("for (const a in b) { break; }", None),
+        ("for (const a of b) { return a; }", None),
+        ("for (const a of b) { if (a) { return a; } else { throw a; } }", None),
+        ("for (const a of b) { while (c) { break; } }", None),
+        ("outer: while (a) { while (b) { continue outer; } }", None),
+        ("while (foo) { break; }", Some(serde_json::json!([{ "ignore": ["ForStatement"] }]))),
+    ];
+
+    Tester::new(NoUnreachableLoop::NAME, NoUnreachableLoop::PLUGIN, pass, fail).test_and_snapshot();
+}
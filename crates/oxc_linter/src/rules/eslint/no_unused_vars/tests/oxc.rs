@@ -1359,3 +1359,49 @@ import Layout from '../layouts/Layout.astro';
 //         .with_snapshot_suffix("<replace>")
 //         .test_and_snapshot();
 // }
+
+#[test]
+fn test_flow_type_imports() {
+    let pass = vec![
+        ("// @flow\nimport { type Node } from 'react'; export const a: Node = null;", None),
+        ("// @flow\nimport type { Node } from 'react'; export const a: Node = null;", None),
+        ("// @flow\nimport typeof TheDefault from './mod'; export type T = TheDefault;", None),
+        (
+            "// @flow\nimport { type Node } from 'react'; type Props = {| children: Node |}; export default function C(props: Props) { return props; }",
+            None,
+        ),
+        (
+            "// @flow\nimport * as React from 'react'; import { type Node } from 'react'; export class C extends React.Component<{ n: Node }> {}",
+            None,
+        ),
+        (
+            "// @flow\nimport { type Node } from 'react'; export const f = (x: any): Node => x;",
+            None,
+        ),
+        (
+            "// @flow\nimport { type Node } from 'react'; export const a = ((null: any): Node);",
+            None,
+        ),
+        ("// @flow\nimport { type Node } from 'react'; declare export function f(): Node;", None),
+        ("// @flow\nimport { type Node } from 'react'; export opaque type T: Node = Node;", None),
+        // Flow files which cannot be parsed are skipped.
+        ("// @flow\nimport { type Node } from 'react'; declare module.exports: Node;", None),
+    ];
+    let fail = vec![
+        ("// @flow\nimport { type Node } from 'react';", None),
+        ("// @flow\nimport type { Node } from 'react';", None),
+        ("// @flow\nimport { type Node } from 'react'; type Unused = Node;", None),
+    ];
+    let fix = vec![(
+        "// @flow\nimport { type Node, useState } from 'react'; useState();",
+        "// @flow\nimport { useState } from 'react'; useState();",
+        None,
+        FixKind::DangerousSuggestion,
+    )];
+
+    Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
+        .change_rule_path_extension("js")
+        .expect_fix(fix)
+        .with_snapshot_suffix("oxc-flow-imports")
+        .test_and_snapshot();
+}
@@ -0,0 +1,387 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, Reference, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_use_before_define_diagnostic(
+    name: &str,
+    span: Span,
+    declaration_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' was used before it was defined.")).with_labels([
+        span.label(format!("'{name}' is used here")),
+        declaration_span.label(format!("'{name}' is defined here")),
+    ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUseBeforeDefine(Box<NoUseBeforeDefineConfig>);
+
+#[derive(Debug, Clone)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct NoUseBeforeDefineConfig {
+    functions: bool,
+    classes: bool,
+    variables: bool,
+    allow_named_exports: bool,
+    enums: bool,
+    typedefs: bool,
+    ignore_type_references: bool,
+}
+
+impl Default for NoUseBeforeDefineConfig {
+    fn default() -> Self {
+        Self {
+            functions: true,
+            classes: true,
+            variables: true,
+            allow_named_exports: false,
+            enums: true,
+            typedefs: true,
+            ignore_type_references: true,
+        }
+    }
+}
+
+impl std::ops::Deref for NoUseBeforeDefine {
+    type Target = NoUseBeforeDefineConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the use of variables before they are defined.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In JavaScript, prior to ES6, variable and function declarations are hoisted to the
+    /// top of a scope, so it's possible to use identifiers before their formal declarations
+    /// in code. This can be confusing. In ES6, block-level bindings (`let` and `const`)
+    /// introduce a "temporal dead zone" where a `ReferenceError` will be thrown with any
+    /// attempt to access the variable before its declaration.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// f();
+    /// function f() {}
+    ///
+    /// new A();
+    /// class A {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// var a;
+    /// a = 10;
+    /// alert(a);
+    ///
+    /// function f() {}
+    /// f();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// The string `"nofunc"` is the same as `{ "functions": false }`.
+    ///
+    /// #### functions
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Check references to function declarations.
+    /// Function declarations are hoisted, so setting this to `false` is safe.
+    ///
+    /// #### classes
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Check references to classes from nested functions.
+    /// References in the same scope are always checked.
+    ///
+    /// #### variables
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Check references to variables from nested functions.
+    /// References in the same scope are always checked.
+    ///
+    /// #### allowNamedExports
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allow references in `export { name }` declarations.
+    ///
+    /// #### enums
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Check references to TypeScript enums from nested functions.
+    ///
+    /// #### typedefs
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Check references to TypeScript type aliases and interfaces.
+    ///
+    /// #### ignoreTypeReferences
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Ignore references in type positions, including `typeof` type queries.
+    NoUseBeforeDefine,
+    eslint,
+    restriction
+);
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoUseBeforeDefineConfig::default();
+        match value.get(0) {
+            Some(Value::String(option)) if option == "nofunc" => config.functions = false,
+            Some(Value::Object(options)) => {
+                let option = |key: &str, default: bool| {
+                    options.get(key).and_then(Value::as_bool).unwrap_or(default)
+                };
+                config = NoUseBeforeDefineConfig {
+                    functions: option("functions", config.functions),
+                    classes: option("classes", config.classes),
+                    variables: option("variables", config.variables),
+                    allow_named_exports: option("allowNamedExports", config.allow_named_exports),
+                    enums: option("enums", config.enums),
+                    typedefs: option("typedefs", config.typedefs),
+                    ignore_type_references: option(
+                        "ignoreTypeReferences",
+                        config.ignore_type_references,
+                    ),
+                };
+            }
+            _ => {}
+        }
+        Self(Box::new(config))
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext) {
+        let scoping = ctx.scoping();
+        let flags = scoping.symbol_flags(symbol_id);
+        if flags.is_function() && !self.functions
+            || flags.intersects(SymbolFlags::TypeAlias | SymbolFlags::Interface) && !self.typedefs
+        {
+            return;
+        }
+        let name = scoping.symbol_name(symbol_id);
+        let declaration_span = scoping.symbol_span(symbol_id);
+        for reference in scoping.get_resolved_references(symbol_id) {
+            let span = ctx.semantic().reference_span(reference);
+            if declaration_span.end < span.end
+                && !is_in_initializer(symbol_id, span, reference, ctx)
+            {
+                continue;
+            }
+            if self.should_check(symbol_id, flags, reference, ctx) {
+                ctx.diagnostic(no_use_before_define_diagnostic(name, span, declaration_span));
+            }
+        }
+    }
+}
+
+impl NoUseBeforeDefine {
+    fn should_check(
+        &self,
+        symbol_id: SymbolId,
+        flags: SymbolFlags,
+        reference: &Reference,
+        ctx: &LintContext,
+    ) -> bool {
+        if self.ignore_type_references
+            && (reference.is_type() || reference.flags().is_value_as_type())
+        {
+            return false;
+        }
+        if self.allow_named_exports
+            && matches!(ctx.nodes().parent_kind(reference.node_id()), AstKind::ExportSpecifier(_))
+        {
+            return false;
+        }
+        // References from the same execution context are always checked, because of the TDZ.
+        let skip_outside_execution_context = !self.variables && flags.is_variable()
+            || !self.classes && flags.is_class()
+            || !self.enums && flags.is_enum();
+        !(skip_outside_execution_context
+            && is_from_separate_execution_context(symbol_id, reference.node_id(), ctx))
+    }
+}
+
+/// The nearest function-like (or top-level) scope containing `scope_id`.
+fn variable_scope(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scoping = ctx.scoping();
+    scoping
+        .scope_ancestors(scope_id)
+        .find(|id| scoping.scope_flags(*id).is_var())
+        .unwrap_or_else(|| scoping.root_scope_id())
+}
+
+/// Whether the reference is in a function other than the one declaring the symbol.
+/// Class static blocks run immediately, so they belong to the enclosing context.
+fn is_from_separate_execution_context(
+    symbol_id: SymbolId,
+    node_id: NodeId,
+    ctx: &LintContext,
+) -> bool {
+    let scoping = ctx.scoping();
+    let target = variable_scope(scoping.symbol_scope_id(symbol_id), ctx);
+    let mut scope_id = variable_scope(ctx.nodes().get_node(node_id).scope_id(), ctx);
+    while scope_id != target {
+        if !scoping.scope_flags(scope_id).is_class_static_block() {
+            return true;
+        }
+        let Some(parent_id) = scoping.scope_parent_id(scope_id) else { return true };
+        scope_id = variable_scope(parent_id, ctx);
+    }
+    false
+}
+
+/// Whether the reference is in the initializer of the variable itself, e.g. `var a = a;`
+/// or `function f(a = a) {}`.
+fn is_in_initializer(
+    symbol_id: SymbolId,
+    span: Span,
+    reference: &Reference,
+    ctx: &LintContext,
+) -> bool {
+    let nodes = ctx.nodes();
+    // Stops at function boundaries, as the initializer runs before any nested function.
+    let declaration_span = ctx.scoping().symbol_span(symbol_id);
+    for node in nodes.ancestors(reference.node_id()) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                return declarator.id.span().contains_inclusive(declaration_span)
+                    && declarator
+                        .init
+                        .as_ref()
+                        .is_some_and(|init| init.span().contains_inclusive(span));
+            }
+            AstKind::AssignmentPattern(pattern) => {
+                if pattern.left.span().contains_inclusive(declaration_span)
+                    && pattern.right.span().contains_inclusive(span)
+                {
+                    return true;
+                }
+            }
+            AstKind::ForInStatement(stmt) => {
+                return stmt.left.span().contains_inclusive(declaration_span)
+                    && stmt.right.span().contains_inclusive(span);
+            }
+            AstKind::ForOfStatement(stmt) => {
+                return stmt.left.span().contains_inclusive(declaration_span)
+                    && stmt.right.span().contains_inclusive(span);
+            }
+            AstKind::Function(_)
+            | AstKind::Class(_)
+            | AstKind::ArrowFunctionExpression(_)
+            | AstKind::CatchClause(_)
+            | AstKind::ImportDeclaration(_)
+            | AstKind::ExportNamedDeclaration(_) => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var a=10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments);}", None),
+        ("a(); function a() { alert(arguments); }", Some(json!(["nofunc"]))),
+        ("(() => { var a = 42; alert(a); })();", None),
+        ("a(); try { throw new Error() } catch (a) {}", None),
+        ("class A {} new A();", None),
+        ("var a = 0, b = a;", None),
+        ("var {a = 0, b = a} = {};", None),
+        ("var [a = 0, b = a] = {};", None),
+        ("function foo() { foo(); }", None),
+        ("var foo = function() { foo(); };", None),
+        ("var a; for (a in a) {}", None),
+        ("var a; for (a of a) {}", None),
+        ("let a; class C { static { a; } }", None),
+        ("class C { static { let a; a; } }", None),
+        ("class C { static { function a() {} a; } }", None),
+        ("class C { static { a; function a() {} } }", Some(json!([{ "functions": false }]))),
+        ("function a() { b; } var b;", Some(json!([{ "variables": false }]))),
+        ("const f = () => { new A(); }; class A {}", Some(json!([{ "classes": false }]))),
+        ("export { a }; const a = 1;", Some(json!([{ "allowNamedExports": true }]))),
+        ("export { a as b }; const a = 1;", Some(json!([{ "allowNamedExports": true }]))),
+        ("let x: Foo; type Foo = string;", None),
+        ("function f(): Foo { return 1; } interface Foo {}", None),
+        ("let x: typeof a; const a = 1;", None),
+        (
+            "let x: Foo; type Foo = string;",
+            Some(json!([{ "typedefs": false, "ignoreTypeReferences": false }])),
+        ),
+        ("function foo() { return E.A; } enum E { A }", Some(json!([{ "enums": false }]))),
+        ("const obj = { a: 1 }; const { a } = obj;", None),
+    ];
+
+    let fail = vec![
+        ("a++; var a=19;", None),
+        ("a(); var a=function() {};", None),
+        ("alert(a[1]); var a=[1,3];", None),
+        ("a(); function a() { alert(b); var b=10; a(); }", None),
+        ("a(); var a=function() {};", Some(json!(["nofunc"]))),
+        ("(() => { alert(a); var a = 42; })();", None),
+        ("(() => a())(); function a() { }", None),
+        ("a(); try { throw new Error() } catch (foo) {var a;}", None),
+        ("var f = () => a; var a;", None),
+        ("new A(); class A {};", None),
+        ("function foo() { new A(); } class A {};", None),
+        ("new A(); var A = class {};", None),
+        ("function foo() { new A(); } var A = class {};", None),
+        ("a++; { var a; }", None),
+        ("\"use strict\"; { a(); function a() {} }", None),
+        ("{a; let a = 1}", None),
+        ("switch (foo) { case 1: a();\n default: \n let a;}", None),
+        ("if (true) { function foo() { a; } let a;}", None),
+        ("var a = a;", None),
+        ("let a = a + b;", None),
+        ("const a = foo(a);", None),
+        ("function foo(a = a) {}", None),
+        ("var {a = a} = [];", None),
+        ("var [a = a] = [];", None),
+        ("var {b = a, a} = {};", None),
+        ("var [b = a, a] = {};", None),
+        ("var {a = 0} = a;", None),
+        ("var [a = 0] = a;", None),
+        ("for (var a in a) {}", None),
+        ("for (var a of a) {}", None),
+        ("new A(); class A {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { b; } let b;", None),
+        ("b; let b;", Some(json!([{ "variables": false }]))),
+        ("class C { static { a; let a; } }", None),
+        ("class C { static { a; } } let a;", Some(json!([{ "variables": false }]))),
+        ("export { a }; const a = 1;", None),
+        ("export { a as b }; const a = 1;", None),
+        ("let x: Foo; type Foo = string;", Some(json!([{ "ignoreTypeReferences": false }]))),
+        ("let x: typeof a; const a = 1;", Some(json!([{ "ignoreTypeReferences": false }]))),
+        ("function foo() { return E.A; } enum E { A }", None),
+        ("const x = E.A; enum E { A }", Some(json!([{ "enums": false }]))),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, NoUseBeforeDefine::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,401 @@
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    EdgeType, Instruction, InstructionKind, ReturnInstructionKind,
+    graph::{
+        Direction,
+        visit::{Control, EdgeRef},
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_useless_return_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary return statement.")
+        .with_help("Remove this `return`, the function ends here anyway.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow redundant return statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `return;` statement with nothing after it is redundant, and has no
+    /// effect on the runtime behavior of a function. This can be confusing, so
+    /// it's better to disallow these redundant statements.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// function foo() { return; }
+    ///
+    /// function bar() {
+    ///     doSomething();
+    ///     return;
+    /// }
+    ///
+    /// function baz() {
+    ///     if (condition) {
+    ///         qux();
+    ///         return;
+    ///     } else {
+    ///         quux();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// function foo() { return 5; }
+    ///
+    /// function bar() {
+    ///     if (condition) {
+    ///         qux();
+    ///         return;
+    ///     }
+    ///     quux();
+    /// }
+    ///
+    /// function baz() {
+    ///     for (const item of items) {
+    ///         return;
+    ///     }
+    /// }
+    /// ```
+    NoUselessReturn,
+    eslint,
+    style,
+    conditional_fix
+);
+
+impl Rule for NoUselessReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ReturnStatement(ret) = node.kind() else { return };
+        if ret.argument.is_some() || is_in_loop_or_finally(node, ctx) {
+            return;
+        }
+
+        let cfg = ctx.cfg();
+        let block = node.cfg_id();
+        // Returns in dead code are reported by `no-unreachable`.
+        if cfg.basic_block(block).is_unreachable() {
+            return;
+        }
+
+        // Whatever follows the `return` in the source, reachable or not, lives behind an
+        // `Unreachable` edge. Had the `return` not been there, control would continue from there.
+        let Some(after) = cfg
+            .graph()
+            .edges_directed(block, Direction::Outgoing)
+            .find(|edge| matches!(edge.weight(), EdgeType::Unreachable))
+            .map(|edge| edge.target())
+        else {
+            return;
+        };
+
+        // `finally` blocks around the `return` run no matter what, they don't count.
+        let finalizers = ctx
+            .nodes()
+            .ancestors(node.id())
+            .take_while(|node| {
+                !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+            })
+            .filter_map(|node| match node.kind() {
+                AstKind::TryStatement(stmt) => {
+                    stmt.finalizer.as_ref().map(|finalizer| finalizer.span)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let is_in_finalizer = |instruction: &Instruction| {
+            instruction.node_id.is_some_and(|id| {
+                let span = ctx.nodes().get_node(id).span();
+                finalizers.iter().any(|finalizer| finalizer.contains_inclusive(span))
+            })
+        };
+
+        let is_followed_by_code = cfg
+            .walk_instructions(after, |instruction| match instruction.kind {
+                _ if is_in_finalizer(instruction) => Control::Continue,
+                InstructionKind::Statement => {
+                    match instruction.node_id.map(|id| ctx.nodes().kind(id)) {
+                        Some(AstKind::BlockStatement(_)) => Control::Continue,
+                        _ => Control::Break(()),
+                    }
+                }
+                InstructionKind::Return(ReturnInstructionKind::ImplicitUndefined)
+                | InstructionKind::ImplicitReturn => Control::Prune,
+                InstructionKind::Return(ReturnInstructionKind::NotImplicitUndefined)
+                | InstructionKind::Throw
+                | InstructionKind::Continue(_)
+                | InstructionKind::Condition
+                | InstructionKind::Iteration(_) => Control::Break(()),
+                InstructionKind::Break(_) | InstructionKind::Unreachable => Control::Continue,
+            })
+            .is_some();
+        if is_followed_by_code {
+            return;
+        }
+
+        let is_removable = matches!(
+            ctx.nodes().parent_kind(node.id()),
+            AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+        );
+        if is_removable && !ctx.semantic().has_comments_between(ret.span) {
+            ctx.diagnostic_with_fix(no_useless_return_diagnostic(ret.span), |fixer| {
+                fixer.delete(ret)
+            });
+        } else {
+            ctx.diagnostic(no_useless_return_diagnostic(ret.span));
+        }
+    }
+}
+
+/// Returns inside loops end the iteration early, and returns inside `finally` blocks override
+/// the completion of the `try` statement, so neither is ever useless.
+fn is_in_loop_or_finally(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut child_span = node.span();
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => return false,
+            AstKind::WhileStatement(_)
+            | AstKind::DoWhileStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_) => return true,
+            AstKind::TryStatement(stmt)
+                if stmt
+                    .finalizer
+                    .as_ref()
+                    .is_some_and(|finalizer| finalizer.span == child_span) =>
+            {
+                return true;
+            }
+            _ => {}
+        }
+        child_span = ancestor.span();
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { return 5; }",
+        "function foo() { return null; }",
+        "function foo() { return doSomething(); }",
+        "
+          function foo() {
+            if (bar) {
+              doSomething();
+              return;
+            } else {
+              doSomethingElse();
+            }
+            qux();
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                doSomething();
+                return;
+              default:
+                doSomethingElse();
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              default:
+                doSomething();
+                return;
+              case 1:
+                doSomething();
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              bar();
+              return;
+            } catch (err) {}
+            baz();
+          }
+        ",
+        "
+          function foo() {
+            if (something) {
+              try {
+                bar();
+                return;
+              } catch (err) {}
+            }
+            baz();
+          }
+        ",
+        "
+          function foo() {
+            return;
+            doSomething();
+          }
+        ",
+        "
+          function foo() {
+            for (var foo of bar) return;
+          }
+        ",
+        "
+          function foo() {
+            for (const foo of bar) {
+              doSomething();
+              return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            while (a) {
+              return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              a();
+            } catch {
+              return;
+            } finally {
+              b();
+            }
+            c();
+          }
+        ",
+        "
+          function foo() {
+            try {
+              a();
+            } finally {
+              return;
+            }
+          }
+        ",
+        "() => { if (foo) return; bar(); }",
+        "() => 5",
+        "() => { return; doSomething(); }",
+    ];
+
+    let fail = vec![
+        "
+          function foo() {
+            try {
+              return;
+            } finally {
+              return 5;
+            }
+          }
+        ",
+        "function foo() { return; }",
+        "function foo() { doSomething(); return; }",
+        "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+        "function foo() { if (foo) return; }",
+        "function foo() { bar(); return/* comment */; }",
+        "function foo() { if (foo) { return; } }",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                doSomething();
+              default:
+                doSomethingElse();
+                return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              default:
+                doSomething();
+              case 1:
+                doSomething();
+                return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                if (a) {
+                  doSomething();
+                  return;
+                }
+                break;
+              default:
+                doSomethingElse();
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              foo();
+              return;
+            } catch (err) {
+              return 5;
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              return;
+            } finally {
+              bar();
+            }
+          }
+        ",
+        "var foo = () => { return; };",
+        "class A { foo() { return; } }",
+        "function foo() { return; return; }",
+    ];
+
+    let fix = vec![
+        ("function foo() { return; }", "function foo() {  }"),
+        ("function foo() { doSomething(); return; }", "function foo() { doSomething();  }"),
+        (
+            "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+            "function foo() { if (condition) { bar();  } else { baz(); } }",
+        ),
+        ("function foo() { if (foo) return; }", "function foo() { if (foo) return; }"),
+        (
+            "function foo() { bar(); return/* comment */; }",
+            "function foo() { bar(); return/* comment */; }",
+        ),
+        ("var foo = () => { return; };", "var foo = () => {  };"),
+    ];
+
+    Tester::new(NoUselessReturn::NAME, NoUselessReturn::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
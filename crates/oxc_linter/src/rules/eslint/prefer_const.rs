@@ -0,0 +1,483 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AssignmentOperator, AssignmentTarget, AssignmentTargetMaybeDefault,
+        AssignmentTargetProperty, BindingIdentifier, VariableDeclarationKind,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{NodeId, ReferenceId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{AstNode, ast_util::is_function_parameter, context::LintContext, rule::Rule};
+
+fn prefer_const_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is never reassigned. Use 'const' instead."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Destructuring {
+    /// Report any variable in a destructuring that should be `const`.
+    #[default]
+    Any,
+    /// Only report when every variable in a destructuring should be `const`.
+    All,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst {
+    destructuring: Destructuring,
+    ignore_read_before_assign: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Requires `const` declarations for variables that are never reassigned after declared.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a variable is never reassigned, using the `const` declaration is better.
+    /// `const` declaration tells readers, "this variable is never reassigned,"
+    /// reducing cognitive load and improving maintainability.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// let b;
+    /// b = 0;
+    /// console.log(b);
+    ///
+    /// for (let i in [1, 2, 3]) {
+    ///     console.log(i);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// const a = 0;
+    ///
+    /// let b;
+    /// if (true) {
+    ///     b = 0;
+    /// }
+    /// console.log(b);
+    ///
+    /// for (let i = 0, l = list.length; i < l; i++) {
+    ///     console.log(list[i]);
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### destructuring
+    ///
+    /// `{ type: "any" | "all", default: "any" }`
+    ///
+    /// With `"any"`, variables in a destructuring are reported if any of them should be `const`.
+    /// With `"all"`, they are only reported if all of them should be `const`.
+    ///
+    /// #### ignoreReadBeforeAssign
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignores variables that are read between the declaration and the first assignment.
+    PreferConst,
+    eslint,
+    style,
+    conditional_fix
+);
+
+/// A variable that should be `const`.
+#[derive(Debug, Clone, Copy)]
+struct Candidate<'a> {
+    name: &'a str,
+    span: Span,
+    declaration: NodeId,
+}
+
+/// A write to a variable, in source order.
+struct Write {
+    start: u32,
+    span: Span,
+    /// The `VariableDeclarator` or `AssignmentExpression` doing the write, if any.
+    host: Option<NodeId>,
+    /// Whether the write happens directly in the declaring scope and could be turned
+    /// into a declaration.
+    can_become_declaration: bool,
+    /// `false` when the write is a destructuring that also assigns something which
+    /// cannot be declared alongside the variable.
+    destructuring_ok: bool,
+}
+
+impl Rule for PreferConst {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let destructuring =
+            match config.and_then(|config| config.get("destructuring")).and_then(Value::as_str) {
+                Some("all") => Destructuring::All,
+                _ => Destructuring::Any,
+            };
+        let ignore_read_before_assign = config
+            .and_then(|config| config.get("ignoreReadBeforeAssign"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        Self { destructuring, ignore_read_before_assign }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+        // Variables are grouped by the declarator or assignment that writes them,
+        // so that destructurings can be reported as a whole.
+        let mut groups = FxHashMap::<NodeId, Vec<Option<Candidate>>>::default();
+        // Declarations that can be turned into `const` as a whole.
+        let mut fixable_declarations = FxHashMap::<NodeId, bool>::default();
+
+        for node in nodes.iter() {
+            let AstKind::VariableDeclarator(declarator) = node.kind() else { continue };
+            let declaration_id = nodes.parent_id(node.id());
+            let AstKind::VariableDeclaration(declaration) = nodes.kind(declaration_id) else {
+                continue;
+            };
+            if declaration.kind != VariableDeclarationKind::Let
+                || declaration.declare
+                || matches!(nodes.parent_kind(declaration_id), AstKind::ForStatement(_))
+            {
+                continue;
+            }
+            let is_for_in_of = matches!(
+                nodes.parent_kind(declaration_id),
+                AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)
+            );
+            let initialized = is_for_in_of || declarator.init.is_some();
+            let fixable = fixable_declarations.entry(declaration_id).or_insert_with(|| {
+                is_for_in_of || declaration.declarations.iter().all(|d| d.init.is_some())
+            });
+
+            for ident in declarator.id.get_binding_identifiers() {
+                let writes = Self::writes(ident, initialized.then_some(node.id()), ctx);
+                let candidate = self.check_variable(ident, &writes, ctx).map(|span| Candidate {
+                    name: ident.name.as_str(),
+                    span,
+                    declaration: declaration_id,
+                });
+                *fixable &= candidate.is_some();
+                for host in writes.iter().filter_map(|write| write.host) {
+                    groups.entry(host).or_default().push(candidate);
+                }
+            }
+        }
+
+        let mut groups = groups.into_iter().collect::<Vec<_>>();
+        groups.sort_unstable_by_key(|(host, _)| *host);
+        for (_, group) in groups {
+            let candidates = group.iter().flatten().collect::<Vec<_>>();
+            let is_whole_group = candidates.len() == group.len();
+            if candidates.is_empty()
+                || (self.destructuring == Destructuring::All && !is_whole_group)
+            {
+                continue;
+            }
+            for candidate in candidates {
+                let diagnostic = prefer_const_diagnostic(candidate.name, candidate.span);
+                if is_whole_group && fixable_declarations[&candidate.declaration] {
+                    let let_span = Span::sized(nodes.kind(candidate.declaration).span().start, 3);
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(let_span, "const"));
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+        }
+    }
+}
+
+impl PreferConst {
+    /// Returns the span to report if the variable declared by `ident` should be `const`.
+    fn check_variable(
+        &self,
+        ident: &BindingIdentifier,
+        writes: &[Write],
+        ctx: &LintContext,
+    ) -> Option<Span> {
+        let symbol_id = ident.symbol_id();
+        let scoping = ctx.scoping();
+        let [writer] = writes else { return None };
+        if !writer.destructuring_ok || !writer.can_become_declaration {
+            return None;
+        }
+        let is_read_before_init = scoping.get_resolved_references(symbol_id).any(|reference| {
+            !reference.is_write() && ctx.semantic().reference_span(reference).start < writer.start
+        });
+        if is_read_before_init {
+            if self.ignore_read_before_assign {
+                return None;
+            }
+            return Some(ident.span);
+        }
+        Some(writer.span)
+    }
+
+    /// Collects the writes to the variable declared by `ident`, including its initializer.
+    fn writes(
+        ident: &BindingIdentifier,
+        declarator: Option<NodeId>,
+        ctx: &LintContext,
+    ) -> Vec<Write> {
+        let nodes = ctx.nodes();
+        let scoping = ctx.scoping();
+        let symbol_id = ident.symbol_id();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+
+        let mut writes = declarator
+            .map(|host| Write {
+                start: ident.span.start,
+                span: ident.span,
+                host: Some(host),
+                can_become_declaration: true,
+                destructuring_ok: true,
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        for reference in scoping.get_resolved_references(symbol_id) {
+            if !reference.is_write() {
+                continue;
+            }
+            let span = nodes.kind(reference.node_id()).span();
+            let host = nodes
+                .ancestors(reference.node_id())
+                .find(|node| !is_pattern(node.kind()))
+                .filter(|node| matches!(node.kind(), AstKind::AssignmentExpression(_)));
+            let mut write = Write {
+                start: span.start,
+                span,
+                host: host.map(AstNode::id),
+                can_become_declaration: false,
+                destructuring_ok: true,
+            };
+            if let Some(host) = host {
+                let AstKind::AssignmentExpression(assignment) = host.kind() else { unreachable!() };
+                write.can_become_declaration = assignment.operator == AssignmentOperator::Assign
+                    && nodes.get_node(reference.node_id()).scope_id() == scope_id
+                    && is_statement_in_block(host.id(), ctx);
+                if assignment.left.is_assignment_target_pattern() {
+                    let mut targets = vec![];
+                    collect_targets(&assignment.left, &mut targets);
+                    write.destructuring_ok = targets.iter().all(|target| {
+                        target.is_some_and(|reference_id| {
+                            scoping.get_reference(reference_id).symbol_id().is_some_and(|id| {
+                                scoping.symbol_scope_id(id) == scope_id
+                                    && !is_function_parameter(id, ctx.semantic())
+                            })
+                        })
+                    });
+                }
+            }
+            writes.push(write);
+        }
+
+        writes.sort_unstable_by_key(|write| write.start);
+        writes
+    }
+}
+
+fn is_pattern(kind: AstKind) -> bool {
+    matches!(
+        kind,
+        AstKind::ArrayAssignmentTarget(_)
+            | AstKind::ObjectAssignmentTarget(_)
+            | AstKind::AssignmentTargetRest(_)
+            | AstKind::AssignmentTargetWithDefault(_)
+            | AstKind::AssignmentTargetPropertyIdentifier(_)
+            | AstKind::AssignmentTargetPropertyProperty(_)
+    )
+}
+
+/// Whether the assignment is an expression statement directly in a block,
+/// where it could be replaced by a declaration.
+fn is_statement_in_block(assignment_id: NodeId, ctx: &LintContext) -> bool {
+    let mut ancestors = ctx
+        .nodes()
+        .ancestors(assignment_id)
+        .filter(|node| !matches!(node.kind(), AstKind::ParenthesizedExpression(_)));
+    ancestors.next().is_some_and(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))
+        && ancestors.next().is_some_and(|node| {
+            matches!(
+                node.kind(),
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::FunctionBody(_)
+            )
+        })
+}
+
+/// Collects the identifiers assigned by a destructuring, with `None` for member expressions.
+fn collect_targets(target: &AssignmentTarget, targets: &mut Vec<Option<ReferenceId>>) {
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+            targets.push(ident.reference_id.get());
+        }
+        AssignmentTarget::ArrayAssignmentTarget(array) => {
+            for element in array.elements.iter().flatten() {
+                collect_maybe_default_targets(element, targets);
+            }
+            if let Some(rest) = &array.rest {
+                collect_targets(&rest.target, targets);
+            }
+        }
+        AssignmentTarget::ObjectAssignmentTarget(object) => {
+            for property in &object.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        targets.push(property.binding.reference_id.get());
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        collect_maybe_default_targets(&property.binding, targets);
+                    }
+                }
+            }
+            if let Some(rest) = &object.rest {
+                collect_targets(&rest.target, targets);
+            }
+        }
+        _ => targets.push(None),
+    }
+}
+
+fn collect_maybe_default_targets(
+    target: &AssignmentTargetMaybeDefault,
+    targets: &mut Vec<Option<ReferenceId>>,
+) {
+    match target {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            collect_targets(&target.binding, targets);
+        }
+        _ => {
+            if let Some(target) = target.as_assignment_target() {
+                collect_targets(target, targets);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var x = 0;", None),
+        ("let x;", None),
+        ("let x; { x = 0; } foo(x);", None),
+        ("let x = 0; x = 1;", None),
+        ("const x = 0;", None),
+        ("for (let i = 0, end = 10; i < end; ++i) {}", None),
+        ("for (let i in [1,2,3]) { i = 0; }", None),
+        ("for (let x of [1,2,3]) { x = 0; }", None),
+        ("(function() { var x = 0; })();", None),
+        ("(function() { let x; })();", None),
+        ("(function() { let x; { x = 0; } foo(x); })();", None),
+        ("(function() { let x = 0; x = 1; })();", None),
+        ("(function() { const x = 0; })();", None),
+        ("(function() { for (let i = 0, end = 10; i < end; ++i) {} })();", None),
+        ("(function() { for (let i in [1,2,3]) { i = 0; } })();", None),
+        ("(function() { for (let x of [1,2,3]) { x = 0; } })();", None),
+        ("(function(x = 0) { })();", None),
+        ("let a; while (a = foo());", None),
+        ("let a; do {} while (a = foo());", None),
+        ("let a; for (; a = foo(); );", None),
+        ("let a; for (;; ++a);", None),
+        ("let a; for (const {b = ++a} in foo());", None),
+        ("let a; for (const {b = ++a} of foo());", None),
+        ("let a; for (const x of [1,2,3]) { if (a) {} a = foo(); }", None),
+        ("let a; for (const x of [1,2,3]) { a = a || foo(); bar(a); }", None),
+        ("let a; for (const x of [1,2,3]) { foo(++a); }", None),
+        ("let a; function foo() { if (a) {} a = bar(); }", None),
+        ("let a; function foo() { a = a || bar(); baz(a); }", None),
+        ("let a; function foo() { bar(++a); }", None),
+        (
+            "let id; function foo() { if (typeof id !== 'undefined') { return; } id = setInterval(() => {}, 250); } foo();",
+            None,
+        ),
+        ("let a; for (a of b) {}", None),
+        ("let a; a += 1;", None),
+        ("let a; a++;", None),
+        ("let x; foo(x); x = 0;", Some(json!([{ "ignoreReadBeforeAssign": true }]))),
+        (
+            "let x; function foo() { bar(x); } x = 0;",
+            Some(json!([{ "ignoreReadBeforeAssign": true }])),
+        ),
+        ("let {a, b} = obj; b = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let a, b; ({a, b} = obj); b++;", Some(json!([{ "destructuring": "all" }]))),
+        ("let [a, b] = [1, 2]; a = 3;", Some(json!([{ "destructuring": "all" }]))),
+        ("function foo(a) { let b; [a, b] = [1, 2]; }", None),
+        ("let a; ({a, b: foo.bar} = obj);", None),
+        ("let a; if (true) a = 0; foo(a);", None),
+        ("let a; label: a = 0;", None),
+        ("declare let a: number;", None),
+        ("let predicate; [typeNode.returnType, predicate] = foo();", None),
+        ("let x = 'x', y = 'y'; ({ x, y } = obj); foo(x, y);", None),
+    ];
+
+    let fail = vec![
+        ("let x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", None),
+        ("let {a: x = -1, b: y} = {a:1,b:2}; y = 0;", None),
+        ("(function() { let x = 1; foo(x); })();", None),
+        ("(function() { for (let i in [1,2,3]) { foo(i); } })();", None),
+        ("(function() { for (let x of [1,2,3]) { foo(x); } })();", None),
+        ("(function() { let [x = -1, y] = [1,2]; y = 0; })();", None),
+        ("let f = (function() { let g = x; })(); f = 1;", None),
+        ("let x = 0; { let x = 1; foo(x); } x = 0;", None),
+        ("for (let i = 0; i < 10; ++i) { let x = 1; foo(x); }", None),
+        ("for (let i in [1,2,3]) { let x = 1; foo(x); }", None),
+        ("let x; x = 0;", None),
+        ("switch (a) { case 0: let x; x = 0; }", None),
+        ("(function() { let x; x = 1; })();", None),
+        ("let {a = 0, b} = obj; b = 0; foo(a, b);", None),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}; b = 3;", None),
+        ("let a, b; ({a = 0, b} = obj); foo(a, b);", None),
+        ("let a, b; [a, b] = [1, 2];", None),
+        ("let {a, b} = obj; foo(a, b);", Some(json!([{ "destructuring": "all" }]))),
+        ("let a, b; ({a, b} = obj); foo(a, b);", Some(json!([{ "destructuring": "all" }]))),
+        ("let x; foo(x); x = 0;", None),
+        ("let x; function foo() { bar(x); } x = 0;", None),
+        ("let a = 1, b = 2; foo(a, b);", None),
+        ("let a = 1, b = 2; b = 3; foo(a, b);", None),
+        ("let a; ({a} = obj);", None),
+        ("var a; let b; [a, b] = [1, 2];", None),
+        ("class C { static { let a = 1; foo(a); } }", None),
+        ("function foo() { let a = 1; return a; }", None),
+        ("export let a = 1;", None),
+        ("let x: number = 1; foo(x);", None),
+    ];
+
+    let fix = vec![
+        ("let x = 1; foo(x);", "const x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", "for (const i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", "for (const x of [1,2,3]) { foo(x); }", None),
+        ("let a = 1, b = 2; foo(a, b);", "const a = 1, b = 2; foo(a, b);", None),
+        ("let a = 1, b = 2; b = 3; foo(a, b);", "let a = 1, b = 2; b = 3; foo(a, b);", None),
+        ("let x; x = 0;", "let x; x = 0;", None),
+        ("let {a, b} = obj; foo(a, b);", "const {a, b} = obj; foo(a, b);", None),
+        ("let {a, b} = obj; b = 0;", "let {a, b} = obj; b = 0;", None),
+        ("let a = 1, b; foo(a);", "let a = 1, b; foo(a);", None),
+        ("export let a = 1;", "export const a = 1;", None),
+    ];
+
+    Tester::new(PreferConst::NAME, PreferConst::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,305 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    module_record::RequestedModule,
+    rule::Rule,
+    utils::{ImportType, PackageJson, package_name, path_matches_glob},
+};
+
+fn missing_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies. Run 'npm i -S {package_name}' to add it"
+    ))
+    .with_label(span)
+}
+
+fn dev_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies, not devDependencies."
+    ))
+    .with_label(span)
+}
+
+fn optional_dependency_diagnostic(span: Span, package_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "'{package_name}' should be listed in the project's dependencies, not optionalDependencies."
+    ))
+    .with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-extraneous-dependencies.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies(Box<NoExtraneousDependenciesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependenciesConfig {
+    dev_dependencies: AllowedIn,
+    optional_dependencies: AllowedIn,
+    peer_dependencies: AllowedIn,
+    bundled_dependencies: AllowedIn,
+    include_internal: bool,
+    include_types: bool,
+}
+
+impl std::ops::Deref for NoExtraneousDependencies {
+    type Target = NoExtraneousDependenciesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Which files may import a kind of dependency.
+#[derive(Debug, Default, Clone)]
+enum AllowedIn {
+    #[default]
+    All,
+    Nowhere,
+    /// Files matching any of these globs.
+    Files(Vec<CompactStr>),
+}
+
+impl AllowedIn {
+    fn from_configuration(value: Option<&Value>) -> Self {
+        match value {
+            Some(Value::Bool(false)) => Self::Nowhere,
+            Some(Value::Array(globs)) => {
+                Self::Files(globs.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+            }
+            _ => Self::All,
+        }
+    }
+
+    fn allows(&self, path: &Path) -> bool {
+        match self {
+            Self::All => true,
+            Self::Nowhere => false,
+            Self::Files(globs) => globs.iter().any(|glob| path_matches_glob(glob, path)),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids the import of external modules that are not declared in the
+    /// `dependencies`, `devDependencies`, `optionalDependencies`,
+    /// `peerDependencies` or `bundledDependencies` of the nearest
+    /// `package.json`.
+    ///
+    /// Modules that can't be resolved are ignored, they are reported by
+    /// `import/no-unresolved`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A package that isn't declared as a dependency may be installed by
+    /// accident, e.g. as a dependency of another package, and is missing as
+    /// soon as the project is installed somewhere else, or published.
+    ///
+    /// ### Examples
+    ///
+    /// Given a `package.json` with `lodash` in `dependencies` and `jest` in
+    /// `devDependencies`, and `{ "devDependencies": false }`:
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import leftPad from 'left-pad';
+    /// import { describe } from 'jest';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import lodash from 'lodash';
+    /// import fs from 'fs';
+    /// import foo from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### devDependencies, optionalDependencies, peerDependencies, bundledDependencies
+    ///
+    /// `{ type: boolean | string[], default: true }`
+    ///
+    /// Whether packages of this kind of dependency may be imported. An array of
+    /// globs allows them only in matching files, e.g.
+    /// `{ "devDependencies": ["**/*.test.js", "**/*.spec.js"] }`.
+    ///
+    /// #### includeInternal
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also check bare specifiers resolved outside of `node_modules`.
+    ///
+    /// #### includeTypes
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also check type-only imports.
+    NoExtraneousDependencies,
+    import,
+    pedantic
+);
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let get = |name: &str| config.and_then(|config| config.get(name));
+        let get_bool = |name: &str| get(name).and_then(Value::as_bool).unwrap_or(false);
+
+        Self(Box::new(NoExtraneousDependenciesConfig {
+            dev_dependencies: AllowedIn::from_configuration(get("devDependencies")),
+            optional_dependencies: AllowedIn::from_configuration(get("optionalDependencies")),
+            peer_dependencies: AllowedIn::from_configuration(get("peerDependencies")),
+            bundled_dependencies: AllowedIn::from_configuration(get("bundledDependencies")),
+            include_internal: get_bool("includeInternal"),
+            include_types: get_bool("includeTypes"),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        let path = ctx.file_path();
+        let Some(package_json) = PackageJson::find(path) else {
+            return;
+        };
+
+        let mut requests = module_record
+            .requested_modules
+            .iter()
+            .flat_map(|(specifier, requests)| {
+                requests.iter().map(move |request| (specifier, request))
+            })
+            .collect::<Vec<_>>();
+        requests.sort_unstable_by_key(|(_, request)| request.span);
+
+        for (specifier, request) in requests {
+            if !self.include_types && Self::is_type_import(request, ctx) {
+                continue;
+            }
+            let Some(Some(resolved_path)) = module_record.resolved_requested_modules.get(specifier)
+            else {
+                continue;
+            };
+            match ImportType::new(specifier, Some(resolved_path)) {
+                ImportType::External => {}
+                ImportType::Internal if self.include_internal => {}
+                _ => continue,
+            }
+            let Some(name) = package_name(specifier) else {
+                continue;
+            };
+
+            if package_json.dependencies.contains(name)
+                || (package_json.bundled_dependencies.contains(name)
+                    && self.bundled_dependencies.allows(path))
+                || (package_json.peer_dependencies.contains(name)
+                    && self.peer_dependencies.allows(path))
+            {
+                continue;
+            }
+            if package_json.dev_dependencies.contains(name) {
+                if !self.dev_dependencies.allows(path) {
+                    ctx.diagnostic(dev_dependency_diagnostic(request.span, name));
+                }
+                continue;
+            }
+            if package_json.optional_dependencies.contains(name) {
+                if !self.optional_dependencies.allows(path) {
+                    ctx.diagnostic(optional_dependency_diagnostic(request.span, name));
+                }
+                continue;
+            }
+            ctx.diagnostic(missing_dependency_diagnostic(request.span, name));
+        }
+    }
+}
+
+impl NoExtraneousDependencies {
+    /// `import type { Foo } from 'foo'`, or an import whose specifiers are all `type`, like
+    /// `import { type Foo } from 'foo'`.
+    fn is_type_import(request: &RequestedModule, ctx: &LintContext<'_>) -> bool {
+        if request.is_type {
+            return true;
+        }
+        let mut entries = ctx
+            .module_record()
+            .import_entries
+            .iter()
+            .filter(|entry| entry.statement_span == request.statement_span)
+            .peekable();
+        entries.peek().is_some() && entries.all(|entry| entry.is_type)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("import 'acorn'", None),
+        ("import acorn from 'acorn'", None),
+        ("import { foo } from '@org/package'", None),
+        ("import foo from '@org/package/internal'", None),
+        ("export { foo } from 'acorn'", None),
+        ("import fs from 'fs'", None),
+        ("import test from 'node:test'", None),
+        ("import foo from '../foo'", None),
+        ("import foo from './foo'", None),
+        ("import chai from 'chai'", None),
+        ("import $ from 'jquery/dist/jquery'", None),
+        ("import React from 'react'", None),
+        ("import a from 'a'", None),
+        ("import foo from 'not-installed'", None),
+        ("var foo = require('left-pad')", None),
+        (
+            "import chai from 'chai'",
+            Some(json!([{ "devDependencies": ["**/extraneous-dependencies/*.js"] }])),
+        ),
+        ("import chai from 'chai'", Some(json!([{ "devDependencies": ["**/*.js"] }]))),
+        ("import $ from 'jquery/dist/jquery'", Some(json!([{ "optionalDependencies": true }]))),
+    ];
+
+    let fail = vec![
+        ("import leftPad from 'left-pad'", None),
+        ("import 'not-a-dependency'", None),
+        ("import foo from '@org/not-a-dependency'", None),
+        ("import foo from '@generated/foo'", None),
+        ("export { foo } from 'es6-module'", None),
+        ("import a from 'a'; import leftPad from 'left-pad'; import b from 'esm-package'", None),
+        ("import chai from 'chai'", Some(json!([{ "devDependencies": false }]))),
+        ("import chai from 'chai'", Some(json!([{ "devDependencies": ["**/*.test.js"] }]))),
+        ("import $ from 'jquery/dist/jquery'", Some(json!([{ "optionalDependencies": false }]))),
+        ("import React from 'react'", Some(json!([{ "peerDependencies": false }]))),
+        ("import a from 'a'", Some(json!([{ "bundledDependencies": false }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .change_rule_path("extraneous-dependencies/index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+
+    let pass = vec![
+        ("import type { Foo } from 'left-pad'", None),
+        ("import { type Foo } from 'left-pad'", None),
+        ("import type { Foo } from 'acorn'", Some(json!([{ "includeTypes": true }]))),
+    ];
+
+    let fail = vec![
+        ("import type { Foo } from 'left-pad'", Some(json!([{ "includeTypes": true }]))),
+        ("import { type Foo } from 'left-pad'", Some(json!([{ "includeTypes": true }]))),
+        ("import { type Foo, bar } from 'left-pad'", None),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, NoExtraneousDependencies::PLUGIN, pass, fail)
+        .change_rule_path("extraneous-dependencies/index.ts")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("typescript")
+        .test_and_snapshot();
+}
@@ -0,0 +1,267 @@
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_internal_modules_diagnostic(span: Span, specifier: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Reaching to \"{specifier}\" is not allowed."))
+        .with_help("Import the module through the entry point of its package or directory.")
+        .with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-internal-modules.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoInternalModules(Box<NoInternalModulesConfig>);
+
+#[derive(Debug, Clone)]
+pub enum NoInternalModulesConfig {
+    /// Any reaching import is reported, unless it matches one of the globs.
+    Allow(Vec<CompactStr>),
+    /// Only imports matching one of the globs are reported.
+    Forbid(Vec<CompactStr>),
+}
+
+impl Default for NoInternalModulesConfig {
+    fn default() -> Self {
+        Self::Allow(vec![])
+    }
+}
+
+impl std::ops::Deref for NoInternalModules {
+    type Target = NoInternalModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbids importing the submodules of other modules, e.g.
+    /// `lodash/fp/map` or `./components/Button/styles`.
+    ///
+    /// An import reaches into another module when it has more than one path
+    /// segment after `.` and `..` segments are resolved, not counting the
+    /// scope of a scoped package. Imports that can't be resolved are ignored.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Submodules are implementation details of a package or directory. Code
+    /// that imports them breaks as soon as they are moved, and bypasses the
+    /// public interface of the module.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { settings } from './app/index'; // Reaching to "./app/index" is not allowed
+    /// import userReducer from './reducer/user'; // Reaching to "./reducer/user" is not allowed
+    /// import configureStore from './redux/configureStore'; // Reaching to "./redux/configureStore" is not allowed
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import 'source-map-support/register';
+    /// import { settings } from '../app';
+    /// import getUser from '../actions/getUser';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// Only one of `allow` and `forbid` may be set.
+    ///
+    /// #### allow
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Globs of imports that may reach into other modules, e.g.
+    /// `["source-map-support/*", "**/actions/*"]`. They are matched against the
+    /// import path and the resolved path.
+    ///
+    /// #### forbid
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Globs of imports that may not reach into other modules. All other
+    /// imports are allowed.
+    NoInternalModules,
+    import,
+    restriction
+);
+
+impl Rule for NoInternalModules {
+    fn from_configuration(value: Value) -> Self {
+        let globs = |name: &str| {
+            value.get(0).and_then(|config| config.get(name)).and_then(Value::as_array).map(
+                |globs| {
+                    globs.iter().filter_map(Value::as_str).map(CompactStr::from).collect::<Vec<_>>()
+                },
+            )
+        };
+
+        let config = if let Some(forbid) = globs("forbid") {
+            NoInternalModulesConfig::Forbid(forbid)
+        } else {
+            NoInternalModulesConfig::Allow(globs("allow").unwrap_or_default())
+        };
+        Self(Box::new(config))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        let mut requests = module_record
+            .requested_modules
+            .iter()
+            .flat_map(|(specifier, requests)| {
+                requests.iter().map(move |request| (specifier, request.span))
+            })
+            .collect::<Vec<_>>();
+        requests.sort_unstable_by_key(|(_, span)| *span);
+
+        for (specifier, span) in requests {
+            let resolved_path = module_record
+                .resolved_requested_modules
+                .get(specifier)
+                .and_then(|path| path.as_ref())
+                .map(|path| path.to_string_lossy().cow_replace('\\', "/").into_owned());
+            let steps = to_steps(specifier);
+            let joined_steps = steps.join("/");
+            let matches_any = |globs: &[CompactStr]| {
+                globs.iter().any(|glob| {
+                    glob_match(glob.as_str(), &joined_steps)
+                        || glob_match(glob.as_str(), format!("/{joined_steps}"))
+                        || resolved_path
+                            .as_ref()
+                            .is_some_and(|path| glob_match(glob.as_str(), path))
+                })
+            };
+
+            let is_violation = match &**self {
+                NoInternalModulesConfig::Forbid(globs) => matches_any(globs),
+                NoInternalModulesConfig::Allow(globs) => {
+                    let non_scope_steps =
+                        steps.iter().filter(|step| !step.starts_with('@')).count();
+                    non_scope_steps > 1 && resolved_path.is_some() && !matches_any(globs)
+                }
+            };
+            if is_violation {
+                ctx.diagnostic(no_internal_modules_diagnostic(span, specifier));
+            }
+        }
+    }
+}
+
+/// Path segments of `specifier`, with `.` and `..` segments resolved.
+fn to_steps(specifier: &str) -> Vec<&str> {
+    let mut steps = vec![];
+    for step in specifier.split(['/', '\\']) {
+        match step {
+            "" | "." => {}
+            ".." => {
+                steps.pop();
+            }
+            step => steps.push(step),
+        }
+    }
+    steps
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let case = |source: &'static str, path: &str, config: Option<Value>| {
+        (source, config, None, Some(PathBuf::from("internal-modules").join(path)))
+    };
+
+    let pass = vec![
+        case("import a from './plugin2'", "plugins/plugin.js", None),
+        case("const a = require('./plugin2')", "plugins/plugin.js", None),
+        case("const a = require('./plugin2/')", "plugins/plugin.js", None),
+        case(
+            "const dynamic = './plugin2/' + 'internal'; const a = require(dynamic)",
+            "plugins/plugin.js",
+            None,
+        ),
+        case("import b from './internal.js'", "plugins/plugin2/index.js", None),
+        case("import get from 'lodash.get'", "plugins/plugin2/index.js", None),
+        case("import b from '@org/package'", "plugins/plugin2/internal.js", None),
+        case(
+            "import b from '../../api/service'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "allow": ["**/api/*"] }])),
+        ),
+        case(
+            "import 'jquery/dist/jquery'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "allow": ["jquery/dist/*"] }])),
+        ),
+        case("import b from './does-not-exist/internal'", "plugins/plugin.js", None),
+        case("export * from './plugin2'", "plugins/plugin.js", None),
+        case("export { a } from './plugin2'", "plugins/plugin.js", None),
+        case("export * from 'lodash.get'", "plugins/plugin.js", None),
+        case(
+            "export * from '../../api/service'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "allow": ["**/api/*"] }])),
+        ),
+        case(
+            "import a from './plugin2/app/index'",
+            "plugins/plugin.js",
+            Some(json!([{ "allow": ["*/app/*"] }])),
+        ),
+        case(
+            "import a from './plugin2/internal'",
+            "plugins/plugin.js",
+            Some(json!([{ "forbid": ["**/api/*"] }])),
+        ),
+        case(
+            "import b from '../../api/service'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "forbid": ["**/internal"] }])),
+        ),
+        case(
+            "import '@org/package'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "forbid": ["@org/package/*"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        case("import a from './plugin2/index.js'", "plugins/plugin.js", None),
+        case("import a from './plugin2/app/index'", "plugins/plugin.js", None),
+        case("import a from './plugin2/internal'", "plugins/plugin.js", None),
+        case("import b from '../../api/service'", "plugins/plugin2/internal.js", None),
+        case("import '@org/package/internal'", "plugins/plugin2/internal.js", None),
+        case("import get from 'jquery/dist/jquery'", "plugins/plugin2/internal.js", None),
+        case("export * from './plugin2/internal'", "plugins/plugin.js", None),
+        case("export { a } from './plugin2/app/index'", "plugins/plugin.js", None),
+        case(
+            "import a from './plugin2/internal'",
+            "plugins/plugin.js",
+            Some(json!([{ "allow": ["*/app/*"] }])),
+        ),
+        case(
+            "import a from './plugin2/internal'",
+            "plugins/plugin.js",
+            Some(json!([{ "forbid": ["**/internal"] }])),
+        ),
+        case(
+            "import '@org/package'",
+            "plugins/plugin2/internal.js",
+            Some(json!([{ "forbid": ["@org/**"] }])),
+        ),
+    ];
+
+    Tester::new(NoInternalModules::NAME, NoInternalModules::PLUGIN, pass, fail)
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
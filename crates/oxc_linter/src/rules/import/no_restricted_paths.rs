@@ -0,0 +1,328 @@
+use std::path::{Component, Path, PathBuf};
+
+use fast_glob::glob_match;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_restricted_paths_diagnostic(
+    span: Span,
+    specifier: &str,
+    message: Option<&str>,
+) -> OxcDiagnostic {
+    let message = message.map(|message| format!(" {message}")).unwrap_or_default();
+    OxcDiagnostic::warn(format!(
+        "Unexpected path \"{specifier}\" imported in restricted zone.{message}"
+    ))
+    .with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-restricted-paths.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPaths(Box<NoRestrictedPathsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedPathsConfig {
+    zones: Vec<Zone>,
+}
+
+impl std::ops::Deref for NoRestrictedPaths {
+    type Target = NoRestrictedPathsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Files in `target` may not import modules in `from`, unless they are in `except`.
+#[derive(Debug, Clone)]
+struct Zone {
+    target: Vec<PathPattern>,
+    from: Vec<PathPattern>,
+    except: Vec<PathPattern>,
+    message: Option<CompactStr>,
+}
+
+/// A glob, or a directory or file that matches everything inside of it.
+#[derive(Debug, Clone)]
+enum PathPattern {
+    Glob(CompactStr),
+    Path(PathBuf),
+}
+
+impl PathPattern {
+    fn new(pattern: &str, base_path: &Path) -> Self {
+        if pattern.contains(['*', '?', '[', '{', '!']) {
+            Self::Glob(CompactStr::from(base_path.join(pattern).to_string_lossy().as_ref()))
+        } else {
+            Self::Path(normalize(&base_path.join(pattern)))
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            Self::Glob(glob) => glob_match(glob.as_str(), path.to_string_lossy().as_ref()),
+            Self::Path(dir) => path.starts_with(dir),
+        }
+    }
+}
+
+/// Resolves `.` and `..` components without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Restricts which files can be imported in a given folder.
+    ///
+    /// Each zone forbids files matching `target` to import modules matching
+    /// `from`. Both are paths relative to `basePath`, which match every file
+    /// inside of them, or globs.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some parts of a project must not depend on others, e.g. client code
+    /// must not import server code, and features should only be imported
+    /// through their public entry points.
+    ///
+    /// ### Examples
+    ///
+    /// Given `{ "zones": [{ "target": "./client", "from": "./server" }] }`, in
+    /// `client/app.js`:
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import db from '../server/db';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import api from './api';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### zones
+    ///
+    /// `{ type: { target: string | string[], from: string | string[], except?: string[], message?: string }[], default: [] }`
+    ///
+    /// The restricted zones. Modules matching `except` may still be imported.
+    /// If `from` is a path, `except` is relative to it, otherwise `except`
+    /// holds globs. `message` is appended to the diagnostic.
+    ///
+    /// #### basePath
+    ///
+    /// `{ type: string, default: "<current working directory>" }`
+    ///
+    /// The directory `target`, `from` and glob patterns are relative to.
+    NoRestrictedPaths,
+    import,
+    restriction
+);
+
+impl Rule for NoRestrictedPaths {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let cwd = std::env::current_dir().unwrap_or_default();
+        let base_path = config
+            .get("basePath")
+            .and_then(Value::as_str)
+            .map_or_else(|| cwd.clone(), |base_path| cwd.join(base_path));
+
+        let patterns = |value: Option<&Value>, base_path: &Path| -> Vec<PathPattern> {
+            match value {
+                Some(Value::String(pattern)) => vec![PathPattern::new(pattern, base_path)],
+                Some(Value::Array(patterns)) => patterns
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|pattern| PathPattern::new(pattern, base_path))
+                    .collect(),
+                _ => vec![],
+            }
+        };
+
+        let zones = config
+            .get("zones")
+            .and_then(Value::as_array)
+            .map(|zones| {
+                zones
+                    .iter()
+                    .map(|zone| {
+                        let from = patterns(zone.get("from"), &base_path);
+                        // Exceptions of a single `from` path are relative to it.
+                        let except_base_path = match from.as_slice() {
+                            [PathPattern::Path(from)] => from.clone(),
+                            _ => base_path.clone(),
+                        };
+                        Zone {
+                            target: patterns(zone.get("target"), &base_path),
+                            from,
+                            except: patterns(zone.get("except"), &except_base_path),
+                            message: zone
+                                .get("message")
+                                .and_then(Value::as_str)
+                                .map(CompactStr::from),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoRestrictedPathsConfig { zones }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let path = ctx.file_path();
+        let zones = self
+            .zones
+            .iter()
+            .filter(|zone| zone.target.iter().any(|target| target.matches(path)))
+            .collect::<Vec<_>>();
+        if zones.is_empty() {
+            return;
+        }
+
+        let module_record = ctx.module_record();
+        let mut requests = module_record
+            .requested_modules
+            .iter()
+            .flat_map(|(specifier, requests)| {
+                requests.iter().map(move |request| (specifier, request.span))
+            })
+            .collect::<Vec<_>>();
+        requests.sort_unstable_by_key(|(_, span)| *span);
+
+        for (specifier, span) in requests {
+            let Some(Some(resolved_path)) = module_record.resolved_requested_modules.get(specifier)
+            else {
+                continue;
+            };
+            for zone in &zones {
+                if zone.from.iter().any(|from| from.matches(resolved_path))
+                    && !zone.except.iter().any(|except| except.matches(resolved_path))
+                {
+                    ctx.diagnostic(no_restricted_paths_diagnostic(
+                        span,
+                        specifier,
+                        zone.message.as_deref(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use std::path::PathBuf;
+
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let case = |source: &'static str, path: &str, config: Value| {
+        (source, Some(config), None, Some(PathBuf::from("restricted-paths").join(path)))
+    };
+
+    let pass = vec![
+        case(
+            r#"import a from "../client/a.js""#,
+            "server/b.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server", "from": "./fixtures/import/restricted-paths/other" }] }]),
+        ),
+        case(
+            r#"import a from "../client/a.js""#,
+            "server/b.js",
+            json!([{ "zones": [{ "target": "server", "from": "other" }], "basePath": "./fixtures/import/restricted-paths" }]),
+        ),
+        case(
+            r#"import a from "./a.js""#,
+            "server/one/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server/one", "from": "./fixtures/import/restricted-paths/server/two" }] }]),
+        ),
+        case(
+            r#"import a from "../two/a.js""#,
+            "server/one/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server/one", "from": "./fixtures/import/restricted-paths/server", "except": ["./two"] }] }]),
+        ),
+        case(
+            r#"import b from "../server/b.js""#,
+            "client/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server", "from": "./fixtures/import/restricted-paths/client" }] }]),
+        ),
+        case(
+            r#"import a from "../one/a.js""#,
+            "server/two/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server/two/**", "from": "./fixtures/import/restricted-paths/server/one/b.js" }] }]),
+        ),
+        case(
+            r#"import a from "../one/a.js""#,
+            "server/two/a.js",
+            json!([{ "zones": [{ "target": "**/server/two/*", "from": "**/server/one/*", "except": ["**/one/a.js"] }] }]),
+        ),
+        case(
+            r#"import a from "./does-not-exist.js""#,
+            "server/b.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server", "from": "./fixtures/import/restricted-paths" }] }]),
+        ),
+        case(r#"import a from "../client/a.js""#, "server/b.js", json!([{}])),
+    ];
+
+    let fail = vec![
+        case(
+            r#"import b from "../server/b.js""#,
+            "client/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/client", "from": "./fixtures/import/restricted-paths/server" }] }]),
+        ),
+        case(
+            r#"import b from "../server/b.js""#,
+            "client/a.js",
+            json!([{ "zones": [{ "target": "client", "from": "server" }], "basePath": "./fixtures/import/restricted-paths" }]),
+        ),
+        case(
+            r#"import a from "../client/a.js"; export { c } from "./c.js""#,
+            "server/b.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server", "from": ["./fixtures/import/restricted-paths/client", "./fixtures/import/restricted-paths/server/c.js"] }] }]),
+        ),
+        case(
+            r#"import a from "../three/a.js""#,
+            "server/one/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/server/one", "from": "./fixtures/import/restricted-paths/server", "except": ["./two"] }] }]),
+        ),
+        case(
+            r#"import b from "../server/b.js""#,
+            "client/a.js",
+            json!([{ "zones": [{ "target": "./fixtures/import/restricted-paths/client", "from": "./fixtures/import/restricted-paths/server", "message": "Custom message" }] }]),
+        ),
+        case(
+            r#"import a from "../one/b.js""#,
+            "server/two/a.js",
+            json!([{ "zones": [{ "target": "**/server/two/*", "from": "**/server/one/*", "except": ["**/one/a.js"] }] }]),
+        ),
+        case(
+            r#"import a from "../../client/one/a.js""#,
+            "server/one/a.js",
+            json!([{ "zones": [{ "target": ["./fixtures/import/restricted-paths/server/one", "./fixtures/import/restricted-paths/server/two"], "from": "./fixtures/import/restricted-paths/client" }] }]),
+        ),
+    ];
+
+    Tester::new(NoRestrictedPaths::NAME, NoRestrictedPaths::PLUGIN, pass, fail)
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,160 @@
+use lazy_regex::Regex;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, utils::is_builtin_module};
+
+fn no_unresolved_diagnostic(span: Span, module_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unable to resolve path to module '{module_name}'."))
+        .with_help("Check the module path for typos, or install the missing package.")
+        .with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    /// Module specifiers matching any of these patterns are not checked.
+    ignore: Vec<Regex>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local
+    /// filesystem.
+    ///
+    /// Modules are resolved the same way as for every other cross-module rule,
+    /// with Node.js resolution, TypeScript path aliases from `tsconfig.json`
+    /// and the file extensions supported by Oxlint. Node.js builtin modules are
+    /// always considered resolved.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An import that can't be resolved either fails at runtime or at build
+    /// time. It's usually a typo, a file that was moved, or a package that
+    /// isn't installed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import x from './does-not-exist';
+    /// export { y } from './neither-does-this';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import fs from 'fs';
+    /// import x from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignore
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Regular expressions of module specifiers to skip, e.g. `["\\.png$"]`.
+    NoUnresolved,
+    import,
+    suspicious
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: Value) -> Self {
+        let ignore = value
+            .get(0)
+            .and_then(|config| config.get("ignore"))
+            .and_then(Value::as_array)
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|p| Regex::new(p).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+
+        let mut unresolved = module_record
+            .resolved_requested_modules
+            .iter()
+            .filter(|(specifier, resolved_path)| {
+                resolved_path.is_none()
+                    && !is_builtin_module(specifier)
+                    && !self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            })
+            .filter_map(|(specifier, _)| {
+                module_record.requested_modules.get(specifier).map(|requests| (specifier, requests))
+            })
+            .flat_map(|(specifier, requests)| {
+                requests.iter().map(move |request| (request.span, specifier))
+            })
+            .collect::<Vec<_>>();
+        unresolved.sort_unstable_by_key(|(span, _)| *span);
+
+        for (span, specifier) in unresolved {
+            ctx.diagnostic(no_unresolved_diagnostic(span, specifier));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (r"import foo from './bar'", None),
+        (r"import foo from './bar.js'", None),
+        (r"import { foo } from './named-exports'", None),
+        (r"import * as ns from './named-exports'", None),
+        (r"import './malformed.js'", None),
+        (r"import data from './data.json'", None),
+        (r"import fs from 'fs'", None),
+        (r"import fs from 'fs/promises'", None),
+        (r"import test from 'node:test'", None),
+        (r"import acorn from 'acorn'", None),
+        (r"import foo from '@org/package'", None),
+        (r"export { foo } from './bar'", None),
+        (r"export * from './bar'", None),
+        (r"import foo from './jsx/MyCoolComponent'", None),
+        (r"var foo = require('./does-not-exist')", None),
+        (r"import('./does-not-exist')", None),
+        (r"import image from './image.png'", Some(json!([{ "ignore": ["\\.png$"] }]))),
+        (r"import foo from 'virtual:foo'", Some(json!([{ "ignore": ["^virtual:"] }]))),
+    ];
+
+    let fail = vec![
+        (r"import reallyfake from './reallyfake/module'", None),
+        (r"import bar from './baz'", None),
+        (r"import bar from './baz'; import foo from './empty-folder'", None),
+        (r"import { foo } from 'not-a-real-package'", None),
+        (r"export { foo } from './does-not-exist'", None),
+        (r"export * from './does-not-exist'", None),
+        (r"import image from './image.png'", Some(json!([{ "ignore": ["\\.gif$"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, NoUnresolved::PLUGIN, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,289 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    module_record::{ExportExportName, ImportedNames},
+    rule::Rule,
+    utils::{PackageJson, path_matches_glob},
+};
+
+fn missing_exports_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("No exports found").with_label(span)
+}
+
+fn unused_export_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("exported declaration '{name}' not used within other modules"))
+        .with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/no-unused-modules.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModulesConfig {
+    missing_exports: bool,
+    unused_exports: bool,
+    /// Only files matching these globs are checked. All files are checked if empty.
+    src: Vec<CompactStr>,
+    ignore_exports: Vec<CompactStr>,
+    ignore_unused_type_exports: bool,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports modules without any exports, or exports that are not imported
+    /// by any other module.
+    ///
+    /// Only the modules being linted are considered as importers, so run it on
+    /// the whole project. Exports of the `main`, `bin` and `browser` entry
+    /// points of a non-private package are never reported, as they are used
+    /// by other packages.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused exports are dead code that is easily missed, as the module
+    /// itself looks fine. A module without exports usually is an entry point,
+    /// or only runs side effects.
+    ///
+    /// ### Examples
+    ///
+    /// Given `{ "unusedExports": true }`, and another module
+    /// `import { used } from './module'`:
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// export const unused = 1;
+    /// export default function () {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// export const used = 1;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// At least one of `missingExports` and `unusedExports` must be enabled.
+    ///
+    /// #### missingExports
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Report modules without any exports.
+    ///
+    /// #### unusedExports
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Report exports that no other module imports.
+    ///
+    /// #### src
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Globs of the files to check. All files are checked by default.
+    ///
+    /// #### ignoreExports
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Globs of files whose exports are never reported.
+    ///
+    /// #### ignoreUnusedTypeExports
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Don't report unused type exports.
+    NoUnusedModules,
+    import,
+    restriction
+);
+
+impl Rule for NoUnusedModules {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config.and_then(|config| config.get(name)).and_then(Value::as_bool).unwrap_or(false)
+        };
+        let get_globs = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(Value::as_array)
+                .map(|globs| globs.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+                .unwrap_or_default()
+        };
+
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: get_bool("missingExports"),
+            unused_exports: get_bool("unusedExports"),
+            src: get_globs("src"),
+            ignore_exports: get_globs("ignoreExports"),
+            ignore_unused_type_exports: get_bool("ignoreUnusedTypeExports"),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let path = ctx.file_path();
+        if !self.src.is_empty() && !self.src.iter().any(|glob| path_matches_glob(glob, path)) {
+            return;
+        }
+        if self.ignore_exports.iter().any(|glob| path_matches_glob(glob, path)) {
+            return;
+        }
+
+        let module_record = ctx.module_record();
+        if self.missing_exports
+            && module_record.local_export_entries.is_empty()
+            && module_record.indirect_export_entries.is_empty()
+            && module_record.star_export_entries.is_empty()
+        {
+            ctx.diagnostic(missing_exports_diagnostic(Span::new(0, 0)));
+        }
+
+        if !self.unused_exports || is_entry_point(ctx) {
+            return;
+        }
+
+        let mut used = ImportedNames::default();
+        for imported in module_record.importers.read().unwrap().values() {
+            used.extend(imported.clone());
+        }
+        let mut entries = module_record
+            .local_export_entries
+            .iter()
+            .chain(&module_record.indirect_export_entries)
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|entry| entry.span);
+
+        for entry in entries {
+            if self.ignore_unused_type_exports && entry.is_type {
+                continue;
+            }
+            let (name, span) = match &entry.export_name {
+                ExportExportName::Name(name) => (name.name.as_str(), name.span),
+                ExportExportName::Default(span) => ("default", *span),
+                ExportExportName::Null => continue,
+            };
+            if !used.contains(name) {
+                ctx.diagnostic(unused_export_diagnostic(span, name));
+            }
+        }
+    }
+}
+
+/// Whether the file is an entry point of a package that can be used by other packages.
+fn is_entry_point(ctx: &LintContext<'_>) -> bool {
+    let path = ctx.file_path();
+    PackageJson::find(path).is_some_and(|package_json| {
+        !package_json.private
+            && package_json.entry_points.iter().any(|entry_point| {
+                let entry_point =
+                    entry_point.canonicalize().unwrap_or_else(|_| entry_point.clone());
+                path == entry_point || path.with_extension("") == entry_point
+            })
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let missing_exports = json!([{ "missingExports": true }]);
+    let pass = vec![
+        ("export default function noOptions() {}", None),
+        ("export default () => 1", Some(missing_exports.clone())),
+        ("export const a = 1", Some(missing_exports.clone())),
+        ("const a = 1; export { a }", Some(missing_exports.clone())),
+        ("function a() { return true }; export { a }", Some(missing_exports.clone())),
+        ("const a = 1; const b = 2; export { a, b }", Some(missing_exports.clone())),
+        ("export * from './file-n'", Some(missing_exports.clone())),
+        ("export { n0 } from './file-n'", Some(missing_exports.clone())),
+        ("const a = 1", Some(json!([{ "missingExports": true, "src": ["**/file-a.js"] }]))),
+        (
+            "const a = 1",
+            Some(json!([{ "missingExports": true, "ignoreExports": ["**/file-0.js"] }])),
+        ),
+    ];
+    let fail = vec![
+        ("const a = 1", Some(missing_exports.clone())),
+        ("/* const a = 1 */", Some(missing_exports)),
+    ];
+
+    Tester::new(NoUnusedModules::NAME, NoUnusedModules::PLUGIN, pass, fail)
+        .change_rule_path("no-unused-modules/file-0.js")
+        .with_import_plugin(true)
+        .with_snapshot_suffix("missing-exports")
+        .test_and_snapshot();
+
+    let unused_exports = json!([{ "unusedExports": true }]);
+    let pass = vec![
+        ("import { o2 } from './file-o'; export default () => 12", "file-a.js"),
+        ("export const b = 2", "file-b.js"),
+        ("const c1 = 3; function c2() { return 3 }; export { c1, c2 }", "file-c.js"),
+        ("export function d() { return 4 }", "file-d.js"),
+        ("const e0 = 5; export { e0 as e }", "file-e.js"),
+        (
+            "const l0 = 5; const l = 10; export { l0 as l1, l }; export default () => {}",
+            "file-l.js",
+        ),
+        (
+            "const o0 = 0; const o1 = 1; export { o0, o1 as o2 }; export default () => {}",
+            "file-o.js",
+        ),
+        ("export const n0 = 'n0'; export const n1 = 42", "file-n.js"),
+        ("export const p = 1", "file-p.js"),
+        ("export const q = 1", "file-q.js"),
+        ("const a = 1", "file-g.js"),
+        ("export const bin = 'bin'", "bin.js"),
+        ("export const main = 'main'", "main/index.js"),
+    ];
+    let fail = vec![
+        ("import eslint from 'eslint'; export const z = 1", "file-0.js"),
+        ("export default () => 13", "file-f.js"),
+        ("export const g = 2", "file-g.js"),
+        (
+            "const h1 = 3; function h2() { return 3 }; const h3 = true; export { h1, h2, h3 }",
+            "file-h.js",
+        ),
+        ("export function j() { return 4 }", "file-j.js"),
+        ("const k0 = 5; export { k0 as k }", "file-k.js"),
+        ("export { default as f } from './file-f'", "file-s.js"),
+        ("export const n0 = 'n0'; export default () => {}", "file-n.js"),
+        ("export const privatePkg = 'privatePkg'", "privatePkg/index.js"),
+    ];
+
+    let to_test_cases = |cases: Vec<(&'static str, &str)>| {
+        cases
+            .into_iter()
+            .map(|(source, file)| {
+                let path = std::path::PathBuf::from("no-unused-modules").join(file);
+                (source, Some(unused_exports.clone()), None, Some(path))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    Tester::new(
+        NoUnusedModules::NAME,
+        NoUnusedModules::PLUGIN,
+        to_test_cases(pass),
+        to_test_cases(fail),
+    )
+    .with_import_plugin(true)
+    .with_module_graph_paths(&["no-unused-modules/file-0.js", "no-unused-modules/file-p.js"])
+    .with_snapshot_suffix("unused-exports")
+    .test_and_snapshot();
+}
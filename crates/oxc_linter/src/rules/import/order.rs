@@ -0,0 +1,1264 @@
+use std::cmp::{Ordering, Reverse};
+
+use cow_utils::CowUtils;
+use fast_glob::glob_match;
+use oxc_ast::ast::{Expression, Statement, TSModuleReference};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, utils::ImportType};
+
+fn out_of_order_diagnostic(
+    span: Span,
+    import: &ImportedModule,
+    order: &str,
+    other: &ImportedModule,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "`{}` {} should occur {order} {} of `{}`",
+        import.name,
+        import.description(),
+        other.description(),
+        other.name
+    ))
+    .with_label(span)
+}
+
+fn missing_newline_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_label(span)
+}
+
+fn newline_in_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group").with_label(span)
+}
+
+fn newline_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups").with_label(span)
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/order.md>
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Groups in the order they should appear in. Imports of groups that are not listed all go
+    /// after the listed groups.
+    groups: Vec<Vec<ImportGroup>>,
+    path_groups: Vec<PathGroup>,
+    /// Import groups that `path_groups` do not apply to.
+    path_groups_excluded_import_types: Vec<ImportGroup>,
+    distinct_group: bool,
+    newlines_between: NewlinesBetween,
+    alphabetize: Alphabetize,
+    warn_on_unassigned_imports: bool,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                vec![ImportGroup::Builtin],
+                vec![ImportGroup::External],
+                vec![ImportGroup::Parent],
+                vec![ImportGroup::Sibling],
+                vec![ImportGroup::Index],
+            ],
+            path_groups: vec![],
+            path_groups_excluded_import_types: vec![
+                ImportGroup::Builtin,
+                ImportGroup::External,
+                ImportGroup::Object,
+            ],
+            distinct_group: true,
+            newlines_between: NewlinesBetween::Ignore,
+            alphabetize: Alphabetize::default(),
+            warn_on_unassigned_imports: false,
+        }
+    }
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportGroup {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    /// `import log = console.log`
+    Object,
+    /// `import type { Foo } from 'foo'`
+    Type,
+    Unknown,
+}
+
+impl TryFrom<&str> for ImportGroup {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "builtin" => Ok(Self::Builtin),
+            "external" => Ok(Self::External),
+            "internal" => Ok(Self::Internal),
+            "parent" => Ok(Self::Parent),
+            "sibling" => Ok(Self::Sibling),
+            "index" => Ok(Self::Index),
+            "object" => Ok(Self::Object),
+            "type" => Ok(Self::Type),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<ImportType> for ImportGroup {
+    fn from(value: ImportType) -> Self {
+        match value {
+            ImportType::Builtin => Self::Builtin,
+            ImportType::External => Self::External,
+            ImportType::Internal => Self::Internal,
+            ImportType::Parent => Self::Parent,
+            ImportType::Sibling => Self::Sibling,
+            ImportType::Index => Self::Index,
+            ImportType::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PathGroup {
+    pattern: CompactStr,
+    group: ImportGroup,
+    /// Negative before the group, positive after it. Path groups with the same group and
+    /// position are ordered the way they are configured.
+    position: i32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SortOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    fn from_configuration(value: Option<&Value>) -> Self {
+        match value.and_then(Value::as_str) {
+            Some("asc") => Self::Asc,
+            Some("desc") => Self::Desc,
+            _ => Self::Ignore,
+        }
+    }
+
+    fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ignore => Ordering::Equal,
+            Self::Asc => ordering,
+            Self::Desc => ordering.reverse(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Alphabetize {
+    order: SortOrder,
+    order_import_kind: SortOrder,
+    case_insensitive: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` declarations, and of
+    /// `require()` calls at the top level of a module.
+    ///
+    /// Imports are ordered by groups, based on the kind of module they import:
+    ///
+    /// ```javascript
+    /// // 1. Node.js builtin modules
+    /// import fs from 'fs';
+    /// import path from 'node:path';
+    /// // 2. "external" modules
+    /// import _ from 'lodash';
+    /// import chalk from 'chalk';
+    /// // 3. "internal" modules, resolved outside of `node_modules`, e.g. by path aliases
+    /// import foo from 'src/foo';
+    /// // 4. modules from a "parent" directory
+    /// import foo from '../foo';
+    /// import qux from '../../foo/qux';
+    /// // 5. "sibling" modules from the same or a sibling's directory
+    /// import bar from './bar';
+    /// import baz from './bar/baz';
+    /// // 6. "index" of the current directory
+    /// import main from './';
+    /// // 7. "object"-imports (only available in TypeScript)
+    /// import log = console.log;
+    /// // 8. "type" imports (only available in Flow and TypeScript)
+    /// import type { Foo } from 'foo';
+    /// ```
+    ///
+    /// Unassigned imports, like `import './polyfills'`, are ignored, as their
+    /// order may matter. `require()` calls are ordered after all `import`
+    /// declarations.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent import order makes it easier to see what a module depends
+    /// on, and avoids merge conflicts.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import _ from 'lodash';
+    /// import path from 'path'; // `path` import should occur before import of `lodash`
+    ///
+    /// import foo from './foo';
+    /// import bar from '../bar'; // `../bar` import should occur before import of `./foo`
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import path from 'path';
+    /// import _ from 'lodash';
+    /// import bar from '../bar';
+    /// import foo from './foo';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### groups
+    ///
+    /// `{ type: (string | string[])[], default: ["builtin", "external", "parent", "sibling", "index"] }`
+    ///
+    /// The order of the groups, out of `"builtin"`, `"external"`,
+    /// `"internal"`, `"unknown"`, `"parent"`, `"sibling"`, `"index"`,
+    /// `"object"` and `"type"`. Groups in a nested array are mixed together.
+    /// Omitted groups go together after all the listed groups.
+    ///
+    /// #### pathGroups
+    ///
+    /// `{ type: { pattern: string, group: string, position?: "before" | "after" }[], default: [] }`
+    ///
+    /// Imports whose specifier matches the glob `pattern` are put into `group`,
+    /// or right before or after it with `position`.
+    ///
+    /// #### pathGroupsExcludedImportTypes
+    ///
+    /// `{ type: string[], default: ["builtin", "external", "object"] }`
+    ///
+    /// Imports of these groups are never put into a path group.
+    ///
+    /// #### distinctGroup
+    ///
+    /// `{ type: boolean, default: true }`
+    ///
+    /// Whether path groups with a `position` are separate groups for
+    /// `newlines-between`.
+    ///
+    /// #### newlines-between
+    ///
+    /// `{ type: "ignore" | "always" | "always-and-inside-groups" | "never", default: "ignore" }`
+    ///
+    /// Whether groups are separated by empty lines. `"always"` also forbids
+    /// empty lines within a group, `"never"` forbids them altogether.
+    ///
+    /// #### alphabetize
+    ///
+    /// `{ type: { order?: "ignore" | "asc" | "desc", orderImportKind?: "ignore" | "asc" | "desc", caseInsensitive?: boolean }, default: { order: "ignore" } }`
+    ///
+    /// Sort the imports within each group by their specifier. `orderImportKind`
+    /// sorts imports of the same module by kind, e.g. `import type` before
+    /// `import`.
+    ///
+    /// #### warnOnUnassignedImports
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also order unassigned imports.
+    Order,
+    import,
+    style,
+    conditional_fix
+);
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let default = OrderConfig::default();
+        let parse_groups = |value: &Value| -> Vec<ImportGroup> {
+            match value {
+                Value::String(group) => {
+                    ImportGroup::try_from(group.as_str()).ok().into_iter().collect()
+                }
+                Value::Array(groups) => groups
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|group| ImportGroup::try_from(group).ok())
+                    .collect(),
+                _ => vec![],
+            }
+        };
+
+        let groups = config
+            .get("groups")
+            .and_then(Value::as_array)
+            .map_or(default.groups, |groups| groups.iter().map(parse_groups).collect());
+
+        let path_groups = config
+            .get("pathGroups")
+            .and_then(Value::as_array)
+            .map(|path_groups| path_groups_with_positions(path_groups))
+            .unwrap_or_default();
+
+        let path_groups_excluded_import_types = config
+            .get("pathGroupsExcludedImportTypes")
+            .map_or(default.path_groups_excluded_import_types, parse_groups);
+
+        let newlines_between = match config.get("newlines-between").and_then(Value::as_str) {
+            Some("always") => NewlinesBetween::Always,
+            Some("always-and-inside-groups") => NewlinesBetween::AlwaysAndInsideGroups,
+            Some("never") => NewlinesBetween::Never,
+            _ => NewlinesBetween::Ignore,
+        };
+
+        let alphabetize =
+            config.get("alphabetize").map_or_else(Alphabetize::default, |value| Alphabetize {
+                order: SortOrder::from_configuration(value.get("order")),
+                order_import_kind: SortOrder::from_configuration(value.get("orderImportKind")),
+                case_insensitive: value
+                    .get("caseInsensitive")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+            });
+
+        Self(Box::new(OrderConfig {
+            groups,
+            path_groups,
+            path_groups_excluded_import_types,
+            distinct_group: config
+                .get("distinctGroup")
+                .and_then(Value::as_bool)
+                .unwrap_or(default.distinct_group),
+            newlines_between,
+            alphabetize,
+            warn_on_unassigned_imports: config
+                .get("warnOnUnassignedImports")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let mut imported = self.collect_imports(ctx);
+        if imported.len() < 2 {
+            return;
+        }
+
+        if self.newlines_between != NewlinesBetween::Ignore {
+            self.check_newlines_between(&imported, ctx);
+        }
+        if self.alphabetize.order != SortOrder::Ignore {
+            self.alphabetize_ranks(&mut imported);
+        }
+        report_out_of_order(&imported, ctx);
+    }
+}
+
+/// Converts `position`s of path groups to ranks relative to their group.
+fn path_groups_with_positions(path_groups: &[Value]) -> Vec<PathGroup> {
+    let mut path_groups = path_groups
+        .iter()
+        .filter_map(|path_group| {
+            let pattern = path_group.get("pattern")?.as_str()?;
+            let group = ImportGroup::try_from(path_group.get("group")?.as_str()?).ok()?;
+            let position = match path_group.get("position").and_then(Value::as_str) {
+                Some("before") => -1,
+                Some("after") => 1,
+                _ => 0,
+            };
+            Some(PathGroup { pattern: CompactStr::from(pattern), group, position })
+        })
+        .collect::<Vec<_>>();
+
+    // The first path group after a group comes right after it, the last path group before a
+    // group comes right before it.
+    for i in 0..path_groups.len() {
+        let PathGroup { group, position, .. } = path_groups[i];
+        let same_position =
+            |other: &PathGroup| other.group == group && other.position.signum() == position;
+        let count = |path_groups: &[PathGroup]| {
+            let count = path_groups.iter().filter(|other| same_position(other)).count();
+            i32::try_from(count).unwrap_or(i32::MAX - 1)
+        };
+        match position.cmp(&0) {
+            Ordering::Greater => path_groups[i].position = 1 + count(&path_groups[..i]),
+            Ordering::Less => path_groups[i].position = -1 - count(&path_groups[i + 1..]),
+            Ordering::Equal => {}
+        }
+    }
+    path_groups
+}
+
+/// An import declaration or `require()` call at the top level of the module.
+struct ImportedModule<'a> {
+    /// The module specifier, or the entity name of an `import x = A.B` declaration.
+    name: &'a str,
+    span: Span,
+    kind: ImportKind,
+    /// Index of the statement in the program body.
+    statement_index: usize,
+    rank: Rank,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportKind {
+    Import,
+    TypeImport,
+    Require,
+}
+
+impl ImportedModule<'_> {
+    fn description(&self) -> &'static str {
+        match self.kind {
+            ImportKind::Import => "import",
+            ImportKind::TypeImport => "type import",
+            ImportKind::Require => "require",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Rank {
+    /// `require()` calls come after all imports.
+    is_require: bool,
+    group: usize,
+    /// Position of a path group relative to `group`.
+    position: i32,
+    /// Position within the group when alphabetized.
+    alphabetical: usize,
+}
+
+impl Order {
+    fn collect_imports<'a>(&self, ctx: &LintContext<'a>) -> Vec<ImportedModule<'a>> {
+        let module_record = ctx.module_record();
+        let mut imported = vec![];
+        for (statement_index, statement) in ctx.nodes().program().body.iter().enumerate() {
+            let (name, span, kind, group) = match statement {
+                Statement::ImportDeclaration(decl) => {
+                    let is_unassigned = decl.specifiers.as_ref().is_none_or(|s| s.is_empty());
+                    if is_unassigned && !self.warn_on_unassigned_imports {
+                        continue;
+                    }
+                    let kind = if decl.import_kind.is_type() {
+                        ImportKind::TypeImport
+                    } else {
+                        ImportKind::Import
+                    };
+                    (decl.source.value.as_str(), decl.span, kind, None)
+                }
+                Statement::TSImportEqualsDeclaration(decl) => match &decl.module_reference {
+                    TSModuleReference::ExternalModuleReference(reference) => {
+                        let kind = if decl.import_kind.is_type() {
+                            ImportKind::TypeImport
+                        } else {
+                            ImportKind::Import
+                        };
+                        (reference.expression.value.as_str(), decl.span, kind, None)
+                    }
+                    reference => (
+                        ctx.source_range(reference.span()),
+                        decl.span,
+                        ImportKind::Import,
+                        Some(ImportGroup::Object),
+                    ),
+                },
+                Statement::VariableDeclaration(decl) => {
+                    let [declarator] = decl.declarations.as_slice() else { continue };
+                    let Some(Expression::CallExpression(call)) =
+                        declarator.init.as_ref().map(Expression::get_inner_expression)
+                    else {
+                        continue;
+                    };
+                    let Some(source) = call.common_js_require() else { continue };
+                    (source.value.as_str(), decl.span, ImportKind::Require, None)
+                }
+                _ => continue,
+            };
+
+            let group = group.unwrap_or_else(|| {
+                if kind == ImportKind::TypeImport && self.has_group(ImportGroup::Type) {
+                    ImportGroup::Type
+                } else {
+                    let resolved_path = module_record
+                        .resolved_requested_modules
+                        .get(name)
+                        .and_then(|path| path.as_deref());
+                    ImportGroup::from(ImportType::new(name, resolved_path))
+                }
+            });
+            imported.push(ImportedModule {
+                name,
+                span,
+                kind,
+                statement_index,
+                rank: self.rank(name, group, kind == ImportKind::Require),
+            });
+        }
+        imported
+    }
+
+    fn has_group(&self, group: ImportGroup) -> bool {
+        self.groups.iter().any(|groups| groups.contains(&group))
+    }
+
+    fn group_rank(&self, group: ImportGroup) -> usize {
+        self.groups.iter().position(|groups| groups.contains(&group)).unwrap_or(self.groups.len())
+    }
+
+    fn rank(&self, name: &str, group: ImportGroup, is_require: bool) -> Rank {
+        let path_group = if self.path_groups_excluded_import_types.contains(&group) {
+            None
+        } else {
+            self.path_groups.iter().find(|path_group| glob_match(path_group.pattern.as_str(), name))
+        };
+        match path_group {
+            Some(path_group) => Rank {
+                is_require,
+                group: self.group_rank(path_group.group),
+                position: path_group.position,
+                alphabetical: 0,
+            },
+            None => {
+                Rank { is_require, group: self.group_rank(group), position: 0, alphabetical: 0 }
+            }
+        }
+    }
+
+    fn check_newlines_between(&self, imported: &[ImportedModule], ctx: &LintContext<'_>) {
+        let source_text = ctx.semantic().source_text();
+        for pair in imported.windows(2) {
+            let [previous, current] = pair else { unreachable!() };
+            let between = &source_text[previous.span.end as usize..current.span.start as usize];
+            let segments = between.split('\n').collect::<Vec<_>>();
+            let empty_lines = segments
+                .get(1..segments.len().saturating_sub(1))
+                .unwrap_or_default()
+                .iter()
+                .filter(|line| line.trim().is_empty())
+                .count();
+
+            let is_same_rank = previous.rank == current.rank;
+            let is_start_of_distinct_group = (current.rank.is_require, current.rank.group)
+                > (previous.rank.is_require, previous.rank.group);
+            match self.newlines_between {
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups => {
+                    if !is_same_rank && empty_lines == 0 {
+                        if self.distinct_group || is_start_of_distinct_group {
+                            let end_of_line = end_of_line_with_comments(previous.span, ctx);
+                            let end = end_of_line
+                                - u32::from(source_text[..end_of_line as usize].ends_with('\n'));
+                            ctx.diagnostic_with_fix(
+                                missing_newline_diagnostic(previous.span),
+                                |fixer| fixer.insert_text_after_range(Span::empty(end), "\n"),
+                            );
+                        }
+                    } else if empty_lines > 0
+                        && self.newlines_between == NewlinesBetween::Always
+                        && (if self.distinct_group {
+                            is_same_rank
+                        } else {
+                            !is_start_of_distinct_group
+                        })
+                    {
+                        report_empty_lines(
+                            newline_in_group_diagnostic(previous.span),
+                            previous,
+                            current,
+                            ctx,
+                        );
+                    }
+                }
+                NewlinesBetween::Never if empty_lines > 0 => {
+                    report_empty_lines(
+                        newline_between_groups_diagnostic(previous.span),
+                        previous,
+                        current,
+                        ctx,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Sorts the imports of each rank by name, and stores their position in `rank.alphabetical`.
+    fn alphabetize_ranks(&self, imported: &mut [ImportedModule]) {
+        let mut order = (0..imported.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&imported[a], &imported[b]);
+            a.rank.cmp(&b.rank).then_with(|| self.compare_names(a, b))
+        });
+
+        let mut alphabetical = 0;
+        let mut previous: Option<usize> = None;
+        for index in order {
+            if let Some(previous) = previous {
+                let (previous, current) = (&imported[previous], &imported[index]);
+                // `previous` already has its alphabetical position, which is not part of the rank.
+                let is_same_rank = Rank { alphabetical: 0, ..previous.rank } == current.rank;
+                if is_same_rank && self.compare_names(previous, current).is_ne() {
+                    alphabetical += 1;
+                }
+            }
+            imported[index].rank.alphabetical = alphabetical;
+            previous = Some(index);
+        }
+    }
+
+    fn compare_names(&self, a: &ImportedModule, b: &ImportedModule) -> Ordering {
+        let (a_name, b_name) = if self.alphabetize.case_insensitive {
+            (a.name.cow_to_lowercase(), b.name.cow_to_lowercase())
+        } else {
+            (a.name.into(), b.name.into())
+        };
+        let a_segments = a_name.split('/').collect::<Vec<_>>();
+        let b_segments = b_name.split('/').collect::<Vec<_>>();
+
+        let mut ordering = Ordering::Equal;
+        for (i, (a_segment, b_segment)) in a_segments.iter().zip(&b_segments).enumerate() {
+            // Relative prefixes are not compared, `./` and `../` imports are in different groups.
+            let is_relative = |segment: &str| matches!(segment, "." | "..");
+            if i == 0 && is_relative(a_segment) && is_relative(b_segment) {
+                if a_segment != b_segment {
+                    break;
+                }
+                continue;
+            }
+            ordering = a_segment.cmp(b_segment);
+            if ordering.is_ne() {
+                break;
+            }
+        }
+        if ordering.is_eq() {
+            ordering = a_segments.len().cmp(&b_segments.len());
+        }
+
+        self.alphabetize.order.apply(ordering).then_with(|| {
+            let is_type = |import: &ImportedModule| import.kind == ImportKind::TypeImport;
+            // `type` sorts before `value`.
+            self.alphabetize.order_import_kind.apply(is_type(b).cmp(&is_type(a)))
+        })
+    }
+}
+
+fn report_empty_lines(
+    diagnostic: OxcDiagnostic,
+    previous: &ImportedModule,
+    current: &ImportedModule,
+    ctx: &LintContext<'_>,
+) {
+    let range =
+        Span::new(end_of_line_with_comments(previous.span, ctx), start_of_line(current.span, ctx));
+    if range.start < range.end && ctx.source_range(range).trim().is_empty() {
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(range));
+    } else {
+        ctx.diagnostic(diagnostic);
+    }
+}
+
+/// Indices of the imports that are ranked lower than an import before them.
+fn find_out_of_order<T: Ord + Copy>(ranks: &[T]) -> Vec<usize> {
+    let Some(&first) = ranks.first() else { return vec![] };
+    let mut max_seen = first;
+    ranks
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &rank)| {
+            let is_out_of_order = rank < max_seen;
+            max_seen = max_seen.max(rank);
+            is_out_of_order.then_some(index)
+        })
+        .collect()
+}
+
+fn report_out_of_order(imported: &[ImportedModule], ctx: &LintContext<'_>) {
+    let ranks = imported.iter().map(|import| import.rank).collect::<Vec<_>>();
+    let out_of_order = find_out_of_order(&ranks);
+    if out_of_order.is_empty() {
+        return;
+    }
+
+    // Report whichever of moving imports up or down takes fewer moves.
+    let reversed = ranks.iter().rev().map(|&rank| Reverse(rank)).collect::<Vec<_>>();
+    let reversed_out_of_order = find_out_of_order(&reversed);
+    if reversed_out_of_order.len() < out_of_order.len() {
+        for index in reversed_out_of_order {
+            let import = &imported[imported.len() - 1 - index];
+            let Some(other) = imported.iter().rev().find(|other| other.rank < import.rank) else {
+                continue;
+            };
+            report_and_move(import, other, false, imported, ctx);
+        }
+    } else {
+        for index in out_of_order {
+            let import = &imported[index];
+            let Some(other) = imported.iter().find(|other| other.rank > import.rank) else {
+                continue;
+            };
+            report_and_move(import, other, true, imported, ctx);
+        }
+    }
+}
+
+/// Reports that `import` should be moved before or after `other`, and fixes it if only imports
+/// are in between.
+fn report_and_move(
+    import: &ImportedModule,
+    other: &ImportedModule,
+    before: bool,
+    imported: &[ImportedModule],
+    ctx: &LintContext<'_>,
+) {
+    let diagnostic = out_of_order_diagnostic(
+        import.span,
+        import,
+        if before { "before" } else { "after" },
+        other,
+    );
+
+    let (first, last) = if before { (other, import) } else { (import, other) };
+    let body = &ctx.nodes().program().body;
+    let can_fix = (first.statement_index..=last.statement_index).all(|index| {
+        imported.iter().any(|import| import.statement_index == index) && can_cross(&body[index])
+    });
+    if !can_fix {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    let source_text = ctx.semantic().source_text();
+    let import_range =
+        Span::new(start_of_line(import.span, ctx), end_of_line_with_comments(import.span, ctx));
+    let import_text = ctx.source_range(import_range);
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        if before {
+            let other_start = start_of_line(other.span, ctx);
+            let between = &source_text[other_start as usize..import_range.start as usize];
+            let mut code = format!("{}\n{between}", import_text.trim_end_matches('\n'));
+            if !import_text.ends_with('\n') {
+                code.pop();
+            }
+            fixer.replace(Span::new(other_start, import_range.end), code)
+        } else {
+            let other_end = end_of_line_with_comments(other.span, ctx);
+            let between = &source_text[import_range.end as usize..other_end as usize];
+            let code = if between.ends_with('\n') {
+                format!("{between}{import_text}")
+            } else {
+                format!("{between}\n{}", import_text.trim_end_matches('\n'))
+            };
+            fixer.replace(Span::new(import_range.start, other_end), code)
+        }
+    });
+}
+
+/// Whether an import can be moved across this collected import. Unassigned imports and object
+/// imports may have side effects that depend on their order.
+fn can_cross(statement: &Statement) -> bool {
+    match statement {
+        Statement::ImportDeclaration(decl) => {
+            decl.specifiers.as_ref().is_some_and(|specifiers| !specifiers.is_empty())
+        }
+        Statement::TSImportEqualsDeclaration(decl) => {
+            matches!(decl.module_reference, TSModuleReference::ExternalModuleReference(_))
+        }
+        Statement::VariableDeclaration(_) => true,
+        _ => false,
+    }
+}
+
+/// Start of the line of `span`, if only whitespace precedes it on that line.
+fn start_of_line(span: Span, ctx: &LintContext<'_>) -> u32 {
+    let source_text = ctx.semantic().source_text();
+    let before = &source_text[..span.start as usize];
+    let indentation = before.len() - before.trim_end_matches([' ', '\t']).len();
+    #[expect(clippy::cast_possible_truncation)]
+    let start = span.start - indentation as u32;
+    start
+}
+
+/// End of the line of `span`, after trailing comments and the line break.
+fn end_of_line_with_comments(span: Span, ctx: &LintContext<'_>) -> u32 {
+    let source_text = ctx.semantic().source_text();
+    let mut end = span.end;
+    for comment in ctx.semantic().comments() {
+        if comment.span.start < end {
+            continue;
+        }
+        let between = &source_text[end as usize..comment.span.start as usize];
+        if !between.trim_start_matches([' ', '\t']).is_empty() {
+            break;
+        }
+        end = comment.span.end;
+    }
+    let rest = &source_text[end as usize..];
+    let whitespace = rest.len() - rest.trim_start_matches([' ', '\t', '\r']).len();
+    #[expect(clippy::cast_possible_truncation)]
+    let end = end + whitespace as u32;
+    end + u32::from(source_text[end as usize..].starts_with('\n'))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            r"
+                var fs = require('fs');
+                var async = require('async');
+                var relParent1 = require('../foo');
+                var relParent2 = require('../foo/bar');
+                var relParent3 = require('../');
+                var sibling = require('./foo');
+                var index = require('./');
+            ",
+            None,
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import async, {foo1} from 'async';
+                import relParent1 from '../foo';
+                import relParent2, {foo2} from '../foo/bar';
+                import relParent3 from '../';
+                import sibling, {foo3} from './foo';
+                import index from './';
+            ",
+            None,
+        ),
+        // Unassigned imports are ignored
+        (
+            r"
+                import './foo';
+                import 'fs';
+                import path from 'path';
+            ",
+            None,
+        ),
+        (
+            r"
+                var path = require('path');
+                import './foo';
+                require('fs');
+            ",
+            None,
+        ),
+        // Imports after the first statement are still checked, but statements in between are fine
+        (
+            r"
+                import fs from 'fs';
+                foo();
+                import sibling from './foo';
+            ",
+            None,
+        ),
+        // Omitted groups go last
+        (
+            r"
+                import sibling from './foo';
+                import fs from 'fs';
+            ",
+            Some(json!([{ "groups": ["sibling"] }])),
+        ),
+        (
+            r"
+                import sibling from './foo';
+                import fs from 'fs';
+                import async from 'async';
+                import parent from '../foo';
+            ",
+            Some(json!([{ "groups": ["sibling", ["builtin", "external"], "parent"] }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import _ from 'lodash';
+                import { Input } from '~/components/Input';
+                import { Button } from '#/components/Button';
+                import { add } from './helper';
+            ",
+            Some(json!([{
+                "pathGroups": [
+                    { "pattern": "~/**", "group": "external", "position": "after" },
+                    { "pattern": "#/**", "group": "external", "position": "after" },
+                ],
+            }])),
+        ),
+        (
+            r"
+                import react from 'react';
+                import fs from 'fs';
+                import sibling from './foo';
+            ",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+
+                import async from 'async';
+
+                import sibling from './foo';
+                import index from './';
+            ",
+            Some(
+                json!([{ "newlines-between": "always", "groups": ["builtin", "external", ["sibling", "index"]] }]),
+            ),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import async from 'async';
+                import sibling from './foo';
+            ",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+
+                import path from 'path';
+
+                import async from 'async';
+            ",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            r"
+                import a from 'a';
+                import B from 'B';
+                import c from 'c';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"
+                import c from 'c';
+                import b from 'b';
+                import a from 'a';
+            ",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"
+                import a from 'a';
+                import b from 'b';
+                import c from 'c';
+                import d from 'd';
+                import b2 from './b';
+                import c2 from './c';
+                import x from './x';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import a from 'foo';
+                import c from 'foo/bar';
+                import d from 'foo/barfoo';
+                import b from 'foo-bar';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import x from 'x';
+                import y from 'y';
+                import z from 'z';
+                import c from '../c';
+                import a from './a';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import type { Foo } from 'foo';
+                import foo from 'foo';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc", "orderImportKind": "asc" } }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import foo from './foo';
+                import type { Bar } from 'bar';
+            ",
+            Some(json!([{ "groups": ["builtin", "external", "sibling", "type"] }])),
+        ),
+        (
+            r"
+                import fs = require('fs');
+                import sibling = require('./foo');
+                import log = console.log;
+            ",
+            Some(json!([{ "groups": ["builtin", "sibling", "object"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r"
+                var async = require('async');
+                var fs = require('fs');
+            ",
+            None,
+        ),
+        (
+            r"
+                import async from 'async';
+                import fs from 'fs';
+            ",
+            None,
+        ),
+        (
+            r"
+                import sibling from './foo';
+                import parent from '../foo';
+            ",
+            None,
+        ),
+        (
+            r"
+                import index from './';
+                import sibling from './foo';
+            ",
+            None,
+        ),
+        (
+            r"
+                var fs = require('fs');
+                import async from 'async';
+            ",
+            None,
+        ),
+        // Reports the fewest imports to move
+        (
+            r"
+                import index from './';
+                import fs from 'fs';
+                import path from 'path';
+                import async from 'async';
+            ",
+            None,
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import index from './';
+                import sibling from './foo';
+            ",
+            None,
+        ),
+        // Can't be fixed, there is code in between
+        (
+            r"
+                import async from 'async';
+                foo();
+                import fs from 'fs';
+            ",
+            None,
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import sibling from './foo';
+            ",
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import { Input } from '~/components/Input';
+                import _ from 'lodash';
+            ",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "~/**", "group": "external", "position": "after" }],
+            }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import async from 'async';
+            ",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+
+                import path from 'path';
+            ",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+
+                import async from 'async';
+            ",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"
+                import b from 'b';
+                import a from 'a';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import a from 'a1';
+                import c from 'c1';
+                import b from 'b1';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import b from './b';
+                import x from './x';
+                import c from './c';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import a from 'a';
+                import B from 'B';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+                import a from 'a';
+                import b from 'b';
+            ",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"
+                import foo from 'foo';
+                import type { Foo } from 'foo';
+            ",
+            Some(json!([{ "alphabetize": { "order": "asc", "orderImportKind": "asc" } }])),
+        ),
+        (
+            r"
+                import type { Bar } from 'bar';
+                import fs from 'fs';
+            ",
+            Some(json!([{ "groups": ["builtin", "external", "type"] }])),
+        ),
+        (
+            r"
+                import fs from 'fs';
+                import './foo';
+                import path from 'path';
+                import async from 'async';
+            ",
+            Some(
+                json!([{ "warnOnUnassignedImports": true, "groups": ["sibling", "builtin", "external"] }]),
+            ),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import async from 'async';\nimport fs from 'fs';\n",
+            "import fs from 'fs';\nimport async from 'async';\n",
+            None,
+        ),
+        (
+            "import async from 'async';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport async from 'async';",
+            None,
+        ),
+        (
+            "import sibling from './foo'; // sibling\nimport parent from '../foo';\n",
+            "import parent from '../foo';\nimport sibling from './foo'; // sibling\n",
+            None,
+        ),
+        (
+            "import index from './';\nimport fs from 'fs';\nimport path from 'path';\nimport async from 'async';\n",
+            "import fs from 'fs';\nimport path from 'path';\nimport async from 'async';\nimport index from './';\n",
+            None,
+        ),
+        (
+            "import b from 'b';\nimport a from 'a';\n",
+            "import a from 'a';\nimport b from 'b';\n",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport async from 'async';\n",
+            "import fs from 'fs';\n\nimport async from 'async';\n",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs'; // fs\nimport async from 'async';\n",
+            "import fs from 'fs'; // fs\n\nimport async from 'async';\n",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\n\nimport path from 'path';\n",
+            "import fs from 'fs';\nimport path from 'path';\n",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport async from 'async';\n",
+            "import fs from 'fs';\nimport async from 'async';\n",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        // Not fixed, there is code in between
+        (
+            "import async from 'async';\nfoo();\nimport fs from 'fs';\n",
+            "import async from 'async';\nfoo();\nimport fs from 'fs';\n",
+            None,
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path("order.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,287 @@
+use cow_utils::CowUtils;
+use oxc_ast::{AstKind, ast::JSXAttributeItem};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::{AriaProperty, AriaPropertyType},
+    rule::Rule,
+    utils::{LiteralPropValue, get_jsx_attribute_name, get_literal_prop_value},
+};
+
+fn aria_proptypes_diagnostic(
+    span: Span,
+    name: &str,
+    value_type: AriaPropertyType,
+) -> OxcDiagnostic {
+    let message = match value_type {
+        AriaPropertyType::Tristate => {
+            format!("The value for {name} must be a boolean or the string \"mixed\".")
+        }
+        AriaPropertyType::Token(values) => format!(
+            "The value for {name} must be a single token from the following: {}.",
+            values.join(",")
+        ),
+        AriaPropertyType::TokenList(values) => format!(
+            "The value for {name} must be a list of one or more tokens from the following: {}.",
+            values.join(",")
+        ),
+        AriaPropertyType::IdList => format!(
+            "The value for {name} must be a list of strings that represent DOM element IDs (idlist)"
+        ),
+        AriaPropertyType::Id => {
+            format!("The value for {name} must be a string that represents a DOM element ID")
+        }
+        AriaPropertyType::Boolean { .. } => format!("The value for {name} must be a boolean."),
+        AriaPropertyType::Integer => format!("The value for {name} must be a integer."),
+        AriaPropertyType::Number => format!("The value for {name} must be a number."),
+        AriaPropertyType::String => format!("The value for {name} must be a string."),
+    };
+    OxcDiagnostic::warn(message).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaProptypes;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the values of ARIA state and property attributes are valid
+    /// for their type, e.g. that `aria-hidden` is a boolean and `aria-sort` is
+    /// one of `ascending`, `descending`, `none` and `other`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assistive technologies ignore or misinterpret ARIA attributes with invalid
+    /// values, so the element is not described to users the way it is intended.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <span aria-hidden="yes">foo</span>
+    /// <div aria-checked="maybe" />
+    /// <div aria-level="first" />
+    /// <div aria-relevant="additions removal" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <span aria-hidden="true">foo</span>
+    /// <span aria-hidden={true}>foo</span>
+    /// <div aria-checked="mixed" />
+    /// <div aria-level={2} />
+    /// <div aria-relevant="additions removals" />
+    /// <div aria-labelledby={labelId} />
+    /// ```
+    AriaProptypes,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for AriaProptypes {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        for item in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attr) = item else {
+                continue;
+            };
+            let name = get_jsx_attribute_name(&attr.name);
+            let name = name.cow_to_ascii_lowercase();
+            let Ok(property) = AriaProperty::try_from(name.as_ref()) else {
+                continue;
+            };
+            // Values which are not literals, `null` and `undefined` can't be checked.
+            let Some(value) = get_literal_prop_value(item) else {
+                continue;
+            };
+            let value_type = property.value_type();
+            if !is_valid_value(value, value_type) {
+                ctx.diagnostic(aria_proptypes_diagnostic(attr.span(), &name, value_type));
+            }
+        }
+    }
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/rules/aria-proptypes.js>
+fn is_valid_value(value: LiteralPropValue, value_type: AriaPropertyType) -> bool {
+    match (value_type, value) {
+        (
+            AriaPropertyType::Boolean { .. } | AriaPropertyType::Tristate,
+            LiteralPropValue::Boolean(_),
+        )
+        | (
+            AriaPropertyType::String | AriaPropertyType::Id | AriaPropertyType::IdList,
+            LiteralPropValue::String(_),
+        )
+        | (AriaPropertyType::Integer | AriaPropertyType::Number, LiteralPropValue::Number(_)) => {
+            true
+        }
+        (AriaPropertyType::Boolean { allow_undefined }, LiteralPropValue::String(value)) => {
+            allow_undefined && value == "undefined"
+        }
+        (AriaPropertyType::Tristate, LiteralPropValue::String(value)) => value == "mixed",
+        (AriaPropertyType::Integer | AriaPropertyType::Number, LiteralPropValue::String(value)) => {
+            let value = value.trim();
+            value.is_empty() || value.parse::<f64>().is_ok()
+        }
+        (AriaPropertyType::Token(tokens), LiteralPropValue::Boolean(value)) => {
+            tokens.contains(&if value { "true" } else { "false" })
+        }
+        (AriaPropertyType::Token(tokens), LiteralPropValue::String(value)) => {
+            tokens.contains(&value.cow_to_ascii_lowercase().as_ref())
+        }
+        (AriaPropertyType::TokenList(tokens), LiteralPropValue::String(value)) => {
+            value.split(' ').all(|token| tokens.contains(&token.cow_to_ascii_lowercase().as_ref()))
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div aria-foo="true" />"#, None),
+        (r#"<div abcaria-foo="true" />"#, None),
+        ("<div aria-hidden={true} />", None),
+        (r#"<div aria-hidden="true" />"#, None),
+        (r#"<div aria-hidden={"false"} />"#, None),
+        ("<div aria-hidden={!false} />", None),
+        ("<div aria-hidden />", None),
+        ("<div aria-hidden={false} />", None),
+        ("<div aria-hidden={!true} />", None),
+        (r#"<div aria-hidden={!"yes"} />"#, None),
+        ("<div aria-hidden={foo} />", None),
+        ("<div aria-hidden={foo.bar} />", None),
+        ("<div aria-hidden={null} />", None),
+        ("<div aria-hidden={undefined} />", None),
+        ("<div aria-hidden={<div />} />", None),
+        (r#"<div aria-expanded="undefined" />"#, None),
+        (r#"<div aria-label="Close" />"#, None),
+        ("<div aria-label={`Close`} />", None),
+        ("<div aria-label={foo} />", None),
+        ("<div aria-label={foo.bar} />", None),
+        ("<div aria-label={null} />", None),
+        ("<div aria-label={undefined} />", None),
+        (r#"<input aria-invalid={error ? "true" : "false"} />"#, None),
+        (r#"<input aria-invalid={undefined ? "true" : "false"} />"#, None),
+        ("<div aria-checked={true} />", None),
+        (r#"<div aria-checked="true" />"#, None),
+        (r#"<div aria-checked={"false"} />"#, None),
+        ("<div aria-checked={!false} />", None),
+        ("<div aria-checked />", None),
+        ("<div aria-checked={false} />", None),
+        ("<div aria-checked={!true} />", None),
+        (r#"<div aria-checked={!"yes"} />"#, None),
+        ("<div aria-checked={foo} />", None),
+        ("<div aria-checked={foo.bar} />", None),
+        (r#"<div aria-checked="mixed" />"#, None),
+        ("<div aria-checked={`mixed`} />", None),
+        ("<div aria-checked={null} />", None),
+        ("<div aria-checked={undefined} />", None),
+        ("<div aria-level={123} />", None),
+        ("<div aria-level={-123} />", None),
+        ("<div aria-level={+123} />", None),
+        ("<div aria-level={~123} />", None),
+        (r#"<div aria-level={"123"} />"#, None),
+        ("<div aria-level={`123`} />", None),
+        (r#"<div aria-level="123" />"#, None),
+        ("<div aria-level={foo} />", None),
+        ("<div aria-level={foo.bar} />", None),
+        ("<div aria-level={null} />", None),
+        ("<div aria-level={undefined} />", None),
+        ("<div aria-valuemax={123} />", None),
+        (r#"<div aria-valuemax="1.5" />"#, None),
+        (r#"<div aria-sort="ascending" />"#, None),
+        (r#"<div aria-sort="ASCENDING" />"#, None),
+        (r#"<div aria-sort={"ascending"} />"#, None),
+        ("<div aria-sort={`ascending`} />", None),
+        (r#"<div aria-sort="descending" />"#, None),
+        (r#"<div aria-sort="none" />"#, None),
+        (r#"<div aria-sort="other" />"#, None),
+        ("<div aria-sort={foo} />", None),
+        ("<div aria-sort={foo.bar} />", None),
+        ("<div aria-invalid={true} />", None),
+        (r#"<div aria-invalid="true" />"#, None),
+        ("<div aria-invalid={false} />", None),
+        (r#"<div aria-invalid="grammar" />"#, None),
+        (r#"<div aria-invalid="spelling" />"#, None),
+        ("<div aria-invalid={null} />", None),
+        ("<div aria-invalid={undefined} />", None),
+        (r#"<div aria-current="page" />"#, None),
+        ("<div aria-current={true} />", None),
+        (r#"<div aria-relevant="additions" />"#, None),
+        (r#"<div aria-relevant={"additions"} />"#, None),
+        ("<div aria-relevant={`additions`} />", None),
+        (r#"<div aria-relevant="additions removals" />"#, None),
+        (r#"<div aria-relevant="additions additions" />"#, None),
+        (r#"<div aria-relevant={"additions removals"} />"#, None),
+        ("<div aria-relevant={`additions removals`} />", None),
+        (r#"<div aria-relevant="additions removals text" />"#, None),
+        (r#"<div aria-relevant="all" />"#, None),
+        ("<div aria-relevant={foo} />", None),
+        ("<div aria-relevant={foo.bar} />", None),
+        ("<div aria-relevant={null} />", None),
+        ("<div aria-relevant={undefined} />", None),
+        (r#"<div aria-activedescendant="ascending" />"#, None),
+        ("<div aria-activedescendant={foo} />", None),
+        ("<div aria-activedescendant={null} />", None),
+        (r#"<div aria-labelledby="additions" />"#, None),
+        (r#"<div aria-labelledby="additions removals" />"#, None),
+        (r#"<div aria-labelledby={"additions removals"} />"#, None),
+        ("<div aria-labelledby={foo} />", None),
+        ("<div aria-labelledby={null} />", None),
+        ("<div aria-labelledby={undefined} />", None),
+        ("<input aria-describedby={undefined} />", None),
+        (r#"<input aria-describedby="my-description" />"#, None),
+        ("<div {...props} />", None),
+    ];
+
+    let fail = vec![
+        (r#"<div aria-hidden="yes" />"#, None),
+        (r#"<div aria-hidden="no" />"#, None),
+        ("<div aria-hidden={1234} />", None),
+        (r#"<div aria-expanded="nope" />"#, None),
+        ("<div aria-label />", None),
+        ("<div aria-label={true} />", None),
+        ("<div aria-label={false} />", None),
+        ("<div aria-label={1234} />", None),
+        (r#"<div aria-checked="yes" />"#, None),
+        (r#"<div aria-checked="no" />"#, None),
+        ("<div aria-checked={1234} />", None),
+        (r#"<div aria-level="yes" />"#, None),
+        ("<div aria-level={true} />", None),
+        ("<div aria-level />", None),
+        (r#"<div aria-level={"false"} />"#, None),
+        (r#"<div aria-valuemax="yes" />"#, None),
+        ("<div aria-valuemax={true} />", None),
+        (r#"<div aria-sort="" />"#, None),
+        (r#"<div aria-sort="descnding" />"#, None),
+        ("<div aria-sort />", None),
+        ("<div aria-sort={true} />", None),
+        (r#"<div aria-sort={"false"} />"#, None),
+        (r#"<div aria-sort="ascending descending" />"#, None),
+        (r#"<div aria-invalid="yes" />"#, None),
+        (r#"<div aria-current="site" />"#, None),
+        (r#"<div aria-relevant="" />"#, None),
+        (r#"<div aria-relevant="foobar" />"#, None),
+        ("<div aria-relevant />", None),
+        ("<div aria-relevant={true} />", None),
+        (r#"<div aria-relevant={"false"} />"#, None),
+        (r#"<div aria-relevant="additions removalss" />"#, None),
+        (r#"<div aria-relevant="additions removalss " />"#, None),
+        ("<div aria-activedescendant={1234} />", None),
+        ("<div aria-activedescendant />", None),
+        ("<div aria-labelledby={1234} />", None),
+        ("<div aria-labelledby />", None),
+    ];
+
+    Tester::new(AriaProptypes::NAME, AriaProptypes::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,329 @@
+use std::ops::Deref;
+
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeItem, JSXChild, JSXElement, JSXOpeningElement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        LiteralPropValue, get_element_type, get_jsx_attribute_name, get_literal_prop_value,
+        has_jsx_prop_ignore_case, is_hidden_from_screen_reader, is_interactive_element,
+        is_interactive_role,
+    },
+};
+
+fn control_has_associated_label_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("A control must be associated with a text label.")
+        .with_help("Add text content, or an `aria-label` or `aria-labelledby` attribute.")
+        .with_label(span)
+}
+
+const DEFAULT_DEPTH: u64 = 2;
+const MAX_DEPTH: u64 = 25;
+
+#[derive(Debug, Default, Clone)]
+pub struct ControlHasAssociatedLabel(Box<ControlHasAssociatedLabelConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ControlHasAssociatedLabelConfig {
+    label_attributes: Vec<CompactStr>,
+    control_components: Vec<CompactStr>,
+    ignore_elements: Vec<CompactStr>,
+    ignore_roles: Vec<CompactStr>,
+    depth: u64,
+}
+
+impl Default for ControlHasAssociatedLabelConfig {
+    fn default() -> Self {
+        Self {
+            label_attributes: vec![],
+            control_components: vec![],
+            ignore_elements: vec![],
+            ignore_roles: vec![],
+            depth: DEFAULT_DEPTH,
+        }
+    }
+}
+
+impl Deref for ControlHasAssociatedLabel {
+    type Target = ControlHasAssociatedLabelConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that controls, i.e. interactive elements and elements with an
+    /// interactive role, have a text label.
+    ///
+    /// An element has a label if it contains text, an expression, or an element
+    /// with a label, or if it has an `alt`, `aria-label` or `aria-labelledby`
+    /// attribute.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assistive technologies announce controls by their label. Without one,
+    /// users can't tell what the control does.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <button />
+    /// <div role="button" />
+    /// <button><span /></button>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <button>Save</button>
+    /// <button aria-label="Save" />
+    /// <div role="button" aria-labelledby="save-label" />
+    /// <button><img alt="Save" /></button>
+    /// <button>{label}</button>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### labelAttributes
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Additional attributes which label the element, e.g. `["label"]`.
+    ///
+    /// #### controlComponents
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Custom components which are controls and must have a label.
+    ///
+    /// #### ignoreElements
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Elements which are not checked. `<a>` elements are checked by
+    /// `jsx-a11y/anchor-has-content` instead, and are always ignored.
+    ///
+    /// #### ignoreRoles
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Roles which are not checked.
+    ///
+    /// #### depth
+    ///
+    /// `{ type: number, default: 2 }`
+    ///
+    /// How deep to look for a label in the children of the element, up to 25.
+    ControlHasAssociatedLabel,
+    jsx_a11y,
+    pedantic
+);
+
+impl Rule for ControlHasAssociatedLabel {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = ControlHasAssociatedLabelConfig::default();
+        let Some(value) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+        let strings = |key: &str| -> Vec<CompactStr> {
+            value
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|values| {
+                    values.iter().filter_map(Value::as_str).map(CompactStr::from).collect()
+                })
+                .unwrap_or_default()
+        };
+        config.label_attributes = strings("labelAttributes");
+        config.control_components = strings("controlComponents");
+        config.ignore_elements = strings("ignoreElements");
+        config.ignore_roles = strings("ignoreRoles");
+        if let Some(depth) = value.get("depth").and_then(Value::as_u64) {
+            config.depth = depth.min(MAX_DEPTH);
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let opening_el = &jsx_el.opening_element;
+        let element_type = get_element_type(ctx, opening_el);
+        if element_type == "a" || self.ignore_elements.iter().any(|el| el == element_type.as_ref())
+        {
+            return;
+        }
+        if let Some(LiteralPropValue::String(role)) =
+            has_jsx_prop_ignore_case(opening_el, "role").and_then(get_literal_prop_value)
+            && self.ignore_roles.iter().any(|r| r == role)
+        {
+            return;
+        }
+        if is_hidden_from_screen_reader(ctx, opening_el) {
+            return;
+        }
+
+        let is_control = is_interactive_element(&element_type, opening_el)
+            || (HTML_TAG.contains(element_type.as_ref()) && is_interactive_role(opening_el))
+            || self.control_components.iter().any(|c| c == element_type.as_ref());
+        if is_control && !self.may_have_accessible_label(jsx_el, 0) {
+            ctx.diagnostic(control_has_associated_label_diagnostic(opening_el.span));
+        }
+    }
+}
+
+impl ControlHasAssociatedLabel {
+    /// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/mayHaveAccessibleLabel.js>
+    fn may_have_accessible_label(&self, jsx_el: &JSXElement, depth: u64) -> bool {
+        if depth > self.depth {
+            return false;
+        }
+        if self.has_labelling_prop(&jsx_el.opening_element) {
+            return true;
+        }
+        self.children_have_accessible_label(&jsx_el.children, depth + 1)
+    }
+
+    fn children_have_accessible_label(&self, children: &[JSXChild], depth: u64) -> bool {
+        depth <= self.depth
+            && children.iter().any(|child| match child {
+                JSXChild::Text(text) => !text.value.trim().is_empty(),
+                // Assume that an expression renders a label, as it can't be known.
+                JSXChild::ExpressionContainer(_) | JSXChild::Spread(_) => true,
+                JSXChild::Element(el) => self.may_have_accessible_label(el, depth),
+                JSXChild::Fragment(fragment) => {
+                    self.children_have_accessible_label(&fragment.children, depth + 1)
+                }
+            })
+    }
+
+    fn has_labelling_prop(&self, jsx_el: &JSXOpeningElement) -> bool {
+        jsx_el.attributes.iter().any(|item| {
+            let JSXAttributeItem::Attribute(attr) = item else {
+                // A spread attribute may contain a label.
+                return true;
+            };
+            let name = get_jsx_attribute_name(&attr.name);
+            let is_labelling_prop =
+                matches!(name.as_ref(), "alt" | "aria-label" | "aria-labelledby")
+                    || self.label_attributes.iter().any(|label| label == name.as_ref());
+            is_labelling_prop
+                && match get_literal_prop_value(item) {
+                    Some(LiteralPropValue::String(value)) => !value.is_empty(),
+                    Some(LiteralPropValue::Boolean(value)) => value,
+                    Some(LiteralPropValue::Number(value)) => value != 0.0,
+                    None => attr.value.is_some(),
+                }
+        })
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "<CustomControl><span><span>Save</span></span></CustomControl>",
+            Some(json!([{ "depth": 3, "controlComponents": ["CustomControl"] }])),
+        ),
+        (
+            "<CustomControl><span><span label=\"Save\"></span></span></CustomControl>",
+            Some(
+                json!([{ "depth": 3, "controlComponents": ["CustomControl"], "labelAttributes": ["label"] }]),
+            ),
+        ),
+        ("<CustomControl>Save</CustomControl>", None),
+        ("<button>Save</button>", None),
+        ("<button><span>Save</span></button>", None),
+        ("<button><span><span>Save</span></span></button>", Some(json!([{ "depth": 3 }]))),
+        (
+            "<button><span><span><span><span><span><span><span><span>Save</span></span></span></span></span></span></span></span></button>",
+            Some(json!([{ "depth": 9 }])),
+        ),
+        ("<button><img alt=\"Save\" /></button>", None),
+        ("<button aria-label=\"Save\" />", None),
+        ("<button><span aria-label=\"Save\" /></button>", None),
+        ("<button aria-labelledby=\"js_1\" />", None),
+        ("<button><span aria-labelledby=\"js_1\" /></button>", None),
+        ("<button>{sureWhyNot}</button>", None),
+        (
+            "<button><span><span label=\"Save\"></span></span></button>",
+            Some(json!([{ "depth": 3, "labelAttributes": ["label"] }])),
+        ),
+        ("<button {...props} />", None),
+        ("<a href=\"#\" />", None),
+        ("<a href=\"#\">Save</a>", None),
+        ("<a href=\"#\" aria-label=\"Save\" />", None),
+        ("<input type=\"hidden\" />", None),
+        ("<input type=\"text\" aria-label=\"Save\" />", None),
+        ("<input type=\"text\" />", Some(json!([{ "ignoreElements": ["input"] }]))),
+        ("<select aria-label=\"Save\"><option>Save</option></select>", None),
+        ("<textarea aria-label=\"Save\" />", None),
+        ("<div role=\"button\">Save</div>", None),
+        ("<div role=\"button\" aria-label=\"Save\" />", None),
+        ("<div role=\"checkbox\" aria-labelledby=\"js_1\" />", None),
+        ("<div role=\"button\" />", Some(json!([{ "ignoreRoles": ["button"] }]))),
+        ("<div role=\"button\" aria-hidden />", None),
+        ("<button aria-hidden />", None),
+        ("<div role=\"article\" />", None),
+        ("<div role=\"presentation\" />", None),
+        ("<div />", None),
+        ("<span />", None),
+        ("<Foo role=\"button\" />", None),
+        ("<button><>Save</></button>", None),
+        ("<td>Save</td>", None),
+        ("<video />", Some(json!([{ "ignoreElements": ["video"] }]))),
+    ];
+
+    let fail = vec![
+        ("<button />", None),
+        ("<button><span /></button>", None),
+        ("<button><img /></button>", None),
+        ("<button><span title=\"This is not a real label\" /></button>", None),
+        (
+            "<button><span><span><span>Save</span></span></span></button>",
+            Some(json!([{ "depth": 3 }])),
+        ),
+        ("<button aria-label=\"\" />", None),
+        ("<button>   </button>", None),
+        (
+            "<CustomControl><span><span></span></span></CustomControl>",
+            Some(json!([{ "depth": 3, "controlComponents": ["CustomControl"] }])),
+        ),
+        (
+            "<CustomControl></CustomControl>",
+            Some(json!([{ "controlComponents": ["CustomControl"] }])),
+        ),
+        (
+            "<button><span><span label=\"Save\"></span></span></button>",
+            Some(json!([{ "depth": 3 }])),
+        ),
+        ("<input type=\"text\" />", None),
+        ("<textarea />", None),
+        ("<select><option /></select>", None),
+        ("<td />", None),
+        ("<div role=\"button\" />", None),
+        ("<div role=\"checkbox\" />", None),
+        ("<span role=\"link\" />", None),
+        ("<div role=\"tab\"><span /></div>", None),
+    ];
+
+    Tester::new(ControlHasAssociatedLabel::NAME, ControlHasAssociatedLabel::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,270 @@
+use std::ops::Deref;
+
+use oxc_ast::{AstKind, ast::JSXOpeningElement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::{HTML_TAG, KEYBOARD_EVENT_HANDLERS, MOUSE_EVENT_HANDLERS},
+    rule::Rule,
+    utils::{
+        LiteralPropValue, get_element_type, get_literal_prop_value, has_defined_prop,
+        has_jsx_prop_ignore_case, is_hidden_from_screen_reader, is_interactive_element,
+        is_interactive_role, is_non_interactive_element, is_non_interactive_role,
+        is_presentation_role,
+    },
+};
+
+fn tabbable_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be tabbable."))
+        .with_help("Add `tabIndex={0}` to the element.")
+        .with_label(span)
+}
+
+fn focusable_diagnostic(span: Span, role: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be focusable."))
+        .with_help("Add a `tabIndex` to the element, or use an interactive element instead.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct InteractiveSupportsFocus(Box<InteractiveSupportsFocusConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct InteractiveSupportsFocusConfig {
+    tabbable: Vec<CompactStr>,
+}
+
+impl Deref for InteractiveSupportsFocus {
+    type Target = InteractiveSupportsFocusConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that elements with an interactive role and interaction handlers
+    /// (mouse or key press) are focusable.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Elements with an interactive role, like `<div role="button" onClick={...}>`,
+    /// are announced to users of assistive technologies as controls, but they can't
+    /// be reached with the keyboard unless they have a `tabIndex`.
+    /// Native interactive elements, like `<button>`, are focusable by default.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <span onClick={submitForm} role="button">Submit</span>
+    /// <a onClick={showNextPage} role="button">Next page</a>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div aria-hidden onClick={() => void 0} />
+    /// <span onClick={doSomething} tabIndex="0" role="button">Click me!</span>
+    /// <span onClick={doSomething} tabIndex="-1" role="menuitem">Click me too!</span>
+    /// <a href="javascript:void(0);" onClick={doSomething}>Click ALL the things!</a>
+    /// <button onClick={doSomething}>Click the button :)</button>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### tabbable
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Roles which should be in the tab order, i.e. have `tabIndex={0}` rather
+    /// than `tabIndex={-1}`. Only changes the message of the diagnostic.
+    InteractiveSupportsFocus,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for InteractiveSupportsFocus {
+    fn from_configuration(value: Value) -> Self {
+        let tabbable = value
+            .get(0)
+            .and_then(|config| config.get("tabbable"))
+            .and_then(Value::as_array)
+            .map(|roles| roles.iter().filter_map(Value::as_str).map(CompactStr::from).collect())
+            .unwrap_or_default();
+        Self(Box::new(InteractiveSupportsFocusConfig { tabbable }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+        let has_interactive_props = MOUSE_EVENT_HANDLERS
+            .iter()
+            .chain(&KEYBOARD_EVENT_HANDLERS)
+            .any(|handler| has_defined_prop(jsx_el, handler));
+        if !has_interactive_props
+            || is_disabled_element(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+
+        if is_interactive_role(jsx_el)
+            && !is_interactive_element(&element_type, jsx_el)
+            && !is_non_interactive_element(&element_type, jsx_el)
+            && !is_non_interactive_role(jsx_el)
+            && !has_tabindex(jsx_el)
+        {
+            let role = has_jsx_prop_ignore_case(jsx_el, "role").and_then(get_literal_prop_value);
+            let Some(LiteralPropValue::String(role)) = role else {
+                return;
+            };
+            if self.tabbable.iter().any(|tabbable| tabbable == role) {
+                ctx.diagnostic(tabbable_diagnostic(jsx_el.name.span(), role));
+            } else {
+                ctx.diagnostic(focusable_diagnostic(jsx_el.name.span(), role));
+            }
+        }
+    }
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isDisabledElement.js>
+fn is_disabled_element(jsx_el: &JSXOpeningElement) -> bool {
+    has_defined_prop(jsx_el, "disabled")
+        || has_jsx_prop_ignore_case(jsx_el, "aria-disabled")
+            .and_then(get_literal_prop_value)
+            .is_some_and(|value| value == LiteralPropValue::Boolean(true))
+}
+
+/// Whether the element has a `tabIndex` that is an integer, or an expression.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/getTabIndex.js>
+fn has_tabindex(jsx_el: &JSXOpeningElement) -> bool {
+    let Some(tabindex) = has_jsx_prop_ignore_case(jsx_el, "tabIndex") else {
+        return false;
+    };
+    match get_literal_prop_value(tabindex) {
+        Some(LiteralPropValue::Number(value)) => value.fract() == 0.0,
+        Some(LiteralPropValue::String(value)) => value.trim().parse::<i64>().is_ok(),
+        Some(LiteralPropValue::Boolean(_)) => false,
+        None => has_defined_prop(jsx_el, "tabIndex"),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let tabbable = || {
+        Some(
+            json!([{ "tabbable": ["button", "checkbox", "link", "searchbox", "spinbutton", "switch", "textbox"] }]),
+        )
+    };
+
+    let pass = vec![
+        ("<Foo onClick={() => void 0} />", None),
+        ("<div />", None),
+        ("<div aria-hidden onClick={() => void 0} />", None),
+        ("<div aria-hidden={true == true} onClick={() => void 0} />", None),
+        ("<div aria-hidden={true === true} onClick={() => void 0} />", None),
+        ("<div aria-hidden={hidden !== false} onClick={() => void 0} />", None),
+        ("<div onClick={() => void 0} />", None),
+        ("<div onClick={() => void 0} tabIndex={undefined} />", None),
+        (r#"<div onClick={() => void 0} tabIndex="bad" />"#, None),
+        ("<div onClick={() => void 0} role={undefined} />", None),
+        (r#"<div role="section" onClick={() => void 0} />"#, None),
+        ("<div onClick={() => void 0} aria-hidden={false} />", None),
+        ("<div onClick={() => void 0} {...props} />", None),
+        (r#"<input type="text" onClick={() => void 0} />"#, None),
+        (r#"<input type="hidden" onClick={() => void 0} tabIndex="-1" />"#, None),
+        (r#"<input type="hidden" onClick={() => void 0} tabIndex={-1} />"#, None),
+        ("<input onClick={() => void 0} />", None),
+        (r#"<input onClick={() => void 0} role="combobox" />"#, None),
+        ("<button onClick={() => void 0} className=\"foo\" />", None),
+        ("<option onClick={() => void 0} className=\"foo\" />", None),
+        ("<select onClick={() => void 0} className=\"foo\" />", None),
+        ("<area href=\"#\" onClick={() => void 0} className=\"foo\" />", None),
+        ("<area onClick={() => void 0} className=\"foo\" />", None),
+        ("<summary onClick={() => void 0} />", None),
+        ("<textarea onClick={() => void 0} className=\"foo\" />", None),
+        ("<a onClick=\"showNextPage();\">Next page</a>", None),
+        ("<a onClick=\"showNextPage();\" tabIndex={undefined}>Next page</a>", None),
+        ("<a onClick=\"showNextPage();\" tabIndex=\"bad\">Next page</a>", None),
+        ("<a onClick={() => void 0} />", None),
+        ("<a tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<a tabIndex={dynamicTabIndex} onClick={() => void 0} />", None),
+        ("<a tabIndex={0} onClick={() => void 0} />", None),
+        ("<a role=\"button\" href=\"#\" onClick={() => void 0} />", None),
+        ("<a onClick={() => void 0} href=\"http://x.y.z\" />", None),
+        ("<a onClick={() => void 0} href=\"http://x.y.z\" tabIndex=\"0\" />", None),
+        ("<a onClick={() => void 0} href=\"http://x.y.z\" tabIndex={0} />", None),
+        ("<a onClick={() => void 0} href=\"http://x.y.z\" role=\"button\" />", None),
+        ("<TestComponent onClick={doFoo} />", None),
+        ("<input onClick={() => void 0} type=\"hidden\" />", None),
+        ("<span onClick=\"submitForm();\">Submit</span>", None),
+        ("<span onClick=\"submitForm();\" tabIndex={undefined}>Submit</span>", None),
+        ("<span onClick=\"submitForm();\" tabIndex=\"bad\">Submit</span>", None),
+        ("<span onClick=\"doSomething();\" tabIndex=\"0\">Click me!</span>", None),
+        ("<span onClick=\"doSomething();\" tabIndex={0}>Click me!</span>", None),
+        ("<span onClick=\"doSomething();\" tabIndex=\"-1\">Click me too!</span>", None),
+        (
+            "<a href=\"javascript:void(0);\" onClick=\"doSomething();\">Click ALL the things!</a>",
+            None,
+        ),
+        ("<section onClick={() => void 0} />", None),
+        ("<main onClick={() => void 0} />", None),
+        ("<article onClick={() => void 0} />", None),
+        ("<header onClick={() => void 0} />", None),
+        ("<footer onClick={() => void 0} />", None),
+        ("<div role=\"button\" tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<div role=\"checkbox\" tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<div role=\"link\" tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<div role=\"menuitem\" tabIndex=\"-1\" onClick={() => void 0} />", None),
+        ("<div role=\"button\" tabIndex={0} onKeyDown={() => void 0} />", None),
+        ("<div role=\"button\" disabled onClick={() => void 0} />", None),
+        ("<div role=\"button\" aria-disabled=\"true\" onClick={() => void 0} />", None),
+        ("<div role=\"button\" aria-hidden=\"true\" onClick={() => void 0} />", None),
+        ("<div role=\"presentation\" onClick={() => void 0} />", None),
+        ("<div role=\"none\" onClick={() => void 0} />", None),
+        ("<div role=\"article\" onClick={() => void 0} />", None),
+        ("<div role=\"progressbar\" onClick={() => void 0} />", None),
+        ("<div role=\"button\" onClick={null} />", None),
+        ("<div role=\"button\" onClick={undefined} />", None),
+        ("<li role=\"button\" onClick={() => void 0} />", None),
+        ("<div role=\"button\" onFocus={() => void 0} />", None),
+        ("<MyButton role=\"button\" onClick={() => void 0} />", None),
+        ("<div role=\"button\" tabIndex=\"0\" onClick={() => void 0} />", tabbable()),
+    ];
+
+    let fail = vec![
+        ("<div role=\"button\" onClick={() => void 0} />", None),
+        ("<div role=\"checkbox\" onClick={() => void 0} />", None),
+        ("<div role=\"link\" onClick={() => void 0} />", None),
+        ("<div role=\"gridcell\" onClick={() => void 0} />", None),
+        ("<div role=\"menuitem\" onClick={() => void 0} />", None),
+        ("<div role=\"tab\" onKeyPress={() => void 0} />", None),
+        ("<div role=\"textbox\" onMouseDown={() => void 0} />", None),
+        ("<span role=\"switch\" onDoubleClick={() => void 0} />", None),
+        ("<div role=\"button\" onClick={() => void 0} aria-disabled=\"false\" />", None),
+        ("<div role=\"button\" onClick={() => void 0} tabIndex=\"bad\" />", None),
+        ("<div role=\"button\" onClick={() => void 0} />", tabbable()),
+        ("<div role=\"menuitem\" onClick={() => void 0} />", tabbable()),
+        ("<span role=\"textbox\" onKeyUp={() => void 0} />", tabbable()),
+    ];
+
+    Tester::new(InteractiveSupportsFocus::NAME, InteractiveSupportsFocus::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
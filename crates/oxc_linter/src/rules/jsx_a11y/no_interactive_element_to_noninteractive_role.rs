@@ -0,0 +1,191 @@
+use std::ops::Deref;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        LiteralPropValue, get_element_type, get_literal_prop_value, has_jsx_prop,
+        is_interactive_element, is_non_interactive_role, is_presentation_role,
+    },
+};
+
+fn no_interactive_element_to_noninteractive_role_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Interactive elements should not be assigned non-interactive roles.")
+        .with_help("Use a non-interactive element like `<div>` for the role, or remove the role.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoInteractiveElementToNoninteractiveRole(Box<FxHashMap<CompactStr, Vec<CompactStr>>>);
+
+impl Default for NoInteractiveElementToNoninteractiveRole {
+    fn default() -> Self {
+        let allowed_roles: [(&str, &[&str]); 2] =
+            [("tr", &["none", "presentation"]), ("canvas", &["img"])];
+        Self(Box::new(
+            allowed_roles
+                .into_iter()
+                .map(|(element, roles)| {
+                    (CompactStr::new(element), roles.iter().copied().map(CompactStr::new).collect())
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Deref for NoInteractiveElementToNoninteractiveRole {
+    type Target = FxHashMap<CompactStr, Vec<CompactStr>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that interactive elements, like `<button>` or `<a href>`, are not
+    /// given a non-interactive role like `article`, `listitem` or `presentation`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Interactive elements are focusable and operable. A non-interactive role
+    /// hides that from assistive technologies, so users can't tell the element
+    /// is a control.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <a href="http://x.y.z" role="img" />
+    /// <button role="listitem" />
+    /// <input role="presentation" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div role="listitem"><button>Save</button></div>
+    /// <button role="menuitem" />
+    /// <tr role="presentation" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// An object which maps element types to the non-interactive roles allowed on them.
+    /// The default is:
+    /// ```json
+    /// {
+    ///   "tr": ["none", "presentation"],
+    ///   "canvas": ["img"]
+    /// }
+    /// ```
+    NoInteractiveElementToNoninteractiveRole,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for NoInteractiveElementToNoninteractiveRole {
+    fn from_configuration(value: Value) -> Self {
+        let Some(value) = value.get(0).and_then(Value::as_object) else {
+            return Self::default();
+        };
+        Self(Box::new(
+            value
+                .iter()
+                .filter_map(|(element, roles)| {
+                    let roles = roles.as_array()?;
+                    Some((
+                        CompactStr::from(element.as_str()),
+                        roles.iter().filter_map(Value::as_str).map(CompactStr::from).collect(),
+                    ))
+                })
+                .collect(),
+        ))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let Some(role) = has_jsx_prop(jsx_el, "role") else {
+            return;
+        };
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+        if let Some(LiteralPropValue::String(value)) = get_literal_prop_value(role)
+            && self.get(element_type.as_ref()).is_some_and(|roles| roles.iter().any(|r| r == value))
+        {
+            return;
+        }
+        if is_interactive_element(&element_type, jsx_el)
+            && (is_non_interactive_role(jsx_el) || is_presentation_role(jsx_el))
+        {
+            ctx.diagnostic(no_interactive_element_to_noninteractive_role_diagnostic(role.span()));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("<TestComponent onClick={doFoo} />", None),
+        ("<Button onClick={doFoo} />", None),
+        ("<a tabIndex=\"0\" role=\"button\" />", None),
+        ("<a href=\"http://x.y.z\" role=\"button\" />", None),
+        ("<a role=\"article\" />", None),
+        ("<button className=\"foo\" role=\"button\" />", None),
+        ("<button role={role} />", None),
+        ("<button role=\"widget\" />", None),
+        ("<input role=\"combobox\" />", None),
+        ("<input type=\"hidden\" role=\"img\" />", None),
+        ("<select role=\"menu\" />", None),
+        ("<td role=\"gridcell\" />", None),
+        ("<tr role=\"presentation\" />", None),
+        ("<tr role=\"none\" />", None),
+        ("<canvas role=\"img\" />", None),
+        ("<div role=\"listitem\" />", None),
+        ("<article role=\"article\" />", None),
+        ("<Component role=\"img\" />", None),
+        ("<button role=\"listitem\" />", Some(json!([{ "button": ["listitem"] }]))),
+    ];
+
+    let fail = vec![
+        ("<a href=\"http://x.y.z\" role=\"img\" />", None),
+        ("<a href=\"http://x.y.z\" tabIndex=\"0\" role=\"img\" />", None),
+        ("<input role=\"img\" />", None),
+        ("<input type=\"text\" role=\"listitem\" />", None),
+        ("<select role=\"article\" />", None),
+        ("<textarea role=\"listitem\" />", None),
+        ("<button role=\"article\" />", None),
+        ("<button role=\"banner\" />", None),
+        ("<button role=\"presentation\" />", None),
+        ("<button role=\"none\" />", None),
+        ("<option role=\"heading\" />", None),
+        ("<td role=\"definition\" />", None),
+        ("<tr role=\"list\" />", None),
+        ("<tr role=\"presentation\" />", Some(json!([{}]))),
+        ("<canvas role=\"img\" />", Some(json!([{ "tr": ["none"] }]))),
+    ];
+
+    Tester::new(
+        NoInteractiveElementToNoninteractiveRole::NAME,
+        NoInteractiveElementToNoninteractiveRole::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
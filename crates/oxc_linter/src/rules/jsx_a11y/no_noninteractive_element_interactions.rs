@@ -0,0 +1,236 @@
+use std::ops::Deref;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::{
+        FOCUS_EVENT_HANDLERS, HTML_TAG, IMAGE_EVENT_HANDLERS, KEYBOARD_EVENT_HANDLERS,
+        MOUSE_EVENT_HANDLERS,
+    },
+    rule::Rule,
+    utils::{
+        get_element_type, has_defined_prop, is_abstract_role, is_content_editable,
+        is_hidden_from_screen_reader, is_interactive_element, is_interactive_role,
+        is_non_interactive_element, is_non_interactive_role, is_presentation_role,
+    },
+};
+
+fn no_noninteractive_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Non-interactive elements should not be assigned mouse or keyboard event listeners.",
+    )
+    .with_help("Use an interactive element like `<button>`, or move the handler to one.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNoninteractiveElementInteractions(Box<NoNoninteractiveElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementInteractionsConfig {
+    handlers: Vec<CompactStr>,
+    /// Handlers which are allowed on specific elements, e.g. `{ "li": ["onClick"] }`.
+    allowed_handlers: FxHashMap<CompactStr, Vec<CompactStr>>,
+}
+
+impl Default for NoNoninteractiveElementInteractionsConfig {
+    fn default() -> Self {
+        Self {
+            handlers: FOCUS_EVENT_HANDLERS
+                .iter()
+                .chain(&IMAGE_EVENT_HANDLERS)
+                .chain(&KEYBOARD_EVENT_HANDLERS)
+                .chain(&MOUSE_EVENT_HANDLERS)
+                .copied()
+                .map(CompactStr::new)
+                .collect(),
+            allowed_handlers: FxHashMap::default(),
+        }
+    }
+}
+
+impl Deref for NoNoninteractiveElementInteractions {
+    type Target = NoNoninteractiveElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that non-interactive elements, i.e. elements with a
+    /// non-interactive role like `<li>`, `<img>` or `<div role="article">`,
+    /// don't have mouse or keyboard event handlers.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive elements are announced as content by assistive
+    /// technologies, so users don't expect them to be operable, and they
+    /// can't be reached with the keyboard.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <li onClick={() => {}} />
+    /// <div onClick={() => {}} role="listitem" />
+    /// <img onClick={() => {}} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => {}} role="button" />
+    /// <div onClick={() => {}} role="presentation" />
+    /// <input type="text" onClick={() => {}} />
+    /// <button onClick={() => {}} className="foo" />
+    /// <img onLoad={() => {}} />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### handlers
+    ///
+    /// `{ type: string[], default: [...all focus, image, keyboard and mouse handlers] }`
+    ///
+    /// The event handlers to check for.
+    ///
+    /// Any other key maps an element type to the handlers which are allowed on it,
+    /// e.g. `{ "handlers": ["onClick"], "li": ["onClick"] }`.
+    NoNoninteractiveElementInteractions,
+    jsx_a11y,
+    suspicious
+);
+
+impl Rule for NoNoninteractiveElementInteractions {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoNoninteractiveElementInteractionsConfig::default();
+        let Some(value) = value.get(0).and_then(Value::as_object) else {
+            return Self(Box::new(config));
+        };
+        for (key, value) in value {
+            let Some(handlers) = value.as_array() else {
+                continue;
+            };
+            let handlers =
+                handlers.iter().filter_map(Value::as_str).map(CompactStr::from).collect();
+            if key == "handlers" {
+                config.handlers = handlers;
+            } else {
+                config.allowed_handlers.insert(CompactStr::from(key.as_str()), handlers);
+            }
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+        let allowed_handlers = self.allowed_handlers.get(element_type.as_ref());
+        let has_interactive_props = self.handlers.iter().any(|handler| {
+            !allowed_handlers.is_some_and(|allowed| allowed.contains(handler))
+                && has_defined_prop(jsx_el, handler)
+        });
+        if !has_interactive_props
+            || is_content_editable(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+        if is_interactive_element(&element_type, jsx_el)
+            || is_interactive_role(jsx_el)
+            || (!is_non_interactive_element(&element_type, jsx_el)
+                && !is_non_interactive_role(jsx_el))
+            || is_abstract_role(jsx_el)
+        {
+            return;
+        }
+        ctx.diagnostic(no_noninteractive_element_interactions_diagnostic(jsx_el.name.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("<TestComponent onClick={doFoo} />", None),
+        ("<Button onClick={doFoo} />", None),
+        ("<div onClick={() => void 0} />;", None),
+        ("<div onClick={() => void 0} role={undefined} />;", None),
+        ("<div onClick={() => void 0} {...props} />;", None),
+        ("<div className=\"foo\" />;", None),
+        ("<span onClick={() => {}} />;", None),
+        ("<header onClick={() => {}} />;", None),
+        ("<section onClick={() => {}} />;", None),
+        ("<a onClick={() => {}} />;", None),
+        ("<a href=\"http://x.y.z\" onClick={() => {}} />", None),
+        ("<button onClick={() => void 0} className=\"foo\" />", None),
+        ("<input type=\"text\" onClick={() => {}} />", None),
+        ("<select onClick={() => {}} />", None),
+        ("<td onClick={() => {}} />;", None),
+        ("<th onClick={() => {}} />;", None),
+        ("<tr onClick={() => {}} />;", None),
+        ("<li onClick={() => {}} role=\"menuitem\" />", None),
+        ("<div onClick={() => {}} role=\"button\" />", None),
+        ("<div onClick={() => {}} role=\"presentation\" />", None),
+        ("<li onClick={() => {}} role=\"presentation\" />", None),
+        ("<div onClick={() => {}} role=\"widget\" />", None),
+        ("<li onClick={() => {}} aria-hidden />", None),
+        ("<li onCopy={() => {}} />", None),
+        ("<li onClick={null} />", None),
+        ("<div contentEditable=\"true\" role=\"article\" onKeyDown={() => {}} />", None),
+        ("<li onMouseDown={() => {}} />", Some(json!([{ "handlers": ["onClick"] }]))),
+        ("<li onClick={() => {}} />", Some(json!([{ "li": ["onClick"] }]))),
+        (
+            "<ul onClick={() => {}} onKeyDown={() => {}} />",
+            Some(json!([{ "ul": ["onClick", "onKeyDown"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("<li onClick={() => {}} />;", None),
+        ("<article onClick={() => {}} />;", None),
+        ("<aside onClick={() => {}} />;", None),
+        ("<blockquote onClick={() => {}} />;", None),
+        ("<form onKeyDown={() => {}} />;", None),
+        ("<h1 onClick={() => {}} />;", None),
+        ("<img onClick={() => {}} />;", None),
+        ("<img onLoad={() => {}} />;", None),
+        ("<label onMouseUp={() => {}} />;", None),
+        ("<main onClick={() => {}} />;", None),
+        ("<ol onFocus={() => {}} />;", None),
+        ("<p onClick={() => {}} />;", None),
+        ("<section onClick={() => {}} aria-label=\"Aa\" />;", None),
+        ("<ul onClick={() => {}} />;", None),
+        ("<div onClick={() => {}} role=\"article\" />;", None),
+        ("<div onClick={() => {}} role=\"listitem\" />;", None),
+        ("<div onClick={() => {}} role=\"progressbar\" />;", None),
+        ("<div contentEditable=\"false\" role=\"article\" onKeyDown={() => {}} />", None),
+        ("<li onClick={() => {}} />", Some(json!([{ "handlers": ["onClick"] }]))),
+        ("<li onClick={() => {}} onKeyDown={() => {}} />", Some(json!([{ "li": ["onClick"] }]))),
+    ];
+
+    Tester::new(
+        NoNoninteractiveElementInteractions::NAME,
+        NoNoninteractiveElementInteractions::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
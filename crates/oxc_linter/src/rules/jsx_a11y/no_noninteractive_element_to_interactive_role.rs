@@ -0,0 +1,220 @@
+use std::ops::Deref;
+
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        LiteralPropValue, get_element_type, get_literal_prop_value, has_jsx_prop,
+        is_interactive_role, is_non_interactive_element,
+    },
+};
+
+fn no_noninteractive_element_to_interactive_role_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Non-interactive elements should not be assigned interactive roles.")
+        .with_help(
+            "Use an interactive element like `<button>` instead, or wrap the content in one.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementToInteractiveRole(Box<FxHashMap<CompactStr, Vec<CompactStr>>>);
+
+impl Default for NoNoninteractiveElementToInteractiveRole {
+    fn default() -> Self {
+        let list_roles =
+            ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"];
+        let allowed_roles: [(&str, &[&str]); 6] = [
+            ("ul", &list_roles),
+            ("ol", &list_roles),
+            (
+                "li",
+                &[
+                    "menuitem",
+                    "menuitemradio",
+                    "menuitemcheckbox",
+                    "option",
+                    "row",
+                    "tab",
+                    "treeitem",
+                ],
+            ),
+            ("table", &["grid"]),
+            ("td", &["gridcell"]),
+            ("fieldset", &["radiogroup", "presentation"]),
+        ];
+        Self(Box::new(
+            allowed_roles
+                .into_iter()
+                .map(|(element, roles)| {
+                    (CompactStr::new(element), roles.iter().copied().map(CompactStr::new).collect())
+                })
+                .collect(),
+        ))
+    }
+}
+
+impl Deref for NoNoninteractiveElementToInteractiveRole {
+    type Target = FxHashMap<CompactStr, Vec<CompactStr>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that non-interactive elements, like `<li>`, `<h1>` or `<img>`,
+    /// are not given an interactive role like `button`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive elements describe the structure of the content. Turning
+    /// them into controls with a role changes their meaning for assistive
+    /// technologies, and they still lack the keyboard support of native controls.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <h1 role="button">Save</h1>
+    /// <li role="button" />
+    /// <img role="link" />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div role="button" />
+    /// <h1><button>Save</button></h1>
+    /// <ul role="menu"><li role="menuitem" /></ul>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// An object which maps element types to the interactive roles allowed on them.
+    /// The default is:
+    /// ```json
+    /// {
+    ///   "ul": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///   "ol": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///   "li": ["menuitem", "menuitemradio", "menuitemcheckbox", "option", "row", "tab", "treeitem"],
+    ///   "table": ["grid"],
+    ///   "td": ["gridcell"],
+    ///   "fieldset": ["radiogroup", "presentation"]
+    /// }
+    /// ```
+    NoNoninteractiveElementToInteractiveRole,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for NoNoninteractiveElementToInteractiveRole {
+    fn from_configuration(value: Value) -> Self {
+        let Some(value) = value.get(0).and_then(Value::as_object) else {
+            return Self::default();
+        };
+        Self(Box::new(
+            value
+                .iter()
+                .filter_map(|(element, roles)| {
+                    let roles = roles.as_array()?;
+                    Some((
+                        CompactStr::from(element.as_str()),
+                        roles.iter().filter_map(Value::as_str).map(CompactStr::from).collect(),
+                    ))
+                })
+                .collect(),
+        ))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let Some(role) = has_jsx_prop(jsx_el, "role") else {
+            return;
+        };
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+        if let Some(LiteralPropValue::String(value)) = get_literal_prop_value(role)
+            && self.get(element_type.as_ref()).is_some_and(|roles| roles.iter().any(|r| r == value))
+        {
+            return;
+        }
+        if is_non_interactive_element(&element_type, jsx_el) && is_interactive_role(jsx_el) {
+            ctx.diagnostic(no_noninteractive_element_to_interactive_role_diagnostic(role.span()));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("<TestComponent onClick={doFoo} />", None),
+        ("<Button onClick={doFoo} />", None),
+        ("<a href=\"http://x.y.z\" role=\"button\" />", None),
+        ("<button className=\"foo\" role=\"button\" />", None),
+        ("<input role=\"menuitem\" />", None),
+        ("<div role=\"button\" />;", None),
+        ("<span role=\"link\" />;", None),
+        ("<header role=\"button\" />;", None),
+        ("<section role=\"button\" />;", None),
+        ("<article />;", None),
+        ("<article role=\"article\" />;", None),
+        ("<li role=\"listitem\" />;", None),
+        ("<li role=\"presentation\" />;", None),
+        ("<h1 role={role} />;", None),
+        ("<h1 role=\"widget\" />;", None),
+        ("<img role=\"none\" />;", None),
+        ("<ul role=\"menu\" />;", None),
+        ("<ol role=\"tablist\" />;", None),
+        ("<li role=\"menuitem\" />;", None),
+        ("<li role=\"tab\" />;", None),
+        ("<table role=\"grid\" />;", None),
+        ("<td role=\"gridcell\" />;", None),
+        ("<fieldset role=\"radiogroup\" />;", None),
+        ("<h1 role=\"button\" />;", Some(json!([{ "h1": ["button"] }]))),
+    ];
+
+    let fail = vec![
+        ("<main role=\"button\" />;", None),
+        ("<article role=\"button\" />;", None),
+        ("<aside role=\"button\" />;", None),
+        ("<blockquote role=\"button\" />;", None),
+        ("<dd role=\"button\" />;", None),
+        ("<form role=\"button\" />;", None),
+        ("<h1 role=\"button\" />;", None),
+        ("<h1 role=\"checkbox\" />;", None),
+        ("<img role=\"link\" />;", None),
+        ("<li role=\"button\" />;", None),
+        ("<ol role=\"button\" />;", None),
+        ("<p role=\"textbox\" />;", None),
+        ("<section aria-label=\"Aa\" role=\"button\" />;", None),
+        ("<ul role=\"button\" />;", None),
+        ("<ul role=\"menu\" />;", Some(json!([{}]))),
+        ("<li role=\"menuitem\" />;", Some(json!([{ "ul": ["menu"] }]))),
+    ];
+
+    Tester::new(
+        NoNoninteractiveElementToInteractiveRole::NAME,
+        NoNoninteractiveElementToInteractiveRole::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -0,0 +1,246 @@
+use std::ops::Deref;
+
+use oxc_ast::{AstKind, ast::JSXOpeningElement};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::{FOCUS_EVENT_HANDLERS, HTML_TAG, KEYBOARD_EVENT_HANDLERS, MOUSE_EVENT_HANDLERS},
+    rule::Rule,
+    utils::{
+        get_element_type, get_literal_prop_value, has_defined_prop, has_jsx_prop_ignore_case,
+        is_abstract_role, is_content_editable, is_hidden_from_screen_reader,
+        is_interactive_element, is_interactive_role, is_non_interactive_element,
+        is_non_interactive_role, is_presentation_role,
+    },
+};
+
+fn no_static_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Avoid non-native interactive elements. If using native HTML is not possible, add an appropriate role and support for tabbing, mouse, key press, and touch inputs to an interactive content element.",
+    )
+    .with_help("Use a native interactive element like `<button>`, or add a `role` to the element.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStaticElementInteractions(Box<NoStaticElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoStaticElementInteractionsConfig {
+    handlers: Vec<CompactStr>,
+    allow_expression_values: bool,
+}
+
+impl Default for NoStaticElementInteractionsConfig {
+    fn default() -> Self {
+        Self {
+            handlers: FOCUS_EVENT_HANDLERS
+                .iter()
+                .chain(&KEYBOARD_EVENT_HANDLERS)
+                .chain(&MOUSE_EVENT_HANDLERS)
+                .copied()
+                .map(CompactStr::new)
+                .collect(),
+            allow_expression_values: false,
+        }
+    }
+}
+
+impl Deref for NoStaticElementInteractions {
+    type Target = NoStaticElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that static HTML elements, i.e. elements without semantic
+    /// meaning like `<div>` and `<span>`, don't have interaction handlers.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Static elements are not announced as controls by assistive technologies,
+    /// and can't be reached with the keyboard. Use an interactive element like
+    /// `<button>` instead, or give the element an interactive `role`, and make it
+    /// focusable and operable with the keyboard.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => {}} />
+    /// <span onKeyDown={handleKeyDown} />
+    /// <a onClick={() => {}} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <button onClick={() => {}} className="foo" />
+    /// <div className="foo" onClick={() => {}} role="button" />
+    /// <input type="text" onClick={() => {}} />
+    /// <div onClick={() => {}} role="presentation" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### handlers
+    ///
+    /// `{ type: string[], default: [...all focus, keyboard and mouse handlers] }`
+    ///
+    /// The event handlers to check for.
+    ///
+    /// #### allowExpressionValues
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allows elements whose `role` is an expression, e.g. `role={ROLE}`.
+    NoStaticElementInteractions,
+    jsx_a11y,
+    suspicious
+);
+
+impl Rule for NoStaticElementInteractions {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoStaticElementInteractionsConfig::default();
+        let Some(value) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+        if let Some(handlers) = value.get("handlers").and_then(Value::as_array) {
+            config.handlers =
+                handlers.iter().filter_map(Value::as_str).map(CompactStr::from).collect();
+        }
+        if let Some(allow) = value.get("allowExpressionValues").and_then(Value::as_bool) {
+            config.allow_expression_values = allow;
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        let element_type = get_element_type(ctx, jsx_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+        if !self.handlers.iter().any(|handler| has_defined_prop(jsx_el, handler))
+            || is_content_editable(jsx_el)
+            || is_hidden_from_screen_reader(ctx, jsx_el)
+            || is_presentation_role(jsx_el)
+        {
+            return;
+        }
+        if is_interactive_element(&element_type, jsx_el)
+            || is_interactive_role(jsx_el)
+            || is_non_interactive_element(&element_type, jsx_el)
+            || is_non_interactive_role(jsx_el)
+            || is_abstract_role(jsx_el)
+        {
+            return;
+        }
+        if self.allow_expression_values && has_expression_role(jsx_el) {
+            return;
+        }
+        ctx.diagnostic(no_static_element_interactions_diagnostic(jsx_el.name.span()));
+    }
+}
+
+fn has_expression_role(jsx_el: &JSXOpeningElement) -> bool {
+    has_jsx_prop_ignore_case(jsx_el, "role").is_some_and(|role| {
+        get_literal_prop_value(role).is_none() && has_defined_prop(jsx_el, "role")
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("<TestComponent onClick={doFoo} />", None),
+        ("<Button onClick={doFoo} />", None),
+        ("<div />;", None),
+        ("<div className=\"foo\" />;", None),
+        ("<div className=\"foo\" {...props} />;", None),
+        ("<div onClick={() => void 0} aria-hidden />;", None),
+        ("<div onClick={() => void 0} aria-hidden={true} />;", None),
+        ("<div onClick={null} />;", None),
+        ("<div onKeyUp={undefined} />;", None),
+        ("<a tabIndex=\"0\" />", None),
+        ("<a href=\"http://x.y.z\" onClick={() => void 0} />", None),
+        ("<a href=\"http://x.y.z\" tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<area href=\"#\" onClick={() => {}} />;", None),
+        ("<audio onClick={() => {}} />;", None),
+        ("<button onClick={() => void 0} className=\"foo\" />", None),
+        ("<canvas onClick={() => {}} />;", None),
+        ("<embed onClick={() => {}} />;", None),
+        ("<input onClick={() => void 0} className=\"foo\" />", None),
+        ("<input type=\"text\" onClick={() => void 0} />", None),
+        ("<menuitem onClick={() => {}} />;", None),
+        ("<option onClick={() => void 0} className=\"foo\" />", None),
+        ("<select onClick={() => void 0} className=\"foo\" />", None),
+        ("<textarea onClick={() => void 0} className=\"foo\" />", None),
+        ("<tr onClick={() => {}} />;", None),
+        ("<video onClick={() => {}} />;", None),
+        ("<article onClick={() => {}} />;", None),
+        ("<aside onClick={() => {}} />;", None),
+        ("<blockquote onClick={() => {}} />;", None),
+        ("<form onClick={() => {}} />;", None),
+        ("<h1 onClick={() => {}} />;", None),
+        ("<img onClick={() => {}} />;", None),
+        ("<li onClick={() => {}} />;", None),
+        ("<ul onClick={() => {}} />;", None),
+        ("<section onClick={() => {}} aria-label=\"Aardvark\" />;", None),
+        ("<div contentEditable=\"true\" onKeyDown={() => {}} />;", None),
+        ("<div onClick={() => void 0} role=\"button\" />", None),
+        ("<div onClick={() => void 0} role=\"checkbox\" />", None),
+        ("<div onClick={() => void 0} role=\"link\" />", None),
+        ("<div onClick={() => void 0} role=\"textbox\" />", None),
+        ("<div onClick={() => void 0} role=\"article\" />", None),
+        ("<div onClick={() => void 0} role=\"listitem\" />", None),
+        ("<div onClick={() => void 0} role=\"region\" />", None),
+        ("<div onClick={() => void 0} role=\"section\" />", None),
+        ("<div onClick={() => void 0} role=\"widget\" />", None),
+        ("<div onClick={() => void 0} role=\"presentation\" />", None),
+        ("<div onClick={() => void 0} role=\"none\" />", None),
+        ("<div onCopy={() => {}} />;", None),
+        ("<div onSubmit={() => {}} />;", None),
+        ("<div onLoad={() => {}} />;", None),
+        ("<div onMouseDown={() => {}} />", Some(json!([{ "handlers": ["onClick"] }]))),
+        (
+            "<div role={ROLE_BUTTON} onClick={() => {}} />",
+            Some(json!([{ "allowExpressionValues": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("<div onClick={() => void 0} />;", None),
+        ("<div onClick={() => void 0} role={undefined} />;", None),
+        ("<div onClick={() => void 0} {...props} />;", None),
+        ("<div onKeyUp={() => void 0} aria-hidden={false} />;", None),
+        ("<div onFocus={() => {}} />;", None),
+        ("<div onBlur={() => {}} />;", None),
+        ("<div onMouseDown={() => {}} />;", None),
+        ("<span onDoubleClick={() => {}} />;", None),
+        ("<a onClick={() => void 0} />", None),
+        ("<a onClick={() => {}} />;", None),
+        ("<a tabIndex=\"0\" onClick={() => void 0} />", None),
+        ("<area onClick={() => {}} />;", None),
+        ("<header onClick={() => {}} />;", None),
+        ("<section onClick={() => {}} />;", None),
+        ("<div contentEditable=\"false\" onKeyDown={() => {}} />;", None),
+        ("<div role={ROLE_BUTTON} onClick={() => {}} />", None),
+        ("<div onClick={() => {}} />", Some(json!([{ "handlers": ["onClick"] }]))),
+    ];
+
+    Tester::new(NoStaticElementInteractions::NAME, NoStaticElementInteractions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,192 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode, ast_util::is_function_node, context::LintContext, rule::Rule,
+    utils::cognitive_complexity,
+};
+
+fn cognitive_complexity_diagnostic(
+    complexity: usize,
+    threshold: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Refactor this function to reduce its Cognitive Complexity from {complexity} to the {threshold} allowed."
+    ))
+    .with_help("Extract nested conditions and loops into functions, and return early.")
+    .with_label(span)
+}
+
+const DEFAULT_THRESHOLD: usize = 15;
+
+#[derive(Debug, Clone)]
+pub struct CognitiveComplexity {
+    threshold: usize,
+}
+
+impl Default for CognitiveComplexity {
+    fn default() -> Self {
+        Self { threshold: DEFAULT_THRESHOLD }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a maximum cognitive complexity of functions, as defined by
+    /// [SonarSource](https://www.sonarsource.com/docs/CognitiveComplexity.pdf).
+    ///
+    /// Unlike the cyclomatic complexity of `eslint/complexity`, it measures how
+    /// hard the control flow is to follow:
+    /// * `if`, conditional expressions, `switch`, loops and `catch` add one,
+    ///   plus one for every level they are nested in.
+    /// * `else if`, `else`, labelled `break` and `continue`, and every sequence
+    ///   of like logical operators add one.
+    ///
+    /// Nested functions are checked on their own.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Deeply nested and intertwined control flow is hard to understand, so
+    /// such functions are hard to maintain and likely to hide bugs.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule with `{ "threshold": 3 }`:
+    /// ```js
+    /// function sumOfPrimes(max) {
+    ///   let total = 0;
+    ///   outer: for (let i = 1; i <= max; ++i) { // +1
+    ///     for (let j = 2; j < i; ++j) {         // +2 (nesting = 1)
+    ///       if (i % j === 0) {                  // +3 (nesting = 2)
+    ///         continue outer;                   // +1
+    ///       }
+    ///     }
+    ///     total += i;
+    ///   }
+    ///   return total;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with `{ "threshold": 3 }`:
+    /// ```js
+    /// function getWords(number) {
+    ///   switch (number) { // +1
+    ///     case 1:
+    ///       return "one";
+    ///     case 2:
+    ///       return "a couple";
+    ///     default:
+    ///       return "lots";
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### threshold
+    ///
+    /// `{ type: number, default: 15 }`
+    ///
+    /// The maximum cognitive complexity allowed. The option can also be given
+    /// as a number, e.g. `"oxc/cognitive-complexity": ["error", 10]`.
+    CognitiveComplexity,
+    oxc,
+    pedantic
+);
+
+impl Rule for CognitiveComplexity {
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let as_usize =
+            |value: &Value| value.as_u64().and_then(|threshold| usize::try_from(threshold).ok());
+        let threshold = config
+            .and_then(as_usize)
+            .or_else(|| config.and_then(|config| config.get("threshold")).and_then(as_usize))
+            .unwrap_or(DEFAULT_THRESHOLD);
+        Self { threshold }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_function_node(node) {
+            return;
+        }
+        let complexity = cognitive_complexity(node.kind());
+        if complexity > self.threshold {
+            ctx.diagnostic(cognitive_complexity_diagnostic(
+                complexity,
+                self.threshold,
+                node.span(),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() {}", Some(json!([0]))),
+        ("function foo() { a(); b(); return c; }", Some(json!([0]))),
+        ("function foo() { if (a) {} }", Some(json!([1]))),
+        ("function foo() { if (a) {} else if (b) {} else {} }", Some(json!([3]))),
+        ("function foo() { if (a) { if (b) {} } }", Some(json!([3]))),
+        ("function foo() { for (;;) { while (a) {} } }", Some(json!([3]))),
+        (
+            "function foo() { switch (a) { case 1: case 2: case 3: break; default: } }",
+            Some(json!([1])),
+        ),
+        ("function foo() { try { a(); } catch (e) {} }", Some(json!([1]))),
+        ("function foo() { try { if (a) {} } finally {} }", Some(json!([1]))),
+        ("function foo() { return a && b && c; }", Some(json!([1]))),
+        ("function foo() { return a && (b && c); }", Some(json!([1]))),
+        ("function foo() { return a && b || c; }", Some(json!([2]))),
+        ("function foo() { return a ? b : c; }", Some(json!([1]))),
+        ("function foo() { label: for (;;) { break label; } }", Some(json!([2]))),
+        ("function foo() { for (;;) { break; } }", Some(json!([1]))),
+        ("function foo() { return () => { if (a) { if (b) {} } }; }", Some(json!([3]))),
+        ("function foo() { class C { x = a ? b : c; } }", Some(json!([0]))),
+        ("var foo = () => a ?? b;", Some(json!([1]))),
+        ("function foo() { if (a) {} }", Some(json!([{ "threshold": 1 }]))),
+        ("function foo() { if (a) {} }", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (a) {} }", Some(json!([0]))),
+        ("function foo() { if (a) {} else if (b) {} else {} }", Some(json!([2]))),
+        ("function foo() { if (a) { if (b) {} } }", Some(json!([2]))),
+        ("function foo() { for (;;) { while (a) { do {} while (b) } } }", Some(json!([5]))),
+        ("function foo() { for (const a of b) { for (const c in a) {} } }", Some(json!([2]))),
+        ("function foo() { if (a) { switch (b) { case 1: } } }", Some(json!([2]))),
+        ("function foo() { try {} catch { if (a) {} } }", Some(json!([2]))),
+        ("function foo() { if (a) { return b ? c : d; } }", Some(json!([2]))),
+        ("function foo() { return a && b || c && d; }", Some(json!([2]))),
+        ("function foo() { label: for (;;) { continue label; } }", Some(json!([1]))),
+        ("var foo = () => { if (a) {} };", Some(json!([0]))),
+        ("class C { foo() { if (a) {} } }", Some(json!([0]))),
+        ("function foo() { return () => { if (a) { if (b) {} } }; }", Some(json!([2]))),
+        (
+            "function sumOfPrimes(max) {
+                let total = 0;
+                outer: for (let i = 1; i <= max; ++i) {
+                    for (let j = 2; j < i; ++j) {
+                        if (i % j === 0) {
+                            continue outer;
+                        }
+                    }
+                    total += i;
+                }
+                return total;
+            }",
+            Some(json!([{ "threshold": 3 }])),
+        ),
+    ];
+
+    Tester::new(CognitiveComplexity::NAME, CognitiveComplexity::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -1,8 +1,8 @@
 use oxc_ast::{
     AstKind,
     ast::{
-        Argument, AssignmentTarget, BindingPatternKind, CallExpression, Expression, ForInStatement,
-        ForOfStatement, ForStatement, VariableDeclarationKind,
+        Argument, BindingPatternKind, CallExpression, Expression, ForInStatement, ForOfStatement,
+        ForStatement, VariableDeclarationKind,
     },
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -15,6 +15,7 @@ use crate::{
     ast_util::{call_expr_method_callee_info, is_method_call},
     context::LintContext,
     rule::Rule,
+    utils::{DefinitionKind, reaching_definitions},
 };
 
 fn reduce_likely_array_spread_diagnostic(spread_span: Span, reduce_span: Span) -> OxcDiagnostic {
@@ -148,6 +149,7 @@ impl Rule for NoAccumulatingSpread {
             declaration,
             ctx.nodes().get_node(declaration_id),
             referenced_symbol_id,
+            reference.node_id(),
             node.id(),
             spread.span,
             ctx,
@@ -194,6 +196,7 @@ fn check_loop_usage<'a>(
     declaration_node: &AstNode<'a>,
     declarator: &AstNode<'a>,
     referenced_symbol_id: SymbolId,
+    reference_node_id: NodeId,
     spread_node_id: NodeId,
     spread_span: Span,
     ctx: &LintContext<'a>,
@@ -208,12 +211,16 @@ fn check_loop_usage<'a>(
         return;
     };
 
-    let Some(assignment_expr) = find_assignment_expression(referenced_symbol_id, ctx) else {
-        return;
-    };
-
-    let Some(expression_type) =
-        get_spread_containing_expression_type(&assignment_expr.right, spread_span)
+    // The assignment which spreads the variable into its new value, e.g. `acc = [...acc, x]`,
+    // reaches the spread on the next iteration of a loop.
+    let Some(expression_type) = reaching_definitions(referenced_symbol_id, reference_node_id, ctx)
+        .iter()
+        .find_map(|def| match def.kind {
+            DefinitionKind::Value(value) if def.span.contains_inclusive(spread_span) => {
+                get_spread_containing_expression_type(value, spread_span)
+            }
+            _ => None,
+        })
     else {
         return;
     };
@@ -228,39 +235,6 @@ fn check_loop_usage<'a>(
     );
 }
 
-/// Find the assignment expression that writes to the referenced symbol
-fn find_assignment_expression<'a>(
-    referenced_symbol_id: SymbolId,
-    ctx: &LintContext<'a>,
-) -> Option<&'a oxc_ast::ast::AssignmentExpression<'a>> {
-    let write_reference =
-        ctx.semantic().symbol_references(referenced_symbol_id).find(|r| r.is_write())?;
-    let parent_node = ctx.nodes().parent_node(write_reference.node_id());
-
-    if let AstKind::AssignmentExpression(expr) = parent_node.kind() {
-        // Verify this assignment is to our symbol
-        if is_assignment_to_symbol(&expr.left, referenced_symbol_id, ctx) {
-            return Some(expr);
-        }
-    }
-    None
-}
-
-/// Check if the assignment target is our referenced symbol
-fn is_assignment_to_symbol(
-    assignment_target: &AssignmentTarget,
-    referenced_symbol_id: SymbolId,
-    ctx: &LintContext,
-) -> bool {
-    if let AssignmentTarget::AssignmentTargetIdentifier(ident) = assignment_target {
-        let scoping = ctx.semantic().scoping();
-        let reference = scoping.get_reference(ident.reference_id());
-        reference.symbol_id() == Some(referenced_symbol_id)
-    } else {
-        false
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 enum SpreadExpressionType {
     Array,
@@ -449,6 +423,8 @@ fn test() {
         "let foo = {}; for (let i of [1,2,3]) { foo[i] = i; }",
         "let foo = {}; for (const i of [1,2,3]) { foo[i] = i; }",
         "let foo = {}; while (Object.keys(foo).length < 10) { foo[Object.keys(foo).length] = Object.keys(foo).length; }",
+        // the spread only runs once
+        "let foo = []; for (const i of [1,2,3]) { foo = [...foo, i]; break; }",
     ];
 
     let fail = vec![
@@ -513,6 +489,7 @@ fn test() {
         "let foo = {}; for (let i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; for (const i of [1,2,3]) { foo = { ...foo, [i]: i }; }",
         "let foo = {}; while (Object.keys(foo).length < 10) { foo = { ...foo, [Object.keys(foo).length]: Object.keys(foo).length }; }",
+        "let foo = []; foo = bar; for (const i of [1,2,3]) { foo = [...foo, i]; }",
     ];
 
     Tester::new(NoAccumulatingSpread::NAME, NoAccumulatingSpread::PLUGIN, pass, fail)
@@ -0,0 +1,143 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        JestFnKind, JestGeneralFnKind, PLAYWRIGHT_ASYNC_MATCHERS, is_promise_handled,
+        parse_playwright_fn_call,
+    },
+};
+
+fn missing_playwright_await_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` must be awaited or returned"))
+        .with_help("Add `await` before the call.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MissingPlaywrightAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the promises returned by async matchers like
+    /// `toBeVisible()`, `expect.poll()` and `test.step()` are handled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The web-first assertions of Playwright retry until they pass, and
+    /// return a promise. When the promise is not awaited, the test continues
+    /// immediately, and a failing assertion is not reported by the test.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test('shows the title', async ({ page }) => {
+    ///   expect(page).toHaveTitle('Home');
+    ///   expect.poll(() => fetchCount()).toBe(1);
+    ///   test.step('open menu', async () => {});
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('shows the title', async ({ page }) => {
+    ///   await expect(page).toHaveTitle('Home');
+    ///   await expect.poll(() => fetchCount()).toBe(1);
+    ///   await test.step('open menu', async () => {});
+    /// });
+    /// ```
+    MissingPlaywrightAwait,
+    playwright,
+    correctness,
+    fix
+);
+
+impl Rule for MissingPlaywrightAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(playwright_fn_call) = parse_playwright_fn_call(call, ctx) else {
+            return;
+        };
+        let members = &playwright_fn_call.members;
+        let name = match playwright_fn_call.kind {
+            JestFnKind::Expect => {
+                let Some(matcher) = members.last() else {
+                    return;
+                };
+                let is_polling = playwright_fn_call.has_member("poll") && matcher != "poll";
+                if !is_polling && !PLAYWRIGHT_ASYNC_MATCHERS.contains(&matcher.as_ref()) {
+                    return;
+                }
+                matcher.as_ref()
+            }
+            JestFnKind::General(JestGeneralFnKind::Test)
+                if members.first().is_some_and(|member| member == "step") =>
+            {
+                "test.step"
+            }
+            _ => return,
+        };
+        if is_promise_handled(node, ctx) {
+            return;
+        }
+        ctx.diagnostic_with_fix(missing_playwright_await_diagnostic(call.span, name), |fixer| {
+            fixer.insert_text_before_range(call.span, "await ")
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "test('a', async ({ page }) => { await expect(page).toHaveTitle('Home'); });",
+        "test('a', async ({ page }) => { await expect(page.getByRole('button')).not.toBeVisible(); });",
+        "test('a', async ({ page }) => { await expect.soft(page.getByText('foo')).toBeVisible(); });",
+        "test('a', async () => { await expect.poll(() => fetchCount()).toBe(1); });",
+        "test('a', async () => { await test.step('step', async () => {}); });",
+        "test('a', ({ page }) => { return expect(page).toHaveURL('/'); });",
+        "test('a', ({ page }) => expect(page).toHaveURL('/'));",
+        "test('a', async ({ page }) => { await Promise.all([expect(a).toBeVisible(), expect(b).toBeVisible()]); });",
+        "test('a', async ({ page }) => { const promise = expect(page).toHaveURL('/'); await promise; });",
+        "test('a', () => { expect(1).toBe(1); expect(value).toEqual({}); });",
+        "test('a', () => { expect.poll(() => 1); });",
+        "test('a', async ({ page }) => { await page.goto('/'); foo.toBeVisible(); });",
+        "import { expect } from 'vitest'; expect(a).toBeVisible();",
+    ];
+
+    let fail = vec![
+        "test('a', async ({ page }) => { expect(page).toHaveTitle('Home'); });",
+        "test('a', async ({ page }) => { expect(page.getByRole('button')).not.toBeVisible(); });",
+        "test('a', async ({ page }) => { expect.soft(page.getByText('foo')).toBeVisible(); });",
+        "test('a', async () => { expect.poll(() => fetchCount()).toBe(1); });",
+        "test('a', async () => { test.step('step', async () => {}); });",
+        "test('a', async ({ page }) => { const promise = expect(page).toHaveURL('/'); });",
+        "import { test as it, expect as check } from '@playwright/test'; it('a', async ({ page }) => { check(page).toHaveURL('/'); });",
+        "const test = base.extend({}); test('a', async () => { test.step('step', async () => {}); });",
+    ];
+
+    let fix = vec![
+        (
+            "test('a', async ({ page }) => { expect(page).toHaveTitle('Home'); });",
+            "test('a', async ({ page }) => { await expect(page).toHaveTitle('Home'); });",
+        ),
+        (
+            "test('a', async () => { test.step('step', async () => {}); });",
+            "test('a', async () => { await test.step('step', async () => {}); });",
+        ),
+    ];
+
+    Tester::new(MissingPlaywrightAwait::NAME, MissingPlaywrightAwait::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,77 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_page_method_name};
+
+fn no_element_handle_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected use of element handles with `page.{method}()`."))
+        .with_help("Use locators instead, e.g. `page.locator()` or `page.getByRole()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoElementHandle;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows element handles, i.e. `page.$()` and `page.$$()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An element handle points to a particular DOM element, which may be
+    /// detached or replaced when the page re-renders. Locators find the
+    /// element again each time they are used, and wait until it is actionable.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const button = await page.$('button');
+    /// const items = await page.$$('li');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const button = page.getByRole('button');
+    /// const items = page.locator('li');
+    /// ```
+    NoElementHandle,
+    playwright,
+    style
+);
+
+impl Rule for NoElementHandle {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if let Some(method @ ("$" | "$$")) = get_page_method_name(call) {
+            ctx.diagnostic(no_element_handle_diagnostic(call.span, method));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const button = page.getByRole('button');",
+        "const items = page.locator('li');",
+        "const el = $('button');",
+        "const el = jquery.$('button');",
+        "const result = await page.$eval('button', (el) => el.textContent);",
+    ];
+
+    let fail = vec![
+        "const button = await page.$('button');",
+        "const items = await page.$$('li');",
+        "const button = await this.page.$('button');",
+        "const items = page['$$']('li');",
+    ];
+
+    Tester::new(NoElementHandle::NAME, NoElementHandle::PLUGIN, pass, fail).test_and_snapshot();
+}
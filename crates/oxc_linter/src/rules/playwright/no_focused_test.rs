@@ -0,0 +1,120 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{JestFnKind, JestGeneralFnKind, parse_playwright_fn_call},
+};
+
+fn no_focused_test_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected focused test.")
+        .with_help("Remove `.only` from the test.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFocusedTest;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows focused tests, i.e. `test.only()` and `test.describe.only()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Focusing a test is useful while debugging it, but a committed `.only`
+    /// silently skips every other test in the file.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test.only('focus this test', async ({ page }) => {});
+    /// test.describe.only('focus this suite', () => {});
+    /// test.describe.parallel.only('focus this suite', () => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('this test', async ({ page }) => {});
+    /// test.describe('this suite', () => {});
+    /// ```
+    NoFocusedTest,
+    playwright,
+    correctness,
+    fix
+);
+
+impl Rule for NoFocusedTest {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(playwright_fn_call) = parse_playwright_fn_call(call, ctx) else {
+            return;
+        };
+        if !matches!(
+            playwright_fn_call.kind,
+            JestFnKind::General(JestGeneralFnKind::Test | JestGeneralFnKind::Describe)
+        ) || !playwright_fn_call.has_member("only")
+        {
+            return;
+        }
+        let Some(only_span) = find_only_span(&call.callee) else {
+            return;
+        };
+        ctx.diagnostic_with_fix(no_focused_test_diagnostic(only_span), |fixer| {
+            fixer.delete_range(only_span)
+        });
+    }
+}
+
+/// The span of `.only` or `['only']` in the callee, e.g. `test.describe.only`.
+fn find_only_span(callee: &Expression) -> Option<Span> {
+    let member = callee.as_member_expression()?;
+    if member.static_property_name() == Some("only") {
+        return Some(Span::new(member.object().span().end, member.span().end));
+    }
+    find_only_span(member.object())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "test('a', async ({ page }) => {});",
+        "test.describe('a', () => {});",
+        "test.skip('a', async ({ page }) => {});",
+        "test.describe.parallel('a', () => {});",
+        "test.step('a', async () => {});",
+        "const only = test.only;",
+        "it.only('a', () => {});",
+        "foo.only('a', () => {});",
+        "import { test } from 'vitest'; test.only('a', () => {});",
+    ];
+
+    let fail = vec![
+        "test.only('a', async ({ page }) => {});",
+        "test.describe.only('a', () => {});",
+        "test.describe.parallel.only('a', () => {});",
+        "test.describe.serial.only('a', () => {});",
+        "test['only']('a', async ({ page }) => {});",
+        "import { test as it } from '@playwright/test'; it.only('a', async () => {});",
+    ];
+
+    let fix = vec![
+        ("test.only('a', async ({ page }) => {});", "test('a', async ({ page }) => {});"),
+        ("test.describe.only('a', () => {});", "test.describe('a', () => {});"),
+        ("test.describe.serial.only('a', () => {});", "test.describe.serial('a', () => {});"),
+        ("test['only']('a', () => {});", "test('a', () => {});"),
+    ];
+
+    Tester::new(NoFocusedTest::NAME, NoFocusedTest::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
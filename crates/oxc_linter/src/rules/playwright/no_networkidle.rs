@@ -0,0 +1,127 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_page_method_name};
+
+fn no_networkidle_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of `networkidle` option.")
+        .with_help("Wait for the content the test needs instead, e.g. with web-first assertions.")
+        .with_label(span)
+}
+
+/// Page methods which accept a `waitUntil` option.
+const NAVIGATION_METHODS: [&str; 7] =
+    ["goBack", "goForward", "goto", "reload", "setContent", "waitForNavigation", "waitForURL"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNetworkidle;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows waiting for the `networkidle` load state.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `networkidle` waits until there has been no network activity for
+    /// 500ms, which makes tests slow, and never happens on pages which poll
+    /// or stream. Playwright discourages it for testing.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// await page.waitForLoadState('networkidle');
+    /// await page.goto('/', { waitUntil: 'networkidle' });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// await page.waitForLoadState();
+    /// await page.goto('/');
+    /// await expect(page.getByRole('table')).toBeVisible();
+    /// ```
+    NoNetworkidle,
+    playwright,
+    suspicious
+);
+
+impl Rule for NoNetworkidle {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(method) = get_page_method_name(call) else {
+            return;
+        };
+        if method == "waitForLoadState" {
+            if let Some(span) =
+                call.arguments.first().and_then(Argument::as_expression).and_then(networkidle_span)
+            {
+                ctx.diagnostic(no_networkidle_diagnostic(span));
+            }
+            return;
+        }
+        if !NAVIGATION_METHODS.contains(&method) {
+            return;
+        }
+        for argument in &call.arguments {
+            let Argument::ObjectExpression(options) = argument else {
+                continue;
+            };
+            let wait_until = options.properties.iter().find_map(|prop| match prop {
+                ObjectPropertyKind::ObjectProperty(prop)
+                    if prop.key.is_specific_static_name("waitUntil") =>
+                {
+                    Some(&prop.value)
+                }
+                _ => None,
+            });
+            if let Some(span) = wait_until.and_then(networkidle_span) {
+                ctx.diagnostic(no_networkidle_diagnostic(span));
+            }
+        }
+    }
+}
+
+/// The span of `expr` if it is the string `'networkidle'`.
+fn networkidle_span(expr: &Expression) -> Option<Span> {
+    match expr {
+        Expression::StringLiteral(lit) if lit.value == "networkidle" => Some(lit.span),
+        Expression::TemplateLiteral(lit)
+            if lit.single_quasi().is_some_and(|quasi| quasi == "networkidle") =>
+        {
+            Some(lit.span)
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await page.waitForLoadState(); }",
+        "async function f() { await page.waitForLoadState('load'); }",
+        "async function f() { await page.goto('/'); }",
+        "async function f() { await page.goto('/', { waitUntil: 'domcontentloaded' }); }",
+        "async function f() { await page.click('button', { waitUntil: 'networkidle' }); }",
+        "async function f() { await other.waitForLoadState('networkidle'); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await page.waitForLoadState('networkidle'); }",
+        "async function f() { await page.waitForLoadState(`networkidle`); }",
+        "async function f() { await page.goto('/', { waitUntil: 'networkidle' }); }",
+        "async function f() { await this.page.reload({ waitUntil: 'networkidle' }); }",
+        "async function f() { await page.waitForURL('/done', { timeout: 1000, waitUntil: 'networkidle' }); }",
+    ];
+
+    Tester::new(NoNetworkidle::NAME, NoNetworkidle::PLUGIN, pass, fail).test_and_snapshot();
+}
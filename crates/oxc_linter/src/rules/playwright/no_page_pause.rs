@@ -0,0 +1,73 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_page_method_name};
+
+fn no_page_pause_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of `page.pause()`.")
+        .with_help("Remove the call before committing the test.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPagePause;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `page.pause()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `page.pause()` stops the test and opens the Playwright Inspector. It is
+    /// useful while debugging, but a committed call hangs the test when it is
+    /// run in headed mode.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// await page.pause();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// await page.click('button');
+    /// ```
+    NoPagePause,
+    playwright,
+    suspicious
+);
+
+impl Rule for NoPagePause {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if get_page_method_name(call) == Some("pause") {
+            ctx.diagnostic(no_page_pause_diagnostic(call.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await page.click('button'); }",
+        "async function f() { await video.pause(); }",
+        "async function f() { await this.player.pause(); }",
+        "function pause() {} pause();",
+    ];
+
+    let fail = vec![
+        "async function f() { await page.pause(); }",
+        "async function f() { await this.page.pause(); }",
+        "async function f() { page['pause'](); }",
+    ];
+
+    Tester::new(NoPagePause::NAME, NoPagePause::PLUGIN, pass, fail).test_and_snapshot();
+}
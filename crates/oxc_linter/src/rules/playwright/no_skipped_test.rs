@@ -0,0 +1,148 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{JestFnKind, JestGeneralFnKind, parse_playwright_fn_call},
+};
+
+fn no_skipped_test_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected skipped test.")
+        .with_help("Remove `.skip` or `.fixme` to run the test.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoSkippedTest {
+    allow_conditional: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows skipped tests, i.e. `test.skip()`, `test.fixme()` and
+    /// `test.describe.skip()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Skipped tests are easily forgotten, and the code they cover is no
+    /// longer tested.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test.skip('skip this test', async ({ page }) => {});
+    /// test.describe.skip('skip this suite', () => {});
+    /// test('skip in Firefox', async ({ page, browserName }) => {
+    ///   test.skip(browserName === 'firefox', 'Working on it');
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('this test', async ({ page }) => {});
+    /// test.describe('this suite', () => {});
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowConditional
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allows skipping a test on a condition, e.g.
+    /// `test.skip(browserName === 'firefox', 'Working on it')`.
+    NoSkippedTest,
+    playwright,
+    pedantic
+);
+
+impl Rule for NoSkippedTest {
+    fn from_configuration(value: Value) -> Self {
+        Self {
+            allow_conditional: value
+                .get(0)
+                .and_then(|value| value.get("allowConditional"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(playwright_fn_call) = parse_playwright_fn_call(call, ctx) else {
+            return;
+        };
+        if !matches!(
+            playwright_fn_call.kind,
+            JestFnKind::General(JestGeneralFnKind::Test | JestGeneralFnKind::Describe)
+        ) || !(playwright_fn_call.has_member("skip") || playwright_fn_call.has_member("fixme"))
+        {
+            return;
+        }
+        if self.allow_conditional && is_conditional(&call.arguments) {
+            return;
+        }
+        ctx.diagnostic(no_skipped_test_diagnostic(call.callee.span()));
+    }
+}
+
+/// Whether the call skips on a condition instead of declaring a skipped test with a title,
+/// e.g. `test.skip(browserName === 'firefox')`.
+fn is_conditional(arguments: &[Argument]) -> bool {
+    arguments.first().and_then(Argument::as_expression).is_some_and(|expr| {
+        !matches!(expr, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("test('a', async ({ page }) => {});", None),
+        ("test.describe('a', () => {});", None),
+        ("test.only('a', async ({ page }) => {});", None),
+        ("test.slow('a', async ({ page }) => {});", None),
+        ("it.skip('a', () => {});", None),
+        ("describe.skip('a', () => {});", None),
+        ("import { test } from 'vitest'; test.skip('a', () => {});", None),
+        (
+            "test('a', async ({ browserName }) => { test.skip(browserName === 'firefox', 'Working on it'); });",
+            Some(json!([{ "allowConditional": true }])),
+        ),
+        (
+            "test.describe('a', () => { test.fixme(({ browserName }) => browserName === 'webkit'); });",
+            Some(json!([{ "allowConditional": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("test.skip('a', async ({ page }) => {});", None),
+        ("test.fixme('a', async ({ page }) => {});", None),
+        ("test.describe.skip('a', () => {});", None),
+        ("test.describe.fixme('a', () => {});", None),
+        ("test.describe.parallel.skip('a', () => {});", None),
+        ("test('a', async () => { test.skip(); });", None),
+        (
+            "test('a', async ({ browserName }) => { test.skip(browserName === 'firefox', 'Working on it'); });",
+            None,
+        ),
+        ("test.skip('a', async ({ page }) => {});", Some(json!([{ "allowConditional": true }]))),
+        ("test('a', async () => { test.skip(); });", Some(json!([{ "allowConditional": true }]))),
+    ];
+
+    Tester::new(NoSkippedTest::NAME, NoSkippedTest::PLUGIN, pass, fail).test_and_snapshot();
+}
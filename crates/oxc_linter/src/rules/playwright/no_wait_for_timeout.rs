@@ -0,0 +1,76 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::get_page_method_name};
+
+fn no_wait_for_timeout_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of `page.waitForTimeout()`.")
+        .with_help("Wait for an element or a network response instead, e.g. with `expect(locator).toBeVisible()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoWaitForTimeout;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows `page.waitForTimeout()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Waiting for a fixed time makes tests slow when the time is too long,
+    /// and flaky when it is too short. Web-first assertions and locators wait
+    /// exactly as long as needed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// await page.waitForTimeout(5000);
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// await expect(page.getByRole('dialog')).toBeVisible();
+    /// await page.waitForResponse('**/api/data');
+    /// ```
+    NoWaitForTimeout,
+    playwright,
+    suspicious
+);
+
+impl Rule for NoWaitForTimeout {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if get_page_method_name(call) == Some("waitForTimeout") {
+            ctx.diagnostic(no_wait_for_timeout_diagnostic(call.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() { await page.waitForResponse('**/api/data'); }",
+        "async function f() { await expect(page.getByRole('dialog')).toBeVisible(); }",
+        "async function f() { await page.waitForLoadState(); }",
+        "function waitForTimeout() {} waitForTimeout(1000);",
+        "async function f() { await scheduler.waitForTimeout(1000); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await page.waitForTimeout(5000); }",
+        "async function f() { await this.page.waitForTimeout(2000); }",
+        "async function f() { page.waitForTimeout(1000); }",
+        "async function f() { await page['waitForTimeout'](1000); }",
+    ];
+
+    Tester::new(NoWaitForTimeout::NAME, NoWaitForTimeout::PLUGIN, pass, fail).test_and_snapshot();
+}
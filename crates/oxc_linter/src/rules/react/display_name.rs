@@ -0,0 +1,356 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, ClassElement, MethodDefinitionKind, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        get_class_static_property, get_component_name, get_component_symbol_id,
+        get_static_property_assignment, is_component_wrapper_call, is_es5_component,
+        is_es6_component, is_function_component, is_react_function_call,
+    },
+};
+
+fn display_name_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Component definition is missing display name")
+        .with_help("Give the component a name, or set its `displayName` property.")
+        .with_label(span)
+}
+
+fn context_display_name_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Context definition is missing display name")
+        .with_help("Assign the context to a variable and set its `displayName` property.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DisplayName {
+    ignore_transpiler_name: bool,
+    check_context_objects: bool,
+}
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/display-name.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/display-name.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/display-name.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that components have a display name, either from their
+    /// definition, e.g. `function Hello() {}`, or from an explicit
+    /// `displayName` property.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React DevTools and error messages identify components by their display
+    /// name. Anonymous components show up as `Anonymous`, which makes them hard
+    /// to find when debugging.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// export default function () {
+    ///   return <div />;
+    /// }
+    ///
+    /// const Hello = React.memo(({ name }) => <div>{name}</div>);
+    ///
+    /// module.exports = createReactClass({
+    ///   render() { return <div />; },
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// export default function Hello() {
+    ///   return <div />;
+    /// }
+    ///
+    /// const Hello = React.memo(function Hello({ name }) { return <div>{name}</div>; });
+    ///
+    /// const Hello = React.forwardRef((props, ref) => <div ref={ref} />);
+    /// Hello.displayName = 'Hello';
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignoreTranspilerName
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Ignore the names which are given to components by their definition, and
+    /// require an explicit `displayName` on every component.
+    ///
+    /// #### checkContextObjects
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Also require a `displayName` on contexts created with `createContext`.
+    DisplayName,
+    react,
+    pedantic
+);
+
+impl Rule for DisplayName {
+    fn from_configuration(value: Value) -> Self {
+        let value = value.get(0);
+        let option = |key: &str| {
+            value.and_then(|value| value.get(key)).and_then(Value::as_bool).unwrap_or(false)
+        };
+        Self {
+            ignore_transpiler_name: option("ignoreTranspilerName"),
+            check_context_objects: option("checkContextObjects"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::Class(class) if is_es6_component(node) => {
+                let has_display_name = get_class_static_property(class, "displayName").is_some()
+                    || class.body.body.iter().any(|element| {
+                        matches!(element, ClassElement::MethodDefinition(method)
+                            if method.r#static
+                                && method.kind == MethodDefinitionKind::Get
+                                && method.key.is_specific_static_name("displayName"))
+                    });
+                if has_display_name || self.has_display_name(node, ctx) {
+                    return;
+                }
+                ctx.diagnostic(display_name_diagnostic(
+                    class.id.as_ref().map_or(class.span, GetSpan::span),
+                ));
+            }
+            AstKind::CallExpression(call) if is_es5_component(node) => {
+                let Some(Argument::ObjectExpression(spec)) = call.arguments.first() else {
+                    return;
+                };
+                let has_display_name = spec.properties.iter().any(|prop| {
+                    matches!(prop, ObjectPropertyKind::ObjectProperty(prop)
+                        if prop.key.is_specific_static_name("displayName"))
+                });
+                if has_display_name || self.has_display_name(node, ctx) {
+                    return;
+                }
+                ctx.diagnostic(display_name_diagnostic(call.span));
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                if is_function_component(node, ctx) =>
+            {
+                if self.has_display_name(node, ctx) {
+                    return;
+                }
+                let span = match node.kind() {
+                    AstKind::Function(func) => func.id.as_ref().map_or(func.span, GetSpan::span),
+                    kind => kind.span(),
+                };
+                ctx.diagnostic(display_name_diagnostic(span));
+            }
+            AstKind::CallExpression(call)
+                if self.check_context_objects && is_react_function_call(call, "createContext") =>
+            {
+                let AstKind::VariableDeclarator(decl) = ctx.nodes().parent_kind(node.id()) else {
+                    ctx.diagnostic(context_display_name_diagnostic(call.span));
+                    return;
+                };
+                let Some(id) = decl.id.get_binding_identifier() else {
+                    return;
+                };
+                if get_static_property_assignment(id.symbol_id(), "displayName", ctx).is_none() {
+                    ctx.diagnostic(context_display_name_diagnostic(call.span));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+impl DisplayName {
+    /// Whether the component has a `displayName` assigned after its definition, or, unless
+    /// `ignoreTranspilerName` is set, a name from its definition.
+    ///
+    /// A function wrapped in `memo` or `forwardRef` is only named by its own name, e.g.
+    /// `memo(function Hello() {})`.
+    fn has_display_name<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+        if get_component_symbol_id(node, ctx).is_some_and(|symbol_id| {
+            get_static_property_assignment(symbol_id, "displayName", ctx).is_some()
+        }) {
+            return true;
+        }
+        if self.ignore_transpiler_name {
+            return false;
+        }
+        if is_wrapped(node, ctx) {
+            return matches!(node.kind(), AstKind::Function(func) if func.id.is_some());
+        }
+        get_component_name(node, ctx).is_some()
+    }
+}
+
+/// Whether `node` is the argument of a `memo` or `forwardRef` call.
+fn is_wrapped<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    ctx.nodes()
+        .ancestors(node.id())
+        .find(|parent| {
+            !matches!(parent.kind(), AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+        })
+        .is_some_and(|parent| {
+            matches!(parent.kind(), AstKind::CallExpression(call) if is_component_wrapper_call(call)
+                && call.arguments.first().and_then(Argument::as_expression)
+                    .is_some_and(|arg| arg.get_inner_expression().span() == node.span()))
+        })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "var Hello = createReactClass({
+               displayName: 'Hello',
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "var Hello = React.createClass({
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }
+             Hello.displayName = 'Hello';",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "class Hello extends React.Component {
+               static displayName = 'Hello';
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "class Hello extends React.Component {
+               static get displayName() { return 'Hello'; }
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            None,
+        ),
+        (
+            "export default class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            None,
+        ),
+        ("function Hello(props) { return <div>Hello {props.name}</div>; }", None),
+        ("const Hello = (props) => <div>Hello {props.name}</div>;", None),
+        ("export default function Hello() { return <div />; }", None),
+        (
+            "const Hello = (props) => <div>Hello {props.name}</div>;
+             Hello.displayName = 'Hello';",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "function Hello(props) { return <div>Hello {props.name}</div>; }
+             Hello.displayName = 'Hello';",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        ("const Hello = React.memo(function Hello() { return <div />; });", None),
+        (
+            "const Hello = forwardRef(function Hello(props, ref) { return <div ref={ref} />; });",
+            None,
+        ),
+        (
+            "const Hello = React.memo((props) => <div />);
+             Hello.displayName = 'Hello';",
+            None,
+        ),
+        (
+            "const Hello = React.memo(React.forwardRef((props, ref) => <div ref={ref} />));
+             Hello.displayName = 'Hello';",
+            None,
+        ),
+        ("module.exports = { Hello: () => <div /> };", None),
+        (
+            "function renderItem() { return <div />; }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "class Foo { render() { return <div />; } }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        ("const Context = React.createContext();", None),
+        (
+            "const Context = createContext();
+             Context.displayName = 'Context';",
+            Some(json!([{ "checkContextObjects": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "var Hello = createReactClass({
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "module.exports = createReactClass({
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        (
+            "export default class extends React.Component {
+               render() { return <div />; }
+             }",
+            None,
+        ),
+        (
+            "function Hello(props) { return <div>Hello {props.name}</div>; }",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        ("export default function () { return <div />; }", None),
+        ("export default () => <div />;", None),
+        ("module.exports = () => <div />;", None),
+        ("const Hello = React.memo((props) => <div />);", None),
+        ("const Hello = forwardRef((props, ref) => <div ref={ref} />);", None),
+        ("export default React.memo(function () { return <div />; });", None),
+        (
+            "const Hello = React.memo(function Hello() { return <div />; });",
+            Some(json!([{ "ignoreTranspilerName": true }])),
+        ),
+        ("const Context = React.createContext();", Some(json!([{ "checkContextObjects": true }]))),
+        ("export default createContext();", Some(json!([{ "checkContextObjects": true }]))),
+    ];
+
+    Tester::new(DisplayName::NAME, DisplayName::PLUGIN, pass, fail).test_and_snapshot();
+}
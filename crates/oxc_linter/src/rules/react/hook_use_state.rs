@@ -0,0 +1,199 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPattern, BindingPatternKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_react_function_call,
+};
+
+fn hook_use_state_diagnostic(span: Span, value_name: Option<&str>) -> OxcDiagnostic {
+    let help = match value_name {
+        Some(name) => {
+            let mut chars = name.chars();
+            let capitalized = chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default();
+            format!("Destructure the state as `[{name}, set{capitalized}]`.")
+        }
+        None => "Destructure the state as `[value, setValue]`.".to_string(),
+    };
+    OxcDiagnostic::warn("useState call is not destructured into value + setter pair")
+        .with_help(help)
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HookUseState {
+    allow_destructured_state: bool,
+}
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/hook-use-state.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/hook-use-state.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/hook-use-state.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the result of `useState` is destructured into a value and
+    /// a setter named after it, e.g. `const [color, setColor] = useState()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Consistent names make it obvious which setter updates which state, and
+    /// a state which is not destructured hides that it is a tuple.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// const useStateResult = useState();
+    /// const [color, updateColor] = useState();
+    /// const [color] = useState();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// const [color, setColor] = useState();
+    /// const [isOpen, setIsOpen] = React.useState(false);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowDestructuredState
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allows the value to be destructured, e.g.
+    /// `const [{ foo, bar }, setFooBar] = useState({ foo: 1, bar: 2 })`.
+    HookUseState,
+    react,
+    style
+);
+
+impl Rule for HookUseState {
+    fn from_configuration(value: Value) -> Self {
+        Self {
+            allow_destructured_state: value
+                .get(0)
+                .and_then(|value| value.get("allowDestructuredState"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        if !is_react_function_call(call, "useState") {
+            return;
+        }
+        let AstKind::VariableDeclarator(decl) = ctx.nodes().parent_kind(node.id()) else {
+            ctx.diagnostic(hook_use_state_diagnostic(call.span, None));
+            return;
+        };
+        let BindingPatternKind::ArrayPattern(pattern) = &decl.id.kind else {
+            ctx.diagnostic(hook_use_state_diagnostic(decl.id.span(), None));
+            return;
+        };
+        let value_name = match pattern.elements.first() {
+            Some(Some(BindingPattern {
+                kind: BindingPatternKind::BindingIdentifier(id), ..
+            })) => Some(id.name.as_str()),
+            _ => None,
+        };
+        let is_valid_value = value_name.is_some()
+            || (self.allow_destructured_state
+                && matches!(
+                    pattern.elements.first(),
+                    Some(Some(BindingPattern {
+                        kind: BindingPatternKind::ObjectPattern(_)
+                            | BindingPatternKind::ArrayPattern(_),
+                        ..
+                    }))
+                ));
+        let is_valid_setter = match pattern.elements.get(1) {
+            Some(Some(BindingPattern {
+                kind: BindingPatternKind::BindingIdentifier(id), ..
+            })) => value_name.is_none_or(|value_name| is_setter_name(value_name, &id.name)),
+            _ => false,
+        };
+        if !is_valid_value
+            || !is_valid_setter
+            || pattern.elements.len() != 2
+            || pattern.rest.is_some()
+        {
+            ctx.diagnostic(hook_use_state_diagnostic(pattern.span, value_name));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Whether `setter` is `set` followed by `value` with its first letter uppercased.
+fn is_setter_name(value: &str, setter: &str) -> bool {
+    let Some(rest) = setter.strip_prefix("set") else {
+        return false;
+    };
+    let mut value_chars = value.chars();
+    let mut rest_chars = rest.chars();
+    match (value_chars.next(), rest_chars.next()) {
+        (Some(v), Some(r)) => v.to_uppercase().eq(std::iter::once(r)) && value_chars.eq(rest_chars),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("const [color, setColor] = useState();", None),
+        ("const [color, setColor] = useState('#ffffff');", None),
+        ("const [color, setColor] = React.useState();", None),
+        ("const [color1, setColor1] = useState();", None),
+        ("const [isOpen, setIsOpen] = useState(false);", None),
+        ("const [_color, set_color] = useState();", None),
+        ("const [color, setColor] = useState<string>();", None),
+        ("const [color, setColor] = useState<string>('#ffffff');", None),
+        ("const result = useCustomState();", None),
+        ("const [color, updateColor] = Foo.useState();", None),
+        (
+            "const [{ foo, bar }, setFooBar] = useState({ foo: 'bar', bar: 'baz' });",
+            Some(json!([{ "allowDestructuredState": true }])),
+        ),
+        (
+            "const [[foo, bar], setFooBar] = useState([1, 2]);",
+            Some(json!([{ "allowDestructuredState": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("useState();", None),
+        ("const result = useState();", None),
+        ("const result = React.useState();", None),
+        ("const [color] = useState();", None),
+        ("const [, setColor] = useState();", None),
+        ("const [color, updateColor] = useState();", None),
+        ("const [color, setcolor] = useState();", None),
+        ("const [color, setColor, extra] = useState();", None),
+        ("const [color, ...rest] = useState();", None),
+        ("const { color, setColor } = useState();", None),
+        ("const [{ foo, bar }, setFooBar] = useState({ foo: 'bar', bar: 'baz' });", None),
+        ("const [color, setColor] = useState(), other = useState();", None),
+    ];
+
+    Tester::new(HookUseState::NAME, HookUseState::PLUGIN, pass, fail).test_and_snapshot();
+}
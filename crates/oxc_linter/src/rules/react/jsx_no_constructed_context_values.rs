@@ -0,0 +1,306 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXOpeningElement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::has_jsx_prop,
+};
+
+fn jsx_no_constructed_context_values_diagnostic(
+    construction: Construction,
+    span: Span,
+    usage_span: Span,
+) -> OxcDiagnostic {
+    let hook = if construction.is_function() { "useCallback" } else { "useMemo" };
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "The {} passed as the value prop to the Context provider changes every render.",
+        construction.as_str()
+    ))
+    .with_help(format!("To fix this consider wrapping it in a `{hook}` hook."));
+    if span == usage_span {
+        diagnostic.with_label(span)
+    } else {
+        diagnostic.with_labels([
+            span.label(format!("The {} is constructed here", construction.as_str())),
+            usage_span.label("And passed to the Context provider here"),
+        ])
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoConstructedContextValues;
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/jsx-no-constructed-context-values.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/jsx-no-constructed-context-values.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/jsx-no-constructed-context-values.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows passing values which are constructed during render, like
+    /// object literals or functions, to the `value` prop of a Context provider.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// React re-renders every consumer of a context when the value of its
+    /// provider changes. A value constructed during render is a new value on
+    /// every render, so all consumers re-render every time the provider does,
+    /// even when nothing they use has changed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   return <MyContext.Provider value={{ foo }}>{children}</MyContext.Provider>;
+    /// }
+    ///
+    /// function Component() {
+    ///   const value = { foo };
+    ///   return <MyContext.Provider value={value}>{children}</MyContext.Provider>;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   const value = useMemo(() => ({ foo }), [foo]);
+    ///   return <MyContext.Provider value={value}>{children}</MyContext.Provider>;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <MyContext.Provider value={foo}>{children}</MyContext.Provider>;
+    /// }
+    /// ```
+    JsxNoConstructedContextValues,
+    react,
+    perf
+);
+
+impl Rule for JsxNoConstructedContextValues {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
+            return;
+        };
+        if !is_context_provider(jsx_el) {
+            return;
+        }
+        let Some(JSXAttributeItem::Attribute(attr)) = has_jsx_prop(jsx_el, "value") else {
+            return;
+        };
+        let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+            return;
+        };
+        let Some(expr) = container.expression.as_expression() else {
+            return;
+        };
+        if let Some((construction, span)) = get_construction(expr, ctx) {
+            ctx.diagnostic(jsx_no_constructed_context_values_diagnostic(
+                construction,
+                span,
+                expr.span(),
+            ));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// Whether `jsx_el` is a `<Foo.Provider>`.
+fn is_context_provider(jsx_el: &JSXOpeningElement) -> bool {
+    matches!(&jsx_el.name, JSXElementName::MemberExpression(member) if member.property.name == "Provider")
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Construction {
+    Object,
+    Array,
+    Function,
+    FunctionDeclaration,
+    Class,
+    NewExpression,
+    JsxElement,
+    RegularExpression,
+}
+
+impl Construction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Function => "function expression",
+            Self::FunctionDeclaration => "function declaration",
+            Self::Class => "class",
+            Self::NewExpression => "new expression",
+            Self::JsxElement => "JSX element",
+            Self::RegularExpression => "regular expression",
+        }
+    }
+
+    fn is_function(self) -> bool {
+        matches!(self, Self::Function | Self::FunctionDeclaration)
+    }
+}
+
+/// The kind and span of the value constructed during render that `expr` may evaluate to.
+///
+/// Identifiers are followed to their declaration, unless they are declared in the module scope,
+/// where they are constructed only once.
+fn get_construction<'a>(
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Construction, Span)> {
+    let expr = expr.get_inner_expression();
+    let construction = match expr {
+        Expression::ObjectExpression(_) => Construction::Object,
+        Expression::ArrayExpression(_) => Construction::Array,
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Construction::Function
+        }
+        Expression::ClassExpression(_) => Construction::Class,
+        Expression::NewExpression(_) => Construction::NewExpression,
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => Construction::JsxElement,
+        Expression::RegExpLiteral(_) => Construction::RegularExpression,
+        Expression::ConditionalExpression(cond) => {
+            return get_construction(&cond.consequent, ctx)
+                .or_else(|| get_construction(&cond.alternate, ctx));
+        }
+        Expression::LogicalExpression(logical) => {
+            return get_construction(&logical.left, ctx)
+                .or_else(|| get_construction(&logical.right, ctx));
+        }
+        Expression::AssignmentExpression(assign) => return get_construction(&assign.right, ctx),
+        Expression::Identifier(ident) => {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+            if ctx.scoping().symbol_scope_id(symbol_id) == ctx.scoping().root_scope_id() {
+                return None;
+            }
+            let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+            return match declaration.kind() {
+                AstKind::VariableDeclarator(decl) if decl.id.kind.is_binding_identifier() => {
+                    get_construction(decl.init.as_ref()?, ctx)
+                }
+                AstKind::Function(func) if func.is_declaration() => {
+                    Some((Construction::FunctionDeclaration, func.span))
+                }
+                AstKind::Class(class) if class.is_declaration() => {
+                    Some((Construction::Class, class.span))
+                }
+                _ => None,
+            };
+        }
+        _ => return None,
+    };
+    Some((construction, expr.span()))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const Component = () => <Context.Provider value={props}></Context.Provider>",
+        "const Component = () => <Context.Provider value={100}></Context.Provider>",
+        "const Component = () => <Context.Provider value=\"Some string\"></Context.Provider>",
+        "function Component() {
+           const a = useMemo(() => ({ foo: 'bar' }), []);
+           return <Context.Provider value={a}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = useCallback(() => {}, []);
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "const foo = {};
+         function Component() {
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const a = { foo: 'bar' };
+           return <Context value={a}></Context>;
+         }",
+        "function Component() {
+           return <div value={{}}></div>;
+         }",
+        "function Component({ value }) {
+           return <Context.Provider value={value ?? fallback}></Context.Provider>;
+         }",
+        "function Component() {
+           const [value] = useState({});
+           return <Context.Provider value={value}></Context.Provider>;
+         }",
+        "function Component() {
+           return <Context.Provider value={cond ? a : b}></Context.Provider>;
+         }",
+    ];
+
+    let fail = vec![
+        "function Component() {
+           return <Context.Provider value={{ foo: 'bar' }}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = { a: 'b' };
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = [];
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = new Object();
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = () => {};
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           function foo() {}
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = class {};
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = <div />;
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = /regex/;
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component({ cond }) {
+           const foo = cond ? {} : undefined;
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           const foo = bar || {};
+           return <Context.Provider value={foo}></Context.Provider>;
+         }",
+        "function Component() {
+           let foo;
+           return <Context.Provider value={(foo = {})}></Context.Provider>;
+         }",
+        "function Component() {
+           return <Context.Provider value={{ foo } as Value}></Context.Provider>;
+         }",
+        "const Component = () => <Foo.Bar.Provider value={[]}></Foo.Bar.Provider>",
+    ];
+
+    Tester::new(
+        JsxNoConstructedContextValues::NAME,
+        JsxNoConstructedContextValues::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -0,0 +1,178 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPatternKind, Expression, FormalParameters},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_function_component,
+};
+
+fn no_object_type_as_default_prop_diagnostic(
+    span: Span,
+    prop: &str,
+    value_type: &str,
+) -> OxcDiagnostic {
+    let article = if value_type.starts_with(['a', 'e', 'i', 'o', 'u']) { "an" } else { "a" };
+    OxcDiagnostic::warn(format!(
+        "{prop} has {article} {value_type} as default prop. This could lead to potential infinite render loop in React."
+    ))
+    .with_help(format!(
+        "Use a variable reference instead of {value_type}, e.g. a constant defined outside of the component."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoObjectTypeAsDefaultProp;
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/no-object-type-as-default-prop.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/no-object-type-as-default-prop.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/no-object-type-as-default-prop.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows object literals, arrays, functions and other values which are
+    /// constructed on every call as default values of destructured props.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A default value is constructed again on every render, so the prop is a
+    /// new value every time it isn't passed. When it is used as a dependency of
+    /// `useEffect`, `useMemo` or `useCallback`, the hook runs on every render,
+    /// which may cause an infinite render loop when the effect sets state.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component({ items = [] }) {
+    ///   useEffect(() => setCount(items.length), [items]);
+    ///   return <List items={items} />;
+    /// }
+    ///
+    /// const Component = ({ onClick = () => {} }) => <button onClick={onClick} />;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// const emptyItems = [];
+    /// function Component({ items = emptyItems }) {
+    ///   useEffect(() => setCount(items.length), [items]);
+    ///   return <List items={items} />;
+    /// }
+    ///
+    /// const Component = ({ count = 0, label = 'Hello' }) => <div>{label}{count}</div>;
+    /// ```
+    NoObjectTypeAsDefaultProp,
+    react,
+    suspicious
+);
+
+impl Rule for NoObjectTypeAsDefaultProp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        if !is_function_component(node, ctx) {
+            return;
+        }
+        check_props_pattern(params, ctx);
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+fn check_props_pattern(params: &FormalParameters, ctx: &LintContext) {
+    let Some(param) = params.items.first() else {
+        return;
+    };
+    let pattern = match &param.pattern.kind {
+        BindingPatternKind::AssignmentPattern(assign) => &assign.left.kind,
+        kind => kind,
+    };
+    let BindingPatternKind::ObjectPattern(pattern) = pattern else {
+        return;
+    };
+    for prop in &pattern.properties {
+        let BindingPatternKind::AssignmentPattern(assign) = &prop.value.kind else {
+            continue;
+        };
+        let Some(value_type) = constructed_value_type(&assign.right) else {
+            continue;
+        };
+        let Some(name) = prop.key.static_name() else {
+            continue;
+        };
+        ctx.diagnostic(no_object_type_as_default_prop_diagnostic(
+            assign.right.span(),
+            &name,
+            value_type,
+        ));
+    }
+}
+
+/// The type of the value, if it is a new value every time it is evaluated.
+fn constructed_value_type(expr: &Expression) -> Option<&'static str> {
+    match expr.get_inner_expression() {
+        Expression::ObjectExpression(_) => Some("object"),
+        Expression::ArrayExpression(_) => Some("array"),
+        Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_) => {
+            Some("function")
+        }
+        Expression::ClassExpression(_) => Some("class"),
+        Expression::NewExpression(_) => Some("constructed object"),
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => Some("JSX element"),
+        Expression::RegExpLiteral(_) => Some("regular expression"),
+        Expression::CallExpression(call) if call.callee.is_specific_id("Symbol") => Some("Symbol"),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function Foo({ bar = emptyFunction }) { return null; }",
+        "function Foo({ bar = emptyFunction, ...rest }) { return null; }",
+        "function Foo({ bar = 1, baz = 'a', qux = null, quux = undefined }) { return null; }",
+        "function Foo({ bar = `template` }) { return null; }",
+        "const Foo = ({ bar = emptyObject }) => null;",
+        "function Foo(props) { return <div />; }",
+        "function foo({ bar = {} }) { return null; }",
+        "const useFoo = ({ bar = [] }) => { return bar; };",
+        "function Foo({ bar = {} }) { return 1; }",
+        "class Foo extends React.Component { render({ bar = {} }) { return <div />; } }",
+        "function Foo(a, { bar = {} }) { return <div />; }",
+        "const Foo = ({ bar = Symbol }) => <div />;",
+        "const Foo = ({ bar = -1 }) => <div />;",
+    ];
+
+    let fail = vec![
+        "function Foo({ a = {}, b = ['one', 'two'], c = /regex/i, d = () => {}, e = function () {}, f = class {}, g = new Thing(), h = <Thing />, i = Symbol('foo') }) {
+           return <div />;
+         }",
+        "const Foo = ({ a = {}, b = [] }) => <div />;",
+        "const Foo = function ({ a = {} }) { return <div />; };",
+        "export default function ({ a = {} }) { return <div />; }",
+        "const Foo = React.memo(({ a = [] }) => <div />);",
+        "const Foo = forwardRef(({ a = {} }, ref) => <div ref={ref} />);",
+        "function Foo({ a = {} } = {}) { return <div />; }",
+        "const Foo = ({ 'data-a': a = {} }) => <div />;",
+        "const Foo = ({ a = ({}) }) => <div />;",
+    ];
+
+    Tester::new(NoObjectTypeAsDefaultProp::NAME, NoObjectTypeAsDefaultProp::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
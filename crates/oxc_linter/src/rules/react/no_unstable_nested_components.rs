@@ -0,0 +1,396 @@
+use std::{borrow::Cow, ops::Deref};
+
+use fast_glob::glob_match;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        get_component_name, get_component_symbol_id, get_jsx_attribute_name, is_es5_component,
+        is_es6_component, is_react_component, is_returning_jsx,
+    },
+};
+
+fn no_unstable_nested_components_diagnostic(
+    span: Span,
+    parent_name: Option<&str>,
+    in_props: bool,
+) -> OxcDiagnostic {
+    let parent = parent_name.map_or_else(
+        || "the parent component".to_string(),
+        |name| format!("the parent component `{name}`"),
+    );
+    let help = if in_props {
+        format!(
+            "Move this component definition out of {parent} and pass data as props. If you want to allow component creation in props, set the `allowAsProps` option to true."
+        )
+    } else {
+        format!("Move this component definition out of {parent} and pass data as props.")
+    };
+    OxcDiagnostic::warn(
+        "Do not define components during render. React will see a new component type on every render and destroy the entire subtree's DOM nodes and state.",
+    )
+    .with_help(help)
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnstableNestedComponents(Box<NoUnstableNestedComponentsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoUnstableNestedComponentsConfig {
+    allow_as_props: bool,
+    prop_name_pattern: CompactStr,
+}
+
+impl Default for NoUnstableNestedComponentsConfig {
+    fn default() -> Self {
+        Self { allow_as_props: false, prop_name_pattern: CompactStr::new("render*") }
+    }
+}
+
+impl Deref for NoUnstableNestedComponents {
+    type Target = NoUnstableNestedComponentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/no-unstable-nested-components.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/no-unstable-nested-components.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/no-unstable-nested-components.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows defining components inside other components.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A component defined during the render of another component is a new
+    /// component type on every render. React can't reconcile it with the
+    /// previous one, so it unmounts the entire subtree, destroys its DOM nodes
+    /// and state, and mounts it again. This is slow, and resets the state of
+    /// inputs, e.g. their focus.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   function UnstableNestedComponent() {
+    ///     return <div />;
+    ///   }
+    ///   return <div><UnstableNestedComponent /></div>;
+    /// }
+    ///
+    /// function SomeComponent({ footer: Footer }) {
+    ///   return <div><Footer /></div>;
+    /// }
+    /// function Component() {
+    ///   return <SomeComponent footer={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function OutsideDefinedComponent(props) {
+    ///   return <div />;
+    /// }
+    /// function Component() {
+    ///   return <div><OutsideDefinedComponent /></div>;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <SomeComponent renderFooter={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowAsProps
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allows components defined in props, e.g. `<Table footer={() => <div />} />`.
+    ///
+    /// #### propNamePattern
+    ///
+    /// `{ type: string, default: "render*" }`
+    ///
+    /// A glob for the names of props which take render functions rather than
+    /// components. Functions passed to `children` are always render functions.
+    NoUnstableNestedComponents,
+    react,
+    correctness
+);
+
+impl Rule for NoUnstableNestedComponents {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = NoUnstableNestedComponentsConfig::default();
+        let Some(value) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+        if let Some(allow) = value.get("allowAsProps").and_then(Value::as_bool) {
+            config.allow_as_props = allow;
+        }
+        if let Some(pattern) = value.get("propNamePattern").and_then(Value::as_str) {
+            config.prop_name_pattern = CompactStr::from(pattern);
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let in_props = match node.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                match get_prop_name(node, ctx) {
+                    Some(name) => {
+                        if self.allow_as_props
+                            || name == "children"
+                            || glob_match(self.prop_name_pattern.as_str(), name.as_ref())
+                            || !is_returning_jsx(node.kind())
+                        {
+                            return;
+                        }
+                        true
+                    }
+                    None if is_react_component(node, ctx) => false,
+                    None => return,
+                }
+            }
+            AstKind::Class(_) if is_es6_component(node) => false,
+            AstKind::CallExpression(_) if is_es5_component(node) => false,
+            _ => return,
+        };
+
+        let Some(parent) = ctx.nodes().ancestors(node.id()).find(|a| is_react_component(a, ctx))
+        else {
+            return;
+        };
+        let parent_name = get_component_name(parent, ctx).or_else(|| {
+            get_component_symbol_id(parent, ctx)
+                .map(|symbol_id| Cow::Borrowed(ctx.scoping().symbol_name(symbol_id)))
+        });
+        ctx.diagnostic(no_unstable_nested_components_diagnostic(
+            node.span(),
+            parent_name.as_deref(),
+            in_props,
+        ));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// The name of the prop `node` is passed to, directly, e.g. `<Foo footer={() => <div />} />`,
+/// or in an object, e.g. `<Foo components={{ Footer: () => <div /> }} />`.
+fn get_prop_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Cow<'a, str>> {
+    let mut ancestors = ctx
+        .nodes()
+        .ancestors(node.id())
+        .map(AstNode::kind)
+        .filter(|kind| !matches!(kind, AstKind::ParenthesizedExpression(_)));
+    let name = match ancestors.next()? {
+        AstKind::JSXExpressionContainer(_) => None,
+        AstKind::ObjectProperty(prop) if !prop.method => {
+            let name = prop.key.static_name()?;
+            let AstKind::ObjectExpression(_) = ancestors.next()? else {
+                return None;
+            };
+            let AstKind::JSXExpressionContainer(_) = ancestors.next()? else {
+                return None;
+            };
+            Some(name)
+        }
+        _ => return None,
+    };
+    let AstKind::JSXAttribute(attr) = ancestors.next()? else {
+        return None;
+    };
+    name.or_else(|| Some(get_jsx_attribute_name(&attr.name)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "function ParentComponent() {
+               return (
+                 <div>
+                   <OutsideDefinedFunctionComponent />
+                 </div>
+               );
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent renderFooter={() => <div />} />;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent>{() => <div />}</SomeComponent>;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent children={() => <div />} />;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent onClick={() => setOpen(true)} />;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               const renderItem = () => <li />;
+               return <ul>{items.map((item) => <li key={item.id}>{item.name}</li>)}</ul>;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               function getValue() { return 1; }
+               return <div>{getValue()}</div>;
+             }",
+            None,
+        ),
+        (
+            "class ParentComponent extends React.Component {
+               renderFooter() { return <div />; }
+               render() { return <div>{this.renderFooter()}</div>; }
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent footer={() => <div />} />;
+             }",
+            Some(json!([{ "allowAsProps": true }])),
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent header={() => <div />} />;
+             }",
+            Some(json!([{ "propNamePattern": "*er" }])),
+        ),
+        (
+            "function Component() { return <div />; } function Other() { return <Component />; }",
+            None,
+        ),
+        ("const columns = [{ render: () => <div /> }];", None),
+        (
+            "function useRenderer() {
+               return () => <div />;
+             }",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "function ParentComponent() {
+               function UnstableNestedFunctionComponent() {
+                 return <div />;
+               }
+               return <div><UnstableNestedFunctionComponent /></div>;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               const UnstableNestedVariableComponent = () => <div />;
+               return <div><UnstableNestedVariableComponent /></div>;
+             }",
+            None,
+        ),
+        (
+            "const ParentComponent = () => {
+               const UnstableNestedComponent = React.memo(() => <div />);
+               return <UnstableNestedComponent />;
+             };",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               class UnstableNestedClassComponent extends React.Component {
+                 render() { return <div />; }
+               }
+               return <UnstableNestedClassComponent />;
+             }",
+            None,
+        ),
+        (
+            "class ParentComponent extends React.Component {
+               render() {
+                 class UnstableNestedClassComponent extends React.Component {
+                   render() { return <div />; }
+                 }
+                 return <UnstableNestedClassComponent />;
+               }
+             }",
+            None,
+        ),
+        (
+            "class ParentComponent extends React.Component {
+               render() {
+                 function UnstableNestedFunctionComponent() { return <div />; }
+                 return <UnstableNestedFunctionComponent />;
+               }
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent footer={() => <div />} />;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent footer={function () { return <div />; }} />;
+             }",
+            None,
+        ),
+        (
+            "function ParentComponent() {
+               return <SomeComponent components={{ Footer: () => <div /> }} />;
+             }",
+            None,
+        ),
+        (
+            "export default function () {
+               const Nested = () => <div />;
+               return <Nested />;
+             }",
+            None,
+        ),
+        (
+            "const ParentComponent = memo(() => {
+               function Nested() { return cond ? <div /> : null; }
+               return <Nested />;
+             });",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUnstableNestedComponents::NAME, NoUnstableNestedComponents::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,517 @@
+use std::ops::Deref;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        Argument, BindingPatternKind, Class, Expression, ObjectPattern, ObjectPropertyKind,
+        PropertyKey, StaticMemberExpression, VariableDeclarator,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{CompactStr, GetSpan, Span};
+use serde_json::Value;
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        get_class_static_property, get_component_symbol_id, get_static_property_assignment,
+        is_component_wrapper_call, is_es5_component, is_es6_component, is_function_component,
+    },
+};
+
+fn prop_types_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' is missing in props validation"))
+        .with_help(format!("Add `{name}` to the `propTypes` of the component."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PropTypes(Box<PropTypesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct PropTypesConfig {
+    ignore: Vec<CompactStr>,
+    skip_undeclared: bool,
+}
+
+impl Deref for PropTypes {
+    type Target = PropTypesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// code: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/lib/rules/prop-types.js
+// doc: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/docs/rules/prop-types.md
+// test: https://github.com/jsx-eslint/eslint-plugin-react/blob/v7.37.2/tests/lib/rules/prop-types.js
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that every prop a component uses is declared in its
+    /// `propTypes`.
+    ///
+    /// Components with TypeScript types for their props, e.g.
+    /// `function Hello(props: Props)`, are not checked, as the types already
+    /// declare the props.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `propTypes` document the props of a component and validate them at
+    /// runtime in development. A prop which is used but not declared is neither
+    /// documented nor validated.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Hello({ name }) {
+    ///   return <div>Hello {name}</div>;
+    /// }
+    ///
+    /// class Hello extends React.Component {
+    ///   static propTypes = { firstName: PropTypes.string };
+    ///   render() {
+    ///     return <div>Hello {this.props.firstName} {this.props.lastName}</div>;
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Hello({ name }) {
+    ///   return <div>Hello {name}</div>;
+    /// }
+    /// Hello.propTypes = { name: PropTypes.string.isRequired };
+    ///
+    /// function Hello({ name }: { name: string }) {
+    ///   return <div>Hello {name}</div>;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### ignore
+    ///
+    /// `{ type: string[], default: [] }`
+    ///
+    /// Props which don't need to be declared, e.g. `["children"]`.
+    ///
+    /// #### skipUndeclared
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Only check components which declare `propTypes`.
+    PropTypes,
+    react,
+    pedantic
+);
+
+impl Rule for PropTypes {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = PropTypesConfig::default();
+        let Some(value) = value.get(0) else {
+            return Self(Box::new(config));
+        };
+        if let Some(ignore) = value.get("ignore").and_then(Value::as_array) {
+            config.ignore = ignore.iter().filter_map(Value::as_str).map(CompactStr::from).collect();
+        }
+        if let Some(skip) = value.get("skipUndeclared").and_then(Value::as_bool) {
+            config.skip_undeclared = skip;
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let mut used_props = vec![];
+        let declared = match node.kind() {
+            AstKind::Class(class) if is_es6_component(node) => {
+                if class.super_type_arguments.is_some() {
+                    return;
+                }
+                let mut finder = ThisPropsFinder::default();
+                finder.visit_class_body(&class.body);
+                used_props = finder.props;
+                get_class_prop_types(class, node, ctx)
+            }
+            AstKind::CallExpression(call) if is_es5_component(node) => {
+                let Some(Argument::ObjectExpression(spec)) = call.arguments.first() else {
+                    return;
+                };
+                let mut finder = ThisPropsFinder::default();
+                finder.visit_object_expression(spec);
+                used_props = finder.props;
+                spec.properties
+                    .iter()
+                    .find_map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(prop)
+                            if prop.key.is_specific_static_name("propTypes") =>
+                        {
+                            Some(&prop.value)
+                        }
+                        _ => None,
+                    })
+                    .map(get_declared_props)
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                if is_function_component(node, ctx) =>
+            {
+                if is_typed_function_component(node, ctx) {
+                    return;
+                }
+                let params = match node.kind() {
+                    AstKind::Function(func) => &func.params,
+                    AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+                    _ => unreachable!(),
+                };
+                let Some(param) = params.items.first() else {
+                    return;
+                };
+                let pattern = match &param.pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign) => &assign.left.kind,
+                    kind => kind,
+                };
+                match pattern {
+                    BindingPatternKind::ObjectPattern(pattern) => {
+                        collect_pattern_props(pattern, &mut used_props);
+                    }
+                    BindingPatternKind::BindingIdentifier(id) => {
+                        collect_props_references(id.symbol_id(), ctx, &mut used_props);
+                    }
+                    _ => return,
+                }
+                get_component_symbol_id(node, ctx)
+                    .and_then(|symbol_id| {
+                        get_static_property_assignment(symbol_id, "propTypes", ctx)
+                    })
+                    .map(get_declared_props)
+            }
+            _ => return,
+        };
+
+        let declared = match declared {
+            Some(DeclaredProps::Known(declared)) => declared,
+            Some(DeclaredProps::Unknown) => return,
+            None if self.skip_undeclared => return,
+            None => vec![],
+        };
+        for (name, span) in used_props {
+            if !declared.contains(&name) && !self.ignore.iter().any(|ignored| ignored == name) {
+                ctx.diagnostic(prop_types_diagnostic(span, name));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// The props declared by `static propTypes = { ... }` or `Foo.propTypes = { ... }`.
+fn get_class_prop_types<'a>(
+    class: &'a Class<'a>,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<DeclaredProps<'a>> {
+    get_class_static_property(class, "propTypes")
+        .or_else(|| {
+            get_component_symbol_id(node, ctx)
+                .and_then(|symbol_id| get_static_property_assignment(symbol_id, "propTypes", ctx))
+        })
+        .map(get_declared_props)
+}
+
+enum DeclaredProps<'a> {
+    Known(Vec<&'a str>),
+    /// The declared props can't be known, e.g. they are spread from another object.
+    Unknown,
+}
+
+/// The names of the props declared by a `propTypes` object.
+fn get_declared_props<'a>(expr: &'a Expression<'a>) -> DeclaredProps<'a> {
+    let Expression::ObjectExpression(obj) = expr.get_inner_expression() else {
+        return DeclaredProps::Unknown;
+    };
+    obj.properties
+        .iter()
+        .map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(prop) => static_key_name(&prop.key),
+            ObjectPropertyKind::SpreadProperty(_) => None,
+        })
+        .collect::<Option<_>>()
+        .map_or(DeclaredProps::Unknown, DeclaredProps::Known)
+}
+
+/// Whether the props of the function component have a TypeScript type, either on the first
+/// parameter, the variable the component is assigned to, or the `memo` or `forwardRef` call
+/// which wraps it.
+fn is_typed_function_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let params = match node.kind() {
+        AstKind::Function(func) => &func.params,
+        AstKind::ArrowFunctionExpression(arrow) => &arrow.params,
+        _ => return false,
+    };
+    if params.items.first().is_some_and(|param| param.pattern.type_annotation.is_some()) {
+        return true;
+    }
+    for ancestor in ctx.nodes().ancestors(node.id()) {
+        match ancestor.kind() {
+            AstKind::CallExpression(call) if is_component_wrapper_call(call) => {
+                if call.type_arguments.is_some() {
+                    return true;
+                }
+            }
+            AstKind::Argument(_) | AstKind::ParenthesizedExpression(_) => {}
+            AstKind::VariableDeclarator(decl) => return decl.id.type_annotation.is_some(),
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn collect_pattern_props<'a>(pattern: &ObjectPattern<'a>, props: &mut Vec<(&'a str, Span)>) {
+    for prop in &pattern.properties {
+        if let Some(name) = static_key_name(&prop.key) {
+            props.push((name, prop.key.span()));
+        }
+    }
+}
+
+fn static_key_name<'a>(key: &PropertyKey<'a>) -> Option<&'a str> {
+    match key {
+        PropertyKey::StaticIdentifier(id) => Some(id.name.as_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Collects the props used through the `props` parameter, i.e. `props.foo` and
+/// `const { foo } = props`.
+fn collect_props_references<'a>(
+    symbol_id: SymbolId,
+    ctx: &LintContext<'a>,
+    props: &mut Vec<(&'a str, Span)>,
+) {
+    let nodes = ctx.nodes();
+    for reference in ctx.symbol_references(symbol_id) {
+        match nodes.parent_kind(reference.node_id()) {
+            AstKind::StaticMemberExpression(member) if member.object.is_identifier_reference() => {
+                props.push((member.property.name.as_str(), member.property.span));
+            }
+            AstKind::VariableDeclarator(decl) => {
+                if let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind {
+                    collect_pattern_props(pattern, props);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collects the props used through `this.props`, i.e. `this.props.foo` and
+/// `const { foo } = this.props`, skipping nested classes.
+#[derive(Default)]
+struct ThisPropsFinder<'a> {
+    props: Vec<(&'a str, Span)>,
+}
+
+fn is_this_props(expr: &Expression) -> bool {
+    matches!(expr.get_inner_expression(), Expression::StaticMemberExpression(member)
+        if matches!(member.object, Expression::ThisExpression(_)) && member.property.name == "props")
+}
+
+impl<'a> Visit<'a> for ThisPropsFinder<'a> {
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        if is_this_props(&it.object) {
+            self.props.push((it.property.name.as_str(), it.property.span));
+        }
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let BindingPatternKind::ObjectPattern(pattern) = &it.id.kind
+            && it.init.as_ref().is_some_and(is_this_props)
+        {
+            collect_pattern_props(pattern, &mut self.props);
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            "class Hello extends React.Component {
+               static propTypes = { name: PropTypes.string };
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }
+             Hello.propTypes = { name: PropTypes.string };",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() {
+                 const { firstName, lastName } = this.props;
+                 return <div>Hello {firstName} {lastName}</div>;
+               }
+             }
+             Hello.propTypes = { firstName: PropTypes.string, lastName: PropTypes.string };",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello</div>; }
+             }",
+            None,
+        ),
+        (
+            "var Hello = createReactClass({
+               propTypes: { name: PropTypes.string },
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            None,
+        ),
+        (
+            "function Hello({ name }) { return <div>Hello {name}</div>; }
+             Hello.propTypes = { name: PropTypes.string };",
+            None,
+        ),
+        (
+            "function Hello(props) { return <div>Hello {props.name}</div>; }
+             Hello.propTypes = { name: PropTypes.string };",
+            None,
+        ),
+        (
+            "const Hello = (props) => {
+               const { name } = props;
+               return <div>Hello {name}</div>;
+             };
+             Hello.propTypes = { name: PropTypes.string };",
+            None,
+        ),
+        (
+            "const Hello = React.memo(({ name }) => <div>Hello {name}</div>);
+             Hello.propTypes = { name: PropTypes.string };",
+            None,
+        ),
+        (
+            "function Hello({ name }) { return <div>Hello {name}</div>; }
+             Hello.propTypes = { ...otherPropTypes };",
+            None,
+        ),
+        (
+            "function Hello({ name }) { return <div>Hello {name}</div>; }
+             Hello.propTypes = otherPropTypes;",
+            None,
+        ),
+        ("function Hello({ name }: { name: string }) { return <div>Hello {name}</div>; }", None),
+        ("function Hello(props: Props) { return <div>Hello {props.name}</div>; }", None),
+        ("const Hello: React.FC<Props> = ({ name }) => <div>Hello {name}</div>;", None),
+        (
+            "const Hello = forwardRef<HTMLDivElement, Props>(({ name }, ref) => <div ref={ref}>{name}</div>);",
+            None,
+        ),
+        (
+            "class Hello extends React.Component<Props> {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            None,
+        ),
+        ("function hello({ name }) { return <div>Hello {name}</div>; }", None),
+        (
+            "function Hello({ name }) { return <div>Hello {name}</div>; }",
+            Some(json!([{ "skipUndeclared": true }])),
+        ),
+        (
+            "function Hello({ children }) { return <div>{children}</div>; }",
+            Some(json!([{ "ignore": ["children"] }])),
+        ),
+        (
+            "class Hello extends React.Component {
+               static propTypes = {};
+               render() {
+                 class Inner extends React.Component {
+                   static propTypes = { name: PropTypes.string };
+                   render() { return this.props.name; }
+                 }
+                 return <Inner />;
+               }
+             }",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "class Hello extends React.Component {
+               render() { return <div>Hello {this.props.name}</div>; }
+             }",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               static propTypes = { firstName: PropTypes.string };
+               render() { return <div>Hello {this.props.firstName} {this.props.lastName}</div>; }
+             }",
+            None,
+        ),
+        (
+            "class Hello extends React.Component {
+               render() {
+                 const { firstName, lastName } = this.props;
+                 return <div>Hello {firstName} {lastName}</div>;
+               }
+             }
+             Hello.propTypes = { firstName: PropTypes.string };",
+            None,
+        ),
+        (
+            "var Hello = createReactClass({
+               propTypes: { firstName: PropTypes.string },
+               render: function() { return <div>Hello {this.props.name}</div>; }
+             });",
+            None,
+        ),
+        ("function Hello({ name }) { return <div>Hello {name}</div>; }", None),
+        ("const Hello = (props) => <div>Hello {props.name}</div>;", None),
+        (
+            "const Hello = (props) => {
+               const { name } = props;
+               return <div>Hello {name}</div>;
+             };
+             Hello.propTypes = {};",
+            None,
+        ),
+        ("export default function ({ name }) { return <div>Hello {name}</div>; }", None),
+        ("const Hello = React.memo(({ name }) => <div>Hello {name}</div>);", None),
+        (
+            "function Hello({ name, children }) { return <div>Hello {name}{children}</div>; }
+             Hello.propTypes = { name: PropTypes.string };",
+            Some(json!([{ "skipUndeclared": true }])),
+        ),
+        (
+            "function Hello({ name, children }) { return <div>Hello {name}{children}</div>; }",
+            Some(json!([{ "ignore": ["children"] }])),
+        ),
+    ];
+
+    Tester::new(PropTypes::NAME, PropTypes::PLUGIN, pass, fail).test_and_snapshot();
+}
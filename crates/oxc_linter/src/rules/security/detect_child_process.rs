@@ -0,0 +1,207 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, BindingPatternKind, CallExpression, Expression, PropertyKey},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::is_static_value};
+
+fn detect_child_process_diagnostic(span: Span, method: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`child_process.{method}` called with a non-literal command"))
+        .with_help("A command built from user input may run arbitrary commands. Use `execFile` or `spawn` with a literal command and pass the input as arguments.")
+        .with_label(span)
+}
+
+/// Methods of `child_process` which take the command as their first argument.
+const COMMAND_METHODS: [&str; 6] =
+    ["exec", "execFile", "execFileSync", "execSync", "spawn", "spawnSync"];
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectChildProcess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows running commands with `child_process` when the command is
+    /// not a literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A command which is built from user input may run arbitrary commands
+    /// on the server (command injection), especially with `exec`, which runs
+    /// the command in a shell.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// import { exec } from 'node:child_process';
+    ///
+    /// function convert(file) {
+    ///   exec(`convert ${file} out.png`);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// import { execFile } from 'node:child_process';
+    ///
+    /// function convert(file) {
+    ///   execFile('convert', [file, 'out.png']);
+    /// }
+    /// ```
+    DetectChildProcess,
+    security,
+    suspicious
+);
+
+impl Rule for DetectChildProcess {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(method) = get_child_process_method(call, ctx) else {
+            return;
+        };
+        if !COMMAND_METHODS.contains(&method) {
+            return;
+        }
+        let Some(command) = call.arguments.first() else {
+            return;
+        };
+        let is_static = match command {
+            Argument::SpreadElement(_) => false,
+            command => command.as_expression().is_some_and(|command| is_static_value(command, ctx)),
+        };
+        if !is_static {
+            ctx.diagnostic(detect_child_process_diagnostic(command.span(), method));
+        }
+    }
+}
+
+/// The name of the `child_process` method which `call` calls, e.g. `exec` for
+/// `require('child_process').exec(command)`, or for `exec(command)` after
+/// `import { exec } from 'child_process'`.
+fn get_child_process_method<'a>(
+    call: &CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a str> {
+    match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+            get_imported_method(symbol_id, ctx)
+        }
+        callee => {
+            let member = callee.as_member_expression()?;
+            is_child_process_module(member.object(), ctx).then_some(())?;
+            member.static_property_name()
+        }
+    }
+}
+
+/// The name of the `child_process` method which `symbol_id` is bound to, e.g.
+/// `import { exec as run } from 'child_process'` or `const { exec } = require('child_process')`.
+fn get_imported_method<'a>(symbol_id: SymbolId, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::ImportSpecifier(specifier) => match ctx.nodes().parent_kind(declaration.id()) {
+            AstKind::ImportDeclaration(import) if is_child_process_source(&import.source.value) => {
+                Some(specifier.imported.name().as_str())
+            }
+            _ => None,
+        },
+        AstKind::VariableDeclarator(decl) => {
+            let BindingPatternKind::ObjectPattern(pattern) = &decl.id.kind else {
+                return None;
+            };
+            if !decl.init.as_ref().is_some_and(is_require_child_process) {
+                return None;
+            }
+            pattern.properties.iter().find_map(|prop| {
+                let id = prop.value.get_binding_identifier()?;
+                if id.symbol_id() != symbol_id {
+                    return None;
+                }
+                match &prop.key {
+                    PropertyKey::StaticIdentifier(key) => Some(key.name.as_str()),
+                    PropertyKey::StringLiteral(key) => Some(key.value.as_str()),
+                    _ => None,
+                }
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` is the `child_process` module, e.g. `require('child_process')`, or a variable
+/// bound to it.
+fn is_child_process_module<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    if is_require_child_process(expr) {
+        return true;
+    }
+    let Expression::Identifier(ident) = expr.get_inner_expression() else {
+        return false;
+    };
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::ImportDefaultSpecifier(_) | AstKind::ImportNamespaceSpecifier(_) => {
+            matches!(ctx.nodes().parent_kind(declaration.id()), AstKind::ImportDeclaration(import)
+                if is_child_process_source(&import.source.value))
+        }
+        AstKind::VariableDeclarator(decl) => {
+            decl.id.kind.is_binding_identifier()
+                && decl.init.as_ref().is_some_and(is_require_child_process)
+        }
+        _ => false,
+    }
+}
+
+fn is_require_child_process(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr.get_inner_expression() else {
+        return false;
+    };
+    call.callee.is_specific_id("require")
+        && matches!(call.arguments.first(), Some(Argument::StringLiteral(source))
+            if is_child_process_source(&source.value))
+}
+
+fn is_child_process_source(source: &str) -> bool {
+    matches!(source, "child_process" | "node:child_process")
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import { exec } from 'child_process'; exec('ls -la');",
+        "import { execFile } from 'node:child_process'; function f(file) { execFile('convert', [file]); }",
+        "const cp = require('child_process'); cp.exec(`git status`);",
+        "const { spawn } = require('child_process'); const cmd = 'ls'; spawn(cmd);",
+        "import { fork } from 'child_process'; fork(modulePath);",
+        "function exec(command) {} exec(command);",
+        "import { exec } from 'shelljs'; exec(command);",
+        "const cp = require('cross-spawn'); cp.spawn(command);",
+        "exec(command);",
+    ];
+
+    let fail = vec![
+        "import { exec } from 'child_process'; function f(file) { exec(`convert ${file} out.png`); }",
+        "import { exec as run } from 'node:child_process'; run(command);",
+        "import * as cp from 'child_process'; cp.execSync('rm -rf ' + dir);",
+        "import cp from 'child_process'; cp.spawn(command, args);",
+        "const cp = require('child_process'); cp.exec(command);",
+        "const { execSync } = require('node:child_process'); execSync(getCommand());",
+        "require('child_process').exec(command);",
+        "const { 'spawnSync': spawnSync } = require('child_process'); let cmd = 'ls'; cmd += args; spawnSync(cmd);",
+    ];
+
+    Tester::new(DetectChildProcess::NAME, DetectChildProcess::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
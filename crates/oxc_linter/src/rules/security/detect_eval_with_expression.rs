@@ -0,0 +1,160 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryOperator, Expression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{is_global_function, is_static_value},
+};
+
+fn eval_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`eval` called with a non-literal argument")
+        .with_help("Code built from user input may run arbitrary code. Avoid `eval`.")
+        .with_label(span)
+}
+
+fn function_constructor_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The `Function` constructor evaluates a string as code")
+        .with_help(
+            "Code built from user input may run arbitrary code. Define the function instead.",
+        )
+        .with_label(span)
+}
+
+fn timer_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` called with a string evaluates it as code"))
+        .with_help("Pass a function instead of a string.")
+        .with_label(span)
+}
+
+/// Timer functions which evaluate their first argument as code when it is a string.
+const TIMER_FUNCTIONS: [&str; 4] = ["execScript", "setImmediate", "setInterval", "setTimeout"];
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectEvalWithExpression;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows evaluating strings as code: `eval` with a non-literal
+    /// argument, the `Function` constructor, and timers like `setTimeout`
+    /// called with a string.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Evaluating a string which contains user input runs arbitrary code with
+    /// the privileges of the application (code injection).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function run(input) {
+    ///   eval(input);
+    ///   const fn = new Function('a', `return a + ${input}`);
+    ///   setTimeout(`update(${input})`, 100);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function run(input) {
+    ///   const fn = (a) => a + Number(input);
+    ///   setTimeout(() => update(input), 100);
+    /// }
+    /// ```
+    DetectEvalWithExpression,
+    security,
+    suspicious
+);
+
+impl Rule for DetectEvalWithExpression {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::NewExpression(new_expr) => {
+                if is_global_function(&new_expr.callee, "Function", ctx) {
+                    ctx.diagnostic(function_constructor_diagnostic(new_expr.span));
+                }
+            }
+            AstKind::CallExpression(call) => {
+                if is_global_function(&call.callee, "Function", ctx) {
+                    ctx.diagnostic(function_constructor_diagnostic(call.span));
+                    return;
+                }
+                let Some(first) = call.arguments.first() else {
+                    return;
+                };
+                if is_global_function(&call.callee, "eval", ctx) {
+                    let is_static =
+                        first.as_expression().is_some_and(|first| is_static_value(first, ctx));
+                    if !is_static {
+                        ctx.diagnostic(eval_diagnostic(first.span()));
+                    }
+                    return;
+                }
+                let Some(name) =
+                    TIMER_FUNCTIONS.iter().find(|name| is_global_function(&call.callee, name, ctx))
+                else {
+                    return;
+                };
+                if first.as_expression().is_some_and(is_string) {
+                    ctx.diagnostic(timer_diagnostic(first.span(), name));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether `expr` is a string, e.g. `'code'`, `` `code` `` or `'code' + input`.
+fn is_string(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            is_string(&binary.left) || is_string(&binary.right)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "eval('1 + 1');",
+        "eval(`1 + 1`);",
+        "const code = 'alert(1)'; eval(code);",
+        "function f(eval, input) { eval(input); }",
+        "setTimeout(() => update(input), 100);",
+        "setTimeout(update, 100);",
+        "function defer(fn) { setTimeout(fn, 0); }",
+        "window.setInterval(function () {}, 100);",
+        "function f(setTimeout) { setTimeout('code'); }",
+        "obj.setTimeout('code');",
+        "class Function {} new Function('a');",
+    ];
+
+    let fail = vec![
+        "function run(input) { eval(input); }",
+        "eval('var x = ' + input);",
+        "window.eval(code);",
+        "globalThis.eval(`${code}`);",
+        "new Function('a', 'b', 'return a + b');",
+        "Function('return this')();",
+        "new window.Function(body);",
+        "setTimeout('update()', 100);",
+        "setInterval(`tick(${id})`, 100);",
+        "window.setTimeout('alert(' + input + ')');",
+        "setImmediate('run()');",
+    ];
+
+    Tester::new(DetectEvalWithExpression::NAME, DetectEvalWithExpression::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,178 @@
+use cow_utils::CowUtils;
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, AssignmentOperator, Expression, MemberExpression},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{flows_from_parameter, is_static_value},
+};
+
+fn detect_html_injection_diagnostic(span: Span, sink: &str, from_parameter: bool) -> OxcDiagnostic {
+    let message = if from_parameter {
+        format!("`{sink}` is given HTML which comes from a function parameter")
+    } else {
+        format!("`{sink}` is given HTML which is not a literal")
+    };
+    OxcDiagnostic::warn(message)
+        .with_help("HTML built from user input may run arbitrary scripts. Set `textContent` instead, or sanitize the HTML.")
+        .with_label(span)
+}
+
+/// Properties which parse the assigned string as HTML.
+const HTML_PROPERTIES: [&str; 2] = ["innerHTML", "outerHTML"];
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectHtmlInjection;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows passing HTML which is not a literal to the DOM sinks which
+    /// parse it: `innerHTML`, `outerHTML`, `insertAdjacentHTML()`,
+    /// `document.write()` and `document.writeln()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// HTML which contains user input may contain scripts, which run with the
+    /// privileges of the page (cross-site scripting).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function render(name) {
+    ///   element.innerHTML = `<b>${name}</b>`;
+    ///   element.insertAdjacentHTML('beforeend', name);
+    ///   document.write(name);
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function render(name) {
+    ///   element.textContent = name;
+    ///   element.innerHTML = '<b>Hello</b>';
+    ///   element.innerHTML = DOMPurify.sanitize(name);
+    /// }
+    /// ```
+    ///
+    /// Values which are passed through a function whose name contains
+    /// `sanitize` or `escape` are allowed.
+    DetectHtmlInjection,
+    security,
+    suspicious
+);
+
+impl Rule for DetectHtmlInjection {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::AssignmentExpression(assign) => {
+                if !matches!(
+                    assign.operator,
+                    AssignmentOperator::Assign | AssignmentOperator::Addition
+                ) {
+                    return;
+                }
+                let Some(member) = assign.left.as_member_expression() else {
+                    return;
+                };
+                let Some(sink) =
+                    member.static_property_name().filter(|name| HTML_PROPERTIES.contains(name))
+                else {
+                    return;
+                };
+                check_html(&assign.right, sink, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                let Some(member) = call.callee.get_inner_expression().as_member_expression() else {
+                    return;
+                };
+                let (sink, html) = match member.static_property_name() {
+                    Some("insertAdjacentHTML") => ("insertAdjacentHTML", call.arguments.get(1)),
+                    Some(name @ ("write" | "writeln"))
+                        if member.object().is_global_reference_name("document", ctx.scoping()) =>
+                    {
+                        let sink =
+                            if name == "write" { "document.write" } else { "document.writeln" };
+                        (sink, call.arguments.first())
+                    }
+                    _ => return,
+                };
+                if let Some(html) = html.and_then(Argument::as_expression) {
+                    check_html(html, sink, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_html<'a>(html: &Expression<'a>, sink: &str, ctx: &LintContext<'a>) {
+    if is_static_value(html, ctx) || is_sanitized(html) {
+        return;
+    }
+    ctx.diagnostic(detect_html_injection_diagnostic(
+        html.span(),
+        sink,
+        flows_from_parameter(html, ctx),
+    ));
+}
+
+/// Whether `expr` is the result of a function which sanitizes or escapes HTML, e.g.
+/// `DOMPurify.sanitize(html)` or `escapeHtml(name)`.
+fn is_sanitized(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr.get_inner_expression() else {
+        return false;
+    };
+    let name = match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        callee => callee.as_member_expression().and_then(MemberExpression::static_property_name),
+    };
+    name.is_some_and(|name| {
+        let name = name.cow_to_ascii_lowercase();
+        name.contains("sanitize") || name.contains("escape")
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "element.innerHTML = '<b>Hello</b>';",
+        "element.innerHTML = '';",
+        "const html = '<hr>'; element.outerHTML = html;",
+        "element.textContent = name;",
+        "function render(name) { element.innerText = name; }",
+        "element.innerHTML = DOMPurify.sanitize(html);",
+        "element.innerHTML = escapeHtml(name);",
+        "element.insertAdjacentHTML('beforeend', '<li></li>');",
+        "document.write('<p>Hello</p>');",
+        "stream.write(data);",
+        "function f(document) { document.write(html); }",
+        "const same = element.innerHTML === html;",
+    ];
+
+    let fail = vec![
+        "function render(name) { element.innerHTML = `<b>${name}</b>`; }",
+        "element.innerHTML = html;",
+        "element.innerHTML += '<li>' + item + '</li>';",
+        "function replace(el, html) { el.outerHTML = html; }",
+        "element['innerHTML'] = html;",
+        "function render(name) { element.insertAdjacentHTML('beforeend', name); }",
+        "document.write(html);",
+        "document.writeln(`<p>${text}</p>`);",
+        "function handler(req) { const { name } = req.query; document.body.innerHTML = 'Hi ' + name; }",
+    ];
+
+    Tester::new(DetectHtmlInjection::NAME, DetectHtmlInjection::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,104 @@
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{is_global_function, is_static_value},
+};
+
+fn detect_non_literal_regexp_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("RegExp constructed from a non-literal value")
+        .with_help("A pattern from user input may take exponential time to match. Use a literal pattern, or escape the input.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectNonLiteralRegexp;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows constructing a `RegExp` from a value which is not a literal.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A pattern which comes from user input can be crafted to take
+    /// exponential time to match (ReDoS), which blocks the event loop.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function search(query) {
+    ///   return new RegExp(query, 'i');
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const pattern = '^[a-z]+$';
+    /// const re = new RegExp(pattern, 'i');
+    /// const escaped = new RegExp(/^[a-z]+$/);
+    /// ```
+    DetectNonLiteralRegexp,
+    security,
+    restriction
+);
+
+impl Rule for DetectNonLiteralRegexp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments) = match node.kind() {
+            AstKind::NewExpression(new_expr) => (&new_expr.callee, &new_expr.arguments),
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments),
+            _ => return,
+        };
+        if !is_global_function(callee, "RegExp", ctx) {
+            return;
+        }
+        let Some(pattern) = arguments.first() else {
+            return;
+        };
+        let is_static = match pattern {
+            Argument::SpreadElement(_) => false,
+            pattern => pattern.as_expression().is_some_and(|pattern| is_static_value(pattern, ctx)),
+        };
+        if !is_static {
+            ctx.diagnostic(detect_non_literal_regexp_diagnostic(pattern.span()));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "new RegExp('^[a-z]+$');",
+        "new RegExp(/^[a-z]+$/, 'g');",
+        "RegExp('^' + 'abc' + '$');",
+        "new RegExp(`^abc$`);",
+        "const pattern = '^abc$'; new RegExp(pattern);",
+        "let pattern = '^abc$'; new RegExp(pattern); pattern = input;",
+        "new RegExp();",
+        "function f(RegExp, input) { new RegExp(input); }",
+        "/^[a-z]+$/.test(input);",
+    ];
+
+    let fail = vec![
+        "function search(query) { return new RegExp(query, 'i'); }",
+        "new RegExp(input);",
+        "RegExp(`^${prefix}`);",
+        "new RegExp('^' + name + '$');",
+        "let pattern = '^abc$'; if (cond) pattern = input; new RegExp(pattern);",
+        "let pattern = '^abc$'; for (const input of inputs) { new RegExp(pattern); pattern = input; }",
+        "new RegExp(...args);",
+        "new RegExp(getPattern());",
+    ];
+
+    Tester::new(DetectNonLiteralRegexp::NAME, DetectNonLiteralRegexp::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
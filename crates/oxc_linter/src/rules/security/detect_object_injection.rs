@@ -0,0 +1,149 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, TSType},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{flows_from_parameter, is_static_value},
+};
+
+fn detect_object_injection_diagnostic(key_span: Span, sink: Sink) -> OxcDiagnostic {
+    let message = match sink {
+        Sink::Write => "Property written with a key which comes from a function parameter",
+        Sink::Call => "Method called with a key which comes from a function parameter",
+        Sink::Read => "Property read with a key which comes from a function parameter",
+    };
+    OxcDiagnostic::warn(message)
+        .with_help("A key like `__proto__` or `constructor` reaches the prototype. Check the key against a list of allowed keys, or use a `Map`.")
+        .with_label(key_span.label("This key comes from a function parameter"))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Sink {
+    Write,
+    Call,
+    Read,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DetectObjectInjection;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows bracket notation, e.g. `obj[key]`, when the key comes from a
+    /// parameter of the enclosing function, either directly or through
+    /// variables and expressions derived from it.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A key which comes from user input may be `__proto__`, `constructor` or
+    /// the name of a method. Writing with it may pollute the prototype of all
+    /// objects, and calling with it may run an unexpected method.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function set(obj, key, value) {
+    ///   obj[key] = value;
+    /// }
+    ///
+    /// function handle(req) {
+    ///   const action = req.query.action;
+    ///   return handlers[action]();
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function set(map, key, value) {
+    ///   map.set(key, value);
+    /// }
+    ///
+    /// function at(items, index: number) {
+    ///   return items[index];
+    /// }
+    /// ```
+    DetectObjectInjection,
+    security,
+    restriction
+);
+
+impl Rule for DetectObjectInjection {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ComputedMemberExpression(member) = node.kind() else {
+            return;
+        };
+        let key = &member.expression;
+        if is_static_value(key, ctx) || is_number_parameter(key, ctx) {
+            return;
+        }
+        if !flows_from_parameter(key, ctx) {
+            return;
+        }
+        let sink = match ctx.nodes().parent_kind(node.id()) {
+            AstKind::AssignmentExpression(assign) if assign.left.span() == member.span => {
+                Sink::Write
+            }
+            AstKind::CallExpression(call) if call.callee.span() == member.span => Sink::Call,
+            _ => Sink::Read,
+        };
+        ctx.diagnostic(detect_object_injection_diagnostic(key.span(), sink));
+    }
+}
+
+/// Whether `key` is a parameter with the type `number`, e.g. `index` in
+/// `function at(items, index: number)`, which can't reach the prototype.
+fn is_number_parameter<'a>(key: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    let Expression::Identifier(ident) = key.get_inner_expression() else {
+        return false;
+    };
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    matches!(declaration.kind(), AstKind::FormalParameter(param)
+    if param.pattern.type_annotation.as_ref().is_some_and(|annotation| {
+        matches!(annotation.type_annotation, TSType::TSNumberKeyword(_))
+    }))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "obj[key] = value;",
+        "const value = obj['key'];",
+        "function f(obj) { return obj[0]; }",
+        "function f(obj) { const key = 'name'; return obj[key]; }",
+        "function f(obj, key) { key = 'name'; return obj[key]; }",
+        "function at(items, index: number) { return items[index]; }",
+        "function f(items) { for (let i = 0; i < items.length; i++) { items[i] = 0; } }",
+        "function f(map, key, value) { map.set(key, value); }",
+        "function f(obj) { return obj.key; }",
+        "function f(obj) { return obj[Symbol.iterator]; }",
+    ];
+
+    let fail = vec![
+        "function set(obj, key, value) { obj[key] = value; }",
+        "function get(obj, key) { return obj[key]; }",
+        "function handle(req) { const action = req.query.action; return handlers[action](); }",
+        "const handler = ({ type }) => reducers[type];",
+        "function f(name) { const key = 'prefix_' + name; cache[key] = true; }",
+        "function f(input) { let key; key = input.trim(); return obj[key]; }",
+        "function f(key) { return obj[`${key}`]; }",
+        "function f(...keys) { return obj[keys[0]]; }",
+        "function at(items, index: string) { return items[index]; }",
+    ];
+
+    Tester::new(DetectObjectInjection::NAME, DetectObjectInjection::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,105 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_testing_library_call_name, is_async_query, is_promise_handled},
+};
+
+fn await_async_queries_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Promise returned from async query `{name}` must be handled"))
+        .with_help("Await the query, return it, or chain it with `.then()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitAsyncQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the promises returned by async queries, i.e. the
+    /// `findBy*` and `findAllBy*` queries, are handled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Async queries wait until the element appears. When the promise is not
+    /// awaited, the test continues before the element has been found, and
+    /// a failure of the query is not reported by the test.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test('shows the button', () => {
+    ///   render(<App />);
+    ///   screen.findByRole('button');
+    ///   findByText('Submit');
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('shows the button', async () => {
+    ///   render(<App />);
+    ///   await screen.findByRole('button');
+    ///   const [first, second] = await Promise.all([findByText('A'), findByText('B')]);
+    ///   return findByText('Submit');
+    /// });
+    /// ```
+    AwaitAsyncQueries,
+    testing_library,
+    correctness
+);
+
+impl Rule for AwaitAsyncQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_call_name(call) else {
+            return;
+        };
+        if !is_async_query(name) || is_promise_handled(node, ctx) {
+            return;
+        }
+        ctx.diagnostic(await_async_queries_diagnostic(call.span, name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async () => { await screen.findByText('foo'); }",
+        "async () => { await findAllByRole('button'); }",
+        "async () => { const el = await screen.findByText('foo'); }",
+        "() => { return screen.findByText('foo'); }",
+        "() => screen.findByText('foo')",
+        "() => { screen.findByText('foo').then((el) => expect(el).toBeVisible()); }",
+        "() => { findByText('foo').catch(() => {}); }",
+        "async () => { await Promise.all([findByText('foo'), findByText('bar')]); }",
+        "() => { return Promise.all([screen.findByText('foo')]); }",
+        "async () => { const promise = screen.findByText('foo'); await promise; }",
+        "() => { screen.getByText('foo'); screen.queryAllByRole('button'); }",
+        "() => { findByFoo('bar'); find('foo'); }",
+        "async () => { await (screen.findByText('foo') as Promise<HTMLElement>); }",
+    ];
+
+    let fail = vec![
+        "() => { screen.findByText('foo'); }",
+        "() => { findAllByRole('button'); }",
+        "async () => { const el = screen.findByText('foo'); }",
+        "() => { const promise = findByTestId('foo'); promise; }",
+        "() => { Promise.all([findByText('foo')]); }",
+        "() => { view.findByLabelText('foo'); }",
+        "() => { expect(screen.findByText('foo')).toBeDefined(); }",
+    ];
+
+    Tester::new(AwaitAsyncQueries::NAME, AwaitAsyncQueries::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,94 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{TESTING_LIBRARY_ASYNC_UTILS, get_testing_library_call_name, is_promise_handled},
+};
+
+fn await_async_utils_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Promise returned from `{name}` must be handled"))
+        .with_help("Await the call, return it, or chain it with `.then()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitAsyncUtils;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the promises returned by the async utilities `waitFor`
+    /// and `waitForElementToBeRemoved` are handled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The async utilities retry their callback until it passes or times out.
+    /// When the promise is not awaited, the test continues without waiting,
+    /// and a timeout is not reported by the test.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test('hides the spinner', () => {
+    ///   waitFor(() => expect(screen.getByText('Done')).toBeVisible());
+    ///   waitForElementToBeRemoved(() => screen.queryByRole('progressbar'));
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('hides the spinner', async () => {
+    ///   await waitFor(() => expect(screen.getByText('Done')).toBeVisible());
+    ///   return waitForElementToBeRemoved(() => screen.queryByRole('progressbar'));
+    /// });
+    /// ```
+    AwaitAsyncUtils,
+    testing_library,
+    correctness
+);
+
+impl Rule for AwaitAsyncUtils {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_call_name(call) else {
+            return;
+        };
+        if !TESTING_LIBRARY_ASYNC_UTILS.contains(&name) || is_promise_handled(node, ctx) {
+            return;
+        }
+        ctx.diagnostic(await_async_utils_diagnostic(call.span, name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async () => { await waitFor(() => {}); }",
+        "async () => { await waitForElementToBeRemoved(() => screen.queryByText('foo')); }",
+        "() => { return waitFor(() => {}); }",
+        "() => waitFor(() => {})",
+        "() => { waitFor(() => {}).then(() => {}); }",
+        "async () => { await Promise.all([waitFor(() => {}), waitFor(() => {})]); }",
+        "async () => { const promise = waitFor(() => {}); await promise; }",
+        "() => { waitForSomething(); wait(); }",
+    ];
+
+    let fail = vec![
+        "() => { waitFor(() => {}); }",
+        "() => { waitForElementToBeRemoved(() => screen.queryByText('foo')); }",
+        "async () => { const promise = waitFor(() => {}); }",
+        "() => { asyncUtils.waitFor(() => {}); }",
+    ];
+
+    Tester::new(AwaitAsyncUtils::NAME, AwaitAsyncUtils::PLUGIN, pass, fail).test_and_snapshot();
+}
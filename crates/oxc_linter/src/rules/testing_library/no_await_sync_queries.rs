@@ -0,0 +1,111 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_testing_library_call_name, is_sync_query},
+};
+
+fn no_await_sync_queries_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`{name}` query is sync so it does not need to be awaited"))
+        .with_help(
+            "Remove the `await`, or use the matching `findBy*` query to wait for the element.",
+        )
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAwaitSyncQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows awaiting the sync queries, i.e. the `getBy*`, `getAllBy*`,
+    /// `queryBy*` and `queryAllBy*` queries.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Sync queries return the element immediately. Awaiting them suggests
+    /// that they wait for the element to appear, which they don't. The
+    /// `findBy*` queries should be used for that.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// test('shows the button', async () => {
+    ///   const button = await screen.getByRole('button');
+    ///   await queryByText('Submit');
+    /// });
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// test('shows the button', async () => {
+    ///   const button = screen.getByRole('button');
+    ///   await screen.findByText('Submit');
+    /// });
+    /// ```
+    NoAwaitSyncQueries,
+    testing_library,
+    suspicious,
+    fix
+);
+
+impl Rule for NoAwaitSyncQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        let Expression::CallExpression(call) = await_expr.argument.get_inner_expression() else {
+            return;
+        };
+        let Some(name) = get_testing_library_call_name(call) else {
+            return;
+        };
+        if !is_sync_query(name) {
+            return;
+        }
+        ctx.diagnostic_with_fix(no_await_sync_queries_diagnostic(await_expr.span, name), |fixer| {
+            fixer.delete_range(Span::new(await_expr.span.start, await_expr.argument.span().start))
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async () => { const button = screen.getByRole('button'); }",
+        "async () => { await screen.findByRole('button'); }",
+        "async () => { await findAllByText('foo'); }",
+        "async () => { queryByText('foo'); }",
+        "async () => { await getByFoo('foo'); }",
+        "async () => { await waitFor(() => getByText('foo')); }",
+    ];
+
+    let fail = vec![
+        "async () => { const button = await screen.getByRole('button'); }",
+        "async () => { await getAllByText('foo'); }",
+        "async () => { await queryByTestId('foo'); }",
+        "async () => { await view.queryAllByLabelText('foo'); }",
+        "async () => { await (screen.getByText('foo')); }",
+    ];
+
+    let fix = vec![
+        (
+            "async () => { const button = await screen.getByRole('button'); }",
+            "async () => { const button = screen.getByRole('button'); }",
+        ),
+        ("async () => { await getAllByText('foo'); }", "async () => { getAllByText('foo'); }"),
+    ];
+
+    Tester::new(NoAwaitSyncQueries::NAME, NoAwaitSyncQueries::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,105 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_declaration_init, is_render_call, is_render_result},
+};
+
+fn no_container_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Avoid using container methods")
+        .with_help("Prefer using the methods from the `screen` object, e.g. `screen.getByRole()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoContainer;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows calling methods on the `container` returned by `render`,
+    /// e.g. `container.querySelector()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Querying the container depends on the structure of the DOM, which the
+    /// user can't see. The queries of the `screen` object find elements the
+    /// way the user does, e.g. by their role or label.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const { container } = render(<Example />);
+    /// const button = container.querySelector('.btn-primary');
+    ///
+    /// const view = render(<Example />);
+    /// const link = view.container.querySelector('a');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// render(<Example />);
+    /// const button = screen.getByRole('button', { name: 'Submit' });
+    /// ```
+    NoContainer,
+    testing_library,
+    suspicious
+);
+
+impl Rule for NoContainer {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(member) = call.callee.as_member_expression() else {
+            return;
+        };
+        if is_container(member.object(), ctx) {
+            ctx.diagnostic(no_container_diagnostic(call.callee.span()));
+        }
+    }
+}
+
+/// Whether `expr` is the `container` returned by `render`, e.g. `container` in
+/// `const { container } = render(<Example />)`, or `view.container`.
+fn is_container<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => {
+            ident.name == "container" && get_declaration_init(expr, ctx).is_some_and(is_render_call)
+        }
+        Expression::StaticMemberExpression(member) => {
+            member.property.name == "container" && is_render_result(&member.object, ctx)
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "render(<Example />); screen.getByRole('button');",
+        "const { container } = render(<Example />); expect(container).toMatchSnapshot();",
+        "const { container } = render(<Example />); expect(container.firstChild).toBeNull();",
+        "const container = document.body; container.querySelector('div');",
+        "const { container } = setup(); container.querySelector('div');",
+        "function test(container) { container.querySelector('div'); }",
+        "const view = render(<Example />); view.getByText('foo');",
+    ];
+
+    let fail = vec![
+        "const { container } = render(<Example />); container.querySelector('.btn-primary');",
+        "const { container } = render(<Example />); container.getElementsByTagName('a');",
+        "const view = render(<Example />); view.container.querySelector('a');",
+        "render(<Example />).container.querySelector('a');",
+    ];
+
+    Tester::new(NoContainer::NAME, NoContainer::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,116 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        TESTING_LIBRARY_DEBUG_UTILS, get_declaration_init, get_testing_library_call_name,
+        is_imported_from_testing_library, is_render_call, is_render_result,
+    },
+};
+
+fn no_debugging_utils_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected debug statement `{name}`"))
+        .with_help("Remove the debugging utility before committing the test.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDebuggingUtils;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the Testing Library utilities for debugging, like `debug()`,
+    /// `prettyDOM()` and `logRoles()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The debugging utilities print the DOM to the console. They are useful
+    /// while writing a test, but a committed call clutters the output of every
+    /// test run.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const { debug } = render(<Hello />);
+    /// debug();
+    ///
+    /// screen.debug();
+    /// logRoles(screen.getByRole('list'));
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// render(<Hello />);
+    /// expect(screen.getByRole('heading')).toHaveTextContent('Hello');
+    /// ```
+    NoDebuggingUtils,
+    testing_library,
+    suspicious
+);
+
+impl Rule for NoDebuggingUtils {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_call_name(call) else {
+            return;
+        };
+        if !TESTING_LIBRARY_DEBUG_UTILS.contains(&name) {
+            return;
+        }
+        let is_debugging_util = match call.callee.get_inner_expression() {
+            // `debug` is too common a name to report without knowing where it comes from.
+            Expression::Identifier(ident) => {
+                is_imported_from_testing_library(ident, ctx)
+                    || get_declaration_init(&call.callee, ctx).is_some_and(is_render_call)
+                    || (name != "debug"
+                        && ctx.scoping().get_reference(ident.reference_id()).symbol_id().is_none())
+            }
+            callee => callee.as_member_expression().is_some_and(|member| {
+                member.object().is_specific_id("screen") || is_render_result(member.object(), ctx)
+            }),
+        };
+        if is_debugging_util {
+            ctx.diagnostic(no_debugging_utils_diagnostic(call.span, name));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "render(<Hello />); expect(screen.getByRole('heading')).toBeVisible();",
+        "debug();",
+        "const debug = require('debug')('app'); debug('message');",
+        "import debug from 'debug'; debug('message');",
+        "logger.debug('message');",
+        "console.debug('message');",
+        "function prettyDOM() {} prettyDOM();",
+        "import { prettyDOM } from 'pretty-dom'; prettyDOM();",
+    ];
+
+    let fail = vec![
+        "const { debug } = render(<Hello />); debug();",
+        "screen.debug();",
+        "screen.debug(screen.getByRole('list'));",
+        "const view = render(<Hello />); view.debug();",
+        "render(<Hello />).debug();",
+        "screen.logTestingPlaygroundURL();",
+        "logRoles(screen.getByRole('list'));",
+        "console.log(prettyDOM(container));",
+        "import { debug } from '@testing-library/react'; debug();",
+        "import { logDOM } from '@testing-library/dom'; logDOM();",
+    ];
+
+    Tester::new(NoDebuggingUtils::NAME, NoDebuggingUtils::PLUGIN, pass, fail).test_and_snapshot();
+}
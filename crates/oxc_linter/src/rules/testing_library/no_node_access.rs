@@ -0,0 +1,112 @@
+use oxc_ast::{AstKind, ast::Expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{DOM_NODE_ACCESS_METHODS, DOM_NODE_ACCESS_PROPERTIES},
+};
+
+fn no_node_access_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Avoid direct node access with `{name}`"))
+        .with_help("Prefer using the methods from Testing Library, e.g. `screen.getByRole()`.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNodeAccess;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows DOM traversal, like `parentElement` or `querySelector()`,
+    /// in tests.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Tests which traverse the DOM depend on its structure, which the user
+    /// can't see. They break when the markup changes even though the page
+    /// still works. The Testing Library queries find elements the way the user
+    /// does, e.g. by their role or label.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const buttons = screen.getAllByRole('button');
+    /// expect(buttons[1].firstChild).toHaveTextContent('Submit');
+    /// const list = document.querySelector('ul');
+    /// screen.getByText('Submit').closest('button');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// const button = screen.getByRole('button', { name: 'Submit' });
+    /// const list = screen.getByRole('list');
+    /// const items = within(list).getAllByRole('listitem');
+    /// ```
+    NoNodeAccess,
+    testing_library,
+    suspicious
+);
+
+impl Rule for NoNodeAccess {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StaticMemberExpression(member) = node.kind() else {
+            return;
+        };
+        let name = member.property.name.as_str();
+        if !DOM_NODE_ACCESS_PROPERTIES.contains(&name) && !DOM_NODE_ACCESS_METHODS.contains(&name) {
+            return;
+        }
+        // `props.children` is a React prop, not a DOM node.
+        if name == "children" && is_props(&member.object) {
+            return;
+        }
+        ctx.diagnostic(no_node_access_diagnostic(member.property.span, name));
+    }
+}
+
+/// Whether `expr` is `props` or `this.props`.
+fn is_props(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == "props",
+        Expression::StaticMemberExpression(member) => {
+            matches!(member.object, Expression::ThisExpression(_))
+                && member.property.name == "props"
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const button = screen.getByRole('button');",
+        "const items = within(screen.getByRole('list')).getAllByRole('listitem');",
+        "expect(screen.getByText('foo')).toBeInTheDocument();",
+        "const { children } = props;",
+        "function Wrapper(props) { return props.children; }",
+        "class Wrapper { render() { return this.props.children; } }",
+        "const element = document.createElement('div');",
+        "const parent = node['parentElement'];",
+    ];
+
+    let fail = vec![
+        "const button = screen.getByText('Submit').closest('button');",
+        "expect(screen.getAllByRole('button')[1].firstChild).toHaveTextContent('foo');",
+        "const list = document.querySelector('ul');",
+        "const items = container.querySelectorAll('li');",
+        "const parent = screen.getByText('foo').parentElement;",
+        "const sibling = element.nextSibling.previousSibling;",
+        "expect(element.children).toHaveLength(2);",
+        "const first = element?.firstElementChild;",
+    ];
+
+    Tester::new(NoNodeAccess::NAME, NoNodeAccess::PLUGIN, pass, fail).test_and_snapshot();
+}
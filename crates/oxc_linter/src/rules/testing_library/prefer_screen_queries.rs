@@ -0,0 +1,145 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, Expression, ObjectPropertyKind},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_declaration_init, get_testing_library_call_name, is_query, is_render_call},
+};
+
+fn prefer_screen_queries_diagnostic(span: Span, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Avoid destructuring queries from `render` result, use `screen.{name}` instead"
+    ))
+    .with_help("The queries of the `screen` object are always bound to `document.body`.")
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferScreenQueries;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces that the queries returned by `render` are called on the
+    /// `screen` object instead, e.g. `screen.getByRole()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The queries returned by `render` are the same as the queries of the
+    /// `screen` object. Using `screen` everywhere means there is no need to keep
+    /// the result of `render` up to date as queries are added or removed.
+    ///
+    /// Queries returned by `render` with a custom `container` or `baseElement`,
+    /// and queries bound with `within`, are allowed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// const { getByText } = render(<Component />);
+    /// getByText('foo');
+    ///
+    /// const utils = render(<Component />);
+    /// utils.getByText('foo');
+    ///
+    /// render(<Component />).getByText('foo');
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// render(<Component />);
+    /// screen.getByText('foo');
+    ///
+    /// within(screen.getByRole('list')).getAllByRole('listitem');
+    ///
+    /// const { getByText } = render(<Component />, { container: table });
+    /// getByText('foo');
+    /// ```
+    PreferScreenQueries,
+    testing_library,
+    style
+);
+
+impl Rule for PreferScreenQueries {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_testing_library_call_name(call) else {
+            return;
+        };
+        if !is_query(name) {
+            return;
+        }
+        let render_call = match call.callee.get_inner_expression() {
+            Expression::Identifier(_) => get_declaration_init(&call.callee, ctx),
+            callee => callee.as_member_expression().and_then(|member| {
+                let object = member.object();
+                if is_render_call(object) {
+                    Some(object)
+                } else {
+                    get_declaration_init(object, ctx)
+                }
+            }),
+        };
+        let Some(Expression::CallExpression(render_call)) =
+            render_call.map(Expression::get_inner_expression)
+        else {
+            return;
+        };
+        if !render_call.callee.is_specific_id("render")
+            || has_custom_container(&render_call.arguments)
+        {
+            return;
+        }
+        ctx.diagnostic(prefer_screen_queries_diagnostic(call.callee.span(), name));
+    }
+}
+
+/// Whether the options of `render` have a `container` or `baseElement`, which the returned
+/// queries are bound to.
+fn has_custom_container(arguments: &[Argument]) -> bool {
+    matches!(arguments.get(1), Some(Argument::ObjectExpression(options))
+        if options.properties.iter().any(|prop| matches!(prop, ObjectPropertyKind::ObjectProperty(prop)
+            if prop.key.is_specific_static_name("container")
+                || prop.key.is_specific_static_name("baseElement"))))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "render(<Component />); screen.getByText('foo');",
+        "render(<Component />); await screen.findAllByRole('button');",
+        "within(screen.getByRole('list')).getAllByRole('listitem');",
+        "const list = within(screen.getByRole('list')); list.getAllByRole('listitem');",
+        "const { getByText } = within(screen.getByRole('list')); getByText('foo');",
+        "const { getByText } = render(<Component />, { container: table }); getByText('foo');",
+        "const utils = render(<Component />, { baseElement: document.body }); utils.getByText('foo');",
+        "const { container } = render(<Component />); expect(container).toBeDefined();",
+        "const { rerender } = render(<Component />); rerender(<Component />);",
+        "getByText(container, 'foo');",
+        "import { getByText } from '@testing-library/dom'; getByText(container, 'foo');",
+        "const { getByText } = setup(); getByText('foo');",
+    ];
+
+    let fail = vec![
+        "const { getByText } = render(<Component />); getByText('foo');",
+        "const { queryAllByRole } = render(<Component />); queryAllByRole('button');",
+        "const { findByText } = render(<Component />); await findByText('foo');",
+        "const utils = render(<Component />); utils.getByText('foo');",
+        "render(<Component />).getByText('foo');",
+        "const view = render(<Component />, { wrapper: Providers }); view.getByRole('button');",
+    ];
+
+    Tester::new(PreferScreenQueries::NAME, PreferScreenQueries::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
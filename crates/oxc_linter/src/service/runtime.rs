@@ -17,7 +17,7 @@ use smallvec::SmallVec;
 
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
-use oxc_parser::{ParseOptions, Parser};
+use oxc_parser::{ParseOptions, Parser, has_flow_pragma};
 use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
@@ -41,6 +41,10 @@ pub struct Runtime {
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: Linter,
     resolver: Option<Resolver>,
+    /// Build the complete module graph before linting any module, so that every module knows
+    /// its importers. Only enabled for rules that need it, as all modules stay in memory until
+    /// the graph is built.
+    complete_module_graph: bool,
 
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
@@ -182,12 +186,15 @@ impl Runtime {
         let resolver = options.cross_module.then(|| {
             Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
         });
+        let complete_module_graph =
+            resolver.is_some() && linter.has_rule_enabled("import", "no-unused-modules");
         Self {
             allocator_pool,
             cwd: options.cwd,
             paths: IndexSet::with_capacity_and_hasher(0, FxBuildHasher),
             linter,
             resolver,
+            complete_module_graph,
             file_system: Box::new(OsFileSystem),
         }
     }
@@ -317,7 +324,12 @@ impl Runtime {
         // We build the module graph from one group, run lint on them, drop sources and semantics but keep the module
         // graph, and then move on to the next group.
         // This size is empirical based on AFFiNE@97cc814a.
-        let group_size = rayon::current_num_threads() * 4;
+        // When the complete module graph is needed, all modules form a single group.
+        let group_size = if self.complete_module_graph {
+            self.paths.len()
+        } else {
+            rayon::current_num_threads() * 4
+        };
 
         // Stores modules that belongs to `self.paths` in current group.
         // They are passed to `on_module_to_lint` at the end of each group.
@@ -478,6 +490,15 @@ impl Runtime {
                         else {
                             continue;
                         };
+                        if me.complete_module_graph {
+                            dep_module_record
+                                .importers
+                                .write()
+                                .unwrap()
+                                .entry(Path::new(&path).to_path_buf())
+                                .or_default()
+                                .extend(record.imported_names(&request.specifier));
+                        }
                         loaded_modules.insert(request.specifier, Arc::clone(dep_module_record));
                     }
                 }
@@ -738,6 +759,8 @@ impl Runtime {
         messages.into_inner().unwrap()
     }
 
+    /// Only messages of the first path are returned, other paths are linted to take part in the
+    /// module graph.
     #[cfg(test)]
     pub(super) fn run_test_source<'a>(
         &mut self,
@@ -748,9 +771,13 @@ impl Runtime {
         use oxc_allocator::CloneIn;
         use std::sync::Mutex;
 
+        let path_to_report = self.paths.first().cloned();
         let messages = Mutex::new(Vec::<Message<'a>>::new());
         rayon::scope(|scope| {
             self.resolve_modules(scope, check_syntax_errors, tx_error, |me, mut module| {
+                if path_to_report.as_ref() != Some(&module.path) {
+                    return;
+                }
                 module.content.with_dependent_mut(
                     |allocator_guard, ModuleContentDependent { source_text: _, section_contents }| {
                         assert_eq!(module.section_module_records.len(), section_contents.len());
@@ -932,16 +959,25 @@ impl Runtime {
         source_type: SourceType,
         check_syntax_errors: bool,
     ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .with_options(ParseOptions {
-                parse_regular_expression: true,
-                allow_return_outside_function: true,
-                ..ParseOptions::default()
-            })
-            .parse();
+        let parse_options = ParseOptions {
+            parse_regular_expression: true,
+            allow_return_outside_function: true,
+            ..ParseOptions::default()
+        };
+        // Files with a `@flow` pragma are parsed with Flow type annotations.
+        let source_type = if source_type.is_javascript() && has_flow_pragma(source_text) {
+            source_type.with_flow(true)
+        } else {
+            source_type
+        };
+        let ret =
+            Parser::new(allocator, source_text, source_type).with_options(parse_options).parse();
 
         if !ret.errors.is_empty() {
-            return Err(if ret.is_flow_language { vec![] } else { ret.errors });
+            // Not all Flow syntax is supported yet, so Flow files which cannot be parsed are
+            // skipped without reporting errors.
+            let is_flow = source_type.is_flow() || ret.is_flow_language;
+            return Err(if is_flow { vec![] } else { ret.errors });
         }
 
         let semantic_ret = SemanticBuilder::new()
@@ -958,7 +994,7 @@ impl Runtime {
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
 
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
@@ -966,18 +1002,31 @@ impl Runtime {
         if let Some(resolver) = &self.resolver {
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
-            resolved_module_requests = module_record
+            module_record.resolved_requested_modules = module_record
                 .requested_modules
                 .keys()
-                .filter_map(|specifier| {
-                    let resolution = resolver.resolve(dir, specifier).ok()?;
+                .map(|specifier| {
+                    let resolution = resolver.resolve(dir, specifier).ok();
+                    (
+                        specifier.clone(),
+                        resolution.map(|resolution| resolution.path().to_path_buf()),
+                    )
+                })
+                .collect();
+            resolved_module_requests = module_record
+                .resolved_requested_modules
+                .iter()
+                .filter_map(|(specifier, resolved_path)| {
                     Some(ResolvedModuleRequest {
                         specifier: specifier.clone(),
-                        resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
+                        resolved_requested_path: Arc::<OsStr>::from(
+                            resolved_path.as_ref()?.as_os_str(),
+                        ),
                     })
                 })
                 .collect();
         }
+        let module_record = Arc::new(module_record);
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic))
     }
 }
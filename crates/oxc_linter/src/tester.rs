@@ -235,6 +235,8 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    /// Other files linted along with the test source, so that they are part of the module graph.
+    module_graph_paths: Vec<PathBuf>,
 }
 
 impl Tester {
@@ -262,6 +264,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            module_graph_paths: vec![],
         }
     }
 
@@ -310,6 +313,14 @@ impl Tester {
         self
     }
 
+    /// Lint the given paths, relative to the fixtures directory, along with each test case so
+    /// they take part in the module graph. Only diagnostics of the test case are reported.
+    pub fn with_module_graph_paths(mut self, paths: &[&str]) -> Self {
+        self.module_graph_paths =
+            paths.iter().map(|path| self.current_working_directory.join(path)).collect();
+        self
+    }
+
     pub fn with_jest_plugin(mut self, yes: bool) -> Self {
         self.plugins.builtin.set(BuiltinLintPlugins::JEST, yes);
         self
@@ -531,8 +542,7 @@ impl Tester {
         .with_fix(fix_kind.into());
 
         let path_to_lint = if self.plugins.has_import() {
-            assert!(path.is_none(), "import plugin does not support path");
-            self.current_working_directory.join(&self.rule_path)
+            self.current_working_directory.join(path.as_ref().unwrap_or(&self.rule_path))
         } else if let Some(path) = path {
             self.current_working_directory.join(path)
         } else if self.plugins.has_test() {
@@ -542,12 +552,15 @@ impl Tester {
         };
 
         let cwd = self.current_working_directory.clone();
-        let paths = vec![Arc::<OsStr>::from(path_to_lint.as_os_str())];
+        let paths = std::iter::once(&path_to_lint)
+            .chain(&self.module_graph_paths)
+            .map(|path| Arc::<OsStr>::from(path.as_os_str()))
+            .collect();
         let options = LintServiceOptions::new(cwd).with_cross_module(self.plugins.has_import());
         let mut lint_service = LintService::new(linter, AllocatorPool::default(), options);
         lint_service
             .with_file_system(Box::new(TesterFileSystem::new(
-                path_to_lint,
+                path_to_lint.clone(),
                 source_text.to_string(),
             )))
             .with_paths(paths);
@@ -565,7 +578,7 @@ impl Tester {
         }
 
         let diagnostic_path = if self.plugins.has_import() {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+            path_to_lint.strip_prefix(&self.current_working_directory).unwrap()
         } else {
             &self.rule_path
         }
@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AccessorProperty, ArrowFunctionExpression, AssignmentExpression, AssignmentPattern,
+        AssignmentTargetPropertyIdentifier, AssignmentTargetWithDefault, BreakStatement,
+        CallExpression, CatchClause, ComputedMemberExpression, ConditionalExpression,
+        ContinueStatement, DoWhileStatement, Expression, ForInStatement, ForOfStatement,
+        ForStatement, FormalParameters, Function, FunctionBody, IfStatement, LogicalExpression,
+        LogicalOperator, PrivateFieldExpression, PropertyDefinition, Statement, StaticBlock,
+        StaticMemberExpression, SwitchCase, SwitchStatement, WhileStatement,
+    },
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{AstNode, ast_util::get_function_name_with_kind};
+
+/// How `switch` statements add to the cyclomatic complexity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchComplexity {
+    /// Every `case` with a test adds one.
+    #[default]
+    Classic,
+    /// The whole `switch` statement adds one.
+    Modified,
+}
+
+/// The name of a function with its kind, e.g. ``method `foo` ``, as returned by
+/// [`get_function_name_with_kind`]. Functions assigned to a variable, e.g.
+/// `const foo = () => {}`, are named after it.
+pub fn get_complexity_function_name<'a>(node: &AstNode<'a>, parent: &AstNode<'a>) -> Cow<'a, str> {
+    let name = get_function_name_with_kind(node, parent);
+    if let AstKind::VariableDeclarator(declarator) = parent.kind()
+        && let Some(id) = declarator.id.get_binding_identifier()
+        && !name.contains('`')
+    {
+        return Cow::Owned(format!("{name} `{}`", id.name));
+    }
+    name
+}
+
+/// The cyclomatic complexity of a function: one, plus one for every branch in it.
+///
+/// Nested functions, class field initializers and static blocks have a complexity
+/// of their own and are not counted. Returns `0` if `kind` is not a function.
+pub fn cyclomatic_complexity(kind: AstKind<'_>, switch: SwitchComplexity) -> usize {
+    let mut visitor = CyclomaticComplexity { complexity: 1, switch };
+    if !visit_function_node(&mut visitor, kind) {
+        return 0;
+    }
+    visitor.complexity
+}
+
+/// The cognitive complexity of a function, as defined by
+/// [SonarSource](https://www.sonarsource.com/docs/CognitiveComplexity.pdf).
+///
+/// Every break in the linear flow adds one, plus the depth of nesting for
+/// conditionals, loops, `switch` and `catch`. Nested functions, class field
+/// initializers and static blocks are not counted. Returns `0` if `kind` is not
+/// a function.
+pub fn cognitive_complexity(kind: AstKind<'_>) -> usize {
+    let mut visitor = CognitiveComplexity { complexity: 0, nesting: 0 };
+    if !visit_function_node(&mut visitor, kind) {
+        return 0;
+    }
+    visitor.complexity
+}
+
+fn visit_function_node<'a>(visitor: &mut impl Visit<'a>, kind: AstKind<'a>) -> bool {
+    let (params, body): (&FormalParameters<'a>, Option<&FunctionBody<'a>>) = match kind {
+        AstKind::Function(func) => (&func.params, func.body.as_deref()),
+        AstKind::ArrowFunctionExpression(arrow) => (&arrow.params, Some(&arrow.body)),
+        _ => return false,
+    };
+    visitor.visit_formal_parameters(params);
+    if let Some(body) = body {
+        visitor.visit_function_body(body);
+    }
+    true
+}
+
+struct CyclomaticComplexity {
+    complexity: usize,
+    switch: SwitchComplexity,
+}
+
+impl<'a> Visit<'a> for CyclomaticComplexity {
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        self.visit_property_key(&it.key);
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.visit_property_key(&it.key);
+    }
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_if_statement(self, it);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.complexity += 1;
+        walk::walk_conditional_expression(self, it);
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        self.complexity += 1;
+        walk::walk_logical_expression(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        if it.operator.is_logical() {
+            self.complexity += 1;
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_for_statement(self, it);
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_for_in_statement(self, it);
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_for_of_statement(self, it);
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_while_statement(self, it);
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.complexity += 1;
+        walk::walk_do_while_statement(self, it);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.complexity += 1;
+        walk::walk_catch_clause(self, it);
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        if self.switch == SwitchComplexity::Modified {
+            self.complexity += 1;
+        }
+        walk::walk_switch_statement(self, it);
+    }
+
+    fn visit_switch_case(&mut self, it: &SwitchCase<'a>) {
+        if self.switch == SwitchComplexity::Classic && it.test.is_some() {
+            self.complexity += 1;
+        }
+        walk::walk_switch_case(self, it);
+    }
+
+    fn visit_assignment_pattern(&mut self, it: &AssignmentPattern<'a>) {
+        self.complexity += 1;
+        walk::walk_assignment_pattern(self, it);
+    }
+
+    fn visit_assignment_target_with_default(&mut self, it: &AssignmentTargetWithDefault<'a>) {
+        self.complexity += 1;
+        walk::walk_assignment_target_with_default(self, it);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        if it.init.is_some() {
+            self.complexity += 1;
+        }
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if it.optional {
+            self.complexity += 1;
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        if it.optional {
+            self.complexity += 1;
+        }
+        walk::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        if it.optional {
+            self.complexity += 1;
+        }
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_private_field_expression(&mut self, it: &PrivateFieldExpression<'a>) {
+        if it.optional {
+            self.complexity += 1;
+        }
+        walk::walk_private_field_expression(self, it);
+    }
+}
+
+struct CognitiveComplexity {
+    complexity: usize,
+    nesting: usize,
+}
+
+impl CognitiveComplexity {
+    /// Adds a structure that increases the nesting, e.g. a loop.
+    fn add_nesting_structure(&mut self) {
+        self.complexity += 1 + self.nesting;
+    }
+
+    fn nested(&mut self, f: impl FnOnce(&mut Self)) {
+        self.nesting += 1;
+        f(self);
+        self.nesting -= 1;
+    }
+
+    /// `else if` and `else` add one, regardless of the nesting.
+    fn visit_if_chain(&mut self, it: &IfStatement<'_>) {
+        self.visit_expression(&it.test);
+        self.nested(|v| v.visit_statement(&it.consequent));
+        match &it.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                self.complexity += 1;
+                self.visit_if_chain(else_if);
+            }
+            Some(alternate) => {
+                self.complexity += 1;
+                self.nested(|v| v.visit_statement(alternate));
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for CognitiveComplexity {
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        self.visit_property_key(&it.key);
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.visit_property_key(&it.key);
+    }
+
+    fn visit_static_block(&mut self, _it: &StaticBlock<'a>) {}
+
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.add_nesting_structure();
+        self.visit_if_chain(it);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.add_nesting_structure();
+        self.visit_expression(&it.test);
+        self.nested(|v| {
+            v.visit_expression(&it.consequent);
+            v.visit_expression(&it.alternate);
+        });
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        self.add_nesting_structure();
+        self.visit_expression(&it.discriminant);
+        self.nested(|v| v.visit_switch_cases(&it.cases));
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.add_nesting_structure();
+        if let Some(init) = &it.init {
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &it.test {
+            self.visit_expression(test);
+        }
+        if let Some(update) = &it.update {
+            self.visit_expression(update);
+        }
+        self.nested(|v| v.visit_statement(&it.body));
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.add_nesting_structure();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.nested(|v| v.visit_statement(&it.body));
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.add_nesting_structure();
+        self.visit_for_statement_left(&it.left);
+        self.visit_expression(&it.right);
+        self.nested(|v| v.visit_statement(&it.body));
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.add_nesting_structure();
+        self.visit_expression(&it.test);
+        self.nested(|v| v.visit_statement(&it.body));
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.add_nesting_structure();
+        self.nested(|v| v.visit_statement(&it.body));
+        self.visit_expression(&it.test);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        self.add_nesting_structure();
+        if let Some(param) = &it.param {
+            self.visit_catch_parameter(param);
+        }
+        self.nested(|v| v.visit_block_statement(&it.body));
+    }
+
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, it: &ContinueStatement<'a>) {
+        if it.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    /// Every sequence of like operators adds one, e.g. `a && b && c || d` adds two.
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        let mut operators = vec![];
+        let mut operands = vec![];
+        flatten_logical_expression(it, &mut operators, &mut operands);
+        self.complexity += operators
+            .iter()
+            .enumerate()
+            .filter(|(i, operator)| *i == 0 || operators[i - 1] != **operator)
+            .count();
+        for operand in operands {
+            self.visit_expression(operand);
+        }
+    }
+}
+
+/// Collects the operators and operands of a chain of logical expressions, from left to right.
+fn flatten_logical_expression<'b, 'a>(
+    expr: &'b LogicalExpression<'a>,
+    operators: &mut Vec<LogicalOperator>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    for (i, side) in [&expr.left, &expr.right].into_iter().enumerate() {
+        if i == 1 {
+            operators.push(expr.operator);
+        }
+        if let Expression::LogicalExpression(inner) = side.without_parentheses() {
+            flatten_logical_expression(inner, operators, operands);
+        } else {
+            operands.push(side);
+        }
+    }
+}
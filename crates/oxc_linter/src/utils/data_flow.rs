@@ -0,0 +1,475 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Argument, AssignmentOperator, Expression, VariableDeclarationKind},
+};
+use oxc_cfg::{
+    BlockNodeId, ControlFlowGraph, EdgeType,
+    graph::{Direction, visit::EdgeRef},
+};
+use oxc_semantic::{NodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{AstNode, context::LintContext};
+
+/// A place where a symbol is given a value.
+#[derive(Debug, Clone, Copy)]
+pub struct Definition<'a> {
+    /// The node which defines the symbol: its declaration, e.g. a `FormalParameter` or a
+    /// `VariableDeclarator`, or the `IdentifierReference` which is written to.
+    pub node_id: NodeId,
+    /// The code which defines the symbol, e.g. the whole assignment for `a = b`.
+    pub span: Span,
+    pub kind: DefinitionKind<'a>,
+    /// Whether the definition takes effect when its function starts rather than where it is,
+    /// like a parameter, a function declaration or `var a;`.
+    hoisted: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DefinitionKind<'a> {
+    /// A parameter of a function, e.g. `a` in `function f(a) {}` or `function f({ a }) {}`.
+    Parameter,
+    /// The symbol is given the value of an expression, e.g. `let a = value` or `a = value`.
+    Value(&'a Expression<'a>),
+    /// The symbol is given a part of the value of an expression, e.g. `const { a } = value`
+    /// or `for (const a of value)`.
+    DerivedFrom(&'a Expression<'a>),
+    /// The previous value of the symbol is updated, e.g. `a += value` or `a++`.
+    Update(Option<&'a Expression<'a>>),
+    /// Any other definition, e.g. `let a;`, a function or class declaration, or an import.
+    Other,
+}
+
+/// All definitions of a symbol: its declaration, followed by every write to it.
+pub fn definitions<'a, 'c>(
+    symbol_id: SymbolId,
+    ctx: &'c LintContext<'a>,
+) -> impl Iterator<Item = Definition<'a>> + 'c {
+    std::iter::once(declaration_definition(symbol_id, ctx)).chain(
+        ctx.symbol_references(symbol_id)
+            .filter(|reference| reference.is_write())
+            .map(|reference| write_definition(reference.node_id(), ctx)),
+    )
+}
+
+fn declaration_definition<'a>(symbol_id: SymbolId, ctx: &LintContext<'a>) -> Definition<'a> {
+    let node = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    let (kind, hoisted) = match node.kind() {
+        AstKind::FormalParameter(_) => (DefinitionKind::Parameter, true),
+        // function f(...rest) {}
+        AstKind::BindingRestElement(_)
+            if matches!(ctx.nodes().parent_kind(node.id()), AstKind::FormalParameters(_)) =>
+        {
+            (DefinitionKind::Parameter, true)
+        }
+        AstKind::VariableDeclarator(decl) => match &decl.init {
+            Some(init) if decl.id.kind.is_binding_identifier() => {
+                (DefinitionKind::Value(init), false)
+            }
+            Some(init) => (DefinitionKind::DerivedFrom(init), false),
+            None => match ctx.nodes().ancestors(node.id()).nth(1).map(AstNode::kind) {
+                Some(AstKind::ForInStatement(stmt)) => {
+                    (DefinitionKind::DerivedFrom(&stmt.right), false)
+                }
+                Some(AstKind::ForOfStatement(stmt)) => {
+                    (DefinitionKind::DerivedFrom(&stmt.right), false)
+                }
+                _ => (DefinitionKind::Other, decl.kind == VariableDeclarationKind::Var),
+            },
+        },
+        AstKind::Function(_)
+        | AstKind::ImportSpecifier(_)
+        | AstKind::ImportDefaultSpecifier(_)
+        | AstKind::ImportNamespaceSpecifier(_) => (DefinitionKind::Other, true),
+        _ => (DefinitionKind::Other, false),
+    };
+    Definition { node_id: node.id(), span: node.kind().span(), kind, hoisted }
+}
+
+fn write_definition<'a>(node_id: NodeId, ctx: &LintContext<'a>) -> Definition<'a> {
+    let span = ctx.nodes().get_node(node_id).kind().span();
+    let parent_id = ctx.nodes().parent_id(node_id);
+    for ancestor in ctx.nodes().ancestors(node_id) {
+        let (kind, span) = match ancestor.kind() {
+            // [a, b] = value
+            AstKind::AssignmentExpression(assign) if ancestor.id() != parent_id => {
+                (DefinitionKind::DerivedFrom(&assign.right), assign.span)
+            }
+            AstKind::AssignmentExpression(assign)
+                if assign.operator == AssignmentOperator::Assign =>
+            {
+                (DefinitionKind::Value(&assign.right), assign.span)
+            }
+            AstKind::AssignmentExpression(assign) => {
+                (DefinitionKind::Update(Some(&assign.right)), assign.span)
+            }
+            AstKind::UpdateExpression(update) => (DefinitionKind::Update(None), update.span),
+            // The loop's body is not part of the definition, it runs after it.
+            AstKind::ForInStatement(stmt) => (DefinitionKind::DerivedFrom(&stmt.right), span),
+            AstKind::ForOfStatement(stmt) => (DefinitionKind::DerivedFrom(&stmt.right), span),
+            AstKind::ArrayAssignmentTarget(_)
+            | AstKind::ObjectAssignmentTarget(_)
+            | AstKind::AssignmentTargetRest(_)
+            | AstKind::AssignmentTargetWithDefault(_)
+            | AstKind::AssignmentTargetPropertyIdentifier(_)
+            | AstKind::AssignmentTargetPropertyProperty(_) => continue,
+            _ => break,
+        };
+        return Definition { node_id, span, kind, hoisted: false };
+    }
+    Definition { node_id, span, kind: DefinitionKind::Other, hoisted: false }
+}
+
+/// The definitions of `symbol_id` which may have given it its value when `node_id` is
+/// evaluated, e.g. both assignments for the last `a` in `let a; if (b) a = 1; else a = 2; f(a)`.
+///
+/// A definition reaches the node when some path through the control flow graph leads from the
+/// definition to the node without passing another definition. Definitions in other functions,
+/// e.g. in a callback, may run at any time and always reach the node.
+pub fn reaching_definitions<'a>(
+    symbol_id: SymbolId,
+    node_id: NodeId,
+    ctx: &LintContext<'a>,
+) -> Vec<Definition<'a>> {
+    let node = ctx.nodes().get_node(node_id);
+    let span = node.kind().span();
+    let function = enclosing_function(node_id, ctx);
+    let definitions = definitions(symbol_id, ctx).collect::<Vec<_>>();
+    let in_function = definitions
+        .iter()
+        .map(|def| enclosing_function(def.node_id, ctx) == function)
+        .collect::<Vec<_>>();
+    // The definitions in the node's function which take effect where they are, with their block.
+    let local = definitions
+        .iter()
+        .enumerate()
+        .filter(|(i, def)| in_function[*i] && !def.hoisted)
+        .map(|(i, def)| (i, ctx.nodes().get_node(def.node_id).cfg_id()))
+        .collect::<Vec<_>>();
+
+    let cfg = ctx.cfg();
+    let mut reaching = vec![false; definitions.len()];
+    let mut reaches_entry = false;
+    let mut visited = FxHashSet::default();
+    // Walk backwards from the node, up to the last definition on every path. The node's own
+    // block is only searched before the node at first. A definition which contains the node,
+    // like `a = f(a)`, can only precede it by going around a loop.
+    let mut stack = vec![(node.cfg_id(), false, true)];
+    while let Some((block, looped, is_start)) = stack.pop() {
+        if !is_start && !visited.insert((block, looped)) {
+            continue;
+        }
+        let last = local
+            .iter()
+            .filter(|(i, def_block)| {
+                let def_span = definitions[*i].span;
+                *def_block == block
+                    && if is_start {
+                        def_span.end <= span.start
+                    } else {
+                        looped || !def_span.contains_inclusive(span)
+                    }
+            })
+            .max_by_key(|(i, _)| definitions[*i].span.end);
+        if let Some((i, _)) = last {
+            reaching[*i] = true;
+            continue;
+        }
+        let predecessors = predecessors(cfg, block).collect::<Vec<_>>();
+        reaches_entry |= predecessors.is_empty();
+        stack.extend(
+            predecessors
+                .into_iter()
+                .map(|(predecessor, is_backedge)| (predecessor, looped || is_backedge, false)),
+        );
+    }
+
+    definitions
+        .into_iter()
+        .enumerate()
+        .filter(|(i, def)| reaching[*i] || !in_function[*i] || (def.hoisted && reaches_entry))
+        .map(|(_, def)| def)
+        .collect()
+}
+
+/// Whether `symbol_id` may be written to after `from` is evaluated and before `to` is, e.g.
+/// `a` between `const b = a` and `f(a)` in `const b = a; if (c) a = 1; f(a)`.
+///
+/// Writes in other functions, e.g. in a callback, may run at any time and always count.
+pub fn is_reassigned_between(
+    symbol_id: SymbolId,
+    from: NodeId,
+    to: NodeId,
+    ctx: &LintContext<'_>,
+) -> bool {
+    let cfg = ctx.cfg();
+    let from = ctx.nodes().get_node(from);
+    let to = ctx.nodes().get_node(to);
+    let function = enclosing_function(to.id(), ctx);
+    ctx.symbol_references(symbol_id).filter(|reference| reference.is_write()).any(|reference| {
+        if enclosing_function(reference.node_id(), ctx) != function {
+            return true;
+        }
+        let def = write_definition(reference.node_id(), ctx);
+        let block = ctx.nodes().get_node(def.node_id).cfg_id();
+        precedes(cfg, (from.cfg_id(), from.kind().span().end), (block, def.span.end))
+            && precedes(cfg, (block, def.span.end), (to.cfg_id(), to.kind().span().start))
+    })
+}
+
+/// Whether the value of `expr` may originate from a definition for which `is_origin` returns
+/// `true`, e.g. from a parameter.
+///
+/// Variables are followed to the definitions which reach them, and a value originates from a
+/// definition if any part of it does, e.g. `'/users/' + id` or `id.trim()`.
+pub fn may_originate_from<'a, F>(expr: &Expression<'a>, ctx: &LintContext<'a>, is_origin: F) -> bool
+where
+    F: Fn(&Definition<'a>) -> bool,
+{
+    OriginFinder { ctx, is_origin, visited: FxHashSet::default() }.expression(expr)
+}
+
+/// Whether the value of `expr` may come from a parameter of a function, e.g. `req.query.id`
+/// in `function handler(req) { const id = req.query.id; }`.
+pub fn flows_from_parameter<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    may_originate_from(expr, ctx, |def| matches!(def.kind, DefinitionKind::Parameter))
+}
+
+struct OriginFinder<'c, 'a, F> {
+    ctx: &'c LintContext<'a>,
+    is_origin: F,
+    /// The definitions already followed, so that loops like `a = a + b` end.
+    visited: FxHashSet<NodeId>,
+}
+
+impl<'a, F: Fn(&Definition<'a>) -> bool> OriginFinder<'_, 'a, F> {
+    fn expression(&mut self, expr: &Expression<'a>) -> bool {
+        match expr.get_inner_expression() {
+            Expression::Identifier(ident) => {
+                let reference = self.ctx.scoping().get_reference(ident.reference_id());
+                reference
+                    .symbol_id()
+                    .is_some_and(|symbol_id| self.symbol(symbol_id, reference.node_id()))
+            }
+            Expression::TemplateLiteral(template) => {
+                template.expressions.iter().any(|expr| self.expression(expr))
+            }
+            Expression::BinaryExpression(binary) => {
+                self.expression(&binary.left) || self.expression(&binary.right)
+            }
+            Expression::LogicalExpression(logical) => {
+                self.expression(&logical.left) || self.expression(&logical.right)
+            }
+            Expression::ConditionalExpression(cond) => {
+                self.expression(&cond.consequent) || self.expression(&cond.alternate)
+            }
+            Expression::AwaitExpression(await_expr) => self.expression(&await_expr.argument),
+            Expression::CallExpression(call) => {
+                call.callee
+                    .as_member_expression()
+                    .is_some_and(|member| self.expression(member.object()))
+                    || call.arguments.iter().any(|arg| match arg {
+                        Argument::SpreadElement(spread) => self.expression(&spread.argument),
+                        arg => arg.as_expression().is_some_and(|arg| self.expression(arg)),
+                    })
+            }
+            expr => {
+                expr.as_member_expression().is_some_and(|member| self.expression(member.object()))
+            }
+        }
+    }
+
+    /// Whether the value of `symbol_id` at `node_id` may originate from an origin.
+    fn symbol(&mut self, symbol_id: SymbolId, node_id: NodeId) -> bool {
+        reaching_definitions(symbol_id, node_id, self.ctx)
+            .iter()
+            .any(|def| self.definition(symbol_id, def))
+    }
+
+    fn definition(&mut self, symbol_id: SymbolId, def: &Definition<'a>) -> bool {
+        if !self.visited.insert(def.node_id) {
+            return false;
+        }
+        if (self.is_origin)(def) {
+            return true;
+        }
+        match def.kind {
+            DefinitionKind::Value(expr) | DefinitionKind::DerivedFrom(expr) => {
+                self.expression(expr)
+            }
+            DefinitionKind::Update(value) => {
+                value.is_some_and(|value| self.expression(value))
+                    || self.symbol(symbol_id, def.node_id)
+            }
+            DefinitionKind::Parameter | DefinitionKind::Other => false,
+        }
+    }
+}
+
+/// The function or arrow function which `node_id` is in, or `None` at the top level.
+fn enclosing_function(node_id: NodeId, ctx: &LintContext) -> Option<NodeId> {
+    ctx.nodes()
+        .ancestors(node_id)
+        .find(|node| {
+            matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .map(AstNode::id)
+}
+
+/// The blocks control can come to `block` from, without entering or leaving a function, and
+/// whether the edge goes back to the start of a loop.
+fn predecessors(
+    cfg: &ControlFlowGraph,
+    block: BlockNodeId,
+) -> impl Iterator<Item = (BlockNodeId, bool)> + '_ {
+    cfg.graph()
+        .edges_directed(block, Direction::Incoming)
+        .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction | EdgeType::Unreachable))
+        .map(|edge| (edge.source(), matches!(edge.weight(), EdgeType::Backedge)))
+}
+
+/// Whether control can get from `from`, a block and a position in it, to `to` in the same
+/// function.
+fn precedes(cfg: &ControlFlowGraph, from: (BlockNodeId, u32), to: (BlockNodeId, u32)) -> bool {
+    if from.0 == to.0 && from.1 <= to.1 {
+        return true;
+    }
+    // Otherwise `to` needs to be reached through at least one edge, which for the same block
+    // means going around a loop.
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![from.0];
+    while let Some(block) = stack.pop() {
+        for edge in cfg.graph().edges_directed(block, Direction::Outgoing) {
+            if matches!(edge.weight(), EdgeType::NewFunction | EdgeType::Unreachable) {
+                continue;
+            }
+            if edge.target() == to.0 {
+                return true;
+            }
+            if visited.insert(edge.target()) {
+                stack.push(edge.target());
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::*;
+    use crate::{ContextHost, ModuleRecord, options::LintOptions};
+
+    fn with_ctx(source: &str, f: impl FnOnce(&LintContext)) {
+        let allocator = Allocator::default();
+        let parser_ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        assert!(parser_ret.errors.is_empty());
+        let semantic = SemanticBuilder::new().with_cfg(true).build(&parser_ret.program).semantic;
+        let ctx = Rc::new(ContextHost::new(
+            "test.js",
+            Rc::new(semantic),
+            Arc::new(ModuleRecord::default()),
+            LintOptions::default(),
+            Arc::default(),
+        ))
+        .spawn_for_test();
+        f(&ctx);
+    }
+
+    /// The first argument of the call to `callee`, e.g. `x` in `use(x)`.
+    fn argument<'a>(callee: &str, ctx: &LintContext<'a>) -> &'a Expression<'a> {
+        ctx.nodes()
+            .iter()
+            .find_map(|node| match node.kind() {
+                AstKind::CallExpression(call) if call.callee.is_specific_id(callee) => {
+                    call.arguments[0].as_expression()
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    /// The symbol and the node of the identifier passed to `callee`, e.g. `x` in `use(x)`.
+    fn argument_reference(callee: &str, ctx: &LintContext) -> (SymbolId, NodeId) {
+        let Expression::Identifier(ident) = argument(callee, ctx) else {
+            unreachable!();
+        };
+        let reference = ctx.scoping().get_reference(ident.reference_id());
+        (reference.symbol_id().unwrap(), reference.node_id())
+    }
+
+    #[test]
+    fn test_reaching_definitions() {
+        let cases = [
+            ("let x = 1; x = 2; use(x);", vec!["x = 2"]),
+            ("let x = 1; if (c) x = 2; use(x);", vec!["x = 1", "x = 2"]),
+            ("let x = 1; if (c) x = 2; else x = 3; use(x);", vec!["x = 2", "x = 3"]),
+            ("let x = 0; while (c) { use(x); x = x + 1; }", vec!["x = 0", "x = x + 1"]),
+            ("let x = 0; x = c ? use(x) : 1;", vec!["x = 0"]),
+            ("let x = 0; for (;;) { x++; use(x); }", vec!["x++"]),
+            ("let x; [x] = arr; use(x);", vec!["[x] = arr"]),
+            ("function f(x) { use(x); x = 1; }", vec!["x"]),
+            ("function f(x) { x = 1; use(x); }", vec!["x = 1"]),
+            ("function f(x) { if (c) x = 1; use(x); }", vec!["x", "x = 1"]),
+            ("let x = 0; g(() => { x = 1; }); use(x);", vec!["x = 0", "x = 1"]),
+            ("let x = 0; function g() { use(x); } x = 1;", vec!["x = 0", "x = 1"]),
+            ("for (const x of xs) use(x);", vec!["x"]),
+            ("use(f); function f() {}", vec!["function f() {}"]),
+        ];
+        for (source, expected) in cases {
+            with_ctx(source, |ctx| {
+                let (symbol_id, node_id) = argument_reference("use", ctx);
+                let definitions = reaching_definitions(symbol_id, node_id, ctx)
+                    .iter()
+                    .map(|def| def.span.source_text(source))
+                    .collect::<Vec<_>>();
+                assert_eq!(definitions, expected, "{source}");
+            });
+        }
+    }
+
+    #[test]
+    fn test_is_reassigned_between() {
+        let cases = [
+            ("let x = 0; from(x); x = 1; to(x);", true),
+            ("let x = 0; x = 1; from(x); to(x);", false),
+            ("let x = 0; from(x); if (c) { x = 1; } to(x);", true),
+            ("let x = 0; from(x); to(x); x = 1;", false),
+            ("let x = 0; while (c) { from(x); to(x); x++; }", true),
+            ("let x = 0; from(x); g(() => { x = 1; }); to(x);", true),
+        ];
+        for (source, expected) in cases {
+            with_ctx(source, |ctx| {
+                let (symbol_id, from) = argument_reference("from", ctx);
+                let (_, to) = argument_reference("to", ctx);
+                assert_eq!(is_reassigned_between(symbol_id, from, to, ctx), expected, "{source}");
+            });
+        }
+    }
+
+    #[test]
+    fn test_flows_from_parameter() {
+        let cases = [
+            ("function f(p) { use(p.id); }", true),
+            ("function f(p) { const { id } = p; use('/' + id); }", true),
+            ("function f(p) { let x = 'a'; x = p.trim(); use(x); }", true),
+            ("function f(p) { let x = p; x = 'a'; use(x); }", false),
+            ("function f(p) { let x = ''; for (const c of p) x += c; use(x); }", true),
+            ("function f(p) { let x = 0; while (x < p) x++; use(x); }", false),
+            ("function f(p) { let x = 'a'; use(x); }", false),
+            ("let x = 1; use(x);", false),
+        ];
+        for (source, expected) in cases {
+            with_ctx(source, |ctx| {
+                assert_eq!(flows_from_parameter(argument("use", ctx), ctx), expected, "{source}");
+            });
+        }
+    }
+}
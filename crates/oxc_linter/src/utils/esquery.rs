@@ -0,0 +1,234 @@
+//! [esquery](https://github.com/estools/esquery) compatible selectors, as used by ESLint's
+//! `no-restricted-syntax` and friends.
+//!
+//! Selectors are matched against [`AstNodes`], using the ESTree names of node types and fields,
+//! so that selectors are written the same as in ESLint
+//! (e.g. `CallExpression[callee.object.name='moment']`).
+
+use std::fmt;
+
+use lazy_regex::Regex;
+use oxc_semantic::{AstNode, AstNodes};
+
+mod estree;
+mod matcher;
+mod parse;
+
+/// A parsed esquery selector.
+#[derive(Debug, Clone)]
+pub enum Selector {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`. Node types are compared case-insensitively.
+    Type(String),
+    /// `[callee.name]`, `[callee.name='foo']`, `[value=/^foo/i]`
+    Attribute(Attribute),
+    /// `.callee`, `.body.body`. Matches nodes found at this field path of their parent.
+    Field(Vec<String>),
+    /// Selectors which must all match the same node, e.g. `CallExpression[optional=true]`.
+    Compound(Vec<Selector>),
+    /// `A, B`, `:matches(A, B)` and `:is(A, B)`
+    Matches(Vec<Selector>),
+    /// `:not(A, B)`
+    Not(Vec<Selector>),
+    /// `:has(A)`, `:has(> A)`
+    Has(Vec<Selector>),
+    /// `:nth-child(n)` and `:first-child`, 1-based.
+    NthChild(usize),
+    /// `:nth-last-child(n)` and `:last-child`, 1-based.
+    NthLastChild(usize),
+    /// `:statement`, `:expression`, `:declaration`, `:function` and `:pattern`
+    Class(NodeClass),
+    /// `A B`, `A > B`, `A ~ B` and `A + B`
+    Relation(Box<Selector>, Combinator, Box<Selector>),
+    /// The node a `:has` selector is applied to, only found in relative selectors such as `:has(> A)`.
+    Scope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+    /// `A ~ B`
+    Sibling,
+    /// `A + B`
+    Adjacent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    /// ESTree field path, e.g. `["callee", "object", "name"]`.
+    pub path: Vec<String>,
+    /// `None` tests for presence of the attribute only.
+    pub test: Option<(AttributeOperator, AttributeValue)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    /// `=`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+}
+
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    /// `'foo'`, `foo` or `1`. Compared by their string representation, like esquery.
+    Literal { text: String, number: Option<f64> },
+    /// `type(string)`
+    Type(String),
+    /// `/^foo/i`
+    Regex(Regex),
+}
+
+impl AttributeValue {
+    /// A string literal value, e.g. the `'foo'` in `[name='foo']`.
+    pub fn string(text: impl Into<String>) -> Self {
+        Self::Literal { text: text.into(), number: None }
+    }
+}
+
+impl Selector {
+    /// Parse a selector such as `CallExpression > MemberExpression.callee[object.name='moment']`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not a valid selector.
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        parse::Parser::new(source).parse()
+    }
+
+    /// Whether this selector matches `node`.
+    ///
+    /// Nodes without an ESTree equivalent, such as `FormalParameters`, are never matched.
+    pub fn matches(&self, node: &AstNode<'_>, nodes: &AstNodes<'_>) -> bool {
+        estree::node_type(node, nodes).is_some()
+            && matcher::Matcher::new(nodes).matches(self, node.id())
+    }
+}
+
+/// Error from parsing an invalid selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub message: String,
+    /// Byte offset in the selector where parsing failed.
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::Selector;
+
+    fn query(source_text: &str, selector: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let selector = Selector::parse(selector).unwrap();
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| selector.matches(node, semantic.nodes()))
+            .map(|node| node.span().source_text(source_text).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_errors() {
+        for selector in [
+            "",
+            "[",
+            "[name",
+            "[name=]",
+            ":not(",
+            ":nth-child(a)",
+            ":unknown",
+            "A >",
+            "A,",
+            "[a</b/]",
+        ] {
+            assert!(Selector::parse(selector).is_err(), "{selector}");
+        }
+    }
+
+    #[test]
+    fn types_and_attributes() {
+        let source = "moment(); foo.bar(); moment.utc(); a = 1; b = 'x';";
+        assert_eq!(query(source, "CallExpression"), ["moment()", "foo.bar()", "moment.utc()"]);
+        assert_eq!(query(source, "callexpression[callee.name='moment']"), ["moment()"]);
+        assert_eq!(query(source, "CallExpression[callee.object.name=moment]"), ["moment.utc()"]);
+        assert_eq!(query(source, "CallExpression[callee.object]"), ["foo.bar()", "moment.utc()"]);
+        assert_eq!(query(source, "Identifier[name=/^mom/]"), ["moment", "moment"]);
+        assert_eq!(query(source, "Identifier[name!=/^mom/]"), ["foo", "bar", "utc", "a", "b"]);
+        assert_eq!(query(source, "Literal[value=1]"), ["1"]);
+        assert_eq!(query(source, "Literal[value>0]"), ["1"]);
+        assert_eq!(query(source, "Literal[value=type(string)]"), ["'x'"]);
+        assert_eq!(query(source, r#"Literal[value="x"]"#), ["'x'"]);
+    }
+
+    #[test]
+    fn combinators() {
+        let source = "function f(a) { return a; } const g = () => { b(); c(); d(); };";
+        assert_eq!(query(source, "FunctionDeclaration Identifier"), ["f", "a", "a"]);
+        assert_eq!(query(source, "FunctionDeclaration > Identifier"), ["f", "a"]);
+        assert_eq!(query(source, "FunctionDeclaration > Identifier.id"), ["f"]);
+        assert_eq!(query(source, "FunctionDeclaration > .params"), ["a"]);
+        assert_eq!(query(source, "ExpressionStatement ~ ExpressionStatement"), ["c();", "d();"]);
+        assert_eq!(query(source, "ExpressionStatement + ExpressionStatement > *"), ["c()", "d()"]);
+        assert_eq!(query(source, "BlockStatement > :first-child"), ["return a;", "b();"]);
+        assert_eq!(query(source, "BlockStatement > :nth-last-child(2)"), ["c();"]);
+        assert_eq!(
+            query(source, "ArrowFunctionExpression, FunctionDeclaration > BlockStatement").len(),
+            2
+        );
+    }
+
+    #[test]
+    fn pseudo_classes() {
+        let source = "function f() { return 1; } const g = () => x; if (a) b;";
+        assert_eq!(query(source, ":function").len(), 2);
+        assert_eq!(query(source, ":function:has(ReturnStatement)"), ["function f() { return 1; }"]);
+        assert_eq!(
+            query(source, "VariableDeclarator:has(> ArrowFunctionExpression)"),
+            ["g = () => x"]
+        );
+        assert_eq!(
+            query(source, ":statement:not(:declaration, IfStatement)"),
+            ["{ return 1; }", "return 1;", "b;"]
+        );
+        assert_eq!(query(source, ":matches(ReturnStatement, IfStatement)").len(), 2);
+        assert_eq!(query(source, ":is(ReturnStatement) > :expression"), ["1"]);
+        assert_eq!(query(source, "IfStatement > :pattern"), ["a"]);
+    }
+}
@@ -0,0 +1,710 @@
+//! ESTree view of [`AstKind`]s: the node types and fields selectors refer to.
+//!
+//! Only the fields of JavaScript nodes are mapped. Other nodes can be matched by their type and
+//! position in the tree.
+
+use std::borrow::Cow;
+
+use oxc_allocator::{Address, GetAddress};
+use oxc_ast::{AstKind, ast::*};
+use oxc_semantic::{AstNode, AstNodes};
+
+/// Value of an ESTree field.
+#[derive(Debug, Clone)]
+pub enum Value<'a> {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Cow<'a, str>),
+    /// A child node.
+    Node(Address),
+    /// An array of child nodes, with `None` for holes, e.g. in `[a, , b]`.
+    Nodes(Vec<Option<Address>>),
+    /// A plain object, e.g. the `regex` of a regular expression literal.
+    Object(Vec<(&'static str, Value<'a>)>),
+}
+
+/// ESTree type of `node`, or `None` if it has no ESTree equivalent, e.g. a `FormalParameters`
+/// node or a `ParenthesizedExpression`.
+pub fn node_type(node: &AstNode<'_>, nodes: &AstNodes<'_>) -> Option<&'static str> {
+    let name = match node.kind() {
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::StaticMemberExpression(_)
+        | AstKind::ComputedMemberExpression(_)
+        | AstKind::PrivateFieldExpression(_) => "MemberExpression",
+        AstKind::ObjectProperty(_)
+        | AstKind::BindingProperty(_)
+        | AstKind::AssignmentTargetPropertyIdentifier(_)
+        | AstKind::AssignmentTargetPropertyProperty(_) => "Property",
+        AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::BindingRestElement(_) | AstKind::AssignmentTargetRest(_) => "RestElement",
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        // The body of `() => x` is the expression.
+        AstKind::FunctionBody(_) if is_expression_body(node.id(), nodes) => return None,
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::ExpressionStatement(_)
+            if is_expression_body(nodes.parent_id(node.id()), nodes) =>
+        {
+            return None;
+        }
+        AstKind::Argument(_)
+        | AstKind::ParenthesizedExpression(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::FormalParameter(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::Elision(_)
+        | AstKind::Hashbang(_)
+        | AstKind::WithClause(_) => return None,
+        kind => return Some(same_name(kind)),
+    };
+    Some(name)
+}
+
+/// Whether `node_id` is the `FunctionBody` of an arrow function with an expression body.
+fn is_expression_body(node_id: oxc_semantic::NodeId, nodes: &AstNodes<'_>) -> bool {
+    matches!(nodes.kind(node_id), AstKind::FunctionBody(_))
+        && matches!(nodes.parent_kind(node_id), AstKind::ArrowFunctionExpression(arrow) if arrow.expression)
+}
+
+/// ESTree type of node kinds which have the same name in ESTree.
+fn same_name(kind: AstKind<'_>) -> &'static str {
+    macro_rules! names {
+        ($($name:ident),* $(,)?) => {
+            match kind {
+                $(AstKind::$name(_) => stringify!($name),)*
+                _ => "",
+            }
+        };
+    }
+    names!(
+        Program,
+        ThisExpression,
+        ArrayExpression,
+        ObjectExpression,
+        TemplateLiteral,
+        TaggedTemplateExpression,
+        TemplateElement,
+        CallExpression,
+        NewExpression,
+        MetaProperty,
+        SpreadElement,
+        UpdateExpression,
+        UnaryExpression,
+        BinaryExpression,
+        PrivateInExpression,
+        LogicalExpression,
+        ConditionalExpression,
+        AssignmentExpression,
+        SequenceExpression,
+        Super,
+        AwaitExpression,
+        ChainExpression,
+        BlockStatement,
+        VariableDeclaration,
+        VariableDeclarator,
+        EmptyStatement,
+        ExpressionStatement,
+        IfStatement,
+        DoWhileStatement,
+        WhileStatement,
+        ForStatement,
+        ForInStatement,
+        ForOfStatement,
+        ContinueStatement,
+        BreakStatement,
+        ReturnStatement,
+        WithStatement,
+        SwitchStatement,
+        SwitchCase,
+        LabeledStatement,
+        ThrowStatement,
+        TryStatement,
+        CatchClause,
+        DebuggerStatement,
+        AssignmentPattern,
+        ObjectPattern,
+        ArrayPattern,
+        ArrowFunctionExpression,
+        YieldExpression,
+        ClassBody,
+        MethodDefinition,
+        PropertyDefinition,
+        PrivateIdentifier,
+        StaticBlock,
+        AccessorProperty,
+        ImportExpression,
+        ImportDeclaration,
+        ImportSpecifier,
+        ImportDefaultSpecifier,
+        ImportNamespaceSpecifier,
+        ImportAttribute,
+        ExportNamedDeclaration,
+        ExportDefaultDeclaration,
+        ExportAllDeclaration,
+        ExportSpecifier,
+        JSXElement,
+        JSXOpeningElement,
+        JSXClosingElement,
+        JSXFragment,
+        JSXOpeningFragment,
+        JSXClosingFragment,
+        JSXNamespacedName,
+        JSXMemberExpression,
+        JSXExpressionContainer,
+        JSXEmptyExpression,
+        JSXAttribute,
+        JSXSpreadAttribute,
+        JSXIdentifier,
+        JSXSpreadChild,
+        JSXText,
+        TSEnumDeclaration,
+        TSEnumBody,
+        TSEnumMember,
+        TSTypeAnnotation,
+        TSLiteralType,
+        TSConditionalType,
+        TSUnionType,
+        TSIntersectionType,
+        TSTypeOperator,
+        TSArrayType,
+        TSIndexedAccessType,
+        TSTupleType,
+        TSNamedTupleMember,
+        TSOptionalType,
+        TSRestType,
+        TSAnyKeyword,
+        TSStringKeyword,
+        TSBooleanKeyword,
+        TSNumberKeyword,
+        TSNeverKeyword,
+        TSIntrinsicKeyword,
+        TSUnknownKeyword,
+        TSNullKeyword,
+        TSUndefinedKeyword,
+        TSVoidKeyword,
+        TSSymbolKeyword,
+        TSThisType,
+        TSObjectKeyword,
+        TSBigIntKeyword,
+        TSTypeReference,
+        TSQualifiedName,
+        TSTypeParameterInstantiation,
+        TSTypeParameter,
+        TSTypeParameterDeclaration,
+        TSTypeAliasDeclaration,
+        TSClassImplements,
+        TSInterfaceDeclaration,
+        TSInterfaceBody,
+        TSPropertySignature,
+        TSIndexSignature,
+        TSCallSignatureDeclaration,
+        TSMethodSignature,
+        TSConstructSignatureDeclaration,
+        TSInterfaceHeritage,
+        TSTypePredicate,
+        TSModuleDeclaration,
+        TSModuleBlock,
+        TSTypeLiteral,
+        TSInferType,
+        TSTypeQuery,
+        TSImportType,
+        TSFunctionType,
+        TSConstructorType,
+        TSMappedType,
+        TSTemplateLiteralType,
+        TSAsExpression,
+        TSSatisfiesExpression,
+        TSTypeAssertion,
+        TSImportEqualsDeclaration,
+        TSExternalModuleReference,
+    )
+}
+
+/// Fields of `kind` holding child nodes, in source order.
+pub fn child_fields(kind: AstKind<'_>) -> Vec<(&'static str, Value<'_>)> {
+    match kind {
+        AstKind::Program(program) => {
+            vec![("body", body(&program.directives, &program.body))]
+        }
+        AstKind::FunctionBody(body) => {
+            vec![("body", self::body(&body.directives, &body.statements))]
+        }
+        AstKind::Directive(directive) => vec![("expression", node(&directive.expression))],
+        AstKind::BlockStatement(block) => {
+            vec![("body", nodes(block.body.iter().map(GetAddress::address)))]
+        }
+        AstKind::StaticBlock(block) => {
+            vec![("body", nodes(block.body.iter().map(GetAddress::address)))]
+        }
+        AstKind::ExpressionStatement(stmt) => vec![("expression", expr(&stmt.expression))],
+        AstKind::IfStatement(stmt) => vec![
+            ("test", expr(&stmt.test)),
+            ("consequent", stmt.consequent.address().into()),
+            ("alternate", optional(stmt.alternate.as_ref().map(GetAddress::address))),
+        ],
+        AstKind::ForStatement(stmt) => vec![
+            (
+                "init",
+                optional(stmt.init.as_ref().map(|init| match init.as_expression() {
+                    Some(init) => init.without_parentheses().address(),
+                    None => init.address(),
+                })),
+            ),
+            ("test", optional(stmt.test.as_ref().map(expr_addr))),
+            ("update", optional(stmt.update.as_ref().map(expr_addr))),
+            ("body", stmt.body.address().into()),
+        ],
+        AstKind::ForInStatement(stmt) => vec![
+            ("left", stmt.left.address().into()),
+            ("right", expr(&stmt.right)),
+            ("body", stmt.body.address().into()),
+        ],
+        AstKind::ForOfStatement(stmt) => vec![
+            ("left", stmt.left.address().into()),
+            ("right", expr(&stmt.right)),
+            ("body", stmt.body.address().into()),
+        ],
+        AstKind::WhileStatement(stmt) => {
+            vec![("test", expr(&stmt.test)), ("body", stmt.body.address().into())]
+        }
+        AstKind::DoWhileStatement(stmt) => {
+            vec![("body", stmt.body.address().into()), ("test", expr(&stmt.test))]
+        }
+        AstKind::ReturnStatement(stmt) => {
+            vec![("argument", optional(stmt.argument.as_ref().map(expr_addr)))]
+        }
+        AstKind::ThrowStatement(stmt) => vec![("argument", expr(&stmt.argument))],
+        AstKind::BreakStatement(stmt) => {
+            vec![("label", optional(stmt.label.as_ref().map(addr)))]
+        }
+        AstKind::ContinueStatement(stmt) => {
+            vec![("label", optional(stmt.label.as_ref().map(addr)))]
+        }
+        AstKind::LabeledStatement(stmt) => {
+            vec![("label", node(&stmt.label)), ("body", stmt.body.address().into())]
+        }
+        AstKind::WithStatement(stmt) => {
+            vec![("object", expr(&stmt.object)), ("body", stmt.body.address().into())]
+        }
+        AstKind::SwitchStatement(stmt) => vec![
+            ("discriminant", expr(&stmt.discriminant)),
+            ("cases", nodes(stmt.cases.iter().map(addr))),
+        ],
+        AstKind::SwitchCase(case) => vec![
+            ("test", optional(case.test.as_ref().map(expr_addr))),
+            ("consequent", nodes(case.consequent.iter().map(GetAddress::address))),
+        ],
+        AstKind::TryStatement(stmt) => vec![
+            ("block", node(&*stmt.block)),
+            ("handler", optional(stmt.handler.as_deref().map(addr))),
+            ("finalizer", optional(stmt.finalizer.as_deref().map(addr))),
+        ],
+        AstKind::CatchClause(clause) => vec![
+            ("param", optional(clause.param.as_ref().map(|param| param.pattern.kind.address()))),
+            ("body", node(&*clause.body)),
+        ],
+        AstKind::VariableDeclaration(decl) => {
+            vec![("declarations", nodes(decl.declarations.iter().map(addr)))]
+        }
+        AstKind::VariableDeclarator(decl) => vec![
+            ("id", decl.id.kind.address().into()),
+            ("init", optional(decl.init.as_ref().map(expr_addr))),
+        ],
+        AstKind::Function(func) => vec![
+            ("id", optional(func.id.as_ref().map(addr))),
+            ("params", params(&func.params)),
+            ("body", optional(func.body.as_deref().map(addr))),
+        ],
+        AstKind::ArrowFunctionExpression(arrow) => vec![
+            ("params", params(&arrow.params)),
+            (
+                "body",
+                match arrow.get_expression() {
+                    Some(expression) => expr(expression),
+                    None => node(&*arrow.body),
+                },
+            ),
+        ],
+        AstKind::Class(class) => vec![
+            ("id", optional(class.id.as_ref().map(addr))),
+            ("superClass", optional(class.super_class.as_ref().map(expr_addr))),
+            ("body", node(&*class.body)),
+        ],
+        AstKind::ClassBody(body) => {
+            vec![("body", nodes(body.body.iter().map(GetAddress::address)))]
+        }
+        AstKind::MethodDefinition(method) => {
+            vec![("key", key(&method.key)), ("value", node(&*method.value))]
+        }
+        AstKind::PropertyDefinition(prop) => {
+            vec![("key", key(&prop.key)), ("value", optional(prop.value.as_ref().map(expr_addr)))]
+        }
+        AstKind::CallExpression(call) => vec![
+            ("callee", expr(&call.callee)),
+            ("arguments", nodes(call.arguments.iter().map(argument))),
+        ],
+        AstKind::NewExpression(new) => vec![
+            ("callee", expr(&new.callee)),
+            ("arguments", nodes(new.arguments.iter().map(argument))),
+        ],
+        AstKind::StaticMemberExpression(member) => {
+            vec![("object", expr(&member.object)), ("property", node(&member.property))]
+        }
+        AstKind::ComputedMemberExpression(member) => {
+            vec![("object", expr(&member.object)), ("property", expr(&member.expression))]
+        }
+        AstKind::PrivateFieldExpression(member) => {
+            vec![("object", expr(&member.object)), ("property", node(&member.field))]
+        }
+        AstKind::ChainExpression(chain) => vec![("expression", chain.expression.address().into())],
+        AstKind::TaggedTemplateExpression(tagged) => {
+            vec![("tag", expr(&tagged.tag)), ("quasi", node(&tagged.quasi))]
+        }
+        AstKind::TemplateLiteral(template) => vec![
+            ("quasis", nodes(template.quasis.iter().map(addr))),
+            ("expressions", nodes(template.expressions.iter().map(expr_addr))),
+        ],
+        AstKind::ArrayExpression(array) => vec![(
+            "elements",
+            Value::Nodes(
+                array
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        ArrayExpressionElement::SpreadElement(spread) => Some(addr(&**spread)),
+                        ArrayExpressionElement::Elision(_) => None,
+                        _ => element.as_expression().map(expr_addr),
+                    })
+                    .collect(),
+            ),
+        )],
+        AstKind::ObjectExpression(object) => {
+            vec![("properties", nodes(object.properties.iter().map(GetAddress::address)))]
+        }
+        AstKind::ObjectProperty(prop) => {
+            vec![("key", key(&prop.key)), ("value", expr(&prop.value))]
+        }
+        AstKind::SpreadElement(spread) => vec![("argument", expr(&spread.argument))],
+        AstKind::UnaryExpression(expression) => vec![("argument", expr(&expression.argument))],
+        AstKind::UpdateExpression(expression) => {
+            vec![("argument", expression.argument.address().into())]
+        }
+        AstKind::AwaitExpression(expression) => vec![("argument", expr(&expression.argument))],
+        AstKind::YieldExpression(expression) => {
+            vec![("argument", optional(expression.argument.as_ref().map(expr_addr)))]
+        }
+        AstKind::BinaryExpression(expression) => {
+            vec![("left", expr(&expression.left)), ("right", expr(&expression.right))]
+        }
+        AstKind::LogicalExpression(expression) => {
+            vec![("left", expr(&expression.left)), ("right", expr(&expression.right))]
+        }
+        AstKind::PrivateInExpression(expression) => {
+            vec![("left", node(&expression.left)), ("right", expr(&expression.right))]
+        }
+        AstKind::AssignmentExpression(expression) => {
+            vec![("left", expression.left.address().into()), ("right", expr(&expression.right))]
+        }
+        AstKind::ConditionalExpression(expression) => vec![
+            ("test", expr(&expression.test)),
+            ("consequent", expr(&expression.consequent)),
+            ("alternate", expr(&expression.alternate)),
+        ],
+        AstKind::SequenceExpression(expression) => {
+            vec![("expressions", nodes(expression.expressions.iter().map(expr_addr)))]
+        }
+        AstKind::ImportExpression(expression) => vec![("source", expr(&expression.source))],
+        AstKind::MetaProperty(meta) => {
+            vec![("meta", node(&meta.meta)), ("property", node(&meta.property))]
+        }
+        AstKind::ObjectPattern(pattern) => vec![(
+            "properties",
+            nodes(pattern.properties.iter().map(addr).chain(pattern.rest.as_deref().map(addr))),
+        )],
+        AstKind::ArrayPattern(pattern) => vec![(
+            "elements",
+            Value::Nodes(
+                pattern
+                    .elements
+                    .iter()
+                    .map(|element| element.as_ref().map(|element| element.kind.address()))
+                    .chain(pattern.rest.as_deref().map(|rest| Some(addr(rest))))
+                    .collect(),
+            ),
+        )],
+        AstKind::BindingProperty(prop) => {
+            vec![("key", key(&prop.key)), ("value", prop.value.kind.address().into())]
+        }
+        AstKind::AssignmentPattern(pattern) => {
+            vec![("left", pattern.left.kind.address().into()), ("right", expr(&pattern.right))]
+        }
+        AstKind::BindingRestElement(rest) => {
+            vec![("argument", rest.argument.kind.address().into())]
+        }
+        AstKind::ObjectAssignmentTarget(target) => vec![(
+            "properties",
+            nodes(
+                target
+                    .properties
+                    .iter()
+                    .map(GetAddress::address)
+                    .chain(target.rest.as_ref().map(addr)),
+            ),
+        )],
+        AstKind::ArrayAssignmentTarget(target) => vec![(
+            "elements",
+            Value::Nodes(
+                target
+                    .elements
+                    .iter()
+                    .map(|element| element.as_ref().map(GetAddress::address))
+                    .chain(target.rest.as_ref().map(|rest| Some(addr(rest))))
+                    .collect(),
+            ),
+        )],
+        // `{ a }` and `{ a = 1 }`, where the key and value are the same identifier.
+        AstKind::AssignmentTargetPropertyIdentifier(prop) => {
+            vec![("key", node(&prop.binding)), ("value", node(&prop.binding))]
+        }
+        AstKind::AssignmentTargetPropertyProperty(prop) => {
+            vec![("key", key(&prop.name)), ("value", prop.binding.address().into())]
+        }
+        AstKind::AssignmentTargetWithDefault(target) => {
+            vec![("left", target.binding.address().into()), ("right", expr(&target.init))]
+        }
+        AstKind::AssignmentTargetRest(rest) => vec![("argument", rest.target.address().into())],
+        AstKind::ImportDeclaration(decl) => vec![
+            ("specifiers", nodes(decl.specifiers.iter().flatten().map(GetAddress::address))),
+            ("source", node(&decl.source)),
+        ],
+        AstKind::ImportSpecifier(specifier) => vec![
+            ("imported", module_export_name(&specifier.imported)),
+            ("local", node(&specifier.local)),
+        ],
+        AstKind::ImportDefaultSpecifier(specifier) => vec![("local", node(&specifier.local))],
+        AstKind::ImportNamespaceSpecifier(specifier) => vec![("local", node(&specifier.local))],
+        AstKind::ExportNamedDeclaration(decl) => vec![
+            ("declaration", optional(decl.declaration.as_ref().map(GetAddress::address))),
+            ("specifiers", nodes(decl.specifiers.iter().map(addr))),
+            ("source", optional(decl.source.as_ref().map(addr))),
+        ],
+        AstKind::ExportDefaultDeclaration(decl) => vec![(
+            "declaration",
+            match decl.declaration.as_expression() {
+                Some(expression) => expr(expression),
+                None => decl.declaration.address().into(),
+            },
+        )],
+        AstKind::ExportAllDeclaration(decl) => vec![
+            ("exported", decl.exported.as_ref().map_or(Value::Null, module_export_name)),
+            ("source", node(&decl.source)),
+        ],
+        AstKind::ExportSpecifier(specifier) => vec![
+            ("local", module_export_name(&specifier.local)),
+            ("exported", module_export_name(&specifier.exported)),
+        ],
+        _ => vec![],
+    }
+}
+
+/// Value of the field `name` of `kind`. Fields holding child nodes are found by [`child_fields`].
+#[expect(clippy::match_same_arms)]
+pub fn field<'a>(kind: AstKind<'a>, name: &str) -> Option<Value<'a>> {
+    let value = match (kind, name) {
+        (AstKind::IdentifierName(ident), "name") => string(ident.name.as_str()),
+        (AstKind::IdentifierReference(ident), "name") => string(ident.name.as_str()),
+        (AstKind::BindingIdentifier(ident), "name") => string(ident.name.as_str()),
+        (AstKind::LabelIdentifier(ident), "name") => string(ident.name.as_str()),
+        (AstKind::PrivateIdentifier(ident), "name") => string(ident.name.as_str()),
+        (AstKind::JSXIdentifier(ident), "name") => string(ident.name.as_str()),
+        (AstKind::StringLiteral(lit), "value") => string(lit.value.as_str()),
+        (AstKind::StringLiteral(lit), "raw") => string(lit.raw?.as_str()),
+        (AstKind::NumericLiteral(lit), "value") => Value::Number(lit.value),
+        (AstKind::NumericLiteral(lit), "raw") => string(lit.raw?.as_str()),
+        (AstKind::BooleanLiteral(lit), "value") => Value::Bool(lit.value),
+        (AstKind::BooleanLiteral(lit), "raw") => string(if lit.value { "true" } else { "false" }),
+        (AstKind::NullLiteral(_), "value") => Value::Null,
+        (AstKind::NullLiteral(_), "raw") => string("null"),
+        (AstKind::BigIntLiteral(lit), "bigint") => string(lit.value.as_str()),
+        (AstKind::BigIntLiteral(lit), "raw") => string(lit.raw?.as_str()),
+        // The value of a regular expression literal is a `RegExp`, which has no JSON value.
+        (AstKind::BigIntLiteral(_) | AstKind::RegExpLiteral(_), "value") => Value::Null,
+        (AstKind::RegExpLiteral(lit), "raw") => string(lit.raw?.as_str()),
+        (AstKind::RegExpLiteral(lit), "regex") => Value::Object(vec![
+            ("pattern", string(lit.regex.pattern.text.as_str())),
+            ("flags", Value::String(Cow::Owned(lit.regex.flags.to_string()))),
+        ]),
+        (AstKind::TemplateElement(element), "value") => Value::Object(vec![
+            ("raw", string(element.value.raw.as_str())),
+            ("cooked", element.value.cooked.map_or(Value::Null, |cooked| string(cooked.as_str()))),
+        ]),
+        (AstKind::TemplateElement(element), "tail") => Value::Bool(element.tail),
+        (AstKind::Directive(directive), "directive") => string(directive.directive.as_str()),
+        (AstKind::Program(program), "sourceType") => {
+            string(if program.source_type.is_module() { "module" } else { "script" })
+        }
+        (AstKind::VariableDeclaration(decl), "kind") => string(decl.kind.as_str()),
+        (AstKind::UnaryExpression(expression), "operator") => string(expression.operator.as_str()),
+        (AstKind::UnaryExpression(_), "prefix") => Value::Bool(true),
+        (AstKind::UpdateExpression(expression), "operator") => string(expression.operator.as_str()),
+        (AstKind::UpdateExpression(expression), "prefix") => Value::Bool(expression.prefix),
+        (AstKind::BinaryExpression(expression), "operator") => string(expression.operator.as_str()),
+        (AstKind::PrivateInExpression(_), "operator") => string("in"),
+        (AstKind::LogicalExpression(expression), "operator") => {
+            string(expression.operator.as_str())
+        }
+        (AstKind::AssignmentExpression(expression), "operator") => {
+            string(expression.operator.as_str())
+        }
+        (AstKind::CallExpression(call), "optional") => Value::Bool(call.optional),
+        (AstKind::NewExpression(_), "optional") => Value::Bool(false),
+        (AstKind::StaticMemberExpression(member), "optional") => Value::Bool(member.optional),
+        (AstKind::ComputedMemberExpression(member), "optional") => Value::Bool(member.optional),
+        (AstKind::PrivateFieldExpression(member), "optional") => Value::Bool(member.optional),
+        (AstKind::ComputedMemberExpression(_), "computed") => Value::Bool(true),
+        (AstKind::StaticMemberExpression(_) | AstKind::PrivateFieldExpression(_), "computed") => {
+            Value::Bool(false)
+        }
+        (AstKind::Function(func), "async") => Value::Bool(func.r#async),
+        (AstKind::Function(func), "generator") => Value::Bool(func.generator),
+        (AstKind::Function(_), "expression") => Value::Bool(false),
+        (AstKind::ArrowFunctionExpression(arrow), "async") => Value::Bool(arrow.r#async),
+        (AstKind::ArrowFunctionExpression(_), "generator") => Value::Bool(false),
+        (AstKind::ArrowFunctionExpression(arrow), "expression") => Value::Bool(arrow.expression),
+        (AstKind::YieldExpression(expression), "delegate") => Value::Bool(expression.delegate),
+        (AstKind::ForOfStatement(stmt), "await") => Value::Bool(stmt.r#await),
+        (AstKind::ObjectProperty(prop), "kind") => string(match prop.kind {
+            PropertyKind::Init => "init",
+            PropertyKind::Get => "get",
+            PropertyKind::Set => "set",
+        }),
+        (AstKind::ObjectProperty(prop), "computed") => Value::Bool(prop.computed),
+        (AstKind::ObjectProperty(prop), "shorthand") => Value::Bool(prop.shorthand),
+        (AstKind::ObjectProperty(prop), "method") => Value::Bool(prop.method),
+        (AstKind::BindingProperty(prop), "computed") => Value::Bool(prop.computed),
+        (AstKind::BindingProperty(prop), "shorthand") => Value::Bool(prop.shorthand),
+        (AstKind::AssignmentTargetPropertyProperty(prop), "computed") => Value::Bool(prop.computed),
+        (AstKind::AssignmentTargetPropertyIdentifier(_), "computed") => Value::Bool(false),
+        (AstKind::AssignmentTargetPropertyIdentifier(_), "shorthand") => Value::Bool(true),
+        (AstKind::AssignmentTargetPropertyProperty(_), "shorthand") => Value::Bool(false),
+        (
+            AstKind::BindingProperty(_)
+            | AstKind::AssignmentTargetPropertyIdentifier(_)
+            | AstKind::AssignmentTargetPropertyProperty(_),
+            "kind",
+        ) => string("init"),
+        (
+            AstKind::BindingProperty(_)
+            | AstKind::AssignmentTargetPropertyIdentifier(_)
+            | AstKind::AssignmentTargetPropertyProperty(_),
+            "method",
+        ) => Value::Bool(false),
+        (AstKind::MethodDefinition(method), "kind") => string(match method.kind {
+            MethodDefinitionKind::Constructor => "constructor",
+            MethodDefinitionKind::Method => "method",
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+        }),
+        (AstKind::MethodDefinition(method), "computed") => Value::Bool(method.computed),
+        (AstKind::MethodDefinition(method), "static") => Value::Bool(method.r#static),
+        (AstKind::PropertyDefinition(prop), "computed") => Value::Bool(prop.computed),
+        (AstKind::PropertyDefinition(prop), "static") => Value::Bool(prop.r#static),
+        _ => {
+            return child_fields(kind)
+                .into_iter()
+                .find_map(|(field, value)| (field == name).then_some(value));
+        }
+    };
+    Some(value)
+}
+
+fn string(s: &str) -> Value<'_> {
+    Value::String(Cow::Borrowed(s))
+}
+
+fn addr<T>(node: &T) -> Address {
+    Address::from_ptr(node)
+}
+
+fn node<T>(node: &T) -> Value<'static> {
+    Value::Node(addr(node))
+}
+
+fn nodes(addresses: impl Iterator<Item = Address>) -> Value<'static> {
+    Value::Nodes(addresses.map(Some).collect())
+}
+
+fn optional(address: Option<Address>) -> Value<'static> {
+    address.map_or(Value::Null, Value::Node)
+}
+
+/// Parentheses are not represented in ESTree.
+fn expr_addr(expression: &Expression<'_>) -> Address {
+    expression.without_parentheses().address()
+}
+
+fn expr(expression: &Expression<'_>) -> Value<'static> {
+    Value::Node(expr_addr(expression))
+}
+
+fn argument(argument: &Argument<'_>) -> Address {
+    argument.as_expression().map_or_else(|| argument.address(), expr_addr)
+}
+
+fn key(key: &PropertyKey<'_>) -> Value<'static> {
+    match key.as_expression() {
+        Some(expression) => expr(expression),
+        None => key.address().into(),
+    }
+}
+
+fn module_export_name(name: &ModuleExportName<'_>) -> Value<'static> {
+    match name {
+        ModuleExportName::IdentifierName(ident) => node(ident),
+        ModuleExportName::IdentifierReference(ident) => node(ident),
+        ModuleExportName::StringLiteral(lit) => node(lit),
+    }
+}
+
+/// Directives are `ExpressionStatement`s at the start of the body in ESTree.
+fn body(directives: &[Directive<'_>], statements: &[Statement<'_>]) -> Value<'static> {
+    nodes(directives.iter().map(addr).chain(statements.iter().map(GetAddress::address)))
+}
+
+/// Parameters of a function, excluding the TypeScript `this` parameter.
+fn params(params: &FormalParameters<'_>) -> Value<'static> {
+    nodes(
+        params
+            .items
+            .iter()
+            .map(|param| param.pattern.kind.address())
+            .chain(params.rest.as_deref().map(addr)),
+    )
+}
+
+impl From<Address> for Value<'_> {
+    fn from(address: Address) -> Self {
+        Self::Node(address)
+    }
+}
@@ -0,0 +1,292 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use oxc_allocator::{Address, GetAddress};
+use oxc_index::Idx;
+use oxc_semantic::{AstNodes, NodeId};
+use oxc_syntax::number::ToJsString;
+
+use super::{
+    Attribute, AttributeOperator, AttributeValue, Combinator, NodeClass, Selector,
+    estree::{self, Value},
+};
+
+/// Matches selectors against nodes, navigating the tree as if it were an ESTree AST.
+pub struct Matcher<'n, 'a> {
+    nodes: &'n AstNodes<'a>,
+    /// The node a `:has` selector is applied to. Relative selectors do not see its ancestors.
+    scope: Option<NodeId>,
+}
+
+/// Where a node is found in its parent.
+struct Position {
+    field: &'static str,
+    /// Index in the parent's field, if the field is an array.
+    index: Option<usize>,
+    /// All nodes in the parent's field, if the field is an array.
+    siblings: Vec<Option<Address>>,
+}
+
+impl<'n, 'a> Matcher<'n, 'a> {
+    pub fn new(nodes: &'n AstNodes<'a>) -> Self {
+        Self { nodes, scope: None }
+    }
+
+    /// Whether `selector` matches the node `id`.
+    pub fn matches(&self, selector: &Selector, id: NodeId) -> bool {
+        match selector {
+            Selector::Wildcard => true,
+            Selector::Type(name) => {
+                self.node_type(id).is_some_and(|ty| ty.eq_ignore_ascii_case(name))
+            }
+            Selector::Attribute(attribute) => {
+                attribute.matches(self.attribute_value(id, &attribute.path).as_ref())
+            }
+            Selector::Field(fields) => self.matches_fields(id, fields),
+            Selector::Compound(selectors) => {
+                selectors.iter().all(|selector| self.matches(selector, id))
+            }
+            Selector::Matches(selectors) => {
+                selectors.iter().any(|selector| self.matches(selector, id))
+            }
+            Selector::Not(selectors) => {
+                !selectors.iter().any(|selector| self.matches(selector, id))
+            }
+            Selector::Has(selectors) => {
+                let matcher = Matcher { nodes: self.nodes, scope: Some(id) };
+                self.subtree(id)
+                    .filter(|&child| self.node_type(child).is_some())
+                    .any(|child| selectors.iter().any(|selector| matcher.matches(selector, child)))
+            }
+            Selector::NthChild(n) => self
+                .position(id)
+                .and_then(|position| position.index)
+                .is_some_and(|index| index + 1 == *n),
+            Selector::NthLastChild(n) => self.position(id).is_some_and(|position| {
+                position.index.is_some_and(|index| position.siblings.len() - index == *n)
+            }),
+            Selector::Class(class) => class.matches(self, id),
+            Selector::Relation(left, combinator, right) => {
+                self.matches(right, id) && self.matches_related(left, *combinator, id)
+            }
+            Selector::Scope => self.scope == Some(id),
+        }
+    }
+
+    /// Whether `left` matches a node related to the node `id` by `combinator`.
+    fn matches_related(&self, left: &Selector, combinator: Combinator, id: NodeId) -> bool {
+        match combinator {
+            Combinator::Child => self.parent(id).is_some_and(|parent| self.matches(left, parent)),
+            Combinator::Descendant => std::iter::successors(self.parent(id), |&id| self.parent(id))
+                .any(|ancestor| self.matches(left, ancestor)),
+            Combinator::Sibling | Combinator::Adjacent => {
+                let (Some(parent), Some(position)) = (self.parent(id), self.position(id)) else {
+                    return false;
+                };
+                let Some(index) = position.index else { return false };
+                let start =
+                    if combinator == Combinator::Sibling { 0 } else { index.saturating_sub(1) };
+                position.siblings[start..index].iter().flatten().any(|&address| {
+                    self.find_descendant(parent, address)
+                        .is_some_and(|sibling| self.matches(left, sibling))
+                })
+            }
+        }
+    }
+
+    /// `.a.b` matches a node in the field `b` of a node in the field `a`.
+    fn matches_fields(&self, id: NodeId, fields: &[String]) -> bool {
+        let mut id = id;
+        for field in fields.iter().rev() {
+            let Some(position) = self.position(id) else { return false };
+            let Some(parent) = self.parent(id) else { return false };
+            if position.field != field {
+                return false;
+            }
+            id = parent;
+        }
+        true
+    }
+
+    fn node_type(&self, id: NodeId) -> Option<&'static str> {
+        estree::node_type(self.nodes.get_node(id), self.nodes)
+    }
+
+    /// The closest ancestor of `id` which is an ESTree node.
+    fn parent(&self, id: NodeId) -> Option<NodeId> {
+        if self.scope == Some(id) {
+            return None;
+        }
+        self.nodes.ancestor_ids(id).find(|&ancestor| self.node_type(ancestor).is_some())
+    }
+
+    fn position(&self, id: NodeId) -> Option<Position> {
+        let parent = self.parent(id)?;
+        let address = self.nodes.kind(id).address();
+        estree::child_fields(self.nodes.kind(parent)).into_iter().find_map(|(field, value)| {
+            match value {
+                Value::Node(child) if child == address => {
+                    Some(Position { field, index: None, siblings: vec![] })
+                }
+                Value::Nodes(siblings) => {
+                    let index = siblings.iter().position(|child| *child == Some(address))?;
+                    Some(Position { field, index: Some(index), siblings })
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Nodes below `id`, which are numbered in depth-first order.
+    fn subtree(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        (id.index() + 1..self.nodes.len())
+            .map(NodeId::from_usize)
+            .take_while(move |&descendant| self.nodes.ancestor_ids(descendant).any(|a| a == id))
+    }
+
+    /// ESTree node in the subtree of `id` at `address`.
+    /// `Argument` wraps an expression at the same address, so nodes without an ESTree type are skipped.
+    fn find_descendant(&self, id: NodeId, address: Address) -> Option<NodeId> {
+        self.subtree(id).find(|&descendant| {
+            self.nodes.kind(descendant).address() == address && self.node_type(descendant).is_some()
+        })
+    }
+
+    /// Value at an ESTree field path of the node `id`, e.g. `callee.object.name`.
+    /// Like in JavaScript, arrays can be indexed and have a `length`.
+    fn attribute_value(&self, id: NodeId, path: &[String]) -> Option<Value<'a>> {
+        let mut node = id;
+        // `None` while the path points to `node`.
+        let mut value: Option<Value<'a>> = None;
+        for key in path {
+            let next = match value.take() {
+                None if key == "type" => Value::String(Cow::Borrowed(self.node_type(node)?)),
+                None => estree::field(self.nodes.kind(node), key)?,
+                Some(Value::Nodes(items)) if key == "length" =>
+                {
+                    #[expect(clippy::cast_precision_loss)]
+                    Value::Number(items.len() as f64)
+                }
+                Some(Value::Nodes(items)) => {
+                    items.get(key.parse::<usize>().ok()?)?.map_or(Value::Null, Value::Node)
+                }
+                Some(Value::Object(fields)) => {
+                    fields.into_iter().find_map(|(field, value)| (field == key).then_some(value))?
+                }
+                Some(_) => return None,
+            };
+            if let Value::Node(address) = next {
+                node = self.find_descendant(node, address)?;
+            } else {
+                value = Some(next);
+            }
+        }
+        Some(value.unwrap_or_else(|| Value::Node(self.nodes.kind(node).address())))
+    }
+}
+
+impl NodeClass {
+    fn matches(self, matcher: &Matcher<'_, '_>, id: NodeId) -> bool {
+        let Some(node_type) = matcher.node_type(id) else { return false };
+        let is_declaration = || node_type.ends_with("Declaration");
+        let is_expression = || {
+            node_type.ends_with("Expression")
+                || node_type.ends_with("Literal")
+                || node_type == "MetaProperty"
+                || (node_type == "Identifier"
+                    && matcher.parent(id).and_then(|parent| matcher.node_type(parent))
+                        != Some("MetaProperty"))
+        };
+        match self {
+            Self::Statement => node_type.ends_with("Statement") || is_declaration(),
+            Self::Declaration => is_declaration(),
+            Self::Pattern => node_type.ends_with("Pattern") || is_expression(),
+            Self::Expression => is_expression(),
+            Self::Function => matches!(
+                node_type,
+                "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+            ),
+        }
+    }
+}
+
+impl Attribute {
+    fn matches(&self, value: Option<&Value<'_>>) -> bool {
+        let Some((operator, expected)) = &self.test else {
+            // `null` is treated as missing, like esquery.
+            return value.is_some_and(|value| !matches!(value, Value::Null));
+        };
+        let is_equal = match expected {
+            AttributeValue::Regex(regex) => {
+                matches!(value, Some(Value::String(s)) if regex.is_match(s))
+            }
+            AttributeValue::Type(type_name) => js_typeof(value) == type_name,
+            AttributeValue::Literal { text, number } => {
+                let compare = || js_compare(value, text, *number);
+                match operator {
+                    AttributeOperator::Equal | AttributeOperator::NotEqual => {
+                        js_to_string(value) == text.as_str()
+                    }
+                    AttributeOperator::Less => return compare().is_some_and(Ordering::is_lt),
+                    AttributeOperator::LessEqual => return compare().is_some_and(Ordering::is_le),
+                    AttributeOperator::Greater => return compare().is_some_and(Ordering::is_gt),
+                    AttributeOperator::GreaterEqual => {
+                        return compare().is_some_and(Ordering::is_ge);
+                    }
+                }
+            }
+        };
+        if *operator == AttributeOperator::NotEqual { !is_equal } else { is_equal }
+    }
+}
+
+pub fn js_number_to_string(number: f64) -> String {
+    if number == 0.0 { "0".to_string() } else { number.to_js_string() }
+}
+
+/// `typeof value`
+fn js_typeof(value: Option<&Value>) -> &'static str {
+    match value {
+        None => "undefined",
+        Some(Value::Bool(_)) => "boolean",
+        Some(Value::Number(_)) => "number",
+        Some(Value::String(_)) => "string",
+        Some(Value::Null | Value::Node(_) | Value::Nodes(_) | Value::Object(_)) => "object",
+    }
+}
+
+/// `` `${value}` ``
+fn js_to_string<'v>(value: Option<&'v Value<'_>>) -> Cow<'v, str> {
+    match value {
+        None => Cow::Borrowed("undefined"),
+        Some(Value::Null) => Cow::Borrowed("null"),
+        Some(Value::Bool(b)) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        Some(Value::Number(n)) => Cow::Owned(js_number_to_string(*n)),
+        Some(Value::String(s)) => Cow::Borrowed(s),
+        // Arrays of nodes, with holes converted to empty strings.
+        Some(Value::Nodes(items)) => Cow::Owned(
+            items
+                .iter()
+                .map(|item| if item.is_some() { "[object Object]" } else { "" })
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        Some(Value::Node(_) | Value::Object(_)) => Cow::Borrowed("[object Object]"),
+    }
+}
+
+/// `value < expected` and friends, following JavaScript's loose comparison.
+fn js_compare(value: Option<&Value>, text: &str, number: Option<f64>) -> Option<Ordering> {
+    if let (Some(Value::String(s)), None) = (value, number) {
+        return Some(s.as_ref().cmp(text));
+    }
+    let expected = number.or_else(|| text.trim().parse().ok())?;
+    let actual = match value? {
+        Value::Null => 0.0,
+        Value::Bool(b) => f64::from(u8::from(*b)),
+        Value::Number(n) => *n,
+        Value::String(s) if s.trim().is_empty() => 0.0,
+        Value::String(s) => s.trim().parse().ok()?,
+        Value::Node(_) | Value::Nodes(_) | Value::Object(_) => return None,
+    };
+    actual.partial_cmp(&expected)
+}
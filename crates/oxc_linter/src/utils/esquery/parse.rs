@@ -0,0 +1,373 @@
+use cow_utils::CowUtils;
+use lazy_regex::RegexBuilder;
+
+use super::{
+    Attribute, AttributeOperator, AttributeValue, Combinator, NodeClass, Selector, SelectorError,
+    matcher::js_number_to_string,
+};
+
+/// Recursive descent parser for the [esquery grammar](https://github.com/estools/esquery/blob/master/grammar.pegjs).
+pub struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    pub fn new(source: &'s str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub fn parse(mut self) -> Result<Selector, SelectorError> {
+        self.skip_whitespace();
+        let mut selectors = self.parse_selector_list(false)?;
+        self.skip_whitespace();
+        if self.pos < self.source.len() {
+            return Err(self.error("Unexpected character"));
+        }
+        Ok(if selectors.len() == 1 {
+            selectors.pop().unwrap()
+        } else {
+            Selector::Matches(selectors)
+        })
+    }
+
+    /// `A, B > C`
+    fn parse_selector_list(&mut self, relative: bool) -> Result<Vec<Selector>, SelectorError> {
+        let mut selectors = vec![self.parse_selector(relative)?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            self.skip_whitespace();
+            selectors.push(self.parse_selector(relative)?);
+        }
+        Ok(selectors)
+    }
+
+    /// `A > B C`. Relative selectors (in `:has`) may start with a combinator, e.g. `> A`.
+    fn parse_selector(&mut self, relative: bool) -> Result<Selector, SelectorError> {
+        let mut left = if relative {
+            let combinator = self.parse_combinator().unwrap_or(Combinator::Descendant);
+            self.skip_whitespace();
+            let right = self.parse_sequence()?;
+            Selector::Relation(Box::new(Selector::Scope), combinator, Box::new(right))
+        } else {
+            self.parse_sequence()?
+        };
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            let combinator = match self.parse_combinator() {
+                Some(combinator) => {
+                    self.skip_whitespace();
+                    combinator
+                }
+                None if has_whitespace && self.peek().is_some_and(starts_atom) => {
+                    Combinator::Descendant
+                }
+                None => break,
+            };
+            let right = self.parse_sequence()?;
+            left = Selector::Relation(Box::new(left), combinator, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_combinator(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            '>' => Combinator::Child,
+            '~' => Combinator::Sibling,
+            '+' => Combinator::Adjacent,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(combinator)
+    }
+
+    /// `CallExpression[optional=true]:first-child`
+    fn parse_sequence(&mut self) -> Result<Selector, SelectorError> {
+        // The subject indicator does not affect which nodes match.
+        self.eat('!');
+        let mut atoms = vec![];
+        while let Some(atom) = self.parse_atom()? {
+            atoms.push(atom);
+        }
+        match atoms.len() {
+            0 => Err(self.error("Expected a selector")),
+            1 => Ok(atoms.pop().unwrap()),
+            _ => Ok(Selector::Compound(atoms)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Option<Selector>, SelectorError> {
+        let Some(c) = self.peek() else { return Ok(None) };
+        let atom = match c {
+            '*' => {
+                self.pos += 1;
+                Selector::Wildcard
+            }
+            '#' => {
+                self.pos += 1;
+                Selector::Type(self.parse_identifier()?.to_string())
+            }
+            '[' => {
+                self.pos += 1;
+                Selector::Attribute(self.parse_attribute()?)
+            }
+            '.' => {
+                self.pos += 1;
+                Selector::Field(self.parse_path()?)
+            }
+            ':' => {
+                self.pos += 1;
+                self.parse_pseudo_class()?
+            }
+            c if is_identifier_char(c) => Selector::Type(self.parse_identifier()?.to_string()),
+            _ => return Ok(None),
+        };
+        Ok(Some(atom))
+    }
+
+    /// `[name]`, `[name="value"]`, `[name=/regex/i]`, `[name=type(string)]`
+    fn parse_attribute(&mut self) -> Result<Attribute, SelectorError> {
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(Attribute { path, test: None });
+        }
+        let operator = self.parse_attribute_operator()?;
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some('\'' | '"') => AttributeValue::string(self.parse_string()?),
+            Some('/') => AttributeValue::Regex(self.parse_regex()?),
+            Some(c) if c.is_ascii_digit() || c == '.' => self.parse_number()?,
+            _ if self.rest().starts_with("type(") => {
+                self.pos += "type(".len();
+                let end = self.rest().find(')').ok_or_else(|| self.error("Expected `)`"))?;
+                let type_name = self.rest()[..end].trim().to_string();
+                self.pos += end + 1;
+                AttributeValue::Type(type_name)
+            }
+            _ => AttributeValue::string(self.parse_path()?.join(".")),
+        };
+        if matches!(value, AttributeValue::Regex(_) | AttributeValue::Type(_))
+            && !matches!(operator, AttributeOperator::Equal | AttributeOperator::NotEqual)
+        {
+            return Err(self.error("Only `=` and `!=` can be used with regex and type values"));
+        }
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Attribute { path, test: Some((operator, value)) })
+    }
+
+    fn parse_attribute_operator(&mut self) -> Result<AttributeOperator, SelectorError> {
+        let operators = [
+            ("!=", AttributeOperator::NotEqual),
+            ("<=", AttributeOperator::LessEqual),
+            (">=", AttributeOperator::GreaterEqual),
+            ("=", AttributeOperator::Equal),
+            ("<", AttributeOperator::Less),
+            (">", AttributeOperator::Greater),
+        ];
+        for (text, operator) in operators {
+            if self.rest().starts_with(text) {
+                self.pos += text.len();
+                return Ok(operator);
+            }
+        }
+        Err(self.error("Expected an attribute operator"))
+    }
+
+    fn parse_string(&mut self) -> Result<String, SelectorError> {
+        let quote = self.next().unwrap();
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('\\') => {
+                    let escaped = self.next().ok_or_else(|| self.error("Unterminated string"))?;
+                    value.push(escaped);
+                }
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    fn parse_regex(&mut self) -> Result<lazy_regex::Regex, SelectorError> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut pattern = String::new();
+        loop {
+            match self.next() {
+                Some('\\') => {
+                    pattern.push('\\');
+                    pattern.push(self.next().ok_or_else(|| self.error("Unterminated regex"))?);
+                }
+                Some('/') if !pattern.is_empty() => break,
+                Some(c) if c != '/' => pattern.push(c),
+                _ => return Err(self.error("Unterminated regex")),
+            }
+        }
+        let mut builder = RegexBuilder::new(&pattern);
+        while let Some(flag) = self.peek().filter(|c| matches!(c, 'i' | 'm' | 's' | 'u')) {
+            self.pos += 1;
+            match flag {
+                'i' => builder.case_insensitive(true),
+                'm' => builder.multi_line(true),
+                's' => builder.dot_matches_new_line(true),
+                _ => builder.unicode(true),
+            };
+        }
+        builder
+            .build()
+            .map_err(|_| SelectorError { message: "Invalid regex".into(), offset: start })
+    }
+
+    fn parse_number(&mut self) -> Result<AttributeValue, SelectorError> {
+        let len = self
+            .rest()
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(self.rest().len());
+        let text = &self.rest()[..len];
+        let number = text.parse::<f64>().map_err(|_| self.error("Invalid number"))?;
+        self.pos += len;
+        Ok(AttributeValue::Literal { text: js_number_to_string(number), number: Some(number) })
+    }
+
+    /// `:not(A)`, `:first-child`, `:statement`, etc.
+    fn parse_pseudo_class(&mut self) -> Result<Selector, SelectorError> {
+        let len = self
+            .rest()
+            .find(|c: char| !c.is_ascii_alphabetic() && c != '-')
+            .unwrap_or(self.rest().len());
+        let name = self.rest()[..len].cow_to_ascii_lowercase();
+        self.pos += len;
+        let selector = match name.as_ref() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let selectors = self.parse_selector_list(name == "has")?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                match name.as_ref() {
+                    "not" => Selector::Not(selectors),
+                    "has" => Selector::Has(selectors),
+                    _ => Selector::Matches(selectors),
+                }
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(')?;
+                self.skip_whitespace();
+                let len =
+                    self.rest().find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest().len());
+                let index = self.rest()[..len]
+                    .parse::<usize>()
+                    .map_err(|_| self.error("Expected a number"))?;
+                self.pos += len;
+                self.skip_whitespace();
+                self.expect(')')?;
+                if name == "nth-child" {
+                    Selector::NthChild(index)
+                } else {
+                    Selector::NthLastChild(index)
+                }
+            }
+            "first-child" => Selector::NthChild(1),
+            "last-child" => Selector::NthLastChild(1),
+            "statement" => Selector::Class(NodeClass::Statement),
+            "expression" => Selector::Class(NodeClass::Expression),
+            "declaration" => Selector::Class(NodeClass::Declaration),
+            "function" => Selector::Class(NodeClass::Function),
+            "pattern" => Selector::Class(NodeClass::Pattern),
+            _ => return Err(self.error("Unknown pseudo-class")),
+        };
+        Ok(selector)
+    }
+
+    /// `callee.object.name`
+    fn parse_path(&mut self) -> Result<Vec<String>, SelectorError> {
+        let mut path = vec![self.parse_identifier()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?.to_string());
+        }
+        Ok(path)
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'s str, SelectorError> {
+        let len = self.rest().find(|c: char| !is_identifier_char(c)).unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("Expected an identifier"));
+        }
+        let identifier = &self.rest()[..len];
+        self.pos += len;
+        Ok(identifier)
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.pos += c.len_utf8();
+        }
+        matched
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) { Ok(()) } else { Err(self.error(&format!("Expected `{c}`"))) }
+    }
+
+    /// Returns whether any whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        let len = self.rest().len() - self.rest().trim_start().len();
+        self.pos += len;
+        self.pos > start
+    }
+
+    fn error(&self, message: &str) -> SelectorError {
+        SelectorError { message: message.to_string(), offset: self.pos }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    !c.is_whitespace()
+        && !matches!(
+            c,
+            '[' | ']'
+                | ','
+                | '('
+                | ')'
+                | ':'
+                | '#'
+                | '!'
+                | '='
+                | '>'
+                | '<'
+                | '~'
+                | '+'
+                | '.'
+                | '*'
+                | '/'
+                | '\''
+                | '"'
+        )
+}
+
+fn starts_atom(c: char) -> bool {
+    matches!(c, '*' | '#' | '[' | '.' | ':' | '!') || is_identifier_char(c)
+}
@@ -0,0 +1,221 @@
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use fast_glob::glob_match;
+use oxc_resolver::NODEJS_BUILTINS;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
+
+/// The kind of module an import specifier refers to.
+///
+/// See <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/src/core/importType.js>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportType {
+    /// Node.js builtin modules, e.g. `fs` or `node:path`.
+    Builtin,
+    /// Packages resolved from `node_modules`, or bare specifiers that could not be resolved.
+    External,
+    /// Bare specifiers resolved outside of `node_modules`, e.g. through path aliases.
+    Internal,
+    /// Modules in a parent directory, e.g. `../foo`.
+    Parent,
+    /// Modules in the same directory, e.g. `./foo`.
+    Sibling,
+    /// The index of the current directory, e.g. `./` or `./index.js`.
+    Index,
+    /// Absolute paths and anything else.
+    Unknown,
+}
+
+impl ImportType {
+    /// Classifies `specifier`, given the path it resolved to.
+    pub fn new(specifier: &str, resolved_path: Option<&Path>) -> Self {
+        if is_builtin_module(specifier) {
+            Self::Builtin
+        } else if is_index(specifier) {
+            Self::Index
+        } else if specifier == ".." || specifier.starts_with("../") {
+            Self::Parent
+        } else if specifier.starts_with("./") {
+            Self::Sibling
+        } else if package_name(specifier).is_some() {
+            if resolved_path.is_none_or(is_in_node_modules) {
+                Self::External
+            } else {
+                Self::Internal
+            }
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+fn is_index(specifier: &str) -> bool {
+    matches!(specifier, "." | "./")
+        || specifier.strip_prefix("./").and_then(|rest| rest.strip_prefix("index")).is_some_and(
+            |ext| ext.is_empty() || ext.strip_prefix('.').is_some_and(|ext| !ext.contains('/')),
+        )
+}
+
+/// Whether `specifier` refers to a Node.js builtin module.
+pub fn is_builtin_module(specifier: &str) -> bool {
+    specifier.starts_with("node:") || NODEJS_BUILTINS.binary_search(&specifier).is_ok()
+}
+
+/// Whether `path` is inside of a `node_modules` directory.
+pub fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|component| component == Component::Normal("node_modules".as_ref()))
+}
+
+/// Name of the package a bare specifier refers to, e.g. `@scope/pkg` for `@scope/pkg/sub/path`.
+/// Returns `None` for relative and absolute specifiers, and for aliases like `~/foo`.
+pub fn package_name(specifier: &str) -> Option<&str> {
+    let first = specifier.split('/').next()?;
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    if !first.starts_with(|c| c == '@' || is_word(c)) || first.contains(':') {
+        return None;
+    }
+    if first.starts_with('@') {
+        let scope_len = first.len();
+        let name = specifier[scope_len..].strip_prefix('/')?.split('/').next()?;
+        if scope_len == 1 || name.is_empty() {
+            return None;
+        }
+        return Some(&specifier[..scope_len + 1 + name.len()]);
+    }
+    Some(first)
+}
+
+/// Whether `path` matches `glob`. Relative globs are resolved against the current working
+/// directory.
+pub fn path_matches_glob(glob: &str, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    if glob_match(glob, path.as_ref()) {
+        return true;
+    }
+    let cwd = std::env::current_dir().unwrap_or_default();
+    glob_match(cwd.join(glob).to_string_lossy().as_ref(), path.as_ref())
+}
+
+/// The fields of a `package.json` that are relevant to the import plugin.
+#[derive(Debug, Default)]
+pub struct PackageJson {
+    pub private: bool,
+    pub dependencies: FxHashSet<String>,
+    pub dev_dependencies: FxHashSet<String>,
+    pub optional_dependencies: FxHashSet<String>,
+    pub peer_dependencies: FxHashSet<String>,
+    pub bundled_dependencies: FxHashSet<String>,
+    /// Files listed in `main`, `bin` and `browser`.
+    pub entry_points: Vec<PathBuf>,
+}
+
+impl PackageJson {
+    /// Finds the nearest `package.json` of the file at `path`.
+    ///
+    /// Parsed manifests are cached for the lifetime of the process. Returns `None` if there is no
+    /// `package.json` or it could not be read.
+    pub fn find(path: &Path) -> Option<Arc<Self>> {
+        static CACHE: OnceLock<RwLock<FxHashMap<PathBuf, Option<Arc<PackageJson>>>>> =
+            OnceLock::new();
+
+        let file = path
+            .ancestors()
+            .skip(1)
+            .map(|dir| dir.join("package.json"))
+            .find(|file| file.is_file())?;
+        let cache = CACHE.get_or_init(RwLock::default);
+        if let Some(package_json) = cache.read().unwrap().get(&file) {
+            return package_json.clone();
+        }
+        let package_json = Self::read(&file).map(Arc::new);
+        cache.write().unwrap().insert(file, package_json.clone());
+        package_json
+    }
+
+    fn read(file: &Path) -> Option<Self> {
+        let source = std::fs::read_to_string(file).ok()?;
+        let json: Value = serde_json::from_str(&source).ok()?;
+        let dir = file.parent()?.to_path_buf();
+
+        let keys = |field: &str| -> FxHashSet<String> {
+            json.get(field)
+                .and_then(Value::as_object)
+                .map(|deps| deps.keys().cloned().collect())
+                .unwrap_or_default()
+        };
+        let bundled_dependencies = ["bundledDependencies", "bundleDependencies"]
+            .iter()
+            .filter_map(|field| json.get(field).and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_str)
+            .map(ToString::to_string)
+            .collect();
+
+        let mut entry_points = vec![];
+        for field in ["main", "bin", "browser"] {
+            match json.get(field) {
+                Some(Value::String(entry)) => entry_points.push(dir.join(entry)),
+                // `main` may only be a string.
+                Some(Value::Object(entries)) if field != "main" => entry_points.extend(
+                    entries.values().filter_map(Value::as_str).map(|entry| dir.join(entry)),
+                ),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            private: json.get("private").and_then(Value::as_bool).unwrap_or(false),
+            dependencies: keys("dependencies"),
+            dev_dependencies: keys("devDependencies"),
+            optional_dependencies: keys("optionalDependencies"),
+            peer_dependencies: keys("peerDependencies"),
+            bundled_dependencies,
+            entry_points,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{ImportType, package_name};
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(package_name("lodash"), Some("lodash"));
+        assert_eq!(package_name("lodash/fp"), Some("lodash"));
+        assert_eq!(package_name("@scope/pkg"), Some("@scope/pkg"));
+        assert_eq!(package_name("@scope/pkg/sub/path"), Some("@scope/pkg"));
+        assert_eq!(package_name("@scope"), None);
+        assert_eq!(package_name("@/alias"), None);
+        assert_eq!(package_name("~/alias"), None);
+        assert_eq!(package_name("#internal"), None);
+        assert_eq!(package_name("./foo"), None);
+        assert_eq!(package_name("../foo"), None);
+        assert_eq!(package_name("/abs/path"), None);
+    }
+
+    #[test]
+    fn test_import_type() {
+        let external = Path::new("/project/node_modules/lodash/index.js");
+        let internal = Path::new("/project/src/utils/index.js");
+        assert_eq!(ImportType::new("fs", None), ImportType::Builtin);
+        assert_eq!(ImportType::new("node:test", None), ImportType::Builtin);
+        assert_eq!(ImportType::new("lodash", Some(external)), ImportType::External);
+        assert_eq!(ImportType::new("not-installed", None), ImportType::External);
+        assert_eq!(ImportType::new("utils", Some(internal)), ImportType::Internal);
+        assert_eq!(ImportType::new("..", None), ImportType::Parent);
+        assert_eq!(ImportType::new("../foo", None), ImportType::Parent);
+        assert_eq!(ImportType::new("./foo", None), ImportType::Sibling);
+        assert_eq!(ImportType::new(".", None), ImportType::Index);
+        assert_eq!(ImportType::new("./", None), ImportType::Index);
+        assert_eq!(ImportType::new("./index", None), ImportType::Index);
+        assert_eq!(ImportType::new("./index.js", None), ImportType::Index);
+        assert_eq!(ImportType::new("./index/foo", None), ImportType::Sibling);
+        assert_eq!(ImportType::new("/abs/path", None), ImportType::Unknown);
+    }
+}
@@ -10,22 +10,30 @@ use std::{
 use oxc_allocator::Allocator;
 
 mod comment;
+mod complexity;
 mod config;
+mod data_flow;
+mod esquery;
 mod express;
+mod import;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod playwright;
 mod promise;
 mod react;
 mod react_perf;
 mod regex;
+mod security;
+mod testing_library;
 mod unicorn;
 mod url;
 mod vitest;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
-    react_perf::*, regex::*, unicorn::*, url::*, vitest::*,
+    comment::*, complexity::*, config::*, data_flow::*, esquery::*, express::*, import::*, jest::*,
+    jsdoc::*, nextjs::*, playwright::*, promise::*, react::*, react_perf::*, regex::*, security::*,
+    testing_library::*, unicorn::*, url::*, vitest::*,
 };
 
 /// List of Jest rules that have Vitest equivalents.
@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    AstKind,
+    ast::{CallExpression, Expression, IdentifierReference},
+};
+
+use crate::{
+    context::LintContext,
+    utils::{JestFnKind, JestGeneralFnKind, get_node_name_vec},
+};
+
+/// Modifiers which may follow `test` or `test.describe`, e.g. `test.describe.only`.
+const PLAYWRIGHT_MODIFIERS: [&str; 8] =
+    ["only", "skip", "fixme", "fail", "slow", "step", "parallel", "serial"];
+
+/// Matchers of `expect` which return a promise, because they retry until they pass.
+pub const PLAYWRIGHT_ASYNC_MATCHERS: [&str; 26] = [
+    "toBeAttached",
+    "toBeChecked",
+    "toBeDisabled",
+    "toBeEditable",
+    "toBeEmpty",
+    "toBeEnabled",
+    "toBeFocused",
+    "toBeHidden",
+    "toBeInViewport",
+    "toBeOK",
+    "toBeVisible",
+    "toContainText",
+    "toHaveAccessibleDescription",
+    "toHaveAccessibleName",
+    "toHaveAttribute",
+    "toHaveClass",
+    "toHaveCount",
+    "toHaveCSS",
+    "toHaveId",
+    "toHaveJSProperty",
+    "toHaveRole",
+    "toHaveScreenshot",
+    "toHaveText",
+    "toHaveTitle",
+    "toHaveURL",
+    "toHaveValue",
+];
+
+pub struct ParsedPlaywrightFnCall<'a> {
+    /// The kind of the call, classified like a Jest call, e.g. `test.describe.only()` is a
+    /// [`JestGeneralFnKind::Describe`].
+    pub kind: JestFnKind,
+    /// The names following `test` or `expect`, e.g. `["describe", "only"]` for
+    /// `test.describe.only()`, or `["not", "toBeVisible"]` for `expect(locator).not.toBeVisible()`.
+    pub members: Vec<Cow<'a, str>>,
+}
+
+impl ParsedPlaywrightFnCall<'_> {
+    pub fn has_member(&self, name: &str) -> bool {
+        self.members.iter().any(|member| member == name)
+    }
+}
+
+/// Parses a call to Playwright's `test` or `expect`, e.g. `test.describe.only()` or
+/// `expect(locator).toBeVisible()`.
+///
+/// `test` and `expect` are recognized when they are globals, imported from `@playwright/test`,
+/// or `test` is extended with fixtures, e.g. `const test = base.extend({})`.
+pub fn parse_playwright_fn_call<'a>(
+    call: &'a CallExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ParsedPlaywrightFnCall<'a>> {
+    let head = get_head_name(leftmost_identifier(&call.callee)?, ctx)?;
+    let mut chain = get_node_name_vec(&call.callee);
+    if chain.is_empty() {
+        return None;
+    }
+    let members = chain.split_off(1);
+
+    let kind = match head {
+        "expect" => JestFnKind::Expect,
+        "test" => match members.first().map(AsRef::as_ref) {
+            None => JestFnKind::General(JestGeneralFnKind::Test),
+            Some(name) if PLAYWRIGHT_MODIFIERS.contains(&name) => {
+                JestFnKind::General(JestGeneralFnKind::Test)
+            }
+            Some(name) => match JestFnKind::from(name) {
+                kind @ JestFnKind::General(
+                    JestGeneralFnKind::Describe | JestGeneralFnKind::Hook,
+                ) => kind,
+                _ => JestFnKind::Unknown,
+            },
+        },
+        _ => return None,
+    };
+
+    Some(ParsedPlaywrightFnCall { kind, members })
+}
+
+/// The leftmost identifier of a callee, e.g. `expect` in `expect(locator).not.toBeVisible`.
+fn leftmost_identifier<'a>(expr: &'a Expression<'a>) -> Option<&'a IdentifierReference<'a>> {
+    match expr {
+        Expression::Identifier(ident) => Some(ident),
+        Expression::CallExpression(call) => leftmost_identifier(&call.callee),
+        _ => leftmost_identifier(expr.as_member_expression()?.object()),
+    }
+}
+
+/// The Playwright name which `ident` refers to, i.e. `test` or `expect`.
+fn get_head_name<'a>(ident: &IdentifierReference<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return Some(ident.name.as_str());
+    };
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    match declaration.kind() {
+        AstKind::ImportSpecifier(specifier) => {
+            let AstKind::ImportDeclaration(import) = ctx.nodes().parent_kind(declaration.id())
+            else {
+                return None;
+            };
+            (import.source.value == "@playwright/test").then(|| specifier.imported.name().as_str())
+        }
+        // const test = base.extend({ ... });
+        AstKind::VariableDeclarator(decl) => {
+            let Some(Expression::CallExpression(call)) = &decl.init else {
+                return None;
+            };
+            let member = call.callee.as_member_expression()?;
+            (ident.name == "test" && member.static_property_name() == Some("extend"))
+                .then_some("test")
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` is a Playwright page, i.e. `page`, or a `page` property like `this.page`.
+pub fn is_page_object(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => ident.name == "page",
+        expr => expr
+            .as_member_expression()
+            .is_some_and(|member| member.static_property_name() == Some("page")),
+    }
+}
+
+/// The name of the page method which `call` calls, e.g. `waitForTimeout` for
+/// `page.waitForTimeout(1000)`.
+pub fn get_page_method_name<'a>(call: &CallExpression<'a>) -> Option<&'a str> {
+    let member = call.callee.get_inner_expression().as_member_expression()?;
+    if !is_page_object(member.object()) {
+        return None;
+    }
+    member.static_property_name()
+}
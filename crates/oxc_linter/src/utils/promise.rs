@@ -1,4 +1,6 @@
-use oxc_ast::ast::CallExpression;
+use oxc_ast::{AstKind, ast::CallExpression};
+
+use crate::{AstNode, context::LintContext};
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
 pub const PROMISE_STATIC_METHODS: [&str; 7] =
@@ -20,3 +22,58 @@ pub fn is_promise(call_expr: &CallExpression) -> Option<String> {
 
     None
 }
+
+/// Whether the promise which `node` evaluates to is handled, i.e. it is awaited, returned,
+/// chained with `then`, `catch` or `finally`, or passed to a `Promise` static method, e.g.
+/// `Promise.all`, whose result is handled.
+///
+/// When the promise is assigned to a variable, it is handled if any reference to that
+/// variable is handled.
+pub fn is_promise_handled<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    for parent in ctx.nodes().ancestors(node.id()) {
+        match parent.kind() {
+            AstKind::AwaitExpression(_) | AstKind::ReturnStatement(_) => return true,
+            AstKind::ExpressionStatement(_) => {
+                // The body of `() => promise` is wrapped in an expression statement.
+                return ctx.nodes().ancestor_kinds(parent.id()).nth(1).is_some_and(
+                    |kind| matches!(kind, AstKind::ArrowFunctionExpression(arrow) if arrow.expression),
+                );
+            }
+            AstKind::StaticMemberExpression(member) => {
+                return matches!(member.property.name.as_str(), "then" | "catch" | "finally");
+            }
+            AstKind::CallExpression(call) => {
+                let is_promise_static_method =
+                    call.callee.get_member_expr().is_some_and(|member| {
+                        member.object().is_specific_id("Promise")
+                            && member
+                                .static_property_name()
+                                .is_some_and(|name| PROMISE_STATIC_METHODS.contains(&name))
+                    });
+                if !is_promise_static_method {
+                    return false;
+                }
+            }
+            AstKind::VariableDeclarator(decl) => {
+                let Some(id) = decl.id.get_binding_identifier() else {
+                    return false;
+                };
+                return ctx.symbol_references(id.symbol_id()).any(|reference| {
+                    is_promise_handled(ctx.nodes().get_node(reference.node_id()), ctx)
+                });
+            }
+            AstKind::Argument(_)
+            | AstKind::ArrayExpression(_)
+            | AstKind::SpreadElement(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::ConditionalExpression(_)
+            | AstKind::LogicalExpression(_)
+            | AstKind::ChainExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_) => {}
+            _ => return false,
+        }
+    }
+    false
+}
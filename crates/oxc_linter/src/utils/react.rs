@@ -1,18 +1,28 @@
 use std::borrow::Cow;
 
+use cow_utils::CowUtils;
 use oxc_ast::{
     AstKind,
     ast::{
-        CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
+        ArrowFunctionExpression, AssignmentTarget, BindingIdentifier, CallExpression, Class,
+        ClassElement, Expression, Function, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
         JSXChild, JSXElement, JSXElementName, JSXExpression, JSXMemberExpression,
-        JSXMemberExpressionObject, JSXOpeningElement, MemberExpression,
+        JSXMemberExpressionObject, JSXOpeningElement, MemberExpression, PropertyKind,
+        ReturnStatement, StaticMemberExpression,
     },
     match_member_expression,
 };
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::{ToBoolean, is_global_reference::WithoutGlobalReferenceInformation};
-use oxc_semantic::AstNode;
+use oxc_semantic::{AstNode, SymbolId};
+use oxc_syntax::scope::ScopeFlags;
 
-use crate::{LintContext, OxlintSettings};
+use crate::{
+    LintContext, OxlintSettings,
+    globals::{
+        ABSTRACT_ARIA_ROLES, INTERACTIVE_ARIA_ROLES, VALID_ARIA_ROLES, is_non_interactive_aria_role,
+    },
+};
 
 pub fn is_create_element_call(call_expr: &CallExpression) -> bool {
     match &call_expr.callee {
@@ -114,37 +124,193 @@ pub fn is_presentation_role(jsx_opening_el: &JSXOpeningElement) -> bool {
     matches!(get_string_literal_prop_value(role), Some("presentation" | "none"))
 }
 
-// TODO: Should re-implement
-// https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/4c7e7815c12a797587bb8e3cdced7f3003848964/src/util/isInteractiveElement.js
-// with `oxc-project/aria-query` which is currently W.I.P.
-//
-// Until then, use simplified version by https://html.spec.whatwg.org/multipage/dom.html#interactive-content
-pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
-    // Interactive contents are...
-    // - button, details, embed, iframe, label, select, textarea
-    // - input (if the `type` attribute is not in the Hidden state)
-    // - a (if the `href` attribute is present)
-    // - audio, video (if the `controls` attribute is present)
-    // - img (if the `usemap` attribute is present)
-    match element_type {
-        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
-        "input" => {
-            if let Some(input_type) = has_jsx_prop(jsx_opening_el, "type") {
-                if get_string_literal_prop_value(input_type)
-                    .is_some_and(|val| val.eq_ignore_ascii_case("hidden"))
-                {
-                    return false;
+/// The value of a JSX attribute if it is a literal, like `getLiteralPropValue` of jsx-ast-utils.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiteralPropValue<'a> {
+    /// `"true"` and `"false"` are booleans as well, and an attribute without a value is `true`.
+    Boolean(bool),
+    Number(f64),
+    String(&'a str),
+}
+
+/// Returns `None` if the value is not a literal, `null` or `undefined`.
+pub fn get_literal_prop_value<'a>(item: &'a JSXAttributeItem<'_>) -> Option<LiteralPropValue<'a>> {
+    let string = |value: &'a str| {
+        if value.eq_ignore_ascii_case("true") {
+            LiteralPropValue::Boolean(true)
+        } else if value.eq_ignore_ascii_case("false") {
+            LiteralPropValue::Boolean(false)
+        } else {
+            LiteralPropValue::String(value)
+        }
+    };
+    match &item.as_attribute()?.value {
+        None => Some(LiteralPropValue::Boolean(true)),
+        Some(JSXAttributeValue::StringLiteral(lit)) => Some(string(lit.value.as_str())),
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            match container.expression.as_expression()?.get_inner_expression() {
+                Expression::StringLiteral(lit) => Some(string(lit.value.as_str())),
+                Expression::BooleanLiteral(lit) => Some(LiteralPropValue::Boolean(lit.value)),
+                Expression::NumericLiteral(lit) => Some(LiteralPropValue::Number(lit.value)),
+                Expression::TemplateLiteral(lit) => {
+                    lit.single_quasi().map(|quasi| string(quasi.as_str()))
                 }
+                _ => None,
             }
-            true
         }
-        "a" => has_jsx_prop(jsx_opening_el, "href").is_some(),
-        "audio" | "video" => has_jsx_prop(jsx_opening_el, "controls").is_some(),
-        "img" => has_jsx_prop(jsx_opening_el, "usemap").is_some(),
+        _ => None,
+    }
+}
+
+/// Whether the element has the prop `name` with a value other than `null` or `undefined`,
+/// like `hasProp(...) && getPropValue(...) != null` with jsx-ast-utils.
+pub fn has_defined_prop(jsx_opening_el: &JSXOpeningElement, name: &str) -> bool {
+    let Some(item) = has_jsx_prop_ignore_case(jsx_opening_el, name) else {
+        return false;
+    };
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = get_prop_value(item) else {
+        return true;
+    };
+    container.expression.as_expression().is_none_or(|expr| {
+        let expr = expr.get_inner_expression();
+        !expr.is_null() && !expr.is_undefined()
+    })
+}
+
+/// Whether the element is `contentEditable="true"`.
+pub fn is_content_editable(jsx_opening_el: &JSXOpeningElement) -> bool {
+    has_jsx_prop_ignore_case(jsx_opening_el, "contentEditable")
+        .and_then(get_string_literal_prop_value)
+        .is_some_and(|value| value == "true")
+}
+
+/// The first valid role in the `role` attribute, if its value is a literal.
+/// Abstract roles are valid as well.
+pub fn get_explicit_role(jsx_opening_el: &JSXOpeningElement) -> Option<&'static str> {
+    let role = has_jsx_prop_ignore_case(jsx_opening_el, "role")?;
+    let Some(LiteralPropValue::String(value)) = get_literal_prop_value(role) else {
+        return None;
+    };
+    value.split(' ').find_map(|name| {
+        let name = name.cow_to_ascii_lowercase();
+        VALID_ARIA_ROLES.get_key(&name).or_else(|| ABSTRACT_ARIA_ROLES.get_key(&name)).copied()
+    })
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isInteractiveRole.js>
+pub fn is_interactive_role(jsx_opening_el: &JSXOpeningElement) -> bool {
+    get_explicit_role(jsx_opening_el).is_some_and(|role| INTERACTIVE_ARIA_ROLES.contains(role))
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isNonInteractiveRole.js>
+pub fn is_non_interactive_role(jsx_opening_el: &JSXOpeningElement) -> bool {
+    get_explicit_role(jsx_opening_el).is_some_and(is_non_interactive_aria_role)
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isAbstractRole.js>
+pub fn is_abstract_role(jsx_opening_el: &JSXOpeningElement) -> bool {
+    get_explicit_role(jsx_opening_el).is_some_and(|role| ABSTRACT_ARIA_ROLES.contains(role))
+}
+
+/// Whether the element is inherently interactive, i.e. it has an implicit widget role, or is a
+/// widget in the accessibility tree, like `<audio>` and `<canvas>`.
+///
+/// `<td>` is considered interactive as well, as the accessibility tree does not distinguish
+/// between cells and grid cells.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isInteractiveElement.js>
+pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    match element_type {
+        "a" | "area" | "link" => has_jsx_prop(jsx_opening_el, "href").is_some(),
+        "input" => !has_jsx_prop(jsx_opening_el, "type")
+            .and_then(get_string_literal_prop_value)
+            .is_some_and(|value| value.eq_ignore_ascii_case("hidden")),
+        "audio" | "button" | "canvas" | "datalist" | "embed" | "menuitem" | "option" | "select"
+        | "summary" | "td" | "textarea" | "th" | "tr" | "video" => true,
         _ => false,
     }
 }
 
+/// Whether the element has an implicit role that is not a widget, like `<li>` or `<img>`.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.10.2/src/util/isNonInteractiveElement.js>
+pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    match element_type {
+        // A `<section>` is a region only if it has an accessible name.
+        "section" => {
+            has_jsx_prop_ignore_case(jsx_opening_el, "aria-label").is_some()
+                || has_jsx_prop_ignore_case(jsx_opening_el, "aria-labelledby").is_some()
+        }
+        "abbr" | "article" | "aside" | "blockquote" | "body" | "br" | "caption" | "dd"
+        | "details" | "dfn" | "dialog" | "dir" | "dl" | "dt" | "fieldset" | "figcaption"
+        | "figure" | "footer" | "form" | "frame" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6"
+        | "hr" | "iframe" | "img" | "label" | "legend" | "li" | "main" | "mark" | "marquee"
+        | "menu" | "meter" | "nav" | "ol" | "optgroup" | "output" | "p" | "pre" | "progress"
+        | "ruby" | "table" | "tbody" | "tfoot" | "thead" | "time" | "ul" => true,
+        _ => false,
+    }
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.9.0/src/util/getImplicitRole.js>
+pub fn get_implicit_role<'a>(
+    node: &'a JSXOpeningElement<'a>,
+    element_type: &str,
+) -> Option<&'static str> {
+    let implicit_role = match element_type {
+        "a" | "area" | "link" => match has_jsx_prop_ignore_case(node, "href") {
+            Some(_) => "link",
+            None => "",
+        },
+        "article" => "article",
+        "aside" => "complementary",
+        "body" => "document",
+        "button" => "button",
+        "datalist" | "select" => "listbox",
+        "details" => "group",
+        "dialog" => "dialog",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "hr" => "separator",
+        "img" => has_jsx_prop_ignore_case(node, "alt").map_or("img", |i| {
+            get_string_literal_prop_value(i)
+                .map_or("img", |v| if v.is_empty() { "" } else { "img" })
+        }),
+        "input" => has_jsx_prop_ignore_case(node, "type").map_or("textbox", |input_type| {
+            match get_string_literal_prop_value(input_type) {
+                Some("button" | "image" | "reset" | "submit") => "button",
+                Some("checkbox") => "checkbox",
+                Some("radio") => "radio",
+                Some("range") => "slider",
+                _ => "textbox",
+            }
+        }),
+        "li" => "listitem",
+        "menu" => has_jsx_prop_ignore_case(node, "type").map_or("", |v| {
+            get_string_literal_prop_value(v)
+                .map_or("", |v| if v == "toolbar" { "toolbar" } else { "" })
+        }),
+        "menuitem" => has_jsx_prop_ignore_case(node, "type").map_or("", |v| {
+            match get_string_literal_prop_value(v) {
+                Some("checkbox") => "menuitemcheckbox",
+                Some("command") => "menuitem",
+                Some("radio") => "menuitemradio",
+                _ => "",
+            }
+        }),
+        "meter" | "progress" => "progressbar",
+        "nav" => "navigation",
+        "ol" | "ul" => "list",
+        "option" => "option",
+        "output" => "status",
+        "section" => "region",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "textarea" => "textbox",
+        _ => "",
+    };
+
+    VALID_ARIA_ROLES.contains(implicit_role).then_some(implicit_role)
+}
+
 const PRAGMA: &str = "React";
 const CREATE_CLASS: &str = "createReactClass";
 
@@ -199,6 +365,227 @@ pub fn get_parent_component<'a, 'b>(
     ctx.nodes().ancestors(node.id()).find(|node| is_es5_component(node) || is_es6_component(node))
 }
 
+/// Whether `call` is `memo(...)` or `forwardRef(...)`, or their `React.` forms, which wrap a
+/// function component.
+pub fn is_component_wrapper_call(call: &CallExpression) -> bool {
+    is_react_function_call(call, "memo") || is_react_function_call(call, "forwardRef")
+}
+
+/// Whether `node` is a class component, a `createReactClass` component or a function component.
+pub fn is_react_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    is_es5_component(node) || is_es6_component(node) || is_function_component(node, ctx)
+}
+
+/// Whether the function or arrow function `node` is a function component, i.e. it is wrapped in
+/// `memo` or `forwardRef`, or it returns JSX and is either named like a component or exported
+/// anonymously.
+///
+/// Methods, like `render() {}`, are never components.
+pub fn is_function_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return false;
+    }
+    let Some(parent) = component_parent(node, ctx) else {
+        return false;
+    };
+    match parent.kind() {
+        AstKind::MethodDefinition(_) => return false,
+        AstKind::ObjectProperty(prop) if prop.method || prop.kind != PropertyKind::Init => {
+            return false;
+        }
+        AstKind::CallExpression(call) if is_component_wrapper_call(call) => return true,
+        _ => {}
+    }
+    if !is_returning_jsx(node.kind()) {
+        return false;
+    }
+    match get_component_name(node, ctx) {
+        Some(name) => is_react_component_name(&name),
+        None => match parent.kind() {
+            AstKind::ExportDefaultDeclaration(_) => true,
+            AstKind::AssignmentExpression(assign) => {
+                matches!(&assign.left, AssignmentTarget::StaticMemberExpression(member) if is_module_exports(member))
+            }
+            _ => false,
+        },
+    }
+}
+
+/// The name a function, class or `createReactClass` call is known by: its own name, or the
+/// variable, property or assignment target it is assigned to.
+///
+/// Assignments to `module.exports` and `exports` don't give a name.
+pub fn get_component_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Cow<'a, str>> {
+    let id = match node.kind() {
+        AstKind::Function(func) => func.id.as_ref(),
+        AstKind::Class(class) => class.id.as_ref(),
+        _ => None,
+    };
+    if let Some(id) = id {
+        return Some(Cow::Borrowed(id.name.as_str()));
+    }
+    match component_parent(node, ctx)?.kind() {
+        AstKind::VariableDeclarator(decl) => {
+            decl.id.get_binding_identifier().map(|id| Cow::Borrowed(id.name.as_str()))
+        }
+        AstKind::AssignmentExpression(assign) => match &assign.left {
+            AssignmentTarget::AssignmentTargetIdentifier(id) => {
+                Some(Cow::Borrowed(id.name.as_str()))
+            }
+            AssignmentTarget::StaticMemberExpression(member) if !is_module_exports(member) => {
+                Some(Cow::Borrowed(member.property.name.as_str()))
+            }
+            _ => None,
+        },
+        AstKind::ObjectProperty(prop) => prop.key.static_name(),
+        AstKind::PropertyDefinition(prop) => prop.key.static_name(),
+        _ => None,
+    }
+}
+
+/// The variable a component is bound to: the name of a function or class declaration, or the
+/// variable its definition is assigned to, through `memo` and `forwardRef` wrappers.
+pub fn get_component_symbol_id<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<SymbolId> {
+    let mut current = node;
+    while let Some(parent) = component_parent(current, ctx) {
+        match parent.kind() {
+            AstKind::CallExpression(call) if is_component_wrapper_call(call) => current = parent,
+            AstKind::VariableDeclarator(decl) => {
+                return decl.id.get_binding_identifier().map(BindingIdentifier::symbol_id);
+            }
+            _ => break,
+        }
+    }
+    match node.kind() {
+        AstKind::Function(func) if func.is_declaration() => func.id.as_ref(),
+        AstKind::Class(class) if class.is_declaration() => class.id.as_ref(),
+        _ => None,
+    }
+    .map(BindingIdentifier::symbol_id)
+}
+
+/// The value assigned to the static property `name` of the component bound to `symbol_id`,
+/// e.g. the object of `Foo.propTypes = { ... }`.
+pub fn get_static_property_assignment<'a>(
+    symbol_id: SymbolId,
+    name: &str,
+    ctx: &LintContext<'a>,
+) -> Option<&'a Expression<'a>> {
+    let nodes = ctx.nodes();
+    ctx.scoping().get_resolved_reference_ids(symbol_id).iter().find_map(|&reference_id| {
+        let reference_node = ctx.scoping().get_reference(reference_id).node_id();
+        let AstKind::StaticMemberExpression(member) = nodes.parent_kind(reference_node) else {
+            return None;
+        };
+        if member.property.name != name {
+            return None;
+        }
+        let member_id = nodes.parent_id(reference_node);
+        let AstKind::AssignmentExpression(assign) = nodes.parent_kind(member_id) else {
+            return None;
+        };
+        matches!(&assign.left, AssignmentTarget::StaticMemberExpression(left) if left.span == member.span)
+            .then_some(&assign.right)
+    })
+}
+
+/// The value of the static property `name` of a class, e.g. the object of
+/// `static propTypes = { ... }`.
+pub fn get_class_static_property<'a>(
+    class: &'a Class<'a>,
+    name: &str,
+) -> Option<&'a Expression<'a>> {
+    class.body.body.iter().find_map(|element| match element {
+        ClassElement::PropertyDefinition(prop)
+            if prop.r#static && prop.key.static_name().is_some_and(|key| key == name) =>
+        {
+            prop.value.as_ref()
+        }
+        _ => None,
+    })
+}
+
+/// Whether the function may return JSX, i.e. any of its return values is a JSX element or
+/// fragment, or a `createElement` call, possibly in a conditional or logical expression.
+pub fn is_returning_jsx(kind: AstKind) -> bool {
+    let body = match kind {
+        AstKind::ArrowFunctionExpression(arrow) if arrow.expression => {
+            return arrow.get_expression().is_some_and(is_jsx_like);
+        }
+        AstKind::ArrowFunctionExpression(arrow) => &arrow.body,
+        AstKind::Function(func) => match &func.body {
+            Some(body) => body,
+            None => return false,
+        },
+        _ => return false,
+    };
+    let mut visitor = ReturnsJsxVisitor { returns_jsx: false };
+    visitor.visit_function_body(body);
+    visitor.returns_jsx
+}
+
+fn is_jsx_like(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::ConditionalExpression(cond) => {
+            is_jsx_like(&cond.consequent) || is_jsx_like(&cond.alternate)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_jsx_like(&logical.left) || is_jsx_like(&logical.right)
+        }
+        Expression::SequenceExpression(seq) => seq.expressions.last().is_some_and(is_jsx_like),
+        Expression::CallExpression(call) => is_react_function_call(call, "createElement"),
+        _ => false,
+    }
+}
+
+struct ReturnsJsxVisitor {
+    returns_jsx: bool,
+}
+
+impl<'a> Visit<'a> for ReturnsJsxVisitor {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        self.returns_jsx |= it.argument.as_ref().is_some_and(is_jsx_like);
+    }
+
+    // Returns of nested functions and classes are their own.
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// The parent of `node`, skipping parentheses, TypeScript expressions and arguments.
+fn component_parent<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    ctx.nodes().ancestors(node.id()).find(|parent| {
+        !matches!(
+            parent.kind(),
+            AstKind::ParenthesizedExpression(_)
+                | AstKind::TSAsExpression(_)
+                | AstKind::TSSatisfiesExpression(_)
+                | AstKind::TSNonNullExpression(_)
+                | AstKind::TSTypeAssertion(_)
+                | AstKind::TSInstantiationExpression(_)
+                | AstKind::Argument(_)
+        )
+    })
+}
+
+/// Whether `member` is `module.exports` or `exports.*`.
+fn is_module_exports(member: &StaticMemberExpression) -> bool {
+    match &member.object {
+        Expression::Identifier(ident) => {
+            ident.name == "exports" || (ident.name == "module" && member.property.name == "exports")
+        }
+        Expression::StaticMemberExpression(object) => is_module_exports(object),
+        _ => false,
+    }
+}
+
 fn get_jsx_mem_expr_name<'a>(jsx_mem_expr: &JSXMemberExpression) -> Cow<'a, str> {
     let prefix = match &jsx_mem_expr.object {
         JSXMemberExpressionObject::IdentifierReference(id) => Cow::Borrowed(id.name.as_str()),
@@ -0,0 +1,77 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BinaryOperator, Expression, VariableDeclarationKind},
+};
+use oxc_semantic::IsGlobalReference;
+
+use crate::{context::LintContext, utils::is_reassigned_between};
+
+/// How many variables are followed to their value before giving up.
+const MAX_DEPTH: u8 = 8;
+
+/// Whether `expr` is the global function `name`, either by itself or as a property of the
+/// global object, e.g. `window.setTimeout` or `globalThis.setTimeout`.
+pub fn is_global_function(expr: &Expression, name: &str, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => ident.is_global_reference_name(name, ctx.scoping()),
+        expr => expr.as_member_expression().is_some_and(|member| {
+            member.static_property_name() == Some(name)
+                && ["window", "globalThis", "self"]
+                    .iter()
+                    .any(|global| member.object().is_global_reference_name(global, ctx.scoping()))
+        }),
+    }
+}
+
+/// Whether `expr` always evaluates to the same value, i.e. it is a literal, a template literal
+/// without expressions, a concatenation of those, or a variable initialized with one which is
+/// `const` or not reassigned before `expr`.
+pub fn is_static_value<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    is_static_value_impl(expr, ctx, 0)
+}
+
+fn is_static_value_impl<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>, depth: u8) -> bool {
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::RegExpLiteral(_) => true,
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().all(|expr| is_static_value_impl(expr, ctx, depth))
+        }
+        Expression::BinaryExpression(binary) if binary.operator == BinaryOperator::Addition => {
+            is_static_value_impl(&binary.left, ctx, depth)
+                && is_static_value_impl(&binary.right, ctx, depth)
+        }
+        Expression::Identifier(ident) => {
+            if depth >= MAX_DEPTH {
+                return false;
+            }
+            let reference = ctx.scoping().get_reference(ident.reference_id());
+            let Some(symbol_id) = reference.symbol_id() else {
+                return false;
+            };
+            let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+            match declaration.kind() {
+                AstKind::VariableDeclarator(decl)
+                    if decl.id.kind.is_binding_identifier()
+                        && (decl.kind == VariableDeclarationKind::Const
+                            || !is_reassigned_between(
+                                symbol_id,
+                                declaration.id(),
+                                reference.node_id(),
+                                ctx,
+                            )) =>
+                {
+                    decl.init
+                        .as_ref()
+                        .is_some_and(|init| is_static_value_impl(init, ctx, depth + 1))
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,146 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPatternKind, CallExpression, Expression, IdentifierReference},
+};
+
+use crate::context::LintContext;
+
+/// Suffixes of the built-in Testing Library queries, e.g. `ByRole` in `getByRole`.
+const QUERY_SUFFIXES: [&str; 8] = [
+    "ByLabelText",
+    "ByPlaceholderText",
+    "ByText",
+    "ByAltText",
+    "ByTitle",
+    "ByDisplayValue",
+    "ByRole",
+    "ByTestId",
+];
+
+/// Async utilities which return a promise.
+pub const TESTING_LIBRARY_ASYNC_UTILS: [&str; 2] = ["waitFor", "waitForElementToBeRemoved"];
+
+/// Utilities which print the DOM for debugging.
+pub const TESTING_LIBRARY_DEBUG_UTILS: [&str; 6] =
+    ["debug", "logTestingPlaygroundURL", "prettyDOM", "logRoles", "logDOM", "prettyFormat"];
+
+/// Properties of DOM nodes which give direct access to other nodes.
+pub const DOM_NODE_ACCESS_PROPERTIES: [&str; 12] = [
+    "children",
+    "childNodes",
+    "firstChild",
+    "firstElementChild",
+    "lastChild",
+    "lastElementChild",
+    "nextElementSibling",
+    "nextSibling",
+    "parentElement",
+    "parentNode",
+    "previousElementSibling",
+    "previousSibling",
+];
+
+/// Methods of DOM nodes which give direct access to other nodes.
+pub const DOM_NODE_ACCESS_METHODS: [&str; 6] = [
+    "closest",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByTagName",
+    "querySelector",
+    "querySelectorAll",
+];
+
+/// Whether `name` is a query with the given prefix, e.g. `getAllByRole` with prefix `get`.
+fn is_query_with_prefix(name: &str, prefix: &str) -> bool {
+    name.strip_prefix(prefix)
+        .map(|rest| rest.strip_prefix("All").unwrap_or(rest))
+        .is_some_and(|suffix| QUERY_SUFFIXES.contains(&suffix))
+}
+
+/// Whether `name` is a synchronous query, e.g. `getByRole` or `queryAllByText`.
+pub fn is_sync_query(name: &str) -> bool {
+    is_query_with_prefix(name, "get") || is_query_with_prefix(name, "query")
+}
+
+/// Whether `name` is an asynchronous query, e.g. `findByRole` or `findAllByText`.
+pub fn is_async_query(name: &str) -> bool {
+    is_query_with_prefix(name, "find")
+}
+
+/// Whether `name` is a query, either synchronous or asynchronous.
+pub fn is_query(name: &str) -> bool {
+    is_sync_query(name) || is_async_query(name)
+}
+
+/// The name of the called function, e.g. `getByText` for both `getByText()` and
+/// `screen.getByText()`.
+pub fn get_testing_library_call_name<'a>(call: &CallExpression<'a>) -> Option<&'a str> {
+    match call.callee.get_inner_expression() {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        expr => expr.as_member_expression()?.static_property_name(),
+    }
+}
+
+/// Whether `expr` is a call to `render`, e.g. `render(<App />)`.
+pub fn is_render_call(expr: &Expression) -> bool {
+    matches!(expr.get_inner_expression(), Expression::CallExpression(call)
+        if call.callee.is_specific_id("render"))
+}
+
+/// The initializer of the variable which `ident` refers to, when it is declared by a
+/// variable declaration, either directly or by destructuring, e.g. `render(<App />)` for
+/// `container` in `const { container } = render(<App />)`.
+pub fn get_declaration_init<'a>(
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a Expression<'a>> {
+    let Expression::Identifier(ident) = expr.get_inner_expression() else {
+        return None;
+    };
+    let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+    let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+    let AstKind::VariableDeclarator(decl) = declaration.kind() else {
+        return None;
+    };
+    match decl.id.kind {
+        BindingPatternKind::BindingIdentifier(_) | BindingPatternKind::ObjectPattern(_) => {
+            decl.init.as_ref()
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` is the result of `render`, e.g. `view` in `const view = render(<App />)`.
+pub fn is_render_result<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+    is_render_call(expr) || get_declaration_init(expr, ctx).is_some_and(is_render_call)
+}
+
+/// Whether `ident` is imported from a Testing Library package, e.g. `@testing-library/react`.
+pub fn is_imported_from_testing_library<'a>(
+    ident: &IdentifierReference<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    let declaration_id = ctx.scoping().symbol_declaration(symbol_id);
+    matches!(ctx.nodes().parent_kind(declaration_id), AstKind::ImportDeclaration(import)
+        if import.source.value.starts_with("@testing-library/"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query_names() {
+        assert!(is_sync_query("getByRole"));
+        assert!(is_sync_query("queryAllByText"));
+        assert!(!is_sync_query("findByRole"));
+        assert!(!is_sync_query("getByFoo"));
+        assert!(is_async_query("findAllByTestId"));
+        assert!(!is_async_query("find"));
+        assert!(is_query("getAllByLabelText"));
+        assert!(!is_query("getElementById"));
+    }
+}
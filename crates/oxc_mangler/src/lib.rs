@@ -393,7 +393,6 @@ impl<'t> Mangler<'t> {
             &slots,
         );
 
-        let root_unresolved_references = scoping.root_unresolved_references();
         let root_bindings = scoping.get_bindings(scoping.root_scope_id());
 
         let mut reserved_names = Vec::with_capacity_in(total_number_of_slots, temp_allocator);
@@ -405,9 +404,7 @@ impl<'t> Mangler<'t> {
                 count += 1;
                 // Do not mangle keywords and unresolved references
                 let n = name.as_str();
-                if !is_keyword(n)
-                    && !is_special_name(n)
-                    && !root_unresolved_references.contains_key(n)
+                if !is_reserved_name(n, scoping)
                     && !(root_bindings.contains_key(n)
                         && (!self.options.top_level || exported_names.contains(n)))
                         // TODO: only skip the names that are kept in the current scope
@@ -542,6 +539,15 @@ impl<'t> Mangler<'t> {
     }
 }
 
+/// Whether `name` can not be given to a binding of the program analyzed in `scoping`,
+/// because it is a keyword, a special name, or the name of an unresolved reference which
+/// the binding would shadow.
+pub fn is_reserved_name(name: &str, scoping: &Scoping) -> bool {
+    is_keyword(name)
+        || is_special_name(name)
+        || scoping.root_unresolved_references().contains_key(name)
+}
+
 fn is_special_name(name: &str) -> bool {
     matches!(name, "exports" | "arguments")
 }
@@ -9,6 +9,7 @@ mod options;
 mod peephole;
 mod state;
 mod symbol_value;
+mod tree_shaker;
 
 #[cfg(test)]
 mod tester;
@@ -20,7 +21,11 @@ use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
 pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{
+    compressor::Compressor,
+    options::*,
+    tree_shaker::{PackageSideEffects, TreeShakeModule, TreeShaker, TreeShakerReturn},
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
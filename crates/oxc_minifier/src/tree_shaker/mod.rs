@@ -0,0 +1,486 @@
+//! Cross-module tree shaking.
+//!
+//! Starting from the entry modules, compute which exports and statements of each module are
+//! used, honouring `sideEffects` from `package.json` and `/* #__NO_SIDE_EFFECTS__ */`
+//! annotations across module boundaries. Unused statements and exports are then dropped from
+//! each module before running [Compressor::dead_code_elimination].
+
+mod module_info;
+mod package_side_effects;
+
+use oxc_allocator::{Allocator, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::VisitMut;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{Scoping, SemanticBuilder, SymbolId};
+use oxc_syntax::module_record::ModuleRecord;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{CompressOptions, Compressor};
+
+use module_info::{ExportTarget, ModuleInfo, NoSideEffectsMarker};
+pub use package_side_effects::PackageSideEffects;
+
+/// A module participating in tree shaking.
+pub struct TreeShakeModule<'a, 'b> {
+    pub program: &'b mut Program<'a>,
+    pub module_record: &'b ModuleRecord<'a>,
+    /// Whether this module may have side effects when imported.
+    ///
+    /// Usually computed from the `sideEffects` field of the closest `package.json`
+    /// with [PackageSideEffects::has_side_effects].
+    ///
+    /// A module without side effects is dropped entirely when none of its exports are used.
+    pub side_effects: bool,
+    /// Import specifier to the index of the resolved module in the module list.
+    ///
+    /// Unresolved specifiers are treated as external modules and are left untouched.
+    pub resolved_requests: FxHashMap<String, usize>,
+}
+
+pub struct TreeShakerReturn<'a> {
+    /// Whether each module is part of the output.
+    pub included_modules: Vec<bool>,
+    /// Used export names of each module.
+    pub used_exports: Vec<FxHashSet<Atom<'a>>>,
+}
+
+pub struct TreeShaker {
+    options: CompressOptions,
+}
+
+impl TreeShaker {
+    pub fn new(options: CompressOptions) -> Self {
+        Self { options }
+    }
+
+    /// Tree shake `modules` in place, with all exports of `entries` considered used.
+    pub fn build<'a>(
+        self,
+        allocator: &'a Allocator,
+        modules: &mut [TreeShakeModule<'a, '_>],
+        entries: &[usize],
+    ) -> TreeShakerReturn<'a> {
+        let scopings = modules
+            .iter()
+            .map(|module| SemanticBuilder::new().build(module.program).semantic.into_scoping())
+            .collect::<Vec<_>>();
+        let mut infos = modules
+            .iter()
+            .zip(&scopings)
+            .map(|(module, scoping)| ModuleInfo::new(module.program, module.module_record, scoping))
+            .collect::<Vec<_>>();
+
+        let no_side_effects =
+            Linker { modules, infos: &infos }.collect_no_side_effects_symbols(&scopings);
+        for ((module, scoping), symbols) in modules.iter_mut().zip(&scopings).zip(&no_side_effects)
+        {
+            if !symbols.is_empty() {
+                NoSideEffectsMarker { scoping, symbols }.visit_program(module.program);
+            }
+        }
+        for ((info, module), scoping) in infos.iter_mut().zip(modules.iter()).zip(&scopings) {
+            info.collect_statements(module.program, scoping, &self.options.treeshake);
+        }
+
+        let mut state = State::new(&infos);
+        let linker = Linker { modules, infos: &infos };
+        for &entry in entries {
+            state.worklist.push(Work::IncludeModule(entry));
+            state.worklist.push(Work::UseAllExports(entry));
+        }
+        linker.run(&mut state);
+
+        for (index, module) in modules.iter_mut().enumerate() {
+            if state.included_modules[index] {
+                shake_module(
+                    allocator,
+                    module.program,
+                    &state.included_statements[index],
+                    &state.used_exports[index],
+                );
+                Compressor::new(allocator)
+                    .dead_code_elimination(module.program, self.options.clone());
+            } else {
+                module.program.body.clear();
+                module.program.directives.clear();
+            }
+        }
+        for index in 0..modules.len() {
+            if state.included_modules[index] {
+                remove_unused_imports(modules, index, &state.included_modules);
+            }
+        }
+
+        TreeShakerReturn {
+            included_modules: state.included_modules,
+            used_exports: state.used_exports,
+        }
+    }
+}
+
+enum Work<'a> {
+    IncludeModule(usize),
+    UseExport(usize, Atom<'a>),
+    UseAllExports(usize),
+    IncludeSymbol(usize, SymbolId),
+    IncludeStatement(usize, usize),
+}
+
+struct State<'a> {
+    worklist: Vec<Work<'a>>,
+    included_modules: Vec<bool>,
+    included_statements: Vec<Vec<bool>>,
+    included_symbols: Vec<FxHashSet<SymbolId>>,
+    used_exports: Vec<FxHashSet<Atom<'a>>>,
+    all_exports_used: Vec<bool>,
+}
+
+impl<'a> State<'a> {
+    fn new(infos: &[ModuleInfo<'a>]) -> Self {
+        Self {
+            worklist: vec![],
+            included_modules: vec![false; infos.len()],
+            included_statements: infos
+                .iter()
+                .map(|info| vec![false; info.statements.len()])
+                .collect(),
+            included_symbols: vec![FxHashSet::default(); infos.len()],
+            used_exports: vec![FxHashSet::default(); infos.len()],
+            all_exports_used: vec![false; infos.len()],
+        }
+    }
+}
+
+struct Linker<'m, 'a, 'b> {
+    modules: &'m [TreeShakeModule<'a, 'b>],
+    infos: &'m [ModuleInfo<'a>],
+}
+
+impl<'a> Linker<'_, 'a, '_> {
+    fn resolve(&self, module: usize, request: &str) -> Option<usize> {
+        self.modules[module].resolved_requests.get(request).copied()
+    }
+
+    /// Resolve an export to its declaring module and symbol, following re-exports.
+    fn resolve_export(
+        &self,
+        module: usize,
+        name: &str,
+        visited: &mut FxHashSet<(usize, String)>,
+    ) -> Option<(usize, SymbolId)> {
+        if !visited.insert((module, name.to_string())) {
+            return None;
+        }
+        let info = &self.infos[module];
+        match info.exports.get(name) {
+            Some(ExportTarget::Symbol { symbol_id, .. }) => match info.imports.get(symbol_id) {
+                Some(import) => {
+                    let target = self.resolve(module, &import.request)?;
+                    self.resolve_export(target, import.import_name.as_deref()?, visited)
+                }
+                None => Some((module, *symbol_id)),
+            },
+            Some(ExportTarget::ReExport { request, import_name, .. }) => {
+                let target = self.resolve(module, request)?;
+                self.resolve_export(target, import_name.as_deref()?, visited)
+            }
+            Some(ExportTarget::Statement(_)) => None,
+            None if name == "default" => None,
+            None => info.star_exports.iter().find_map(|(_, request)| {
+                let target = self.resolve(module, request)?;
+                self.resolve_export(target, name, visited)
+            }),
+        }
+    }
+
+    fn provides_export(&self, module: usize, name: &str, visited: &mut FxHashSet<usize>) -> bool {
+        if !visited.insert(module) {
+            return false;
+        }
+        let info = &self.infos[module];
+        info.exports.contains_key(name)
+            || (name != "default"
+                && info.star_exports.iter().any(|(_, request)| {
+                    self.resolve(module, request)
+                        .is_none_or(|target| self.provides_export(target, name, visited))
+                }))
+    }
+
+    /// Root scope symbols of each module whose calls are free of side effects,
+    /// either declared with `/* #__NO_SIDE_EFFECTS__ */` or imported from such a declaration.
+    fn collect_no_side_effects_symbols(&self, scopings: &[Scoping]) -> Vec<FxHashSet<SymbolId>> {
+        self.infos
+            .iter()
+            .enumerate()
+            .map(|(module, info)| {
+                let scoping = &scopings[module];
+                let mut symbols = scoping
+                    .no_side_effects()
+                    .iter()
+                    .copied()
+                    .filter(|symbol_id| {
+                        scoping.symbol_scope_id(*symbol_id) == scoping.root_scope_id()
+                    })
+                    .collect::<FxHashSet<_>>();
+                for (symbol_id, import) in &info.imports {
+                    let Some(target) = self.resolve(module, &import.request) else { continue };
+                    let Some(import_name) = &import.import_name else { continue };
+                    let resolved =
+                        self.resolve_export(target, import_name, &mut FxHashSet::default());
+                    if let Some((target, target_symbol_id)) = resolved {
+                        if scopings[target].no_side_effects().contains(&target_symbol_id) {
+                            symbols.insert(*symbol_id);
+                        }
+                    }
+                }
+                symbols
+            })
+            .collect()
+    }
+
+    fn run(&self, state: &mut State<'a>) {
+        while let Some(work) = state.worklist.pop() {
+            match work {
+                Work::IncludeModule(module) => self.include_module(module, state),
+                Work::UseExport(module, name) => self.use_export(module, name, state),
+                Work::UseAllExports(module) => self.use_all_exports(module, state),
+                Work::IncludeSymbol(module, symbol_id) => {
+                    self.include_symbol(module, symbol_id, state);
+                }
+                Work::IncludeStatement(module, stmt) => self.include_statement(module, stmt, state),
+            }
+        }
+    }
+
+    fn include_module(&self, module: usize, state: &mut State<'a>) {
+        if std::mem::replace(&mut state.included_modules[module], true) {
+            return;
+        }
+        let info = &self.infos[module];
+        for (stmt, stmt_info) in info.statements.iter().enumerate() {
+            if stmt_info.side_effects {
+                state.worklist.push(Work::IncludeStatement(module, stmt));
+            }
+        }
+        for request in &info.requests {
+            if let Some(target) = self.resolve(module, request) {
+                if self.modules[target].side_effects {
+                    state.worklist.push(Work::IncludeModule(target));
+                }
+            }
+        }
+        for request in &info.dynamic_requests {
+            if let Some(target) = self.resolve(module, request) {
+                state.worklist.push(Work::IncludeModule(target));
+                state.worklist.push(Work::UseAllExports(target));
+            }
+        }
+    }
+
+    fn use_export(&self, module: usize, name: Atom<'a>, state: &mut State<'a>) {
+        if !state.used_exports[module].insert(name) {
+            return;
+        }
+        state.worklist.push(Work::IncludeModule(module));
+        let info = &self.infos[module];
+        match info.exports.get(&name) {
+            Some(ExportTarget::Symbol { symbol_id, stmt }) => {
+                state.worklist.push(Work::IncludeStatement(module, *stmt));
+                state.worklist.push(Work::IncludeSymbol(module, *symbol_id));
+            }
+            Some(ExportTarget::Statement(stmt)) => {
+                state.worklist.push(Work::IncludeStatement(module, *stmt));
+            }
+            Some(ExportTarget::ReExport { stmt, request, import_name }) => {
+                state.worklist.push(Work::IncludeStatement(module, *stmt));
+                if let Some(target) = self.resolve(module, request) {
+                    state.worklist.push(match import_name {
+                        Some(import_name) => Work::UseExport(target, *import_name),
+                        None => Work::UseAllExports(target),
+                    });
+                }
+            }
+            None if name == "default" => {}
+            None => {
+                for (stmt, request) in &info.star_exports {
+                    match self.resolve(module, request) {
+                        Some(target) => {
+                            if self.provides_export(target, &name, &mut FxHashSet::default()) {
+                                state.worklist.push(Work::IncludeStatement(module, *stmt));
+                                state.worklist.push(Work::UseExport(target, name));
+                            }
+                        }
+                        // The name may come from an external module.
+                        None => state.worklist.push(Work::IncludeStatement(module, *stmt)),
+                    }
+                }
+            }
+        }
+    }
+
+    fn use_all_exports(&self, module: usize, state: &mut State<'a>) {
+        if std::mem::replace(&mut state.all_exports_used[module], true) {
+            return;
+        }
+        state.worklist.push(Work::IncludeModule(module));
+        let info = &self.infos[module];
+        for name in info.exports.keys() {
+            state.worklist.push(Work::UseExport(module, *name));
+        }
+        for (stmt, request) in &info.star_exports {
+            state.worklist.push(Work::IncludeStatement(module, *stmt));
+            if let Some(target) = self.resolve(module, request) {
+                state.worklist.push(Work::UseAllExports(target));
+            }
+        }
+    }
+
+    fn include_symbol(&self, module: usize, symbol_id: SymbolId, state: &mut State<'a>) {
+        if !state.included_symbols[module].insert(symbol_id) {
+            return;
+        }
+        let info = &self.infos[module];
+        if let Some(import) = info.imports.get(&symbol_id) {
+            if let Some(target) = self.resolve(module, &import.request) {
+                state.worklist.push(match import.import_name {
+                    Some(import_name) => Work::UseExport(target, import_name),
+                    None => Work::UseAllExports(target),
+                });
+            }
+            return;
+        }
+        if let Some(stmts) = info.declarations.get(&symbol_id) {
+            for stmt in stmts {
+                state.worklist.push(Work::IncludeStatement(module, *stmt));
+            }
+        }
+    }
+
+    fn include_statement(&self, module: usize, stmt: usize, state: &mut State<'a>) {
+        if std::mem::replace(&mut state.included_statements[module][stmt], true) {
+            return;
+        }
+        for symbol_id in &self.infos[module].statements[stmt].references {
+            state.worklist.push(Work::IncludeSymbol(module, *symbol_id));
+        }
+    }
+}
+
+/// Remove statements and exports which are not used by other modules.
+fn shake_module<'a>(
+    allocator: &'a Allocator,
+    program: &mut Program<'a>,
+    included_statements: &[bool],
+    used_exports: &FxHashSet<Atom<'a>>,
+) {
+    let ast = AstBuilder::new(allocator);
+    let body = program.body.take_in(ast);
+    for (stmt, included) in body.into_iter().zip(included_statements) {
+        if !included && !matches!(stmt, Statement::ImportDeclaration(_)) {
+            continue;
+        }
+        if let Some(stmt) = shake_exports(stmt, used_exports, ast) {
+            program.body.push(stmt);
+        }
+    }
+}
+
+fn shake_exports<'a>(
+    stmt: Statement<'a>,
+    used_exports: &FxHashSet<Atom<'a>>,
+    ast: AstBuilder<'a>,
+) -> Option<Statement<'a>> {
+    match stmt {
+        Statement::ExportNamedDeclaration(mut decl) => {
+            if let Some(declaration) = &decl.declaration {
+                let mut names = vec![];
+                declaration.bound_names(&mut |ident| names.push(ident.name));
+                if names.iter().any(|name| used_exports.contains(name)) {
+                    return Some(Statement::ExportNamedDeclaration(decl));
+                }
+                return decl.declaration.take().map(Statement::from);
+            }
+            decl.specifiers.retain(|specifier| used_exports.contains(&specifier.exported.name()));
+            (!decl.specifiers.is_empty()).then_some(Statement::ExportNamedDeclaration(decl))
+        }
+        Statement::ExportAllDeclaration(decl) => match &decl.exported {
+            Some(exported) if !used_exports.contains(&exported.name()) => None,
+            _ => Some(Statement::ExportAllDeclaration(decl)),
+        },
+        Statement::ExportDefaultDeclaration(decl) if !used_exports.contains("default") => {
+            let ExportDefaultDeclaration { span, exported, declaration } = decl.unbox();
+            match declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) if func.id.is_some() => {
+                    Some(Statement::FunctionDeclaration(func))
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) if class.id.is_some() => {
+                    Some(Statement::ClassDeclaration(class))
+                }
+                kind if kind.is_expression() => {
+                    Some(ast.statement_expression(span, kind.into_expression()))
+                }
+                declaration => Some(Statement::ExportDefaultDeclaration(
+                    ast.alloc_export_default_declaration(span, exported, declaration),
+                )),
+            }
+        }
+        stmt => Some(stmt),
+    }
+}
+
+/// Remove import specifiers left unused after dead code elimination.
+///
+/// Imports of external modules are left untouched.
+fn remove_unused_imports(
+    modules: &mut [TreeShakeModule<'_, '_>],
+    index: usize,
+    included_modules: &[bool],
+) {
+    let scoping = SemanticBuilder::new().build(modules[index].program).semantic.into_scoping();
+    let resolved_requests = &modules[index].resolved_requests;
+    let keep_bare_import = |request: &str| {
+        resolved_requests
+            .get(request)
+            .is_none_or(|target| included_modules[*target] && modules[*target].side_effects)
+    };
+    let mut retained = vec![];
+    for stmt in &modules[index].program.body {
+        let Statement::ImportDeclaration(decl) = stmt else {
+            retained.push(None);
+            continue;
+        };
+        if !resolved_requests.contains_key(decl.source.value.as_str()) {
+            retained.push(None);
+            continue;
+        }
+        let used = decl.specifiers.as_ref().map(|specifiers| {
+            specifiers
+                .iter()
+                .map(|specifier| !scoping.symbol_is_unused(specifier.local().symbol_id()))
+                .collect::<Vec<_>>()
+        });
+        let keep = used.as_ref().is_some_and(|used| used.contains(&true))
+            || keep_bare_import(decl.source.value.as_str());
+        retained.push(Some((keep, used)));
+    }
+
+    let program = &mut *modules[index].program;
+    let mut retained = retained.into_iter();
+    program.body.retain_mut(|stmt| {
+        let Some((keep, used)) = retained.next().flatten() else { return true };
+        if !keep {
+            return false;
+        }
+        if let (Statement::ImportDeclaration(decl), Some(used)) = (stmt, used) {
+            let mut used = used.into_iter();
+            if let Some(specifiers) = &mut decl.specifiers {
+                specifiers.retain(|_| used.next().unwrap_or(true));
+                if specifiers.is_empty() {
+                    decl.specifiers = None;
+                }
+            }
+        }
+        true
+    });
+}
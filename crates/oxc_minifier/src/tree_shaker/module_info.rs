@@ -0,0 +1,328 @@
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_ecmascript::{
+    is_global_reference::IsGlobalReference,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference as _, Scoping, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::TreeShakeOptions;
+
+/// What an export name of a module points to.
+#[derive(Debug, Clone)]
+pub enum ExportTarget<'a> {
+    /// `export { a }`, `export const a = 1`, `export default function a() {}`
+    Symbol { symbol_id: SymbolId, stmt: usize },
+    /// `export default expr`, `export default function() {}`
+    Statement(usize),
+    /// `export { a } from 'mod'`, `export * as ns from 'mod'`
+    ReExport { stmt: usize, request: Atom<'a>, import_name: Option<Atom<'a>> },
+}
+
+/// How a top-level import binding is imported.
+#[derive(Debug, Clone)]
+pub struct ImportBinding<'a> {
+    pub request: Atom<'a>,
+    /// `None` for `import * as ns from 'mod'`.
+    pub import_name: Option<Atom<'a>>,
+}
+
+#[derive(Debug, Default)]
+pub struct StatementInfo {
+    /// Root scope symbols referenced by this statement.
+    pub references: Vec<SymbolId>,
+    pub side_effects: bool,
+}
+
+/// Per-module information collected before tree shaking.
+#[derive(Debug)]
+pub struct ModuleInfo<'a> {
+    pub statements: Vec<StatementInfo>,
+    /// Statements declaring each root scope symbol.
+    pub declarations: FxHashMap<SymbolId, Vec<usize>>,
+    pub imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+    pub exports: FxHashMap<Atom<'a>, ExportTarget<'a>>,
+    /// `export * from 'mod'`
+    pub star_exports: Vec<(usize, Atom<'a>)>,
+    /// Requests of import and re-export statements.
+    pub requests: Vec<Atom<'a>>,
+    /// Requests of `import('mod')` with a string literal specifier.
+    pub dynamic_requests: Vec<&'a str>,
+}
+
+impl<'a> ModuleInfo<'a> {
+    pub fn new(program: &Program<'a>, module_record: &ModuleRecord<'a>, scoping: &Scoping) -> Self {
+        let stmt_spans = program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+        let find_stmt = |span: Span| {
+            let index = stmt_spans.partition_point(|s| s.end < span.end);
+            (index < stmt_spans.len() && stmt_spans[index].start <= span.start).then_some(index)
+        };
+
+        let root_scope_id = scoping.root_scope_id();
+        let mut declarations = FxHashMap::<SymbolId, Vec<usize>>::default();
+        for symbol_id in scoping.symbol_ids() {
+            if scoping.symbol_scope_id(symbol_id) != root_scope_id {
+                continue;
+            }
+            let spans = std::iter::once(scoping.symbol_span(symbol_id))
+                .chain(scoping.symbol_redeclarations(symbol_id).iter().map(|r| r.span));
+            for span in spans {
+                if let Some(stmt) = find_stmt(span) {
+                    let stmts = declarations.entry(symbol_id).or_default();
+                    if !stmts.contains(&stmt) {
+                        stmts.push(stmt);
+                    }
+                }
+            }
+        }
+
+        let mut imports = FxHashMap::default();
+        for entry in &module_record.import_entries {
+            let Some(symbol_id) = scoping.get_root_binding(&entry.local_name.name) else {
+                continue;
+            };
+            let import_name = match &entry.import_name {
+                ImportImportName::Name(name) => Some(name.name),
+                ImportImportName::Default(_) => Some(Atom::from("default")),
+                ImportImportName::NamespaceObject => None,
+            };
+            imports.insert(
+                symbol_id,
+                ImportBinding { request: entry.module_request.name, import_name },
+            );
+        }
+
+        let mut exports = FxHashMap::default();
+        for entry in &module_record.local_export_entries {
+            let Some(stmt) = find_stmt(entry.statement_span) else { continue };
+            let Some(export_name) = export_name(&entry.export_name) else { continue };
+            let target = match &entry.local_name {
+                ExportLocalName::Name(name) => match scoping.get_root_binding(&name.name) {
+                    Some(symbol_id) => ExportTarget::Symbol { symbol_id, stmt },
+                    None => ExportTarget::Statement(stmt),
+                },
+                ExportLocalName::Default(_) | ExportLocalName::Null => {
+                    ExportTarget::Statement(stmt)
+                }
+            };
+            exports.insert(export_name, target);
+        }
+        for entry in &module_record.indirect_export_entries {
+            let Some(stmt) = find_stmt(entry.statement_span) else { continue };
+            let Some(export_name) = export_name(&entry.export_name) else { continue };
+            let Some(request) = &entry.module_request else { continue };
+            let import_name = match &entry.import_name {
+                ExportImportName::Name(name) => Some(name.name),
+                _ => None,
+            };
+            exports.insert(
+                export_name,
+                ExportTarget::ReExport { stmt, request: request.name, import_name },
+            );
+        }
+        let star_exports = module_record
+            .star_export_entries
+            .iter()
+            .filter_map(|entry| {
+                let stmt = find_stmt(entry.statement_span)?;
+                Some((stmt, entry.module_request.as_ref()?.name))
+            })
+            .collect();
+
+        let mut requests = module_record.requested_modules.keys().copied().collect::<Vec<_>>();
+        requests.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        let dynamic_requests = module_record
+            .dynamic_imports
+            .iter()
+            .filter_map(|import| {
+                let text = import.module_request.source_text(program.source_text);
+                let quote = text.chars().next()?;
+                if !matches!(quote, '"' | '\'') || text.len() < 2 || !text.ends_with(quote) {
+                    return None;
+                }
+                Some(&text[1..text.len() - 1])
+            })
+            .collect();
+
+        Self {
+            statements: Vec::new(),
+            declarations,
+            imports,
+            exports,
+            star_exports,
+            requests,
+            dynamic_requests,
+        }
+    }
+
+    /// Collect references and side effects of every top-level statement.
+    pub fn collect_statements(
+        &mut self,
+        program: &Program<'a>,
+        scoping: &Scoping,
+        options: &TreeShakeOptions,
+    ) {
+        let ctx = SideEffectsContext { scoping, options };
+        self.statements = program
+            .body
+            .iter()
+            .map(|stmt| {
+                let references = match stmt {
+                    // References of `export { a }` are followed through the export table.
+                    Statement::ExportNamedDeclaration(decl) if decl.declaration.is_none() => {
+                        vec![]
+                    }
+                    Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => {
+                        vec![]
+                    }
+                    _ => {
+                        let mut collector = ReferenceCollector { scoping, references: vec![] };
+                        collector.visit_statement(stmt);
+                        collector.references
+                    }
+                };
+                StatementInfo { references, side_effects: statement_has_side_effects(stmt, &ctx) }
+            })
+            .collect();
+    }
+}
+
+fn export_name<'a>(export_name: &ExportExportName<'a>) -> Option<Atom<'a>> {
+    match export_name {
+        ExportExportName::Name(name) => Some(name.name),
+        ExportExportName::Default(_) => Some(Atom::from("default")),
+        ExportExportName::Null => None,
+    }
+}
+
+struct ReferenceCollector<'s> {
+    scoping: &'s Scoping,
+    references: Vec<SymbolId>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if self.scoping.symbol_scope_id(symbol_id) == self.scoping.root_scope_id()
+            && !self.references.contains(&symbol_id)
+        {
+            self.references.push(symbol_id);
+        }
+    }
+}
+
+/// Set `pure` on calls to functions annotated with `/* #__NO_SIDE_EFFECTS__ */`,
+/// including functions imported from other modules.
+pub struct NoSideEffectsMarker<'s> {
+    pub scoping: &'s Scoping,
+    pub symbols: &'s FxHashSet<SymbolId>,
+}
+
+impl NoSideEffectsMarker<'_> {
+    fn is_no_side_effects_callee(&self, callee: &Expression) -> bool {
+        let Expression::Identifier(ident) = callee else { return false };
+        self.scoping
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.symbols.contains(&symbol_id))
+    }
+}
+
+impl<'a> VisitMut<'a> for NoSideEffectsMarker<'_> {
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        if self.is_no_side_effects_callee(&call.callee) {
+            call.pure = true;
+        }
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_new_expression(&mut self, new_expr: &mut NewExpression<'a>) {
+        if self.is_no_side_effects_callee(&new_expr.callee) {
+            new_expr.pure = true;
+        }
+        walk_mut::walk_new_expression(self, new_expr);
+    }
+}
+
+struct SideEffectsContext<'s> {
+    scoping: &'s Scoping,
+    options: &'s TreeShakeOptions,
+}
+
+impl<'a> IsGlobalReference<'a> for SideEffectsContext<'_> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> Option<bool> {
+        Some(ident.is_global_reference(self.scoping))
+    }
+}
+
+impl MayHaveSideEffectsContext<'_> for SideEffectsContext<'_> {
+    fn annotations(&self) -> bool {
+        self.options.annotations
+    }
+
+    fn manual_pure_functions(&self, callee: &Expression) -> bool {
+        if let Expression::Identifier(ident) = callee {
+            return self.options.manual_pure_functions.iter().any(|name| ident.name == name);
+        }
+        false
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        self.options.property_read_side_effects
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        self.options.unknown_global_side_effects
+    }
+}
+
+fn statement_has_side_effects(stmt: &Statement<'_>, ctx: &SideEffectsContext<'_>) -> bool {
+    match stmt {
+        Statement::EmptyStatement(_)
+        | Statement::FunctionDeclaration(_)
+        | Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSTypeAliasDeclaration(_)
+        | Statement::TSInterfaceDeclaration(_) => false,
+        Statement::ExpressionStatement(stmt) => stmt.expression.may_have_side_effects(ctx),
+        Statement::ClassDeclaration(class) => class.may_have_side_effects(ctx),
+        Statement::VariableDeclaration(decl) => variable_has_side_effects(decl, ctx),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            None
+            | Some(
+                Declaration::FunctionDeclaration(_)
+                | Declaration::TSTypeAliasDeclaration(_)
+                | Declaration::TSInterfaceDeclaration(_),
+            ) => false,
+            Some(Declaration::ClassDeclaration(class)) => class.may_have_side_effects(ctx),
+            Some(Declaration::VariableDeclaration(decl)) => variable_has_side_effects(decl, ctx),
+            Some(_) => true,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(_)
+            | ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => false,
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                class.may_have_side_effects(ctx)
+            }
+            kind => kind.as_expression().is_none_or(|expr| expr.may_have_side_effects(ctx)),
+        },
+        _ => true,
+    }
+}
+
+fn variable_has_side_effects(decl: &VariableDeclaration<'_>, ctx: &SideEffectsContext<'_>) -> bool {
+    if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing) {
+        return true;
+    }
+    decl.declarations.iter().any(|declarator| {
+        !declarator.id.kind.is_binding_identifier()
+            || declarator.init.as_ref().is_some_and(|init| init.may_have_side_effects(ctx))
+    })
+}
@@ -0,0 +1,94 @@
+/// The `sideEffects` field of a `package.json`.
+///
+/// <https://webpack.js.org/guides/tree-shaking/#mark-the-file-as-side-effect-free>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSideEffects {
+    /// `"sideEffects": true` or `"sideEffects": false`.
+    All(bool),
+    /// `"sideEffects": ["./src/polyfill.js", "*.css"]`.
+    Globs(Vec<String>),
+}
+
+impl Default for PackageSideEffects {
+    /// Modules are assumed to have side effects when the field is missing.
+    fn default() -> Self {
+        Self::All(true)
+    }
+}
+
+impl PackageSideEffects {
+    /// Whether the module at `path` (relative to the package root) may have side effects.
+    ///
+    /// Patterns without a `/` match the file name in any directory, following webpack.
+    pub fn has_side_effects(&self, path: &str) -> bool {
+        match self {
+            Self::All(value) => *value,
+            Self::Globs(globs) => {
+                let path = normalize_path(path);
+                globs.iter().any(|glob| {
+                    let glob = normalize_path(glob);
+                    if glob.contains('/') {
+                        glob_match(glob.as_bytes(), path.as_bytes())
+                    } else {
+                        let file_name = path.rsplit('/').next().unwrap_or(path);
+                        glob_match(glob.as_bytes(), file_name.as_bytes())
+                    }
+                })
+            }
+        }
+    }
+}
+
+fn normalize_path(path: &str) -> &str {
+    path.trim_start_matches("./").trim_start_matches('/')
+}
+
+/// Match `text` against a glob `pattern` supporting `*`, `**` and `?`.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', b'/', rest @ ..] => {
+            // `**/` matches zero or more directories.
+            glob_match(rest, text)
+                || text
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| **c == b'/')
+                    .any(|(i, _)| glob_match(rest, &text[i + 1..]))
+        }
+        [b'*', b'*', rest @ ..] => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        [b'*', rest @ ..] => {
+            let segment_len = text.iter().position(|c| *c == b'/').unwrap_or(text.len());
+            (0..=segment_len).any(|i| glob_match(rest, &text[i..]))
+        }
+        [b'?', rest @ ..] => matches!(text, [c, ..] if *c != b'/') && glob_match(rest, &text[1..]),
+        [c, rest @ ..] => matches!(text, [t, ..] if t == c) && glob_match(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PackageSideEffects;
+
+    #[test]
+    fn all() {
+        assert!(PackageSideEffects::All(true).has_side_effects("src/index.js"));
+        assert!(!PackageSideEffects::All(false).has_side_effects("src/index.js"));
+    }
+
+    #[test]
+    fn globs() {
+        let side_effects = PackageSideEffects::Globs(vec![
+            "*.css".to_string(),
+            "./src/polyfill.js".to_string(),
+            "./src/global/**/*.js".to_string(),
+        ]);
+        assert!(side_effects.has_side_effects("./style.css"));
+        assert!(side_effects.has_side_effects("src/components/button.css"));
+        assert!(side_effects.has_side_effects("src/polyfill.js"));
+        assert!(side_effects.has_side_effects("src/global/setup.js"));
+        assert!(side_effects.has_side_effects("src/global/a/b/setup.js"));
+        assert!(!side_effects.has_side_effects("src/index.js"));
+        assert!(!side_effects.has_side_effects("src/global/setup.ts"));
+    }
+}
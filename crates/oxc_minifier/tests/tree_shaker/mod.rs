@@ -0,0 +1,147 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_minifier::{CompressOptions, TreeShakeModule, TreeShaker};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashMap;
+
+/// Tree shake `modules` (`(specifier, side_effects, source)`) with the first module as entry.
+/// Every module can import the others by their specifier.
+#[track_caller]
+fn test(modules: &[(&str, bool, &str)], expected: &[&str]) {
+    let allocator = Allocator::default();
+    let mut rets = modules
+        .iter()
+        .map(|(_, _, source_text)| {
+            let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+            assert!(ret.errors.is_empty(), "{source_text}");
+            ret
+        })
+        .collect::<Vec<_>>();
+    let resolved_requests = modules
+        .iter()
+        .enumerate()
+        .map(|(index, (specifier, _, _))| ((*specifier).to_string(), index))
+        .collect::<FxHashMap<_, _>>();
+    let mut tree_shake_modules = rets
+        .iter_mut()
+        .zip(modules)
+        .map(|(ret, (_, side_effects, _))| TreeShakeModule {
+            program: &mut ret.program,
+            module_record: &ret.module_record,
+            side_effects: *side_effects,
+            resolved_requests: resolved_requests.clone(),
+        })
+        .collect::<Vec<_>>();
+    TreeShaker::new(CompressOptions::dce()).build(&allocator, &mut tree_shake_modules, &[0]);
+
+    for ((module, (specifier, _, _)), expected) in
+        tree_shake_modules.iter().zip(modules).zip(expected)
+    {
+        let result = Codegen::new().build(module.program).code;
+        let expected = Codegen::new()
+            .build(&Parser::new(&allocator, expected, SourceType::mjs()).parse().program)
+            .code;
+        assert_eq!(result, expected, "\nfor module {specifier}\nexpect\n{expected}\ngot\n{result}");
+    }
+}
+
+#[test]
+fn unused_exports() {
+    test(
+        &[
+            ("./entry", true, "import { a } from './lib'; console.log(a());"),
+            (
+                "./lib",
+                true,
+                "export function a() { return b() } function b() { return 1 } export function c() {} export const d = 1;",
+            ),
+        ],
+        &[
+            "import { a } from './lib'; console.log(a());",
+            "export function a() { return b() } function b() { return 1 }",
+        ],
+    );
+}
+
+#[test]
+fn re_exports() {
+    test(
+        &[
+            ("./entry", true, "import { a, ns } from './index'; console.log(a, ns.b);"),
+            (
+                "./index",
+                false,
+                "export { a, c } from './a'; export * from './b'; export * as ns from './c';",
+            ),
+            ("./a", false, "export const a = 1; export const c = 2;"),
+            ("./b", false, "export const unused = 1;"),
+            ("./c", false, "export const b = 1; export const c = 2;"),
+        ],
+        &[
+            "import { a, ns } from './index'; console.log(a, ns.b);",
+            "export { a } from './a'; export * as ns from './c';",
+            "export const a = 1;",
+            "",
+            "export const b = 1; export const c = 2;",
+        ],
+    );
+}
+
+#[test]
+fn side_effects() {
+    test(
+        &[
+            ("./entry", true, "import './polyfill'; import './pure'; import { a } from './lib';"),
+            ("./polyfill", true, "globalThis.foo = 1; export const unused = 1;"),
+            ("./pure", false, "globalThis.bar = 1;"),
+            ("./lib", true, "export const a = foo(); export const b = 1;"),
+        ],
+        &["import './polyfill'; import './lib';", "globalThis.foo = 1;", "", "foo();"],
+    );
+}
+
+#[test]
+fn no_side_effects_annotation() {
+    test(
+        &[
+            (
+                "./entry",
+                true,
+                "import { create } from './lib'; const x = create(); export const y = create();",
+            ),
+            ("./lib", false, "/* #__NO_SIDE_EFFECTS__ */ export function create() { return {} }"),
+        ],
+        &[
+            "import { create } from './lib'; export const y = /* @__PURE__ */ create();",
+            "/* #__NO_SIDE_EFFECTS__ */ export function create() { return {} }",
+        ],
+    );
+}
+
+#[test]
+fn default_exports() {
+    test(
+        &[
+            ("./entry", true, "import foo from './a'; import './b'; foo();"),
+            ("./a", false, "export default function foo() {}"),
+            ("./b", true, "export default function bar() {} bar();"),
+        ],
+        &[
+            "import foo from './a'; import './b'; foo();",
+            "export default function foo() {}",
+            "function bar() {} bar();",
+        ],
+    );
+}
+
+#[test]
+fn dynamic_import() {
+    test(
+        &[
+            ("./entry", true, "import('./lazy');"),
+            ("./lazy", false, "export const a = 1; const b = 2;"),
+        ],
+        &["import('./lazy');", "export const a = 1;"],
+    );
+}
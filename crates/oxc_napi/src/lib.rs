@@ -17,6 +17,18 @@ pub fn convert_utf8_to_utf16(
     errors: &mut [OxcError],
 ) -> Vec<Comment> {
     let span_converter = Utf8ToUtf16::new(source_text);
+    convert_utf8_to_utf16_with(&span_converter, source_text, program, module_record, errors)
+}
+
+/// Convert spans to UTF-16 with `span_converter`, which can be reused to convert other spans
+/// of `source_text`.
+pub fn convert_utf8_to_utf16_with(
+    span_converter: &Utf8ToUtf16,
+    source_text: &str,
+    program: &mut Program,
+    module_record: &mut ModuleRecord,
+    errors: &mut [OxcError],
+) -> Vec<Comment> {
     span_converter.convert_program(program);
 
     // Convert comments
@@ -17,7 +17,8 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
-    fatal_error: Option<FatalError>,
+    tokens_pos: usize,
+    fatal_error: Option<FatalError<'a>>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -101,6 +102,7 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token(kind);
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
@@ -108,6 +110,7 @@ impl<'a> ParserImpl<'a> {
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.collect_token(self.cur_kind());
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
@@ -261,18 +264,26 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_pos: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            tokens_pos,
+            fatal_error,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.tokens.truncate(tokens_pos);
         self.fatal_error = fatal_error;
     }
 
@@ -282,7 +293,7 @@ impl<'a> ParserImpl<'a> {
     ) -> Option<T> {
         let checkpoint = self.checkpoint();
         let ctx = self.ctx;
-        let node = func(self);
+        let node = self.without_recovery(func);
         if self.fatal_error.is_none() {
             Some(node)
         } else {
@@ -294,7 +305,7 @@ impl<'a> ParserImpl<'a> {
 
     pub(crate) fn lookahead<U>(&mut self, predicate: impl Fn(&mut ParserImpl<'a>) -> U) -> U {
         let checkpoint = self.checkpoint();
-        let answer = predicate(self);
+        let answer = self.without_recovery(predicate);
         self.rewind(checkpoint);
         answer
     }
@@ -32,6 +32,17 @@ pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
 }
 
+#[cold]
+pub fn flow_write_only_property(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Flow write-only properties are not supported")
+        .with_label(span.label("Remove this `-` variance sigil"))
+}
+
+#[cold]
+pub fn flow_unsupported(x0: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Flow `{x0}` is not supported")).with_label(span)
+}
+
 #[cold]
 pub fn unexpected_token(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected token").with_label(span)
@@ -1,17 +1,267 @@
 //! Code related to error handling.
 
+use std::mem;
+
 use oxc_allocator::Dummy;
+use oxc_ast::{AstBuilder, NONE, ast::*, match_declaration};
+use oxc_ast_visit::{VisitMut, walk_mut};
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
-use crate::{ParserImpl, diagnostics, lexer::Kind};
+use crate::{
+    ParserImpl, diagnostics,
+    lexer::{Kind, LexerCheckpoint, Token},
+};
 
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
-pub struct FatalError {
+pub struct FatalError<'a> {
     /// The fatal error
     pub error: OxcDiagnostic,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// Lexer state at time fatal error is recorded, to resume parsing from in error-recovering mode
+    lexer: LexerCheckpoint<'a>,
+    /// The token at which the fatal error was recorded
+    token: Token,
+    /// End of the last token parsed before the fatal error
+    prev_token_end: u32,
+}
+
+/// List element which the parser can recover from a fatal error in,
+/// see [`ParseOptions::recover_from_errors`](crate::ParseOptions::recover_from_errors).
+pub trait Recoverable<'a>: GetSpan {
+    /// Kind of the list
+    const LIST: RecoveryList;
+
+    /// Fix up the partially parsed element.
+    fn fix(&mut self, fixer: &mut PartialNodeFixer<'a>);
+}
+
+/// Kind of list the parser recovers in, which decides where the next element may start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryList {
+    /// Statements of a program, block, function body or switch case
+    Statements,
+    /// Elements of a class body
+    ClassElements,
+    /// Properties of an object literal
+    ObjectProperties,
+}
+
+impl RecoveryList {
+    /// Whether a token of `kind` at the top nesting level ends the skipped source text.
+    /// Returns `Some(true)` if the token belongs to the skipped source text.
+    fn sync_token(self, kind: Kind) -> Option<bool> {
+        match (self, kind) {
+            (Self::Statements, Kind::Semicolon) => Some(true),
+            (Self::ClassElements, Kind::Semicolon) | (Self::ObjectProperties, Kind::Comma) => {
+                Some(false)
+            }
+            (_, Kind::RCurly | Kind::Eof) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Whether a token of `kind` may start the next element, when on a new line.
+    fn starts_element(self, kind: Kind) -> bool {
+        if kind.is_identifier_name() {
+            return true;
+        }
+        match self {
+            Self::Statements => kind == Kind::At,
+            Self::ClassElements => matches!(kind, Kind::At | Kind::PrivateIdentifier),
+            Self::ObjectProperties => matches!(kind, Kind::Dot3 | Kind::Str),
+        }
+    }
+}
+
+impl<'a> Recoverable<'a> for Statement<'a> {
+    const LIST: RecoveryList = RecoveryList::Statements;
+
+    fn fix(&mut self, fixer: &mut PartialNodeFixer<'a>) {
+        fixer.visit_statement(self);
+    }
+}
+
+impl<'a> Recoverable<'a> for ClassElement<'a> {
+    const LIST: RecoveryList = RecoveryList::ClassElements;
+
+    fn fix(&mut self, fixer: &mut PartialNodeFixer<'a>) {
+        fixer.visit_class_element(self);
+    }
+}
+
+impl<'a> Recoverable<'a> for ObjectPropertyKind<'a> {
+    const LIST: RecoveryList = RecoveryList::ObjectProperties;
+
+    fn fix(&mut self, fixer: &mut PartialNodeFixer<'a>) {
+        fixer.visit_object_property_kind(self);
+    }
+}
+
+/// Fixes up a node which was partially parsed before a fatal error.
+///
+/// Nodes which could not be parsed are [`Dummy`] nodes without a span, and nodes which were
+/// completed after the error may extend to the end of the file. Spans are clamped to the source
+/// text parsed before the error, so the placeholder nodes sit at the position of the error,
+/// and dummy statements (`debugger`) are replaced with empty statements.
+///
+/// Identifiers which could not be parsed have an empty name. They are replaced with placeholders
+/// which declare and reference nothing (`null`, `{}` and `unknown`), or removed from their list,
+/// so semantic analysis does not create symbols or references for them.
+pub struct PartialNodeFixer<'a> {
+    ast: AstBuilder<'a>,
+    /// End of the last token parsed before the fatal error
+    end: u32,
+}
+
+impl<'a> VisitMut<'a> for PartialNodeFixer<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        if span.is_unspanned() {
+            *span = Span::empty(self.end);
+        } else {
+            span.start = span.start.min(self.end);
+            span.end = span.end.min(self.end);
+        }
+    }
+
+    fn visit_statement(&mut self, it: &mut Statement<'a>) {
+        let is_placeholder = match it {
+            Statement::DebuggerStatement(debugger) => debugger.span.is_unspanned(),
+            // Imports and exports without a module source
+            Statement::ImportDeclaration(decl) => decl.source.span.is_unspanned(),
+            Statement::ExportAllDeclaration(decl) => decl.source.span.is_unspanned(),
+            Statement::ExportNamedDeclaration(decl) => {
+                decl.source.as_ref().is_some_and(|source| source.span.is_unspanned())
+            }
+            match_declaration!(Statement) => is_unnamed_declaration(it.to_declaration()),
+            _ => false,
+        };
+        if is_placeholder {
+            *it = self.ast.statement_empty(Span::empty(self.end));
+            return;
+        }
+        walk_mut::walk_statement(self, it);
+    }
+
+    fn visit_export_named_declaration(&mut self, it: &mut ExportNamedDeclaration<'a>) {
+        if it.declaration.as_ref().is_some_and(is_unnamed_declaration) {
+            it.declaration = None;
+        }
+        it.specifiers.retain(|specifier| {
+            !matches!(&specifier.local, ModuleExportName::IdentifierReference(ident) if ident.name.is_empty())
+        });
+        walk_mut::walk_export_named_declaration(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &mut Expression<'a>) {
+        let is_placeholder = match it {
+            Expression::Identifier(ident) => ident.name.is_empty(),
+            Expression::UpdateExpression(update) => matches!(
+                &update.argument,
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) if ident.name.is_empty()
+            ),
+            _ => false,
+        };
+        if is_placeholder {
+            *it = self.ast.expression_null_literal(Span::empty(self.end));
+            return;
+        }
+        walk_mut::walk_expression(self, it);
+    }
+
+    fn visit_assignment_target(&mut self, it: &mut AssignmentTarget<'a>) {
+        if matches!(it, AssignmentTarget::AssignmentTargetIdentifier(ident) if ident.name.is_empty())
+        {
+            *it =
+                AssignmentTarget::from(self.ast.assignment_target_pattern_array_assignment_target(
+                    Span::empty(self.end),
+                    self.ast.vec(),
+                    None,
+                ));
+            return;
+        }
+        walk_mut::walk_assignment_target(self, it);
+    }
+
+    fn visit_binding_pattern(&mut self, it: &mut BindingPattern<'a>) {
+        if matches!(&it.kind, BindingPatternKind::BindingIdentifier(ident) if ident.name.is_empty())
+        {
+            it.kind = self.ast.binding_pattern_kind_object_pattern(
+                Span::empty(self.end),
+                self.ast.vec(),
+                NONE,
+            );
+        }
+        walk_mut::walk_binding_pattern(self, it);
+    }
+
+    fn visit_function(&mut self, it: &mut Function<'a>, flags: ScopeFlags) {
+        if it.id.as_ref().is_some_and(|id| id.name.is_empty()) {
+            it.id = None;
+        }
+        walk_mut::walk_function(self, it, flags);
+    }
+
+    fn visit_class(&mut self, it: &mut Class<'a>) {
+        if it.id.as_ref().is_some_and(|id| id.name.is_empty()) {
+            it.id = None;
+        }
+        walk_mut::walk_class(self, it);
+    }
+
+    fn visit_import_declaration(&mut self, it: &mut ImportDeclaration<'a>) {
+        if let Some(specifiers) = &mut it.specifiers {
+            specifiers.retain(|specifier| !specifier.local().name.is_empty());
+        }
+        walk_mut::walk_import_declaration(self, it);
+    }
+
+    fn visit_ts_type_parameter_declaration(&mut self, it: &mut TSTypeParameterDeclaration<'a>) {
+        it.params.retain(|param| !param.name.name.is_empty());
+        walk_mut::walk_ts_type_parameter_declaration(self, it);
+    }
+
+    fn visit_ts_type(&mut self, it: &mut TSType<'a>) {
+        if matches!(
+            it,
+            TSType::TSTypeReference(reference) if matches!(
+                &reference.type_name,
+                TSTypeName::IdentifierReference(ident) if ident.name.is_empty()
+            )
+        ) {
+            *it = self.ast.ts_type_unknown_keyword(Span::empty(self.end));
+            return;
+        }
+        walk_mut::walk_ts_type(self, it);
+    }
+}
+
+/// Whether a TypeScript declaration has a name (or `import =` reference) which could not be parsed.
+/// Classes and functions without a name are kept, see [`PartialNodeFixer::visit_class`].
+fn is_unnamed_declaration(declaration: &Declaration<'_>) -> bool {
+    let id = match declaration {
+        Declaration::TSTypeAliasDeclaration(decl) => &decl.id,
+        Declaration::TSInterfaceDeclaration(decl) => &decl.id,
+        Declaration::TSEnumDeclaration(decl) => &decl.id,
+        Declaration::TSImportEqualsDeclaration(decl) => {
+            if matches!(
+                &decl.module_reference,
+                TSModuleReference::IdentifierReference(ident) if ident.name.is_empty()
+            ) {
+                return true;
+            }
+            &decl.id
+        }
+        Declaration::TSModuleDeclaration(decl) => match &decl.id {
+            TSModuleDeclarationName::Identifier(id) => id,
+            TSModuleDeclarationName::StringLiteral(_) => return false,
+        },
+        _ => return false,
+    };
+    id.name.is_empty()
 }
 
 impl<'a> ParserImpl<'a> {
@@ -54,8 +304,15 @@ impl<'a> ParserImpl<'a> {
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: OxcDiagnostic) {
         if self.fatal_error.is_none() {
+            let lexer = self.lexer.checkpoint();
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error = Some(FatalError {
+                error,
+                errors_len: self.errors.len(),
+                lexer,
+                token: self.token,
+                prev_token_end: self.prev_token_end,
+            });
         }
     }
 
@@ -68,4 +325,319 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn has_fatal_error(&self) -> bool {
         matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) || self.fatal_error.is_some()
     }
+
+    /// Run `f` without recovering from fatal errors.
+    ///
+    /// Speculative parsing relies on fatal errors to detect that it took the wrong path,
+    /// which error recovery would hide.
+    pub(crate) fn without_recovery<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let recover_from_errors = mem::replace(&mut self.recover_from_errors, false);
+        let result = f(self);
+        self.recover_from_errors = recover_from_errors;
+        result
+    }
+
+    /// Recover from the fatal error raised while parsing list `element`, which started at `start`.
+    ///
+    /// The error is reported, and the parser moves back to the token where the error occurred,
+    /// then skips tokens until the next element or the end of the list.
+    /// `element` is kept with the part parsed before the error, see [`PartialNodeFixer`].
+    ///
+    /// Returns `false` if nothing of `element` could be parsed, and it should be dropped.
+    /// Does nothing and returns `true` if error recovery is disabled.
+    #[cold]
+    pub(crate) fn recover<T: Recoverable<'a>>(&mut self, element: &mut T, start: u32) -> bool {
+        if !self.recover_from_errors {
+            return true;
+        }
+        let Some(fatal_error) = self.fatal_error.take() else { return true };
+
+        self.errors.truncate(fatal_error.errors_len);
+        // The lexer has already reported an error for the end of file, same as in `parse`.
+        if !fatal_error.token.kind().is_eof() || self.lexer.errors.is_empty() {
+            self.error(fatal_error.error);
+        }
+
+        self.lexer.rewind(fatal_error.lexer);
+        self.token = fatal_error.token;
+        self.prev_token_end = fatal_error.prev_token_end;
+
+        let mut fixer = PartialNodeFixer { ast: self.ast, end: fatal_error.prev_token_end };
+        element.fix(&mut fixer);
+        let parsed = !element.span().is_empty();
+
+        // Always make progress. The unexpected token may be directly followed by the next element.
+        if self.cur_token().start() == start && !self.cur_kind().is_eof() {
+            self.bump_any();
+            if T::LIST.starts_element(self.cur_kind()) {
+                return parsed;
+            }
+        }
+        self.skip_to_next_element(T::LIST);
+        parsed
+    }
+
+    /// Skip tokens until the next element of `list` may start, or `list` ends.
+    /// Brackets and template literals are skipped as a whole.
+    fn skip_to_next_element(&mut self, list: RecoveryList) {
+        let mut open = vec![];
+        loop {
+            let kind = self.cur_kind();
+            if open.is_empty() {
+                if let Some(skip) = list.sync_token(kind) {
+                    if skip {
+                        self.bump_any();
+                    }
+                    return;
+                }
+                if self.cur_token().is_on_new_line() && list.starts_element(kind) {
+                    return;
+                }
+            }
+            match kind {
+                Kind::Eof => return,
+                Kind::LCurly | Kind::LParen | Kind::LBrack | Kind::TemplateHead => open.push(kind),
+                Kind::RCurly if open.last() == Some(&Kind::TemplateHead) => {
+                    // `}` ends a substitution, continue with the rest of the template literal
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateTail) {
+                        open.pop();
+                    }
+                }
+                Kind::RCurly | Kind::RParen | Kind::RBrack => {
+                    open.pop();
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{
+        BindingIdentifier, ClassElement, IdentifierReference, ObjectPropertyKind, Statement,
+    };
+    use oxc_ast_visit::Visit;
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use crate::{ParseOptions, Parser, ParserReturn};
+
+    fn parse<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> ParserReturn<'a> {
+        let options = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        Parser::new(allocator, source_text, source_type).with_options(options).parse()
+    }
+
+    fn errors(ret: &ParserReturn<'_>) -> Vec<String> {
+        ret.errors.iter().map(ToString::to_string).collect()
+    }
+
+    fn sources<'a, T: GetSpan>(nodes: &[T], source_text: &'a str) -> Vec<&'a str> {
+        nodes.iter().map(|node| node.span().source_text(source_text)).collect()
+    }
+
+    #[test]
+    fn statements() {
+        let allocator = Allocator::default();
+        let source_text = "let a = ;\nfoo(a b);\nif (a) { a = 1 2 }\n) bar();";
+        let ret = parse(&allocator, source_text, SourceType::default());
+        assert!(!ret.panicked);
+        assert_eq!(
+            errors(&ret),
+            [
+                "Unexpected token",
+                "Expected `,` but found `Identifier`",
+                "Expected a semicolon or an implicit semicolon after a statement, but found none",
+                "Unexpected token",
+            ]
+        );
+        assert_eq!(
+            sources(&ret.program.body, source_text),
+            ["let a =", "foo(a", "if (a) { a = 1 2 }", "bar();"]
+        );
+
+        // The missing initializer is a placeholder at the position of the error
+        let Statement::VariableDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(decl.declarations[0].init.as_ref().unwrap().span(), Span::empty(7));
+        let Statement::IfStatement(if_stmt) = &ret.program.body[2] else { unreachable!() };
+        let Statement::BlockStatement(block) = &if_stmt.consequent else { unreachable!() };
+        assert_eq!(sources(&block.body, source_text), ["a = 1"]);
+    }
+
+    #[test]
+    fn class_elements() {
+        let allocator = Allocator::default();
+        let source_text = "class A {\n  a = ;\n  b( {}\n  #c = 1\n  ) d() {}\n}";
+        let ret = parse(&allocator, source_text, SourceType::default());
+        assert_eq!(
+            errors(&ret),
+            ["Unexpected token", "Expected `,` but found `#identifier`", "Unexpected token"]
+        );
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(class.span.source_text(source_text), source_text);
+        assert_eq!(sources(&class.body.body, source_text), ["a =", "b( {}", "#c = 1", "d() {}"]);
+        assert!(matches!(class.body.body[3], ClassElement::MethodDefinition(_)));
+    }
+
+    #[test]
+    fn object_properties() {
+        let allocator = Allocator::default();
+        let source_text = "x = {\n  a: ,\n  b: 1\n  c: [1, 2\n  ...d\n};";
+        let ret = parse(&allocator, source_text, SourceType::default());
+        assert_eq!(
+            errors(&ret),
+            [
+                "Unexpected token",
+                "Expected `,` but found `Identifier`",
+                "Expected `,` but found `...`"
+            ]
+        );
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else { unreachable!() };
+        assert_eq!(stmt.span.source_text(source_text), source_text);
+        let object = stmt.expression.get_inner_expression();
+        let oxc_ast::ast::Expression::AssignmentExpression(assignment) = object else {
+            unreachable!()
+        };
+        let oxc_ast::ast::Expression::ObjectExpression(object) = &assignment.right else {
+            unreachable!()
+        };
+        assert_eq!(sources(&object.properties, source_text), ["a:", "b: 1", "c: [1, 2", "...d"]);
+        assert!(matches!(object.properties[3], ObjectPropertyKind::SpreadProperty(_)));
+    }
+
+    #[test]
+    fn unclosed_at_end_of_file() {
+        let allocator = Allocator::default();
+        let source_text = "function f() {\n  let a = 1;\n  if (a) {\n    g(";
+        let ret = parse(&allocator, source_text, SourceType::default());
+        assert!(!ret.panicked);
+        assert_eq!(
+            errors(&ret),
+            [
+                "Expected `)` but found `EOF`",
+                "Expected `}` but found `EOF`",
+                "Expected `}` but found `EOF`"
+            ]
+        );
+        let Statement::FunctionDeclaration(function) = &ret.program.body[0] else { unreachable!() };
+        let body = function.body.as_ref().unwrap();
+        assert_eq!(sources(&body.statements, source_text), ["let a = 1;", "if (a) {\n    g("]);
+    }
+
+    #[test]
+    fn disabled() {
+        let allocator = Allocator::default();
+        let source_text = "let a = ;\nfoo(a b);";
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.panicked);
+        assert!(ret.program.body.is_empty());
+        assert_eq!(errors(&ret), ["Unexpected token"]);
+    }
+
+    #[test]
+    fn speculative_parsing() {
+        let allocator = Allocator::default();
+        // Arrow function heads and TypeScript type arguments are parsed speculatively
+        let source_text = "(a = { b: 1 }) ? (c) : d;\nf < { e: 1 } > (g);";
+        let ret = parse(&allocator, source_text, SourceType::ts());
+        assert!(ret.errors.is_empty());
+        let fresh = Parser::new(&allocator, source_text, SourceType::ts()).parse();
+        assert_eq!(ret.program.to_estree_ts_json(false), fresh.program.to_estree_ts_json(false));
+    }
+
+    /// Checks that spans of all nodes are within the source text.
+    struct SpanChecker<'s> {
+        source_text: &'s str,
+    }
+
+    impl Visit<'_> for SpanChecker<'_> {
+        fn visit_span(&mut self, span: &Span) {
+            assert!(span.start <= span.end, "{span:?} {}", self.source_text);
+            assert!(span.end as usize <= self.source_text.len(), "{span:?} {}", self.source_text);
+        }
+    }
+
+    /// Checks that no placeholder identifiers are left, which would declare or reference `""`.
+    struct PlaceholderChecker<'s> {
+        source_text: &'s str,
+    }
+
+    impl<'a> Visit<'a> for PlaceholderChecker<'_> {
+        fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+            assert!(!it.name.is_empty(), "{}", self.source_text);
+        }
+
+        fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+            assert!(!it.name.is_empty(), "{}", self.source_text);
+        }
+    }
+
+    /// Break the source text with random edits, and check that parsing always recovers.
+    #[test]
+    fn fuzz() {
+        const SOURCE: &str = "import a, { b as c } from 'a';
+export const d = { e: 1, [c]: 2, ...a, get f() { return 3; } };
+export default function g(h = 1, ...i) { return `j${h}k${i}`; }
+let l = /m/g.test(d) ? (n) => n + 1 : async function* () { yield await o; };
+label: for (const p of [1, 2]) { if (p) continue label; else { switch (p) { case 1: break; default: q(); } } }
+class R extends c { #s = 1; static { t(); } get u() { return this.#s; } v(w) { try {} catch { } } }
+while (x) do y(); while (z);
+const aa = <Bb cc=\"dd\" {...ee}>ff {gg} <Hh /></Bb>;
+";
+        const REPLACEMENTS: &[&str] =
+            &["", "x", " ", "\n", ";", "(", ")", "{", "}", "[", "]", "`", "${", "/", "=", ",", "<"];
+
+        // xorshift
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            #[expect(clippy::cast_possible_truncation)]
+            let value = (state % bound as u64) as usize;
+            value
+        };
+
+        for source_type in [SourceType::jsx(), SourceType::tsx()] {
+            for _ in 0..1000 {
+                let allocator = Allocator::default();
+                let mut source_text = SOURCE.to_string();
+                for _ in 0..=random(3) {
+                    let start = random(source_text.len() + 1);
+                    let end = start + random(4).min(source_text.len() - start);
+                    let replacement = REPLACEMENTS[random(REPLACEMENTS.len())];
+                    source_text.replace_range(start..end, replacement);
+                }
+                let source_text = allocator.alloc_str(&source_text);
+
+                let ret = parse(&allocator, source_text, source_type);
+                let fresh = Parser::new(&allocator, source_text, source_type).parse();
+                assert!(!ret.panicked, "{source_text}");
+                if fresh.errors.is_empty() {
+                    assert!(ret.errors.is_empty(), "{source_text}");
+                    assert_eq!(
+                        ret.program.to_estree_ts_json(false),
+                        fresh.program.to_estree_ts_json(false),
+                        "{source_text}"
+                    );
+                } else {
+                    assert!(!ret.errors.is_empty(), "{source_text}");
+                }
+                SpanChecker { source_text }.visit_program(&ret.program);
+                PlaceholderChecker { source_text }.visit_program(&ret.program);
+                for statements in ret.program.body.windows(2) {
+                    assert!(
+                        statements[0].span().end <= statements[1].span().start,
+                        "{source_text}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,32 @@
+//! [Flow](https://flow.org) syntax.
+//!
+//! Flow types and declarations are parsed into the TypeScript AST where the two languages
+//! overlap, so they can be consumed (and stripped) like TypeScript.
+
+mod statement;
+mod types;
+
+/// Whether any comment before the first token of `source_text` contains a `@flow` pragma.
+///
+/// The pragma must be [before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow),
+/// so this is cheap enough to check before parsing.
+pub fn has_flow_pragma(source_text: &str) -> bool {
+    let mut text = source_text;
+    if text.starts_with("#!") {
+        text = text.find('\n').map_or("", |end| &text[end..]);
+    }
+    loop {
+        text = text.trim_start();
+        let (comment, rest) = if let Some(rest) = text.strip_prefix("//") {
+            rest.split_once(['\n', '\r']).unwrap_or((rest, ""))
+        } else if let Some(rest) = text.strip_prefix("/*") {
+            rest.split_once("*/").unwrap_or((rest, ""))
+        } else {
+            return false;
+        };
+        if comment.contains("@flow") {
+            return true;
+        }
+        text = rest;
+    }
+}
@@ -0,0 +1,145 @@
+use oxc_ast::{NONE, ast::*};
+use oxc_span::Span;
+
+use crate::{
+    ParserImpl, diagnostics,
+    lexer::Kind,
+    modifiers::{Modifier, ModifierFlags, ModifierKind, Modifiers},
+};
+
+impl<'a> ParserImpl<'a> {
+    /// `opaque type`
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.is_flow
+            && self.at(Kind::Ident)
+            && self.cur_src() == "opaque"
+            && self.lookahead(|p| {
+                p.bump_any(); // bump `opaque`
+                p.at(Kind::Type) && !p.cur_token().is_on_new_line()
+            })
+    }
+
+    /// `opaque type T: Super = U`, parsed as a type alias of `U`.
+    ///
+    /// Opaque types may omit the underlying type in declarations (`declare opaque type T: Super`),
+    /// which are parsed as a type alias of the supertype, or `unknown` if there is no supertype.
+    pub(crate) fn parse_flow_opaque_type_declaration(
+        &mut self,
+        span: u32,
+        modifiers: &Modifiers<'a>,
+    ) -> Declaration<'a> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type);
+        let id = self.parse_binding_identifier();
+        let params = self.parse_ts_type_parameters();
+        let supertype = if self.eat(Kind::Colon) { Some(self.parse_ts_type()) } else { None };
+        let ty = if self.ctx.has_ambient() && !self.at(Kind::Eq) {
+            supertype.unwrap_or_else(|| {
+                self.ast.ts_type_unknown_keyword(Span::empty(self.prev_token_end))
+            })
+        } else {
+            self.expect(Kind::Eq);
+            self.parse_ts_type()
+        };
+        self.asi();
+        let span = self.end_span(span);
+
+        self.verify_modifiers(
+            modifiers,
+            ModifierFlags::DECLARE,
+            diagnostics::modifier_cannot_be_used_here,
+        );
+
+        self.ast.declaration_ts_type_alias(span, id, params, ty, modifiers.contains_declare())
+    }
+
+    /// `declare export` and `declare module.exports`
+    pub(crate) fn at_start_of_flow_declare_statement(&mut self) -> bool {
+        self.is_flow
+            && self.lookahead(|p| {
+                p.bump_any(); // bump `declare`
+                if p.cur_token().is_on_new_line() {
+                    return false;
+                }
+                match p.cur_kind() {
+                    Kind::Export => true,
+                    Kind::Module => {
+                        p.bump_any();
+                        p.at(Kind::Dot)
+                    }
+                    _ => false,
+                }
+            })
+    }
+
+    /// `declare export Declaration`, parsed as a type-only export of the declaration.
+    ///
+    /// `declare export default` and `declare module.exports` are not supported.
+    pub(crate) fn parse_flow_declare_statement(&mut self, span: u32) -> Statement<'a> {
+        let declare_span = self.start_span();
+        self.bump_any(); // bump `declare`
+        let declare_span = self.end_span(declare_span);
+
+        if self.at(Kind::Module) {
+            self.bump_any(); // bump `module`
+            let error =
+                diagnostics::flow_unsupported("declare module.exports", self.end_span(span));
+            return self.fatal_error(error);
+        }
+        match self.lexer.peek_token().kind() {
+            // `declare export { a }`, `declare export * from 'source'`
+            Kind::LCurly | Kind::Star => {
+                return self.parse_export_declaration(span, self.ast.vec());
+            }
+            Kind::Default => {
+                self.bump_any(); // bump `export`
+                let error =
+                    diagnostics::flow_unsupported("declare export default", self.end_span(span));
+                return self.fatal_error(error);
+            }
+            _ => {}
+        }
+        self.bump_any(); // bump `export`
+
+        let modifier = Modifier::new(declare_span, ModifierKind::Declare);
+        let modifiers = Modifiers::new(Some(self.ast.vec1(modifier)), ModifierFlags::DECLARE);
+        let reserved_ctx = self.ctx;
+        self.ctx = self.ctx.and_ambient(true);
+        let declaration = self.parse_declaration(self.start_span(), &modifiers, self.ast.vec());
+        self.ctx = reserved_ctx;
+
+        Statement::from(self.ast.module_declaration_export_named_declaration(
+            self.end_span(span),
+            Some(declaration),
+            self.ast.vec(),
+            None,
+            ImportOrExportKind::Type,
+            NONE,
+        ))
+    }
+
+    /// `import typeof A, { B } from 'source'`, parsed as a type-only import.
+    pub(crate) fn parse_flow_import_typeof_declaration(&mut self, span: u32) -> Statement<'a> {
+        self.bump_any(); // bump `typeof`
+        let default_specifier = if self.cur_kind().is_binding_identifier() {
+            Some(self.parse_binding_identifier())
+        } else {
+            None
+        };
+        let specifiers =
+            self.parse_import_declaration_specifiers(default_specifier, ImportOrExportKind::Type);
+        let source = self.parse_literal_string();
+        let with_clause = self.parse_import_attributes();
+        self.asi();
+        let span = self.end_span(span);
+
+        Statement::from(self.ast.module_declaration_import_declaration(
+            span,
+            Some(specifiers),
+            source,
+            None,
+            with_clause,
+            ImportOrExportKind::Type,
+        ))
+    }
+}
@@ -0,0 +1,277 @@
+use oxc_allocator::Box;
+use oxc_ast::ast::*;
+use oxc_span::Span;
+
+use crate::{ParserImpl, diagnostics, lexer::Kind};
+
+impl<'a> ParserImpl<'a> {
+    /// `?T`, parsed as a prefix [`TSType::JSDocNullableType`].
+    ///
+    /// Unlike JSDoc, `?` binds tighter than unions and intersections: `?A | B` is `(?A) | B`.
+    pub(crate) fn parse_flow_maybe_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `?`
+        let type_annotation = if self.is_start_of_function_type_or_constructor_type() {
+            self.parse_function_or_constructor_type()
+        } else {
+            self.parse_type_operator_or_higher()
+        };
+        self.ast.ts_type_js_doc_nullable_type(
+            self.end_span(span),
+            type_annotation,
+            /* postfix */ false,
+        )
+    }
+
+    /// `<T>(...) =>` or `(...) =>`
+    ///
+    /// Flow parameters may be unnamed, so `(A)` is only the start of a function type if the
+    /// matching `)` is followed by `=>`.
+    pub(crate) fn is_start_of_flow_function_type(&mut self) -> bool {
+        if self.at(Kind::LAngle) {
+            return true;
+        }
+        if !self.at(Kind::LParen) {
+            return false;
+        }
+        self.lookahead(|p| {
+            p.bump_any(); // bump `(`
+            if matches!(p.cur_kind(), Kind::RParen | Kind::Dot3) {
+                return true;
+            }
+            let mut depth = 1;
+            while depth > 0 {
+                match p.cur_kind() {
+                    Kind::LParen => depth += 1,
+                    Kind::RParen => depth -= 1,
+                    Kind::Eof => return false,
+                    _ => {}
+                }
+                p.bump_any();
+            }
+            p.at(Kind::Arrow)
+        })
+    }
+
+    /// Parameters of function types, method signatures and call signatures.
+    ///
+    /// ```text
+    /// (this: T, name: T, optional?: T, T, ...rest: T)
+    /// ```
+    ///
+    /// Unnamed parameters are given an empty name.
+    pub(crate) fn parse_flow_function_type_parameters(
+        &mut self,
+    ) -> (Option<TSThisParameter<'a>>, Box<'a, FormalParameters<'a>>) {
+        let span = self.start_span();
+        self.expect(Kind::LParen);
+        let this_param = if self.at(Kind::This) && self.lexer.peek_token().kind() == Kind::Colon {
+            let param = self.parse_ts_this_parameter();
+            if !self.at(Kind::RParen) {
+                self.expect(Kind::Comma);
+            }
+            Some(param)
+        } else {
+            None
+        };
+        let mut items = self.ast.vec();
+        let mut rest: Option<BindingRestElement<'a>> = None;
+        while !self.at(Kind::RParen) && !self.has_fatal_error() {
+            if let Some(rest) = &rest {
+                self.set_fatal_error(diagnostics::rest_parameter_last(rest.span));
+                break;
+            }
+            let param_span = self.start_span();
+            if self.eat(Kind::Dot3) {
+                let argument = self.parse_flow_function_type_parameter_pattern();
+                rest = Some(self.ast.binding_rest_element(self.end_span(param_span), argument));
+            } else {
+                let pattern = self.parse_flow_function_type_parameter_pattern();
+                items.push(self.ast.plain_formal_parameter(self.end_span(param_span), pattern));
+            }
+            if !self.at(Kind::RParen) {
+                self.expect(Kind::Comma);
+            }
+        }
+        self.expect(Kind::RParen);
+        let params = self.ast.alloc_formal_parameters(
+            self.end_span(span),
+            FormalParameterKind::Signature,
+            items,
+            rest,
+        );
+        (this_param, params)
+    }
+
+    fn parse_flow_function_type_parameter_pattern(&mut self) -> BindingPattern<'a> {
+        let is_named = self.cur_kind().is_binding_identifier()
+            && matches!(self.lexer.peek_token().kind(), Kind::Colon | Kind::Question);
+        if is_named {
+            // `name: T`, `name?: T`
+            let ident = self.parse_binding_identifier();
+            let optional = self.eat(Kind::Question);
+            let type_annotation = self.parse_ts_type_annotation();
+            let kind = BindingPatternKind::BindingIdentifier(self.alloc(ident));
+            return self.ast.binding_pattern(kind, type_annotation, optional);
+        }
+        // `T`
+        let span = self.start_span();
+        let ty = self.parse_ts_type();
+        let type_annotation = self.ast.alloc_ts_type_annotation(self.end_span(span), ty);
+        let ident = self.ast.alloc_binding_identifier(Span::empty(span), "");
+        let kind = BindingPatternKind::BindingIdentifier(ident);
+        self.ast.binding_pattern(kind, Some(type_annotation), false)
+    }
+
+    /// Object types, which may be exact (`{| |}`), and may contain spreads, variance sigils and
+    /// unnamed indexers.
+    ///
+    /// ```text
+    /// { a: T, +b: T, -c: T, [K]: V, ...Spread, ... }
+    /// ```
+    ///
+    /// Spreads are parsed as an intersection with the remaining members, e.g. `{ ...A, b: B }` is
+    /// parsed as `A & { b: B }`. `+` (read-only) properties are marked `readonly`.
+    pub(crate) fn parse_flow_object_type(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly);
+        let mut members = self.ast.vec();
+        let mut spreads = self.ast.vec();
+        // `{||}`
+        if !self.eat(Kind::Pipe2) {
+            let exact = self.eat(Kind::Pipe);
+            loop {
+                if self.has_fatal_error()
+                    || matches!(self.cur_kind(), Kind::RCurly | Kind::Eof)
+                    || (exact && self.at(Kind::Pipe))
+                {
+                    break;
+                }
+                if self.eat(Kind::Dot3) {
+                    if !matches!(
+                        self.cur_kind(),
+                        Kind::Comma | Kind::Semicolon | Kind::RCurly | Kind::Pipe
+                    ) {
+                        spreads.push(self.parse_ts_type());
+                    }
+                    // Otherwise this is the `...` of an explicitly inexact object.
+                    self.parse_type_member_semicolon();
+                    continue;
+                }
+                members.push(self.parse_flow_object_type_member());
+            }
+            if exact {
+                self.expect(Kind::Pipe);
+            }
+        }
+        self.expect(Kind::RCurly);
+        let literal = self.ast.ts_type_type_literal(self.end_span(span), members);
+        if spreads.is_empty() {
+            return literal;
+        }
+        spreads.push(literal);
+        self.ast.ts_type_intersection_type(self.end_span(span), spreads)
+    }
+
+    fn parse_flow_object_type_member(&mut self) -> TSSignature<'a> {
+        let span = self.start_span();
+        let readonly = self.parse_flow_property_variance();
+        let mut signature = if self.at(Kind::LBrack) && !self.is_index_signature() {
+            self.parse_flow_indexer(span)
+        } else {
+            self.parse_ts_type_signature()
+        };
+        match &mut signature {
+            TSSignature::TSPropertySignature(property) => {
+                property.span = self.end_span(span);
+                property.readonly |= readonly;
+            }
+            TSSignature::TSIndexSignature(index) => {
+                index.span = self.end_span(span);
+                index.readonly |= readonly;
+            }
+            _ => {}
+        }
+        signature
+    }
+
+    /// `[K]: V`
+    fn parse_flow_indexer(&mut self, span: u32) -> TSSignature<'a> {
+        self.bump_any(); // bump `[`
+        let key_span = self.start_span();
+        let key = self.parse_ts_type();
+        let key_span = self.end_span(key_span);
+        self.expect(Kind::RBrack);
+        let Some(type_annotation) = self.parse_ts_type_annotation() else {
+            return self
+                .fatal_error(diagnostics::index_signature_type_annotation(self.end_span(span)));
+        };
+        self.parse_type_member_semicolon();
+        let key = self.ast.alloc_ts_type_annotation(key_span, key);
+        let parameter = self.ast.ts_index_signature_name(key_span, "", key);
+        TSSignature::TSIndexSignature(self.ast.alloc_ts_index_signature(
+            self.end_span(span),
+            self.ast.vec1(parameter),
+            type_annotation,
+            /* readonly */ false,
+            /* static */ false,
+        ))
+    }
+
+    /// `|}`, the end of an exact object type.
+    pub(crate) fn at_flow_exact_object_type_end(&mut self) -> bool {
+        self.is_flow && self.at(Kind::Pipe) && self.lexer.peek_token().kind() == Kind::RCurly
+    }
+
+    /// `+` and `-` before a type parameter, returned as `(in, out)`.
+    pub(crate) fn parse_flow_variance(&mut self) -> (bool, bool) {
+        match self.cur_kind() {
+            Kind::Plus => {
+                self.bump_any();
+                (false, true)
+            }
+            Kind::Minus => {
+                self.bump_any();
+                (true, false)
+            }
+            _ => (false, false),
+        }
+    }
+
+    /// `+` and `-` before a property, returns whether the property is read-only (`+`).
+    ///
+    /// Write-only (`-`) properties cannot be represented in the AST, and are reported.
+    pub(crate) fn parse_flow_property_variance(&mut self) -> bool {
+        match self.cur_kind() {
+            Kind::Plus => {
+                self.bump_any();
+                true
+            }
+            Kind::Minus => {
+                let span = self.cur_token().span();
+                self.bump_any();
+                self.error(diagnostics::flow_write_only_property(span));
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// `%checks` or `%checks(expression)` after a return type, which is discarded.
+    pub(crate) fn parse_flow_predicate(&mut self) {
+        if !self.at(Kind::Percent) || self.lexer.peek_token().kind() != Kind::Ident {
+            return;
+        }
+        let checkpoint = self.checkpoint();
+        self.bump_any(); // bump `%`
+        if self.cur_src() != "checks" {
+            self.rewind(checkpoint);
+            return;
+        }
+        self.bump_any(); // bump `checks`
+        if self.eat(Kind::LParen) {
+            self.parse_expr();
+            self.expect(Kind::RParen);
+        }
+    }
+}
@@ -0,0 +1,463 @@
+//! Incremental reparsing.
+//!
+//! Editors re-parse a file on every keystroke. Most edits only touch a single top-level statement,
+//! so instead of parsing the whole file again, [`Parser::parse_incremental`] re-lexes and re-parses
+//! only the edited statement (and the statement before it, whose end depends on the first token of
+//! the edited one), and moves all other top-level statements over from the previous [`Program`],
+//! shifting the spans of the statements after the edit.
+//!
+//! The reparsed region is only accepted if it parses without errors and ends exactly where the
+//! following statement starts. Otherwise, e.g. when the edit crosses statement boundaries,
+//! the whole file is parsed again.
+//!
+//! [`Parser::parse_incremental`]: crate::Parser::parse_incremental
+//! [`Program`]: oxc_ast::ast::Program
+
+use oxc_ast::ast::{Comment, Expression, Statement};
+use oxc_ast_visit::VisitMut;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::module_record::DynamicImport;
+
+use crate::{
+    MAX_LEN, ParserImpl, ParserReturn, context::StatementContext,
+    module_record::ModuleRecordBuilder,
+};
+
+/// A single text replacement, as reported by an editor.
+///
+/// All offsets are in UTF-8 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Replaced range in the previous source text
+    pub span: Span,
+    /// Length of the text which replaced `span` in the new source text
+    pub new_len: u32,
+}
+
+impl TextEdit {
+    /// Create a [`TextEdit`] replacing `span` with `new_len` bytes of text.
+    pub fn new(span: Span, new_len: u32) -> Self {
+        Self { span, new_len }
+    }
+}
+
+/// The reparsed part of the source text.
+///
+/// Everything before `lex_start` is unchanged, everything from `old_next_start` in the previous
+/// source text is moved to `next_start` in the new source text.
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    lex_start: u32,
+    old_next_start: u32,
+    next_start: u32,
+    delta: i64,
+}
+
+impl Region {
+    fn new(lex_start: u32, old_next_start: u32, delta: i64) -> Self {
+        let mut region = Self { lex_start, old_next_start, next_start: old_next_start, delta };
+        region.next_start = region.shift(old_next_start);
+        region
+    }
+
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift(self, offset: u32) -> u32 {
+        (i64::from(offset) + self.delta) as u32
+    }
+
+    fn shift_span(self, span: Span) -> Span {
+        Span::new(self.shift(span.start), self.shift(span.end))
+    }
+
+    /// Merge `previous` items outside of the region with `reparsed` items inside of it.
+    /// Both inputs and the output are sorted.
+    fn splice<T: Copy>(
+        self,
+        previous: &[T],
+        reparsed: &[T],
+        span: impl Fn(&T) -> Span,
+        shift: impl Fn(T) -> T,
+    ) -> Vec<T> {
+        let before = previous.iter().copied().filter(|it| span(it).end <= self.lex_start);
+        let inside = reparsed.iter().copied().filter(|it| span(it).start < self.next_start);
+        let after = previous.iter().copied().filter(|it| span(it).start >= self.old_next_start);
+        before.chain(inside).chain(after.map(shift)).collect()
+    }
+}
+
+struct SpanShifter {
+    region: Region,
+}
+
+impl VisitMut<'_> for SpanShifter {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.region.shift_span(*span);
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Reparse the top-level statement containing `edit`, reusing all other statements of `previous`.
+    ///
+    /// Returns `None` if the edit cannot be handled incrementally,
+    /// in which case the caller must parse the whole file again.
+    #[expect(clippy::cast_possible_truncation)]
+    pub(crate) fn parse_incremental(
+        mut self,
+        previous: ParserReturn<'a>,
+        edit: TextEdit,
+    ) -> Option<ParserReturn<'a>> {
+        #[cfg(feature = "regular_expression")]
+        if self.options.parse_regular_expression {
+            // Regular expression ASTs are not visited by `VisitMut`, so their spans cannot be shifted.
+            return None;
+        }
+        // Tokens of the previous parse are not reused, so collecting them requires a full parse.
+        if self.options.collect_tokens || previous.panicked || !previous.errors.is_empty() {
+            return None;
+        }
+
+        let mut program = previous.program;
+        let old_len = program.source_text.len() as u32;
+        if self.source_text.len() > MAX_LEN {
+            return None;
+        }
+        let new_len = self.source_text.len() as u32;
+        let delta = i64::from(edit.new_len) - i64::from(edit.span.size());
+        if edit.span.end > old_len || i64::from(old_len) + delta != i64::from(new_len) {
+            return None;
+        }
+
+        // Only edits inside a single top-level statement can be handled.
+        let body = &program.body;
+        let index = body.iter().position(|stmt| {
+            stmt.span().start <= edit.span.start && edit.span.end <= stmt.span().end
+        })?;
+        // The first statement may be merged into the last directive.
+        if index == 0 && !program.directives.is_empty() {
+            return None;
+        }
+        let first = index.saturating_sub(1);
+        let lex_start = if first > 0 {
+            body[first - 1].span().end
+        } else {
+            program.hashbang.as_ref().map_or(0, |hashbang| hashbang.span.end)
+        };
+        let old_next_start = body.get(index + 1).map_or(old_len, |stmt| stmt.span().start);
+        let region = Region::new(lex_start, old_next_start, delta);
+        let region_end = region.shift(body[index].span().end);
+
+        // Reparse the region.
+        if lex_start > 0 {
+            self.lexer.seek(lex_start);
+        }
+        self.bump_any();
+        self.prev_token_end = lex_start;
+        let mut statements = self.ast.vec();
+        while !self.has_fatal_error() && self.cur_token().start() < region_end {
+            statements.push(self.parse_statement_list_item(StatementContext::StatementList));
+        }
+        self.check_unfinished_errors();
+        if self.fatal_error.is_some()
+            || self.errors_count() > 0
+            || self.cur_token().start() != region.next_start
+            || statements.last().is_none_or(|stmt| stmt.span().end != region_end)
+            // A string literal at the start of the body is a directive.
+            || (first == 0 && is_string_literal_statement(&statements[0]))
+        {
+            return None;
+        }
+
+        // Move over statements after the region, shifting their spans.
+        let reparsed_len = statements.len();
+        program.body.splice(first..=index, statements);
+        if delta != 0 {
+            let mut shifter = SpanShifter { region };
+            for stmt in program.body.iter_mut().skip(first + reparsed_len) {
+                shifter.visit_statement(stmt);
+            }
+        }
+
+        let comments = region.splice(
+            &program.comments,
+            &self.lexer.trivia_builder.comments,
+            |comment| comment.span,
+            |comment| Comment {
+                span: region.shift_span(comment.span),
+                attached_to: region.shift(comment.attached_to),
+                ..comment
+            },
+        );
+        program.comments = self.ast.vec_from_iter(comments);
+        program.source_text = self.source_text;
+        program.span = Span::new(0, new_len);
+
+        let irregular_whitespaces = region
+            .splice(
+                &previous.irregular_whitespaces,
+                &self.lexer.trivia_builder.irregular_whitespaces,
+                |span| *span,
+                |span| region.shift_span(span),
+            )
+            .into_boxed_slice();
+
+        // Rebuild the module record. Module declarations are all top-level statements, so are
+        // visited again. Dynamic imports and `import.meta`s outside of the region are carried
+        // over from the previous module record.
+        let previous_module_record = previous.module_record;
+        let (region_module_record, _) = self.module_record_builder.build();
+        let mut module_record_builder = ModuleRecordBuilder::new(self.ast.allocator);
+        for stmt in &program.body {
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                module_record_builder.visit_module_declaration(module_decl);
+            }
+        }
+        let dynamic_imports = region.splice(
+            &previous_module_record.dynamic_imports,
+            &region_module_record.dynamic_imports,
+            |dynamic_import| dynamic_import.span,
+            |dynamic_import| DynamicImport {
+                span: region.shift_span(dynamic_import.span),
+                module_request: region.shift_span(dynamic_import.module_request),
+            },
+        );
+        for dynamic_import in dynamic_imports {
+            module_record_builder.add_dynamic_import(dynamic_import);
+        }
+        let import_metas = region.splice(
+            &previous_module_record.import_metas,
+            &region_module_record.import_metas,
+            |span| *span,
+            |span| region.shift_span(span),
+        );
+        for span in import_metas {
+            module_record_builder.visit_import_meta(span);
+        }
+
+        let (module_record, module_record_errors) = module_record_builder.build();
+        let mut errors = vec![];
+        // Skip checking for exports in TypeScript
+        if !self.source_type.is_typescript() {
+            errors.extend(module_record_errors);
+        }
+
+        program.source_type = self.source_type;
+        if self.source_type.is_unambiguous() {
+            program.source_type = if module_record.has_module_syntax {
+                self.source_type.with_module(true)
+            } else {
+                self.source_type.with_script(true)
+            };
+        }
+
+        Some(ParserReturn {
+            program,
+            module_record,
+            errors,
+            irregular_whitespaces,
+            tokens: self.tokens,
+            panicked: false,
+            is_flow_language: false,
+        })
+    }
+}
+
+fn is_string_literal_statement(stmt: &Statement<'_>) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(stmt) if matches!(stmt.expression, Expression::StringLiteral(_)))
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_span::{SourceType, Span};
+
+    use crate::{Parser, ParserReturn, TextEdit};
+
+    fn module_record_snapshot(ret: &ParserReturn<'_>) -> String {
+        let module_record = &ret.module_record;
+        let mut requested_modules = module_record
+            .requested_modules
+            .iter()
+            .map(|(name, modules)| format!("{name}: {modules:?}"))
+            .collect::<Vec<_>>();
+        requested_modules.sort_unstable();
+        let mut exported_bindings = module_record
+            .exported_bindings
+            .iter()
+            .map(|(name, span)| format!("{name}: {span:?}"))
+            .collect::<Vec<_>>();
+        exported_bindings.sort_unstable();
+        format!(
+            "{} {requested_modules:?} {exported_bindings:?} {:?} {:?} {:?} {:?} {:?} {:?}",
+            module_record.has_module_syntax,
+            module_record.import_entries,
+            module_record.local_export_entries,
+            module_record.indirect_export_entries,
+            module_record.star_export_entries,
+            module_record.dynamic_imports,
+            module_record.import_metas,
+        )
+    }
+
+    fn assert_same(incremental: &ParserReturn<'_>, fresh: &ParserReturn<'_>, source_text: &str) {
+        assert_eq!(
+            incremental.program.to_estree_ts_json(false),
+            fresh.program.to_estree_ts_json(false),
+            "{source_text}"
+        );
+        assert_eq!(incremental.program.comments, fresh.program.comments, "{source_text}");
+        assert_eq!(incremental.program.source_type, fresh.program.source_type, "{source_text}");
+        assert_eq!(incremental.irregular_whitespaces, fresh.irregular_whitespaces, "{source_text}");
+        assert_eq!(
+            incremental.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            fresh.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "{source_text}"
+        );
+        assert_eq!(incremental.panicked, fresh.panicked, "{source_text}");
+        assert_eq!(
+            module_record_snapshot(incremental),
+            module_record_snapshot(fresh),
+            "{source_text}"
+        );
+    }
+
+    fn edit<'a>(
+        allocator: &'a Allocator,
+        source_text: &str,
+        span: Span,
+        replacement: &str,
+    ) -> (&'a str, TextEdit) {
+        let mut new_source_text = source_text.to_string();
+        new_source_text.replace_range(span.start as usize..span.end as usize, replacement);
+        #[expect(clippy::cast_possible_truncation)]
+        let edit = TextEdit::new(span, replacement.len() as u32);
+        (allocator.alloc_str(&new_source_text), edit)
+    }
+
+    #[test]
+    fn reuses_statements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let source_text = "const a = 1; // a\nfoo(a);\nconst b = 2; /* b */\nconst c = 3;\n";
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let Statement::VariableDeclaration(first) = &ret.program.body[0] else { unreachable!() };
+        let first = &raw const **first;
+
+        // `2` -> `20`
+        let (new_source_text, text_edit) = edit(&allocator, source_text, Span::new(36, 37), "20");
+        let ret =
+            Parser::new(&allocator, new_source_text, source_type).parse_incremental(ret, text_edit);
+        let fresh = Parser::new(&allocator, new_source_text, source_type).parse();
+        assert_same(&ret, &fresh, new_source_text);
+        let Statement::VariableDeclaration(reused) = &ret.program.body[0] else { unreachable!() };
+        assert!(std::ptr::eq(first, &raw const **reused));
+    }
+
+    #[test]
+    fn falls_back_to_full_parse() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let source_text = "let a = 1;\nlet b = 2;\n";
+        let cases = [
+            // Crosses statement boundaries
+            (Span::new(8, 15), "2;\nlet x"),
+            // Splits a statement
+            (Span::new(15, 15), "; x"),
+            // Merges with the next statement
+            (Span::new(9, 10), ""),
+            // Syntax error
+            (Span::new(8, 9), "("),
+        ];
+        for (span, replacement) in cases {
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let (new_source_text, text_edit) = edit(&allocator, source_text, span, replacement);
+            let ret = Parser::new(&allocator, new_source_text, source_type)
+                .parse_incremental(ret, text_edit);
+            let fresh = Parser::new(&allocator, new_source_text, source_type).parse();
+            assert_same(&ret, &fresh, new_source_text);
+        }
+    }
+
+    /// Apply random edits, and compare the result of each incremental reparse with a fresh parse.
+    #[test]
+    fn fuzz() {
+        const SOURCE: &str = "#!/usr/bin/env node
+import a, { b as c } from 'a';
+/* leading */ export const d = 1; // trailing
+export default function e() { return import('e'); }
+let f = `f${d}` + /f/g.source
+foo(f)
+label: for (const x of [1, 2]) { if (x) continue label; }
+class G extends c { #h = import.meta.url; method() {} }
+export { a as i };
+// last
+";
+        const REPLACEMENTS: &[&str] = &[
+            "",
+            "x",
+            "1",
+            " ",
+            "\n",
+            ";",
+            "(",
+            ")",
+            "{",
+            "}",
+            "[",
+            "]",
+            "'s'",
+            "`t`",
+            "/r/",
+            "=",
+            "+",
+            ",",
+            ".",
+            "/* c */",
+            "// c\n",
+            "import('m')",
+            "import.meta",
+            "\u{a0}",
+            "é",
+            "export let y = 1;",
+            "await",
+            "=>",
+        ];
+
+        // xorshift
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move |bound: usize| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            #[expect(clippy::cast_possible_truncation)]
+            let value = (state % bound as u64) as usize;
+            value
+        };
+
+        let source_type = SourceType::mjs();
+        for _ in 0..1000 {
+            let allocator = Allocator::default();
+            let mut source_text: &str = SOURCE;
+            let mut ret = Parser::new(&allocator, source_text, source_type).parse();
+            for _ in 0..3 {
+                let mut start = random(source_text.len() + 1);
+                while !source_text.is_char_boundary(start) {
+                    start -= 1;
+                }
+                let mut end = start + random(4).min(source_text.len() - start);
+                while !source_text.is_char_boundary(end) {
+                    end += 1;
+                }
+                let replacement = REPLACEMENTS[random(REPLACEMENTS.len())];
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::new(start as u32, end as u32);
+                let (new_source_text, text_edit) = edit(&allocator, source_text, span, replacement);
+                ret = Parser::new(&allocator, new_source_text, source_type)
+                    .parse_incremental(ret, text_edit);
+                let fresh = Parser::new(&allocator, new_source_text, source_type).parse();
+                assert_same(&ret, &fresh, new_source_text);
+                source_text = new_source_text;
+            }
+        }
+    }
+}
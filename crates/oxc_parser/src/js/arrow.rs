@@ -134,6 +134,8 @@ impl<'a> ParserImpl<'a> {
                         match third {
                             // If we have something like "(a:", then we must have a
                             // type-annotated parameter in an arrow function expression.
+                            // In Flow, this may also be a type cast: "(a: T)".
+                            Kind::Colon if self.is_flow => Tristate::Maybe,
                             Kind::Colon => Tristate::True,
                             // If we have "(a?:" or "(a?," or "(a?=" or "(a?)" then it is definitely a lambda.
                             Kind::Question => {
@@ -182,6 +184,8 @@ impl<'a> ParserImpl<'a> {
                             }
                         }
                         Kind::Eq | Kind::Comma => Tristate::True,
+                        // Flow allows `<T>(x: T) => x` in JSX.
+                        Kind::RAngle | Kind::Colon if self.is_flow => Tristate::Maybe,
                         _ => Tristate::False,
                     };
                 }
@@ -345,7 +349,7 @@ impl<'a> ParserImpl<'a> {
 
         let checkpoint = self.checkpoint();
 
-        let head = self.parse_parenthesized_arrow_function_head();
+        let head = self.without_recovery(Self::parse_parenthesized_arrow_function_head);
         if self.has_fatal_error() {
             self.state.not_parenthesized_arrow.insert(pos);
             self.rewind(checkpoint);
@@ -188,14 +188,24 @@ impl<'a> ParserImpl<'a> {
     fn parse_class_body(&mut self) -> Box<'a, ClassBody<'a>> {
         let span = self.start_span();
         let class_elements = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
-            // Skip empty class element `;`
-            if p.eat(Kind::Semicolon) {
-                while p.eat(Kind::Semicolon) {}
-                if p.at(Kind::RCurly) {
-                    return None;
+            loop {
+                // Skip empty class element `;`
+                if p.eat(Kind::Semicolon) {
+                    while p.eat(Kind::Semicolon) {}
+                    if p.at(Kind::RCurly) {
+                        return None;
+                    }
+                }
+                let start = p.start_span();
+                let mut element = Self::parse_class_element(p);
+                if p.fatal_error.is_some() && !p.recover(&mut element, start) {
+                    if p.at(Kind::RCurly) || p.has_fatal_error() {
+                        return None;
+                    }
+                    continue;
                 }
+                return Some(element);
             }
-            Some(Self::parse_class_element(p))
         });
         self.ast.alloc_class_body(self.end_span(span), class_elements)
     }
@@ -278,16 +288,23 @@ impl<'a> ParserImpl<'a> {
             );
         }
 
+        // Flow variance sigil (`+p: T`), `+` (read-only) properties are marked `readonly`.
+        let readonly = self.is_flow && self.parse_flow_property_variance();
+
         let kind = self.cur_kind();
         if kind.is_identifier_or_keyword() || kind == Kind::Star || kind == Kind::LBrack {
             let is_ambient = modifiers.contains(ModifierKind::Declare);
-            return if is_ambient {
+            let mut element = if is_ambient {
                 self.context(Context::Ambient, Context::empty(), |p| {
                     p.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
                 })
             } else {
                 self.parse_property_or_method_declaration(span, r#type, &modifiers, decorators)
             };
+            if let ClassElement::PropertyDefinition(property) = &mut element {
+                property.readonly |= readonly;
+            }
+            return element;
         }
 
         self.unexpected()
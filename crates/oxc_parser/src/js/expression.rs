@@ -204,11 +204,16 @@ impl<'a> ParserImpl<'a> {
         self.bump_any(); // `bump` `(`
         let expr_span = self.start_span();
         let (mut expressions, comma_span) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                Self::parse_assignment_expression_or_higher,
-            )
+            p.parse_delimited_list(Kind::RParen, Kind::Comma, |p| {
+                let span = p.start_span();
+                let expr = p.parse_assignment_expression_or_higher();
+                // Flow type cast: `(expr: T)`
+                if p.is_flow && p.eat(Kind::Colon) {
+                    let ty = p.parse_ts_type();
+                    return p.ast.expression_ts_as(p.end_span(span), expr, ty);
+                }
+                expr
+            })
         });
 
         if let Some(comma_span) = comma_span {
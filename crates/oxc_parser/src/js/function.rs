@@ -45,6 +45,9 @@ impl<'a> ParserImpl<'a> {
         func_kind: FunctionKind,
         params_kind: FormalParameterKind,
     ) -> (Option<TSThisParameter<'a>>, Box<'a, FormalParameters<'a>>) {
+        if self.is_flow && params_kind == FormalParameterKind::Signature {
+            return self.parse_flow_function_type_parameters();
+        }
         let span = self.start_span();
         self.expect(Kind::LParen);
         let this_param = if self.is_ts && self.at(Kind::This) {
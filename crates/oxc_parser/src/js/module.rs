@@ -62,6 +62,9 @@ impl<'a> ParserImpl<'a> {
 
     /// Section 16.2.2 Import Declaration
     pub(crate) fn parse_import_declaration(&mut self, span: u32) -> Statement<'a> {
+        if self.is_flow && self.at(Kind::Typeof) {
+            return self.parse_flow_import_typeof_declaration(span);
+        }
         let token_after_import = self.cur_token();
         let mut identifier_after_import: Option<BindingIdentifier<'_>> =
             if self.cur_kind().is_binding_identifier() {
@@ -199,7 +202,7 @@ impl<'a> ParserImpl<'a> {
     }
 
     // Full Syntax: <https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#syntax>
-    fn parse_import_declaration_specifiers(
+    pub(crate) fn parse_import_declaration_specifiers(
         &mut self,
         // A default specifier, if we already saw any identifier after `import`
         default_specifier: Option<BindingIdentifier<'a>>,
@@ -280,7 +283,7 @@ impl<'a> ParserImpl<'a> {
     }
 
     /// [Import Attributes](https://tc39.es/proposal-import-attributes)
-    fn parse_import_attributes(&mut self) -> Option<WithClause<'a>> {
+    pub(crate) fn parse_import_attributes(&mut self) -> Option<WithClause<'a>> {
         let attributes_keyword = match self.cur_kind() {
             Kind::Assert if !self.cur_token().is_on_new_line() => self.parse_identifier_name(),
             Kind::With => self.parse_identifier_name(),
@@ -709,11 +712,15 @@ impl<'a> ParserImpl<'a> {
         parent_kind: ImportOrExportKind,
     ) -> ImportOrExportSpecifier<'a> {
         let specifier_span = self.start_span();
+        // Flow `import { typeof A } from 'source'`
+        let is_flow_typeof =
+            self.is_flow && specifier_type == ImportOrExport::Import && self.eat(Kind::Typeof);
         let type_or_name_token = self.cur_token();
         let type_or_name_token_kind = type_or_name_token.kind();
         let mut check_identifier_token = self.cur_token();
 
-        let mut kind = ImportOrExportKind::Value;
+        let mut kind =
+            if is_flow_typeof { ImportOrExportKind::Type } else { ImportOrExportKind::Value };
         let mut can_parse_as_keyword = true;
         let mut property_name: Option<ModuleExportName<'a>> = None;
         let mut name = self.parse_module_export_name();
@@ -1,4 +1,4 @@
-use oxc_allocator::Box;
+use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
 use oxc_syntax::operator::AssignmentOperator;
 
@@ -19,17 +19,49 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_object_expression(&mut self) -> Box<'a, ObjectExpression<'a>> {
         let span = self.start_span();
         self.expect(Kind::LCurly);
-        let (object_expression_properties, _) = self.context(Context::In, Context::empty(), |p| {
-            p.parse_delimited_list(
-                Kind::RCurly,
-                Kind::Comma,
-                Self::parse_object_expression_property,
-            )
-        });
+        let object_expression_properties =
+            self.context(Context::In, Context::empty(), Self::parse_object_expression_properties);
         self.expect(Kind::RCurly);
         self.ast.alloc_object_expression(self.end_span(span), object_expression_properties)
     }
 
+    fn parse_object_expression_properties(&mut self) -> Vec<'a, ObjectPropertyKind<'a>> {
+        let mut properties = self.ast.vec();
+        let mut expect_comma = false;
+        while !self.at(Kind::RCurly) && !self.has_fatal_error() {
+            if expect_comma {
+                if self.recover_from_errors && !self.at(Kind::Comma) {
+                    // `{ a: 1 b: 2 }`: report the missing comma, and continue with the next property
+                    self.error(diagnostics::expect_token(
+                        Kind::Comma.to_str(),
+                        self.cur_kind().to_str(),
+                        self.cur_token().span(),
+                    ));
+                } else {
+                    self.expect(Kind::Comma);
+                    if self.at(Kind::RCurly) {
+                        break;
+                    }
+                }
+            }
+            let start = self.start_span();
+            let mut property = self.parse_object_expression_property();
+            if self.fatal_error.is_some() && self.recover_from_errors {
+                let parsed = self.recover(&mut property, start);
+                // Recovery stops at the comma before the next property, or at a new line
+                expect_comma = false;
+                self.bump(Kind::Comma);
+                if !parsed {
+                    continue;
+                }
+            } else {
+                expect_comma = true;
+            }
+            properties.push(property);
+        }
+        properties
+    }
+
     fn parse_object_expression_property(&mut self) -> ObjectPropertyKind<'a> {
         match self.cur_kind() {
             Kind::Dot3 => ObjectPropertyKind::SpreadProperty(self.parse_spread_element()),
@@ -41,7 +41,11 @@ impl<'a> ParserImpl<'a> {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let start = self.start_span();
+            let mut stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if self.fatal_error.is_some() && !self.recover(&mut stmt, start) {
+                continue;
+            }
 
             if is_top_level {
                 if let Some(module_decl) = stmt.as_module_declaration() {
@@ -122,6 +126,12 @@ impl<'a> ParserImpl<'a> {
             Kind::Const => self.parse_const_statement(stmt_ctx),
             Kind::Using if self.is_using_declaration() => self.parse_using_statement(),
             Kind::Await if self.is_using_statement() => self.parse_using_statement(),
+            Kind::Declare if self.at_start_of_flow_declare_statement() => {
+                self.parse_flow_declare_statement(self.start_span())
+            }
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_ts_declaration_statement(self.start_span())
+            }
             Kind::Interface
             | Kind::Type
             | Kind::Module
@@ -207,7 +217,11 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly);
         let mut body = self.ast.vec();
         while !self.at(Kind::RCurly) && !self.has_fatal_error() {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let start = self.start_span();
+            let mut stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if self.fatal_error.is_some() && !self.recover(&mut stmt, start) {
+                continue;
+            }
             body.push(stmt);
         }
         self.expect(Kind::RCurly);
@@ -621,7 +635,11 @@ impl<'a> ParserImpl<'a> {
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly)
             && !self.has_fatal_error()
         {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            let start = self.start_span();
+            let mut stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if self.fatal_error.is_some() && !self.recover(&mut stmt, start) {
+                continue;
+            }
             consequent.push(stmt);
         }
         Some(self.ast.switch_case(self.end_span(span), test, consequent))
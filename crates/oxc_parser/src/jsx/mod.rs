@@ -4,7 +4,7 @@ use oxc_allocator::{Box, Dummy, Vec};
 use oxc_ast::ast::*;
 use oxc_span::{Atom, GetSpan, Span};
 
-use crate::{Context, ParserImpl, diagnostics, lexer::Kind};
+use crate::{Context, ParserImpl, diagnostics, lexer::Kind, token::TokenKind};
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_jsx_expression(&mut self) -> Expression<'a> {
@@ -426,6 +426,7 @@ impl<'a> ParserImpl<'a> {
         // Currently at a valid normal Ident or Keyword, keep on lexing for `-` in `<component-name />`
         self.continue_lex_jsx_identifier();
         self.bump_any();
+        self.remap_last_token(TokenKind::JSXIdentifier);
         let span = self.end_span(span);
         let name = span.source_text(self.source_text);
         self.ast.jsx_identifier(span, name)
@@ -181,6 +181,14 @@ impl<'a> Lexer<'a> {
         token
     }
 
+    /// Move source cursor to `offset`, as if a token ended there.
+    /// Used by incremental reparsing to resume lexing at a statement boundary.
+    pub(crate) fn seek(&mut self, offset: u32) {
+        self.source.set_offset(offset);
+        self.token = Token::default();
+        self.trivia_builder.resume_at(offset);
+    }
+
     /// Advance source cursor to end of file.
     #[inline]
     pub fn advance_to_end(&mut self) {
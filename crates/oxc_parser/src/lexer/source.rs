@@ -190,6 +190,18 @@ impl<'a> Source<'a> {
         self.ptr = pos.ptr;
     }
 
+    /// Move current position to `offset` bytes from start of source.
+    ///
+    /// # Panics
+    /// Panics if `offset` is out of bounds, or not on a UTF-8 character boundary.
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        let offset = offset as usize;
+        assert!(self.whole().is_char_boundary(offset));
+        // SAFETY: Checked above that `offset` is within bounds of source text
+        // and on a UTF-8 char boundary
+        self.ptr = unsafe { self.start.add(offset) };
+    }
+
     /// Advance `Source`'s cursor to end.
     #[inline]
     pub(super) fn advance_to_end(&mut self) {
@@ -23,6 +23,9 @@ pub struct TriviaBuilder {
     /// Previous token kind, used to indicates comments are trailing from what kind
     previous_kind: Kind,
 
+    /// End of previous token, which trailing comments are attached to
+    previous_token_end: u32,
+
     pub(super) has_pure_comment: bool,
 
     pub(super) has_no_side_effects_comment: bool,
@@ -36,6 +39,7 @@ impl Default for TriviaBuilder {
             processed: 0,
             saw_newline: true,
             previous_kind: Kind::Undetermined,
+            previous_token_end: 0,
             has_pure_comment: false,
             has_no_side_effects_comment: false,
         }
@@ -51,6 +55,13 @@ impl TriviaBuilder {
         self.has_no_side_effects_comment
     }
 
+    /// Reset state as if a token ended at `offset`, with no comments pending.
+    pub fn resume_at(&mut self, offset: u32) {
+        self.processed = self.comments.len();
+        self.saw_newline = false;
+        self.previous_token_end = offset;
+    }
+
     pub fn add_irregular_whitespace(&mut self, start: u32, end: u32) {
         self.irregular_whitespaces.push(Span::new(start, end));
     }
@@ -88,6 +99,7 @@ impl TriviaBuilder {
             }
             self.processed = len;
         }
+        self.previous_token_end = token.end();
         self.saw_newline = false;
     }
 
@@ -132,6 +144,8 @@ impl TriviaBuilder {
 
         // This newly added comment may be preceded by a newline.
         comment.set_preceded_by_newline(self.saw_newline);
+        // Attach to the previous token until it turns out to be a leading comment of the next token.
+        comment.attached_to = self.previous_token_end;
         if comment.is_line() {
             // A line comment is always followed by a newline. This is never set in `handle_newline`.
             comment.set_followed_by_newline(true);
@@ -299,7 +313,7 @@ mod test {
                 span: Span::new(76, 92),
                 kind: CommentKind::Block,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 75,
                 newlines: CommentNewlines::None,
                 content: CommentContent::None,
             },
@@ -307,7 +321,7 @@ mod test {
                 span: Span::new(93, 106),
                 kind: CommentKind::Line,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 75,
                 newlines: CommentNewlines::Trailing,
                 content: CommentContent::None,
             },
@@ -347,7 +361,7 @@ token /* Trailing 1 */
                 span: Span::new(42, 58),
                 kind: CommentKind::Block,
                 position: CommentPosition::Trailing,
-                attached_to: 0,
+                attached_to: 41,
                 newlines: CommentNewlines::Trailing,
                 content: CommentContent::None,
             },
@@ -4,6 +4,7 @@
 //! - The latest stable ECMAScript syntax
 //! - TypeScript
 //! - JSX and TSX
+//! - [Flow](https://flow.org) type annotations, with [`SourceType::with_flow`]
 //! - [Stage 3 Decorators](https://github.com/tc39/proposal-decorator-metadata)
 //!
 //! # Usage
@@ -69,10 +70,13 @@
 mod context;
 mod cursor;
 mod error_handler;
+mod incremental;
 mod modifiers;
 mod module_record;
 mod state;
+mod token;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -86,7 +90,7 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
+use oxc_allocator::{Allocator, Box as ArenaBox, Dummy, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
     ast::{Expression, Program},
@@ -95,10 +99,16 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{ModuleKind, SourceType, Span};
 use oxc_syntax::module_record::ModuleRecord;
 
+pub use crate::{
+    flow::has_flow_pragma,
+    incremental::TextEdit,
+    token::{Token, TokenFlags, TokenKind},
+};
+
 use crate::{
     context::{Context, StatementContext},
     error_handler::FatalError,
-    lexer::{Lexer, Token},
+    lexer::Lexer,
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
@@ -137,6 +147,9 @@ pub(crate) const MAX_LEN: usize = if std::mem::size_of::<usize>() >= 8 {
 /// When the parser cannot recover, it will abort and terminate parsing early. [`program`] will
 /// be empty and [`panicked`] will be `true`.
 ///
+/// With [`ParseOptions::recover_from_errors`], the parser also recovers from errors which would
+/// otherwise abort parsing, and reports all of them.
+///
 /// [`program`]: ParserReturn::program
 /// [`errors`]: ParserReturn::errors
 /// [`panicked`]: ParserReturn::panicked
@@ -145,6 +158,7 @@ pub struct ParserReturn<'a> {
     /// The parsed AST.
     ///
     /// Will be empty (e.g. no statements, directives, etc) if the parser panicked.
+    /// See [`ParseOptions::recover_from_errors`] for the AST of a recovered parse.
     ///
     /// ## Validity
     /// It is possible for the AST to be present and semantically invalid. This will happen if
@@ -168,6 +182,12 @@ pub struct ParserReturn<'a> {
     /// Irregular whitespaces for `Oxlint`
     pub irregular_whitespaces: Box<[Span]>,
 
+    /// Tokens of the source text, in source order.
+    ///
+    /// Empty unless [`ParseOptions::collect_tokens`] is enabled.
+    /// If the parser panicked, only contains the tokens before the error.
+    pub tokens: ArenaVec<'a, Token>,
+
     /// Whether the parser panicked and terminated early.
     ///
     /// This will be `false` if parsing was successful, or if parsing was able to recover from a
@@ -179,6 +199,10 @@ pub struct ParserReturn<'a> {
     pub panicked: bool,
 
     /// Whether the file is [flow](https://flow.org).
+    ///
+    /// This is only detected for JavaScript files which failed to parse, and means the file
+    /// should be parsed again with [`SourceType::with_flow`]. Use [`has_flow_pragma`] to
+    /// detect Flow files before parsing.
     pub is_flow_language: bool,
 }
 
@@ -221,6 +245,29 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Recover from syntax errors which would otherwise abort parsing.
+    ///
+    /// Parsing resumes at the next statement, class element or object property, so all
+    /// syntax errors are reported and [`ParserReturn::program`] contains everything which
+    /// could be parsed. A statement, class element or object property containing an error is
+    /// kept with the part before the error, and placeholder nodes (e.g. `null` literals, empty
+    /// identifiers and empty statements) with an empty span at the position of the error.
+    /// Source text after the error is skipped up to the next element.
+    ///
+    /// This is useful for editors, which need the AST of incomplete code for semantic analysis
+    /// and linting. The AST is not valid JavaScript if [`ParserReturn::errors`] is non-empty.
+    ///
+    /// Default: `false`
+    pub recover_from_errors: bool,
+
+    /// Collect [`Token`]s into [`ParserReturn::tokens`].
+    ///
+    /// Tokens are needed by ESLint-compatible rules which check formatting (e.g. `comma-spacing`),
+    /// and for semantic highlighting.
+    ///
+    /// Default: `false`
+    pub collect_tokens: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +278,8 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            recover_from_errors: false,
+            collect_tokens: false,
         }
     }
 }
@@ -348,6 +397,53 @@ mod parser_parse {
             );
             parser.parse_expression()
         }
+
+        /// Parse the source text after a single [`TextEdit`], reusing the AST of the previous parse.
+        ///
+        /// Only the top-level statement containing the edit is re-lexed and re-parsed. All other
+        /// top-level statements, comments and module record entries are moved over from `previous`,
+        /// with their spans shifted by the length change of the edit.
+        /// If the edit cannot be handled incrementally (e.g. it crosses statement boundaries,
+        /// or `previous` has errors), the whole source text is parsed again.
+        ///
+        /// `previous` must be the result of parsing the source text before the edit, with the
+        /// same [`SourceType`] and [`ParseOptions`], and allocated in the same [`Allocator`].
+        /// The previous AST is consumed, but reused statements stay in the arena,
+        /// so the allocator should be reset from time to time.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TextEdit};
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::default();
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 2;", source_type).parse();
+        ///
+        /// // Replace `1` with `100`
+        /// let edit = TextEdit::new(Span::new(8, 9), 3);
+        /// let ret = Parser::new(&allocator, "let a = 100;\nlet b = 2;", source_type)
+        ///     .parse_incremental(ret, edit);
+        /// ```
+        pub fn parse_incremental(
+            self,
+            previous: ParserReturn<'a>,
+            edit: TextEdit,
+        ) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            // `parser` is dropped before falling back to `parse`,
+            // which creates a new `UniquePromise`.
+            parser.parse_incremental(previous, edit).unwrap_or_else(|| self.parse())
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -369,10 +465,14 @@ struct ParserImpl<'a> {
     /// Note: favor adding to `Diagnostics` instead of raising Err
     errors: Vec<OxcDiagnostic>,
 
-    fatal_error: Option<FatalError>,
+    fatal_error: Option<FatalError<'a>>,
+
+    /// Whether to recover from fatal errors, see [`ParseOptions::recover_from_errors`].
+    /// Disabled while parsing speculatively.
+    recover_from_errors: bool,
 
     /// The current parsing token
-    token: Token,
+    token: lexer::Token,
 
     /// The end range of the previous token
     prev_token_end: u32,
@@ -380,6 +480,9 @@ struct ParserImpl<'a> {
     /// Parser state
     state: ParserState<'a>,
 
+    /// Tokens collected when [`ParseOptions::collect_tokens`] is enabled
+    tokens: ArenaVec<'a, Token>,
+
     /// Parsing context
     ctx: Context,
 
@@ -389,8 +492,12 @@ struct ParserImpl<'a> {
     /// Module Record Builder
     module_record_builder: ModuleRecordBuilder<'a>,
 
-    /// Precomputed typescript detection
+    /// Precomputed typescript detection.
+    /// Also `true` for Flow, whose type annotations are parsed into TypeScript AST nodes.
     is_ts: bool,
+
+    /// Precomputed flow detection
+    is_flow: bool,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -413,13 +520,16 @@ impl<'a> ParserImpl<'a> {
             source_text,
             errors: vec![],
             fatal_error: None,
-            token: Token::default(),
+            recover_from_errors: options.recover_from_errors,
+            token: lexer::Token::default(),
             prev_token_end: 0,
             state: ParserState::new(),
+            tokens: ArenaVec::new_in(allocator),
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
-            is_ts: source_type.is_typescript(),
+            is_ts: source_type.is_typescript() || source_type.is_flow(),
+            is_flow: source_type.is_flow(),
         }
     }
 
@@ -491,6 +601,7 @@ impl<'a> ParserImpl<'a> {
             module_record,
             errors,
             irregular_whitespaces,
+            tokens: self.tokens,
             panicked,
             is_flow_language,
         }
@@ -588,7 +699,7 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{CommentKind, Expression, RegExpFlags, Statement};
     use oxc_span::GetSpan;
 
     use super::*;
@@ -632,7 +743,71 @@ mod test {
             assert!(ret.is_flow_language);
             assert_eq!(ret.errors.len(), 1);
             assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
+            assert!(has_flow_pragma(source));
         }
+
+        assert!(has_flow_pragma("#!/usr/bin/env node\n// @flow strict\nfoo;"));
+        assert!(!has_flow_pragma("foo; // @flow"));
+        assert!(has_flow_pragma("// eslint-disable\n// @flow\nfoo;"));
+        assert!(has_flow_pragma("/* Copyright */\n\n/**\n * @flow strict\n */\nfoo;"));
+        assert!(!has_flow_pragma("// eslint-disable\nfoo; // @flow"));
+        assert!(!has_flow_pragma("const s = '@flow';"));
+    }
+
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx().with_flow(true);
+        let sources = [
+            "// @flow\nconst x: number = 1;",
+            "import type { Node } from 'react';",
+            "import typeof TheDefault, { named } from './mod';",
+            "import { typeof other, type Type } from './mod';",
+            "type Props = {| +name: string, age?: ?number, ...Other |};",
+            "type Inexact = { a: number, ... };",
+            "type Empty = {||};",
+            "type Indexer = { [string]: number, [key: string]: number };",
+            "type Union = {| a: A | B |};",
+            "type Callback = (string, y?: number, ...Array<mixed>) => void;",
+            "type Generic = <T>(x: T) => T;",
+            "type Maybe = ?() => void;",
+            "opaque type ID: string = string;",
+            "export opaque type Token = string;",
+            "declare opaque type Secret: string;",
+            "declare export function f(x: number): string;",
+            "declare module 'm' { declare export var x: number; }",
+            "class C<+T, -U: Object = {}> { p: T; }",
+            "function f(x: mixed): boolean %checks { return !!x; }",
+            "const a = (b: any);",
+            "const a = cond ? (b: number) : c;",
+            "const f = <T>(x: T): T => x;",
+            "<div>{(value: any)}</div>;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let source = "(a: any)";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("Expected ExpressionStatement");
+        };
+        let Expression::ParenthesizedExpression(expr) = &stmt.expression else {
+            panic!("Expected ParenthesizedExpression");
+        };
+        assert!(matches!(expr.expression, Expression::TSAsExpression(_)));
+
+        for source in ["type T = { -x: number };", "class C { -x: number; }"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), "Flow write-only properties are not supported");
+        }
+
+        let source = "declare module.exports: number;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Flow `declare module.exports` is not supported");
     }
 
     #[test]
@@ -729,6 +904,62 @@ mod test {
         }
     }
 
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::jsx();
+        let source = "let a = /x/gi;\nif (`t${a}u`) <A b=\"c\">d</A>; obj.if = true";
+        let opts = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind, token.span.source_text(source)))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Keyword, "let"),
+                (TokenKind::Identifier, "a"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::RegularExpression, "/x/gi"),
+                (TokenKind::Punctuator, ";"),
+                (TokenKind::Keyword, "if"),
+                (TokenKind::Punctuator, "("),
+                (TokenKind::Template, "`t${"),
+                (TokenKind::Identifier, "a"),
+                (TokenKind::Template, "}u`"),
+                (TokenKind::Punctuator, ")"),
+                (TokenKind::Punctuator, "<"),
+                (TokenKind::JSXIdentifier, "A"),
+                (TokenKind::JSXIdentifier, "b"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::String, "\"c\""),
+                (TokenKind::Punctuator, ">"),
+                (TokenKind::JSXText, "d"),
+                (TokenKind::Punctuator, "<"),
+                (TokenKind::Punctuator, "/"),
+                (TokenKind::JSXIdentifier, "A"),
+                (TokenKind::Punctuator, ">"),
+                (TokenKind::Punctuator, ";"),
+                (TokenKind::Identifier, "obj"),
+                (TokenKind::Punctuator, "."),
+                (TokenKind::Identifier, "if"),
+                (TokenKind::Punctuator, "="),
+                (TokenKind::Boolean, "true"),
+            ]
+        );
+        assert_eq!(ret.tokens[3].regex_flags, RegExpFlags::G | RegExpFlags::I);
+        assert!(ret.tokens[5].flags.contains(TokenFlags::OnNewLine));
+        assert_eq!(ret.tokens[7].flags, TokenFlags::TemplateHead);
+        assert_eq!(ret.tokens[9].flags, TokenFlags::TemplateTail);
+
+        // Not collected by default
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
     #[test]
     fn hashbang() {
         let allocator = Allocator::default();
@@ -177,9 +177,11 @@ impl<'a> ModuleRecordBuilder<'a> {
     }
 
     pub fn visit_import_expression(&mut self, e: &ImportExpression<'a>) {
-        self.module_record
-            .dynamic_imports
-            .push(DynamicImport { span: e.span, module_request: e.source.span() });
+        self.add_dynamic_import(DynamicImport { span: e.span, module_request: e.source.span() });
+    }
+
+    pub fn add_dynamic_import(&mut self, dynamic_import: DynamicImport) {
+        self.module_record.dynamic_imports.push(dynamic_import);
     }
 
     pub fn visit_import_meta(&mut self, span: Span) {
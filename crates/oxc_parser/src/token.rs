@@ -0,0 +1,149 @@
+//! Tokens collected with [`ParseOptions::collect_tokens`].
+//!
+//! [`ParseOptions::collect_tokens`]: crate::ParseOptions::collect_tokens
+
+use bitflags::bitflags;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::Span;
+
+use crate::{ParserImpl, lexer::Kind};
+
+/// A token of the parsed source text.
+///
+/// Only tokens consumed by the parser are collected, so the list does not contain comments,
+/// the hashbang, or tokens which were re-lexed (e.g. `/` which turned out to start a regular
+/// expression).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// Kind of token
+    pub kind: TokenKind,
+    /// Location of the token in the source text
+    pub span: Span,
+    /// Flags of the token
+    pub flags: TokenFlags,
+    /// Flags of a [`TokenKind::RegularExpression`] token. Empty for all other tokens.
+    pub regex_flags: RegExpFlags,
+}
+
+/// Kind of a [`Token`].
+///
+/// Follows [ESTree token types](https://github.com/eslint/js/tree/main/packages/espree#tokenize),
+/// so tokens can be passed on to ESLint-compatible consumers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// `true` or `false`
+    Boolean,
+    /// Identifier, including contextual keywords (e.g. `async`, `of`, `type`),
+    /// and keywords used as identifiers
+    Identifier,
+    /// Reserved word (e.g. `if`, `this`), or reserved word in strict mode (e.g. `let`, `static`)
+    Keyword,
+    /// `null`
+    Null,
+    /// Numeric or BigInt literal
+    Numeric,
+    /// Punctuator (e.g. `(`, `=>`, `@`)
+    Punctuator,
+    /// String literal, including JSX attribute strings
+    String,
+    /// Regular expression literal, including the flags
+    RegularExpression,
+    /// Part of a template literal, from `` ` `` or `}` up to `${` or `` ` ``
+    Template,
+    /// `#name`
+    PrivateIdentifier,
+    /// Name of a JSX element or attribute
+    JSXIdentifier,
+    /// Text between JSX tags
+    JSXText,
+}
+
+bitflags! {
+    /// Flags of a [`Token`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TokenFlags: u8 {
+        /// A line terminator precedes the token
+        const OnNewLine = 1 << 0;
+        /// The token contains escape sequences, e.g. `a` in an identifier or `\n` in a string
+        const Escaped = 1 << 1;
+        /// The [`TokenKind::Template`] token starts a template literal with `` ` ``
+        const TemplateHead = 1 << 2;
+        /// The [`TokenKind::Template`] token ends a template literal with `` ` ``
+        const TemplateTail = 1 << 3;
+    }
+}
+
+impl TokenKind {
+    fn from_kind(kind: Kind) -> Option<Self> {
+        let token_kind = match kind {
+            Kind::Eof | Kind::Undetermined | Kind::Skip | Kind::HashbangComment => return None,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Null => Self::Null,
+            Kind::Ident => Self::Identifier,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegularExpression,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::JSXText => Self::JSXText,
+            _ if kind.is_number() => Self::Numeric,
+            _ if kind.is_reserved_keyword() || kind.is_strict_mode_contextual_keyword() => {
+                Self::Keyword
+            }
+            _ if kind.is_contextual_keyword() => Self::Identifier,
+            _ => Self::Punctuator,
+        };
+        Some(token_kind)
+    }
+}
+
+impl ParserImpl<'_> {
+    /// Collect the current token, as `kind`.
+    #[inline]
+    pub(crate) fn collect_token(&mut self, kind: Kind) {
+        if self.options.collect_tokens {
+            self.push_token(kind);
+        }
+    }
+
+    fn push_token(&mut self, kind: Kind) {
+        let Some(token_kind) = TokenKind::from_kind(kind) else { return };
+        let token = self.token;
+        let span = token.span();
+
+        let mut flags = TokenFlags::empty();
+        flags.set(TokenFlags::OnNewLine, token.is_on_new_line());
+        flags.set(TokenFlags::Escaped, token.escaped());
+        flags.set(
+            TokenFlags::TemplateHead,
+            matches!(kind, Kind::NoSubstitutionTemplate | Kind::TemplateHead),
+        );
+        flags.set(
+            TokenFlags::TemplateTail,
+            matches!(kind, Kind::NoSubstitutionTemplate | Kind::TemplateTail),
+        );
+
+        let mut regex_flags = RegExpFlags::empty();
+        if kind == Kind::RegExp {
+            // Flags are identifier characters, so the last `/` closes the pattern
+            let raw = span.source_text(self.source_text);
+            if let Some(pattern_end) = raw.rfind('/') {
+                for flag in raw[pattern_end + 1..].bytes() {
+                    regex_flags |= RegExpFlags::try_from(flag).unwrap_or(RegExpFlags::empty());
+                }
+            }
+        }
+
+        self.tokens.push(Token { kind: token_kind, span, flags, regex_flags });
+    }
+
+    /// Change kind of the last collected token.
+    pub(crate) fn remap_last_token(&mut self, kind: TokenKind) {
+        if let Some(token) = self.tokens.last_mut() {
+            token.kind = kind;
+        }
+    }
+}
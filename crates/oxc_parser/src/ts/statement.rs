@@ -421,6 +421,9 @@ impl<'a> ParserImpl<'a> {
                 Declaration::TSModuleDeclaration(decl)
             }
             Kind::Type => self.parse_ts_type_alias_declaration(start_span, modifiers),
+            Kind::Ident if self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_declaration(start_span, modifiers)
+            }
             Kind::Enum => self.parse_ts_enum_declaration(start_span, modifiers),
             Kind::Interface => {
                 self.bump_any();
@@ -573,6 +576,7 @@ impl<'a> ParserImpl<'a> {
                     return self.cur_kind().is_binding_identifier()
                         && !self.cur_token().is_on_new_line();
                 }
+                Kind::Ident if self.at_flow_opaque_type() => return true,
                 Kind::Module | Kind::Namespace => {
                     self.bump_any();
                     return !self.cur_token().is_on_new_line()
@@ -50,7 +50,7 @@ impl<'a> ParserImpl<'a> {
         ty
     }
 
-    fn parse_function_or_constructor_type(&mut self) -> TSType<'a> {
+    pub(crate) fn parse_function_or_constructor_type(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let r#abstract = self.eat(Kind::Abstract);
         let is_constructor_type = self.eat(Kind::New);
@@ -81,7 +81,10 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
-    fn is_start_of_function_type_or_constructor_type(&mut self) -> bool {
+    pub(crate) fn is_start_of_function_type_or_constructor_type(&mut self) -> bool {
+        if self.is_flow {
+            return self.is_start_of_flow_function_type();
+        }
         if self.at(Kind::LAngle) {
             return true;
         }
@@ -188,6 +191,8 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type_parameter(&mut self) -> TSTypeParameter<'a> {
         let span = self.start_span();
 
+        // Flow variance: `<+T, -U>`
+        let (r#in, out) = if self.is_flow { self.parse_flow_variance() } else { (false, false) };
         let modifiers = self.parse_modifiers(true, false);
         self.verify_modifiers(
             &modifiers,
@@ -196,7 +201,12 @@ impl<'a> ParserImpl<'a> {
         );
 
         let name = self.parse_binding_identifier();
-        let constraint = self.parse_ts_type_constraint();
+        // Flow bound: `<T: Bound>`
+        let constraint = if self.is_flow && self.eat(Kind::Colon) {
+            Some(self.parse_ts_type())
+        } else {
+            self.parse_ts_type_constraint()
+        };
         let default = self.parse_ts_default_type();
 
         self.ast.ts_type_parameter(
@@ -204,8 +214,8 @@ impl<'a> ParserImpl<'a> {
             name,
             constraint,
             default,
-            modifiers.contains(ModifierKind::In),
-            modifiers.contains(ModifierKind::Out),
+            r#in || modifiers.contains(ModifierKind::In),
+            out || modifiers.contains(ModifierKind::Out),
             modifiers.contains(ModifierKind::Const),
         )
     }
@@ -233,9 +243,11 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        // `|` may end a Flow exact object type: `{| a: A | B |}`
+        if (self.at(kind) && !self.at_flow_exact_object_type_end()) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while self.at(kind) && !self.at_flow_exact_object_type_end() {
+                self.bump_any();
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -251,7 +263,7 @@ impl<'a> ParserImpl<'a> {
         ty
     }
 
-    fn parse_type_operator_or_higher(&mut self) -> TSType<'a> {
+    pub(crate) fn parse_type_operator_or_higher(&mut self) -> TSType<'a> {
         match self.cur_kind() {
             Kind::KeyOf => self.parse_type_operator(TSTypeOperatorOperator::Keyof),
             Kind::Unique => self.parse_type_operator(TSTypeOperatorOperator::Unique),
@@ -380,6 +392,7 @@ impl<'a> ParserImpl<'a> {
             // // falls through
             // case SyntaxKind.FunctionKeyword:
             // return parseJSDocFunctionType();
+            Kind::Question if self.is_flow => self.parse_flow_maybe_type(),
             Kind::Question => self.parse_js_doc_unknown_or_nullable_type(),
             Kind::Bang => self.parse_js_doc_non_nullable_type(),
             Kind::NoSubstitutionTemplate | Kind::Str | Kind::True | Kind::False => {
@@ -419,6 +432,7 @@ impl<'a> ParserImpl<'a> {
             Kind::Typeof => {
                 self.parse_type_query()
             }
+            Kind::LCurly if self.is_flow => self.parse_flow_object_type(),
             Kind::LCurly => {
                 if self.lookahead(Self::is_start_of_mapped_type) {
                     self.parse_mapped_type()
@@ -1037,7 +1051,11 @@ impl<'a> ParserImpl<'a> {
         }
         let span = self.start_span();
         let return_type = self.parse_return_type();
-        Some(self.ast.alloc_ts_type_annotation(self.end_span(span), return_type))
+        let span = self.end_span(span);
+        if self.is_flow {
+            self.parse_flow_predicate();
+        }
+        Some(self.ast.alloc_ts_type_annotation(span, return_type))
     }
 
     fn parse_return_type(&mut self) -> TSType<'a> {
@@ -1229,7 +1247,7 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
-    fn parse_type_member_semicolon(&mut self) {
+    pub(crate) fn parse_type_member_semicolon(&mut self) {
         // We allow type members to be separated by commas or (possibly ASI) semicolons.
         // First check if it was a comma.  If so, we're done with the member.
         if self.eat(Kind::Comma) {
@@ -2,7 +2,7 @@ use memchr::memmem::Finder;
 
 use oxc_span::{SourceType, Span};
 
-use crate::loader::JavaScriptSource;
+use crate::JavaScriptSource;
 
 use super::{SCRIPT_END, SCRIPT_START};
 
@@ -1,11 +1,12 @@
-use oxc_span::VALID_EXTENSIONS;
-
-use crate::loader::JavaScriptSource;
+//! Extracts the JavaScript and TypeScript sections of framework files, such as the `<script>`
+//! blocks of Vue and Svelte components and the frontmatter of Astro components.
 
 mod astro;
+mod source;
 mod svelte;
 mod vue;
 pub use astro::AstroPartialLoader;
+pub use source::JavaScriptSource;
 pub use svelte::SveltePartialLoader;
 pub use vue::VuePartialLoader;
 
@@ -14,12 +15,7 @@ const SCRIPT_END: &str = "</script>";
 
 /// File extensions that can contain JS/TS code in certain parts, such as in `<script>` tags, and can
 /// be loaded using the [`PartialLoader`].
-pub const LINT_PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
-
-/// All valid JavaScript/TypeScript extensions, plus additional framework files that
-/// contain JavaScript/TypeScript code in them (e.g., Vue, Astro, Svelte, etc.).
-pub const LINTABLE_EXTENSIONS: &[&str] =
-    constcat::concat_slices!([&str]: VALID_EXTENSIONS, LINT_PARTIAL_LOADER_EXTENSIONS);
+pub const PARTIAL_LOADER_EXTENSIONS: &[&str] = &["vue", "astro", "svelte"];
 
 pub struct PartialLoader;
 
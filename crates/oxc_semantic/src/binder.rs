@@ -145,7 +145,7 @@ impl<'a> Binder<'a> for Function<'a> {
         };
 
         if let Some(ident) = &self.id {
-            let excludes = if builder.source_type.is_typescript() {
+            let excludes = if builder.source_type.is_typescript() || builder.source_type.is_flow() {
                 SymbolFlags::FunctionExcludes
             } else if is_function_part_of_if_statement(self, builder) {
                 SymbolFlags::empty()
@@ -408,8 +408,11 @@ pub fn check_module_declaration(decl: &ModuleDeclarationKind, ctx: &SemanticBuil
             ctx.error(module_code(text, span));
         }
         ModuleKind::Module => {
-            if matches!(ctx.nodes.parent_kind(ctx.current_node_id), AstKind::Program(_)) {
-                return;
+            match ctx.nodes.parent_kind(ctx.current_node_id) {
+                AstKind::Program(_) => return,
+                // Flow `declare module 'name' { declare export ... }`
+                AstKind::TSModuleBlock(_) if ctx.source_type.is_flow() => return,
+                _ => {}
             }
             ctx.error(top_level(text, span));
         }
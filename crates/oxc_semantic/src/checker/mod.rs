@@ -79,7 +79,10 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
             }
             ts::check_class(class, ctx);
         }
-        AstKind::Function(func) if !ctx.source_type.is_typescript() => {
+        // Flow allows overloading functions with `declare function`, like TypeScript.
+        AstKind::Function(func)
+            if !ctx.source_type.is_typescript() && !ctx.source_type.is_flow() =>
+        {
             js::check_function_redeclaration(func, ctx);
         }
         AstKind::MethodDefinition(method) => {
@@ -36,6 +36,10 @@ fn jsdoc_type_in_annotation(
 }
 
 pub fn check_ts_type_annotation(annotation: &TSTypeAnnotation<'_>, ctx: &SemanticBuilder<'_>) {
+    // `?T` is a Flow maybe type.
+    if ctx.source_type.is_flow() {
+        return;
+    }
     let (modifier, is_start, span_with_illegal_modifier) = match &annotation.type_annotation {
         TSType::JSDocNonNullableType(ty) => ('!', !ty.postfix, ty.span()),
         TSType::JSDocNullableType(ty) => ('?', !ty.postfix, ty.span()),
@@ -110,6 +114,10 @@ pub fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<
 fn check_duplicate_bound_names<'a, T: BoundNames<'a>>(bound_names: &T, ctx: &SemanticBuilder<'_>) {
     let mut idents: FxHashMap<Atom<'a>, Span> = FxHashMap::default();
     bound_names.bound_names(&mut |ident| {
+        // Unnamed Flow function type parameters: `(string, number) => void`
+        if ident.name.is_empty() {
+            return;
+        }
         if let Some(old_span) = idents.insert(ident.name, ident.span) {
             ctx.error(redeclaration(&ident.name, old_span, ident.span));
         }
@@ -3,6 +3,7 @@
 pub mod cfg;
 pub mod classes;
 pub mod modules;
+pub mod recovery;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
@@ -0,0 +1,60 @@
+use oxc_span::SourceType;
+
+use crate::util::SemanticTester;
+
+#[test]
+fn test_recovered_statements() {
+    SemanticTester::js("let a = ;\nfoo(a b);\nif (a) { a = 1 2 }\nbar(a);")
+        .with_recovery(true)
+        .has_root_symbol("a")
+        .has_number_of_reads(3)
+        .has_number_of_writes(1)
+        .test();
+
+    SemanticTester::js("class A {\n  a = ;\n  b() { return A; }\n}\nconst o = { a: , b: o };")
+        .with_recovery(true)
+        .has_root_symbol("A")
+        .has_number_of_reads(1)
+        .test();
+}
+
+#[test]
+fn test_placeholders_declare_nothing() {
+    let sources = [
+        "const = 1;",
+        "let [a, = 1;",
+        "let { a: } = b;",
+        "function f(, b) {}",
+        "function f(a,",
+        "for (;;) { let }",
+        "export const ;",
+        "import { a as } from 'a';",
+        "export { a as",
+        "x = { a: , b };",
+        "for (const of y) {}",
+        "f(...);",
+        "++;",
+        "a = ;",
+        "x = (a, ) => ;",
+        "switch (x) { case : }",
+        "new ;",
+        "`${}`;",
+        "export default ;",
+        "enum { A }",
+        "declare const ;",
+        "let x: = 1;",
+        "function f<, T>() {}",
+        "import a = ;",
+    ];
+    for source_text in sources {
+        let tester =
+            SemanticTester::new(source_text, SourceType::tsx()).with_recovery(true).with_cfg(true);
+        let semantic = tester.build_with_errors().semantic;
+        let scoping = semantic.scoping();
+        assert!(scoping.symbol_names().all(|name| !name.is_empty()), "{source_text}");
+        assert!(
+            scoping.root_unresolved_references().keys().all(|name| !name.is_empty()),
+            "{source_text}"
+        );
+    }
+}
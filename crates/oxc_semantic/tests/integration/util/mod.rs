@@ -9,6 +9,7 @@ use itertools::Itertools;
 use oxc_allocator::Allocator;
 use oxc_cfg::DisplayDot;
 use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+use oxc_parser::ParseOptions;
 use oxc_semantic::{Semantic, SemanticBuilder, SemanticBuilderReturn, dot::DebugDot};
 use oxc_span::SourceType;
 pub use symbol_tester::SymbolTester;
@@ -31,6 +32,11 @@ pub struct SemanticTester<'a> {
     ///
     /// Default is `false`.
     expect_errors: bool,
+    /// Parse with [`ParseOptions::recover_from_errors`], the source is expected to have syntax
+    /// errors.
+    ///
+    /// [`ParseOptions::recover_from_errors`]: oxc_parser::ParseOptions::recover_from_errors
+    recover_from_errors: bool,
 }
 
 impl<'a> SemanticTester<'a> {
@@ -67,6 +73,7 @@ impl<'a> SemanticTester<'a> {
             cfg: false,
             scope_tree_child_ids: false,
             expect_errors: false,
+            recover_from_errors: false,
         }
     }
 
@@ -103,6 +110,12 @@ impl<'a> SemanticTester<'a> {
         self
     }
 
+    /// Parse the source text, which has syntax errors, with error recovery.
+    pub fn with_recovery(mut self, yes: bool) -> Self {
+        self.recover_from_errors = yes;
+        self
+    }
+
     /// The program being tested is expected to produce errors during semantic analysis.
     ///
     /// By default, programs are expected to be error-free.
@@ -152,20 +165,33 @@ impl<'a> SemanticTester<'a> {
     ///
     #[expect(unstable_name_collisions)]
     pub fn build_with_errors(&self) -> SemanticBuilderReturn<'_> {
-        let parse =
-            oxc_parser::Parser::new(&self.allocator, self.source_text, self.source_type).parse();
+        let options = ParseOptions {
+            recover_from_errors: self.recover_from_errors,
+            ..ParseOptions::default()
+        };
+        let parse = oxc_parser::Parser::new(&self.allocator, self.source_text, self.source_type)
+            .with_options(options)
+            .parse();
 
-        assert!(
-            parse.errors.is_empty(),
-            "\n Failed to parse source:\n{}\n\n{}",
-            self.source_text,
-            parse
-                .errors
-                .iter()
-                .map(|e| format!("{e}"))
-                .intersperse("\n\n".to_owned())
-                .collect::<String>()
-        );
+        if self.recover_from_errors {
+            assert!(
+                !parse.errors.is_empty() && !parse.panicked,
+                "\n Expected recovered syntax errors in source:\n{}",
+                self.source_text
+            );
+        } else {
+            assert!(
+                parse.errors.is_empty(),
+                "\n Failed to parse source:\n{}\n\n{}",
+                self.source_text,
+                parse
+                    .errors
+                    .iter()
+                    .map(|e| format!("{e}"))
+                    .intersperse("\n\n".to_owned())
+                    .collect::<String>()
+            );
+        }
 
         SemanticBuilder::new()
             .with_check_syntax_error(true)
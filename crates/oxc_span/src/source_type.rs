@@ -42,6 +42,8 @@ pub enum Language {
     TypeScript = 1,
     /// Indicates a TypeScript definition file (`*.d.ts`)
     TypeScriptDefinition = 2,
+    /// Indicates a JavaScript or JSX file with [Flow](https://flow.org) type annotations
+    Flow = 3,
 }
 
 /// Script or Module
@@ -346,6 +348,13 @@ impl SourceType {
         self.language == Language::TypeScriptDefinition
     }
 
+    /// Returns `true` if this is a JavaScript file with [Flow](https://flow.org) type annotations.
+    ///
+    /// Flow files are never created from a file path, since they share the `.js` extension.
+    pub fn is_flow(self) -> bool {
+        self.language == Language::Flow
+    }
+
     /// Returns `true` if this source type is using JSX.
     ///
     /// Note that TSX is considered JSX in this context.
@@ -431,6 +440,18 @@ impl SourceType {
         self
     }
 
+    /// Mark this [`SourceType`] as using [Flow] if `yes` is `true`. No change
+    /// will occur if `yes` is `false`.
+    ///
+    /// [Flow]: Language::Flow
+    #[must_use]
+    pub const fn with_flow(mut self, yes: bool) -> Self {
+        if yes {
+            self.language = Language::Flow;
+        }
+        self
+    }
+
     /// Mark this [`SourceType`] as using [JSX] if `yes` is `true`. No change
     /// will occur if `yes` is `false`.
     ///
@@ -691,4 +712,14 @@ mod tests {
         assert!(cjs.is_javascript());
         assert!(jsx.is_jsx());
     }
+
+    #[test]
+    fn test_flow() {
+        let flow = SourceType::jsx().with_flow(true);
+        assert!(flow.is_flow());
+        assert!(flow.is_jsx());
+        assert!(!flow.is_javascript());
+        assert!(!flow.is_typescript());
+        assert!(!SourceType::from_path("foo.js").unwrap().is_flow());
+    }
 }
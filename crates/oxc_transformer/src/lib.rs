@@ -127,6 +127,14 @@ impl<'a> Transformer<'a> {
         self.ctx.source_type = program.source_type;
         self.ctx.source_text = program.source_text;
 
+        let source_type = program.source_type;
+        if source_type.is_flow() {
+            // Flow types are parsed into TypeScript AST nodes, which are removed like TypeScript's.
+            // Like Babel's `transform-flow-strip-types`, unused imports are not removed, because
+            // Flow imports are only elided when they are explicitly `import type`.
+            self.typescript.only_remove_type_imports = true;
+        }
+
         if program.source_type.is_jsx() {
             jsx::update_options_with_comments(
                 &program.comments,
@@ -144,9 +152,7 @@ impl<'a> Transformer<'a> {
                 .proposals
                 .explicit_resource_management
                 .then(|| ExplicitResourceManagement::new(&self.ctx)),
-            x0_typescript: program
-                .source_type
-                .is_typescript()
+            x0_typescript: (source_type.is_typescript() || source_type.is_flow())
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
             x2_es2022: ES2022::new(
@@ -127,7 +127,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
 
                             if specifiers.is_empty() {
                                 // `import { type A } from 'mod'`
-                                if self.only_remove_type_imports {
+                                // Flow removes the import declaration, like Babel's `transform-flow-strip-types`.
+                                if self.only_remove_type_imports && !self.ctx.source_type.is_flow() {
                                     // -> `import 'mod'`
                                     decl.specifiers = None;
                                     true
@@ -0,0 +1,38 @@
+use oxc_span::SourceType;
+use oxc_transformer::TransformOptions;
+
+use crate::{codegen, test_with_source_type};
+
+#[test]
+fn strip_flow_types() {
+    let source_type = SourceType::mjs().with_flow(true);
+    let cases = [
+        (
+            "import type { Node } from 'react';
+            import typeof TheDefault, { named } from './mod';
+            import { typeof other } from './other';
+            import { type A, b } from './x';
+            export type Props = {| +name: string, age?: ?number, ...Other |};
+            opaque type ID: string = string;
+            export opaque type Token = string;
+            declare export function f(x: number): string;
+            declare var __DEV__: boolean;",
+            "import { b } from './x';",
+        ),
+        (
+            "function f<T: Object = {}>(x: T, y?: (string, number) => void): boolean %checks {
+                return !!x;
+            }",
+            "function f(x, y) {\n\treturn !!x;\n}",
+        ),
+        ("const a = (b: any);", "const a = b;"),
+        ("const a = ((b: any): string);", "const a = b;"),
+        ("const a = (b: number): string => String(b);", "const a = (b) => String(b);"),
+        ("class C<+T> { p: T; m(): void {} }", "class C {\n\tp;\n\tm() {}\n}"),
+    ];
+    let options = TransformOptions::default();
+    for (source, expected) in cases {
+        let result = test_with_source_type(source, source_type, &options).unwrap();
+        assert_eq!(result, codegen(expected, SourceType::mjs()), "{source}");
+    }
+}
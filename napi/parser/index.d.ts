@@ -32,6 +32,8 @@ export declare class ParseResult {
   get program(): import("@oxc-project/types").Program
   get module(): EcmaScriptModule
   get comments(): Array<Comment>
+  /** Tokens of the source text. Empty unless `tokens` option is enabled. */
+  get tokens(): Array<Token>
   get errors(): Array<OxcError>
 }
 
@@ -175,6 +177,16 @@ export interface ParserOptions {
    * @default false
    */
   showSemanticErrors?: boolean
+  /**
+   * Collect tokens, and return them in `tokens` in ESTree format.
+   *
+   * Comments are not included. They are available in `comments`.
+   * Not supported by raw transfer, `experimentalRawTransfer` and `experimentalLazy` options
+   * throw an error if `tokens` is enabled.
+   *
+   * @default false
+   */
+  tokens?: boolean
 }
 
 /** Parse synchronously. */
@@ -276,6 +288,25 @@ export interface StaticImportEntry {
   isType: boolean
 }
 
+export interface Token {
+  type: 'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'String' | 'RegularExpression' | 'Template' | 'PrivateIdentifier' | 'JSXIdentifier' | 'JSXText'
+  /**
+   * Source text of the token.
+   *
+   * Without the leading `#` for `PrivateIdentifier`s.
+   */
+  value: string
+  start: number
+  end: number
+  /** Pattern and flags of a `RegularExpression` token. */
+  regex?: TokenRegExp
+}
+
+export interface TokenRegExp {
+  pattern: string
+  flags: string
+}
+
 export interface ValueSpan {
   value: string
   start: number
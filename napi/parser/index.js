@@ -51,7 +51,7 @@ function loadRawTransferLazy() {
  * @param {string} filename - Filename
  * @param {string} sourceText - Source text of file
  * @param {Object|undefined} options - Parsing options
- * @returns {Object} - Object with property getters for `program`, `module`, `comments`, and `errors`
+ * @returns {Object} - Object with property getters for `program`, `module`, `comments`, `tokens`, and `errors`
  * @throws {Error} - If `experimentalRawTransfer` or `experimentalLazy` option is enabled,
  *   and raw transfer is not supported on this platform
  */
@@ -83,7 +83,7 @@ function parseSync(filename, sourceText, options) {
  * @param {string} filename - Filename
  * @param {string} sourceText - Source text of file
  * @param {Object|undefined} options - Parsing options
- * @returns {Object} - Object with property getters for `program`, `module`, `comments`, and `errors`
+ * @returns {Object} - Object with property getters for `program`, `module`, `comments`, `tokens`, and `errors`
  * @throws {Error} - If `experimentalRawTransfer` or `experimentalLazy` option is enabled,
  *   and raw transfer is not supported on this platform
  */
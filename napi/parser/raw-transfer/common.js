@@ -43,11 +43,25 @@ if (!rawTransferSupported()) {
  * @returns {Object} - The return value of `convert`
  */
 function parseSyncRawImpl(filename, sourceText, options, convert) {
+  checkOptions(options);
   const { buffer, sourceByteLen } = prepareRaw(sourceText);
   parseSyncRawBinding(filename, buffer, sourceByteLen, options);
   return convert(buffer, sourceText, sourceByteLen);
 }
 
+/**
+ * Throw an error if options which raw transfer does not support are enabled.
+ *
+ * @param {Object|undefined} options - Parsing options
+ * @returns {undefined}
+ * @throws {Error} - If `tokens` option is enabled
+ */
+function checkOptions(options) {
+  if (options?.tokens) {
+    throw new Error('`tokens` option is not supported with `experimentalRawTransfer` or `experimentalLazy` options');
+  }
+}
+
 // User should not schedule more async tasks than there are available CPUs, as it hurts performance,
 // but it's a common mistake in async JS code to do exactly that.
 //
@@ -102,6 +116,8 @@ const queue = [];
  * @returns {Object} - The return value of `convert`
  */
 async function parseAsyncRawImpl(filename, sourceText, options, convert) {
+  checkOptions(options);
+
   // Wait for a free CPU core if all CPUs are currently busy.
   //
   // Note: `availableCores` is NOT decremented if have to wait in the queue first,
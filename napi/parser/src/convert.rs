@@ -1,13 +1,65 @@
 use rustc_hash::FxHashMap;
 
-use oxc::syntax::module_record::{self, ModuleRecord};
+use oxc::{
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
+    parser::TokenKind,
+    syntax::module_record::{self, ModuleRecord},
+};
 
 use crate::types::{
     DynamicImport, EcmaScriptModule, ExportExportName, ExportExportNameKind, ExportImportName,
     ExportImportNameKind, ExportLocalName, ExportLocalNameKind, ImportName, ImportNameKind, Span,
-    StaticExport, StaticExportEntry, StaticImport, StaticImportEntry, ValueSpan,
+    StaticExport, StaticExportEntry, StaticImport, StaticImportEntry, Token, TokenRegExp,
+    ValueSpan,
 };
 
+/// Convert tokens to ESTree format, with UTF-16 spans.
+pub fn convert_tokens(
+    tokens: &[oxc::parser::Token],
+    source_text: &str,
+    span_converter: &Utf8ToUtf16,
+) -> Vec<Token> {
+    let mut offset_converter = span_converter.converter();
+    tokens
+        .iter()
+        .map(|token| {
+            let raw = token.span.source_text(source_text);
+            let (r#type, value) = match token.kind {
+                TokenKind::Boolean => ("Boolean", raw),
+                TokenKind::Identifier => ("Identifier", raw),
+                TokenKind::Keyword => ("Keyword", raw),
+                TokenKind::Null => ("Null", raw),
+                TokenKind::Numeric => ("Numeric", raw),
+                TokenKind::Punctuator => ("Punctuator", raw),
+                TokenKind::String => ("String", raw),
+                TokenKind::RegularExpression => ("RegularExpression", raw),
+                TokenKind::Template => ("Template", raw),
+                TokenKind::PrivateIdentifier => ("PrivateIdentifier", &raw[1..]),
+                TokenKind::JSXIdentifier => ("JSXIdentifier", raw),
+                TokenKind::JSXText => ("JSXText", raw),
+            };
+            let regex = (token.kind == TokenKind::RegularExpression).then(|| {
+                let pattern_end = raw.rfind('/').unwrap_or(raw.len());
+                TokenRegExp {
+                    pattern: raw[1..pattern_end].to_string(),
+                    flags: token.regex_flags.to_string(),
+                }
+            });
+            let mut span = token.span;
+            if let Some(converter) = offset_converter.as_mut() {
+                converter.convert_span(&mut span);
+            }
+            Token {
+                r#type: r#type.to_string(),
+                value: value.to_string(),
+                start: span.start,
+                end: span.end,
+                regex,
+            }
+        })
+        .collect()
+}
+
 impl From<&ModuleRecord<'_>> for EcmaScriptModule {
     fn from(record: &ModuleRecord<'_>) -> Self {
         let mut static_imports = record
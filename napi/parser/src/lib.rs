@@ -15,11 +15,12 @@ use napi_derive::napi;
 
 use oxc::{
     allocator::Allocator,
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
     parser::{ParseOptions, Parser, ParserReturn},
     semantic::SemanticBuilder,
     span::SourceType,
 };
-use oxc_napi::{Comment, OxcError, convert_utf8_to_utf16, get_source_type};
+use oxc_napi::{Comment, OxcError, convert_utf8_to_utf16_with, get_source_type};
 
 mod convert;
 mod types;
@@ -84,6 +85,7 @@ fn parse<'a>(
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
+            collect_tokens: options.tokens.unwrap_or(false),
             ..ParseOptions::default()
         })
         .parse()
@@ -108,8 +110,19 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let mut errors = OxcError::from_diagnostics(filename, &source_text, diagnostics);
 
-    let mut comments =
-        convert_utf8_to_utf16(&source_text, &mut program, &mut module_record, &mut errors);
+    let span_converter = Utf8ToUtf16::new(&source_text);
+    let mut comments = convert_utf8_to_utf16_with(
+        &span_converter,
+        &source_text,
+        &mut program,
+        &mut module_record,
+        &mut errors,
+    );
+    let tokens = if options.tokens == Some(true) {
+        convert::convert_tokens(&ret.tokens, &source_text, &span_converter)
+    } else {
+        vec![]
+    };
 
     let program_and_fixes = match ast_type {
         AstType::JavaScript => {
@@ -139,7 +152,7 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let module = EcmaScriptModule::from(&module_record);
 
-    ParseResult { program_and_fixes, module, comments, errors }
+    ParseResult { program_and_fixes, module, comments, tokens, errors }
 }
 
 /// Parse synchronously.
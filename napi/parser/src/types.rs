@@ -46,6 +46,15 @@ pub struct ParserOptions {
     ///
     /// @default false
     pub show_semantic_errors: Option<bool>,
+
+    /// Collect tokens, and return them in `tokens` in ESTree format.
+    ///
+    /// Comments are not included. They are available in `comments`.
+    /// Not supported by raw transfer, `experimentalRawTransfer` and `experimentalLazy` options
+    /// throw an error if `tokens` is enabled.
+    ///
+    /// @default false
+    pub tokens: Option<bool>,
 }
 
 #[napi]
@@ -53,6 +62,7 @@ pub struct ParseResult {
     pub(crate) program_and_fixes: String,
     pub(crate) module: EcmaScriptModule,
     pub(crate) comments: Vec<Comment>,
+    pub(crate) tokens: Vec<Token>,
     pub(crate) errors: Vec<OxcError>,
 }
 
@@ -73,12 +83,40 @@ impl ParseResult {
         mem::take(&mut self.comments)
     }
 
+    /// Tokens of the source text. Empty unless `tokens` option is enabled.
+    #[napi(getter)]
+    pub fn tokens(&mut self) -> Vec<Token> {
+        mem::take(&mut self.tokens)
+    }
+
     #[napi(getter)]
     pub fn errors(&mut self) -> Vec<OxcError> {
         mem::take(&mut self.errors)
     }
 }
 
+#[napi(object)]
+pub struct Token {
+    #[napi(
+        ts_type = "'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'String' | 'RegularExpression' | 'Template' | 'PrivateIdentifier' | 'JSXIdentifier' | 'JSXText'"
+    )]
+    pub r#type: String,
+    /// Source text of the token.
+    ///
+    /// Without the leading `#` for `PrivateIdentifier`s.
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+    /// Pattern and flags of a `RegularExpression` token.
+    pub regex: Option<TokenRegExp>,
+}
+
+#[napi(object)]
+pub struct TokenRegExp {
+    pub pattern: String,
+    pub flags: String,
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct EcmaScriptModule {
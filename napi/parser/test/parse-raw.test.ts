@@ -253,3 +253,14 @@ it.concurrent('checks semantic', async () => {
   ret = parseSync('test.js', code, { experimentalRawTransfer: true, showSemanticErrors: true });
   expect(ret.errors.length).toBe(1);
 });
+
+it.concurrent('throws with tokens option', async () => {
+  const message = '`tokens` option is not supported with `experimentalRawTransfer` or `experimentalLazy` options';
+
+  // @ts-ignore
+  expect(() => parseSync('test.js', 'a', { experimentalRawTransfer: true, tokens: true })).toThrow(message);
+  // @ts-ignore
+  expect(() => parseSync('test.js', 'a', { experimentalLazy: true, tokens: true })).toThrow(message);
+  // @ts-ignore
+  await expect(parseAsync('test.js', 'a', { experimentalRawTransfer: true, tokens: true })).rejects.toThrow(message);
+});
@@ -712,6 +712,37 @@ describe('parse', () => {
       expect(ret.program.body[0].range).toBeUndefined();
     });
   });
+
+  describe('tokens', () => {
+    it('should include tokens when true', () => {
+      const ret = parseSync('test.js', 'a = /x/g; // c\n`t${b}`', { tokens: true });
+      expect(ret.tokens).toEqual([
+        { type: 'Identifier', value: 'a', start: 0, end: 1 },
+        { type: 'Punctuator', value: '=', start: 2, end: 3 },
+        {
+          type: 'RegularExpression',
+          value: '/x/g',
+          start: 4,
+          end: 8,
+          regex: { pattern: 'x', flags: 'g' },
+        },
+        { type: 'Punctuator', value: ';', start: 8, end: 9 },
+        { type: 'Template', value: '`t${', start: 15, end: 19 },
+        { type: 'Identifier', value: 'b', start: 19, end: 20 },
+        { type: 'Template', value: '}`', start: 20, end: 22 },
+      ]);
+    });
+
+    it('should use UTF-16 spans', () => {
+      const ret = parseSync('test.js', "'🤨' + x", { tokens: true });
+      expect(ret.tokens.map(({ start, end }) => [start, end])).toEqual([[0, 4], [5, 6], [7, 8]]);
+    });
+
+    it('should not include tokens by default', () => {
+      const ret = parseSync('test.js', 'a = b');
+      expect(ret.tokens).toEqual([]);
+    });
+  });
 });
 
 describe('UTF-16 span', () => {
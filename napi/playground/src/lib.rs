@@ -127,6 +127,7 @@ impl Oxc {
             allow_v8_intrinsics: parser_options
                 .allow_v8_intrinsics
                 .unwrap_or(default_parser_options.allow_v8_intrinsics),
+            ..default_parser_options
         };
         let ParserReturn { mut program, errors, mut module_record, .. } =
             Parser::new(&allocator, &source_text, source_type)
@@ -56,11 +56,13 @@ impl Generator for FormatterFormatGenerator {
 
         let output = quote! {
             use oxc_ast::ast::*;
+            use oxc_span::GetSpan;
 
             ///@@line_break
             use crate::{
                 formatter::{
                     Buffer, Format, FormatResult, Formatter,
+                    prelude::format_suppressed_node,
                     trivia::FormatTrailingComments,
                 },
                 parentheses::NeedsParentheses,
@@ -157,6 +159,18 @@ fn implementation(type_def: &TypeDef, schema: &Schema) -> TokenStream {
                 self.write(f)
             }
         };
+        // Nodes with a `// prettier-ignore` comment are printed as they are in the source text.
+        let write_implementation = if leading_comments.is_empty() {
+            write_implementation
+        } else {
+            quote! {
+                if f.context().comments().is_suppressed(self.span().start) {
+                    format_suppressed_node(self.span()).fmt(f)
+                } else {
+                    #write_implementation
+                }
+            }
+        };
         if needs_parentheses_before.is_empty() && trailing_comments.is_empty() {
             quote! {
                 #write_implementation
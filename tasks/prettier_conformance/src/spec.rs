@@ -7,8 +7,9 @@ use oxc_ast::ast::{
 };
 use oxc_ast_visit::VisitMut;
 use oxc_formatter::{
-    ArrowParentheses, BracketSpacing, FormatOptions, IndentWidth, LineEnding, LineWidth,
-    OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+    ArrowParentheses, BracketSpacing, EmbeddedLanguageFormatting, FormatOptions, IndentWidth,
+    LineEnding, LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons,
+    TrailingCommas,
 };
 use oxc_parser::Parser;
 use oxc_span::{GetSpan, SourceType};
@@ -176,6 +177,12 @@ impl VisitMut<'_> for SpecParser {
                                         options.experimental_operator_position =
                                             OperatorPosition::from_str(s).unwrap_or_default();
                                     }
+                                    "embeddedLanguageFormatting" => {
+                                        // TODO: change `unwrap_or_default` to `unwrap`
+                                        options.embedded_language_formatting =
+                                            EmbeddedLanguageFormatting::from_str(s)
+                                                .unwrap_or_default();
+                                    }
                                     _ => {}
                                 }
                             }